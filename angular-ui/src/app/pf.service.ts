@@ -1,6 +1,6 @@
 import { Injectable } from '@angular/core';
 import { Observable, of } from 'rxjs';
-import { HttpClient, HttpHeaders } from '@angular/common/http';
+import { HttpClient, HttpHeaders, HttpResponse } from '@angular/common/http';
 import { catchError, map, tap, finalize } from 'rxjs/operators';
 import {MatSnackBar} from '@angular/material/snack-bar';
 
@@ -61,7 +61,14 @@ type ErrorFunc<T> = (error: any) => Observable<T>;
 
 export interface Listener {
   bind: string,
-  targets: string[]
+  targets: string[],
+  enabled?: boolean,
+  max_idle_time_ms?: number,
+  connect_timeout_ms?: number,
+  max_connection_lifetime_ms?: number,
+  buffer_size?: number,
+  health_check_timeout_ms?: number,
+  health_check_interval_ms?: number,
 }
 //{"test1":{"Ok":true},"awefawef":{"Err":{"message":"invalid socket address"}},"l1":{"Ok":true}}
 export interface ListenerErrorMessage {
@@ -75,7 +82,13 @@ export interface ListenerOk {
   Ok: boolean
 }
 
-export type ListenerStatus = ListenerOk | ListenerError;
+// only set by /status/listeners
+export interface ListenerDraining {
+  draining?: boolean,
+  remaining?: number
+}
+
+export type ListenerStatus = (ListenerOk | ListenerError) & ListenerDraining;
 
 export type ListenerStatuses = Record<string, ListenerStatus>;
 
@@ -90,6 +103,73 @@ export interface Stats {
 
 
 export type StatsResponse = Record<string, Stats>;
+
+export interface HistoryPoint {
+  at: string,
+  total: number,
+  active: number,
+  downloaded_bytes: number,
+  uploaded_bytes: number,
+  connection_rate: number,
+  downloaded_rate: number,
+  uploaded_rate: number,
+}
+
+export interface History {
+  name: string,
+  resolution: "second" | "minute",
+  points: HistoryPoint[],
+}
+export interface ConfigVersion {
+  version: number,
+  at: string,
+  user: string,
+  action: string,
+  changes: string[],
+}
+
+export type Role = "viewer" | "operator" | "admin";
+
+export interface WhoAmI {
+  username: string,
+  role: Role,
+}
+
+export interface TokenInfo {
+  id: string,
+  name: string,
+  role: Role,
+  created_at: string,
+  created_by: string,
+  expires_at?: string,
+}
+
+export interface CreatedToken extends TokenInfo {
+  token: string,
+}
+
+export interface AuditRecord {
+  at: string,
+  user: string,
+  client?: string,
+  action: string,
+  changes: string[],
+  diff?: string[],
+}
+
+export interface AuditPage {
+  total: number,
+  offset: number,
+  limit: number,
+  records: AuditRecord[],
+}
+
+export interface ConfigDiff {
+  from: number,
+  to: number,
+  changes: string[],
+  lines: string[],
+}
 export type Listeners = Record<string, Listener>;
 
 export type DNS = Record<string, string>;
@@ -108,11 +188,32 @@ export class PFService {
     headers: new HttpHeaders({ 'Content-Type': 'application/json' })
   };
 
+  // ETag of the config file last loaded or saved. Sent back as If-Match, so
+  // saving over someone else's changes fails with 412 instead.
+  private configETag: string | null = null;
+
   constructor(private http: HttpClient) { }
 
+  private rememberETag<T>(response: HttpResponse<T>): T {
+    const etag = response.headers.get('ETag');
+    if (etag) {
+      this.configETag = etag;
+    }
+    return response.body as T;
+  }
+
+  private editOptions() {
+    let headers = this.httpOptions.headers;
+    if (this.configETag) {
+      headers = headers.set('If-Match', this.configETag);
+    }
+    return { headers, observe: 'response' as const };
+  }
+
   getListeners(): Observable<Listeners> {
-    return this.http.get<Listeners>(this.baseUrl + "/config/listeners",)
+    return this.http.get<Listeners>(this.baseUrl + "/config/listeners", { observe: 'response' })
           .pipe(
+            map(response => this.rememberETag(response)),
             tap(result => this.log(`fetched listeners ${JSON.stringify(result)}`)),
     );
   }
@@ -123,10 +224,17 @@ export class PFService {
       );
   }
 
+  getHistory(name:string, resolution:string, limit:number):Observable<History> {
+    return this.http.get<History>(`${this.baseUrl}/stats/history/${encodeURIComponent(name)}?resolution=${resolution}&limit=${limit}`)
+      .pipe(tap(result => this.log(`fetched history of ${name}: ${result.points.length} points`)),
+      );
+  }
+
   getDNS():Observable<DNS> {
     // DELETE /rest/youtubeChannel/byId/18
-    return this.http.get<DNS>(this.baseUrl + "/config/dns")
-      .pipe(tap(result => this.log(`fetched dns ${JSON.stringify(result)}`)),
+    return this.http.get<DNS>(this.baseUrl + "/config/dns", { observe: 'response' })
+      .pipe(map(response => this.rememberETag(response)),
+        tap(result => this.log(`fetched dns ${JSON.stringify(result)}`)),
       );
   }
   
@@ -134,8 +242,9 @@ export class PFService {
     var body = target;
     var bodyJson = JSON.stringify(body);
     console.log(`After conversion: ${JSON.stringify(bodyJson)}`);
-    return this.http.put<DNS>(this.baseUrl + "/config/dns", bodyJson)
-      .pipe(tap(result => this.log(`Update DNS:${target} result ${JSON.stringify(result)}`)),
+    return this.http.put<DNS>(this.baseUrl + "/config/dns", bodyJson, this.editOptions())
+      .pipe(map(response => this.rememberETag(response)),
+        tap(result => this.log(`Update DNS:${target} result ${JSON.stringify(result)}`)),
       );
   }
 
@@ -148,11 +257,48 @@ export class PFService {
     var body = target;
     var bodyJson = JSON.stringify(body);
     console.log(`After conversion: ${JSON.stringify(bodyJson)}`);
-    return this.http.put<Listeners>(this.baseUrl + "/config/listeners", bodyJson)
-      .pipe(tap(result => this.log(`Update Listeners:${target} result ${JSON.stringify(result)}`)),
+    return this.http.put<Listeners>(this.baseUrl + "/config/listeners", bodyJson, this.editOptions())
+      .pipe(map(response => this.rememberETag(response)),
+        tap(result => this.log(`Update Listeners:${target} result ${JSON.stringify(result)}`)),
       );
   }
 
+  saveListener(name:string, listener:Listener, create:boolean):Observable<Listener> {
+    const url = `${this.baseUrl}/config/listeners/${encodeURIComponent(name)}`;
+    const body = JSON.stringify(listener);
+    const request = create
+      ? this.http.post<Listener>(url, body, this.editOptions())
+      : this.http.put<Listener>(url, body, this.editOptions());
+    return request.pipe(map(response => this.rememberETag(response)),
+      tap(result => this.log(`${create ? "Add" : "Update"} listener ${name} result ${JSON.stringify(result)}`)),
+    );
+  }
+
+  deleteListener(name:string):Observable<SimpleResult> {
+    return this.http.delete<SimpleResult>(`${this.baseUrl}/config/listeners/${encodeURIComponent(name)}`, this.editOptions())
+      .pipe(map(response => this.rememberETag(response)),
+        tap(result => this.log(`Delete listener ${name} result ${JSON.stringify(result)}`)),
+    );
+  }
+
+  saveDNSEntry(key:string, target:string, create:boolean):Observable<string> {
+    const url = `${this.baseUrl}/config/dns/${encodeURIComponent(key)}`;
+    const body = JSON.stringify(target);
+    const request = create
+      ? this.http.post<string>(url, body, this.editOptions())
+      : this.http.put<string>(url, body, this.editOptions());
+    return request.pipe(map(response => this.rememberETag(response)),
+      tap(result => this.log(`${create ? "Add" : "Update"} dns ${key} result ${JSON.stringify(result)}`)),
+    );
+  }
+
+  deleteDNSEntry(key:string):Observable<SimpleResult> {
+    return this.http.delete<SimpleResult>(`${this.baseUrl}/config/dns/${encodeURIComponent(key)}`, this.editOptions())
+      .pipe(map(response => this.rememberETag(response)),
+        tap(result => this.log(`Delete dns ${key} result ${JSON.stringify(result)}`)),
+    );
+  }
+
   restore():Observable<string> {
     return this.http.post<string>(this.baseUrl + "/config/reset", "")
       .pipe(tap(result => this.log(`Reset config result ${result}`)),
@@ -170,11 +316,90 @@ export class PFService {
     );
   }
 
+  startListener(name:string):Observable<SimpleResult> {
+    return this.listenerOperation(name, "start");
+  }
+
+  stopListener(name:string):Observable<SimpleResult> {
+    return this.listenerOperation(name, "stop");
+  }
+
+  restartListener(name:string):Observable<SimpleResult> {
+    return this.listenerOperation(name, "restart");
+  }
+
+  private listenerOperation(name:string, operation:string):Observable<SimpleResult> {
+    return this.http.post<SimpleResult>(`${this.baseUrl}/listeners/${encodeURIComponent(name)}/${operation}`, "")
+      .pipe(tap(result => this.log(`${operation} ${name} result ${JSON.stringify(result)}`)),
+    );
+  }
+
+  whoami():Observable<WhoAmI> {
+    return this.http.get<WhoAmI>(this.baseUrl + "/whoami")
+      .pipe(tap(result => this.log(`logged in as ${result.username} (${result.role})`)),
+    );
+  }
+
+  getTokens():Observable<TokenInfo[]> {
+    return this.http.get<TokenInfo[]>(this.baseUrl + "/tokens")
+      .pipe(tap(result => this.log(`fetched ${result.length} tokens`)),
+    );
+  }
+
+  createToken(name:string, role:Role, expiresInSecs?:number):Observable<CreatedToken> {
+    return this.http.post<CreatedToken>(this.baseUrl + "/tokens", {name, role, expires_in_secs: expiresInSecs})
+      .pipe(tap(result => this.log(`created token ${result.id} (${result.name})`)),
+    );
+  }
+
+  revokeToken(id:string):Observable<SimpleResult> {
+    return this.http.delete<SimpleResult>(`${this.baseUrl}/tokens/${id}`)
+      .pipe(tap(result => this.log(`revoke token ${id} result ${JSON.stringify(result)}`)),
+    );
+  }
+
+  getAudit(offset:number, limit:number):Observable<AuditPage> {
+    return this.http.get<AuditPage>(`${this.baseUrl}/audit?offset=${offset}&limit=${limit}`)
+      .pipe(tap(result => this.log(`fetched ${result.records.length} of ${result.total} audit records`)),
+    );
+  }
+
+  getConfigHistory():Observable<ConfigVersion[]> {
+    return this.http.get<ConfigVersion[]>(this.baseUrl + "/config/history")
+      .pipe(tap(result => this.log(`fetched ${result.length} config versions`)),
+    );
+  }
+
+  getConfigDiff(from:number, to:number):Observable<ConfigDiff> {
+    return this.http.get<ConfigDiff>(`${this.baseUrl}/config/history/diff?from=${from}&to=${to}`)
+      .pipe(tap(result => this.log(`fetched diff ${from}..${to}: ${JSON.stringify(result.changes)}`)),
+    );
+  }
+
+  rollbackConfig(version:number):Observable<ListenerStatuses> {
+    return this.http.post<ListenerStatuses>(`${this.baseUrl}/config/history/${version}/rollback`, "")
+      .pipe(tap(result => this.log(`Rollback to ${version} result ${JSON.stringify(result)}`)),
+    );
+  }
+
   start():Observable<StartResult> {
     return this.http.post<StartResult>(this.baseUrl + "/config/start", "")
       .pipe(tap(result => this.log(`Start result ${JSON.stringify(result)}`)),
     );
   }
+  // Server-Sent Events: stats deltas, connection open/close and health transitions
+  events(): Observable<MessageEvent> {
+    return new Observable<MessageEvent>(subscriber => {
+      const source = new EventSource(this.baseUrl + "/events");
+      const handler = (event: MessageEvent) => subscriber.next(event);
+      for (const kind of ["stats", "connection_opened", "connection_closed", "health"]) {
+        source.addEventListener(kind, handler);
+      }
+      source.onerror = (error) => this.log(`event stream error ${JSON.stringify(error)}`);
+      return () => source.close();
+    });
+  }
+
   private log(message: string) {
     console.log(`PFService: ${message}`);
   }
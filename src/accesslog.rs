@@ -0,0 +1,169 @@
+use std::sync::{Arc, RwLock};
+
+use chrono::Local;
+use lazy_static::lazy_static;
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    fs::OpenOptions,
+    io::AsyncWriteExt,
+    sync::mpsc::{self, UnboundedSender},
+};
+
+use crate::{
+    activetracker::ActiveConnection,
+    config::Config,
+    events::{self, Event},
+};
+
+/// Path of the access log and the channel to the task writing it
+type Sink = Option<(String, UnboundedSender<String>)>;
+
+lazy_static! {
+    // std lock on purpose: records are emitted from `Drop`, which can't await.
+    static ref SINK: Arc<RwLock<Sink>> = Arc::new(RwLock::new(None));
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CloseReason {
+    ClientEof,
+    ServerEof,
+    IdleTimeout,
+    MaxLifetime,
+    Cancelled,
+    Error,
+}
+
+/// One JSON line, written when a connection closes.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccessRecord {
+    pub id: u64,
+    pub listener: String,
+    pub client: String,
+    pub target: Option<String>,
+    pub resolved: Option<String>,
+    pub local: Option<String>,
+    pub start: String,
+    pub end: String,
+    pub duration_ms: i64,
+    pub uploaded_bytes: u64,
+    pub downloaded_bytes: u64,
+    pub close_reason: CloseReason,
+    pub error: Option<String>,
+}
+
+impl AccessRecord {
+    pub fn from(conn: &ActiveConnection, reason: CloseReason, error: Option<String>) -> Self {
+        let end = Local::now();
+        let read = |x: &tokio::sync::RwLock<Option<String>>| match x.try_read() {
+            Ok(inner) => inner.clone(),
+            Err(_) => None,
+        };
+        Self {
+            id: conn.id,
+            listener: conn.listener.clone(),
+            client: conn.client.to_string(),
+            target: read(&conn.target),
+            resolved: read(&conn.resolved),
+            local: read(&conn.local),
+            start: conn.started.to_rfc3339(),
+            end: end.to_rfc3339(),
+            duration_ms: (end - conn.started).num_milliseconds(),
+            uploaded_bytes: conn.uploaded_bytes_count(),
+            downloaded_bytes: conn.downloaded_bytes_count(),
+            close_reason: reason,
+            error,
+        }
+    }
+}
+
+/// Emits the access record for a connection when dropped. If `close` was never
+/// called (the connection task was cancelled), the reason is `Cancelled`.
+pub struct Guard {
+    conn: Arc<ActiveConnection>,
+    reason: CloseReason,
+    error: Option<String>,
+}
+
+impl Guard {
+    pub fn new(conn: Arc<ActiveConnection>) -> Self {
+        Self {
+            conn,
+            reason: CloseReason::Cancelled,
+            error: None,
+        }
+    }
+
+    pub fn close(&mut self, reason: CloseReason, error: Option<String>) {
+        self.reason = reason;
+        self.error = error;
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let mut reason = self.reason;
+        if self.conn.is_killed() {
+            reason = CloseReason::Cancelled;
+        }
+        let record = AccessRecord::from(&self.conn, reason, self.error.take());
+        log(&record);
+        events::publish(Event::ConnectionClosed(record));
+    }
+}
+
+pub async fn init(config: &Config) {
+    let path = config.options.access_log_file.clone().unwrap_or_default();
+    if path.is_empty() {
+        if SINK.write().unwrap().take().is_some() {
+            info!("access log disabled");
+        }
+        return;
+    }
+    if let Some((current, _)) = SINK.read().unwrap().as_ref() {
+        if *current == path {
+            return;
+        }
+    }
+    let file = OpenOptions::new().create(true).append(true).open(&path).await;
+    let mut file = match file {
+        Ok(file) => file,
+        Err(cause) => {
+            error!("unable to open access log `{path}`: {cause}");
+            *SINK.write().unwrap() = None;
+            return;
+        }
+    };
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    let path_clone = path.clone();
+    tokio::spawn(async move {
+        while let Some(mut line) = rx.recv().await {
+            line.push('\n');
+            let result = file.write_all(line.as_bytes()).await;
+            let result = match result {
+                Ok(_) => file.flush().await,
+                Err(cause) => Err(cause),
+            };
+            if let Err(cause) = result {
+                error!("failed to write access log `{path_clone}`: {cause}");
+            }
+        }
+    });
+    info!("access log enabled at `{path}`");
+    *SINK.write().unwrap() = Some((path, tx));
+}
+
+pub fn log(record: &AccessRecord) {
+    let r = SINK.read().unwrap();
+    if let Some((_, tx)) = r.as_ref() {
+        match serde_json::to_string(record) {
+            Ok(line) => {
+                let _ = tx.send(line);
+            }
+            Err(cause) => {
+                error!("failed to serialize access record {}: {cause}", record.id);
+            }
+        }
+    }
+}
@@ -1,31 +1,226 @@
-use std::{sync::Arc, collections::HashMap, net::SocketAddr};
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
+        Arc,
+    },
+};
 
+use chrono::{DateTime, Local, TimeZone};
 use lazy_static::lazy_static;
-use tokio::sync::RwLock;
+use serde::{Deserialize, Serialize};
+use tokio::{sync::RwLock, task::AbortHandle};
 
 lazy_static! {
-    static ref ACTIVE: Arc<RwLock<HashMap<u64, SocketAddr>>> = Arc::new(RwLock::new(HashMap::new()));
+    static ref ACTIVE: Arc<RwLock<HashMap<u64, Arc<ActiveConnection>>>> =
+        Arc::new(RwLock::new(HashMap::new()));
+}
+
+/// Live bookkeeping for a single forwarded connection.
+#[derive(Debug)]
+pub struct ActiveConnection {
+    pub id: u64,
+    pub listener: String,
+    pub client: SocketAddr,
+    pub started: DateTime<Local>,
+    pub target: RwLock<Option<String>>,
+    pub resolved: RwLock<Option<String>>,
+    pub local: RwLock<Option<String>>,
+    pub uploaded_bytes: AtomicU64,
+    pub downloaded_bytes: AtomicU64,
+    last_active_ms: AtomicI64,
+    killed: AtomicBool,
+    handles: RwLock<Vec<AbortHandle>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConnectionSerde {
+    pub id: u64,
+    pub listener: String,
+    pub client: String,
+    pub target: Option<String>,
+    pub resolved: Option<String>,
+    pub local: Option<String>,
+    pub started: String,
+    pub last_active: String,
+    pub uploaded_bytes: u64,
+    pub downloaded_bytes: u64,
+}
+
+/// Optional filters for `list`. Every filter that is set must match.
+#[derive(Clone, Debug, Default)]
+pub struct ConnectionFilter {
+    pub listener: Option<String>,
+    pub client: Option<String>,
+    pub target: Option<String>,
+}
+
+impl ActiveConnection {
+    pub fn new(id: u64, listener: &str, client: SocketAddr) -> Self {
+        let started = Local::now();
+        Self {
+            id,
+            listener: listener.into(),
+            client,
+            started,
+            target: RwLock::new(None),
+            resolved: RwLock::new(None),
+            local: RwLock::new(None),
+            uploaded_bytes: AtomicU64::new(0),
+            downloaded_bytes: AtomicU64::new(0),
+            last_active_ms: AtomicI64::new(started.timestamp_millis()),
+            killed: AtomicBool::new(false),
+            handles: RwLock::new(Vec::new()),
+        }
+    }
+
+    pub async fn set_target(&self, target: &str, resolved: &str) {
+        *self.target.write().await = Some(target.into());
+        *self.resolved.write().await = Some(resolved.into());
+    }
+
+    pub async fn set_local(&self, local: SocketAddr) {
+        *self.local.write().await = Some(local.to_string());
+    }
+
+    pub fn add_uploaded_bytes(&self, count: u64) {
+        self.uploaded_bytes.fetch_add(count, Ordering::SeqCst);
+        self.mark();
+    }
+
+    pub fn add_downloaded_bytes(&self, count: u64) {
+        self.downloaded_bytes.fetch_add(count, Ordering::SeqCst);
+        self.mark();
+    }
+
+    pub fn uploaded_bytes_count(&self) -> u64 {
+        self.uploaded_bytes.load(Ordering::SeqCst)
+    }
+
+    pub fn downloaded_bytes_count(&self) -> u64 {
+        self.downloaded_bytes.load(Ordering::SeqCst)
+    }
+
+    fn mark(&self) {
+        self.last_active_ms
+            .store(Local::now().timestamp_millis(), Ordering::SeqCst);
+    }
+
+    pub fn last_active(&self) -> DateTime<Local> {
+        let ms = self.last_active_ms.load(Ordering::SeqCst);
+        Local.timestamp_millis_opt(ms).single().unwrap_or(self.started)
+    }
+
+    pub fn is_killed(&self) -> bool {
+        self.killed.load(Ordering::SeqCst)
+    }
+
+    /// Registers a task that must be aborted when this connection is killed.
+    /// If the connection was already killed, the task is aborted right away.
+    pub async fn attach(&self, handle: AbortHandle) {
+        let mut w = self.handles.write().await;
+        if self.is_killed() {
+            handle.abort();
+        }
+        w.push(handle);
+    }
+
+    /// Aborts all attached tasks. Tasks attached later are aborted on attach.
+    pub async fn kill(&self) {
+        let w = self.handles.write().await;
+        self.killed.store(true, Ordering::SeqCst);
+        for handle in w.iter() {
+            handle.abort();
+        }
+    }
+
+    pub async fn to_serde(&self) -> ConnectionSerde {
+        ConnectionSerde {
+            id: self.id,
+            listener: self.listener.clone(),
+            client: self.client.to_string(),
+            target: self.target.read().await.clone(),
+            resolved: self.resolved.read().await.clone(),
+            local: self.local.read().await.clone(),
+            started: self.started.to_rfc3339(),
+            last_active: self.last_active().to_rfc3339(),
+            uploaded_bytes: self.uploaded_bytes_count(),
+            downloaded_bytes: self.downloaded_bytes_count(),
+        }
+    }
+
+    async fn matches(&self, filter: &ConnectionFilter) -> bool {
+        if let Some(listener) = &filter.listener {
+            if &self.listener != listener {
+                return false;
+            }
+        }
+        if let Some(client) = &filter.client {
+            if !self.client.to_string().contains(client.as_str()) {
+                return false;
+            }
+        }
+        if let Some(target) = &filter.target {
+            let current = self.target.read().await;
+            let resolved = self.resolved.read().await;
+            let target_match = current.as_ref().is_some_and(|x| x.contains(target.as_str()));
+            let resolved_match = resolved.as_ref().is_some_and(|x| x.contains(target.as_str()));
+            if !target_match && !resolved_match {
+                return false;
+            }
+        }
+        true
+    }
 }
 
 pub async fn reset() {
     ACTIVE.write().await.clear()
 }
 
-pub async fn put(id:u64, addr:SocketAddr) {
+pub async fn put(conn: Arc<ActiveConnection>) {
     let mut w = ACTIVE.write().await;
-    w.insert(id, addr);
+    w.insert(conn.id, conn);
 }
 
-pub async fn remove(id:u64) {
+pub async fn remove(id: u64) {
     let mut w = ACTIVE.write().await;
     w.remove(&id);
 }
 
+pub async fn get(id: u64) -> Option<Arc<ActiveConnection>> {
+    let r = ACTIVE.read().await;
+    r.get(&id).cloned()
+}
+
 pub async fn get_active_list() -> Vec<(u64, SocketAddr)> {
     let mut result = Vec::new();
     let r = ACTIVE.read().await;
-    for (id, addr) in r.iter() {
-        result.push((*id, *addr));
+    for (id, conn) in r.iter() {
+        result.push((*id, conn.client));
     }
-    return result;
-}
\ No newline at end of file
+    result
+}
+
+pub async fn list(filter: &ConnectionFilter) -> Vec<ConnectionSerde> {
+    let snapshot: Vec<Arc<ActiveConnection>> = ACTIVE.read().await.values().cloned().collect();
+    let mut result = Vec::new();
+    for conn in snapshot {
+        if conn.matches(filter).await {
+            result.push(conn.to_serde().await);
+        }
+    }
+    result.sort_by_key(|x| x.id);
+    result
+}
+
+/// Kills the connection with the given id. Returns false if no such connection is active.
+pub async fn kill(id: u64) -> bool {
+    match get(id).await {
+        Some(conn) => {
+            conn.kill().await;
+            true
+        }
+        None => false,
+    }
+}
@@ -1,28 +1,35 @@
 use std::{
-    collections::HashMap, convert::Infallible, error::Error, fmt::Display, io::Cursor, path::PathBuf, sync::Arc
+    collections::HashMap, convert::Infallible, error::Error, fmt::Display, io::Cursor, ops::Deref, path::PathBuf, sync::Arc
 };
 
 use crate::{
-    config::{AdminServerConfig, Config as PFConfig, Listener},
-    manager, activetracker,
+    audit::{self, AuditRecord},
+    auth::{self, Role},
+    config::{self, AdminServerConfig, Config as PFConfig, IncludeFile, Listener, ValidationError, ValidationErrors},
+    interpolation::Interpolated,
+    manager, activetracker, confighistory, events, openapi, statestore, timeseries, tokens, watcher,
 };
 use base64::{engine::general_purpose, Engine as _};
 use lazy_static::lazy_static;
-use log::info;
+use log::{debug, info, warn};
 use regex::Regex;
-use rocket::{config::TlsConfig, Request};
+use rocket::{config::TlsConfig, mtls::{x509, Certificate}, Request};
 use rocket::{
     catch, catchers,
     config::{MutualTls, Shutdown},
-    get,
+    delete, get,
     http::{ContentType, Header, Status},
     post, put,
     request::FromRequest,
-    response::Responder,
+    response::{
+        stream::{Event as SseEvent, EventStream},
+        Responder,
+    },
     routes, Response,
 };
 use serde::{Deserialize, Serialize};
-use tokio::{fs::File, io::AsyncWriteExt, sync::RwLock};
+use sha2::{Digest, Sha256};
+use tokio::sync::{broadcast::error::RecvError, RwLock};
 use include_dir::{include_dir, Dir};
 
 static STATIC: Dir<'_> = include_dir!("static");
@@ -149,6 +156,9 @@ impl Display for AuthError {
 impl Error for AuthError {}
 pub struct Authenticated {
     pub username: String,
+    pub role: Role,
+    /// Address the request came from
+    pub client: Option<String>,
 }
 
 #[rocket::async_trait]
@@ -158,11 +168,41 @@ impl<'r> FromRequest<'r> for Authenticated {
     async fn from_request(
         request: &'r rocket::Request<'_>,
     ) -> rocket::request::Outcome<Self, Self::Error> {
+        let config = CONFIG.read().await.clone();
+        let client = request.client_ip().map(|x| x.to_string());
+        if !config.client_certs.is_empty() {
+            if let rocket::request::Outcome::Success(cert) = request.guard::<Certificate<'_>>().await {
+                if let Some(who) = cert_identity(&config, &cert, client.clone()) {
+                    return rocket::request::Outcome::Success(who);
+                }
+            }
+        }
+        let client_key = client.clone().unwrap_or_default();
+        if let Some(wait) = auth::retry_after(&client_key).await {
+            request.local_cache(|| RetryAfter((wait.as_millis() as u64).div_ceil(1000)));
+            return rocket::request::Outcome::Error((Status::TooManyRequests, AuthError{}));
+        }
         let authorization = request.headers().get_one("authorization");
         if authorization.is_none() {
             return rocket::request::Outcome::Error((Status::Unauthorized, AuthError{}));
         }
         let authorization = authorization.unwrap();
+        if authorization.to_ascii_lowercase().starts_with("bearer ") {
+            return match tokens::verify(authorization[7..].trim()).await {
+                Some(token) => {
+                    auth::succeeded(&client_key).await;
+                    rocket::request::Outcome::Success(Authenticated {
+                        username: format!("token:{}", token.name),
+                        role: token.role,
+                        client,
+                    })
+                }
+                None => {
+                    auth::failed(&client_key, "bearer token").await;
+                    rocket::request::Outcome::Error((Status::Unauthorized, AuthError{}))
+                }
+            };
+        }
         let prefix = "basic";
         if !authorization.to_ascii_lowercase().starts_with(&prefix) {
             return rocket::request::Outcome::Error((Status::Unauthorized, AuthError{}));
@@ -187,27 +227,134 @@ impl<'r> FromRequest<'r> for Authenticated {
         let idx = idx.unwrap();
         let username = &str[0..idx];
         let password = &str[idx + 1..];
-        let ro = CONFIG.read().await;
-        let expected_username = ro.username.clone();
-        let expected_password = ro.password.clone();
-        if expected_password.is_some() || expected_username.is_some() {
-            let expected_username = expected_username.unwrap();
-            let expected_password = expected_password.unwrap();
-            if username == expected_username && password == expected_password {
-                return rocket::request::Outcome::Success(Authenticated {
-                    username: username.into(),
-                });
-            } else {
-                return rocket::request::Outcome::Error((Status::Unauthorized, AuthError{}));
-            }
-        } else {
+        if !auth::requires_login(&config) {
             return rocket::request::Outcome::Success(Authenticated {
                 username: "anonymous".into(),
+                role: Role::Admin,
+                client,
             });
         }
+        if let Some(role) = auth::login(&config, username, password).await {
+            auth::succeeded(&client_key).await;
+            return rocket::request::Outcome::Success(Authenticated {
+                username: username.into(),
+                role,
+                client,
+            });
+        } else {
+            auth::failed(&client_key, username).await;
+            return rocket::request::Outcome::Error((Status::Unauthorized, AuthError{}));
+        }
     }
 }
 
+/// The user a verified client certificate is mapped to in `client_certs`.
+fn cert_identity(config: &AdminServerConfig, cert: &Certificate<'_>, client: Option<String>) -> Option<Authenticated> {
+    let common_names: Vec<&str> = cert.subject().common_names().collect();
+    let mut sans = Vec::new();
+    if let Ok(Some(alt)) = cert.subject_alternative_name() {
+        for name in &alt.value.general_names {
+            match name {
+                x509::GeneralName::RFC822Name(x) | x509::GeneralName::DNSName(x) | x509::GeneralName::URI(x) => sans.push(*x),
+                _ => {}
+            }
+        }
+    }
+    match auth::cert_user(config, &common_names, &sans) {
+        Some(user) => Some(Authenticated {
+            username: user.username.clone(),
+            role: user.role,
+            client,
+        }),
+        None => {
+            warn!("client certificate `{}` is not mapped to a user", cert.subject());
+            None
+        }
+    }
+}
+
+/// Authenticates and checks that the user has at least `role`.
+async fn require_role(
+    request: &rocket::Request<'_>,
+    role: Role,
+) -> rocket::request::Outcome<Authenticated, AuthError> {
+    match request.guard::<Authenticated>().await {
+        rocket::request::Outcome::Success(who) if who.role < role => {
+            warn!("`{}` ({:?}) was denied {} {}", who.username, who.role, request.method(), request.uri());
+            rocket::request::Outcome::Error((Status::Forbidden, AuthError{}))
+        }
+        outcome => outcome,
+    }
+}
+
+/// A user with the operator or admin role.
+pub struct Operator(pub Authenticated);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Operator {
+    type Error = AuthError;
+
+    async fn from_request(
+        request: &'r rocket::Request<'_>,
+    ) -> rocket::request::Outcome<Self, Self::Error> {
+        require_role(request, Role::Operator).await.map(Operator)
+    }
+}
+
+impl Deref for Operator {
+    type Target = Authenticated;
+
+    fn deref(&self) -> &Authenticated {
+        &self.0
+    }
+}
+
+/// A user with the admin role.
+pub struct Admin(pub Authenticated);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Admin {
+    type Error = AuthError;
+
+    async fn from_request(
+        request: &'r rocket::Request<'_>,
+    ) -> rocket::request::Outcome<Self, Self::Error> {
+        require_role(request, Role::Admin).await.map(Admin)
+    }
+}
+
+impl Deref for Admin {
+    type Target = Authenticated;
+
+    fn deref(&self) -> &Authenticated {
+        &self.0
+    }
+}
+
+/// Seconds a client has to wait before logging in again, for the 429 catcher.
+struct RetryAfter(u64);
+
+#[derive(Responder, Debug, Clone)]
+#[response(status = 429)]
+pub struct TooManyAttempts {
+    pub body: String,
+    pub retry_after: Header<'static>,
+}
+
+#[catch(429)]
+async fn status_429(request: &Request<'_>) -> TooManyAttempts {
+    let RetryAfter(secs) = request.local_cache(|| RetryAfter(1));
+    TooManyAttempts {
+        body: format!("Too many failed logins. Try again in {secs}s"),
+        retry_after: Header::new("Retry-After", secs.to_string()),
+    }
+}
+
+#[catch(403)]
+async fn status_403() -> &'static str {
+    "Your role does not allow this"
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ISE {
     pub message: String,
@@ -231,6 +378,100 @@ impl Display for ISE {
 
 impl Error for ISE {}
 
+/// An error response with a status other than 500.
+#[derive(Debug, Clone)]
+pub struct StatusError {
+    pub status: Status,
+    pub message: String,
+}
+
+impl StatusError {
+    pub fn new(status: Status, message: &str) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<ISE> for StatusError {
+    fn from(value: ISE) -> Self {
+        Self {
+            status: Status::InternalServerError,
+            message: value.message,
+        }
+    }
+}
+
+impl<'r> Responder<'r, 'static> for StatusError {
+    fn respond_to(self, _request: &'r rocket::Request<'_>) -> rocket::response::Result<'static> {
+        let message = self.message.clone();
+        Response::build()
+            .status(self.status)
+            .header(ContentType::Plain)
+            .sized_body(message.len(), Cursor::new(message))
+            .ok()
+    }
+}
+
+/// An error response with a JSON body: `{"error": "...", "details": [{"path": "...", "message": "..."}]}`.
+/// `details` lists validation errors, if any.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiError {
+    #[serde(skip)]
+    pub status: Status,
+    pub error: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<ValidationError>,
+}
+
+impl ApiError {
+    pub fn new(status: Status, message: &str) -> Self {
+        Self {
+            status,
+            error: message.into(),
+            details: Vec::new(),
+        }
+    }
+
+    pub fn not_found(what: &str) -> Self {
+        Self::new(Status::NotFound, &format!("{what} not found"))
+    }
+}
+
+impl From<ValidationErrors> for ApiError {
+    fn from(value: ValidationErrors) -> Self {
+        Self {
+            status: Status::UnprocessableEntity,
+            error: "invalid config".into(),
+            details: value.0,
+        }
+    }
+}
+
+impl From<StatusError> for ApiError {
+    fn from(value: StatusError) -> Self {
+        Self::new(value.status, &value.message)
+    }
+}
+
+impl From<ISE> for ApiError {
+    fn from(value: ISE) -> Self {
+        Self::new(Status::InternalServerError, &value.message)
+    }
+}
+
+impl<'r> Responder<'r, 'static> for ApiError {
+    fn respond_to(self, _request: &'r rocket::Request<'_>) -> rocket::response::Result<'static> {
+        let body = serde_json::to_string(&self).unwrap_or_default();
+        Response::build()
+            .status(self.status)
+            .header(ContentType::JSON)
+            .sized_body(body.len(), Cursor::new(body))
+            .ok()
+    }
+}
+
 impl<'r> Responder<'r, 'static> for ISE {
     fn respond_to(self, _request: &'r rocket::Request<'_>) -> rocket::response::Result<'static> {
         let message = self.message.clone();
@@ -281,26 +522,135 @@ async fn index(who: Authenticated, range:RangeHeader) -> Option<PartialContent>
     static_handler(PathBuf::from("index.html"), who, range).await
 }
 
+/// Loads the config file and everything it includes.
+async fn load_config() -> Result<PFConfig, ISE> {
+    convert_error(PFConfig::load_file(&config_file().await).await)
+}
+
+/// Strong ETag of a config, covering the main file and every included file.
+fn etag_of(config: &PFConfig) -> String {
+    let mut hasher = Sha256::new();
+    for (path, content) in &config.sources.files {
+        hasher.update(path.as_bytes());
+        hasher.update(content.as_bytes());
+    }
+    let digest = hasher.finalize();
+    let hex: String = digest.iter().take(16).map(|b| format!("{b:02x}")).collect();
+    format!("\"{hex}\"")
+}
+
+/// A response carrying the ETag of the config file it was made from.
+#[derive(Responder, Debug, Clone)]
+pub struct Tagged {
+    pub body: String,
+    pub etag: Header<'static>,
+}
+
+impl Tagged {
+    fn new(body: String, etag: String) -> Self {
+        Self {
+            body,
+            etag: Header::new("ETag", etag),
+        }
+    }
+}
+
+/// The `If-Match` header, if sent. Edits are only applied when it matches the
+/// ETag of the current config file, so concurrent editors don't overwrite each other.
+pub struct IfMatch(Option<String>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for IfMatch {
+    type Error = Infallible;
+
+    async fn from_request(
+        request: &'r rocket::Request<'_>,
+    ) -> rocket::request::Outcome<Self, Self::Error> {
+        let value = request.headers().get_one("if-match").map(|x| x.to_string());
+        rocket::request::Outcome::Success(IfMatch(value))
+    }
+}
+
+impl IfMatch {
+    fn check(&self, config: &PFConfig) -> Result<(), StatusError> {
+        let expected = match &self.0 {
+            Some(expected) => expected,
+            None => return Ok(()),
+        };
+        let current = etag_of(config);
+        if expected.split(',').any(|x| x.trim() == "*" || x.trim() == current) {
+            return Ok(());
+        }
+        Err(StatusError::new(
+            Status::PreconditionFailed,
+            "config file was changed since it was loaded. reload and try again",
+        ))
+    }
+}
+
+/// Writes a config back to the files it was loaded from and records the new
+/// version. Included files are only rewritten when their part changed.
+/// Returns the ETag of the saved config.
+async fn save_config(conf: &PFConfig, who: &Authenticated, action: &str) -> Result<String, ISE> {
+    let before = confighistory::snapshot(&load_config().await?);
+    let (main, parts) = conf.split();
+    for (path, part) in parts {
+        let content = convert_error(config::to_yaml(&part, &conf.interpolated))?;
+        // compared as written, i.e. with `${...}` references unresolved
+        let unchanged = conf
+            .sources
+            .files
+            .iter()
+            .find(|(x, _)| *x == path)
+            .and_then(|(_, on_disk)| serde_yaml_ng::from_str::<IncludeFile>(on_disk).ok())
+            .is_some_and(|on_disk| serde_yaml_ng::from_str::<IncludeFile>(&content).ok() == Some(on_disk));
+        if unchanged {
+            continue;
+        }
+        convert_error(config::write_file(&path, &content).await)?;
+    }
+    let content = convert_error(config::to_yaml(&main, &conf.interpolated))?;
+    convert_error(config::write_file(&config_file().await, &content).await)?;
+    watcher::acknowledge().await;
+    let saved = load_config().await?;
+    let after = confighistory::snapshot(&saved);
+    audit_config(who, action, &before, &after).await;
+    record_history(&after, &who.username, action).await;
+    Ok(etag_of(&saved))
+}
+
+/// Records a mutating action in the audit log.
+async fn audit(who: &Authenticated, action: &str, changes: Vec<String>, diff: Vec<String>) {
+    audit::record(AuditRecord::new(&who.username, who.client.clone(), action, changes, diff)).await;
+}
+
+/// Records a config change, with the lines of the config that were removed
+/// and added. Unchanged lines are left out.
+async fn audit_config(who: &Authenticated, action: &str, before: &str, after: &str) {
+    let changes = confighistory::summarize_text(before, after);
+    let diff = confighistory::diff_lines(before, after)
+        .into_iter()
+        .filter(|x| !x.starts_with(' '))
+        .collect();
+    audit(who, action, changes, diff).await;
+}
+
 #[get("/apiserver/config/listeners")]
 #[allow(unused_variables)]
-async fn get_listener_config(who: Authenticated) -> Result<String, ISE> {
-    let _ = LOCK.read().await;
-    let conf: PFConfig = PFConfig::load_file(CONFIG_FILE)
-        .await
-        .map_err(|e| ISE::from(e))?;
-    let result = serde_json::to_string(&conf.listeners).map_err(|e| ISE::from(e))?;
-    return Ok(result);
+async fn get_listener_config(who: Operator) -> Result<Tagged, ISE> {
+    let _r = LOCK.read().await;
+    let conf = load_config().await?;
+    let result = convert_error(serde_json::to_string(&conf.listeners))?;
+    Ok(Tagged::new(result, etag_of(&conf)))
 }
 
 #[get("/apiserver/config/dns")]
 #[allow(unused_variables)]
-async fn get_dns_config(who: Authenticated) -> Result<String, ISE> {
-    let _ = LOCK.read().await;
-    let conf: PFConfig = PFConfig::load_file(CONFIG_FILE)
-        .await
-        .map_err(|e| ISE::from(e))?;
-    let result = serde_json::to_string(&conf.dns).map_err(|e| ISE::from(e))?;
-    return Ok(result);
+async fn get_dns_config(who: Operator) -> Result<Tagged, ISE> {
+    let _r = LOCK.read().await;
+    let conf = load_config().await?;
+    let result = convert_error(serde_json::to_string(&conf.dns))?;
+    Ok(Tagged::new(result, etag_of(&conf)))
 }
 
 fn convert_error<T, X>(input: Result<T, X>) -> Result<T, ISE>
@@ -311,57 +661,345 @@ where
 }
 
 #[put("/apiserver/config/dns", data = "<data>")]
-#[allow(unused_variables)]
-async fn put_dns_config(who: Authenticated, data: String) -> Result<String, ISE> {
-    let _ = LOCK.write().await;
-    let map: HashMap<String, String> = convert_error(serde_json::from_str(&data))?;
-    let mut conf: PFConfig = convert_error(PFConfig::load_file(CONFIG_FILE).await)?;
+async fn put_dns_config(who: Admin, if_match: IfMatch, data: String) -> Result<Tagged, ApiError> {
+    let _w = LOCK.write().await;
+    let map: HashMap<String, String> = parse_body(&data)?;
+    let mut conf = load_for_edit(&if_match).await?;
     conf.dns = map;
-    let yamlout = serde_yaml_ng::to_string(&conf).unwrap();
-    let mut file_out = convert_error(File::create("config.yaml").await)?;
-    let _wr = convert_error(file_out.write_all(yamlout.as_bytes()).await)?;
-    Ok(data)
+    let etag = save_edit(&conf, &who, "save dns").await?;
+    Ok(Tagged::new(data, etag))
 }
 
 #[put("/apiserver/config/listeners", data = "<data>")]
-#[allow(unused_variables)]
-async fn put_listener_config(who: Authenticated, data: String) -> Result<String, ISE> {
-    let _ = LOCK.write().await;
-    let map: HashMap<String, Listener> = convert_error(serde_json::from_str(&data))?;
-    let mut conf: PFConfig = convert_error(PFConfig::load_file(CONFIG_FILE).await)?;
+async fn put_listener_config(who: Admin, if_match: IfMatch, data: String) -> Result<Tagged, ApiError> {
+    let _w = LOCK.write().await;
+    let map: HashMap<String, Listener> = parse_body(&data)?;
+    let mut conf = load_for_edit(&if_match).await?;
     conf.listeners = map;
-    let yamlout = serde_yaml_ng::to_string(&conf).unwrap();
-    let mut file_out = convert_error(File::create("config.yaml").await)?;
-    convert_error(file_out.write_all(yamlout.as_bytes()).await)?;
-    Ok(data)
+    let etag = save_edit(&conf, &who, "save listeners").await?;
+    Ok(Tagged::new(data, etag))
+}
+
+/// Parses the JSON body of a per-entry route.
+fn parse_body<T: serde::de::DeserializeOwned>(data: &str) -> Result<T, ApiError> {
+    serde_json::from_str(data).map_err(|e| ApiError::new(Status::UnprocessableEntity, &format!("invalid body: {e}")))
+}
+
+/// Loads the config for an edit, checking `If-Match`.
+async fn load_for_edit(if_match: &IfMatch) -> Result<PFConfig, ApiError> {
+    let conf = load_config().await?;
+    if_match.check(&conf)?;
+    Ok(conf)
+}
+
+/// Validates and saves an edited config. Returns the new ETag.
+async fn save_edit(conf: &PFConfig, who: &Authenticated, action: &str) -> Result<String, ApiError> {
+    conf.check()?;
+    Ok(save_config(conf, who, action).await?)
+}
+
+#[get("/apiserver/config/listeners/<name>")]
+#[allow(unused_variables)]
+async fn get_listener(who: Operator, name: &str) -> Result<Tagged, ApiError> {
+    let _r = LOCK.read().await;
+    let conf = load_config().await?;
+    let listener = conf
+        .listeners
+        .get(name)
+        .ok_or_else(|| ApiError::not_found(&format!("listener `{name}`")))?;
+    let result = convert_error(serde_json::to_string(listener))?;
+    Ok(Tagged::new(result, etag_of(&conf)))
+}
+
+#[post("/apiserver/config/listeners/<name>", data = "<data>")]
+async fn create_listener(
+    who: Admin,
+    if_match: IfMatch,
+    name: &str,
+    data: String,
+) -> Result<(Status, Tagged), ApiError> {
+    let _w = LOCK.write().await;
+    let listener: Listener = parse_body(&data)?;
+    let mut conf = load_for_edit(&if_match).await?;
+    if conf.listeners.contains_key(name) {
+        return Err(ApiError::new(Status::Conflict, &format!("listener `{name}` already exists")));
+    }
+    conf.listeners.insert(name.into(), listener);
+    let etag = save_edit(&conf, &who, &format!("add listener `{name}`")).await?;
+    Ok((Status::Created, Tagged::new(data, etag)))
+}
+
+#[put("/apiserver/config/listeners/<name>", data = "<data>")]
+async fn update_listener(who: Admin, if_match: IfMatch, name: &str, data: String) -> Result<Tagged, ApiError> {
+    let _w = LOCK.write().await;
+    let listener: Listener = parse_body(&data)?;
+    let mut conf = load_for_edit(&if_match).await?;
+    match conf.listeners.get_mut(name) {
+        Some(existing) => *existing = listener,
+        None => return Err(ApiError::not_found(&format!("listener `{name}`"))),
+    }
+    let etag = save_edit(&conf, &who, &format!("update listener `{name}`")).await?;
+    Ok(Tagged::new(data, etag))
+}
+
+#[delete("/apiserver/config/listeners/<name>")]
+async fn delete_listener(who: Admin, if_match: IfMatch, name: &str) -> Result<Tagged, ApiError> {
+    let _w = LOCK.write().await;
+    let mut conf = load_for_edit(&if_match).await?;
+    if conf.listeners.remove(name).is_none() {
+        return Err(ApiError::not_found(&format!("listener `{name}`")));
+    }
+    let etag = save_edit(&conf, &who, &format!("delete listener `{name}`")).await?;
+    let result = convert_error(serde_json::to_string(&SimpleOperationResult::ok(None)))?;
+    Ok(Tagged::new(result, etag))
+}
+
+#[get("/apiserver/config/dns/<key>")]
+#[allow(unused_variables)]
+async fn get_dns_entry(who: Operator, key: &str) -> Result<Tagged, ApiError> {
+    let _r = LOCK.read().await;
+    let conf = load_config().await?;
+    let value = conf.dns.get(key).ok_or_else(|| ApiError::not_found(&format!("dns entry `{key}`")))?;
+    let result = convert_error(serde_json::to_string(value))?;
+    Ok(Tagged::new(result, etag_of(&conf)))
+}
+
+#[post("/apiserver/config/dns/<key>", data = "<data>")]
+async fn create_dns_entry(
+    who: Admin,
+    if_match: IfMatch,
+    key: &str,
+    data: String,
+) -> Result<(Status, Tagged), ApiError> {
+    let _w = LOCK.write().await;
+    let value: String = parse_body(&data)?;
+    let mut conf = load_for_edit(&if_match).await?;
+    if conf.dns.contains_key(key) {
+        return Err(ApiError::new(Status::Conflict, &format!("dns entry `{key}` already exists")));
+    }
+    conf.dns.insert(key.into(), value);
+    let etag = save_edit(&conf, &who, &format!("add dns `{key}`")).await?;
+    Ok((Status::Created, Tagged::new(data, etag)))
+}
+
+#[put("/apiserver/config/dns/<key>", data = "<data>")]
+async fn update_dns_entry(who: Admin, if_match: IfMatch, key: &str, data: String) -> Result<Tagged, ApiError> {
+    let _w = LOCK.write().await;
+    let value: String = parse_body(&data)?;
+    let mut conf = load_for_edit(&if_match).await?;
+    match conf.dns.get_mut(key) {
+        Some(existing) => *existing = value,
+        None => return Err(ApiError::not_found(&format!("dns entry `{key}`"))),
+    }
+    let etag = save_edit(&conf, &who, &format!("update dns `{key}`")).await?;
+    Ok(Tagged::new(data, etag))
+}
+
+#[delete("/apiserver/config/dns/<key>")]
+async fn delete_dns_entry(who: Admin, if_match: IfMatch, key: &str) -> Result<Tagged, ApiError> {
+    let _w = LOCK.write().await;
+    let mut conf = load_for_edit(&if_match).await?;
+    if conf.dns.remove(key).is_none() {
+        return Err(ApiError::not_found(&format!("dns entry `{key}`")));
+    }
+    let etag = save_edit(&conf, &who, &format!("delete dns `{key}`")).await?;
+    let result = convert_error(serde_json::to_string(&SimpleOperationResult::ok(None)))?;
+    Ok(Tagged::new(result, etag))
+}
+
+/// Whether a listener runs, and the connections a stopped instance of it is still draining.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListenerStatus {
+    #[serde(flatten)]
+    pub result: Result<bool, ISE>,
+    pub draining: bool,
+    pub remaining: usize,
 }
 
 #[get("/apiserver/status/listeners")]
 #[allow(unused_variables)]
 async fn get_listener_status(who: Authenticated) -> Result<String, ISE> {
     let result = manager::get_listener_status().await;
+    let mut draining = manager::get_draining().await;
     let mut result_converted = HashMap::new();
     for (key, value) in result {
-        let new_error = value.map_err(|x| ISE::from(x));
-        result_converted.insert(key, new_error);
+        let remaining = draining.remove(&key);
+        let status = ListenerStatus {
+            result: value.map_err(|x| ISE::from(x)),
+            draining: remaining.is_some(),
+            remaining: remaining.unwrap_or(0),
+        };
+        result_converted.insert(key, status);
+    }
+    // removed from the config, but connections are still finishing
+    for (key, remaining) in draining {
+        let status = ListenerStatus {
+            result: Ok(false),
+            draining: true,
+            remaining,
+        };
+        result_converted.insert(key, status);
     }
     let result = convert_error(serde_json::to_string(&result_converted));
     return result;
 }
 
+async fn listener_operation_result(
+    who: &Authenticated,
+    action: &str,
+    name: &str,
+    result: anyhow::Result<bool>,
+) -> Result<String, ISE> {
+    if let Ok(true) = result {
+        audit(who, action, vec![format!("listener `{name}`")], Vec::new()).await;
+    }
+    let result = match result {
+        Ok(true) => SimpleOperationResult::ok(None),
+        Ok(false) => SimpleOperationResult::ok_no_change(None),
+        Err(cause) => SimpleOperationResult::fail(&format!("{cause}")),
+    };
+    convert_error(serde_json::to_string(&result))
+}
+
+#[post("/apiserver/listeners/<name>/start")]
+async fn start_listener(who: Operator, name: &str) -> Result<String, ISE> {
+    let _w = LOCK.write().await;
+    info!("listener {name} start requested by `{}`", who.username);
+    listener_operation_result(&who, "start listener", name, manager::start_listener(name).await).await
+}
+
+#[post("/apiserver/listeners/<name>/stop")]
+async fn stop_listener(who: Operator, name: &str) -> Result<String, ISE> {
+    let _w = LOCK.write().await;
+    info!("listener {name} stop requested by `{}`", who.username);
+    listener_operation_result(&who, "stop listener", name, manager::stop_listener(name).await).await
+}
+
+#[post("/apiserver/listeners/<name>/restart")]
+async fn restart_listener(who: Operator, name: &str) -> Result<String, ISE> {
+    let _w = LOCK.write().await;
+    info!("listener {name} restart requested by `{}`", who.username);
+    listener_operation_result(&who, "restart listener", name, manager::restart_listener(name).await).await
+}
+
+#[get("/apiserver/status/config")]
+#[allow(unused_variables)]
+async fn get_config_status(who: Authenticated) -> Result<String, ISE> {
+    let result = watcher::get_status().await;
+    convert_error(serde_json::to_string(&result))
+}
+
 #[get("/apiserver/stats/listeners")]
 #[allow(unused_variables)]
 async fn get_listener_stats(who: Authenticated) -> Result<String, ISE> {
     let stats = activetracker::get_active_list().await;
-    info!("{} connections active", stats.len());
+    debug!("{} connections active", stats.len());
     for (id, addr) in stats.iter() {
-        info!("active connection {id} from {addr}");
+        debug!("active connection {id} from {addr}");
     }
     let result = manager::get_listener_stats().await;
     let result = convert_error(serde_json::to_string(&result));
     return result;
 }
 
+#[post("/apiserver/stats/reset?<name>")]
+async fn reset_stats(who: Operator, name: Option<&str>) -> Result<String, ISE> {
+    let _w = LOCK.write().await;
+    statestore::reset(name).await;
+    info!("counters of `{}` reset by `{}`", name.unwrap_or("all listeners"), who.username);
+    let changes = vec![format!("counters of `{}` reset", name.unwrap_or("all listeners"))];
+    audit(&who, "reset stats", changes, Vec::new()).await;
+    let result = SimpleOperationResult::ok(None);
+    convert_error(serde_json::to_string(&result))
+}
+
+#[get("/apiserver/stats/history")]
+#[allow(unused_variables)]
+async fn get_history_names(who: Authenticated) -> Result<String, ISE> {
+    let result = timeseries::get_names().await;
+    convert_error(serde_json::to_string(&result))
+}
+
+#[get("/apiserver/stats/history/<name>?<resolution>&<limit>")]
+#[allow(unused_variables)]
+async fn get_history(
+    who: Authenticated,
+    name: &str,
+    resolution: Option<&str>,
+    limit: Option<usize>,
+) -> Result<Option<String>, ApiError> {
+    let resolution = match resolution {
+        Some(value) => match timeseries::Resolution::parse(value) {
+            Some(inner) => inner,
+            None => return Err(ApiError::new(Status::BadRequest, &format!("unknown resolution `{value}`"))),
+        },
+        None => timeseries::Resolution::Second,
+    };
+    let result = timeseries::get_history(name, resolution, limit.unwrap_or(usize::MAX)).await;
+    match result {
+        Some(history) => Ok(Some(convert_error(serde_json::to_string(&history))?)),
+        None => Ok(None),
+    }
+}
+
+#[get("/apiserver/connections?<listener>&<client>&<target>")]
+#[allow(unused_variables)]
+async fn get_connections(
+    who: Authenticated,
+    listener: Option<String>,
+    client: Option<String>,
+    target: Option<String>,
+) -> Result<String, ISE> {
+    let filter = activetracker::ConnectionFilter {
+        listener,
+        client,
+        target,
+    };
+    let result = activetracker::list(&filter).await;
+    convert_error(serde_json::to_string(&result))
+}
+
+#[delete("/apiserver/connections/<id>")]
+async fn kill_connection(who: Operator, id: u64) -> Result<String, ISE> {
+    let conn = match activetracker::get(id).await {
+        Some(conn) => Some(conn.to_serde().await),
+        None => None,
+    };
+    let result = if activetracker::kill(id).await {
+        info!("connection {id} killed by `{}`", who.username);
+        let changes = match conn {
+            Some(conn) => vec![format!("connection {id} of listener `{}` from {} killed", conn.listener, conn.client)],
+            None => vec![format!("connection {id} killed")],
+        };
+        audit(&who, "kill connection", changes, Vec::new()).await;
+        SimpleOperationResult::ok(None)
+    } else {
+        SimpleOperationResult::fail(&format!("connection {id} is not active"))
+    };
+    convert_error(serde_json::to_string(&result))
+}
+
+#[get("/apiserver/events")]
+#[allow(unused_variables)]
+async fn get_events(who: Authenticated, mut shutdown: rocket::Shutdown) -> EventStream![] {
+    let mut rx = events::subscribe();
+    EventStream! {
+        loop {
+            let event = tokio::select! {
+                msg = rx.recv() => match msg {
+                    Ok(event) => event,
+                    Err(RecvError::Closed) => break,
+                    Err(RecvError::Lagged(_)) => continue,
+                },
+                _ = &mut shutdown => break,
+            };
+            if let Ok(data) = serde_json::to_string(&event) {
+                yield SseEvent::data(data).event(event.kind());
+            }
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SimpleOperationResult {
     pub success: bool,
@@ -396,13 +1034,15 @@ impl SimpleOperationResult {
 }
 #[post("/apiserver/config/stop")]
 #[allow(unused_variables)]
-async fn stop(who: Authenticated) -> Result<String, ISE> {
-    let _ = LOCK.write().await;
+async fn stop(who: Operator) -> Result<String, ISE> {
+    let _w = LOCK.write().await;
     let current_status = manager::get_run_status().await;
     let mut result = SimpleOperationResult::ok(None);
     match current_status {
         manager::Status::STARTED => {
             manager::stop().await;
+            let diff = vec!["-status: started".into(), "+status: stopped".into()];
+            audit(&who, "stop", vec!["all listeners stopped".into()], diff).await;
         }
         manager::Status::STOPPED => {
             result = SimpleOperationResult::ok_no_change(None);
@@ -414,13 +1054,13 @@ async fn stop(who: Authenticated) -> Result<String, ISE> {
     return Ok(serde_json::to_string(&result).unwrap());
 }
 #[post("/apiserver/config/start")]
-async fn start(who: Authenticated) -> Result<String, ISE> {
+async fn start(who: Operator) -> Result<String, ISE> {
     let _w = LOCK.write().await;
     let current_status = manager::get_run_status().await;
     match current_status {
         manager::Status::STOPPED => {
             drop(_w);
-            return restart_and_apply_config(who).await;
+            return apply_config(&who, "start").await;
         }
         _ => {
             let result = SimpleOperationResult::ok_no_change(None);
@@ -431,20 +1071,20 @@ async fn start(who: Authenticated) -> Result<String, ISE> {
 
 #[post("/apiserver/config/apply")]
 #[allow(unused_variables)]
-async fn restart_and_apply_config(w: Authenticated) -> Result<String, ISE> {
-    let _ = LOCK.write().await;
+async fn restart_and_apply_config(w: Operator) -> Result<String, ISE> {
+    apply_config(&w, "apply").await
+}
 
-    let conf: PFConfig = convert_error(PFConfig::load_file(CONFIG_FILE).await)?;
-    {
-        let mut last_w = LAST_CONFIG.write().await;
-        *last_w = conf.clone();
-    }
-    info!("stopping manager...");
-    manager::stop().await;
-    info!("manager stopped");
-    info!("starting manager...");
-    let result = convert_error(manager::start(conf).await)?;
-    info!("manager started");
+/// Applies the config file and records what changed in the running config.
+async fn apply_config(who: &Authenticated, action: &str) -> Result<String, ISE> {
+    let before = confighistory::snapshot(&*LAST_CONFIG.read().await);
+    let result = convert_error(reload(&who.username).await)?;
+    let after = confighistory::snapshot(&*LAST_CONFIG.read().await);
+    audit_config(who, action, &before, &after).await;
+    apply_result(result)
+}
+
+fn apply_result(result: HashMap<String, anyhow::Result<bool>>) -> Result<String, ISE> {
     let mut result_converted = HashMap::new();
     for (key, value) in result {
         let new_error = value.map_err(|x| ISE::from(x));
@@ -454,38 +1094,183 @@ async fn restart_and_apply_config(w: Authenticated) -> Result<String, ISE> {
     return result;
 }
 
+/// References to restore when listeners and dns are reset to `last`: those of `last`
+/// for listeners and dns, since their values come from it, and `current` for the rest.
+fn reset_interpolated(current: &[Interpolated], last: &[Interpolated]) -> Vec<Interpolated> {
+    let reset = |x: &&Interpolated| matches!(x.path.first(), Some(Some(key)) if key == "listeners" || key == "dns");
+    let kept = current.iter().filter(|x| !reset(x));
+    kept.chain(last.iter().filter(reset)).cloned().collect()
+}
+
 #[post("/apiserver/config/reset")]
-#[allow(unused_variables)]
-async fn reset_original_config(who: Authenticated) -> Result<String, ISE> {
-    let _ = LOCK.write().await;
+async fn reset_original_config(who: Admin, if_match: IfMatch) -> Result<String, ApiError> {
+    let _w = LOCK.write().await;
     let old = LAST_CONFIG.read().await;
     let old_dns = old.dns.clone();
     let old_listeners = old.listeners.clone();
 
-    let mut conf: PFConfig = convert_error(PFConfig::load_file(CONFIG_FILE).await)?;
+    let mut conf = load_for_edit(&if_match).await?;
     conf.listeners = old_listeners;
     conf.dns = old_dns;
-    let yamlout = serde_yaml_ng::to_string(&conf).unwrap();
-    let mut file_out = convert_error(File::create("config.yaml").await)?;
-    let _wr = convert_error(file_out.write_all(yamlout.as_bytes()).await)?;
+    conf.interpolated = reset_interpolated(&conf.interpolated, &old.interpolated);
+    save_edit(&conf, &who, "reset to last applied").await?;
     let json_result = serde_json::to_string("OK").unwrap();
     Ok(json_result)
 }
 
-static CONFIG_FILE: &str = "config.yaml";
+async fn record_history(content: &str, user: &str, action: &str) {
+    if let Err(cause) = confighistory::record(content, user, action).await {
+        warn!("failed to record config history: {cause}");
+    }
+}
+
+#[get("/apiserver/config/history")]
+#[allow(unused_variables)]
+async fn get_config_history(who: Admin) -> Result<String, ISE> {
+    let result = confighistory::list().await;
+    convert_error(serde_json::to_string(&result))
+}
+
+#[get("/apiserver/config/history/diff?<from>&<to>")]
+#[allow(unused_variables)]
+async fn get_config_diff(who: Admin, from: u64, to: u64) -> Result<Option<String>, ISE> {
+    match convert_error(confighistory::diff(from, to).await)? {
+        Some(result) => Ok(Some(convert_error(serde_json::to_string(&result))?)),
+        None => Ok(None),
+    }
+}
+
+#[get("/apiserver/config/history/<version>")]
+#[allow(unused_variables)]
+async fn get_config_version(who: Admin, version: u64) -> Result<Option<String>, ISE> {
+    convert_error(confighistory::get(version).await)
+}
+
+#[post("/apiserver/config/history/<version>/rollback")]
+async fn rollback_config(who: Admin, if_match: IfMatch, version: u64) -> Result<String, ApiError> {
+    {
+        let _w = LOCK.write().await;
+        let current = load_for_edit(&if_match).await?;
+        let content = convert_error(confighistory::get(version).await)?
+            .ok_or_else(|| ApiError::not_found(&format!("config version {version}")))?;
+        let mut conf = PFConfig::load_string(&content)
+            .map_err(|e| ApiError::new(Status::UnprocessableEntity, &format!("version {version} can't be loaded: {e}")))?;
+        // entries go back to the files they live in now
+        conf.include = current.include.clone();
+        conf.sources = current.sources.clone();
+        conf.check()?;
+        info!("config rolled back to version {version} by `{}`", who.username);
+        save_config(&conf, &who, &format!("rollback to {version}")).await?;
+    }
+    let result = convert_error(reload(&who.username).await)?;
+    Ok(apply_result(result)?)
+}
+
+#[get("/apiserver/tokens")]
+#[allow(unused_variables)]
+async fn get_tokens(who: Admin) -> Result<String, ISE> {
+    convert_error(serde_json::to_string(&tokens::list().await))
+}
+
+#[post("/apiserver/tokens", data = "<data>")]
+async fn create_token(who: Admin, data: String) -> Result<String, StatusError> {
+    let request: tokens::NewToken = serde_json::from_str(&data)
+        .map_err(|e| StatusError::new(Status::BadRequest, &format!("invalid token request: {e}")))?;
+    let created = tokens::create(&request, &who.username)
+        .await
+        .map_err(|e| StatusError::new(Status::BadRequest, &e.to_string()))?;
+    let info = &created.info;
+    let changes = vec![format!("token `{}` ({}) created with role {:?}", info.name, info.id, info.role)];
+    audit(&who, "create token", changes, Vec::new()).await;
+    convert_error(serde_json::to_string(&created)).map_err(StatusError::from)
+}
+
+#[delete("/apiserver/tokens/<id>")]
+async fn revoke_token(who: Admin, id: &str) -> Result<Option<String>, ISE> {
+    if !convert_error(tokens::revoke(id, &who.username).await)? {
+        return Ok(None);
+    }
+    audit(&who, "revoke token", vec![format!("token {id} revoked")], Vec::new()).await;
+    convert_error(serde_json::to_string(&SimpleOperationResult::ok(None))).map(Some)
+}
+
+#[get("/apiserver/audit?<offset>&<limit>")]
+#[allow(unused_variables)]
+async fn get_audit(who: Admin, offset: Option<usize>, limit: Option<usize>) -> Result<String, ISE> {
+    let limit = limit.unwrap_or(100).clamp(1, 1000);
+    let result = convert_error(audit::list(offset.unwrap_or(0), limit).await)?;
+    convert_error(serde_json::to_string(&result))
+}
+
+#[get("/apiserver/openapi.json")]
+#[allow(unused_variables)]
+async fn get_openapi(who: Authenticated) -> (ContentType, String) {
+    (ContentType::JSON, openapi::document().to_string())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WhoAmI {
+    pub username: String,
+    pub role: Role,
+}
+
+#[get("/apiserver/whoami")]
+async fn whoami(who: Authenticated) -> Result<String, ISE> {
+    convert_error(serde_json::to_string(&WhoAmI {
+        username: who.username,
+        role: who.role,
+    }))
+}
 
 lazy_static! {
     static ref CONFIG: Arc<RwLock<AdminServerConfig>> = Arc::new(RwLock::new(Default::default()));
     static ref LAST_CONFIG: Arc<RwLock<PFConfig>> = Arc::new(RwLock::new(Default::default()));
+    static ref CONFIG_FILE: Arc<RwLock<String>> = Arc::new(RwLock::new("config.yaml".into()));
+    static ref SHUTDOWN: Arc<RwLock<Option<rocket::Shutdown>>> = Arc::new(RwLock::new(None));
+}
+
+/// The config file the admin server reads and writes.
+pub async fn config_file() -> String {
+    CONFIG_FILE.read().await.clone()
+}
+
+/// Loads the config file and applies it, the same way `/apiserver/config/apply` does.
+/// `user` is who asked for it, as recorded in the config history.
+pub async fn reload(user: &str) -> Result<HashMap<String, anyhow::Result<bool>>, Box<dyn Error>> {
+    let _w = LOCK.write().await;
+    let conf: PFConfig = PFConfig::load_file(&config_file().await).await?;
+    conf.check()?;
+    {
+        let mut last_w = LAST_CONFIG.write().await;
+        *last_w = conf.clone();
+    }
+    let snapshot = confighistory::snapshot(&conf);
+    info!("applying config to manager...");
+    let result = manager::apply(conf).await?;
+    info!("config applied");
+    record_history(&snapshot, user, "apply").await;
+    Ok(result)
 }
 
+/// Asks a running admin server to shut down gracefully.
+pub async fn shutdown() {
+    if let Some(shutdown) = SHUTDOWN.read().await.as_ref() {
+        info!("shutting down admin server");
+        shutdown.clone().notify();
+    }
+}
 
-pub async fn init(config: &PFConfig) {
+pub async fn init(config: &PFConfig, config_file: &str) {
     info!("initializing adminserver...");
     {
         let mut w = LAST_CONFIG.write().await;
         *w = config.clone();
     }
+    {
+        let mut w = CONFIG_FILE.write().await;
+        *w = config_file.into();
+    }
+    auth::forget().await;
     let admin_config = (&config.admin_server).clone();
     match admin_config {
         Some(what) => {
@@ -513,15 +1298,64 @@ where
         }
     }
 }
-pub async fn run_rocket() -> Result<(), Box<dyn Error>> {
-    let config = CONFIG.read().await;
+/// Every route of the admin server.
+pub fn routes() -> Vec<rocket::Route> {
+    routes![
+        index,
+        get_listener_config,
+        get_dns_config,
+        put_dns_config,
+        put_listener_config,
+        get_listener,
+        create_listener,
+        update_listener,
+        delete_listener,
+        get_dns_entry,
+        create_dns_entry,
+        update_dns_entry,
+        delete_dns_entry,
+        reset_original_config,
+        restart_and_apply_config,
+        start,
+        stop,
+        start_listener,
+        stop_listener,
+        restart_listener,
+        get_listener_stats,
+        get_listener_status,
+        get_config_status,
+        get_connections,
+        kill_connection,
+        get_events,
+        get_history_names,
+        get_history,
+        reset_stats,
+        get_config_history,
+        get_config_diff,
+        get_config_version,
+        rollback_config,
+        whoami,
+        get_openapi,
+        get_tokens,
+        create_token,
+        revoke_token,
+        get_audit,
+        static_handler,
+    ]
+}
+
+/// The admin server for `config`, not launched yet.
+fn build(config: &AdminServerConfig) -> rocket::Rocket<rocket::Build> {
     let mut figment = rocket::Config::figment()
         .merge(("port", choose(&config.bind_port, 48888)))
         .merge(("address", choose(&config.bind_address, "0.0.0.0".into())))
         .merge((
             "log_level",
             choose(&config.rocket_log_level, "normal".into()),
-        ));
+        ))
+        // the client address is the peer address. `X-Real-IP` is set by the
+        // client and would let it dodge the failed login tracking
+        .merge(("ip_header", false));
 
     let enable_tls = choose(&config.tls, false);
     if enable_tls {
@@ -535,30 +1369,177 @@ pub async fn run_rocket() -> Result<(), Box<dyn Error>> {
         }
         figment = figment.merge(("tls", tls_config));
     }
-    let shutdown: Shutdown = Default::default();
+    // signals are handled by `signals`, which drains listeners before shutting Rocket down
+    let shutdown = Shutdown {
+        ctrlc: false,
+        #[cfg(unix)]
+        signals: Default::default(),
+        ..Default::default()
+    };
     figment = figment.merge(("shutdown", shutdown));
+    rocket::custom(figment)
+        .register("/", catchers![status_401, status_403, status_429])
+        .mount("/", routes())
+}
 
-    let _r = rocket::custom(figment)
-        .register("/", catchers![status_401])
-        .mount(
-            "/",
-            routes![
-                index,
-                get_listener_config,
-                get_dns_config,
-                put_dns_config,
-                put_listener_config,
-                reset_original_config,
-                restart_and_apply_config,
-                start,
-                stop,
-                get_listener_stats,
-                get_listener_status,
-                static_handler,
-            ],
-        )
-        .launch()
-        .await?;
+pub async fn run_rocket() -> Result<(), Box<dyn Error>> {
+    let config = CONFIG.read().await.clone();
+    let ignited = build(&config).ignite().await?;
+    {
+        let mut w = SHUTDOWN.write().await;
+        *w = Some(ignited.shutdown());
+    }
+    let _r = ignited.launch().await?;
     info!("Rocket over");
     return Ok(());
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use rocket::local::asynchronous::Client;
+
+    use super::*;
+
+    fn basic(username: &str, password: &str) -> Header<'static> {
+        let encoded = general_purpose::STANDARD.encode(format!("{username}:{password}"));
+        Header::new("Authorization", format!("Basic {encoded}"))
+    }
+
+    async fn client() -> Client {
+        let config = AdminServerConfig {
+            bind_address: Some("127.0.0.1".into()),
+            username: Some("admin".into()),
+            password: Some("right".into()),
+            rocket_log_level: Some("off".into()),
+            ..Default::default()
+        };
+        *CONFIG.write().await = config.clone();
+        Client::untracked(build(&config)).await.unwrap()
+    }
+
+    #[rocket::async_test]
+    async fn failed_logins_are_counted_by_peer_address() {
+        let client = client().await;
+        let remote: SocketAddr = "10.1.2.3:5000".parse().unwrap();
+        for index in 0..3 {
+            let response = client
+                .get("/apiserver/whoami")
+                .remote(remote)
+                .header(Header::new("X-Real-IP", format!("10.9.9.{index}")))
+                .header(basic("admin", "wrong"))
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), Status::Unauthorized);
+        }
+        // a new X-Real-IP does not reset the count, even with the right password
+        let response = client
+            .get("/apiserver/whoami")
+            .remote(remote)
+            .header(Header::new("X-Real-IP", "10.9.9.99"))
+            .header(basic("admin", "right"))
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::TooManyRequests);
+    }
+
+    #[rocket::async_test]
+    async fn unknown_resolution_is_a_bad_request() {
+        let client = client().await;
+        let response = client
+            .get("/apiserver/stats/history/a?resolution=fortnight")
+            .remote("10.1.2.4:5000".parse().unwrap())
+            .header(basic("admin", "right"))
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::BadRequest);
+        let body: serde_json::Value = serde_json::from_str(&response.into_string().await.unwrap()).unwrap();
+        assert_eq!(body["error"], "unknown resolution `fortnight`");
+    }
+
+    #[test]
+    fn listener_status_keeps_the_result_shape() {
+        let running = ListenerStatus {
+            result: Ok(true),
+            draining: true,
+            remaining: 2,
+        };
+        assert_eq!(
+            serde_json::to_value(running).unwrap(),
+            serde_json::json!({ "Ok": true, "draining": true, "remaining": 2 })
+        );
+        let failed = ListenerStatus {
+            result: Err(ISE::from("address in use".to_string())),
+            draining: false,
+            remaining: 0,
+        };
+        assert_eq!(
+            serde_json::to_value(failed).unwrap(),
+            serde_json::json!({ "Err": { "message": "address in use" }, "draining": false, "remaining": 0 })
+        );
+    }
+
+    #[rocket::async_test]
+    async fn invalid_config_edits_are_unprocessable() {
+        let client = client().await;
+        let file = std::env::temp_dir().join(format!("pf-admin-{}.yaml", std::process::id()));
+        std::fs::copy("config.yaml", &file).unwrap();
+        *CONFIG_FILE.write().await = file.to_string_lossy().into_owned();
+        let before = std::fs::read_to_string(&file).unwrap();
+        let put = |path: &'static str, body: &'static str| {
+            client
+                .put(path)
+                .remote("10.1.2.5:5000".parse().unwrap())
+                .header(basic("admin", "right"))
+                .body(body)
+                .dispatch()
+        };
+
+        let response = put("/apiserver/config/listeners", r#"{"a": {"bind": "nowhere", "targets": []}}"#).await;
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        let body: serde_json::Value = serde_json::from_str(&response.into_string().await.unwrap()).unwrap();
+        let paths: Vec<&str> = body["details"].as_array().unwrap().iter().map(|x| x["path"].as_str().unwrap()).collect();
+        assert_eq!(paths, vec!["listeners.a.bind", "listeners.a.targets"]);
+
+        let response = put("/apiserver/config/dns", r#"{"a": "not a host"}"#).await;
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        let response = put("/apiserver/config/dns", "not json").await;
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        assert!(response.into_string().await.unwrap().contains("invalid body"));
+
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), before, "nothing was saved");
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn reset_writes_references_not_what_they_resolved_to() {
+        let content = "listeners:
+  a:
+    bind: 0.0.0.0:8080
+    targets:
+    - ${PF_TEST_RESET_HOST}:80
+options:
+  health_check_timeout_ms: 1
+  log_config_file: ''
+  max_idle_time_ms: 1
+dns: {}
+admin_server:
+  username: admin
+  password: ${PF_TEST_RESET_PASSWORD}
+";
+        std::env::set_var("PF_TEST_RESET_PASSWORD", "secret");
+        std::env::set_var("PF_TEST_RESET_HOST", "old-host");
+        let last = PFConfig::load_string(content).unwrap();
+        // the variable changed after the config was applied
+        std::env::set_var("PF_TEST_RESET_HOST", "new-host");
+        let mut conf = PFConfig::load_string(content).unwrap();
+        conf.listeners = last.listeners.clone();
+        conf.interpolated = reset_interpolated(&conf.interpolated, &last.interpolated);
+
+        let saved = config::to_yaml(&conf, &conf.interpolated).unwrap();
+        assert!(saved.contains("${PF_TEST_RESET_HOST}:80"), "{saved}");
+        assert!(saved.contains("${PF_TEST_RESET_PASSWORD}"), "{saved}");
+        assert!(!saved.contains("old-host") && !saved.contains("secret"), "{saved}");
+    }
+}
@@ -0,0 +1,147 @@
+use std::{path::PathBuf, sync::Arc};
+
+use anyhow::Result;
+use chrono::Local;
+use lazy_static::lazy_static;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    fs::{self, OpenOptions},
+    io::AsyncWriteExt,
+    sync::{Mutex, RwLock},
+};
+
+use crate::{cli, config::Config};
+
+lazy_static! {
+    static ref AUDIT_FILE: Arc<RwLock<PathBuf>> = Arc::new(RwLock::new(PathBuf::from("audit.jsonl")));
+    /// Keeps lines of concurrent actions from interleaving
+    static ref WRITING: Mutex<()> = Mutex::new(());
+}
+
+/// One JSON line per mutating admin action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub at: String,
+    pub user: String,
+    /// Address the request came from
+    pub client: Option<String>,
+    /// e.g. `save listeners`, `stop`, `kill connection`
+    pub action: String,
+    /// Summary of what changed
+    pub changes: Vec<String>,
+    /// Before/after lines. Lines start with `+`, `-` or a space
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub diff: Vec<String>,
+}
+
+impl AuditRecord {
+    pub fn new(user: &str, client: Option<String>, action: &str, changes: Vec<String>, diff: Vec<String>) -> Self {
+        Self {
+            at: Local::now().to_rfc3339(),
+            user: user.into(),
+            client,
+            action: action.into(),
+            changes,
+            diff,
+        }
+    }
+}
+
+/// A page of the audit log, newest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditPage {
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+    pub records: Vec<AuditRecord>,
+}
+
+async fn append(record: &AuditRecord) -> Result<()> {
+    let mut line = serde_json::to_string(record)?;
+    line.push('\n');
+    let _guard = WRITING.lock().await;
+    let path = AUDIT_FILE.read().await.clone();
+    let mut file = OpenOptions::new().create(true).append(true).open(&path).await?;
+    file.write_all(line.as_bytes()).await?;
+    file.sync_data().await?;
+    Ok(())
+}
+
+/// Appends a record. Failing to write is logged, the action itself already happened.
+pub async fn record(record: AuditRecord) {
+    info!(
+        "audit: `{}` from {} did `{}`: {}",
+        record.user,
+        record.client.as_deref().unwrap_or("unknown"),
+        record.action,
+        record.changes.join(", ")
+    );
+    if let Err(cause) = append(&record).await {
+        error!("failed to write audit log: {cause}");
+    }
+}
+
+/// Up to `limit` records, newest first, skipping the `offset` newest.
+pub async fn list(offset: usize, limit: usize) -> Result<AuditPage> {
+    let path = AUDIT_FILE.read().await.clone();
+    let content = match fs::read_to_string(&path).await {
+        Ok(content) => content,
+        Err(cause) if cause.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(cause) => return Err(cause.into()),
+    };
+    let mut records = Vec::new();
+    for line in content.lines().filter(|x| !x.trim().is_empty()) {
+        match serde_json::from_str::<AuditRecord>(line) {
+            Ok(record) => records.push(record),
+            Err(cause) => warn!("ignoring unreadable audit record: {cause}"),
+        }
+    }
+    let total = records.len();
+    let records = records.into_iter().rev().skip(offset).take(limit).collect();
+    Ok(AuditPage {
+        total,
+        offset,
+        limit,
+        records,
+    })
+}
+
+pub async fn init(config: &Config) {
+    let path = cli::state_path(config.options.audit_log_file.as_deref(), "audit.jsonl")
+        .unwrap_or_else(|| PathBuf::from("audit.jsonl"));
+    info!("writing the admin audit log to `{}`", path.display());
+    *AUDIT_FILE.write().await = path;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn list_pages_newest_first() {
+        let path = std::env::temp_dir().join(format!("pf-audit-{}.jsonl", std::process::id()));
+        *AUDIT_FILE.write().await = path.clone();
+        assert_eq!(list(0, 10).await.unwrap().total, 0, "a missing file is an empty log");
+        for index in 0..5 {
+            let changes = vec![format!("change {index}")];
+            record(AuditRecord::new("alice", Some("10.0.0.1".into()), "save listeners", changes, Vec::new())).await;
+        }
+        // a damaged line is skipped, not fatal
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| std::io::Write::write_all(&mut file, b"not json\n"))
+            .unwrap();
+
+        let changes = |page: &AuditPage| page.records.iter().map(|x| x.changes[0].clone()).collect::<Vec<_>>();
+        let page = list(0, 2).await.unwrap();
+        assert_eq!((page.total, page.offset, page.limit), (5, 0, 2));
+        assert_eq!(changes(&page), vec!["change 4", "change 3"]);
+        assert_eq!(changes(&list(2, 2).await.unwrap()), vec!["change 2", "change 1"]);
+        assert_eq!(changes(&list(4, 2).await.unwrap()), vec!["change 0"]);
+        assert!(list(10, 2).await.unwrap().records.is_empty());
+        assert_eq!(page.records[0].client.as_deref(), Some("10.0.0.1"));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
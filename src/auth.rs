@@ -0,0 +1,335 @@
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use lazy_static::lazy_static;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::RwLock;
+
+use crate::config::{AdminServerConfig, ClientCertUser};
+
+/// How long a verified password is remembered. Hashes are slow on purpose,
+/// and the UI sends credentials with every request.
+const VERIFIED_TTL: Duration = Duration::from_secs(300);
+/// Failed logins in a row after which a client is locked out
+const MAX_FAILURES: u32 = 10;
+const LOCKOUT: Duration = Duration::from_secs(900);
+/// Failed logins that are free, for typos
+const FREE_FAILURES: u32 = 2;
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Fingerprint of the last verified password and when it was verified, by username
+type Verified = HashMap<String, ([u8; 32], Instant)>;
+
+lazy_static! {
+    static ref VERIFIED: Arc<RwLock<Verified>> = Arc::new(RwLock::new(HashMap::new()));
+    /// Checked for unknown usernames, so they take as long as known ones
+    static ref DUMMY_HASH: String =
+        hash_password(&format!("{:x}", rand::random::<u128>()), false).unwrap_or_default();
+    /// Failed logins in a row and the time of the last one, by client address
+    static ref FAILURES: Arc<RwLock<HashMap<String, (u32, Instant)>>> = Arc::new(RwLock::new(HashMap::new()));
+}
+
+/// What an admin user may do. Each role includes the ones before it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// stats and status only
+    #[default]
+    Viewer,
+    /// start, stop and apply; read the config
+    Operator,
+    /// edit the config
+    Admin,
+}
+
+/// Whether `value` looks like an argon2 or bcrypt hash.
+pub fn is_hash(value: &str) -> bool {
+    value.starts_with("$argon2") || ["$2a$", "$2b$", "$2y$"].iter().any(|x| value.starts_with(x))
+}
+
+/// Compares in time independent of where the inputs differ.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let a = Sha256::digest(a);
+    let b = Sha256::digest(b);
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Checks a password against an argon2 or bcrypt hash, or plain text.
+pub fn verify_password(password: &str, expected: &str) -> bool {
+    if expected.starts_with("$argon2") {
+        match PasswordHash::new(expected) {
+            Ok(hash) => Argon2::default().verify_password(password.as_bytes(), &hash).is_ok(),
+            Err(cause) => {
+                warn!("unusable argon2 hash in admin config: {cause}");
+                false
+            }
+        }
+    } else if is_hash(expected) {
+        bcrypt::verify(password, expected).unwrap_or(false)
+    } else {
+        constant_time_eq(password.as_bytes(), expected.as_bytes())
+    }
+}
+
+/// Hashes a password for `admin_server.users`. argon2id unless `bcrypt` is set.
+pub fn hash_password(password: &str, use_bcrypt: bool) -> Result<String, String> {
+    if use_bcrypt {
+        return bcrypt::hash(password, bcrypt::DEFAULT_COST).map_err(|e| e.to_string());
+    }
+    let salt = SaltString::encode_b64(&rand::random::<[u8; 16]>()).map_err(|e| e.to_string())?;
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|x| x.to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// Whether the admin server asks for credentials at all.
+pub fn requires_login(config: &AdminServerConfig) -> bool {
+    config.username.is_some() || config.password.is_some() || !config.users.is_empty() || !config.client_certs.is_empty()
+}
+
+/// The user a client certificate is mapped to, from its subject common names
+/// and subject alternative names.
+pub fn cert_user<'a>(config: &'a AdminServerConfig, common_names: &[&str], sans: &[&str]) -> Option<&'a ClientCertUser> {
+    config.client_certs.iter().find(|user| {
+        let cn = match &user.common_name {
+            Some(expected) => common_names.iter().any(|x| x == expected),
+            None => true,
+        };
+        let san = match &user.san {
+            Some(expected) => sans.iter().any(|x| x.eq_ignore_ascii_case(expected)),
+            None => true,
+        };
+        (user.common_name.is_some() || user.san.is_some()) && cn && san
+    })
+}
+
+/// The configured password (or hash) of `username` and their role. The
+/// `username`/`password` account is an admin.
+fn expected_password<'a>(config: &'a AdminServerConfig, username: &str) -> Option<(&'a str, Role)> {
+    if let Some(user) = config.users.iter().find(|x| x.username == username) {
+        return Some((&user.password_hash, user.role));
+    }
+    match (&config.username, &config.password) {
+        (Some(expected_username), Some(password)) if constant_time_eq(expected_username.as_bytes(), username.as_bytes()) => {
+            Some((password, Role::Admin))
+        }
+        (Some(_), None) | (None, Some(_)) => {
+            warn!("admin_server needs both username and password. login with them is disabled");
+            None
+        }
+        _ => None,
+    }
+}
+
+fn fingerprint(password: &str) -> [u8; 32] {
+    Sha256::digest(password.as_bytes()).into()
+}
+
+/// Checks Basic credentials. Returns the user's role when they are valid.
+pub async fn login(config: &AdminServerConfig, username: &str, password: &str) -> Option<Role> {
+    let (expected, role) = match expected_password(config, username) {
+        Some((expected, role)) => (expected.to_string(), role),
+        None => {
+            let password = password.to_string();
+            let _ = tokio::task::spawn_blocking(move || verify_password(&password, &DUMMY_HASH)).await;
+            return None;
+        }
+    };
+    let presented = fingerprint(password);
+    if let Some((verified, at)) = VERIFIED.read().await.get(username) {
+        if at.elapsed() < VERIFIED_TTL && constant_time_eq(verified, &presented) {
+            return Some(role);
+        }
+    }
+    let password = password.to_string();
+    let valid = tokio::task::spawn_blocking(move || verify_password(&password, &expected))
+        .await
+        .unwrap_or(false);
+    if !valid {
+        return None;
+    }
+    VERIFIED.write().await.insert(username.into(), (presented, Instant::now()));
+    Some(role)
+}
+
+/// How long a client has to wait after `count` failed logins in a row: nothing
+/// for the first few, then doubling up to `MAX_BACKOFF`, then `LOCKOUT`.
+fn wait_after(count: u32) -> Duration {
+    if count >= MAX_FAILURES {
+        LOCKOUT
+    } else if count <= FREE_FAILURES {
+        Duration::ZERO
+    } else {
+        Duration::from_secs(1 << (count - FREE_FAILURES - 1)).min(MAX_BACKOFF)
+    }
+}
+
+/// How long `client` must wait before it may try to log in again, if at all.
+pub async fn retry_after(client: &str) -> Option<Duration> {
+    let (count, last) = *FAILURES.read().await.get(client)?;
+    wait_after(count).checked_sub(last.elapsed()).filter(|x| !x.is_zero())
+}
+
+/// Counts a failed login of `client`.
+pub async fn failed(client: &str, username: &str) {
+    let mut w = FAILURES.write().await;
+    // forget clients that have been quiet for long enough
+    w.retain(|_, (_, last)| last.elapsed() < LOCKOUT);
+    let entry = w.entry(client.into()).or_insert((0, Instant::now()));
+    entry.0 += 1;
+    entry.1 = Instant::now();
+    let count = entry.0;
+    if count >= MAX_FAILURES {
+        warn!("failed admin login as `{username}` from {client}, {count} in a row. locked out for {}s", LOCKOUT.as_secs());
+    } else {
+        warn!("failed admin login as `{username}` from {client}, {count} in a row");
+    }
+}
+
+/// Clears the failed logins of `client`.
+pub async fn succeeded(client: &str) {
+    if FAILURES.read().await.contains_key(client) {
+        FAILURES.write().await.remove(client);
+    }
+}
+
+/// Whether other hosts can reach the admin server, and that wasn't explicitly allowed.
+fn reachable_unprotected(config: &AdminServerConfig) -> bool {
+    if config.allow_default_credentials == Some(true) {
+        return false;
+    }
+    let address = config.bind_address.clone().unwrap_or_else(|| "0.0.0.0".into());
+    !address.parse::<IpAddr>().is_ok_and(|x| x.is_loopback())
+}
+
+/// Whether the admin server would let anyone from other hosts in as an admin,
+/// because no login is configured at all.
+pub fn anonymous_admin_exposed(config: &AdminServerConfig) -> bool {
+    reachable_unprotected(config) && !requires_login(config)
+}
+
+/// Whether the admin server would accept admin/admin from other hosts.
+pub fn default_credentials_exposed(config: &AdminServerConfig) -> bool {
+    if !reachable_unprotected(config) {
+        return false;
+    }
+    let legacy = match (&config.username, &config.password) {
+        (Some(username), Some(password)) => username == "admin" && verify_password("admin", password),
+        _ => false,
+    };
+    legacy || config.users.iter().any(|x| x.username == "admin" && verify_password("admin", &x.password_hash))
+}
+
+/// Forgets verified passwords, e.g. after the accounts changed.
+pub async fn forget() {
+    VERIFIED.write().await.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AdminUser;
+
+    #[tokio::test]
+    async fn only_known_users_log_in() {
+        let config = AdminServerConfig {
+            users: vec![AdminUser {
+                username: "alice".into(),
+                password_hash: hash_password("secret", false).unwrap(),
+                role: Role::Operator,
+            }],
+            ..Default::default()
+        };
+        assert_eq!(login(&config, "alice", "secret").await, Some(Role::Operator));
+        assert_eq!(login(&config, "alice", "wrong").await, None);
+        assert_eq!(login(&config, "bob", "secret").await, None);
+    }
+
+    #[test]
+    fn client_certs_match_by_cn_san_or_both() {
+        let user = |username: &str, common_name: Option<&str>, san: Option<&str>| ClientCertUser {
+            common_name: common_name.map(String::from),
+            san: san.map(String::from),
+            username: username.into(),
+            role: Role::Viewer,
+        };
+        let config = AdminServerConfig {
+            client_certs: vec![
+                user("both", Some("ops"), Some("ops@example.com")),
+                user("cn", Some("alice"), None),
+                user("san", None, Some("bob.example.com")),
+                user("neither", None, None),
+            ],
+            ..Default::default()
+        };
+        let cases: &[(&[&str], &[&str], Option<&str>)] = &[
+            (&["alice"], &[], Some("cn")),
+            (&["alice"], &["anything.example.com"], Some("cn")),
+            (&["ALICE"], &[], None),
+            (&["x"], &["bob.example.com"], Some("san")),
+            (&[], &["BOB.Example.com"], Some("san")),
+            (&["ops"], &["ops@example.com"], Some("both")),
+            (&["ops"], &["OPS@EXAMPLE.COM"], Some("both")),
+            (&["ops"], &[], None),
+            (&["other"], &["ops@example.com"], None),
+            (&["x", "alice"], &["a", "bob.example.com"], Some("cn")),
+            (&[], &[], None),
+        ];
+        for (common_names, sans, expected) in cases {
+            let found = cert_user(&config, common_names, sans).map(|x| x.username.as_str());
+            assert_eq!(found, *expected, "cn {common_names:?} san {sans:?}");
+        }
+    }
+
+    #[test]
+    fn no_login_is_only_allowed_on_loopback_or_when_opted_in() {
+        let open = |bind_address: Option<&str>, allow: Option<bool>| AdminServerConfig {
+            bind_address: bind_address.map(String::from),
+            username: None,
+            password: None,
+            allow_default_credentials: allow,
+            ..Default::default()
+        };
+        assert!(anonymous_admin_exposed(&open(None, None)));
+        assert!(anonymous_admin_exposed(&open(Some("0.0.0.0"), Some(false))));
+        assert!(anonymous_admin_exposed(&open(Some("192.168.1.10"), None)));
+        assert!(!anonymous_admin_exposed(&open(Some("127.0.0.1"), None)));
+        assert!(!anonymous_admin_exposed(&open(Some("::1"), None)));
+        assert!(!anonymous_admin_exposed(&open(None, Some(true))));
+        // any way to log in counts
+        let with_users = AdminServerConfig {
+            users: vec![AdminUser {
+                username: "alice".into(),
+                password_hash: "$argon2id$x".into(),
+                role: Role::Admin,
+            }],
+            ..open(None, None)
+        };
+        assert!(!anonymous_admin_exposed(&with_users));
+        assert!(!anonymous_admin_exposed(&AdminServerConfig::default()));
+        assert!(default_credentials_exposed(&AdminServerConfig::default()));
+    }
+
+    #[test]
+    fn backoff_grows_then_locks_out() {
+        assert_eq!(wait_after(0), Duration::ZERO);
+        assert_eq!(wait_after(FREE_FAILURES), Duration::ZERO);
+        assert_eq!(wait_after(FREE_FAILURES + 1), Duration::from_secs(1));
+        assert_eq!(wait_after(FREE_FAILURES + 2), Duration::from_secs(2));
+        assert_eq!(wait_after(FREE_FAILURES + 3), Duration::from_secs(4));
+        assert_eq!(wait_after(MAX_FAILURES - 1), MAX_BACKOFF);
+        assert_eq!(wait_after(MAX_FAILURES), LOCKOUT);
+        assert_eq!(wait_after(u32::MAX), LOCKOUT);
+    }
+}
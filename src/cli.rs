@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use clap::{Parser, Subcommand};
+
+static STATE_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+#[derive(Parser, Debug, Clone)]
+#[command(version, about = "TCP port forwarder with a web admin UI")]
+pub struct Cli {
+    /// Config file to load, and to write back when edited from the admin UI
+    #[arg(short, long, global = true, default_value = "config.yaml")]
+    pub config: String,
+
+    /// log4rs config file. Overrides `options.log_config_file`
+    #[arg(long, global = true)]
+    pub log_config: Option<String>,
+
+    /// Directory for runtime state such as saved counters. Relative state
+    /// paths in the config are resolved against it
+    #[arg(long, global = true)]
+    pub state_dir: Option<PathBuf>,
+
+    /// Do not start the admin server
+    #[arg(long, global = true)]
+    pub no_admin: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Run the port forwarder (default)
+    Run,
+    /// Parse and check the config file, exit non-zero on errors
+    Validate,
+    /// Print an example config to stdout
+    PrintDefaultConfig,
+    /// Read a password from stdin and print its hash for `admin_server.users`
+    HashPassword {
+        /// Use bcrypt instead of argon2id
+        #[arg(long)]
+        bcrypt: bool,
+    },
+}
+
+impl Cli {
+    /// Remembers the state directory for `state_path`. Only the first call has an effect.
+    pub fn init_state_dir(&self) -> std::io::Result<()> {
+        if let Some(dir) = &self.state_dir {
+            std::fs::create_dir_all(dir)?;
+        }
+        let _ = STATE_DIR.set(self.state_dir.clone());
+        Ok(())
+    }
+}
+
+/// The state directory given on the command line, if any.
+pub fn state_dir() -> Option<PathBuf> {
+    STATE_DIR.get().cloned().flatten()
+}
+
+/// Resolves a state file: relative paths are placed in the state directory.
+/// Without a configured file, `default_name` inside the state directory is used,
+/// but only when a state directory was given.
+pub fn state_path(configured: Option<&str>, default_name: &str) -> Option<PathBuf> {
+    let dir = state_dir();
+    match configured.filter(|x| !x.is_empty()) {
+        Some(file) => {
+            let file = Path::new(file);
+            match dir {
+                Some(dir) if file.is_relative() => Some(dir.join(file)),
+                _ => Some(file.to_path_buf()),
+            }
+        }
+        None => dir.map(|dir| dir.join(default_name)),
+    }
+}
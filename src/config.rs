@@ -1,27 +1,71 @@
 use std::collections::{HashSet, HashMap};
 use std::error::Error;
+use std::fmt::Display;
+use std::net::{IpAddr, Ipv6Addr, SocketAddr};
+use std::path::Path;
+use std::time::Duration;
 use tokio::fs;
-use serde::{Serialize, Deserialize};
+use tokio::io::AsyncWriteExt;
+use serde::{de::DeserializeOwned, Serialize, Deserialize};
 use serde_yaml_ng;
 
+use crate::auth;
+use crate::interpolation::{self, Interpolated};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// Files or directories contributing more listeners and DNS entries.
+    /// Relative paths are resolved against the directory of the main config file.
+    /// Directories include every `.yaml` and `.yml` file in them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
     pub listeners: HashMap<String, Listener>,
     pub options:Options,
     pub dns: HashMap<String, String>,
     pub admin_server: Option<AdminServerConfig>,
+    #[serde(skip)]
+    pub sources: Sources,
+    /// Values resolved from `${...}` references, to be put back when saving
+    #[serde(skip)]
+    pub interpolated: Vec<Interpolated>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            include: Vec::new(),
             listeners: HashMap::new(),
             options: Default::default(),
             dns: HashMap::new(),
-            admin_server: None
+            admin_server: None,
+            sources: Default::default(),
+            interpolated: Vec::new(),
         }
     }
 }
+
+/// Where a config loaded by `Config::load_file` came from.
+#[derive(Debug, Clone, Default)]
+pub struct Sources {
+    /// Every file read and its content. The main config file comes first
+    pub files: Vec<(String, String)>,
+    /// Included directories
+    pub dirs: Vec<String>,
+    /// The included file each listener came from. Others are in the main file
+    pub listeners: HashMap<String, String>,
+    /// The included file each DNS entry came from. Others are in the main file
+    pub dns: HashMap<String, String>,
+}
+
+/// A file listed in `include`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IncludeFile {
+    #[serde(default)]
+    pub listeners: HashMap<String, Listener>,
+    #[serde(default)]
+    pub dns: HashMap<String, String>,
+}
 #[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
 pub struct AdminServerConfig {
     pub bind_address: Option<String>,
@@ -34,6 +78,39 @@ pub struct AdminServerConfig {
     pub mutual_tls: Option<bool>,
     pub tls: Option<bool>,
     pub rocket_log_level: Option<String>,
+    /// Admin accounts with hashed passwords, in addition to `username`/`password`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<AdminUser>,
+    /// Users identified by their client certificate. Needs `mutual_tls`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub client_certs: Vec<ClientCertUser>,
+    /// Start even though admin/admin, or anyone when no login is configured, can get in from other hosts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_default_credentials: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
+pub struct AdminUser {
+    pub username: String,
+    /// argon2 or bcrypt hash, as printed by `portforwarder hash-password`
+    pub password_hash: String,
+    #[serde(default)]
+    pub role: auth::Role,
+}
+
+/// Maps a client certificate to a user. Matches when every field that is set
+/// matches the certificate.
+#[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ClientCertUser {
+    /// Common name (CN) of the certificate subject
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub common_name: Option<String>,
+    /// A subject alternative name: e-mail address, DNS name or URI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub san: Option<String>,
+    pub username: String,
+    #[serde(default)]
+    pub role: auth::Role,
 }
 
 impl Default for AdminServerConfig {
@@ -49,19 +126,284 @@ impl Default for AdminServerConfig {
             tls_ca_cert: Some("".into()), 
             mutual_tls: Some(false),
             rocket_log_level: Some("normal".into()),
+            users: Vec::new(),
+            client_certs: Vec::new(),
+            allow_default_credentials: None,
         }
     }
 }
 impl Config {
+    /// Loads the config file and merges the files it includes.
     pub async fn load_file(filename:&str) -> Result<Config, Box<dyn Error>> {
         let content = fs::read_to_string(filename).await?;
     
-        let config:Config = serde_yaml_ng::from_str(&content)?;
+        let mut config = Config::load_string(&content)?;
+        config.sources.files.push((filename.into(), content));
+        config.load_includes(filename).await?;
         return Ok(config);
     }
 
+    async fn include_paths(&mut self, filename: &str) -> Result<Vec<String>, Box<dyn Error>> {
+        let base = Path::new(filename).parent().unwrap_or(Path::new(""));
+        let mut result = Vec::new();
+        for entry in &self.include {
+            let path = base.join(entry);
+            let is_dir = fs::metadata(&path).await.map(|x| x.is_dir()).unwrap_or(false);
+            if !is_dir {
+                result.push(path.to_string_lossy().to_string());
+                continue;
+            }
+            self.sources.dirs.push(path.to_string_lossy().to_string());
+            let mut found = Vec::new();
+            let mut entries = fs::read_dir(&path).await.map_err(|e| format!("include `{entry}`: {e}"))?;
+            while let Some(next) = entries.next_entry().await? {
+                let file = next.path();
+                if matches!(file.extension().and_then(|x| x.to_str()), Some("yaml" | "yml")) {
+                    found.push(file.to_string_lossy().to_string());
+                }
+            }
+            found.sort();
+            result.extend(found);
+        }
+        Ok(result)
+    }
+
+    async fn load_includes(&mut self, filename: &str) -> Result<(), Box<dyn Error>> {
+        let mut duplicates = Vec::new();
+        let paths = self.include_paths(filename).await?;
+        for path in paths {
+            if self.sources.files.iter().any(|(x, _)| *x == path) {
+                continue;
+            }
+            let content = fs::read_to_string(&path).await.map_err(|e| format!("include `{path}`: {e}"))?;
+            let (part, interpolated) =
+                parse::<IncludeFile>(&content).map_err(|e| format!("include `{path}`: {e}"))?;
+            self.interpolated.extend(interpolated);
+            for (name, listener) in part.listeners {
+                if self.listeners.contains_key(&name) {
+                    let other = self.sources.listeners.get(&name).map(|x| x.as_str()).unwrap_or(filename);
+                    duplicates.push(format!("listener `{name}` in `{path}` is already defined in `{other}`"));
+                    continue;
+                }
+                self.sources.listeners.insert(name.clone(), path.clone());
+                self.listeners.insert(name, listener);
+            }
+            for (key, value) in part.dns {
+                if self.dns.contains_key(&key) {
+                    let other = self.sources.dns.get(&key).map(|x| x.as_str()).unwrap_or(filename);
+                    duplicates.push(format!("dns `{key}` in `{path}` is already defined in `{other}`"));
+                    continue;
+                }
+                self.sources.dns.insert(key.clone(), path.clone());
+                self.dns.insert(key, value);
+            }
+            self.sources.files.push((path, content));
+        }
+        if !duplicates.is_empty() {
+            return Err(duplicates.join("; ").into());
+        }
+        Ok(())
+    }
+
+    /// Files and directories whose changes affect this config.
+    pub fn watched_paths(&self) -> Vec<String> {
+        let mut result: Vec<String> = self.sources.files.iter().map(|(x, _)| x.clone()).collect();
+        result.extend(self.sources.dirs.iter().cloned());
+        result
+    }
+
+    /// Splits the config back into the files it was loaded from. Listeners and
+    /// DNS entries from an included file go back there; everything else,
+    /// including new entries, goes to the main file.
+    pub fn split(&self) -> (Config, Vec<(String, IncludeFile)>) {
+        let mut main = self.clone();
+        let mut parts: Vec<(String, IncludeFile)> = self
+            .sources
+            .files
+            .iter()
+            .skip(1)
+            .map(|(path, _)| (path.clone(), IncludeFile::default()))
+            .collect();
+        for (name, path) in &self.sources.listeners {
+            if let Some(part) = parts.iter_mut().find(|(x, _)| x == path) {
+                if let Some(listener) = main.listeners.remove(name) {
+                    part.1.listeners.insert(name.clone(), listener);
+                }
+            }
+        }
+        for (key, path) in &self.sources.dns {
+            if let Some(part) = parts.iter_mut().find(|(x, _)| x == path) {
+                if let Some(value) = main.dns.remove(key) {
+                    part.1.dns.insert(key.clone(), value);
+                }
+            }
+        }
+        (main, parts)
+    }
+
+    /// Example config printed by `print-default-config`
+    pub fn sample() -> Config {
+        let mut listeners = HashMap::new();
+        listeners.insert("ssh".to_string(), Listener {
+            bind: "0.0.0.0:10022".into(),
+            targets: HashSet::from(["localhost:22".to_string()]),
+            enabled: true,
+            ..Default::default()
+        });
+        Config {
+            listeners,
+            options: Options {
+                health_check_timeout_ms: 4000,
+                log_config_file: "log4rs.yaml".into(),
+                max_idle_time_ms: 1000000,
+                ..Default::default()
+            },
+            dns: HashMap::new(),
+            // admin/admin is only safe on loopback. Change the password before binding elsewhere
+            admin_server: Some(AdminServerConfig {
+                bind_address: Some("127.0.0.1".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Checks what parsing can't and reports every problem found, each located
+    /// by its path in the config, e.g. `listeners.foo.targets[1]`.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        let mut names: Vec<&String> = self.listeners.keys().collect();
+        names.sort();
+        let mut binds: Vec<(&String, SocketAddr)> = Vec::new();
+        for name in names {
+            let listener = &self.listeners[name];
+            let path = format!("listeners.{name}");
+            match listener.bind.parse::<SocketAddr>() {
+                // disabled listeners may share a port with the one they stand in for
+                Ok(addr) if listener.enabled => {
+                    let clash = binds.iter().find(|(_, other)| {
+                        other.port() == addr.port()
+                            && (other.ip() == addr.ip() || other.ip().is_unspecified() || addr.ip().is_unspecified())
+                    });
+                    match clash {
+                        Some((other, _)) => errors.push(ValidationError::new(
+                            format!("{path}.bind"),
+                            format!("port {} is already used by listener `{other}`", addr.port()),
+                        )),
+                        None => binds.push((name, addr)),
+                    }
+                }
+                Ok(_) => {}
+                Err(_) => errors.push(ValidationError::new(
+                    format!("{path}.bind"),
+                    format!("invalid socket address `{}`", listener.bind),
+                )),
+            }
+            if listener.targets.is_empty() {
+                errors.push(ValidationError::new(format!("{path}.targets"), "no targets".into()));
+            }
+            check_positive(&mut errors, format!("{path}.connect_timeout_ms"), listener.connect_timeout_ms);
+            check_positive(&mut errors, format!("{path}.buffer_size"), listener.buffer_size.map(|x| x as u64));
+            check_positive(&mut errors, format!("{path}.health_check_timeout_ms"), listener.health_check_timeout_ms);
+            check_positive(&mut errors, format!("{path}.health_check_interval_ms"), listener.health_check_interval_ms);
+            for (index, target) in listener.sorted_targets().iter().enumerate() {
+                if !is_host_port(target) {
+                    errors.push(ValidationError::new(
+                        format!("{path}.targets[{index}]"),
+                        format!("invalid host:port `{target}`"),
+                    ));
+                }
+            }
+        }
+        let options = &self.options;
+        check_positive(&mut errors, "options.connect_timeout_ms".into(), options.connect_timeout_ms);
+        check_positive(&mut errors, "options.buffer_size".into(), options.buffer_size.map(|x| x as u64));
+        check_positive(&mut errors, "options.health_check_interval_ms".into(), options.health_check_interval_ms);
+        let mut dns: Vec<(&String, &String)> = self.dns.iter().collect();
+        dns.sort();
+        for (from, to) in dns {
+            if !is_host_port(from) {
+                errors.push(ValidationError::new(format!("dns.\"{from}\""), "key is not a valid host:port".into()));
+            }
+            if !is_host_port(to) {
+                errors.push(ValidationError::new(format!("dns.\"{from}\""), format!("invalid host:port `{to}`")));
+            }
+        }
+        if let Some(admin) = &self.admin_server {
+            if let Some(address) = &admin.bind_address {
+                if address.parse::<IpAddr>().is_err() {
+                    errors.push(ValidationError::new(
+                        "admin_server.bind_address".into(),
+                        format!("invalid IP address `{address}`"),
+                    ));
+                }
+            }
+            match (&admin.username, &admin.password) {
+                (Some(_), None) => errors.push(ValidationError::new(
+                    "admin_server.password".into(),
+                    "required when username is set".into(),
+                )),
+                (None, Some(_)) => errors.push(ValidationError::new(
+                    "admin_server.username".into(),
+                    "required when password is set".into(),
+                )),
+                _ => {}
+            }
+            let mut usernames: HashSet<&String> = admin.username.iter().collect();
+            for (index, user) in admin.users.iter().enumerate() {
+                let path = format!("admin_server.users[{index}]");
+                if !usernames.insert(&user.username) {
+                    errors.push(ValidationError::new(
+                        format!("{path}.username"),
+                        format!("`{}` is defined more than once", user.username),
+                    ));
+                }
+                if !auth::is_hash(&user.password_hash) {
+                    errors.push(ValidationError::new(
+                        format!("{path}.password_hash"),
+                        "not an argon2 or bcrypt hash".into(),
+                    ));
+                }
+            }
+            for (index, user) in admin.client_certs.iter().enumerate() {
+                let path = format!("admin_server.client_certs[{index}]");
+                if user.common_name.is_none() && user.san.is_none() {
+                    errors.push(ValidationError::new(path.clone(), "needs common_name or san".into()));
+                }
+                if user.username.is_empty() {
+                    errors.push(ValidationError::new(format!("{path}.username"), "must not be empty".into()));
+                }
+            }
+            if !admin.client_certs.is_empty() && (admin.tls != Some(true) || admin.mutual_tls != Some(true)) {
+                errors.push(ValidationError::new(
+                    "admin_server.client_certs".into(),
+                    "requires tls and mutual_tls".into(),
+                ));
+            }
+            if admin.tls == Some(true) {
+                check_file(&mut errors, "admin_server.tls_cert", &admin.tls_cert, "server.pem");
+                check_file(&mut errors, "admin_server.tls_key", &admin.tls_key, "server.key");
+                if admin.mutual_tls == Some(true) {
+                    check_file(&mut errors, "admin_server.tls_ca_cert", &admin.tls_ca_cert, "ca.pem");
+                }
+            }
+        }
+        errors
+    }
+
+    /// `validate` as a `Result`, for callers that refuse invalid configs.
+    pub fn check(&self) -> Result<(), ValidationErrors> {
+        let errors = self.validate();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors(errors))
+        }
+    }
+
     pub fn load_string(content:&str) -> Result<Config, Box<dyn Error>> {
-        let config:Config = serde_yaml_ng::from_str(&content)?;
+        let (mut config, interpolated) = parse::<Config>(content)?;
+        config.interpolated = interpolated;
         return Ok(config);
     }
 
@@ -82,10 +424,201 @@ impl Config {
         }
     }
 }
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Parses YAML, resolving `${ENV}` and `${file:/path}` references in string values.
+fn parse<T: DeserializeOwned>(content: &str) -> Result<(T, Vec<Interpolated>), Box<dyn Error>> {
+    let mut value: serde_yaml_ng::Value = serde_yaml_ng::from_str(content)?;
+    let interpolated = interpolation::resolve(&mut value)?;
+    Ok((serde_yaml_ng::from_value(value)?, interpolated))
+}
+
+/// Serializes a config or a part of it for writing to disk, with the original
+/// `${...}` references in place of the values they resolved to.
+pub fn to_yaml<T: Serialize>(value: &T, interpolated: &[Interpolated]) -> Result<String, serde_yaml_ng::Error> {
+    let mut value = serde_yaml_ng::to_value(value)?;
+    interpolation::restore(&mut value, interpolated);
+    serde_yaml_ng::to_string(&value)
+}
+
+/// Replaces `filename` without ever leaving a partly written file behind: the
+/// content goes to a temporary file that is synced and renamed over the
+/// original. The previous content is kept as `<filename>.bak`.
+pub async fn write_file(filename: &str, content: &str) -> std::io::Result<()> {
+    let path = Path::new(filename);
+    let temp = format!("{filename}.tmp");
+    let mut file = fs::File::create(&temp).await?;
+    file.write_all(content.as_bytes()).await?;
+    file.sync_all().await?;
+    drop(file);
+    if fs::try_exists(path).await.unwrap_or(false) {
+        fs::copy(path, format!("{filename}.bak")).await?;
+    }
+    fs::rename(&temp, path).await?;
+    // make the rename itself durable
+    #[cfg(unix)]
+    {
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        if let Ok(dir) = fs::File::open(dir).await {
+            let _ = dir.sync_all().await;
+        }
+    }
+    Ok(())
+}
+
+/// A problem found by `Config::validate`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationError {
+    pub path: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    fn new(path: String, message: String) -> Self {
+        Self { path, message }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let messages: Vec<String> = self.0.iter().map(|x| x.to_string()).collect();
+        write!(f, "invalid config: {}", messages.join("; "))
+    }
+}
+
+impl Error for ValidationErrors {}
+
+/// `host:port` with a non-zero port. IPv6 hosts must be in brackets.
+fn is_host_port(input: &str) -> bool {
+    let (host, port) = match input.rsplit_once(':') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    if !matches!(port.parse::<u16>(), Ok(port) if port > 0) {
+        return false;
+    }
+    if let Some(inner) = host.strip_prefix('[').and_then(|x| x.strip_suffix(']')) {
+        return inner.parse::<Ipv6Addr>().is_ok();
+    }
+    !host.is_empty() && host.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_')
+}
+
+/// Zero sized buffers and timeouts would make every connection or check fail.
+fn check_positive(errors: &mut Vec<ValidationError>, path: String, value: Option<u64>) {
+    if value == Some(0) {
+        errors.push(ValidationError::new(path, "must be greater than 0".into()));
+    }
+}
+
+/// TLS files fall back to the same defaults the admin server uses.
+fn check_file(errors: &mut Vec<ValidationError>, path: &str, configured: &Option<String>, default: &str) {
+    let file = configured.as_deref().unwrap_or(default);
+    if file.is_empty() {
+        errors.push(ValidationError::new(path.into(), "required when TLS is enabled".into()));
+    } else if !Path::new(file).is_file() {
+        errors.push(ValidationError::new(path.into(), format!("file `{file}` not found")));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Listener {
     pub bind: String,
     pub targets: HashSet<String>,
+    /// Disabled listeners are kept in the config but not started
+    #[serde(default = "default_enabled", skip_serializing_if = "is_enabled")]
+    pub enabled: bool,
+    /// Overrides `options.max_idle_time_ms`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_idle_time_ms: Option<u64>,
+    /// Overrides `options.connect_timeout_ms`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_ms: Option<u64>,
+    /// Overrides `options.max_connection_lifetime_ms`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_connection_lifetime_ms: Option<u64>,
+    /// Overrides `options.buffer_size`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_size: Option<usize>,
+    /// Overrides `options.health_check_timeout_ms` for this listener's targets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_check_timeout_ms: Option<u64>,
+    /// Overrides `options.health_check_interval_ms` for this listener's targets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_check_interval_ms: Option<u64>,
+}
+
+impl Default for Listener {
+    fn default() -> Self {
+        Self {
+            bind: "".into(),
+            targets: HashSet::new(),
+            enabled: true,
+            max_idle_time_ms: None,
+            connect_timeout_ms: None,
+            max_connection_lifetime_ms: None,
+            buffer_size: None,
+            health_check_timeout_ms: None,
+            health_check_interval_ms: None,
+        }
+    }
+}
+
+/// Settings a listener runs with: its own overrides, falling back to `Options`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListenerSettings {
+    pub max_idle_time_ms: u64,
+    pub connect_timeout: Duration,
+    /// Connections are closed once they are this old. Unlimited when `None`
+    pub max_connection_lifetime: Option<Duration>,
+    pub buffer_size: usize,
+    pub health_check_timeout: Duration,
+    pub health_check_interval: Duration,
+}
+
+impl Listener {
+    pub fn settings(&self, options: &Options) -> ListenerSettings {
+        ListenerSettings {
+            max_idle_time_ms: self.max_idle_time_ms.unwrap_or(options.max_idle_time_ms),
+            connect_timeout: Duration::from_millis(self.connect_timeout_ms.unwrap_or(options.connect_timeout_ms())),
+            max_connection_lifetime: self
+                .max_connection_lifetime_ms
+                .or(options.max_connection_lifetime_ms)
+                .filter(|x| *x > 0)
+                .map(Duration::from_millis),
+            buffer_size: self.buffer_size.unwrap_or(options.buffer_size()),
+            health_check_timeout: Duration::from_millis(
+                self.health_check_timeout_ms.unwrap_or(options.health_check_timeout_ms()),
+            ),
+            health_check_interval: Duration::from_millis(
+                self.health_check_interval_ms.unwrap_or(options.health_check_interval_ms()),
+            ),
+        }
+    }
+
+    /// Targets in a stable order, so they can be referred to by index.
+    pub fn sorted_targets(&self) -> Vec<&String> {
+        let mut result: Vec<&String> = self.targets.iter().collect();
+        result.sort();
+        result
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn is_enabled(enabled: &bool) -> bool {
+    *enabled
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,6 +626,91 @@ pub struct Options {
     pub health_check_timeout_ms: u64,
     pub log_config_file: String,
     pub max_idle_time_ms: u64,
+    /// JSON-lines file receiving one record per closed connection. Disabled when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_log_file: Option<String>,
+    /// File where cumulative listener counters are saved, so they survive a process restart.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_file: Option<String>,
+    /// How long connections of a stopped or removed listener may keep running
+    /// before they are cancelled. Defaults to 30 seconds; 0 cancels them right away.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drain_timeout_ms: Option<u64>,
+    /// Apply the config file automatically when it changes on disk
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub watch_config: bool,
+    /// How long the file must stay unchanged before it is applied. Defaults to 500ms
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watch_debounce_ms: Option<u64>,
+    /// How long connecting to a target may take. Defaults to 5 seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_ms: Option<u64>,
+    /// Connections are closed after this long, even when busy. Unlimited when unset or 0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_connection_lifetime_ms: Option<u64>,
+    /// Bytes read at a time in each direction of a connection. Defaults to 4096
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_size: Option<usize>,
+    /// Time between health checks of a target. Defaults to 5 seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_check_interval_ms: Option<u64>,
+    /// Directory keeping every saved or applied config. Relative paths are
+    /// resolved against the state directory. Defaults to `config-history` in
+    /// the state directory, or in the working directory without one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_history_dir: Option<String>,
+    /// How many config versions are kept. Defaults to 100
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_history_limit: Option<usize>,
+    /// File keeping the admin API tokens. Relative paths are resolved against
+    /// the state directory. Defaults to `tokens.json` in the state directory,
+    /// or in the working directory without one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens_file: Option<String>,
+    /// JSON-lines file recording every change made through the admin API.
+    /// Relative paths are resolved against the state directory. Defaults to
+    /// `audit.jsonl` in the state directory, or in the working directory without one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_log_file: Option<String>,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl Options {
+    pub fn drain_timeout(&self) -> Duration {
+        Duration::from_millis(self.drain_timeout_ms.unwrap_or(30000))
+    }
+
+    pub fn watch_debounce_ms(&self) -> u64 {
+        self.watch_debounce_ms.unwrap_or(500)
+    }
+
+    /// `health_check_timeout_ms`, or 5 seconds when it is 0
+    pub fn health_check_timeout_ms(&self) -> u64 {
+        if self.health_check_timeout_ms == 0 {
+            5000
+        } else {
+            self.health_check_timeout_ms
+        }
+    }
+
+    pub fn health_check_interval_ms(&self) -> u64 {
+        self.health_check_interval_ms.unwrap_or(5000)
+    }
+
+    pub fn connect_timeout_ms(&self) -> u64 {
+        self.connect_timeout_ms.unwrap_or(5000)
+    }
+
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size.unwrap_or(4096)
+    }
+
+    pub fn config_history_limit(&self) -> usize {
+        self.config_history_limit.unwrap_or(100).max(1)
+    }
 }
 
 impl Default for Options {
@@ -100,7 +718,175 @@ impl Default for Options {
         Self {
             health_check_timeout_ms: 0,
             log_config_file: "".into(),
-            max_idle_time_ms: 0
+            max_idle_time_ms: 0,
+            access_log_file: None,
+            state_file: None,
+            drain_timeout_ms: None,
+            watch_config: false,
+            watch_debounce_ms: None,
+            connect_timeout_ms: None,
+            max_connection_lifetime_ms: None,
+            buffer_size: None,
+            health_check_interval_ms: None,
+            config_history_dir: None,
+            config_history_limit: None,
+            tokens_file: None,
+            audit_log_file: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listener(bind: &str, targets: &[&str], enabled: bool) -> Listener {
+        Listener {
+            bind: bind.into(),
+            targets: targets.iter().map(|x| x.to_string()).collect(),
+            enabled,
+            ..Default::default()
         }
     }
-}
\ No newline at end of file
+
+    fn config(listeners: Vec<(&str, Listener)>) -> Config {
+        Config {
+            listeners: listeners.into_iter().map(|(name, x)| (name.to_string(), x)).collect(),
+            admin_server: None,
+            ..Default::default()
+        }
+    }
+
+    fn paths(config: &Config) -> Vec<String> {
+        config.validate().into_iter().map(|x| x.path).collect()
+    }
+
+    #[test]
+    fn host_port() {
+        for valid in ["localhost:22", "10.0.0.1:443", "my-host.example_1.com:8080", "[::1]:80", "[fe80::1]:65535"] {
+            assert!(is_host_port(valid), "{valid}");
+        }
+        for invalid in ["localhost", "localhost:0", "localhost:65536", ":80", "host:port", "::1:80", "[::1]", "[nope]:80", "a b:80", "what???:443"] {
+            assert!(!is_host_port(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn valid_config_has_no_errors() {
+        let config = config(vec![
+            ("a", listener("0.0.0.0:8080", &["localhost:80"], true)),
+            ("b", listener("127.0.0.1:8081", &["[::1]:80", "backend:80"], true)),
+        ]);
+        assert_eq!(config.validate(), Vec::new());
+    }
+
+    #[test]
+    fn reports_every_problem_with_its_path() {
+        let mut config = config(vec![
+            ("a", listener("nonsense", &[], true)),
+            ("b", listener("0.0.0.0:8080", &["ok:80", "bad"], true)),
+        ]);
+        config.dns.insert("from".into(), "to:80".into());
+        assert_eq!(
+            paths(&config),
+            vec!["listeners.a.bind", "listeners.a.targets", "listeners.b.targets[0]", "dns.\"from\""]
+        );
+    }
+
+    #[test]
+    fn enabled_listeners_must_not_share_a_port() {
+        let config = config(vec![
+            ("a", listener("0.0.0.0:8080", &["x:80"], true)),
+            ("b", listener("127.0.0.1:8080", &["x:80"], true)),
+            ("c", listener("127.0.0.1:8081", &["x:80"], true)),
+        ]);
+        assert_eq!(paths(&config), vec!["listeners.b.bind"]);
+    }
+
+    #[test]
+    fn disabled_listeners_may_share_a_port_in_any_order() {
+        for (enabled, disabled) in [("a", "b"), ("b", "a")] {
+            let config = config(vec![
+                (enabled, listener("0.0.0.0:8080", &["x:80"], true)),
+                (disabled, listener("0.0.0.0:8080", &["y:80"], false)),
+            ]);
+            assert_eq!(config.validate(), Vec::new(), "{disabled} disabled");
+        }
+    }
+
+    #[test]
+    fn sample_passes_startup_checks() {
+        let sample = Config::sample();
+        assert_eq!(sample.validate(), Vec::new());
+        assert!(!auth::default_credentials_exposed(sample.admin_server.as_ref().unwrap()));
+        assert!(!auth::anonymous_admin_exposed(sample.admin_server.as_ref().unwrap()));
+    }
+
+    #[test]
+    fn split_puts_included_entries_back_into_their_files() {
+        let mut config = config(vec![
+            ("main", listener("0.0.0.0:8080", &["x:80"], true)),
+            ("included", listener("0.0.0.0:8081", &["y:80"], true)),
+        ]);
+        config.dns.insert("a:1".into(), "b:1".into());
+        config.dns.insert("c:1".into(), "d:1".into());
+        config.sources = Sources {
+            files: vec![
+                ("config.yaml".into(), String::new()),
+                ("extra.yaml".into(), String::new()),
+                ("empty.yaml".into(), String::new()),
+            ],
+            listeners: HashMap::from([("included".to_string(), "extra.yaml".to_string())]),
+            dns: HashMap::from([("c:1".to_string(), "extra.yaml".to_string())]),
+            ..Default::default()
+        };
+        let (main, parts) = config.split();
+        assert_eq!(main.listeners.keys().collect::<Vec<_>>(), vec!["main"]);
+        assert_eq!(main.dns, HashMap::from([("a:1".to_string(), "b:1".to_string())]));
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].0, "extra.yaml");
+        assert_eq!(parts[0].1.listeners.keys().collect::<Vec<_>>(), vec!["included"]);
+        assert_eq!(parts[0].1.dns, HashMap::from([("c:1".to_string(), "d:1".to_string())]));
+        assert_eq!(parts[1], ("empty.yaml".to_string(), IncludeFile::default()));
+    }
+
+    #[test]
+    fn saving_keeps_env_and_file_references() {
+        let secret = std::env::temp_dir().join(format!("pf-secret-{}", std::process::id()));
+        std::fs::write(&secret, "s3cret\n").unwrap();
+        std::env::set_var("PF_TEST_TARGET_HOST", "backend");
+        let content = format!(
+            "listeners:
+  a:
+    bind: 0.0.0.0:8080
+    targets:
+    - ${{PF_TEST_TARGET_HOST}}:80
+  b:
+    bind: 0.0.0.0:8081
+    targets:
+    - ${{PF_TEST_TARGET_HOST}}:81
+options:
+  health_check_timeout_ms: 4000
+  log_config_file: ''
+  max_idle_time_ms: 1000
+dns: {{}}
+admin_server:
+  username: admin
+  password: ${{file:{}}}
+",
+            secret.display()
+        );
+        let mut config = Config::load_string(&content).unwrap();
+        std::fs::remove_file(&secret).unwrap();
+        assert_eq!(config.admin_server.as_ref().unwrap().password.as_deref(), Some("s3cret"));
+        assert!(config.listeners["a"].targets.contains("backend:80"));
+        // an edited value is saved as it is now
+        config.listeners.get_mut("b").unwrap().targets = HashSet::from(["other:81".to_string()]);
+
+        let saved: serde_yaml_ng::Value = serde_yaml_ng::from_str(&to_yaml(&config, &config.interpolated).unwrap()).unwrap();
+        assert_eq!(saved["listeners"]["a"]["targets"][0].as_str(), Some("${PF_TEST_TARGET_HOST}:80"));
+        assert_eq!(saved["listeners"]["b"]["targets"][0].as_str(), Some("other:81"));
+        let password = format!("${{file:{}}}", secret.display());
+        assert_eq!(saved["admin_server"]["password"].as_str(), Some(password.as_str()));
+    }
+}
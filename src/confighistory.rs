@@ -0,0 +1,331 @@
+use std::{collections::BTreeSet, path::PathBuf, sync::Arc};
+
+use anyhow::{anyhow, Result};
+use chrono::Local;
+use lazy_static::lazy_static;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::{
+    fs,
+    io::AsyncWriteExt,
+    sync::{Mutex, RwLock},
+};
+
+use crate::{
+    cli,
+    config::{self, Config},
+};
+
+lazy_static! {
+    static ref HISTORY_DIR: Arc<RwLock<PathBuf>> = Arc::new(RwLock::new(PathBuf::from("config-history")));
+    static ref LIMIT: Arc<RwLock<usize>> = Arc::new(RwLock::new(100));
+    /// Serializes `record`, so two saves never get the same number
+    static ref RECORDING: Mutex<()> = Mutex::new(());
+}
+
+/// One saved config. The config itself is kept next to it as `<version>.yaml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Version {
+    pub version: u64,
+    pub at: String,
+    pub user: String,
+    /// What produced this version, e.g. `save listeners`, `apply`, `rollback to 3`
+    pub action: String,
+    /// Summary of what changed since the previous version
+    pub changes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionDiff {
+    pub from: u64,
+    pub to: u64,
+    pub changes: Vec<String>,
+    /// Line diff of the two files. Lines start with `+`, `-` or a space
+    pub lines: Vec<String>,
+}
+
+async fn meta_path(version: u64) -> PathBuf {
+    HISTORY_DIR.read().await.join(format!("{version:06}.json"))
+}
+
+async fn content_path(version: u64) -> PathBuf {
+    HISTORY_DIR.read().await.join(format!("{version:06}.yaml"))
+}
+
+async fn versions() -> Vec<u64> {
+    let dir = HISTORY_DIR.read().await.clone();
+    let mut result = Vec::new();
+    if let Ok(mut entries) = fs::read_dir(&dir).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if let Some(version) = name.strip_suffix(".json").and_then(|x| x.parse::<u64>().ok()) {
+                result.push(version);
+            }
+        }
+    }
+    result.sort();
+    result
+}
+
+/// All versions, newest first.
+pub async fn list() -> Vec<Version> {
+    let mut result = Vec::new();
+    for version in versions().await.into_iter().rev() {
+        match fs::read_to_string(meta_path(version).await).await {
+            Ok(content) => match serde_json::from_str::<Version>(&content) {
+                Ok(meta) => result.push(meta),
+                Err(cause) => warn!("ignoring unreadable config history entry {version}: {cause}"),
+            },
+            Err(cause) => warn!("ignoring unreadable config history entry {version}: {cause}"),
+        }
+    }
+    result
+}
+
+/// The config file as it was saved in `version`, or `None` if there is no such version.
+pub async fn get(version: u64) -> Result<Option<String>> {
+    match fs::read_to_string(content_path(version).await).await {
+        Ok(content) => Ok(Some(content)),
+        Err(cause) if cause.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(cause) => Err(anyhow!("config version {version} can't be read: {cause}")),
+    }
+}
+
+async fn write_file(path: &PathBuf, content: &str) -> Result<()> {
+    let mut file = fs::File::create(path).await?;
+    file.write_all(content.as_bytes()).await?;
+    file.sync_all().await?;
+    Ok(())
+}
+
+/// Saves `content` as a new version, unless it is identical to the latest one.
+/// Returns the new version number.
+pub async fn record(content: &str, user: &str, action: &str) -> Result<Option<u64>> {
+    let _guard = RECORDING.lock().await;
+    let existing = versions().await;
+    let previous = match existing.last() {
+        Some(last) => get(*last).await?,
+        None => None,
+    };
+    if previous.as_deref() == Some(content) {
+        return Ok(None);
+    }
+    let changes = match &previous {
+        Some(previous) => summarize_text(previous, content),
+        None => vec!["initial version".into()],
+    };
+    let version = existing.last().copied().unwrap_or(0) + 1;
+    let meta = Version {
+        version,
+        at: Local::now().to_rfc3339(),
+        user: user.into(),
+        action: action.into(),
+        changes,
+    };
+    fs::create_dir_all(HISTORY_DIR.read().await.as_path()).await?;
+    // content first, so a listed version always has its file
+    write_file(&content_path(version).await, content).await?;
+    write_file(&meta_path(version).await, &serde_json::to_string_pretty(&meta)?).await?;
+    info!("config version {version} saved ({action} by `{user}`): {}", meta.changes.join(", "));
+    let limit = *LIMIT.read().await;
+    if existing.len() + 1 > limit {
+        for old in existing.iter().take(existing.len() + 1 - limit) {
+            let _ = fs::remove_file(meta_path(*old).await).await;
+            let _ = fs::remove_file(content_path(*old).await).await;
+        }
+    }
+    Ok(Some(version))
+}
+
+/// What changed from `from` to `to`, or `None` if either version doesn't exist.
+pub async fn diff(from: u64, to: u64) -> Result<Option<VersionDiff>> {
+    let (old, new) = match (get(from).await?, get(to).await?) {
+        (Some(old), Some(new)) => (old, new),
+        _ => return Ok(None),
+    };
+    Ok(Some(VersionDiff {
+        from,
+        to,
+        changes: summarize_text(&old, &new),
+        lines: diff_lines(&old, &new),
+    }))
+}
+
+pub fn summarize_text(old: &str, new: &str) -> Vec<String> {
+    match (Config::load_string(old), Config::load_string(new)) {
+        (Ok(old), Ok(new)) => {
+            let result = summarize(&old, &new);
+            if result.is_empty() {
+                vec!["formatting only".into()]
+            } else {
+                result
+            }
+        }
+        _ => vec!["config could not be parsed".into()],
+    }
+}
+
+/// Names of the fields that differ between two JSON objects.
+fn changed_fields(old: &Value, new: &Value) -> Vec<String> {
+    let mut keys = BTreeSet::new();
+    if let (Some(old), Some(new)) = (old.as_object(), new.as_object()) {
+        keys.extend(old.keys().cloned());
+        keys.extend(new.keys().cloned());
+    }
+    keys.into_iter().filter(|k| old.get(k) != new.get(k)).collect()
+}
+
+/// Human readable list of what changed from `old` to `new`.
+pub fn summarize(old: &Config, new: &Config) -> Vec<String> {
+    let mut result = Vec::new();
+    let names: BTreeSet<&String> = old.listeners.keys().chain(new.listeners.keys()).collect();
+    for name in names {
+        match (old.listeners.get(name), new.listeners.get(name)) {
+            (None, Some(_)) => result.push(format!("listener `{name}` added")),
+            (Some(_), None) => result.push(format!("listener `{name}` removed")),
+            (Some(a), Some(b)) if a != b => {
+                let fields = changed_fields(
+                    &serde_json::to_value(a).unwrap_or_default(),
+                    &serde_json::to_value(b).unwrap_or_default(),
+                );
+                result.push(format!("listener `{name}` changed: {}", fields.join(", ")));
+            }
+            _ => {}
+        }
+    }
+    let keys: BTreeSet<&String> = old.dns.keys().chain(new.dns.keys()).collect();
+    for key in keys {
+        match (old.dns.get(key), new.dns.get(key)) {
+            (None, Some(_)) => result.push(format!("dns `{key}` added")),
+            (Some(_), None) => result.push(format!("dns `{key}` removed")),
+            (Some(a), Some(b)) if a != b => result.push(format!("dns `{key}` changed")),
+            _ => {}
+        }
+    }
+    let options = changed_fields(
+        &serde_json::to_value(&old.options).unwrap_or_default(),
+        &serde_json::to_value(&new.options).unwrap_or_default(),
+    );
+    if !options.is_empty() {
+        result.push(format!("options changed: {}", options.join(", ")));
+    }
+    if old.admin_server != new.admin_server {
+        result.push("admin_server changed".into());
+    }
+    result
+}
+
+/// Line diff based on the longest common subsequence. Configs are small enough
+/// for the quadratic table.
+pub fn diff_lines(old: &str, new: &str) -> Vec<String> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let mut table = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            table[i][j] = if a[i] == b[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            result.push(format!(" {}", a[i]));
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            result.push(format!("-{}", a[i]));
+            i += 1;
+        } else {
+            result.push(format!("+{}", b[j]));
+            j += 1;
+        }
+    }
+    result.extend(a[i..].iter().map(|x| format!("-{x}")));
+    result.extend(b[j..].iter().map(|x| format!("+{x}")));
+    result
+}
+
+/// Text kept for a config: the main file as it is, or the merged config when
+/// it includes other files, so that one version covers all of them.
+pub fn snapshot(config: &Config) -> String {
+    if let [(_, content)] = config.sources.files.as_slice() {
+        return content.clone();
+    }
+    let mut merged = config.clone();
+    merged.include.clear();
+    config::to_yaml(&merged, &config.interpolated).unwrap_or_default()
+}
+
+/// Sets up the history directory and records the config the process started with.
+pub async fn init(config: &Config) {
+    let dir = cli::state_path(config.options.config_history_dir.as_deref(), "config-history")
+        .unwrap_or_else(|| PathBuf::from("config-history"));
+    info!("keeping config history in `{}`", dir.display());
+    *HISTORY_DIR.write().await = dir;
+    *LIMIT.write().await = config.options.config_history_limit();
+    if let Err(cause) = record(&snapshot(config), "system", "startup").await {
+        warn!("failed to record config history: {cause}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_marks_added_removed_and_kept_lines() {
+        assert_eq!(diff_lines("a\nb\nc\n", "a\nc\nd\n"), vec![" a", "-b", " c", "+d"]);
+        assert_eq!(diff_lines("same\n", "same\n"), vec![" same"]);
+        assert_eq!(diff_lines("", "new\n"), vec!["+new"]);
+        assert_eq!(diff_lines("old\n", ""), vec!["-old"]);
+    }
+
+    #[test]
+    fn diff_keeps_the_longest_common_part() {
+        let old = "listeners:\n  a:\n    bind: 0.0.0.0:1\n  b:\n    bind: 0.0.0.0:2\n";
+        let new = "listeners:\n  b:\n    bind: 0.0.0.0:2\n  c:\n    bind: 0.0.0.0:3\n";
+        let diff = diff_lines(old, new);
+        assert_eq!(diff.iter().filter(|x| x.starts_with(' ')).count(), 3);
+        assert_eq!(diff.iter().filter(|x| x.starts_with('-')).count(), 2);
+        assert_eq!(diff.iter().filter(|x| x.starts_with('+')).count(), 2);
+    }
+
+    #[tokio::test]
+    async fn record_diff_and_roll_back() {
+        let dir = std::env::temp_dir().join(format!("pf-history-{}", std::process::id()));
+        *HISTORY_DIR.write().await = dir.clone();
+        let first = "listeners: {}\noptions:\n  health_check_timeout_ms: 1\n  log_config_file: ''\n  max_idle_time_ms: 1\ndns: {}\n";
+        let second = first.replace("dns: {}", "dns:\n  a:1: b:1");
+
+        assert_eq!(record(first, "alice", "startup").await.unwrap(), Some(1));
+        assert_eq!(record(first, "alice", "apply").await.unwrap(), None, "unchanged content is not recorded");
+        assert_eq!(record(&second, "bob", "save dns").await.unwrap(), Some(2));
+        let listed = list().await;
+        assert_eq!(listed.iter().map(|x| x.version).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(listed[0].changes, vec!["dns `a:1` added"]);
+
+        let changed = diff(1, 2).await.unwrap().unwrap();
+        assert_eq!(changed.changes, vec!["dns `a:1` added"]);
+        assert_eq!(changed.lines.iter().filter(|x| !x.starts_with(' ')).collect::<Vec<_>>(), vec!["-dns: {}", "+dns:", "+  a:1: b:1"]);
+
+        // rolling back saves the old content as a new version
+        let old = get(1).await.unwrap().unwrap();
+        assert_eq!(old, first);
+        assert_eq!(record(&old, "bob", "rollback to 1").await.unwrap(), Some(3));
+        assert_eq!(diff(2, 3).await.unwrap().unwrap().changes, vec!["dns `a:1` removed"]);
+        assert_eq!(get(3).await.unwrap().as_deref(), Some(first));
+
+        assert_eq!(get(99).await.unwrap(), None);
+        assert!(diff(1, 99).await.unwrap().is_none());
+        // anything but a missing file is an error
+        std::fs::create_dir(dir.join("000099.yaml")).unwrap();
+        assert!(get(99).await.is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
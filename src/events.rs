@@ -0,0 +1,149 @@
+use std::{collections::HashMap, time::Duration};
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::broadcast::{self, Receiver, Sender},
+    time::sleep,
+};
+
+use crate::{accesslog::AccessRecord, activetracker::ConnectionSerde, listener_stats::StatsSerde, manager};
+
+lazy_static! {
+    static ref BUS: Sender<Event> = broadcast::channel(1024).0;
+}
+
+/// Stats of a listener that changed since the previous tick, with the increments.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StatsDelta {
+    #[serde(flatten)]
+    pub stats: StatsSerde,
+    pub total_delta: usize,
+    pub downloaded_bytes_delta: usize,
+    pub uploaded_bytes_delta: usize,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    Stats { listeners: HashMap<String, StatsDelta> },
+    ConnectionOpened(ConnectionSerde),
+    ConnectionClosed(AccessRecord),
+    Health { host: String, up: bool, at: String },
+}
+
+impl Event {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Event::Stats { .. } => "stats",
+            Event::ConnectionOpened(_) => "connection_opened",
+            Event::ConnectionClosed(_) => "connection_closed",
+            Event::Health { .. } => "health",
+        }
+    }
+}
+
+pub fn subscribe() -> Receiver<Event> {
+    BUS.subscribe()
+}
+
+/// Publishes to all current subscribers. Events are dropped when nobody listens.
+pub fn publish(event: Event) {
+    if BUS.receiver_count() > 0 {
+        let _ = BUS.send(event);
+    }
+}
+
+pub fn has_subscribers() -> bool {
+    BUS.receiver_count() > 0
+}
+
+/// Listeners whose stats changed from `previous` to `current`, with the increments.
+/// Listeners seen for the first time count from zero.
+fn deltas(previous: &HashMap<String, StatsSerde>, current: &HashMap<String, StatsSerde>) -> HashMap<String, StatsDelta> {
+    let mut changed = HashMap::new();
+    for (name, stats) in current.iter() {
+        let delta = match previous.get(name) {
+            Some(old) => StatsDelta {
+                stats: stats.clone(),
+                total_delta: stats.total.saturating_sub(old.total),
+                downloaded_bytes_delta: stats.downloaded_bytes.saturating_sub(old.downloaded_bytes),
+                uploaded_bytes_delta: stats.uploaded_bytes.saturating_sub(old.uploaded_bytes),
+            },
+            None => StatsDelta {
+                stats: stats.clone(),
+                total_delta: stats.total,
+                downloaded_bytes_delta: stats.downloaded_bytes,
+                uploaded_bytes_delta: stats.uploaded_bytes,
+            },
+        };
+        let unchanged = previous.get(name).is_some_and(|old| old.active == stats.active)
+            && delta.total_delta == 0
+            && delta.downloaded_bytes_delta == 0
+            && delta.uploaded_bytes_delta == 0;
+        if !unchanged {
+            changed.insert(name.clone(), delta);
+        }
+    }
+    changed
+}
+
+/// Starts the background task that turns listener stats into `Stats` deltas once a second.
+pub fn start() {
+    tokio::spawn(async move {
+        let mut previous = HashMap::<String, StatsSerde>::new();
+        loop {
+            sleep(Duration::from_millis(1000)).await;
+            let current = manager::get_listener_stats().await;
+            if has_subscribers() {
+                let changed = deltas(&previous, &current);
+                if !changed.is_empty() {
+                    publish(Event::Stats { listeners: changed });
+                }
+            }
+            previous = current;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(name: &str, total: usize, active: usize, downloaded: usize, uploaded: usize) -> (String, StatsSerde) {
+        let stats = StatsSerde {
+            name: name.into(),
+            total,
+            active,
+            downloaded_bytes: downloaded,
+            uploaded_bytes: uploaded,
+        };
+        (name.to_string(), stats)
+    }
+
+    #[test]
+    fn deltas_count_increments_since_the_previous_tick() {
+        let previous = HashMap::from([stats("a", 10, 2, 1000, 100), stats("b", 5, 0, 50, 5)]);
+        let current = HashMap::from([stats("a", 13, 2, 1500, 100), stats("b", 5, 0, 50, 5), stats("c", 1, 1, 7, 3)]);
+        let changed = deltas(&previous, &current);
+        assert_eq!(changed.len(), 2, "unchanged `b` is left out");
+        let a = &changed["a"];
+        assert_eq!((a.total_delta, a.downloaded_bytes_delta, a.uploaded_bytes_delta), (3, 500, 0));
+        assert_eq!(a.stats.total, 13);
+        let c = &changed["c"];
+        assert_eq!((c.total_delta, c.downloaded_bytes_delta, c.uploaded_bytes_delta), (1, 7, 3));
+    }
+
+    #[test]
+    fn deltas_report_active_changes_and_survive_resets() {
+        let previous = HashMap::from([stats("a", 10, 2, 1000, 100)]);
+        // only the number of active connections changed
+        let changed = deltas(&previous, &HashMap::from([stats("a", 10, 1, 1000, 100)]));
+        assert_eq!(changed["a"].total_delta, 0);
+        assert_eq!(changed["a"].stats.active, 1);
+        // counters were reset
+        let changed = deltas(&previous, &HashMap::from([stats("a", 0, 0, 0, 0)]));
+        assert_eq!(changed["a"].total_delta, 0);
+        assert_eq!(changed["a"].downloaded_bytes_delta, 0);
+    }
+}
@@ -1,53 +1,83 @@
 use crate::controller::Controller;
+use crate::events::{self, Event};
 use crate::{config::Config, resolver};
 use chrono::{DateTime, Local};
 use lazy_static::lazy_static;
 use log::{info, warn};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::error::Error;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::net::TcpStream;
 use tokio::sync::{RwLock, mpsc};
 
 lazy_static! {
     static ref STATUS: Arc<RwLock<HashMap<String, (bool, DateTime<Local>)>>> =
         Arc::new(RwLock::new(HashMap::new()));
-    static ref HOSTS: Arc<RwLock<HashSet<String>>> = Arc::new(RwLock::new(HashSet::new()));
+    static ref HOSTS: Arc<RwLock<HashMap<String, HostCheck>>> = Arc::new(RwLock::new(HashMap::new()));
+}
+
+/// How often the checker wakes up to look for targets that are due
+const TICK: Duration = Duration::from_millis(500);
+
+/// How a target is checked. When listeners sharing a target disagree, the
+/// shortest interval and the longest timeout win.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct HostCheck {
+    timeout: Duration,
+    interval: Duration,
+}
+
+impl HostCheck {
+    fn merge(&mut self, other: &HostCheck) {
+        self.timeout = self.timeout.max(other.timeout);
+        self.interval = self.interval.min(other.interval);
+    }
 }
 
 pub async fn init(config: &Config) {
     info!("initializing config");
-    let mut hosts = HashSet::<String>::new();
+    let mut hosts = HashMap::<String, HostCheck>::new();
     for (name, listener) in &config.listeners {
+        let settings = listener.settings(&config.options);
+        let check = HostCheck {
+            timeout: settings.health_check_timeout,
+            interval: settings.health_check_interval,
+        };
         let targets = &listener.targets;
         for target in targets {
             info!("register host `{target}` under `{name}`");
-            hosts.insert(target.clone());
+            match hosts.get_mut(target) {
+                Some(existing) => existing.merge(&check),
+                None => {
+                    hosts.insert(target.clone(), check);
+                }
+            }
         }
     }
     init_inner(hosts).await;
     info!("initialization completed");
 }
-async fn init_inner(hosts: HashSet<String>) {
+async fn init_inner(hosts: HashMap<String, HostCheck>) {
     {
-        info!("clearing all host status");
+        info!("clearing status of unregistered hosts");
         let mut statusw = STATUS.write().await;
-        statusw.clear();
+        statusw.retain(|host, _| hosts.contains_key(host));
         info!("host status cleared");
     }
     let mut w = HOSTS.write().await;
     info!("clearing host registry");
     w.clear();
-    for next in &hosts {
-        info!("registering host {next}");
-        w.insert(next.clone());
+    for (next, check) in &hosts {
+        info!("registering host {next} (timeout {:?} interval {:?})", check.timeout, check.interval);
+        w.insert(next.clone(), *check);
     }
 }
 
 pub async fn start_checker(controller:Arc<RwLock<Controller>>) {
     let controller_clone = Arc::clone(&controller);
     controller.write().await.spawn(async move {
+        let mut last_checked = HashMap::<String, Instant>::new();
         loop {
             let mut hosts_list = Vec::new();
             let mut hosts_results = Vec::new();
@@ -56,10 +86,16 @@ pub async fn start_checker(controller:Arc<RwLock<Controller>>) {
                 // Do something
                 let hosts = HOSTS.read().await;
 
-                for i in hosts.iter() {
+                last_checked.retain(|host, _| hosts.contains_key(host));
+                for (i, host_check) in hosts.iter() {
+                    let due = last_checked.get(i).is_none_or(|x| x.elapsed() >= host_check.interval);
+                    if !due {
+                        continue;
+                    }
+                    last_checked.insert(i.clone(), Instant::now());
                     let controller_clone = Arc::clone(&controller_clone);
                     hosts_list.push(i.clone());
-                    hosts_results.push(check(controller_clone, i.clone(), Duration::from_secs(5)));
+                    hosts_results.push(check(controller_clone, i.clone(), host_check.timeout));
                 }
             }
 
@@ -88,6 +124,7 @@ pub async fn start_checker(controller:Arc<RwLock<Controller>>) {
                             "update host `{host}` to be `{result}` at {:?}",
                             now.to_rfc3339()
                         );
+                        publish_health(&host, result, now);
                         w.insert(host, (result, now));
                     } else {
                         let (current_status, current_ts) = w.get(&host).unwrap();
@@ -97,17 +134,26 @@ pub async fn start_checker(controller:Arc<RwLock<Controller>>) {
                                 now.to_rfc3339(),
                                 current_ts.to_rfc3339()
                             );
+                            publish_health(&host, result, now);
                             w.insert(host, (result, now));
                         }
                     }
                 }
                 // w is dropped here
             }
-            tokio::time::sleep(Duration::from_millis(5000)).await;
+            tokio::time::sleep(TICK).await;
         }
     }).await;
 }
 
+fn publish_health(host: &str, up: bool, at: DateTime<Local>) {
+    events::publish(Event::Health {
+        host: host.into(),
+        up,
+        at: at.to_rfc3339(),
+    });
+}
+
 async fn check(controller:Arc<RwLock<Controller>>, host: String, timeout: Duration) -> Result<(String, bool), Box<dyn Error>> {
     let resolved_opt = resolver::resolve(&host).await;
     let resolved: String;
@@ -167,7 +213,12 @@ pub async fn get_status_for(host: &str) -> Option<(bool, DateTime<Local>)> {
     return Some((*result, when.clone()));
 }
 
-pub async fn select<'a>(name: &str, what: &'a Vec<String>) -> (bool, &'a str) {
+/// Picks a healthy target at random, or any target if none is healthy.
+/// Returns `None` only when there are no targets at all.
+pub async fn select<'a>(name: &str, what: &'a Vec<String>) -> Option<(bool, &'a str)> {
+    if what.is_empty() {
+        return None;
+    }
     let r = STATUS.read().await;
     let mut candidate: Vec<&String> = Vec::new();
     for host in what {
@@ -186,10 +237,10 @@ pub async fn select<'a>(name: &str, what: &'a Vec<String>) -> (bool, &'a str) {
         warn!("listener {name} has no available backend. randomly selecting...");
         let rand = rand::random_range(0..what.len());
         let selection = what.get(rand).unwrap();
-        return (false, selection);
+        return Some((false, selection));
     } else {
         let rand = rand::random_range(0..candidate.len());
         let selection = *candidate.get(rand).unwrap();
-        return (true, selection);
+        return Some((true, selection));
     }
 }
@@ -0,0 +1,166 @@
+use serde_yaml_ng::Value;
+
+/// A config value that was written with `${...}` references and resolved on load.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interpolated {
+    /// Mapping keys leading to the value. `None` stands for an element of a sequence
+    pub path: Vec<Option<String>>,
+    /// The value as written in the file
+    pub raw: String,
+    pub resolved: String,
+}
+
+/// Replaces `${NAME}` with the environment variable `NAME` and `${file:/path}`
+/// with the content of the file, without its trailing newline. `$${` is a
+/// literal `${`.
+fn expand(input: &str) -> Result<String, String> {
+    let mut result = String::new();
+    let mut rest = input;
+    while let Some(start) = rest.find("${") {
+        if rest[..start].ends_with('$') {
+            result.push_str(&rest[..start - 1]);
+            result.push_str("${");
+            rest = &rest[start + 2..];
+            continue;
+        }
+        result.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| format!("unterminated `${{` in `{input}`"))?;
+        let name = &rest[start + 2..start + end];
+        let value = match name.strip_prefix("file:") {
+            Some(file) => std::fs::read_to_string(file)
+                .map(|x| x.trim_end_matches(['\r', '\n']).to_string())
+                .map_err(|e| format!("secret file `{file}`: {e}"))?,
+            None => std::env::var(name).map_err(|_| format!("environment variable `{name}` is not set"))?,
+        };
+        result.push_str(&value);
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+fn path_text(path: &[Option<String>]) -> String {
+    let mut result = String::new();
+    for key in path {
+        match key {
+            Some(key) if result.is_empty() => result.push_str(key),
+            Some(key) => {
+                result.push('.');
+                result.push_str(key);
+            }
+            None => result.push_str("[]"),
+        }
+    }
+    result
+}
+
+fn resolve_at(value: &mut Value, path: &mut Vec<Option<String>>, found: &mut Vec<Interpolated>) -> Result<(), String> {
+    match value {
+        Value::String(raw) if raw.contains("${") => {
+            let resolved = expand(raw).map_err(|e| format!("{}: {e}", path_text(path)))?;
+            let whole = raw.starts_with("${") && raw.ends_with('}') && raw.matches("${").count() == 1;
+            found.push(Interpolated {
+                path: path.clone(),
+                raw: raw.clone(),
+                resolved: resolved.clone(),
+            });
+            // a lone reference may stand for a number or a boolean, e.g. `bind_port: ${PORT}`
+            *value = match serde_yaml_ng::from_str::<Value>(&resolved) {
+                Ok(parsed @ (Value::Number(_) | Value::Bool(_))) if whole => parsed,
+                _ => Value::String(resolved),
+            };
+        }
+        Value::Mapping(mapping) => {
+            for (key, inner) in mapping.iter_mut() {
+                path.push(Some(key.as_str().unwrap_or_default().to_string()));
+                resolve_at(inner, path, found)?;
+                path.pop();
+            }
+        }
+        Value::Sequence(sequence) => {
+            for inner in sequence.iter_mut() {
+                path.push(None);
+                resolve_at(inner, path, found)?;
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Resolves every reference in the string values of a parsed YAML document.
+pub fn resolve(value: &mut Value) -> Result<Vec<Interpolated>, String> {
+    let mut found = Vec::new();
+    resolve_at(value, &mut Vec::new(), &mut found)?;
+    Ok(found)
+}
+
+fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(x) => Some(x.clone()),
+        Value::Number(x) => Some(x.to_string()),
+        Value::Bool(x) => Some(x.to_string()),
+        _ => None,
+    }
+}
+
+fn restore_at(value: &mut Value, path: &[Option<String>], item: &Interpolated) {
+    match path.split_first() {
+        None => {
+            if scalar_text(value).as_deref() == Some(item.resolved.as_str()) {
+                *value = Value::String(item.raw.clone());
+            }
+        }
+        Some((Some(key), rest)) => {
+            if let Some(inner) = value.get_mut(key.as_str()) {
+                restore_at(inner, rest, item);
+            }
+        }
+        Some((None, rest)) => {
+            if let Value::Sequence(sequence) = value {
+                for inner in sequence.iter_mut() {
+                    restore_at(inner, rest, item);
+                }
+            }
+        }
+    }
+}
+
+/// Puts the references back where values still equal what they resolved to,
+/// so secrets are never written to disk. Values that were edited are kept.
+pub fn restore(value: &mut Value, interpolated: &[Interpolated]) {
+    for item in interpolated {
+        restore_at(value, &item.path, item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_are_restored_unless_edited() {
+        std::env::set_var("PF_TEST_INTERPOLATION", "8080");
+        let mut value: Value = serde_yaml_ng::from_str("port: ${PF_TEST_INTERPOLATION}\nhost: h-${PF_TEST_INTERPOLATION}\nliteral: $${HOME}\nlist:\n- ${PF_TEST_INTERPOLATION}\n").unwrap();
+        let found = resolve(&mut value).unwrap();
+        assert_eq!(value["port"].as_u64(), Some(8080));
+        assert_eq!(value["host"].as_str(), Some("h-8080"));
+        assert_eq!(value["literal"].as_str(), Some("${HOME}"));
+        assert_eq!(value["list"][0].as_u64(), Some(8080));
+        value["host"] = Value::String("edited".into());
+        restore(&mut value, &found);
+        assert_eq!(value["port"].as_str(), Some("${PF_TEST_INTERPOLATION}"));
+        assert_eq!(value["host"].as_str(), Some("edited"));
+        assert_eq!(value["list"][0].as_str(), Some("${PF_TEST_INTERPOLATION}"));
+    }
+
+    #[test]
+    fn missing_variable_names_the_path() {
+        let mut value: Value = serde_yaml_ng::from_str("a:\n  b: ${PF_TEST_NOT_SET}\n").unwrap();
+        let error = resolve(&mut value).unwrap_err();
+        assert!(error.starts_with("a.b: "), "{error}");
+    }
+}
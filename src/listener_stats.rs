@@ -22,6 +22,13 @@ pub struct StatsSerde {
 }
 
 impl StatsSerde {
+    pub fn add(&mut self, other:&StatsSerde) {
+        self.total += other.total;
+        self.active += other.active;
+        self.downloaded_bytes += other.downloaded_bytes;
+        self.uploaded_bytes += other.uploaded_bytes;
+    }
+
     pub fn from(input:&ListenerStats) -> Self {
         Self {
             name: input.name.clone(),
@@ -47,6 +54,20 @@ impl ListenerStats{
             uploaded_bytes: Self::newau(),
         }
     }
+    /// Adds previously accumulated totals. Active connections are not carried.
+    pub fn restore(&self, from:&StatsSerde) {
+        self.total.fetch_add(from.total, Ordering::SeqCst);
+        self.downloaded_bytes.fetch_add(from.downloaded_bytes, Ordering::SeqCst);
+        self.uploaded_bytes.fetch_add(from.uploaded_bytes, Ordering::SeqCst);
+    }
+
+    /// Zeroes the cumulative counters. Active connections are still counted.
+    pub fn reset(&self) {
+        self.total.store(self.active_count(), Ordering::SeqCst);
+        self.downloaded_bytes.store(0, Ordering::SeqCst);
+        self.uploaded_bytes.store(0, Ordering::SeqCst);
+    }
+
     pub fn increase_conn_count(&self) -> usize {
         self.total.fetch_add(1, Ordering::SeqCst);
         self.active.fetch_add(1, Ordering::SeqCst) + 1
@@ -8,16 +8,119 @@ pub mod idletracker;
 pub mod adminserver;
 pub mod controller;
 pub mod activetracker;
+pub mod accesslog;
+pub mod events;
+pub mod timeseries;
+pub mod statestore;
+pub mod cli;
+pub mod signals;
+pub mod watcher;
+pub mod confighistory;
+pub mod interpolation;
+pub mod auth;
+pub mod tokens;
+pub mod audit;
+pub mod openapi;
 extern crate rocket;
 use std::error::Error;
+use std::io::IsTerminal;
+use clap::Parser;
+use cli::{Cli, Command};
 use config::Config;
 use log::{info, error};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    let config = Config::load_file("config.yaml").await.unwrap();
+    let cli = Cli::parse();
+    match cli.command.clone().unwrap_or(Command::Run) {
+        Command::Run => run(cli).await,
+        Command::Validate => validate(&cli).await,
+        Command::PrintDefaultConfig => {
+            print!("{}", serde_yaml_ng::to_string(&Config::sample())?);
+            Ok(())
+        }
+        Command::HashPassword { bcrypt } => hash_password(bcrypt),
+    }
+}
+
+fn hash_password(bcrypt: bool) -> Result<(), Box<dyn Error>> {
+    if std::io::stdin().is_terminal() {
+        eprint!("Password: ");
+    }
+    let mut password = String::new();
+    std::io::stdin().read_line(&mut password)?;
+    let password = password.trim_end_matches(['\r', '\n']);
+    if password.is_empty() {
+        eprintln!("empty password");
+        std::process::exit(1);
+    }
+    println!("{}", auth::hash_password(password, bcrypt)?);
+    Ok(())
+}
+
+async fn load_config(cli: &Cli) -> Config {
+    match Config::load_file(&cli.config).await {
+        Ok(config) => config,
+        Err(cause) => {
+            eprintln!("failed to load `{}`: {cause}", cli.config);
+            std::process::exit(1);
+        }
+    }
+}
+
+async fn validate(cli: &Cli) -> Result<(), Box<dyn Error>> {
+    let config = load_config(cli).await;
+    let errors = config.validate();
+    if errors.is_empty() {
+        println!("`{}` is valid", cli.config);
+        return Ok(());
+    }
+    for error in &errors {
+        eprintln!("{error}");
+    }
+    eprintln!("`{}` has {} error(s)", cli.config, errors.len());
+    std::process::exit(1);
+}
+
+async fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let mut config = load_config(&cli).await;
+    let errors = config.validate();
+    if !errors.is_empty() {
+        for error in &errors {
+            eprintln!("{error}");
+        }
+        eprintln!("refusing to start: `{}` has {} error(s)", cli.config, errors.len());
+        std::process::exit(1);
+    }
+    let admin = config.admin_server.clone().unwrap_or_default();
+    if !cli.no_admin && auth::default_credentials_exposed(&admin) {
+        eprintln!(
+            "refusing to start: the admin server listens on {} with the default credentials admin/admin. \
+             change them, bind it to a loopback address or set admin_server.allow_default_credentials",
+            admin.bind_address.as_deref().unwrap_or("0.0.0.0")
+        );
+        std::process::exit(1);
+    }
+    if !cli.no_admin && auth::anonymous_admin_exposed(&admin) {
+        eprintln!(
+            "refusing to start: the admin server listens on {} without any login, so anyone reaching it is an admin. \
+             set a username and password, users or client_certs, bind it to a loopback address or set admin_server.allow_default_credentials",
+            admin.bind_address.as_deref().unwrap_or("0.0.0.0")
+        );
+        std::process::exit(1);
+    }
+    if let Some(log_config) = &cli.log_config {
+        config.options.log_config_file = log_config.clone();
+    }
     config.init_logging();
-    adminserver::init(&config).await;
+    cli.init_state_dir()?;
+    adminserver::init(&config, &cli.config).await;
+    confighistory::init(&config).await;
+    tokens::init(&config).await;
+    audit::init(&config).await;
+    statestore::init(&config).await;
+    events::start();
+    timeseries::start();
     let start_result = manager::start(config).await;
     match start_result {
         Ok(result) => {
@@ -40,6 +143,15 @@ async fn main() -> Result<(), Box<dyn Error>> {
             error!("failed to start all listeners: {cause}");
         }
     }
+    signals::start();
+    watcher::start();
+    if cli.no_admin {
+        info!("admin server disabled from the command line");
+        signals::terminated().await;
+        return Ok(());
+    }
     let _ = adminserver::run_rocket().await?;
+    // Rocket returns once `signals` asked it to stop; let the shutdown finish
+    signals::terminated().await;
     Ok(())
 }
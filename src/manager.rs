@@ -1,20 +1,24 @@
 use std::collections::HashMap;
 
-use crate::activetracker;
+use crate::accesslog;
 use crate::controller::Controller;
 use crate::listener_stats::StatsSerde;
 use crate::runner::Runner;
 use crate::{
-    config::Config,
+    config::{Config, Listener, ListenerSettings},
     healthcheck,
     listener_stats::ListenerStats,
-    resolver,
+    resolver, statestore,
 };
 use anyhow::{anyhow, Result};
+use chrono::Local;
 use lazy_static::lazy_static;
 use log::{info, warn, error};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::{RwLock, mpsc};
+use tokio::time::{sleep, Instant};
 #[derive(Debug, PartialEq, Clone)]
 pub enum Status {
     STARTING,
@@ -30,6 +34,31 @@ lazy_static! {
     static ref LISTENERS_STATUS: Arc<RwLock<HashMap<String, Result<bool, anyhow::Error>>>> =
         Arc::new(RwLock::new(HashMap::new()));
     static ref CONTROLLER: Arc<RwLock<Controller>> = Arc::new(RwLock::new(Controller::new()));
+    static ref RUNNING: Arc<RwLock<HashMap<String, Running>>> = Arc::new(RwLock::new(HashMap::new()));
+    static ref CURRENT: Arc<RwLock<Config>> = Arc::new(RwLock::new(Default::default()));
+    static ref DRAINING: Arc<RwLock<Vec<Draining>>> = Arc::new(RwLock::new(Vec::new()));
+    static ref DRAIN_ID: AtomicU64 = AtomicU64::new(0);
+}
+
+/// A started listener. Each has its own controllers, so it can be stopped
+/// without touching the connections of other listeners.
+struct Running {
+    listener: Listener,
+    settings: ListenerSettings,
+    acceptor: Arc<RwLock<Controller>>,
+    controller: Arc<RwLock<Controller>>,
+}
+
+/// A stopped listener whose connections are still allowed to finish.
+struct Draining {
+    id: u64,
+    stats: Arc<ListenerStats>,
+}
+
+impl Running {
+    fn is_same(&self, listener: &Listener, config: &Config) -> bool {
+        self.listener == *listener && self.settings == listener.settings(&config.options)
+    }
 }
 
 pub async fn cancel() {
@@ -49,18 +78,130 @@ pub async fn get_stats(name: &str) -> Option<Arc<ListenerStats>> {
 }
 
 pub async fn is_running(name: &str) -> bool {
-    get_stats(name).await.is_none()
+    get_stats(name).await.is_some()
 }
 
+/// Stats of running listeners. Connections still draining from a stopped
+/// instance are counted under the same name.
 pub async fn get_listener_stats() -> HashMap<String, StatsSerde> {
     let mut result = HashMap::new();
     let r = LISTENERS.read().await;
     for i in r.iter() {
         result.insert(i.name.clone(), StatsSerde::from(i));
     }
+    drop(r);
+    let draining = DRAINING.read().await;
+    for i in draining.iter() {
+        let stats = StatsSerde::from(&i.stats);
+        match result.get_mut(&i.stats.name) {
+            Some(existing) => existing.add(&stats),
+            None => {
+                result.insert(i.stats.name.clone(), stats);
+            }
+        }
+    }
     return result;
 }
 
+/// The config the manager was last started or applied with
+pub async fn get_config() -> Config {
+    CURRENT.read().await.clone()
+}
+
+pub async fn drain_timeout() -> Duration {
+    CURRENT.read().await.options.drain_timeout()
+}
+
+/// Connections still open on stopped listener instances, by listener name.
+pub async fn get_draining() -> HashMap<String, usize> {
+    let r = DRAINING.read().await;
+    let mut result = HashMap::new();
+    for i in r.iter() {
+        *result.entry(i.stats.name.clone()).or_insert(0) += i.stats.active_count();
+    }
+    result
+}
+
+/// Waits until no listener is draining, or `timeout` passed.
+pub async fn wait_drained(timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        if DRAINING.read().await.is_empty() {
+            return true;
+        }
+        sleep(Duration::from_millis(200)).await;
+    }
+    DRAINING.read().await.is_empty()
+}
+
+/// Hands the counters of a finished listener instance over to the running
+/// instance of the same name, or to the state store if there is none.
+/// Counters of listeners that were removed from the config are dropped.
+async fn hand_over(stats: &Arc<ListenerStats>) {
+    match get_stats(&stats.name).await {
+        Some(live) => live.restore(&StatsSerde::from(stats)),
+        None if CURRENT.read().await.listeners.contains_key(&stats.name) => {
+            statestore::carry(std::slice::from_ref(stats)).await
+        }
+        None => info!("dropping counters of removed listener `{}`", stats.name),
+    }
+}
+
+/// Stops accepting on `running` right away, then lets its connections finish
+/// for up to `timeout` before cancelling them.
+async fn drain(name: &str, stats: Arc<ListenerStats>, running: Running, timeout: Duration) {
+    running.acceptor.write().await.cancel().await;
+    if stats.active_count() == 0 || timeout.is_zero() {
+        running.controller.write().await.cancel().await;
+        hand_over(&stats).await;
+        info!("stopped listener {name}");
+        return;
+    }
+    let id = DRAIN_ID.fetch_add(1, Ordering::SeqCst);
+    let deadline = Local::now() + chrono::Duration::milliseconds(timeout.as_millis() as i64);
+    info!(
+        "draining listener {name}: {} active connections, deadline {}",
+        stats.active_count(),
+        deadline.to_rfc3339()
+    );
+    DRAINING.write().await.push(Draining {
+        id,
+        stats: Arc::clone(&stats),
+    });
+    let name = name.to_string();
+    tokio::spawn(async move {
+        let until = Instant::now() + timeout;
+        while stats.active_count() > 0 && Instant::now() < until {
+            sleep(Duration::from_millis(200)).await;
+        }
+        let remaining = stats.active_count();
+        if remaining > 0 {
+            warn!("draining listener {name}: timed out, cancelling {remaining} connections");
+        } else {
+            info!("draining listener {name}: all connections finished");
+        }
+        running.controller.write().await.cancel().await;
+        DRAINING.write().await.retain(|x| x.id != id);
+        hand_over(&stats).await;
+        info!("stopped listener {name}");
+    });
+}
+
+pub async fn reset_stats(name: Option<&str>) {
+    let r = LISTENERS.read().await;
+    for i in r.iter() {
+        if name.is_none() || name == Some(i.name.as_str()) {
+            i.reset();
+        }
+    }
+    let draining = DRAINING.read().await;
+    for i in draining.iter() {
+        if name.is_none() || name == Some(i.stats.name.as_str()) {
+            i.stats.reset();
+        }
+    }
+}
+
 pub async fn stop() {
     info!("stopping manager");
     let mut status = STATUS.write().await;
@@ -68,14 +209,23 @@ pub async fn stop() {
         info!("stopping manager: succeeded (already stopped)");
         return;
     }
-    let mut listeners = LISTENERS.write().await;
-    let mut listener_status = LISTENERS_STATUS.write().await;
     info!("transitioning from `{status:?}` to `{:?}`", Status::STOPPING);
     *status = Status::STOPPING;
-    listeners.clear();
-    listener_status.clear();
-    activetracker::reset().await;
+    let drain_timeout = CURRENT.read().await.options.drain_timeout();
+    let listeners: Vec<Arc<ListenerStats>> = LISTENERS.write().await.drain(..).collect();
+    LISTENERS_STATUS.write().await.clear();
     info!("cancelling all tasks");
+    let running: Vec<(String, Running)> = RUNNING.write().await.drain().collect();
+    for (name, running) in running {
+        info!("stopping listener {name}");
+        match listeners.iter().find(|x| x.name == name) {
+            Some(stats) => drain(&name, Arc::clone(stats), running, drain_timeout).await,
+            None => {
+                running.acceptor.write().await.cancel().await;
+                running.controller.write().await.cancel().await;
+            }
+        }
+    }
     cancel().await;
     info!("all tasks cancelled by controller");
     *status = Status::STOPPED;
@@ -124,48 +274,375 @@ pub async fn start(config: Config) -> Result<HashMap<String, Result<bool>>> {
 
     resolver::init(&config).await;
     healthcheck::init(&config).await;
-    activetracker::reset().await;
+    accesslog::init(&config).await;
     let controller_clone = Arc::clone(&CONTROLLER);
     healthcheck::start_checker(controller_clone).await;
 
+    mark_disabled(&config).await;
+    let names: Vec<String> = config
+        .listeners
+        .iter()
+        .filter(|(_, listener)| listener.enabled)
+        .map(|(name, _)| name.clone())
+        .collect();
+    start_listeners(&config, names).await;
+    *CURRENT.write().await = config;
+    info!("starting manager: succeeded");
+    *status = Status::STARTED;
+    //return get_listener_status();
+    return Ok(get_listener_status().await);
+}
+
+/// Applies a new config to a running manager. Only listeners that were added,
+/// removed or changed are touched; the others keep their live connections.
+/// A stopped manager is simply started.
+pub async fn apply(config: Config) -> Result<HashMap<String, Result<bool>>> {
+    info!("applying config");
+    let mut status = STATUS.write().await;
+    if *status == Status::STOPPED {
+        drop(status);
+        statestore::forget_removed(&config).await;
+        return start(config).await;
+    }
+    if *status != Status::STARTED {
+        warn!("applying config: failed (manager is `{status:?}`)");
+        return Err(anyhow!("failed to apply, manager is {status:?}"));
+    }
+    *status = Status::STARTING;
+
+    resolver::init(&config).await;
+    healthcheck::init(&config).await;
+    accesslog::init(&config).await;
+
+    let mut to_stop = Vec::new();
+    let mut to_start = Vec::new();
+    {
+        let running = RUNNING.read().await;
+        for (name, current) in running.iter() {
+            match config.listeners.get(name) {
+                Some(listener) if !listener.enabled => {
+                    info!("applying config: {name} disabled");
+                    to_stop.push(name.clone());
+                }
+                Some(listener) if current.is_same(listener, &config) => {
+                    info!("applying config: {name} unchanged");
+                }
+                Some(_) => {
+                    info!("applying config: {name} changed");
+                    to_stop.push(name.clone());
+                    to_start.push(name.clone());
+                }
+                None => {
+                    info!("applying config: {name} removed");
+                    to_stop.push(name.clone());
+                }
+            }
+        }
+        for (name, listener) in config.listeners.iter() {
+            if !running.contains_key(name) && listener.enabled {
+                info!("applying config: {name} added or not running");
+                to_start.push(name.clone());
+            }
+        }
+    }
+    {
+        let mut listener_status = LISTENERS_STATUS.write().await;
+        listener_status.retain(|name, _| config.listeners.contains_key(name));
+    }
+    // connections of changed listeners drain under the old settings
+    let drain_timeout = CURRENT.read().await.options.drain_timeout();
+    for name in to_stop {
+        stop_listener_inner(&name, drain_timeout).await;
+    }
+    mark_disabled(&config).await;
+    start_listeners(&config, to_start).await;
+    statestore::forget_removed(&config).await;
+    *CURRENT.write().await = config;
+    info!("applying config: succeeded");
+    *status = Status::STARTED;
+    return Ok(get_listener_status().await);
+}
+
+/// Reports disabled listeners as not running.
+async fn mark_disabled(config: &Config) {
+    let mut listener_status = LISTENERS_STATUS.write().await;
+    for (name, listener) in config.listeners.iter() {
+        if !listener.enabled {
+            info!("listener {name} is disabled");
+            listener_status.insert(name.clone(), Ok(false));
+        }
+    }
+}
+
+/// Starts a single listener of the applied config, even if it is disabled.
+/// Returns false if it was already running.
+pub async fn start_listener(name: &str) -> Result<bool> {
+    let status = STATUS.write().await;
+    if *status != Status::STARTED {
+        return Err(anyhow!("manager is {status:?}"));
+    }
+    if RUNNING.read().await.contains_key(name) {
+        return Ok(false);
+    }
+    let config = CURRENT.read().await.clone();
+    if !config.listeners.contains_key(name) {
+        return Err(anyhow!("no listener named `{name}`"));
+    }
+    start_listeners(&config, vec![name.to_string()]).await;
+    match LISTENERS_STATUS.read().await.get(name) {
+        Some(Err(cause)) => Err(anyhow!("{cause}")),
+        _ => Ok(true),
+    }
+}
+
+/// Stops a single listener, draining its connections. It stays stopped
+/// until it is started again or the config is applied.
+/// Returns false if it was not running.
+pub async fn stop_listener(name: &str) -> Result<bool> {
+    let status = STATUS.write().await;
+    if *status != Status::STARTED {
+        return Err(anyhow!("manager is {status:?}"));
+    }
+    let (known, drain_timeout) = {
+        let config = CURRENT.read().await;
+        (config.listeners.contains_key(name), config.options.drain_timeout())
+    };
+    let running = RUNNING.read().await.contains_key(name);
+    if !running && !known {
+        return Err(anyhow!("no listener named `{name}`"));
+    }
+    if running {
+        stop_listener_inner(name, drain_timeout).await;
+    }
+    LISTENERS_STATUS.write().await.insert(name.to_string(), Ok(false));
+    Ok(running)
+}
+
+pub async fn restart_listener(name: &str) -> Result<bool> {
+    stop_listener(name).await?;
+    start_listener(name).await
+}
+
+async fn stop_listener_inner(name: &str, drain_timeout: Duration) {
+    let running = RUNNING.write().await.remove(name);
+    let running = match running {
+        Some(running) => running,
+        None => return,
+    };
+    info!("stopping listener {name}");
+    let stats = {
+        let mut listeners = LISTENERS.write().await;
+        let index = listeners.iter().position(|x| x.name == name);
+        index.map(|index| listeners.remove(index))
+    };
+    LISTENERS_STATUS.write().await.remove(name);
+    match stats {
+        Some(stats) => drain(name, stats, running, drain_timeout).await,
+        None => {
+            running.acceptor.write().await.cancel().await;
+            running.controller.write().await.cancel().await;
+        }
+    }
+}
+
+async fn start_listeners(config: &Config, names: Vec<String>) {
     let config_x = Arc::new(RwLock::new(config.clone()));
-    let (tx, mut rx) = mpsc::channel(config.listeners.len());
-    for (name, listener) in &config.listeners {
-        let name = name.clone();
+    let (tx, mut rx) = mpsc::channel(names.len().max(1));
+    let mut count = 0;
+    for name in names {
+        let listener = match config.listeners.get(&name) {
+            Some(listener) => listener.clone(),
+            None => continue,
+        };
         let local_config = Arc::clone(&config_x);
-        let controller_local = Arc::clone(&CONTROLLER);
+        let acceptor_local = Arc::new(RwLock::new(Controller::new()));
+        let controller_local = Arc::new(RwLock::new(Controller::new()));
+        RUNNING.write().await.insert(
+            name.clone(),
+            Running {
+                listener: listener.clone(),
+                settings: listener.settings(&config.options),
+                acceptor: Arc::clone(&acceptor_local),
+                controller: Arc::clone(&controller_local),
+            },
+        );
         let r = Runner::new(
             name.clone(),
-            listener.clone(),
+            listener,
             local_config,
-            controller_local,
+            Arc::clone(&acceptor_local),
+            Arc::clone(&controller_local),
         );
     
         let context = r.start();
-        let mut w = CONTROLLER.write().await;
+        let mut w = acceptor_local.write().await;
     
         let tx = tx.clone();
+        count += 1;
         w.spawn(async move {
             let result1 = context.await;
             match result1 {
                 Ok(some) => {
+                    statestore::restore(&some).await;
                     LISTENERS.write().await.push(some);
                     LISTENERS_STATUS.write().await.insert(name.clone(), Ok(true));
                     info!("starting manager: {name} started OK");
                 }
                 Err(cause) => {
                     error!("starting manager: {name} start failed ({cause})");
+                    RUNNING.write().await.remove(&name);
                     LISTENERS_STATUS.write().await.insert(name.clone(), Err(cause));
                 }
             }
             let _ = tx.send(()).await;
         }).await;
     }
-    for _ in 0..config.listeners.len() {
+    for _ in 0..count {
         rx.recv().await;
     }
-    info!("starting manager: succeeded");
-    *status = Status::STARTED;
-    //return get_listener_status();
-    return Ok(get_listener_status().await);
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::HashSet;
+
+    use crate::config::Options;
+
+    use super::*;
+
+    lazy_static! {
+        /// The manager and the carried counters are global, so tests using them take turns
+        pub(crate) static ref SERIAL: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+    }
+
+    fn listener(target: &str, enabled: bool) -> Listener {
+        Listener {
+            bind: "127.0.0.1:0".into(),
+            targets: HashSet::from([target.to_string()]),
+            enabled,
+            ..Default::default()
+        }
+    }
+
+    fn config(listeners: Vec<(&str, Listener)>) -> Config {
+        Config {
+            listeners: listeners.into_iter().map(|(name, x)| (name.to_string(), x)).collect(),
+            options: Options {
+                drain_timeout_ms: Some(0),
+                ..Default::default()
+            },
+            admin_server: None,
+            ..Default::default()
+        }
+    }
+
+    async fn controller_of(name: &str) -> Option<Arc<RwLock<Controller>>> {
+        RUNNING.read().await.get(name).map(|x| Arc::clone(&x.controller))
+    }
+
+    async fn running_names() -> Vec<String> {
+        let mut names: Vec<String> = RUNNING.read().await.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn apply_restarts_only_what_changed() {
+        let _serial = SERIAL.lock().await;
+        start(config(vec![
+            ("kept", listener("127.0.0.1:1", true)),
+            ("changed", listener("127.0.0.1:2", true)),
+            ("removed", listener("127.0.0.1:3", true)),
+        ]))
+        .await
+        .unwrap();
+        assert_eq!(running_names().await, vec!["changed", "kept", "removed"]);
+        let kept = controller_of("kept").await.unwrap();
+        let changed = controller_of("changed").await.unwrap();
+
+        let result = apply(config(vec![
+            ("kept", listener("127.0.0.1:1", true)),
+            ("changed", listener("127.0.0.1:20", true)),
+            ("added", listener("127.0.0.1:4", true)),
+        ]))
+        .await
+        .unwrap();
+        assert_eq!(running_names().await, vec!["added", "changed", "kept"]);
+        assert!(Arc::ptr_eq(&kept, &controller_of("kept").await.unwrap()), "unchanged listener was restarted");
+        assert!(!Arc::ptr_eq(&changed, &controller_of("changed").await.unwrap()), "changed listener kept running");
+        assert!(result.values().all(|x| matches!(x, Ok(true))));
+        assert!(!result.contains_key("removed"));
+
+        // disabling stops it, and nothing else
+        apply(config(vec![
+            ("kept", listener("127.0.0.1:1", true)),
+            ("changed", listener("127.0.0.1:20", false)),
+            ("added", listener("127.0.0.1:4", true)),
+        ]))
+        .await
+        .unwrap();
+        assert_eq!(running_names().await, vec!["added", "kept"]);
+        assert!(Arc::ptr_eq(&kept, &controller_of("kept").await.unwrap()));
+        assert!(matches!(get_listener_status().await.get("changed"), Some(Ok(false))));
+        stop().await;
+    }
+
+    #[tokio::test]
+    async fn apply_restarts_listeners_that_inherit_a_changed_option() {
+        let _serial = SERIAL.lock().await;
+        let overriding = Listener {
+            max_idle_time_ms: Some(5000),
+            ..listener("127.0.0.1:2", true)
+        };
+        let mut before = config(vec![("inheriting", listener("127.0.0.1:1", true)), ("overriding", overriding.clone())]);
+        before.options.max_idle_time_ms = 1000;
+        start(before.clone()).await.unwrap();
+        let inheriting = controller_of("inheriting").await.unwrap();
+        let kept = controller_of("overriding").await.unwrap();
+
+        let mut after = before.clone();
+        after.options.max_idle_time_ms = 2000;
+        apply(after).await.unwrap();
+        assert!(!Arc::ptr_eq(&inheriting, &controller_of("inheriting").await.unwrap()));
+        assert!(Arc::ptr_eq(&kept, &controller_of("overriding").await.unwrap()));
+        stop().await;
+    }
+
+    #[tokio::test]
+    async fn listeners_start_and_stop_one_at_a_time() {
+        let _serial = SERIAL.lock().await;
+        start(config(vec![
+            ("a", listener("127.0.0.1:1", true)),
+            ("b", listener("127.0.0.1:2", true)),
+            ("off", listener("127.0.0.1:3", false)),
+        ]))
+        .await
+        .unwrap();
+        assert!(is_running("a").await);
+        assert!(!is_running("off").await, "disabled listeners are not started");
+        let b = controller_of("b").await.unwrap();
+
+        assert!(stop_listener("a").await.unwrap());
+        assert!(!is_running("a").await);
+        assert!(!stop_listener("a").await.unwrap(), "already stopped");
+        assert!(matches!(get_listener_status().await.get("a"), Some(Ok(false))));
+        assert!(start_listener("a").await.unwrap());
+        assert!(is_running("a").await);
+        assert!(!start_listener("a").await.unwrap(), "already running");
+
+        // a disabled listener can still be started by hand
+        assert!(start_listener("off").await.unwrap());
+        assert!(is_running("off").await);
+
+        let a = controller_of("a").await.unwrap();
+        assert!(restart_listener("a").await.unwrap());
+        assert!(is_running("a").await);
+        assert!(!Arc::ptr_eq(&a, &controller_of("a").await.unwrap()));
+        assert!(Arc::ptr_eq(&b, &controller_of("b").await.unwrap()), "other listeners are untouched");
+
+        assert!(start_listener("unknown").await.is_err());
+        assert!(stop_listener("unknown").await.is_err());
+        stop().await;
+        assert!(start_listener("a").await.is_err(), "manager is stopped");
+    }
 }
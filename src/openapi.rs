@@ -0,0 +1,690 @@
+use serde_json::{json, Map, Value};
+
+/// Reference to a schema in `components.schemas`.
+fn schema(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{name}") })
+}
+
+fn json_body(schema: Value) -> Value {
+    json!({ "content": { "application/json": { "schema": schema } } })
+}
+
+fn text_body(description: &str) -> Value {
+    json!({ "description": description, "content": { "text/plain": { "schema": { "type": "string" } } } })
+}
+
+fn path_param(name: &str, description: &str, kind: &str) -> Value {
+    json!({ "name": name, "in": "path", "required": true, "description": description, "schema": { "type": kind } })
+}
+
+fn query_param(name: &str, description: &str, kind: &str, required: bool) -> Value {
+    json!({ "name": name, "in": "query", "required": required, "description": description, "schema": { "type": kind } })
+}
+
+fn if_match() -> Value {
+    json!({
+        "name": "If-Match",
+        "in": "header",
+        "required": false,
+        "description": "ETag of the config the edit is based on. The edit fails with 412 when the config changed since",
+        "schema": { "type": "string" }
+    })
+}
+
+/// One operation. `role` is the least role that may call it.
+struct Operation {
+    tag: &'static str,
+    summary: &'static str,
+    role: &'static str,
+    parameters: Vec<Value>,
+    body: Option<Value>,
+    responses: Vec<(&'static str, Value)>,
+}
+
+impl Operation {
+    fn new(tag: &'static str, summary: &'static str, role: &'static str) -> Self {
+        Self {
+            tag,
+            summary,
+            role,
+            parameters: Vec::new(),
+            body: None,
+            responses: Vec::new(),
+        }
+    }
+
+    fn param(mut self, param: Value) -> Self {
+        self.parameters.push(param);
+        self
+    }
+
+    fn body(mut self, schema: Value) -> Self {
+        let mut body = json_body(schema);
+        body["required"] = json!(true);
+        self.body = Some(body);
+        self
+    }
+
+    fn ok(self, schema: Value) -> Self {
+        self.response("200", json_body(schema))
+    }
+
+    fn response(mut self, status: &'static str, mut response: Value) -> Self {
+        if response.get("description").is_none() {
+            response["description"] = json!(match status {
+                "200" => "OK",
+                "201" => "Created",
+                "400" => "Invalid parameter",
+                "404" => "Not found",
+                "409" => "Already exists",
+                "412" => "The config changed since the ETag in If-Match",
+                "422" => "Invalid body or config",
+                _ => "",
+            });
+        }
+        self.responses.push((status, response));
+        self
+    }
+
+    fn into_value(self) -> Value {
+        let mut responses = Map::new();
+        for (status, response) in self.responses {
+            responses.insert(status.into(), response);
+        }
+        responses.insert("401".into(), text_body("Not logged in"));
+        responses.insert("403".into(), text_body("The role of the user does not allow this"));
+        responses.insert("429".into(), text_body("Too many failed logins, see Retry-After"));
+        responses.insert("500".into(), text_body("Internal error"));
+        let mut result = json!({
+            "tags": [self.tag],
+            "summary": self.summary,
+            "description": format!("Requires the `{}` role.", self.role),
+            "x-required-role": self.role,
+            "responses": responses,
+        });
+        if !self.parameters.is_empty() {
+            result["parameters"] = Value::Array(self.parameters);
+        }
+        if let Some(body) = self.body {
+            result["requestBody"] = body;
+        }
+        result
+    }
+}
+
+fn operations() -> Vec<(&'static str, &'static str, Operation)> {
+    let name = || path_param("name", "listener name", "string");
+    let key = || path_param("key", "DNS entry, `host:port`", "string");
+    let version = || path_param("version", "config version", "integer");
+    vec![
+        ("get", "/apiserver/config/listeners", Operation::new("config", "All listeners", "operator").ok(schema("Listeners"))),
+        (
+            "put",
+            "/apiserver/config/listeners",
+            Operation::new("config", "Replace all listeners", "admin")
+                .param(if_match())
+                .body(schema("Listeners"))
+                .ok(schema("Listeners"))
+                .response("412", json_body(schema("ApiError")))
+                .response("422", json_body(schema("ApiError"))),
+        ),
+        ("get", "/apiserver/config/dns", Operation::new("config", "All DNS entries", "operator").ok(schema("Dns"))),
+        (
+            "put",
+            "/apiserver/config/dns",
+            Operation::new("config", "Replace all DNS entries", "admin")
+                .param(if_match())
+                .body(schema("Dns"))
+                .ok(schema("Dns"))
+                .response("412", json_body(schema("ApiError")))
+                .response("422", json_body(schema("ApiError"))),
+        ),
+        (
+            "get",
+            "/apiserver/config/listeners/{name}",
+            Operation::new("config", "One listener", "operator")
+                .param(name())
+                .ok(schema("Listener"))
+                .response("404", json_body(schema("ApiError"))),
+        ),
+        (
+            "post",
+            "/apiserver/config/listeners/{name}",
+            Operation::new("config", "Add a listener", "admin")
+                .param(name())
+                .param(if_match())
+                .body(schema("Listener"))
+                .response("201", json_body(schema("Listener")))
+                .response("409", json_body(schema("ApiError")))
+                .response("412", json_body(schema("ApiError")))
+                .response("422", json_body(schema("ApiError"))),
+        ),
+        (
+            "put",
+            "/apiserver/config/listeners/{name}",
+            Operation::new("config", "Replace a listener", "admin")
+                .param(name())
+                .param(if_match())
+                .body(schema("Listener"))
+                .ok(schema("Listener"))
+                .response("404", json_body(schema("ApiError")))
+                .response("412", json_body(schema("ApiError")))
+                .response("422", json_body(schema("ApiError"))),
+        ),
+        (
+            "delete",
+            "/apiserver/config/listeners/{name}",
+            Operation::new("config", "Remove a listener", "admin")
+                .param(name())
+                .param(if_match())
+                .ok(schema("SimpleOperationResult"))
+                .response("404", json_body(schema("ApiError")))
+                .response("412", json_body(schema("ApiError")))
+                .response("422", json_body(schema("ApiError"))),
+        ),
+        (
+            "get",
+            "/apiserver/config/dns/{key}",
+            Operation::new("config", "One DNS entry", "operator")
+                .param(key())
+                .ok(json!({ "type": "string" }))
+                .response("404", json_body(schema("ApiError"))),
+        ),
+        (
+            "post",
+            "/apiserver/config/dns/{key}",
+            Operation::new("config", "Add a DNS entry", "admin")
+                .param(key())
+                .param(if_match())
+                .body(json!({ "type": "string", "description": "`host:port` to connect to instead" }))
+                .response("201", json_body(json!({ "type": "string" })))
+                .response("409", json_body(schema("ApiError")))
+                .response("412", json_body(schema("ApiError")))
+                .response("422", json_body(schema("ApiError"))),
+        ),
+        (
+            "put",
+            "/apiserver/config/dns/{key}",
+            Operation::new("config", "Replace a DNS entry", "admin")
+                .param(key())
+                .param(if_match())
+                .body(json!({ "type": "string", "description": "`host:port` to connect to instead" }))
+                .ok(json!({ "type": "string" }))
+                .response("404", json_body(schema("ApiError")))
+                .response("412", json_body(schema("ApiError")))
+                .response("422", json_body(schema("ApiError"))),
+        ),
+        (
+            "delete",
+            "/apiserver/config/dns/{key}",
+            Operation::new("config", "Remove a DNS entry", "admin")
+                .param(key())
+                .param(if_match())
+                .ok(schema("SimpleOperationResult"))
+                .response("404", json_body(schema("ApiError")))
+                .response("412", json_body(schema("ApiError")))
+                .response("422", json_body(schema("ApiError"))),
+        ),
+        (
+            "post",
+            "/apiserver/config/stop",
+            Operation::new("control", "Stop all listeners", "operator").ok(schema("SimpleOperationResult")),
+        ),
+        (
+            "post",
+            "/apiserver/config/start",
+            Operation::new("control", "Start the listeners of the config file", "operator").ok(json!({
+                "oneOf": [schema("SimpleOperationResult"), schema("ListenerResults")]
+            })),
+        ),
+        (
+            "post",
+            "/apiserver/config/apply",
+            Operation::new("control", "Apply the config file", "operator").ok(schema("ListenerResults")),
+        ),
+        (
+            "post",
+            "/apiserver/config/reset",
+            Operation::new("config", "Reset listeners and DNS to the last applied config", "admin")
+                .param(if_match())
+                .ok(json!({ "type": "string" }))
+                .response("412", json_body(schema("ApiError")))
+                .response("422", json_body(schema("ApiError"))),
+        ),
+        (
+            "post",
+            "/apiserver/listeners/{name}/start",
+            Operation::new("control", "Start one listener", "operator").param(name()).ok(schema("SimpleOperationResult")),
+        ),
+        (
+            "post",
+            "/apiserver/listeners/{name}/stop",
+            Operation::new("control", "Stop one listener", "operator").param(name()).ok(schema("SimpleOperationResult")),
+        ),
+        (
+            "post",
+            "/apiserver/listeners/{name}/restart",
+            Operation::new("control", "Restart one listener", "operator").param(name()).ok(schema("SimpleOperationResult")),
+        ),
+        (
+            "get",
+            "/apiserver/status/listeners",
+            Operation::new("status", "Whether each listener is running or draining", "viewer").ok(schema("ListenerStatuses")),
+        ),
+        (
+            "get",
+            "/apiserver/status/config",
+            Operation::new("status", "State of the config file watcher", "viewer").ok(schema("WatchStatus")),
+        ),
+        (
+            "get",
+            "/apiserver/stats/listeners",
+            Operation::new("stats", "Counters of each listener", "viewer")
+                .ok(json!({ "type": "object", "additionalProperties": schema("StatsSerde") })),
+        ),
+        (
+            "post",
+            "/apiserver/stats/reset",
+            Operation::new("stats", "Zero the counters of one or all listeners", "operator")
+                .param(query_param("name", "listener. All listeners when missing", "string", false))
+                .ok(schema("SimpleOperationResult")),
+        ),
+        (
+            "get",
+            "/apiserver/stats/history",
+            Operation::new("stats", "Names of the listeners with history", "viewer")
+                .ok(json!({ "type": "array", "items": { "type": "string" } })),
+        ),
+        (
+            "get",
+            "/apiserver/stats/history/{name}",
+            Operation::new("stats", "Counters of a listener over time", "viewer")
+                .param(name())
+                .param(query_param("resolution", "`second` (default) or `minute`", "string", false))
+                .param(query_param("limit", "most recent points to return", "integer", false))
+                .ok(schema("History"))
+                .response("400", json_body(schema("ApiError")))
+                .response("404", json!({})),
+        ),
+        (
+            "get",
+            "/apiserver/connections",
+            Operation::new("connections", "Active connections", "viewer")
+                .param(query_param("listener", "only connections of this listener", "string", false))
+                .param(query_param("client", "only connections whose client address contains this", "string", false))
+                .param(query_param("target", "only connections whose target contains this", "string", false))
+                .ok(json!({ "type": "array", "items": schema("ConnectionSerde") })),
+        ),
+        (
+            "delete",
+            "/apiserver/connections/{id}",
+            Operation::new("connections", "Close a connection", "operator")
+                .param(path_param("id", "connection id", "integer"))
+                .ok(schema("SimpleOperationResult")),
+        ),
+        (
+            "get",
+            "/apiserver/events",
+            Operation::new("status", "Server-sent events: `stats`, `connection_opened`, `connection_closed` and `health`", "viewer")
+                .response("200", json!({ "content": { "text/event-stream": { "schema": { "type": "string" } } } })),
+        ),
+        (
+            "get",
+            "/apiserver/config/history",
+            Operation::new("history", "Saved config versions, newest first", "admin")
+                .ok(json!({ "type": "array", "items": schema("ConfigVersion") })),
+        ),
+        (
+            "get",
+            "/apiserver/config/history/diff",
+            Operation::new("history", "Difference between two config versions", "admin")
+                .param(query_param("from", "older version", "integer", true))
+                .param(query_param("to", "newer version", "integer", true))
+                .ok(schema("ConfigDiff"))
+                .response("404", json!({})),
+        ),
+        (
+            "get",
+            "/apiserver/config/history/{version}",
+            Operation::new("history", "A config version as YAML", "admin")
+                .param(version())
+                .response("200", text_body("The config file"))
+                .response("404", json!({})),
+        ),
+        (
+            "post",
+            "/apiserver/config/history/{version}/rollback",
+            Operation::new("history", "Save a config version as the current config and apply it", "admin")
+                .param(version())
+                .param(if_match())
+                .ok(schema("ListenerResults"))
+                .response("404", json_body(schema("ApiError")))
+                .response("412", json_body(schema("ApiError")))
+                .response("422", json_body(schema("ApiError"))),
+        ),
+        (
+            "get",
+            "/apiserver/tokens",
+            Operation::new("access", "API tokens, without the tokens themselves", "admin")
+                .ok(json!({ "type": "array", "items": schema("TokenInfo") })),
+        ),
+        (
+            "post",
+            "/apiserver/tokens",
+            Operation::new("access", "Create an API token. The token is only returned here", "admin")
+                .body(schema("NewToken"))
+                .ok(schema("CreatedToken"))
+                .response("400", text_body("Invalid request")),
+        ),
+        (
+            "delete",
+            "/apiserver/tokens/{id}",
+            Operation::new("access", "Revoke an API token", "admin")
+                .param(path_param("id", "token id", "string"))
+                .ok(schema("SimpleOperationResult"))
+                .response("404", json!({})),
+        ),
+        (
+            "get",
+            "/apiserver/audit",
+            Operation::new("access", "Audit log of changes, newest first", "admin")
+                .param(query_param("offset", "records to skip. Default 0", "integer", false))
+                .param(query_param("limit", "records to return, 1 to 1000. Default 100", "integer", false))
+                .ok(schema("AuditPage")),
+        ),
+        ("get", "/apiserver/whoami", Operation::new("access", "The current user and role", "viewer").ok(schema("WhoAmI"))),
+        (
+            "get",
+            "/apiserver/openapi.json",
+            Operation::new("meta", "This document", "viewer").ok(json!({ "type": "object" })),
+        ),
+    ]
+}
+
+fn object(required: &[&str], properties: Value) -> Value {
+    json!({ "type": "object", "required": required, "properties": properties })
+}
+
+fn schemas() -> Value {
+    let string = || json!({ "type": "string" });
+    let integer = || json!({ "type": "integer", "minimum": 0 });
+    let strings = || json!({ "type": "array", "items": { "type": "string" } });
+    let role = || schema("Role");
+    json!({
+        "Role": { "type": "string", "enum": ["viewer", "operator", "admin"] },
+        "Listener": object(&["bind", "targets"], json!({
+            "bind": { "type": "string", "description": "`ip:port` to listen on" },
+            "targets": { "type": "array", "items": { "type": "string" }, "uniqueItems": true, "description": "`host:port` of each target" },
+            "enabled": { "type": "boolean", "default": true },
+            "max_idle_time_ms": integer(),
+            "connect_timeout_ms": integer(),
+            "max_connection_lifetime_ms": integer(),
+            "buffer_size": integer(),
+            "health_check_timeout_ms": integer(),
+            "health_check_interval_ms": integer(),
+        })),
+        "Listeners": { "type": "object", "additionalProperties": schema("Listener") },
+        "Dns": { "type": "object", "additionalProperties": { "type": "string" }, "description": "`host:port` to `host:port` to connect to instead" },
+        "StatsSerde": object(&["name", "total", "active", "downloaded_bytes", "uploaded_bytes"], json!({
+            "name": string(),
+            "total": integer(),
+            "active": integer(),
+            "downloaded_bytes": integer(),
+            "uploaded_bytes": integer(),
+        })),
+        "SimpleOperationResult": object(&["success", "changed"], json!({
+            "success": { "type": "boolean" },
+            "changed": { "type": "boolean" },
+            "message": { "type": ["string", "null"] },
+        })),
+        "ListenerResults": {
+            "type": "object",
+            "description": "Result for each listener: `{\"Ok\": true}` when it runs, or the error",
+            "additionalProperties": {
+                "oneOf": [
+                    object(&["Ok"], json!({ "Ok": { "type": "boolean" } })),
+                    object(&["Err"], json!({ "Err": object(&["message"], json!({ "message": string() })) })),
+                ]
+            }
+        },
+        "ListenerStatuses": {
+            "type": "object",
+            "description": "Like `ListenerResults`, plus whether a stopped instance is still draining connections",
+            "additionalProperties": {
+                "allOf": [
+                    object(&["draining", "remaining"], json!({
+                        "draining": { "type": "boolean" },
+                        "remaining": integer(),
+                    })),
+                    { "oneOf": [
+                        object(&["Ok"], json!({ "Ok": { "type": "boolean" } })),
+                        object(&["Err"], json!({ "Err": object(&["message"], json!({ "message": string() })) })),
+                    ] },
+                ]
+            }
+        },
+        "WatchStatus": object(&["watching", "files"], json!({
+            "watching": { "type": "boolean" },
+            "files": strings(),
+            "last_change": { "type": ["string", "null"] },
+            "last_applied": { "type": ["string", "null"] },
+            "last_error": { "type": ["string", "null"] },
+        })),
+        "HistoryPoint": object(&["at", "total", "active", "downloaded_bytes", "uploaded_bytes", "connection_rate", "downloaded_rate", "uploaded_rate"], json!({
+            "at": string(),
+            "total": integer(),
+            "active": integer(),
+            "downloaded_bytes": integer(),
+            "uploaded_bytes": integer(),
+            "connection_rate": { "type": "number" },
+            "downloaded_rate": { "type": "number" },
+            "uploaded_rate": { "type": "number" },
+        })),
+        "History": object(&["name", "resolution", "points"], json!({
+            "name": string(),
+            "resolution": { "type": "string", "enum": ["second", "minute"] },
+            "points": { "type": "array", "items": schema("HistoryPoint") },
+        })),
+        "ConnectionSerde": object(&["id", "listener", "client", "started", "last_active", "uploaded_bytes", "downloaded_bytes"], json!({
+            "id": integer(),
+            "listener": string(),
+            "client": string(),
+            "target": { "type": ["string", "null"] },
+            "resolved": { "type": ["string", "null"] },
+            "local": { "type": ["string", "null"] },
+            "started": string(),
+            "last_active": string(),
+            "uploaded_bytes": integer(),
+            "downloaded_bytes": integer(),
+        })),
+        "ConfigVersion": object(&["version", "at", "user", "action", "changes"], json!({
+            "version": integer(),
+            "at": string(),
+            "user": string(),
+            "action": string(),
+            "changes": strings(),
+        })),
+        "ConfigDiff": object(&["from", "to", "changes", "lines"], json!({
+            "from": integer(),
+            "to": integer(),
+            "changes": strings(),
+            "lines": { "type": "array", "items": { "type": "string" }, "description": "lines starting with `+`, `-` or a space" },
+        })),
+        "TokenInfo": object(&["id", "name", "role", "created_at", "created_by"], json!({
+            "id": string(),
+            "name": string(),
+            "role": role(),
+            "created_at": string(),
+            "created_by": string(),
+            "expires_at": string(),
+        })),
+        "NewToken": object(&["name"], json!({
+            "name": string(),
+            "role": role(),
+            "expires_in_secs": integer(),
+        })),
+        "CreatedToken": {
+            "allOf": [
+                schema("TokenInfo"),
+                object(&["token"], json!({ "token": { "type": "string", "description": "send as `Authorization: Bearer <token>`" } })),
+            ]
+        },
+        "AuditRecord": object(&["at", "user", "action", "changes"], json!({
+            "at": string(),
+            "user": string(),
+            "client": { "type": ["string", "null"] },
+            "action": string(),
+            "changes": strings(),
+            "diff": strings(),
+        })),
+        "AuditPage": object(&["total", "offset", "limit", "records"], json!({
+            "total": integer(),
+            "offset": integer(),
+            "limit": integer(),
+            "records": { "type": "array", "items": schema("AuditRecord") },
+        })),
+        "WhoAmI": object(&["username", "role"], json!({
+            "username": string(),
+            "role": role(),
+        })),
+        "ValidationError": object(&["path", "message"], json!({
+            "path": string(),
+            "message": string(),
+        })),
+        "ApiError": object(&["error"], json!({
+            "error": string(),
+            "details": { "type": "array", "items": schema("ValidationError") },
+        })),
+    })
+}
+
+/// The OpenAPI document of the admin API.
+pub fn document() -> Value {
+    let mut paths = Map::new();
+    for (method, path, operation) in operations() {
+        let item = paths.entry(path).or_insert_with(|| json!({}));
+        item[method] = operation.into_value();
+    }
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "Port Forwarder admin API",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "security": [{ "basicAuth": [] }, { "bearerAuth": [] }, { "clientCert": [] }],
+        "paths": paths,
+        "components": {
+            "securitySchemes": {
+                "basicAuth": { "type": "http", "scheme": "basic" },
+                "bearerAuth": { "type": "http", "scheme": "bearer", "description": "API token from `POST /apiserver/tokens`" },
+                "clientCert": { "type": "mutualTLS", "description": "client certificate listed in `admin_server.client_certs`" },
+            },
+            "schemas": schemas(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use super::*;
+    use crate::{
+        adminserver::{self, SimpleOperationResult},
+        config::Listener,
+        listener_stats::StatsSerde,
+    };
+
+    /// `/a/<name>` as `/a/{name}`
+    fn openapi_path(path: &str) -> String {
+        path.replace('<', "{").replace('>', "}")
+    }
+
+    #[test]
+    fn every_route_is_documented() {
+        let document = document();
+        let mut routes = BTreeSet::new();
+        for route in adminserver::routes() {
+            let path = route.uri.path().to_string();
+            if !path.starts_with("/apiserver/") {
+                continue;
+            }
+            let method = route.method.as_str().to_ascii_lowercase();
+            let path = openapi_path(&path);
+            let operation = &document["paths"][&path][&method];
+            assert!(operation.is_object(), "{method} {path} is not in the OpenAPI document");
+            let documented: BTreeSet<String> = operation["parameters"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter(|x| x["in"] == "query")
+                .map(|x| x["name"].as_str().unwrap_or_default().to_string())
+                .collect();
+            let mounted: BTreeSet<String> = route
+                .uri
+                .query()
+                .into_iter()
+                .flat_map(|x| x.split('&'))
+                .map(|x| x.trim_matches(['<', '>']).to_string())
+                .collect();
+            assert_eq!(documented, mounted, "query parameters of {method} {path}");
+            routes.insert((method, path));
+        }
+        for (path, item) in document["paths"].as_object().unwrap() {
+            for method in item.as_object().unwrap().keys() {
+                assert!(
+                    routes.contains(&(method.clone(), path.clone())),
+                    "{method} {path} is documented but not mounted"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_reference_resolves() {
+        let document = document();
+        let text = document.to_string();
+        let schemas = document["components"]["schemas"].as_object().unwrap();
+        for part in text.split("#/components/schemas/").skip(1) {
+            let name = &part[..part.find('"').unwrap()];
+            assert!(schemas.contains_key(name), "schema `{name}` is referenced but not defined");
+        }
+    }
+
+    /// The schema lists exactly the fields `value` serializes to.
+    fn assert_fields(name: &str, value: Value) {
+        let document = document();
+        let properties: HashSet<&String> = document["components"]["schemas"][name]["properties"]
+            .as_object()
+            .unwrap_or_else(|| panic!("schema `{name}` has no properties"))
+            .keys()
+            .collect();
+        let fields: HashSet<&String> = value.as_object().unwrap().keys().collect();
+        assert_eq!(properties, fields, "fields of `{name}`");
+    }
+
+    #[test]
+    fn schemas_match_types() {
+        let listener = Listener {
+            bind: "127.0.0.1:8080".into(),
+            targets: HashSet::from(["127.0.0.1:80".to_string()]),
+            enabled: false,
+            max_idle_time_ms: Some(1),
+            connect_timeout_ms: Some(1),
+            max_connection_lifetime_ms: Some(1),
+            buffer_size: Some(1),
+            health_check_timeout_ms: Some(1),
+            health_check_interval_ms: Some(1),
+        };
+        assert_fields("Listener", serde_json::to_value(listener).unwrap());
+        let stats = StatsSerde {
+            name: "web".into(),
+            total: 1,
+            active: 1,
+            downloaded_bytes: 1,
+            uploaded_bytes: 1,
+        };
+        assert_fields("StatsSerde", serde_json::to_value(stats).unwrap());
+        assert_fields("SimpleOperationResult", serde_json::to_value(SimpleOperationResult::ok(None)).unwrap());
+    }
+}
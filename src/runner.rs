@@ -1,4 +1,6 @@
-use crate::activetracker;
+use crate::accesslog::{self, CloseReason};
+use crate::activetracker::{self, ActiveConnection};
+use crate::events::{self, Event};
 use crate::controller::Controller;
 use crate::healthcheck;
 use crate::idletracker::IdleTracker;
@@ -7,7 +9,7 @@ use anyhow::Result;
 use lazy_static::lazy_static;
 use log::{info, warn, error};
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::{sync::Arc, time::Duration};
+use std::{sync::Arc, time::{Duration, Instant}};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::sync::mpsc;
 use tokio::{
@@ -19,7 +21,7 @@ use tokio::{
 };
 
 use crate::{
-    config::{Config, Listener},
+    config::{Config, Listener, ListenerSettings},
     listener_stats::ListenerStats,
     resolver,
 };
@@ -31,9 +33,31 @@ pub struct Runner {
     pub name: String,
     pub listener: Listener,
     pub config: Arc<RwLock<Config>>,
+    /// runs the accept loop. Cancelling it stops accepting new connections
+    pub acceptor: Arc<RwLock<Controller>>,
+    /// runs the accepted connections
     pub controller: Arc<RwLock<Controller>>,
 }
 
+/// Undoes the bookkeeping of an accepted connection. Runs on drop, so it also
+/// happens when the connection task is cancelled with its listener.
+struct ConnectionCleanup {
+    conn_id: u64,
+    stats: Arc<ListenerStats>,
+}
+
+impl Drop for ConnectionCleanup {
+    fn drop(&mut self) {
+        let conn_id = self.conn_id;
+        let new_active = self.stats.decrease_conn_count();
+        let new_total = self.stats.total_count();
+        tokio::spawn(async move {
+            activetracker::remove(conn_id).await;
+        });
+        info!("{conn_id} closing connection: active {new_active} total {new_total}");
+    }
+}
+
 fn id() -> u64 {
     COUNTER.fetch_add(1, Ordering::SeqCst) + 1
 }
@@ -42,12 +66,14 @@ impl Runner {
         name: String,
         listener: Listener,
         config: Arc<RwLock<Config>>,
+        acceptor: Arc<RwLock<Controller>>,
         root_context: Arc<RwLock<Controller>>,
     ) -> Runner {
         Runner {
             name,
             listener,
             config,
+            acceptor,
             controller: root_context,
         }
     }
@@ -58,10 +84,10 @@ impl Runner {
         let targets = self.listener.targets.clone();
         let mut targets_new = Vec::<String>::new();
         targets_new.extend(targets.clone());
-        let idle_timeout_ms = self.config.read().await.options.max_idle_time_ms;
-        let stats = ListenerStats::new(&self.name, idle_timeout_ms);
+        let settings = Arc::new(self.listener.settings(&self.config.read().await.options));
+        let stats = ListenerStats::new(&self.name, settings.max_idle_time_ms);
         let stats = Arc::new(stats);
-        let root_context_clone = Arc::clone(&self.controller);
+        let root_context_clone = Arc::clone(&self.acceptor);
         let controller_clone = Arc::clone(&self.controller);
         let stats_clone = Arc::clone(&stats);
         let (tx, mut rx) = mpsc::channel(1);
@@ -89,6 +115,7 @@ impl Runner {
                             name_clone,
                             inner_listener,
                             targets_new,
+                            settings,
                             stats_clone,
                             controller_clone,
                         ).await;
@@ -138,6 +165,7 @@ impl Runner {
         name: String,
         listener: TcpListener,
         targets: Vec<String>,
+        settings: Arc<ListenerSettings>,
         stats: Arc<ListenerStats>,
         controller: Arc<RwLock<Controller>>,
     ) -> Result<()> {
@@ -152,6 +180,7 @@ impl Runner {
             let mut controller_inner = controller_clone.write().await;
             let controller_clone_inner = Arc::clone(&controller);
             let name = Arc::clone(&name);
+            let settings = Arc::clone(&settings);
             controller_inner.spawn(async move {
                 let stats_local = Arc::clone(&stats);
                 
@@ -163,36 +192,52 @@ impl Runner {
                 let addr = addr.unwrap();
                 let new_active = stats_local.increase_conn_count();
                 let new_total = stats_local.total_count();
-                activetracker::put(conn_id, addr).await;
+                let conn = Arc::new(ActiveConnection::new(conn_id, &name, addr));
+                activetracker::put(Arc::clone(&conn)).await;
+                let cleanup = ConnectionCleanup {
+                    conn_id,
+                    stats: Arc::clone(&stats_local),
+                };
+                let mut access_log = accesslog::Guard::new(Arc::clone(&conn));
+                if events::has_subscribers() {
+                    events::publish(Event::ConnectionOpened(conn.to_serde().await));
+                }
                 info!("{conn_id} new connection from {addr:?} active {new_active} total {new_total}");
                 
                 let stats_local_clone = Arc::clone(&stats_local);
-                let rr = Self::worker(name, conn_id, target_vec_clone, socket, stats_local_clone, controller_clone_inner).await;
-                if rr.is_err() {
-                    let err = rr.err().unwrap();
-                    warn!("{conn_id} connection error: {err}");
+                let rr = Self::worker(name, conn, target_vec_clone, settings, socket, stats_local_clone, controller_clone_inner).await;
+                match rr {
+                    Ok(reason) => {
+                        access_log.close(reason, None);
+                    }
+                    Err(err) => {
+                        warn!("{conn_id} connection error: {err}");
+                        access_log.close(CloseReason::Error, Some(format!("{err}")));
+                    }
                 }
-                let new_active = stats_local.decrease_conn_count();
-                let new_total = stats_local.total_count();
-                activetracker::remove(conn_id).await;
-                info!("{conn_id} closing connection: active {new_active} total {new_total}");
+                drop(access_log);
+                drop(cleanup);
             }).await;
         }
     }
 
     async fn worker(
         name: Arc<String>,
-        conn_id: u64,
+        conn: Arc<ActiveConnection>,
         targets_all: Arc<Vec<String>>,
+        settings: Arc<ListenerSettings>,
         socket: TcpStream,
         context: Arc<ListenerStats>,
         controller: Arc<RwLock<Controller>>,
-    ) -> Result<()> {
+    ) -> Result<CloseReason> {
         // TODO
         // Selecting from targets_vec, consulting health check
 
         //let target = targets_vec.get(0).unwrap().clone();
-        let (ok, target) = healthcheck::select(&name, &targets_all).await;
+        let conn_id = conn.id;
+        let (ok, target) = healthcheck::select(&name, &targets_all)
+            .await
+            .ok_or_else(|| anyhow!("listener {name} has no targets"))?;
         if !ok {
             info!("{conn_id} selected {target} to connect (failed one)");
         } else {
@@ -210,16 +255,19 @@ impl Runner {
                 info!("{conn_id} `{target}` did not resolve. using original `{target}`")
             }
         }
+        conn.set_target(target, &resolved).await;
         let connect_future = TcpStream::connect(&resolved);
-        let r_stream = tokio::time::timeout(Duration::from_secs(5), connect_future).await??;
+        let r_stream = tokio::time::timeout(settings.connect_timeout, connect_future).await??;
         let local_addr = r_stream.local_addr()?;
+        if conn.is_killed() {
+            return Err(anyhow!("connection killed before it was established"));
+        }
+        conn.set_local(local_addr).await;
         info!("{conn_id} connected to `{resolved}` via {local_addr:?}");
         let (lr, lw) = tokio::io::split(socket);
         let (rr, rw) = tokio::io::split(r_stream);
-        let idle_tracker = Arc::new(Mutex::new(IdleTracker::new(context.idle_timeout_ms)));
+        let idle_tracker = Arc::new(Mutex::new(IdleTracker::new(settings.max_idle_time_ms)));
         let context_clone = Arc::clone(&context);
-        let uploaded = Arc::new(AtomicU64::new(0));
-        let downloaded = Arc::new(AtomicU64::new(0));
         let controller_clone = Arc::clone(&controller);
         let jh1 = Self::pipe(
             conn_id,
@@ -227,8 +275,9 @@ impl Runner {
             rw,
             context_clone,
             Arc::clone(&idle_tracker),
+            settings.buffer_size,
             true,
-            Arc::clone(&uploaded),
+            Arc::clone(&conn),
             controller_clone,
         )
         .await;
@@ -240,11 +289,14 @@ impl Runner {
             lw,
             context_clone,
             Arc::clone(&idle_tracker),
+            settings.buffer_size,
             false,
-            Arc::clone(&downloaded),
+            Arc::clone(&conn),
             controller_clone,
         )
         .await;
+        conn.attach(jh1.abort_handle()).await;
+        conn.attach(jh2.abort_handle()).await;
 
         let controller_clone = Arc::clone(&controller);
         let jh = Self::run_idle_tracker(
@@ -252,28 +304,31 @@ impl Runner {
             jh1,
             jh2,
             Arc::clone(&idle_tracker),
+            settings.max_connection_lifetime.map(|x| Instant::now() + x),
             controller_clone,
         )
         .await;
-        let _ = jh.await;
-        let uploaded_total = uploaded.load(Ordering::SeqCst);
-        let downloaded_total = downloaded.load(Ordering::SeqCst);
+        let reason = jh.await.ok().flatten().unwrap_or(CloseReason::Cancelled);
+        let uploaded_total = conn.uploaded_bytes_count();
+        let downloaded_total = conn.downloaded_bytes_count();
         info!("{conn_id} end uploaded {uploaded_total} downloaded {downloaded_total}");
-        Ok(())
+        Ok(reason)
     }
     async fn run_idle_tracker(
         conn_id: u64,
-        jh1: JoinHandle<Option<()>>,
-        jh2: JoinHandle<Option<()>>,
+        jh1: JoinHandle<Option<CloseReason>>,
+        jh2: JoinHandle<Option<CloseReason>>,
         idletracker: Arc<Mutex<IdleTracker>>,
+        deadline: Option<Instant>,
         root_context: Arc<RwLock<Controller>>,
-    ) -> JoinHandle<Option<()>> {
+    ) -> JoinHandle<Option<CloseReason>> {
         root_context
             .write()
             .await
             .spawn(async move {
                 loop {
                     if jh1.is_finished() || jh2.is_finished() {
+                        let upload_finished = jh1.is_finished();
                         if !jh1.is_finished() {
                             info!("{conn_id} abort upload as download stopped");
                             jh1.abort();
@@ -282,7 +337,9 @@ impl Runner {
                             info!("{conn_id} abort download as upload stopped");
                             jh2.abort();
                         }
-                        break;
+                        // the side that finished first decides why the connection closed
+                        let first = if upload_finished { jh1 } else { jh2 };
+                        return first.await.ok().flatten().unwrap_or(CloseReason::Cancelled);
                     }
                     if idletracker.lock().await.is_expired() {
                         info!("{conn_id} idle time out. aborting.");
@@ -292,7 +349,17 @@ impl Runner {
                         if !jh2.is_finished() {
                             jh2.abort();
                         }
-                        break;
+                        return CloseReason::IdleTimeout;
+                    }
+                    if deadline.is_some_and(|x| Instant::now() >= x) {
+                        info!("{conn_id} max lifetime reached. aborting.");
+                        if !jh1.is_finished() {
+                            jh1.abort();
+                        }
+                        if !jh2.is_finished() {
+                            jh2.abort();
+                        }
+                        return CloseReason::MaxLifetime;
                     }
                     sleep(Duration::from_millis(500)).await;
                 }
@@ -305,10 +372,11 @@ impl Runner {
         writer_i: WriteHalf<TcpStream>,
         context: Arc<ListenerStats>,
         idletracker: Arc<Mutex<IdleTracker>>,
+        buffer_size: usize,
         is_upload: bool,
-        counter: Arc<AtomicU64>,
+        conn: Arc<ActiveConnection>,
         controller: Arc<RwLock<Controller>>,
-    ) -> JoinHandle<Option<()>> {
+    ) -> JoinHandle<Option<CloseReason>> {
         let mut reader = reader_i;
         let mut writer = writer_i;
         let direction = match is_upload {
@@ -320,12 +388,17 @@ impl Runner {
             .write()
             .await
             .spawn(async move {
-                let mut buf = vec![0; 4096];
-
+                let mut buf = vec![0; buffer_size];
+                let eof = match is_upload {
+                    true => CloseReason::ClientEof,
+                    false => CloseReason::ServerEof,
+                };
+                let reason;
                 loop {
                     let nr = reader.read(&mut buf).await;
                     match nr {
                         Err(_) => {
+                            reason = CloseReason::Error;
                             break;
                         }
                         _ => {}
@@ -333,19 +406,22 @@ impl Runner {
 
                     let n = nr.unwrap();
                     if n == 0 {
+                        reason = eof;
                         break;
                     }
 
                     let write_result = writer.write_all(&buf[0..n]).await;
                     match write_result {
                         Err(_) => {
+                            reason = CloseReason::Error;
                             break;
                         }
                         Ok(_) => {
-                            counter.fetch_add(n as u64, Ordering::SeqCst);
                             if is_upload {
+                                conn.add_uploaded_bytes(n as u64);
                                 context.increase_uploaded_bytes(n);
                             } else {
+                                conn.add_downloaded_bytes(n as u64);
                                 context.increase_downloaded_bytes(n);
                             }
                             idletracker.lock().await.mark();
@@ -353,6 +429,7 @@ impl Runner {
                     }
                 }
                 info!("{conn_id} {direction} ended");
+                reason
             })
             .await
     }
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+use log::{error, info, warn};
+use tokio::sync::Notify;
+
+use crate::{adminserver, manager, statestore};
+
+lazy_static! {
+    static ref TERMINATED: Notify = Notify::new();
+}
+
+/// Resolves once a termination signal was handled and everything is shut down.
+pub async fn terminated() {
+    TERMINATED.notified().await;
+}
+
+/// Stops accepting, lets connections drain, saves state and stops the admin server.
+async fn shutdown(signal: &str) {
+    info!("{signal} received. shutting down");
+    let drain_timeout = manager::drain_timeout().await;
+    manager::stop().await;
+    // drains end at their deadline, give them a moment to report back
+    if !manager::wait_drained(drain_timeout + Duration::from_secs(1)).await {
+        warn!("connections still draining after {drain_timeout:?}");
+    }
+    if let Err(cause) = statestore::save().await {
+        error!("failed to save state on shutdown: {cause}");
+    }
+    adminserver::shutdown().await;
+    info!("shutdown complete");
+    TERMINATED.notify_one();
+}
+
+async fn reload() {
+    info!("SIGHUP received. reloading config");
+    match adminserver::reload("signal").await {
+        Ok(result) => {
+            for (name, inner_result) in result {
+                if let Err(cause) = inner_result {
+                    warn!("reload: listener {name} failed to start: {cause}");
+                }
+            }
+            info!("reload: config applied");
+        }
+        Err(cause) => {
+            error!("reload: config not applied: {cause}");
+        }
+    }
+}
+
+/// SIGHUP reloads and applies the config file. SIGTERM and SIGINT shut down
+/// gracefully; a second one while draining exits right away.
+#[cfg(unix)]
+pub fn start() {
+    use tokio::signal::unix::{signal, SignalKind};
+    tokio::spawn(async move {
+        let hup = signal(SignalKind::hangup());
+        let term = signal(SignalKind::terminate());
+        let int = signal(SignalKind::interrupt());
+        let (mut hup, mut term, mut int) = match (hup, term, int) {
+            (Ok(hup), Ok(term), Ok(int)) => (hup, term, int),
+            _ => {
+                error!("unable to install signal handlers");
+                return;
+            }
+        };
+        let mut shutting_down = false;
+        loop {
+            let name = tokio::select! {
+                _ = hup.recv() => "SIGHUP",
+                _ = term.recv() => "SIGTERM",
+                _ = int.recv() => "SIGINT",
+            };
+            if name == "SIGHUP" {
+                if !shutting_down {
+                    reload().await;
+                }
+                continue;
+            }
+            if shutting_down {
+                warn!("{name} received again. exiting without waiting for connections");
+                std::process::exit(1);
+            }
+            shutting_down = true;
+            tokio::spawn(shutdown(name));
+        }
+    });
+}
+
+#[cfg(not(unix))]
+pub fn start() {
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            shutdown("ctrl-c").await;
+        }
+    });
+}
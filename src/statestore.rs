@@ -0,0 +1,176 @@
+use std::{collections::HashMap, path::PathBuf, sync::Arc, time::Duration};
+
+use anyhow::Result;
+use lazy_static::lazy_static;
+use log::{error, info, warn};
+use tokio::{fs, io::AsyncWriteExt, sync::RwLock, time::sleep};
+
+use crate::{
+    cli,
+    config::Config,
+    listener_stats::{ListenerStats, StatsSerde},
+    manager,
+};
+
+const SAVE_INTERVAL: Duration = Duration::from_secs(10);
+
+lazy_static! {
+    /// Counters of listeners that are not running right now. They are added back
+    /// when a listener of the same name starts again.
+    static ref CARRIED: Arc<RwLock<HashMap<String, StatsSerde>>> = Arc::new(RwLock::new(HashMap::new()));
+    static ref STATE_FILE: Arc<RwLock<Option<PathBuf>>> = Arc::new(RwLock::new(None));
+}
+
+/// Remembers the counters of listeners that stopped, adding to what is already carried.
+pub async fn carry(listeners: &[Arc<ListenerStats>]) {
+    let mut w = CARRIED.write().await;
+    for stats in listeners {
+        let mut current = StatsSerde::from(stats);
+        current.active = 0;
+        match w.get_mut(&stats.name) {
+            Some(existing) => existing.add(&current),
+            None => {
+                w.insert(stats.name.clone(), current);
+            }
+        }
+    }
+}
+
+/// Adds carried counters, if any, to a freshly started listener.
+pub async fn restore(stats: &ListenerStats) {
+    let carried = CARRIED.write().await.remove(&stats.name);
+    if let Some(carried) = carried {
+        info!(
+            "restoring counters of `{}`: total {} uploaded {} downloaded {}",
+            stats.name, carried.total, carried.uploaded_bytes, carried.downloaded_bytes
+        );
+        stats.restore(&carried);
+    }
+}
+
+/// Zeroes counters of one listener, or of all listeners when `name` is `None`.
+pub async fn reset(name: Option<&str>) {
+    {
+        let mut w = CARRIED.write().await;
+        match name {
+            Some(name) => {
+                w.remove(name);
+            }
+            None => w.clear(),
+        }
+    }
+    manager::reset_stats(name).await;
+    if let Err(cause) = save().await {
+        error!("failed to save state after reset: {cause}");
+    }
+}
+
+/// Forgets carried counters of listeners that are not in `config` any more.
+pub async fn forget_removed(config: &Config) {
+    let removed: Vec<String> = {
+        let mut w = CARRIED.write().await;
+        let removed = w.keys().filter(|x| !config.listeners.contains_key(*x)).cloned().collect();
+        w.retain(|name, _| config.listeners.contains_key(name));
+        removed
+    };
+    if removed.is_empty() {
+        return;
+    }
+    info!("dropping counters of removed listeners: {}", removed.join(", "));
+    if let Err(cause) = save().await {
+        error!("failed to save state after dropping counters: {cause}");
+    }
+}
+
+/// Carried counters plus live ones.
+async fn snapshot() -> HashMap<String, StatsSerde> {
+    let mut result = CARRIED.read().await.clone();
+    for (name, stats) in manager::get_listener_stats().await {
+        match result.get_mut(&name) {
+            Some(existing) => existing.add(&stats),
+            None => {
+                result.insert(name, stats);
+            }
+        }
+    }
+    for stats in result.values_mut() {
+        stats.active = 0;
+    }
+    result
+}
+
+pub async fn save() -> Result<()> {
+    let path = STATE_FILE.read().await.clone();
+    let path = match path {
+        Some(path) => path,
+        None => return Ok(()),
+    };
+    let content = serde_json::to_string_pretty(&snapshot().await)?;
+    let mut temp = path.clone().into_os_string();
+    temp.push(".tmp");
+    let mut file = fs::File::create(&temp).await?;
+    file.write_all(content.as_bytes()).await?;
+    file.sync_all().await?;
+    fs::rename(&temp, &path).await?;
+    Ok(())
+}
+
+/// Loads counters saved by a previous process and starts saving them periodically.
+pub async fn init(config: &Config) {
+    let path = cli::state_path(config.options.state_file.as_deref(), "state.json");
+    *STATE_FILE.write().await = path.clone();
+    let path = match path {
+        Some(path) => path,
+        None => {
+            info!("no state file configured. counters will not survive a process restart");
+            return;
+        }
+    };
+    match fs::read_to_string(&path).await {
+        Ok(content) => match serde_json::from_str::<HashMap<String, StatsSerde>>(&content) {
+            Ok(saved) => {
+                info!("loaded counters of {} listeners from `{}`", saved.len(), path.display());
+                CARRIED.write().await.extend(saved);
+            }
+            Err(cause) => {
+                warn!("ignoring unreadable state file `{}`: {cause}", path.display());
+            }
+        },
+        Err(cause) => {
+            info!("no previous state loaded from `{}`: {cause}", path.display());
+        }
+    }
+    tokio::spawn(async move {
+        loop {
+            sleep(SAVE_INTERVAL).await;
+            if let Err(cause) = save().await {
+                error!("failed to save state: {cause}");
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Listener;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn applying_a_config_drops_counters_of_removed_listeners() {
+        let _serial = manager::tests::SERIAL.lock().await;
+        let kept = Arc::new(ListenerStats::new("kept", 0));
+        let removed = Arc::new(ListenerStats::new("removed", 0));
+        kept.increase_conn_count();
+        removed.increase_conn_count();
+        carry(&[kept, removed]).await;
+        let config = Config {
+            listeners: [("kept".to_string(), Listener::default())].into_iter().collect(),
+            ..Default::default()
+        };
+        forget_removed(&config).await;
+        let carried = CARRIED.read().await;
+        assert_eq!(carried.get("kept").map(|x| x.total), Some(1));
+        assert!(!carried.contains_key("removed"));
+    }
+}
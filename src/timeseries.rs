@@ -0,0 +1,253 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::{DateTime, Local};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use tokio::{sync::RwLock, time::sleep};
+
+use crate::{listener_stats::StatsSerde, manager};
+
+/// 1 second samples for an hour
+const FINE_CAPACITY: usize = 3600;
+/// 1 minute samples for a day
+const COARSE_CAPACITY: usize = 1440;
+const COARSE_EVERY: u64 = 60;
+
+lazy_static! {
+    static ref SERIES: Arc<RwLock<HashMap<String, Series>>> = Arc::new(RwLock::new(HashMap::new()));
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Resolution {
+    Second,
+    Minute,
+}
+
+impl Resolution {
+    pub fn parse(input: &str) -> Option<Self> {
+        match input.to_ascii_lowercase().as_str() {
+            "second" | "s" | "1s" => Some(Resolution::Second),
+            "minute" | "m" | "1m" => Some(Resolution::Minute),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Sample {
+    at: DateTime<Local>,
+    stats: StatsSerde,
+}
+
+#[derive(Default)]
+struct Series {
+    fine: VecDeque<Sample>,
+    coarse: VecDeque<Sample>,
+}
+
+impl Series {
+    fn push(&mut self, sample: Sample, coarse: bool) {
+        if coarse {
+            if self.coarse.len() >= COARSE_CAPACITY {
+                self.coarse.pop_front();
+            }
+            self.coarse.push_back(sample.clone());
+        }
+        if self.fine.len() >= FINE_CAPACITY {
+            self.fine.pop_front();
+        }
+        self.fine.push_back(sample);
+    }
+
+    fn last_seen(&self) -> Option<DateTime<Local>> {
+        self.fine.back().map(|x| x.at)
+    }
+}
+
+/// One point of a listener's history. Rates are per second, derived from the previous point.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HistoryPoint {
+    pub at: String,
+    pub total: usize,
+    pub active: usize,
+    pub downloaded_bytes: usize,
+    pub uploaded_bytes: usize,
+    pub connection_rate: f64,
+    pub downloaded_rate: f64,
+    pub uploaded_rate: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct History {
+    pub name: String,
+    pub resolution: Resolution,
+    pub points: Vec<HistoryPoint>,
+}
+
+fn rate(current: usize, previous: usize, seconds: f64) -> f64 {
+    if seconds <= 0.0 {
+        return 0.0;
+    }
+    current.saturating_sub(previous) as f64 / seconds
+}
+
+fn to_points(samples: &VecDeque<Sample>, limit: usize) -> Vec<HistoryPoint> {
+    let skip = samples.len().saturating_sub(limit);
+    let mut result = Vec::new();
+    let mut previous: Option<&Sample> = if skip > 0 { samples.get(skip - 1) } else { None };
+    for sample in samples.iter().skip(skip) {
+        let stats = &sample.stats;
+        let point = match previous {
+            Some(prev) => {
+                let seconds = (sample.at - prev.at).num_milliseconds() as f64 / 1000.0;
+                HistoryPoint {
+                    at: sample.at.to_rfc3339(),
+                    total: stats.total,
+                    active: stats.active,
+                    downloaded_bytes: stats.downloaded_bytes,
+                    uploaded_bytes: stats.uploaded_bytes,
+                    connection_rate: rate(stats.total, prev.stats.total, seconds),
+                    downloaded_rate: rate(stats.downloaded_bytes, prev.stats.downloaded_bytes, seconds),
+                    uploaded_rate: rate(stats.uploaded_bytes, prev.stats.uploaded_bytes, seconds),
+                }
+            }
+            None => HistoryPoint {
+                at: sample.at.to_rfc3339(),
+                total: stats.total,
+                active: stats.active,
+                downloaded_bytes: stats.downloaded_bytes,
+                uploaded_bytes: stats.uploaded_bytes,
+                connection_rate: 0.0,
+                downloaded_rate: 0.0,
+                uploaded_rate: 0.0,
+            },
+        };
+        result.push(point);
+        previous = Some(sample);
+    }
+    result
+}
+
+/// History of a single listener, at most `limit` most recent points.
+pub async fn get_history(name: &str, resolution: Resolution, limit: usize) -> Option<History> {
+    let r = SERIES.read().await;
+    let series = r.get(name)?;
+    let samples = match resolution {
+        Resolution::Second => &series.fine,
+        Resolution::Minute => &series.coarse,
+    };
+    Some(History {
+        name: name.into(),
+        resolution,
+        points: to_points(samples, limit),
+    })
+}
+
+pub async fn get_names() -> Vec<String> {
+    let r = SERIES.read().await;
+    let mut result: Vec<String> = r.keys().cloned().collect();
+    result.sort();
+    result
+}
+
+/// Starts the background sampler. Listeners that disappear keep their history
+/// until it is older than the coarse window, so restarts don't wipe it.
+pub fn start() {
+    tokio::spawn(async move {
+        let mut tick: u64 = 0;
+        loop {
+            sleep(Duration::from_millis(1000)).await;
+            tick += 1;
+            let coarse = tick.is_multiple_of(COARSE_EVERY);
+            let now = Local::now();
+            let current = manager::get_listener_stats().await;
+            let mut w = SERIES.write().await;
+            for (name, stats) in current {
+                let sample = Sample { at: now, stats };
+                w.entry(name).or_default().push(sample, coarse);
+            }
+            let window = chrono::Duration::minutes(COARSE_CAPACITY as i64);
+            w.retain(|_, series| series.last_seen().is_some_and(|x| now - x < window));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(start: DateTime<Local>, second: i64, total: usize, downloaded: usize) -> Sample {
+        Sample {
+            at: start + chrono::Duration::seconds(second),
+            stats: StatsSerde {
+                name: "a".into(),
+                total,
+                active: 0,
+                downloaded_bytes: downloaded,
+                uploaded_bytes: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn series_keeps_the_most_recent_samples() {
+        let start = Local::now();
+        let mut series = Series::default();
+        for second in 0..(FINE_CAPACITY + 5) {
+            let second = second as i64;
+            series.push(sample(start, second, second as usize, 0), second % COARSE_EVERY as i64 == 0);
+        }
+        assert_eq!(series.fine.len(), FINE_CAPACITY);
+        assert_eq!(series.fine.front().unwrap().stats.total, 5);
+        assert_eq!(series.fine.back().unwrap().stats.total, FINE_CAPACITY + 4);
+        assert_eq!(series.coarse.len(), (FINE_CAPACITY + 5).div_ceil(COARSE_EVERY as usize));
+        assert_eq!(series.last_seen(), Some(start + chrono::Duration::seconds(FINE_CAPACITY as i64 + 4)));
+
+        for minute in 0..(COARSE_CAPACITY + 3) {
+            series.push(sample(start, minute as i64 * 60, minute, 0), true);
+        }
+        assert_eq!(series.coarse.len(), COARSE_CAPACITY);
+        assert_eq!(series.coarse.back().unwrap().stats.total, COARSE_CAPACITY + 2);
+    }
+
+    #[test]
+    fn rates_are_per_second_from_the_previous_point() {
+        let start = Local::now();
+        let samples = VecDeque::from([
+            sample(start, 0, 10, 0),
+            sample(start, 2, 14, 2000),
+            sample(start, 4, 14, 3000),
+        ]);
+        let points = to_points(&samples, usize::MAX);
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0].connection_rate, 0.0);
+        assert_eq!(points[1].connection_rate, 2.0);
+        assert_eq!(points[1].downloaded_rate, 1000.0);
+        assert_eq!(points[2].connection_rate, 0.0);
+        assert_eq!(points[2].downloaded_rate, 500.0);
+
+        // the first returned point still has a rate when older points are cut off
+        let points = to_points(&samples, 1);
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].downloaded_rate, 500.0);
+    }
+
+    #[test]
+    fn rates_do_not_go_negative() {
+        assert_eq!(rate(5, 10, 1.0), 0.0);
+        assert_eq!(rate(10, 5, 0.0), 0.0);
+        assert_eq!(rate(10, 5, 2.0), 2.5);
+    }
+
+    #[test]
+    fn resolution_names() {
+        assert_eq!(Resolution::parse("Minute"), Some(Resolution::Minute));
+        assert_eq!(Resolution::parse("1s"), Some(Resolution::Second));
+        assert_eq!(Resolution::parse("hour"), None);
+    }
+}
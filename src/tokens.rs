@@ -0,0 +1,217 @@
+use std::{path::PathBuf, sync::Arc};
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Local};
+use lazy_static::lazy_static;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::{fs, io::AsyncWriteExt, sync::RwLock};
+
+use crate::{
+    auth::{self, Role},
+    cli,
+    config::Config,
+};
+
+/// Prefix of every token, so they are easy to recognize in scripts and logs
+const PREFIX: &str = "pf_";
+
+lazy_static! {
+    static ref TOKENS: Arc<RwLock<Vec<Token>>> = Arc::new(RwLock::new(Vec::new()));
+    static ref TOKENS_FILE: Arc<RwLock<PathBuf>> = Arc::new(RwLock::new(PathBuf::from("tokens.json")));
+}
+
+/// What is shown about a token. The token itself is only returned when it is created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenInfo {
+    pub id: String,
+    pub name: String,
+    pub role: Role,
+    pub created_at: String,
+    pub created_by: String,
+    /// Never expires when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+impl TokenInfo {
+    pub fn is_expired(&self) -> bool {
+        match &self.expires_at {
+            Some(at) => match DateTime::parse_from_rfc3339(at) {
+                Ok(at) => at <= Local::now(),
+                Err(_) => true,
+            },
+            None => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Token {
+    #[serde(flatten)]
+    info: TokenInfo,
+    /// sha256 of the token, hex encoded
+    hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewToken {
+    pub name: String,
+    #[serde(default)]
+    pub role: Role,
+    /// Seconds until the token expires. Never expires when unset
+    #[serde(default)]
+    pub expires_in_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedToken {
+    /// Send as `Authorization: Bearer <token>`
+    pub token: String,
+    #[serde(flatten)]
+    pub info: TokenInfo,
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn hash(token: &str) -> String {
+    hex(&Sha256::digest(token.as_bytes()))
+}
+
+async fn save(tokens: &[Token]) -> Result<()> {
+    let path = TOKENS_FILE.read().await.clone();
+    let content = serde_json::to_string_pretty(tokens)?;
+    let mut temp = path.clone().into_os_string();
+    temp.push(".tmp");
+    let mut file = fs::File::create(&temp).await?;
+    file.write_all(content.as_bytes()).await?;
+    file.sync_all().await?;
+    fs::rename(&temp, &path).await?;
+    Ok(())
+}
+
+/// All tokens, oldest first.
+pub async fn list() -> Vec<TokenInfo> {
+    TOKENS.read().await.iter().map(|x| x.info.clone()).collect()
+}
+
+pub async fn create(request: &NewToken, created_by: &str) -> Result<CreatedToken> {
+    if request.name.trim().is_empty() {
+        return Err(anyhow!("name must not be empty"));
+    }
+    let expires_at = match request.expires_in_secs {
+        Some(secs) => {
+            let secs = i64::try_from(secs).map_err(|_| anyhow!("expires_in_secs is too large"))?;
+            Some((Local::now() + Duration::seconds(secs)).to_rfc3339())
+        }
+        None => None,
+    };
+    let token = format!("{PREFIX}{}", hex(&rand::random::<[u8; 32]>()));
+    let info = TokenInfo {
+        id: hex(&rand::random::<[u8; 6]>()),
+        name: request.name.clone(),
+        role: request.role,
+        created_at: Local::now().to_rfc3339(),
+        created_by: created_by.into(),
+        expires_at,
+    };
+    let mut w = TOKENS.write().await;
+    w.push(Token {
+        info: info.clone(),
+        hash: hash(&token),
+    });
+    save(&w).await?;
+    info!("api token `{}` ({}, {:?}) created by `{created_by}`", info.name, info.id, info.role);
+    Ok(CreatedToken { token, info })
+}
+
+/// Removes a token. Returns whether it existed.
+pub async fn revoke(id: &str, revoked_by: &str) -> Result<bool> {
+    let mut w = TOKENS.write().await;
+    let before = w.len();
+    w.retain(|x| x.info.id != id);
+    if w.len() == before {
+        return Ok(false);
+    }
+    save(&w).await?;
+    info!("api token {id} revoked by `{revoked_by}`");
+    Ok(true)
+}
+
+/// The token presented as a bearer credential, unless it is unknown or expired.
+pub async fn verify(token: &str) -> Option<TokenInfo> {
+    if !token.starts_with(PREFIX) {
+        return None;
+    }
+    let presented = hash(token);
+    let r = TOKENS.read().await;
+    let found = r.iter().find(|x| auth::constant_time_eq(x.hash.as_bytes(), presented.as_bytes()))?;
+    if found.info.is_expired() {
+        warn!("expired api token `{}` ({}) was used", found.info.name, found.info.id);
+        return None;
+    }
+    Some(found.info.clone())
+}
+
+/// Loads the tokens saved by previous processes.
+pub async fn init(config: &Config) {
+    let path = cli::state_path(config.options.tokens_file.as_deref(), "tokens.json")
+        .unwrap_or_else(|| PathBuf::from("tokens.json"));
+    *TOKENS_FILE.write().await = path.clone();
+    match fs::read_to_string(&path).await {
+        Ok(content) => match serde_json::from_str::<Vec<Token>>(&content) {
+            Ok(saved) => {
+                info!("loaded {} api tokens from `{}`", saved.len(), path.display());
+                *TOKENS.write().await = saved;
+            }
+            Err(cause) => warn!("ignoring unreadable tokens file `{}`: {cause}", path.display()),
+        },
+        Err(cause) => info!("no api tokens loaded from `{}`: {cause}", path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn tokens_authenticate_until_expired_or_revoked() {
+        let path = std::env::temp_dir().join(format!("pf-tokens-{}.json", std::process::id()));
+        *TOKENS_FILE.write().await = path.clone();
+        let request = |name: &str, role: Role, expires_in_secs: Option<u64>| NewToken {
+            name: name.into(),
+            role,
+            expires_in_secs,
+        };
+
+        let created = create(&request("deploy", Role::Operator, None), "alice").await.unwrap();
+        assert!(created.token.starts_with(PREFIX));
+        let found = verify(&created.token).await.unwrap();
+        assert_eq!((found.id.as_str(), found.role), (created.info.id.as_str(), Role::Operator));
+        assert_eq!(found.created_by, "alice");
+        // only the hash is kept
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(!saved.contains(&created.token));
+        assert!(saved.contains(&hash(&created.token)));
+
+        assert!(verify(&format!("{}0", created.token)).await.is_none());
+        assert!(verify(created.token.trim_start_matches(PREFIX)).await.is_none());
+        assert!(create(&request(" ", Role::Viewer, None), "alice").await.is_err());
+
+        let expired = create(&request("old", Role::Admin, Some(0)), "alice").await.unwrap();
+        assert!(expired.info.is_expired());
+        assert!(verify(&expired.token).await.is_none());
+        let later = create(&request("later", Role::Viewer, Some(3600)), "alice").await.unwrap();
+        assert!(verify(&later.token).await.is_some());
+
+        assert!(revoke(&created.info.id, "bob").await.unwrap());
+        assert!(verify(&created.token).await.is_none());
+        assert!(!revoke(&created.info.id, "bob").await.unwrap());
+        let names: Vec<String> = list().await.into_iter().map(|x| x.name).collect();
+        assert_eq!(names, vec!["old", "later"]);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
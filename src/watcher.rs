@@ -0,0 +1,205 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use chrono::Local;
+use lazy_static::lazy_static;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::{fs, sync::RwLock, time::sleep};
+
+use crate::{adminserver, config::Config, manager};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(1000);
+
+lazy_static! {
+    static ref STATUS: Arc<RwLock<WatchStatus>> = Arc::new(RwLock::new(Default::default()));
+    static ref BASELINE: Arc<RwLock<Fingerprint>> = Arc::new(RwLock::new(HashMap::new()));
+}
+
+/// Modification time and size of every watched file
+type Fingerprint = HashMap<String, Option<(std::time::SystemTime, u64)>>;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WatchStatus {
+    pub watching: bool,
+    pub files: Vec<String>,
+    pub last_change: Option<String>,
+    pub last_applied: Option<String>,
+    pub last_error: Option<String>,
+}
+
+pub async fn get_status() -> WatchStatus {
+    STATUS.read().await.clone()
+}
+
+async fn fingerprint(files: &[String]) -> Fingerprint {
+    let mut result = HashMap::new();
+    for file in files {
+        let meta = fs::metadata(file).await.ok();
+        let value = meta.and_then(|m| m.modified().ok().map(|t| (t, m.len())));
+        result.insert(file.clone(), value);
+    }
+    result
+}
+
+/// The config file and everything it includes. Just the config file when it
+/// can't be loaded.
+async fn watched_files() -> Vec<String> {
+    let config_file = adminserver::config_file().await;
+    match Config::load_file(&config_file).await {
+        Ok(config) => config.watched_paths(),
+        Err(_) => vec![config_file],
+    }
+}
+
+/// Takes the current state of the watched files as known, so changes made
+/// by the admin server itself don't trigger an automatic apply.
+pub async fn acknowledge() {
+    let files = watched_files().await;
+    *BASELINE.write().await = fingerprint(&files).await;
+    STATUS.write().await.files = files;
+}
+
+/// Waits until `files` stop changing for `debounce`. Returns their final state.
+async fn settle(files: &[String], mut current: Fingerprint, debounce: Duration) -> Fingerprint {
+    loop {
+        sleep(debounce).await;
+        let next = fingerprint(files).await;
+        if next == current {
+            return current;
+        }
+        current = next;
+    }
+}
+
+async fn apply_change(config_file: &str) {
+    let now = Local::now().to_rfc3339();
+    let problem = match Config::load_file(config_file).await {
+        Ok(config) => config.check().err().map(|e| e.to_string()),
+        Err(cause) => Some(format!("{cause}")),
+    };
+    if let Some(message) = problem {
+        warn!("config watcher: `{config_file}` changed but is invalid, keeping the running config: {message}");
+        STATUS.write().await.last_error = Some(message);
+        return;
+    }
+    let result = adminserver::reload("watcher").await.map_err(|e| format!("{e}"));
+    match result {
+        Ok(result) => {
+            for (name, inner_result) in result {
+                if let Err(cause) = inner_result {
+                    warn!("config watcher: listener {name} failed to start: {cause}");
+                }
+            }
+            info!("config watcher: `{config_file}` applied");
+            let mut w = STATUS.write().await;
+            w.last_applied = Some(now);
+            w.last_error = None;
+        }
+        Err(cause) => {
+            error!("config watcher: applying `{config_file}` failed: {cause}");
+            STATUS.write().await.last_error = Some(cause);
+        }
+    }
+}
+
+/// Polls the config file and the files it includes, and applies them after it has been stable for the
+/// debounce period. Only active while `options.watch_config` is set.
+pub fn start() {
+    tokio::spawn(async move {
+        acknowledge().await;
+        loop {
+            sleep(POLL_INTERVAL).await;
+            let options = manager::get_config().await.options;
+            if STATUS.read().await.watching != options.watch_config {
+                info!("config watcher: watching is now {}", options.watch_config);
+                STATUS.write().await.watching = options.watch_config;
+                acknowledge().await;
+            }
+            if !options.watch_config {
+                continue;
+            }
+            let files = STATUS.read().await.files.clone();
+            let current = fingerprint(&files).await;
+            if current == *BASELINE.read().await {
+                continue;
+            }
+            info!("config watcher: change detected in {files:?}");
+            STATUS.write().await.last_change = Some(Local::now().to_rfc3339());
+            let current = settle(&files, current, Duration::from_millis(options.watch_debounce_ms())).await;
+            *BASELINE.write().await = current;
+            apply_change(&adminserver::config_file().await).await;
+            // includes may have changed
+            acknowledge().await;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, content: &str) -> String {
+        let path = std::env::temp_dir().join(format!("pf-watcher-{}-{name}", std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[tokio::test]
+    async fn fingerprint_notices_changes_and_missing_files() {
+        let file = temp_file("fingerprint.yaml", "a: 1\n");
+        let missing = format!("{file}.missing");
+        let files = vec![file.clone(), missing.clone()];
+        let before = fingerprint(&files).await;
+        assert!(before[&file].is_some());
+        assert_eq!(before[&missing], None);
+        assert_eq!(fingerprint(&files).await, before);
+
+        std::fs::write(&file, "a: 12\n").unwrap();
+        let after = fingerprint(&files).await;
+        assert_ne!(after, before);
+        std::fs::remove_file(&file).unwrap();
+        assert_eq!(fingerprint(&files).await[&file], None);
+    }
+
+    #[tokio::test]
+    async fn settle_waits_for_writes_to_stop() {
+        let file = temp_file("settle.yaml", "a: 1\n");
+        let files = vec![file.clone()];
+        let first = fingerprint(&files).await;
+        let writer = {
+            let file = file.clone();
+            tokio::spawn(async move {
+                for content in ["a: 12\n", "a: 123\n"] {
+                    sleep(Duration::from_millis(30)).await;
+                    std::fs::write(&file, content).unwrap();
+                }
+            })
+        };
+        let settled = settle(&files, first.clone(), Duration::from_millis(100)).await;
+        writer.await.unwrap();
+        assert_ne!(settled, first);
+        assert_eq!(settled, fingerprint(&files).await, "settled before the last write");
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn invalid_change_is_not_applied() {
+        for (name, content, expected) in [
+            ("unparsable.yaml", "listeners: [\n", ""),
+            (
+                "invalid.yaml",
+                "listeners:\n  a:\n    bind: nowhere\n    targets: []\noptions:\n  health_check_timeout_ms: 1\n  log_config_file: ''\n  max_idle_time_ms: 1\ndns: {}\n",
+                "listeners.a.bind",
+            ),
+        ] {
+            let file = temp_file(name, content);
+            STATUS.write().await.last_applied = None;
+            apply_change(&file).await;
+            let status = get_status().await;
+            let error = status.last_error.unwrap_or_else(|| panic!("{name} has no error"));
+            assert!(error.contains(expected), "{name}: {error}");
+            assert_eq!(status.last_applied, None, "{name} was applied");
+            std::fs::remove_file(&file).unwrap();
+        }
+    }
+}
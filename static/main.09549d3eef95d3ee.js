@@ -0,0 +1 @@
+"use strict";(self.webpackChunkangular_ui=self.webpackChunkangular_ui||[]).push([[179],{39:()=>{function ve(t){return"function"==typeof t}function Es(t){const e=t(i=>{Error.call(i),i.stack=(new Error).stack});return e.prototype=Object.create(Error.prototype),e.prototype.constructor=e,e}const xc=Es(t=>function(e){t(this),this.message=e?`${e.length} errors occurred during unsubscription:\n${e.map((i,r)=>`${r+1}) ${i.toString()}`).join("\n  ")}`:"",this.name="UnsubscriptionError",this.errors=e});function to(t,n){if(t){const e=t.indexOf(n);0<=e&&t.splice(e,1)}}class Le{constructor(n){this.initialTeardown=n,this.closed=!1,this._parentage=null,this._finalizers=null}unsubscribe(){let n;if(!this.closed){this.closed=!0;const{_parentage:e}=this;if(e)if(this._parentage=null,Array.isArray(e))for(const o of e)o.remove(this);else e.remove(this);const{initialTeardown:i}=this;if(ve(i))try{i()}catch(o){n=o instanceof xc?o.errors:[o]}const{_finalizers:r}=this;if(r){this._finalizers=null;for(const o of r)try{fb(o)}catch(s){n=n??[],s instanceof xc?n=[...n,...s.errors]:n.push(s)}}if(n)throw new xc(n)}}add(n){var e;if(n&&n!==this)if(this.closed)fb(n);else{if(n instanceof Le){if(n.closed||n._hasParent(this))return;n._addParent(this)}(this._finalizers=null!==(e=this._finalizers)&&void 0!==e?e:[]).push(n)}}_hasParent(n){const{_parentage:e}=this;return e===n||Array.isArray(e)&&e.includes(n)}_addParent(n){const{_parentage:e}=this;this._parentage=Array.isArray(e)?(e.push(n),e):e?[e,n]:n}_removeParent(n){const{_parentage:e}=this;e===n?this._parentage=null:Array.isArray(e)&&to(e,n)}remove(n){const{_finalizers:e}=this;e&&to(e,n),n instanceof Le&&n._removeParent(this)}}Le.EMPTY=(()=>{const t=new Le;return t.closed=!0,t})();const ub=Le.EMPTY;function hb(t){return t instanceof Le||t&&"closed"in t&&ve(t.remove)&&ve(t.add)&&ve(t.unsubscribe)}function fb(t){ve(t)?t():t.unsubscribe()}const mr={onUnhandledError:null,onStoppedNotification:null,Promise:void 0,useDeprecatedSynchronousErrorHandling:!1,useDeprecatedNextContext:!1},Cc={setTimeout(t,n,...e){const{delegate:i}=Cc;return i?.setTimeout?i.setTimeout(t,n,...e):setTimeout(t,n,...e)},clearTimeout(t){const{delegate:n}=Cc;return(n?.clearTimeout||clearTimeout)(t)},delegate:void 0};function pb(t){Cc.setTimeout(()=>{const{onUnhandledError:n}=mr;if(!n)throw t;n(t)})}function ih(){}const RA=rh("C",void 0,void 0);function rh(t,n,e){return{kind:t,value:n,error:e}}let gr=null;function Dc(t){if(mr.useDeprecatedSynchronousErrorHandling){const n=!gr;if(n&&(gr={errorThrown:!1,error:null}),t(),n){const{errorThrown:e,error:i}=gr;if(gr=null,e)throw i}}else t()}class oh extends Le{constructor(n){super(),this.isStopped=!1,n?(this.destination=n,hb(n)&&n.add(this)):this.destination=VA}static create(n,e,i){return new Ss(n,e,i)}next(n){this.isStopped?ah(function FA(t){return rh("N",t,void 0)}(n),this):this._next(n)}error(n){this.isStopped?ah(function OA(t){return rh("E",void 0,t)}(n),this):(this.isStopped=!0,this._error(n))}complete(){this.isStopped?ah(RA,this):(this.isStopped=!0,this._complete())}unsubscribe(){this.closed||(this.isStopped=!0,super.unsubscribe(),this.destination=null)}_next(n){this.destination.next(n)}_error(n){try{this.destination.error(n)}finally{this.unsubscribe()}}_complete(){try{this.destination.complete()}finally{this.unsubscribe()}}}const PA=Function.prototype.bind;function sh(t,n){return PA.call(t,n)}class LA{constructor(n){this.partialObserver=n}next(n){const{partialObserver:e}=this;if(e.next)try{e.next(n)}catch(i){Ec(i)}}error(n){const{partialObserver:e}=this;if(e.error)try{e.error(n)}catch(i){Ec(i)}else Ec(n)}complete(){const{partialObserver:n}=this;if(n.complete)try{n.complete()}catch(e){Ec(e)}}}class Ss extends oh{constructor(n,e,i){let r;if(super(),ve(n)||!n)r={next:n??void 0,error:e??void 0,complete:i??void 0};else{let o;this&&mr.useDeprecatedNextContext?(o=Object.create(n),o.unsubscribe=()=>this.unsubscribe(),r={next:n.next&&sh(n.next,o),error:n.error&&sh(n.error,o),complete:n.complete&&sh(n.complete,o)}):r=n}this.destination=new LA(r)}}function Ec(t){mr.useDeprecatedSynchronousErrorHandling?function NA(t){mr.useDeprecatedSynchronousErrorHandling&&gr&&(gr.errorThrown=!0,gr.error=t)}(t):pb(t)}function ah(t,n){const{onStoppedNotification:e}=mr;e&&Cc.setTimeout(()=>e(t,n))}const VA={closed:!0,next:ih,error:function BA(t){throw t},complete:ih},ch="function"==typeof Symbol&&Symbol.observable||"@@observable";function Vi(t){return t}function mb(t){return 0===t.length?Vi:1===t.length?t[0]:function(e){return t.reduce((i,r)=>r(i),e)}}let Ne=(()=>{class t{constructor(e){e&&(this._subscribe=e)}lift(e){const i=new t;return i.source=this,i.operator=e,i}subscribe(e,i,r){const o=function zA(t){return t&&t instanceof oh||function HA(t){return t&&ve(t.next)&&ve(t.error)&&ve(t.complete)}(t)&&hb(t)}(e)?e:new Ss(e,i,r);return Dc(()=>{const{operator:s,source:a}=this;o.add(s?s.call(o,a):a?this._subscribe(o):this._trySubscribe(o))}),o}_trySubscribe(e){try{return this._subscribe(e)}catch(i){e.error(i)}}forEach(e,i){return new(i=gb(i))((r,o)=>{const s=new Ss({next:a=>{try{e(a)}catch(c){o(c),s.unsubscribe()}},error:o,complete:r});this.subscribe(s)})}_subscribe(e){var i;return null===(i=this.source)||void 0===i?void 0:i.subscribe(e)}[ch](){return this}pipe(...e){return mb(e)(this)}toPromise(e){return new(e=gb(e))((i,r)=>{let o;this.subscribe(s=>o=s,s=>r(s),()=>i(o))})}}return t.create=n=>new t(n),t})();function gb(t){var n;return null!==(n=t??mr.Promise)&&void 0!==n?n:Promise}const $A=Es(t=>function(){t(this),this.name="ObjectUnsubscribedError",this.message="object unsubscribed"});let U=(()=>{class t extends Ne{constructor(){super(),this.closed=!1,this.currentObservers=null,this.observers=[],this.isStopped=!1,this.hasError=!1,this.thrownError=null}lift(e){const i=new _b(this,this);return i.operator=e,i}_throwIfClosed(){if(this.closed)throw new $A}next(e){Dc(()=>{if(this._throwIfClosed(),!this.isStopped){this.currentObservers||(this.currentObservers=Array.from(this.observers));for(const i of this.currentObservers)i.next(e)}})}error(e){Dc(()=>{if(this._throwIfClosed(),!this.isStopped){this.hasError=this.isStopped=!0,this.thrownError=e;const{observers:i}=this;for(;i.length;)i.shift().error(e)}})}complete(){Dc(()=>{if(this._throwIfClosed(),!this.isStopped){this.isStopped=!0;const{observers:e}=this;for(;e.length;)e.shift().complete()}})}unsubscribe(){this.isStopped=this.closed=!0,this.observers=this.currentObservers=null}get observed(){var e;return(null===(e=this.observers)||void 0===e?void 0:e.length)>0}_trySubscribe(e){return this._throwIfClosed(),super._trySubscribe(e)}_subscribe(e){return this._throwIfClosed(),this._checkFinalizedStatuses(e),this._innerSubscribe(e)}_innerSubscribe(e){const{hasError:i,isStopped:r,observers:o}=this;return i||r?ub:(this.currentObservers=null,o.push(e),new Le(()=>{this.currentObservers=null,to(o,e)}))}_checkFinalizedStatuses(e){const{hasError:i,thrownError:r,isStopped:o}=this;i?e.error(r):o&&e.complete()}asObservable(){const e=new Ne;return e.source=this,e}}return t.create=(n,e)=>new _b(n,e),t})();class _b extends U{constructor(n,e){super(),this.destination=n,this.source=e}next(n){var e,i;null===(i=null===(e=this.destination)||void 0===e?void 0:e.next)||void 0===i||i.call(e,n)}error(n){var e,i;null===(i=null===(e=this.destination)||void 0===e?void 0:e.error)||void 0===i||i.call(e,n)}complete(){var n,e;null===(e=null===(n=this.destination)||void 0===n?void 0:n.complete)||void 0===e||e.call(n)}_subscribe(n){var e,i;return null!==(i=null===(e=this.source)||void 0===e?void 0:e.subscribe(n))&&void 0!==i?i:ub}}function bb(t){return ve(t?.lift)}function nt(t){return n=>{if(bb(n))return n.lift(function(e){try{return t(e,this)}catch(i){this.error(i)}});throw new TypeError("Unable to lift unknown Observable type")}}function qe(t,n,e,i,r){return new UA(t,n,e,i,r)}class UA extends oh{constructor(n,e,i,r,o,s){super(n),this.onFinalize=o,this.shouldUnsubscribe=s,this._next=e?function(a){try{e(a)}catch(c){n.error(c)}}:super._next,this._error=r?function(a){try{r(a)}catch(c){n.error(c)}finally{this.unsubscribe()}}:super._error,this._complete=i?function(){try{i()}catch(a){n.error(a)}finally{this.unsubscribe()}}:super._complete}unsubscribe(){var n;if(!this.shouldUnsubscribe||this.shouldUnsubscribe()){const{closed:e}=this;super.unsubscribe(),!e&&(null===(n=this.onFinalize)||void 0===n||n.call(this))}}}function pe(t,n){return nt((e,i)=>{let r=0;e.subscribe(qe(i,o=>{i.next(t.call(n,o,r++))}))})}function ji(t){return this instanceof ji?(this.v=t,this):new ji(t)}function xb(t){if(!Symbol.asyncIterator)throw new TypeError("Symbol.asyncIterator is not defined.");var e,n=t[Symbol.asyncIterator];return n?n.call(t):(t=function hh(t){var n="function"==typeof Symbol&&Symbol.iterator,e=n&&t[n],i=0;if(e)return e.call(t);if(t&&"number"==typeof t.length)return{next:function(){return t&&i>=t.length&&(t=void 0),{value:t&&t[i++],done:!t}}};throw new TypeError(n?"Object is not iterable.":"Symbol.iterator is not defined.")}(t),e={},i("next"),i("throw"),i("return"),e[Symbol.asyncIterator]=function(){return this},e);function i(o){e[o]=t[o]&&function(s){return new Promise(function(a,c){!function r(o,s,a,c){Promise.resolve(c).then(function(l){o({value:l,done:a})},s)}(a,c,(s=t[o](s)).done,s.value)})}}}"function"==typeof SuppressedError&&SuppressedError;const fh=t=>t&&"number"==typeof t.length&&"function"!=typeof t;function Cb(t){return ve(t?.then)}function Db(t){return ve(t[ch])}function Eb(t){return Symbol.asyncIterator&&ve(t?.[Symbol.asyncIterator])}function Sb(t){return new TypeError(`You provided ${null!==t&&"object"==typeof t?"an invalid object":`'${t}'`} where a stream was expected. You can provide an Observable, Promise, ReadableStream, Array, AsyncIterable, or Iterable.`)}const Mb=function hk(){return"function"==typeof Symbol&&Symbol.iterator?Symbol.iterator:"@@iterator"}();function Ib(t){return ve(t?.[Mb])}function Tb(t){return function wb(t,n,e){if(!Symbol.asyncIterator)throw new TypeError("Symbol.asyncIterator is not defined.");var r,i=e.apply(t,n||[]),o=[];return r={},s("next"),s("throw"),s("return"),r[Symbol.asyncIterator]=function(){return this},r;function s(h){i[h]&&(r[h]=function(p){return new Promise(function(m,g){o.push([h,p,m,g])>1||a(h,p)})})}function a(h,p){try{!function c(h){h.value instanceof ji?Promise.resolve(h.value.v).then(l,d):u(o[0][2],h)}(i[h](p))}catch(m){u(o[0][3],m)}}function l(h){a("next",h)}function d(h){a("throw",h)}function u(h,p){h(p),o.shift(),o.length&&a(o[0][0],o[0][1])}}(this,arguments,function*(){const e=t.getReader();try{for(;;){const{value:i,done:r}=yield ji(e.read());if(r)return yield ji(void 0);yield yield ji(i)}}finally{e.releaseLock()}})}function Ab(t){return ve(t?.getReader)}function Ot(t){if(t instanceof Ne)return t;if(null!=t){if(Db(t))return function fk(t){return new Ne(n=>{const e=t[ch]();if(ve(e.subscribe))return e.subscribe(n);throw new TypeError("Provided object does not correctly implement Symbol.observable")})}(t);if(fh(t))return function pk(t){return new Ne(n=>{for(let e=0;e<t.length&&!n.closed;e++)n.next(t[e]);n.complete()})}(t);if(Cb(t))return function mk(t){return new Ne(n=>{t.then(e=>{n.closed||(n.next(e),n.complete())},e=>n.error(e)).then(null,pb)})}(t);if(Eb(t))return kb(t);if(Ib(t))return function gk(t){return new Ne(n=>{for(const e of t)if(n.next(e),n.closed)return;n.complete()})}(t);if(Ab(t))return function _k(t){return kb(Tb(t))}(t)}throw Sb(t)}function kb(t){return new Ne(n=>{(function bk(t,n){var e,i,r,o;return function vb(t,n,e,i){return new(e||(e=Promise))(function(o,s){function a(d){try{l(i.next(d))}catch(u){s(u)}}function c(d){try{l(i.throw(d))}catch(u){s(u)}}function l(d){d.done?o(d.value):function r(o){return o instanceof e?o:new e(function(s){s(o)})}(d.value).then(a,c)}l((i=i.apply(t,n||[])).next())})}(this,void 0,void 0,function*(){try{for(e=xb(t);!(i=yield e.next()).done;)if(n.next(i.value),n.closed)return}catch(s){r={error:s}}finally{try{i&&!i.done&&(o=e.return)&&(yield o.call(e))}finally{if(r)throw r.error}}n.complete()})})(t,n).catch(e=>n.error(e))})}function hi(t,n,e,i=0,r=!1){const o=n.schedule(function(){e(),r?t.add(this.schedule(null,i)):this.unsubscribe()},i);if(t.add(o),!r)return o}function yt(t,n,e=1/0){return ve(n)?yt((i,r)=>pe((o,s)=>n(i,o,r,s))(Ot(t(i,r))),e):("number"==typeof n&&(e=n),nt((i,r)=>function vk(t,n,e,i,r,o,s,a){const c=[];let l=0,d=0,u=!1;const h=()=>{u&&!c.length&&!l&&n.complete()},p=g=>l<i?m(g):c.push(g),m=g=>{o&&n.next(g),l++;let _=!1;Ot(e(g,d++)).subscribe(qe(n,C=>{r?.(C),o?p(C):n.next(C)},()=>{_=!0},void 0,()=>{if(_)try{for(l--;c.length&&l<i;){const C=c.shift();s?hi(n,s,()=>m(C)):m(C)}h()}catch(C){n.error(C)}}))};return t.subscribe(qe(n,p,()=>{u=!0,h()})),()=>{a?.()}}(i,r,t,e)))}function no(t=1/0){return yt(Vi,t)}const Qt=new Ne(t=>t.complete());function Rb(t){return t&&ve(t.schedule)}function ph(t){return t[t.length-1]}function Ob(t){return ve(ph(t))?t.pop():void 0}function Ms(t){return Rb(ph(t))?t.pop():void 0}function Fb(t,n=0){return nt((e,i)=>{e.subscribe(qe(i,r=>hi(i,t,()=>i.next(r),n),()=>hi(i,t,()=>i.complete(),n),r=>hi(i,t,()=>i.error(r),n)))})}function Nb(t,n=0){return nt((e,i)=>{i.add(t.schedule(()=>e.subscribe(i),n))})}function Pb(t,n){if(!t)throw new Error("Iterable cannot be null");return new Ne(e=>{hi(e,n,()=>{const i=t[Symbol.asyncIterator]();hi(e,n,()=>{i.next().then(r=>{r.done?e.complete():e.next(r.value)})},0,!0)})})}function dt(t,n){return n?function Mk(t,n){if(null!=t){if(Db(t))return function xk(t,n){return Ot(t).pipe(Nb(n),Fb(n))}(t,n);if(fh(t))return function Dk(t,n){return new Ne(e=>{let i=0;return n.schedule(function(){i===t.length?e.complete():(e.next(t[i++]),e.closed||this.schedule())})})}(t,n);if(Cb(t))return function Ck(t,n){return Ot(t).pipe(Nb(n),Fb(n))}(t,n);if(Eb(t))return Pb(t,n);if(Ib(t))return function Ek(t,n){return new Ne(e=>{let i;return hi(e,n,()=>{i=t[Mb](),hi(e,n,()=>{let r,o;try{({value:r,done:o}=i.next())}catch(s){return void e.error(s)}o?e.complete():e.next(r)},0,!0)}),()=>ve(i?.return)&&i.return()})}(t,n);if(Ab(t))return function Sk(t,n){return Pb(Tb(t),n)}(t,n)}throw Sb(t)}(t,n):Ot(t)}function An(...t){const n=Ms(t),e=function wk(t,n){return"number"==typeof ph(t)?t.pop():n}(t,1/0),i=t;return i.length?1===i.length?Ot(i[0]):no(e)(dt(i,n)):Qt}class $t extends U{constructor(n){super(),this._value=n}get value(){return this.getValue()}_subscribe(n){const e=super._subscribe(n);return!e.closed&&n.next(this._value),e}getValue(){const{hasError:n,thrownError:e,_value:i}=this;if(n)throw e;return this._throwIfClosed(),i}next(n){super.next(this._value=n)}}function z(...t){return dt(t,Ms(t))}function Sc(t={}){const{connector:n=(()=>new U),resetOnError:e=!0,resetOnComplete:i=!0,resetOnRefCountZero:r=!0}=t;return o=>{let s,a,c,l=0,d=!1,u=!1;const h=()=>{a?.unsubscribe(),a=void 0},p=()=>{h(),s=c=void 0,d=u=!1},m=()=>{const g=s;p(),g?.unsubscribe()};return nt((g,_)=>{l++,!u&&!d&&h();const C=c=c??n();_.add(()=>{l--,0===l&&!u&&!d&&(a=mh(m,r))}),C.subscribe(_),!s&&l>0&&(s=new Ss({next:v=>C.next(v),error:v=>{u=!0,h(),a=mh(p,e,v),C.error(v)},complete:()=>{d=!0,h(),a=mh(p,i),C.complete()}}),Ot(g).subscribe(s))})(o)}}function mh(t,n,...e){if(!0===n)return void t();if(!1===n)return;const i=new Ss({next:()=>{i.unsubscribe(),t()}});return Ot(n(...e)).subscribe(i)}function Xt(t,n){return nt((e,i)=>{let r=null,o=0,s=!1;const a=()=>s&&!r&&i.complete();e.subscribe(qe(i,c=>{r?.unsubscribe();let l=0;const d=o++;Ot(t(c,d)).subscribe(r=qe(i,u=>i.next(n?n(c,u,d,l++):u),()=>{r=null,a()}))},()=>{s=!0,a()}))})}function Mc(t,n=Vi){return t=t??Ik,nt((e,i)=>{let r,o=!0;e.subscribe(qe(i,s=>{const a=n(s);(o||!t(r,a))&&(o=!1,r=a,i.next(s))}))})}function Ik(t,n){return t===n}function ke(t){for(let n in t)if(t[n]===ke)return n;throw Error("Could not find renamed property on target object.")}function Ic(t,n){for(const e in n)n.hasOwnProperty(e)&&!t.hasOwnProperty(e)&&(t[e]=n[e])}function pt(t){if("string"==typeof t)return t;if(Array.isArray(t))return"["+t.map(pt).join(", ")+"]";if(null==t)return""+t;if(t.overriddenName)return`${t.overriddenName}`;if(t.name)return`${t.name}`;const n=t.toString();if(null==n)return""+n;const e=n.indexOf("\n");return-1===e?n:n.substring(0,e)}function gh(t,n){return null==t||""===t?null===n?"":n:null==n||""===n?t:t+" "+n}const Tk=ke({__forward_ref__:ke});function Pe(t){return t.__forward_ref__=Pe,t.toString=function(){return pt(this())},t}function te(t){return _h(t)?t():t}function _h(t){return"function"==typeof t&&t.hasOwnProperty(Tk)&&t.__forward_ref__===Pe}function bh(t){return t&&!!t.\u0275providers}const Lb="https://g.co/ng/security#xss";class y extends Error{constructor(n,e){super(function Tc(t,n){return`NG0${Math.abs(t)}${n?": "+n:""}`}(n,e)),this.code=n}}function ie(t){return"string"==typeof t?t:null==t?"":String(t)}function vh(t,n){throw new y(-201,!1)}function mn(t,n){null==t&&function J(t,n,e,i){throw new Error(`ASSERTION ERROR: ${t}`+(null==i?"":` [Expected=> ${e} ${i} ${n} <=Actual]`))}(n,t,null,"!=")}function I(t){return{token:t.token,providedIn:t.providedIn||null,factory:t.factory,value:void 0}}function ae(t){return{providers:t.providers||[],imports:t.imports||[]}}function Ac(t){return Bb(t,Rc)||Bb(t,Vb)}function Bb(t,n){return t.hasOwnProperty(n)?t[n]:null}function kc(t){return t&&(t.hasOwnProperty(yh)||t.hasOwnProperty(Lk))?t[yh]:null}const Rc=ke({\u0275prov:ke}),yh=ke({\u0275inj:ke}),Vb=ke({ngInjectableDef:ke}),Lk=ke({ngInjectorDef:ke});var ge=function(t){return t[t.Default=0]="Default",t[t.Host=1]="Host",t[t.Self=2]="Self",t[t.SkipSelf=4]="SkipSelf",t[t.Optional=8]="Optional",t}(ge||{});let wh;function Ut(t){const n=wh;return wh=t,n}function Hb(t,n,e){const i=Ac(t);return i&&"root"==i.providedIn?void 0===i.value?i.value=i.factory():i.value:e&ge.Optional?null:void 0!==n?n:void vh(pt(t))}const Ve=globalThis,Is={},Sh="__NG_DI_FLAG__",Oc="ngTempTokenPath",jk=/\n/gm,$b="__source";let io;function Hi(t){const n=io;return io=t,n}function $k(t,n=ge.Default){if(void 0===io)throw new y(-203,!1);return null===io?Hb(t,void 0,n):io.get(t,n&ge.Optional?null:void 0,n)}function b(t,n=ge.Default){return(function jb(){return wh}()||$k)(te(t),n)}function T(t,n=ge.Default){return b(t,Fc(n))}function Fc(t){return typeof t>"u"||"number"==typeof t?t:0|(t.optional&&8)|(t.host&&1)|(t.self&&2)|(t.skipSelf&&4)}function Mh(t){const n=[];for(let e=0;e<t.length;e++){const i=te(t[e]);if(Array.isArray(i)){if(0===i.length)throw new y(900,!1);let r,o=ge.Default;for(let s=0;s<i.length;s++){const a=i[s],c=Uk(a);"number"==typeof c?-1===c?r=a.token:o|=c:r=a}n.push(b(r,o))}else n.push(b(i))}return n}function Ts(t,n){return t[Sh]=n,t.prototype[Sh]=n,t}function Uk(t){return t[Sh]}function fi(t){return{toString:t}.toString()}var Nc=function(t){return t[t.OnPush=0]="OnPush",t[t.Default=1]="Default",t}(Nc||{}),gn=function(t){return t[t.Emulated=0]="Emulated",t[t.None=2]="None",t[t.ShadowDom=3]="ShadowDom",t}(gn||{});const qn={},De=[],Pc=ke({\u0275cmp:ke}),Ih=ke({\u0275dir:ke}),Th=ke({\u0275pipe:ke}),Gb=ke({\u0275mod:ke}),pi=ke({\u0275fac:ke}),As=ke({__NG_ELEMENT_ID__:ke}),Wb=ke({__NG_ENV_ID__:ke});function qb(t,n,e){let i=t.length;for(;;){const r=t.indexOf(n,e);if(-1===r)return r;if(0===r||t.charCodeAt(r-1)<=32){const o=n.length;if(r+o===i||t.charCodeAt(r+o)<=32)return r}e=r+1}}function Ah(t,n,e){let i=0;for(;i<e.length;){const r=e[i];if("number"==typeof r){if(0!==r)break;i++;const o=e[i++],s=e[i++],a=e[i++];t.setAttribute(n,s,a,o)}else{const o=r,s=e[++i];Zb(o)?t.setProperty(n,o,s):t.setAttribute(n,o,s),i++}}return i}function Yb(t){return 3===t||4===t||6===t}function Zb(t){return 64===t.charCodeAt(0)}function ks(t,n){if(null!==n&&0!==n.length)if(null===t||0===t.length)t=n.slice();else{let e=-1;for(let i=0;i<n.length;i++){const r=n[i];"number"==typeof r?e=r:0===e||Kb(t,e,r,null,-1===e||2===e?n[++i]:null)}}return t}function Kb(t,n,e,i,r){let o=0,s=t.length;if(-1===n)s=-1;else for(;o<t.length;){const a=t[o++];if("number"==typeof a){if(a===n){s=-1;break}if(a>n){s=o-1;break}}}for(;o<t.length;){const a=t[o];if("number"==typeof a)break;if(a===e){if(null===i)return void(null!==r&&(t[o+1]=r));if(i===t[o+1])return void(t[o+2]=r)}o++,null!==i&&o++,null!==r&&o++}-1!==s&&(t.splice(s,0,n),o=s+1),t.splice(o++,0,e),null!==i&&t.splice(o++,0,i),null!==r&&t.splice(o++,0,r)}const Qb="ng-template";function qk(t,n,e){let i=0,r=!0;for(;i<t.length;){let o=t[i++];if("string"==typeof o&&r){const s=t[i++];if(e&&"class"===o&&-1!==qb(s.toLowerCase(),n,0))return!0}else{if(1===o){for(;i<t.length&&"string"==typeof(o=t[i++]);)if(o.toLowerCase()===n)return!0;return!1}"number"==typeof o&&(r=!1)}}return!1}function Xb(t){return 4===t.type&&t.value!==Qb}function Yk(t,n,e){return n===(4!==t.type||e?t.value:Qb)}function Zk(t,n,e){let i=4;const r=t.attrs||[],o=function Xk(t){for(let n=0;n<t.length;n++)if(Yb(t[n]))return n;return t.length}(r);let s=!1;for(let a=0;a<n.length;a++){const c=n[a];if("number"!=typeof c){if(!s)if(4&i){if(i=2|1&i,""!==c&&!Yk(t,c,e)||""===c&&1===n.length){if(kn(i))return!1;s=!0}}else{const l=8&i?c:n[++a];if(8&i&&null!==t.attrs){if(!qk(t.attrs,l,e)){if(kn(i))return!1;s=!0}continue}const u=Kk(8&i?"class":c,r,Xb(t),e);if(-1===u){if(kn(i))return!1;s=!0;continue}if(""!==l){let h;h=u>o?"":r[u+1].toLowerCase();const p=8&i?h:null;if(p&&-1!==qb(p,l,0)||2&i&&l!==h){if(kn(i))return!1;s=!0}}}}else{if(!s&&!kn(i)&&!kn(c))return!1;if(s&&kn(c))continue;s=!1,i=c|1&i}}return kn(i)||s}function kn(t){return 0==(1&t)}function Kk(t,n,e,i){if(null===n)return-1;let r=0;if(i||!e){let o=!1;for(;r<n.length;){const s=n[r];if(s===t)return r;if(3===s||6===s)o=!0;else{if(1===s||2===s){let a=n[++r];for(;"string"==typeof a;)a=n[++r];continue}if(4===s)break;if(0===s){r+=4;continue}}r+=o?1:2}return-1}return function Jk(t,n){let e=t.indexOf(4);if(e>-1)for(e++;e<t.length;){const i=t[e];if("number"==typeof i)return-1;if(i===n)return e;e++}return-1}(n,t)}function Jb(t,n,e=!1){for(let i=0;i<n.length;i++)if(Zk(t,n[i],e))return!0;return!1}function e1(t,n){e:for(let e=0;e<n.length;e++){const i=n[e];if(t.length===i.length){for(let r=0;r<t.length;r++)if(t[r]!==i[r])continue e;return!0}}return!1}function ev(t,n){return t?":not("+n.trim()+")":n}function t1(t){let n=t[0],e=1,i=2,r="",o=!1;for(;e<t.length;){let s=t[e];if("string"==typeof s)if(2&i){const a=t[++e];r+="["+s+(a.length>0?'="'+a+'"':"")+"]"}else 8&i?r+="."+s:4&i&&(r+=" "+s);else""!==r&&!kn(s)&&(n+=ev(o,r),r=""),i=s,o=o||!kn(i);e++}return""!==r&&(n+=ev(o,r)),n}function oe(t){return fi(()=>{const n=nv(t),e={...n,decls:t.decls,vars:t.vars,template:t.template,consts:t.consts||null,ngContentSelectors:t.ngContentSelectors,onPush:t.changeDetection===Nc.OnPush,directiveDefs:null,pipeDefs:null,dependencies:n.standalone&&t.dependencies||null,getStandaloneInjector:null,signals:t.signals??!1,data:t.data||{},encapsulation:t.encapsulation||gn.Emulated,styles:t.styles||De,_:null,schemas:t.schemas||null,tView:null,id:""};iv(e);const i=t.dependencies;return e.directiveDefs=Lc(i,!1),e.pipeDefs=Lc(i,!0),e.id=function d1(t){let n=0;const e=[t.selectors,t.ngContentSelectors,t.hostVars,t.hostAttrs,t.consts,t.vars,t.decls,t.encapsulation,t.standalone,t.signals,t.exportAs,JSON.stringify(t.inputs),JSON.stringify(t.outputs),Object.getOwnPropertyNames(t.type.prototype),!!t.contentQueries,!!t.viewQuery].join("|");for(const r of e)n=Math.imul(31,n)+r.charCodeAt(0)<<0;return n+=2147483648,"c"+n}(e),e})}function s1(t){return ye(t)||xt(t)}function a1(t){return null!==t}function le(t){return fi(()=>({type:t.type,bootstrap:t.bootstrap||De,declarations:t.declarations||De,imports:t.imports||De,exports:t.exports||De,transitiveCompileScopes:null,schemas:t.schemas||null,id:t.id||null}))}function tv(t,n){if(null==t)return qn;const e={};for(const i in t)if(t.hasOwnProperty(i)){let r=t[i],o=r;Array.isArray(r)&&(o=r[1],r=r[0]),e[r]=i,n&&(n[r]=o)}return e}function x(t){return fi(()=>{const n=nv(t);return iv(n),n})}function ye(t){return t[Pc]||null}function xt(t){return t[Ih]||null}function Ft(t){return t[Th]||null}function en(t,n){const e=t[Gb]||null;if(!e&&!0===n)throw new Error(`Type ${pt(t)} does not have '\u0275mod' property.`);return e}function nv(t){const n={};return{type:t.type,providersResolver:null,factory:null,hostBindings:t.hostBindings||null,hostVars:t.hostVars||0,hostAttrs:t.hostAttrs||null,contentQueries:t.contentQueries||null,declaredInputs:n,inputTransforms:null,inputConfig:t.inputs||qn,exportAs:t.exportAs||null,standalone:!0===t.standalone,signals:!0===t.signals,selectors:t.selectors||De,viewQuery:t.viewQuery||null,features:t.features||null,setInput:null,findHostDirectiveDefs:null,hostDirectives:null,inputs:tv(t.inputs,n),outputs:tv(t.outputs)}}function iv(t){t.features?.forEach(n=>n(t))}function Lc(t,n){if(!t)return null;const e=n?Ft:s1;return()=>("function"==typeof t?t():t).map(i=>e(i)).filter(a1)}const Xe=0,P=1,ce=2,Ge=3,Rn=4,Rs=5,It=6,oo=7,it=8,zi=9,so=10,re=11,Os=12,rv=13,ao=14,rt=15,Fs=16,co=17,Yn=18,Ns=19,ov=20,$i=21,mi=22,Ps=23,Ls=24,_e=25,kh=1,sv=2,Zn=7,lo=9,Ct=11;function Wt(t){return Array.isArray(t)&&"object"==typeof t[kh]}function Nt(t){return Array.isArray(t)&&!0===t[kh]}function Rh(t){return 0!=(4&t.flags)}function br(t){return t.componentOffset>-1}function Vc(t){return 1==(1&t.flags)}function On(t){return!!t.template}function Oh(t){return 0!=(512&t[ce])}function vr(t,n){return t.hasOwnProperty(pi)?t[pi]:null}let Dt=null,jc=!1;function _n(t){const n=Dt;return Dt=t,n}const lv={version:0,dirty:!1,producerNode:void 0,producerLastReadVersion:void 0,producerIndexOfThis:void 0,nextProducerIndex:0,liveConsumerNode:void 0,liveConsumerIndexOfThis:void 0,consumerAllowSignalWrites:!1,consumerIsAlwaysLive:!1,producerMustRecompute:()=>!1,producerRecomputeValue:()=>{},consumerMarkedDirty:()=>{}};function uv(t){if(!Vs(t)||t.dirty){if(!t.producerMustRecompute(t)&&!pv(t))return void(t.dirty=!1);t.producerRecomputeValue(t),t.dirty=!1}}function fv(t){t.dirty=!0,function hv(t){if(void 0===t.liveConsumerNode)return;const n=jc;jc=!0;try{for(const e of t.liveConsumerNode)e.dirty||fv(e)}finally{jc=n}}(t),t.consumerMarkedDirty?.(t)}function Nh(t){return t&&(t.nextProducerIndex=0),_n(t)}function Ph(t,n){if(_n(n),t&&void 0!==t.producerNode&&void 0!==t.producerIndexOfThis&&void 0!==t.producerLastReadVersion){if(Vs(t))for(let e=t.nextProducerIndex;e<t.producerNode.length;e++)Hc(t.producerNode[e],t.producerIndexOfThis[e]);for(;t.producerNode.length>t.nextProducerIndex;)t.producerNode.pop(),t.producerLastReadVersion.pop(),t.producerIndexOfThis.pop()}}function pv(t){uo(t);for(let n=0;n<t.producerNode.length;n++){const e=t.producerNode[n],i=t.producerLastReadVersion[n];if(i!==e.version||(uv(e),i!==e.version))return!0}return!1}function mv(t){if(uo(t),Vs(t))for(let n=0;n<t.producerNode.length;n++)Hc(t.producerNode[n],t.producerIndexOfThis[n]);t.producerNode.length=t.producerLastReadVersion.length=t.producerIndexOfThis.length=0,t.liveConsumerNode&&(t.liveConsumerNode.length=t.liveConsumerIndexOfThis.length=0)}function Hc(t,n){if(function _v(t){t.liveConsumerNode??=[],t.liveConsumerIndexOfThis??=[]}(t),uo(t),1===t.liveConsumerNode.length)for(let i=0;i<t.producerNode.length;i++)Hc(t.producerNode[i],t.producerIndexOfThis[i]);const e=t.liveConsumerNode.length-1;if(t.liveConsumerNode[n]=t.liveConsumerNode[e],t.liveConsumerIndexOfThis[n]=t.liveConsumerIndexOfThis[e],t.liveConsumerNode.length--,t.liveConsumerIndexOfThis.length--,n<t.liveConsumerNode.length){const i=t.liveConsumerIndexOfThis[n],r=t.liveConsumerNode[n];uo(r),r.producerIndexOfThis[i]=n}}function Vs(t){return t.consumerIsAlwaysLive||(t?.liveConsumerNode?.length??0)>0}function uo(t){t.producerNode??=[],t.producerIndexOfThis??=[],t.producerLastReadVersion??=[]}let bv=null;const xv=()=>{},C1=(()=>({...lv,consumerIsAlwaysLive:!0,consumerAllowSignalWrites:!1,consumerMarkedDirty:t=>{t.schedule(t.ref)},hasRun:!1,cleanupFn:xv}))();class D1{constructor(n,e,i){this.previousValue=n,this.currentValue=e,this.firstChange=i}isFirstChange(){return this.firstChange}}function ut(){return Cv}function Cv(t){return t.type.prototype.ngOnChanges&&(t.setInput=S1),E1}function E1(){const t=Ev(this),n=t?.current;if(n){const e=t.previous;if(e===qn)t.previous=n;else for(let i in n)e[i]=n[i];t.current=null,this.ngOnChanges(n)}}function S1(t,n,e,i){const r=this.declaredInputs[e],o=Ev(t)||function M1(t,n){return t[Dv]=n}(t,{previous:qn,current:null}),s=o.current||(o.current={}),a=o.previous,c=a[r];s[r]=new D1(c&&c.currentValue,n,a===qn),t[i]=n}ut.ngInherit=!0;const Dv="__ngSimpleChanges__";function Ev(t){return t[Dv]||null}const Kn=function(t,n,e){},Sv="svg";function je(t){for(;Array.isArray(t);)t=t[Xe];return t}function zc(t,n){return je(n[t])}function qt(t,n){return je(n[t.index])}function Iv(t,n){return t.data[n]}function tn(t,n){const e=n[t];return Wt(e)?e:e[Xe]}function Gi(t,n){return null==n?null:t[n]}function Tv(t){t[co]=0}function O1(t){1024&t[ce]||(t[ce]|=1024,kv(t,1))}function Av(t){1024&t[ce]&&(t[ce]&=-1025,kv(t,-1))}function kv(t,n){let e=t[Ge];if(null===e)return;e[Rs]+=n;let i=e;for(e=e[Ge];null!==e&&(1===n&&1===i[Rs]||-1===n&&0===i[Rs]);)e[Rs]+=n,i=e,e=e[Ge]}const ee={lFrame:zv(null),bindingsEnabled:!0,skipHydrationRootTNode:null};function Fv(){return ee.bindingsEnabled}function fo(){return null!==ee.skipHydrationRootTNode}function S(){return ee.lFrame.lView}function we(){return ee.lFrame.tView}function Fn(t){return ee.lFrame.contextLView=t,t[it]}function Nn(t){return ee.lFrame.contextLView=null,t}function Et(){let t=Nv();for(;null!==t&&64===t.type;)t=t.parent;return t}function Nv(){return ee.lFrame.currentTNode}function Qn(t,n){const e=ee.lFrame;e.currentTNode=t,e.isParent=n}function Hh(){return ee.lFrame.isParent}function zh(){ee.lFrame.isParent=!1}function Pt(){const t=ee.lFrame;let n=t.bindingRootIndex;return-1===n&&(n=t.bindingRootIndex=t.tView.bindingStartIndex),n}function po(){return ee.lFrame.bindingIndex++}function _i(t){const n=ee.lFrame,e=n.bindingIndex;return n.bindingIndex=n.bindingIndex+t,e}function G1(t,n){const e=ee.lFrame;e.bindingIndex=e.bindingRootIndex=t,$h(n)}function $h(t){ee.lFrame.currentDirectiveIndex=t}function Uh(t){const n=ee.lFrame.currentDirectiveIndex;return-1===n?null:t[n]}function Vv(){return ee.lFrame.currentQueryIndex}function Gh(t){ee.lFrame.currentQueryIndex=t}function q1(t){const n=t[P];return 2===n.type?n.declTNode:1===n.type?t[It]:null}function jv(t,n,e){if(e&ge.SkipSelf){let r=n,o=t;for(;!(r=r.parent,null!==r||e&ge.Host||(r=q1(o),null===r||(o=o[ao],10&r.type))););if(null===r)return!1;n=r,t=o}const i=ee.lFrame=Hv();return i.currentTNode=n,i.lView=t,!0}function Wh(t){const n=Hv(),e=t[P];ee.lFrame=n,n.currentTNode=e.firstChild,n.lView=t,n.tView=e,n.contextLView=t,n.bindingIndex=e.bindingStartIndex,n.inI18n=!1}function Hv(){const t=ee.lFrame,n=null===t?null:t.child;return null===n?zv(t):n}function zv(t){const n={currentTNode:null,isParent:!0,lView:null,tView:null,selectedIndex:-1,contextLView:null,elementDepthCount:0,currentNamespace:null,currentDirectiveIndex:-1,bindingRootIndex:-1,bindingIndex:-1,currentQueryIndex:0,parent:t,child:null,inI18n:!1};return null!==t&&(t.child=n),n}function $v(){const t=ee.lFrame;return ee.lFrame=t.parent,t.currentTNode=null,t.lView=null,t}const Uv=$v;function qh(){const t=$v();t.isParent=!0,t.tView=null,t.selectedIndex=-1,t.contextLView=null,t.elementDepthCount=0,t.currentDirectiveIndex=-1,t.currentNamespace=null,t.bindingRootIndex=-1,t.bindingIndex=-1,t.currentQueryIndex=0}function Lt(){return ee.lFrame.selectedIndex}function yr(t){ee.lFrame.selectedIndex=t}function Ye(){const t=ee.lFrame;return Iv(t.tView,t.selectedIndex)}function $c(){ee.lFrame.currentNamespace=Sv}let qv=!0;function Uc(){return qv}function Wi(t){qv=t}function Gc(t,n){for(let e=n.directiveStart,i=n.directiveEnd;e<i;e++){const o=t.data[e].type.prototype,{ngAfterContentInit:s,ngAfterContentChecked:a,ngAfterViewInit:c,ngAfterViewChecked:l,ngOnDestroy:d}=o;s&&(t.contentHooks??=[]).push(-e,s),a&&((t.contentHooks??=[]).push(e,a),(t.contentCheckHooks??=[]).push(e,a)),c&&(t.viewHooks??=[]).push(-e,c),l&&((t.viewHooks??=[]).push(e,l),(t.viewCheckHooks??=[]).push(e,l)),null!=d&&(t.destroyHooks??=[]).push(e,d)}}function Wc(t,n,e){Yv(t,n,3,e)}function qc(t,n,e,i){(3&t[ce])===e&&Yv(t,n,e,i)}function Yh(t,n){let e=t[ce];(3&e)===n&&(e&=8191,e+=1,t[ce]=e)}function Yv(t,n,e,i){const o=i??-1,s=n.length-1;let a=0;for(let c=void 0!==i?65535&t[co]:0;c<s;c++)if("number"==typeof n[c+1]){if(a=n[c],null!=i&&a>=i)break}else n[c]<0&&(t[co]+=65536),(a<o||-1==o)&&(J1(t,e,n,c),t[co]=(4294901760&t[co])+c+2),c++}function Zv(t,n){Kn(4,t,n);const e=_n(null);try{n.call(t)}finally{_n(e),Kn(5,t,n)}}function J1(t,n,e,i){const r=e[i]<0,o=e[i+1],a=t[r?-e[i]:e[i]];r?t[ce]>>13<t[co]>>16&&(3&t[ce])===n&&(t[ce]+=8192,Zv(a,o)):Zv(a,o)}const mo=-1;class Hs{constructor(n,e,i){this.factory=n,this.resolving=!1,this.canSeeViewProviders=e,this.injectImpl=i}}function Kh(t){return t!==mo}function zs(t){return 32767&t}function $s(t,n){let e=function iR(t){return t>>16}(t),i=n;for(;e>0;)i=i[ao],e--;return i}let Qh=!0;function Yc(t){const n=Qh;return Qh=t,n}const Kv=255,Qv=5;let rR=0;const Xn={};function Zc(t,n){const e=Xv(t,n);if(-1!==e)return e;const i=n[P];i.firstCreatePass&&(t.injectorIndex=n.length,Xh(i.data,t),Xh(n,null),Xh(i.blueprint,null));const r=Kc(t,n),o=t.injectorIndex;if(Kh(r)){const s=zs(r),a=$s(r,n),c=a[P].data;for(let l=0;l<8;l++)n[o+l]=a[s+l]|c[s+l]}return n[o+8]=r,o}function Xh(t,n){t.push(0,0,0,0,0,0,0,0,n)}function Xv(t,n){return-1===t.injectorIndex||t.parent&&t.parent.injectorIndex===t.injectorIndex||null===n[t.injectorIndex+8]?-1:t.injectorIndex}function Kc(t,n){if(t.parent&&-1!==t.parent.injectorIndex)return t.parent.injectorIndex;let e=0,i=null,r=n;for(;null!==r;){if(i=oy(r),null===i)return mo;if(e++,r=r[ao],-1!==i.injectorIndex)return i.injectorIndex|e<<16}return mo}function Jh(t,n,e){!function oR(t,n,e){let i;"string"==typeof e?i=e.charCodeAt(0)||0:e.hasOwnProperty(As)&&(i=e[As]),null==i&&(i=e[As]=rR++);const r=i&Kv;n.data[t+(r>>Qv)]|=1<<r}(t,n,e)}function Jv(t,n,e){if(e&ge.Optional||void 0!==t)return t;vh()}function ey(t,n,e,i){if(e&ge.Optional&&void 0===i&&(i=null),!(e&(ge.Self|ge.Host))){const r=t[zi],o=Ut(void 0);try{return r?r.get(n,i,e&ge.Optional):Hb(n,i,e&ge.Optional)}finally{Ut(o)}}return Jv(i,0,e)}function ty(t,n,e,i=ge.Default,r){if(null!==t){if(2048&n[ce]&&!(i&ge.Self)){const s=function uR(t,n,e,i,r){let o=t,s=n;for(;null!==o&&null!==s&&2048&s[ce]&&!(512&s[ce]);){const a=ny(o,s,e,i|ge.Self,Xn);if(a!==Xn)return a;let c=o.parent;if(!c){const l=s[ov];if(l){const d=l.get(e,Xn,i);if(d!==Xn)return d}c=oy(s),s=s[ao]}o=c}return r}(t,n,e,i,Xn);if(s!==Xn)return s}const o=ny(t,n,e,i,Xn);if(o!==Xn)return o}return ey(n,e,i,r)}function ny(t,n,e,i,r){const o=function cR(t){if("string"==typeof t)return t.charCodeAt(0)||0;const n=t.hasOwnProperty(As)?t[As]:void 0;return"number"==typeof n?n>=0?n&Kv:dR:n}(e);if("function"==typeof o){if(!jv(n,t,i))return i&ge.Host?Jv(r,0,i):ey(n,e,i,r);try{let s;if(s=o(i),null!=s||i&ge.Optional)return s;vh()}finally{Uv()}}else if("number"==typeof o){let s=null,a=Xv(t,n),c=mo,l=i&ge.Host?n[rt][It]:null;for((-1===a||i&ge.SkipSelf)&&(c=-1===a?Kc(t,n):n[a+8],c!==mo&&ry(i,!1)?(s=n[P],a=zs(c),n=$s(c,n)):a=-1);-1!==a;){const d=n[P];if(iy(o,a,d.data)){const u=aR(a,n,e,s,i,l);if(u!==Xn)return u}c=n[a+8],c!==mo&&ry(i,n[P].data[a+8]===l)&&iy(o,a,n)?(s=d,a=zs(c),n=$s(c,n)):a=-1}}return r}function aR(t,n,e,i,r,o){const s=n[P],a=s.data[t+8],d=Qc(a,s,e,null==i?br(a)&&Qh:i!=s&&0!=(3&a.type),r&ge.Host&&o===a);return null!==d?wr(n,s,d,a):Xn}function Qc(t,n,e,i,r){const o=t.providerIndexes,s=n.data,a=1048575&o,c=t.directiveStart,d=o>>20,h=r?a+d:t.directiveEnd;for(let p=i?a:a+d;p<h;p++){const m=s[p];if(p<c&&e===m||p>=c&&m.type===e)return p}if(r){const p=s[c];if(p&&On(p)&&p.type===e)return c}return null}function wr(t,n,e,i){let r=t[e];const o=n.data;if(function eR(t){return t instanceof Hs}(r)){const s=r;s.resolving&&function Ak(t,n){const e=n?`. Dependency path: ${n.join(" > ")} > ${t}`:"";throw new y(-200,`Circular dependency in DI detected for ${t}${e}`)}(function Te(t){return"function"==typeof t?t.name||t.toString():"object"==typeof t&&null!=t&&"function"==typeof t.type?t.type.name||t.type.toString():ie(t)}(o[e]));const a=Yc(s.canSeeViewProviders);s.resolving=!0;const l=s.injectImpl?Ut(s.injectImpl):null;jv(t,i,ge.Default);try{r=t[e]=s.factory(void 0,o,t,i),n.firstCreatePass&&e>=i.directiveStart&&function X1(t,n,e){const{ngOnChanges:i,ngOnInit:r,ngDoCheck:o}=n.type.prototype;if(i){const s=Cv(n);(e.preOrderHooks??=[]).push(t,s),(e.preOrderCheckHooks??=[]).push(t,s)}r&&(e.preOrderHooks??=[]).push(0-t,r),o&&((e.preOrderHooks??=[]).push(t,o),(e.preOrderCheckHooks??=[]).push(t,o))}(e,o[e],n)}finally{null!==l&&Ut(l),Yc(a),s.resolving=!1,Uv()}}return r}function iy(t,n,e){return!!(e[n+(t>>Qv)]&1<<t)}function ry(t,n){return!(t&ge.Self||t&ge.Host&&n)}class Bt{constructor(n,e){this._tNode=n,this._lView=e}get(n,e,i){return ty(this._tNode,this._lView,n,Fc(i),e)}}function dR(){return new Bt(Et(),S())}function fe(t){return fi(()=>{const n=t.prototype.constructor,e=n[pi]||ef(n),i=Object.prototype;let r=Object.getPrototypeOf(t.prototype).constructor;for(;r&&r!==i;){const o=r[pi]||ef(r);if(o&&o!==e)return o;r=Object.getPrototypeOf(r)}return o=>new o})}function ef(t){return _h(t)?()=>{const n=ef(te(t));return n&&n()}:vr(t)}function oy(t){const n=t[P],e=n.type;return 2===e?n.declTNode:1===e?t[It]:null}function bi(t){return function sR(t,n){if("class"===n)return t.classes;if("style"===n)return t.styles;const e=t.attrs;if(e){const i=e.length;let r=0;for(;r<i;){const o=e[r];if(Yb(o))break;if(0===o)r+=2;else if("number"==typeof o)for(r++;r<i&&"string"==typeof e[r];)r++;else{if(o===n)return e[r+1];r+=2}}}return null}(Et(),t)}const _o="__parameters__";function vo(t,n,e){return fi(()=>{const i=function tf(t){return function(...e){if(t){const i=t(...e);for(const r in i)this[r]=i[r]}}}(n);function r(...o){if(this instanceof r)return i.apply(this,o),this;const s=new r(...o);return a.annotation=s,a;function a(c,l,d){const u=c.hasOwnProperty(_o)?c[_o]:Object.defineProperty(c,_o,{value:[]})[_o];for(;u.length<=d;)u.push(null);return(u[d]=u[d]||[]).push(s),c}}return e&&(r.prototype=Object.create(e.prototype)),r.prototype.ngMetadataName=t,r.annotationCls=r,r})}function wo(t,n){t.forEach(e=>Array.isArray(e)?wo(e,n):n(e))}function ay(t,n,e){n>=t.length?t.push(e):t.splice(n,0,e)}function Xc(t,n){return n>=t.length-1?t.pop():t.splice(n,1)[0]}function Ws(t,n){const e=[];for(let i=0;i<t;i++)e.push(n);return e}function nn(t,n,e){let i=xo(t,n);return i>=0?t[1|i]=e:(i=~i,function bR(t,n,e,i){let r=t.length;if(r==n)t.push(e,i);else if(1===r)t.push(i,t[0]),t[0]=e;else{for(r--,t.push(t[r-1],t[r]);r>n;)t[r]=t[r-2],r--;t[n]=e,t[n+1]=i}}(t,i,n,e)),i}function nf(t,n){const e=xo(t,n);if(e>=0)return t[1|e]}function xo(t,n){return function cy(t,n,e){let i=0,r=t.length>>e;for(;r!==i;){const o=i+(r-i>>1),s=t[o<<e];if(n===s)return o<<e;s>n?r=o:i=o+1}return~(r<<e)}(t,n,1)}const xr=Ts(vo("Optional"),8),qs=Ts(vo("SkipSelf"),4);function rl(t){return 128==(128&t.flags)}var qi=function(t){return t[t.Important=1]="Important",t[t.DashCase=2]="DashCase",t}(qi||{});const VR=/^>|^->|<!--|-->|--!>|<!-$/g,jR=/(<|>)/g,HR="\u200b$1\u200b";const lf=new Map;let zR=0;const uf="__ngContext__";function Tt(t,n){Wt(n)?(t[uf]=n[Ns],function UR(t){lf.set(t[Ns],t)}(n)):t[uf]=n}let hf;function ff(t,n){return hf(t,n)}function Ks(t){const n=t[Ge];return Nt(n)?n[Ge]:n}function Iy(t){return Ay(t[Os])}function Ty(t){return Ay(t[Rn])}function Ay(t){for(;null!==t&&!Nt(t);)t=t[Rn];return t}function Eo(t,n,e,i,r){if(null!=i){let o,s=!1;Nt(i)?o=i:Wt(i)&&(s=!0,i=i[Xe]);const a=je(i);0===t&&null!==e?null==r?Fy(n,e,a):Cr(n,e,a,r||null,!0):1===t&&null!==e?Cr(n,e,a,r||null,!0):2===t?function ul(t,n,e){const i=ll(t,n);i&&function lO(t,n,e,i){t.removeChild(n,e,i)}(t,i,n,e)}(n,a,s):3===t&&n.destroyNode(a),null!=o&&function hO(t,n,e,i,r){const o=e[Zn];o!==je(e)&&Eo(n,t,i,o,r);for(let a=Ct;a<e.length;a++){const c=e[a];Xs(c[P],c,t,n,i,o)}}(n,t,o,e,r)}}function pf(t,n){return t.createComment(function yy(t){return t.replace(VR,n=>n.replace(jR,HR))}(n))}function al(t,n,e){return t.createElement(n,e)}function Ry(t,n){const e=t[lo],i=e.indexOf(n);Av(n),e.splice(i,1)}function cl(t,n){if(t.length<=Ct)return;const e=Ct+n,i=t[e];if(i){const r=i[Fs];null!==r&&r!==t&&Ry(r,i),n>0&&(t[e-1][Rn]=i[Rn]);const o=Xc(t,Ct+n);!function tO(t,n){Xs(t,n,n[re],2,null,null),n[Xe]=null,n[It]=null}(i[P],i);const s=o[Yn];null!==s&&s.detachView(o[P]),i[Ge]=null,i[Rn]=null,i[ce]&=-129}return i}function mf(t,n){if(!(256&n[ce])){const e=n[re];n[Ps]&&mv(n[Ps]),n[Ls]&&mv(n[Ls]),e.destroyNode&&Xs(t,n,e,3,null,null),function rO(t){let n=t[Os];if(!n)return gf(t[P],t);for(;n;){let e=null;if(Wt(n))e=n[Os];else{const i=n[Ct];i&&(e=i)}if(!e){for(;n&&!n[Rn]&&n!==t;)Wt(n)&&gf(n[P],n),n=n[Ge];null===n&&(n=t),Wt(n)&&gf(n[P],n),e=n&&n[Rn]}n=e}}(n)}}function gf(t,n){if(!(256&n[ce])){n[ce]&=-129,n[ce]|=256,function cO(t,n){let e;if(null!=t&&null!=(e=t.destroyHooks))for(let i=0;i<e.length;i+=2){const r=n[e[i]];if(!(r instanceof Hs)){const o=e[i+1];if(Array.isArray(o))for(let s=0;s<o.length;s+=2){const a=r[o[s]],c=o[s+1];Kn(4,a,c);try{c.call(a)}finally{Kn(5,a,c)}}else{Kn(4,r,o);try{o.call(r)}finally{Kn(5,r,o)}}}}}(t,n),function aO(t,n){const e=t.cleanup,i=n[oo];if(null!==e)for(let o=0;o<e.length-1;o+=2)if("string"==typeof e[o]){const s=e[o+3];s>=0?i[s]():i[-s].unsubscribe(),o+=2}else e[o].call(i[e[o+1]]);null!==i&&(n[oo]=null);const r=n[$i];if(null!==r){n[$i]=null;for(let o=0;o<r.length;o++)(0,r[o])()}}(t,n),1===n[P].type&&n[re].destroy();const e=n[Fs];if(null!==e&&Nt(n[Ge])){e!==n[Ge]&&Ry(e,n);const i=n[Yn];null!==i&&i.detachView(t)}!function GR(t){lf.delete(t[Ns])}(n)}}function _f(t,n,e){return function Oy(t,n,e){let i=n;for(;null!==i&&40&i.type;)i=(n=i).parent;if(null===i)return e[Xe];{const{componentOffset:r}=i;if(r>-1){const{encapsulation:o}=t.data[i.directiveStart+r];if(o===gn.None||o===gn.Emulated)return null}return qt(i,e)}}(t,n.parent,e)}function Cr(t,n,e,i,r){t.insertBefore(n,e,i,r)}function Fy(t,n,e){t.appendChild(n,e)}function Ny(t,n,e,i,r){null!==i?Cr(t,n,e,i,r):Fy(t,n,e)}function ll(t,n){return t.parentNode(n)}function Py(t,n,e){return By(t,n,e)}let bf,hl,xf,By=function Ly(t,n,e){return 40&t.type?qt(t,e):null};function dl(t,n,e,i){const r=_f(t,i,n),o=n[re],a=Py(i.parent||n[It],i,n);if(null!=r)if(Array.isArray(e))for(let c=0;c<e.length;c++)Ny(o,r,e[c],a,!1);else Ny(o,r,e,a,!1);void 0!==bf&&bf(o,i,n,e,r)}function Qs(t,n){if(null!==n){const e=n.type;if(3&e)return qt(n,t);if(4&e)return vf(-1,t[n.index]);if(8&e){const i=n.child;if(null!==i)return Qs(t,i);{const r=t[n.index];return Nt(r)?vf(-1,r):je(r)}}if(32&e)return ff(n,t)()||je(t[n.index]);{const i=jy(t,n);return null!==i?Array.isArray(i)?i[0]:Qs(Ks(t[rt]),i):Qs(t,n.next)}}return null}function jy(t,n){return null!==n?t[rt][It].projection[n.projection]:null}function vf(t,n){const e=Ct+t+1;if(e<n.length){const i=n[e],r=i[P].firstChild;if(null!==r)return Qs(i,r)}return n[Zn]}function yf(t,n,e,i,r,o,s){for(;null!=e;){const a=i[e.index],c=e.type;if(s&&0===n&&(a&&Tt(je(a),i),e.flags|=2),32!=(32&e.flags))if(8&c)yf(t,n,e.child,i,r,o,!1),Eo(n,t,r,a,o);else if(32&c){const l=ff(e,i);let d;for(;d=l();)Eo(n,t,r,d,o);Eo(n,t,r,a,o)}else 16&c?zy(t,n,i,e,r,o):Eo(n,t,r,a,o);e=s?e.projectionNext:e.next}}function Xs(t,n,e,i,r,o){yf(e,i,t.firstChild,n,r,o,!1)}function zy(t,n,e,i,r,o){const s=e[rt],c=s[It].projection[i.projection];if(Array.isArray(c))for(let l=0;l<c.length;l++)Eo(n,t,r,c[l],o);else{let l=c;const d=s[Ge];rl(i)&&(l.flags|=128),yf(t,n,l,d,r,o,!0)}}function $y(t,n,e){""===e?t.removeAttribute(n,"class"):t.setAttribute(n,"class",e)}function Uy(t,n,e){const{mergedAttrs:i,classes:r,styles:o}=e;null!==i&&Ah(t,n,i),null!==r&&$y(t,n,r),null!==o&&function pO(t,n,e){t.setAttribute(n,"style",e)}(t,n,o)}function So(t){return function wf(){if(void 0===hl&&(hl=null,Ve.trustedTypes))try{hl=Ve.trustedTypes.createPolicy("angular",{createHTML:t=>t,createScript:t=>t,createScriptURL:t=>t})}catch{}return hl}()?.createHTML(t)||t}class Dr{constructor(n){this.changingThisBreaksApplicationSecurity=n}toString(){return`SafeValue must use [property]=binding: ${this.changingThisBreaksApplicationSecurity} (see ${Lb})`}}class vO extends Dr{getTypeName(){return"HTML"}}class yO extends Dr{getTypeName(){return"Style"}}class wO extends Dr{getTypeName(){return"Script"}}class xO extends Dr{getTypeName(){return"URL"}}class CO extends Dr{getTypeName(){return"ResourceURL"}}function rn(t){return t instanceof Dr?t.changingThisBreaksApplicationSecurity:t}function Jn(t,n){const e=function DO(t){return t instanceof Dr&&t.getTypeName()||null}(t);if(null!=e&&e!==n){if("ResourceURL"===e&&"URL"===n)return!0;throw new Error(`Required a safe ${n}, got a ${e} (see ${Lb})`)}return e===n}class AO{constructor(n){this.inertDocumentHelper=n}getInertBodyElement(n){n="<body><remove></remove>"+n;try{const e=(new window.DOMParser).parseFromString(So(n),"text/html").body;return null===e?this.inertDocumentHelper.getInertBodyElement(n):(e.removeChild(e.firstChild),e)}catch{return null}}}class kO{constructor(n){this.defaultDoc=n,this.inertDocument=this.defaultDoc.implementation.createHTMLDocument("sanitization-inert")}getInertBodyElement(n){const e=this.inertDocument.createElement("template");return e.innerHTML=So(n),e}}const OO=/^(?!javascript:)(?:[a-z0-9+.-]+:|[^&:\/?#]*(?:[\/?#]|$))/i;function pl(t){return(t=String(t)).match(OO)?t:"unsafe:"+t}function vi(t){const n={};for(const e of t.split(","))n[e]=!0;return n}function Js(...t){const n={};for(const e of t)for(const i in e)e.hasOwnProperty(i)&&(n[i]=!0);return n}const Zy=vi("area,br,col,hr,img,wbr"),Ky=vi("colgroup,dd,dt,li,p,tbody,td,tfoot,th,thead,tr"),Qy=vi("rp,rt"),Df=Js(Zy,Js(Ky,vi("address,article,aside,blockquote,caption,center,del,details,dialog,dir,div,dl,figure,figcaption,footer,h1,h2,h3,h4,h5,h6,header,hgroup,hr,ins,main,map,menu,nav,ol,pre,section,summary,table,ul")),Js(Qy,vi("a,abbr,acronym,audio,b,bdi,bdo,big,br,cite,code,del,dfn,em,font,i,img,ins,kbd,label,map,mark,picture,q,ruby,rp,rt,s,samp,small,source,span,strike,strong,sub,sup,time,track,tt,u,var,video")),Js(Qy,Ky)),Ef=vi("background,cite,href,itemtype,longdesc,poster,src,xlink:href"),Xy=Js(Ef,vi("abbr,accesskey,align,alt,autoplay,axis,bgcolor,border,cellpadding,cellspacing,class,clear,color,cols,colspan,compact,controls,coords,datetime,default,dir,download,face,headers,height,hidden,hreflang,hspace,ismap,itemscope,itemprop,kind,label,lang,language,loop,media,muted,nohref,nowrap,open,preload,rel,rev,role,rows,rowspan,rules,scope,scrolling,shape,size,sizes,span,srclang,srcset,start,summary,tabindex,target,title,translate,type,usemap,valign,value,vspace,width"),vi("aria-activedescendant,aria-atomic,aria-autocomplete,aria-busy,aria-checked,aria-colcount,aria-colindex,aria-colspan,aria-controls,aria-current,aria-describedby,aria-details,aria-disabled,aria-dropeffect,aria-errormessage,aria-expanded,aria-flowto,aria-grabbed,aria-haspopup,aria-hidden,aria-invalid,aria-keyshortcuts,aria-label,aria-labelledby,aria-level,aria-live,aria-modal,aria-multiline,aria-multiselectable,aria-orientation,aria-owns,aria-placeholder,aria-posinset,aria-pressed,aria-readonly,aria-relevant,aria-required,aria-roledescription,aria-rowcount,aria-rowindex,aria-rowspan,aria-selected,aria-setsize,aria-sort,aria-valuemax,aria-valuemin,aria-valuenow,aria-valuetext")),FO=vi("script,style,template");class NO{constructor(){this.sanitizedSomething=!1,this.buf=[]}sanitizeChildren(n){let e=n.firstChild,i=!0;for(;e;)if(e.nodeType===Node.ELEMENT_NODE?i=this.startElement(e):e.nodeType===Node.TEXT_NODE?this.chars(e.nodeValue):this.sanitizedSomething=!0,i&&e.firstChild)e=e.firstChild;else for(;e;){e.nodeType===Node.ELEMENT_NODE&&this.endElement(e);let r=this.checkClobberedElement(e,e.nextSibling);if(r){e=r;break}e=this.checkClobberedElement(e,e.parentNode)}return this.buf.join("")}startElement(n){const e=n.nodeName.toLowerCase();if(!Df.hasOwnProperty(e))return this.sanitizedSomething=!0,!FO.hasOwnProperty(e);this.buf.push("<"),this.buf.push(e);const i=n.attributes;for(let r=0;r<i.length;r++){const o=i.item(r),s=o.name,a=s.toLowerCase();if(!Xy.hasOwnProperty(a)){this.sanitizedSomething=!0;continue}let c=o.value;Ef[a]&&(c=pl(c)),this.buf.push(" ",s,'="',Jy(c),'"')}return this.buf.push(">"),!0}endElement(n){const e=n.nodeName.toLowerCase();Df.hasOwnProperty(e)&&!Zy.hasOwnProperty(e)&&(this.buf.push("</"),this.buf.push(e),this.buf.push(">"))}chars(n){this.buf.push(Jy(n))}checkClobberedElement(n,e){if(e&&(n.compareDocumentPosition(e)&Node.DOCUMENT_POSITION_CONTAINED_BY)===Node.DOCUMENT_POSITION_CONTAINED_BY)throw new Error(`Failed to sanitize html because the element is clobbered: ${n.outerHTML}`);return e}}const PO=/[\uD800-\uDBFF][\uDC00-\uDFFF]/g,LO=/([^\#-~ |!])/g;function Jy(t){return t.replace(/&/g,"&amp;").replace(PO,function(n){return"&#"+(1024*(n.charCodeAt(0)-55296)+(n.charCodeAt(1)-56320)+65536)+";"}).replace(LO,function(n){return"&#"+n.charCodeAt(0)+";"}).replace(/</g,"&lt;").replace(/>/g,"&gt;")}let ml;function e0(t,n){let e=null;try{ml=ml||function Yy(t){const n=new kO(t);return function RO(){try{return!!(new window.DOMParser).parseFromString(So(""),"text/html")}catch{return!1}}()?new AO(n):n}(t);let i=n?String(n):"";e=ml.getInertBodyElement(i);let r=5,o=i;do{if(0===r)throw new Error("Failed to sanitize html because the input is unstable");r--,i=o,o=e.innerHTML,e=ml.getInertBodyElement(i)}while(i!==o);return So((new NO).sanitizeChildren(Sf(e)||e))}finally{if(e){const i=Sf(e)||e;for(;i.firstChild;)i.removeChild(i.firstChild)}}}function Sf(t){return"content"in t&&function BO(t){return t.nodeType===Node.ELEMENT_NODE&&"TEMPLATE"===t.nodeName}(t)?t.content:null}var St=function(t){return t[t.NONE=0]="NONE",t[t.HTML=1]="HTML",t[t.STYLE=2]="STYLE",t[t.SCRIPT=3]="SCRIPT",t[t.URL=4]="URL",t[t.RESOURCE_URL=5]="RESOURCE_URL",t}(St||{});class E{constructor(n,e){this._desc=n,this.ngMetadataName="InjectionToken",this.\u0275prov=void 0,"number"==typeof e?this.__NG_ELEMENT_ID__=e:void 0!==e&&(this.\u0275prov=I({token:this,providedIn:e.providedIn||"root",factory:e.factory}))}get multi(){return this}toString(){return`InjectionToken ${this._desc}`}}const ta=new E("ENVIRONMENT_INITIALIZER"),o0=new E("INJECTOR",-1),s0=new E("INJECTOR_DEF_TYPES");class Mf{get(n,e=Is){if(e===Is){const i=new Error(`NullInjectorError: No provider for ${pt(n)}!`);throw i.name="NullInjectorError",i}return e}}function GO(...t){return{\u0275providers:a0(0,t),\u0275fromNgModule:!0}}function a0(t,...n){const e=[],i=new Set;let r;const o=s=>{e.push(s)};return wo(n,s=>{const a=s;gl(a,o,[],i)&&(r||=[],r.push(a))}),void 0!==r&&c0(r,o),e}function c0(t,n){for(let e=0;e<t.length;e++){const{ngModule:i,providers:r}=t[e];Tf(r,o=>{n(o,i)})}}function gl(t,n,e,i){if(!(t=te(t)))return!1;let r=null,o=kc(t);const s=!o&&ye(t);if(o||s){if(s&&!s.standalone)return!1;r=t}else{const c=t.ngModule;if(o=kc(c),!o)return!1;r=c}const a=i.has(r);if(s){if(a)return!1;if(i.add(r),s.dependencies){const c="function"==typeof s.dependencies?s.dependencies():s.dependencies;for(const l of c)gl(l,n,e,i)}}else{if(!o)return!1;{if(null!=o.imports&&!a){let l;i.add(r);try{wo(o.imports,d=>{gl(d,n,e,i)&&(l||=[],l.push(d))})}finally{}void 0!==l&&c0(l,n)}if(!a){const l=vr(r)||(()=>new r);n({provide:r,useFactory:l,deps:De},r),n({provide:s0,useValue:r,multi:!0},r),n({provide:ta,useValue:()=>b(r),multi:!0},r)}const c=o.providers;if(null!=c&&!a){const l=t;Tf(c,d=>{n(d,l)})}}}return r!==t&&void 0!==t.providers}function Tf(t,n){for(let e of t)bh(e)&&(e=e.\u0275providers),Array.isArray(e)?Tf(e,n):n(e)}const WO=ke({provide:String,useValue:ke});function Af(t){return null!==t&&"object"==typeof t&&WO in t}function Er(t){return"function"==typeof t}const kf=new E("Set Injector scope."),_l={},YO={};let Rf;function bl(){return void 0===Rf&&(Rf=new Mf),Rf}class on{}class vl extends on{get destroyed(){return this._destroyed}constructor(n,e,i,r){super(),this.parent=e,this.source=i,this.scopes=r,this.records=new Map,this._ngOnDestroyHooks=new Set,this._onDestroyHooks=[],this._destroyed=!1,Ff(n,s=>this.processProvider(s)),this.records.set(o0,Io(void 0,this)),r.has("environment")&&this.records.set(on,Io(void 0,this));const o=this.records.get(kf);null!=o&&"string"==typeof o.value&&this.scopes.add(o.value),this.injectorDefTypes=new Set(this.get(s0.multi,De,ge.Self))}destroy(){this.assertNotDestroyed(),this._destroyed=!0;try{for(const e of this._ngOnDestroyHooks)e.ngOnDestroy();const n=this._onDestroyHooks;this._onDestroyHooks=[];for(const e of n)e()}finally{this.records.clear(),this._ngOnDestroyHooks.clear(),this.injectorDefTypes.clear()}}onDestroy(n){return this.assertNotDestroyed(),this._onDestroyHooks.push(n),()=>this.removeOnDestroy(n)}runInContext(n){this.assertNotDestroyed();const e=Hi(this),i=Ut(void 0);try{return n()}finally{Hi(e),Ut(i)}}get(n,e=Is,i=ge.Default){if(this.assertNotDestroyed(),n.hasOwnProperty(Wb))return n[Wb](this);i=Fc(i);const o=Hi(this),s=Ut(void 0);try{if(!(i&ge.SkipSelf)){let c=this.records.get(n);if(void 0===c){const l=function JO(t){return"function"==typeof t||"object"==typeof t&&t instanceof E}(n)&&Ac(n);c=l&&this.injectableDefInScope(l)?Io(Of(n),_l):null,this.records.set(n,c)}if(null!=c)return this.hydrate(n,c)}return(i&ge.Self?bl():this.parent).get(n,e=i&ge.Optional&&e===Is?null:e)}catch(a){if("NullInjectorError"===a.name){if((a[Oc]=a[Oc]||[]).unshift(pt(n)),o)throw a;return function Gk(t,n,e,i){const r=t[Oc];throw n[$b]&&r.unshift(n[$b]),t.message=function Wk(t,n,e,i=null){t=t&&"\n"===t.charAt(0)&&"\u0275"==t.charAt(1)?t.slice(2):t;let r=pt(n);if(Array.isArray(n))r=n.map(pt).join(" -> ");else if("object"==typeof n){let o=[];for(let s in n)if(n.hasOwnProperty(s)){let a=n[s];o.push(s+":"+("string"==typeof a?JSON.stringify(a):pt(a)))}r=`{${o.join(", ")}}`}return`${e}${i?"("+i+")":""}[${r}]: ${t.replace(jk,"\n  ")}`}("\n"+t.message,r,e,i),t.ngTokenPath=r,t[Oc]=null,t}(a,n,"R3InjectorError",this.source)}throw a}finally{Ut(s),Hi(o)}}resolveInjectorInitializers(){const n=Hi(this),e=Ut(void 0);try{const r=this.get(ta.multi,De,ge.Self);for(const o of r)o()}finally{Hi(n),Ut(e)}}toString(){const n=[],e=this.records;for(const i of e.keys())n.push(pt(i));return`R3Injector[${n.join(", ")}]`}assertNotDestroyed(){if(this._destroyed)throw new y(205,!1)}processProvider(n){let e=Er(n=te(n))?n:te(n&&n.provide);const i=function KO(t){return Af(t)?Io(void 0,t.useValue):Io(u0(t),_l)}(n);if(Er(n)||!0!==n.multi)this.records.get(e);else{let r=this.records.get(e);r||(r=Io(void 0,_l,!0),r.factory=()=>Mh(r.multi),this.records.set(e,r)),e=n,r.multi.push(n)}this.records.set(e,i)}hydrate(n,e){return e.value===_l&&(e.value=YO,e.value=e.factory()),"object"==typeof e.value&&e.value&&function XO(t){return null!==t&&"object"==typeof t&&"function"==typeof t.ngOnDestroy}(e.value)&&this._ngOnDestroyHooks.add(e.value),e.value}injectableDefInScope(n){if(!n.providedIn)return!1;const e=te(n.providedIn);return"string"==typeof e?"any"===e||this.scopes.has(e):this.injectorDefTypes.has(e)}removeOnDestroy(n){const e=this._onDestroyHooks.indexOf(n);-1!==e&&this._onDestroyHooks.splice(e,1)}}function Of(t){const n=Ac(t),e=null!==n?n.factory:vr(t);if(null!==e)return e;if(t instanceof E)throw new y(204,!1);if(t instanceof Function)return function ZO(t){const n=t.length;if(n>0)throw Ws(n,"?"),new y(204,!1);const e=function Pk(t){return t&&(t[Rc]||t[Vb])||null}(t);return null!==e?()=>e.factory(t):()=>new t}(t);throw new y(204,!1)}function u0(t,n,e){let i;if(Er(t)){const r=te(t);return vr(r)||Of(r)}if(Af(t))i=()=>te(t.useValue);else if(function d0(t){return!(!t||!t.useFactory)}(t))i=()=>t.useFactory(...Mh(t.deps||[]));else if(function l0(t){return!(!t||!t.useExisting)}(t))i=()=>b(te(t.useExisting));else{const r=te(t&&(t.useClass||t.provide));if(!function QO(t){return!!t.deps}(t))return vr(r)||Of(r);i=()=>new r(...Mh(t.deps))}return i}function Io(t,n,e=!1){return{factory:t,value:n,multi:e?[]:void 0}}function Ff(t,n){for(const e of t)Array.isArray(e)?Ff(e,n):e&&bh(e)?Ff(e.\u0275providers,n):n(e)}const yl=new E("AppId",{providedIn:"root",factory:()=>eF}),eF="ng",h0=new E("Platform Initializer"),Yi=new E("Platform ID",{providedIn:"platform",factory:()=>"unknown"}),gt=new E("AnimationModuleType"),Nf=new E("CSP nonce",{providedIn:"root",factory:()=>function Mo(){if(void 0!==xf)return xf;if(typeof document<"u")return document;throw new y(210,!1)}().body?.querySelector("[ngCspNonce]")?.getAttribute("ngCspNonce")||null});let f0=(t,n,e)=>null;function $f(t,n,e=!1){return f0(t,n,e)}class dF{}class g0{}class hF{resolveComponentFactory(n){throw function uF(t){const n=Error(`No component factory found for ${pt(t)}.`);return n.ngComponent=t,n}(n)}}let Sr=(()=>{class t{static#e=this.NULL=new hF}return t})();function fF(){return ko(Et(),S())}function ko(t,n){return new $(qt(t,n))}let $=(()=>{class t{constructor(e){this.nativeElement=e}static#e=this.__NG_ELEMENT_ID__=fF}return t})();function pF(t){return t instanceof $?t.nativeElement:t}class ra{}let yi=(()=>{class t{constructor(){this.destroyNode=null}static#e=this.__NG_ELEMENT_ID__=()=>function mF(){const t=S(),e=tn(Et().index,t);return(Wt(e)?e:t)[re]}()}return t})(),gF=(()=>{class t{static#e=this.\u0275prov=I({token:t,providedIn:"root",factory:()=>null})}return t})();class Mr{constructor(n){this.full=n,this.major=n.split(".")[0],this.minor=n.split(".")[1],this.patch=n.split(".").slice(2).join(".")}}const _F=new Mr("16.2.7"),Wf={};function w0(t,n=null,e=null,i){const r=x0(t,n,e,i);return r.resolveInjectorInitializers(),r}function x0(t,n=null,e=null,i,r=new Set){const o=[e||De,GO(t)];return i=i||("object"==typeof t?void 0:pt(t)),new vl(o,n||bl(),i||null,r)}let We=(()=>{class t{static#e=this.THROW_IF_NOT_FOUND=Is;static#t=this.NULL=new Mf;static create(e,i){if(Array.isArray(e))return w0({name:""},i,e,"");{const r=e.name??"";return w0({name:r},e.parent,e.providers,r)}}static#n=this.\u0275prov=I({token:t,providedIn:"any",factory:()=>b(o0)});static#i=this.__NG_ELEMENT_ID__=-1}return t})();function Yf(t){return t.ngOriginalError}class vn{constructor(){this._console=console}handleError(n){const e=this._findOriginalError(n);this._console.error("ERROR",n),e&&this._console.error("ORIGINAL ERROR",e)}_findOriginalError(n){let e=n&&Yf(n);for(;e&&Yf(e);)e=Yf(e);return e||null}}function Kf(t){return n=>{setTimeout(t,void 0,n)}}const q=class DF extends U{constructor(n=!1){super(),this.__isAsync=n}emit(n){super.next(n)}subscribe(n,e,i){let r=n,o=e||(()=>null),s=i;if(n&&"object"==typeof n){const c=n;r=c.next?.bind(c),o=c.error?.bind(c),s=c.complete?.bind(c)}this.__isAsync&&(o=Kf(o),r&&(r=Kf(r)),s&&(s=Kf(s)));const a=super.subscribe({next:r,error:o,complete:s});return n instanceof Le&&n.add(a),a}};function D0(...t){}class j{constructor({enableLongStackTrace:n=!1,shouldCoalesceEventChangeDetection:e=!1,shouldCoalesceRunChangeDetection:i=!1}){if(this.hasPendingMacrotasks=!1,this.hasPendingMicrotasks=!1,this.isStable=!0,this.onUnstable=new q(!1),this.onMicrotaskEmpty=new q(!1),this.onStable=new q(!1),this.onError=new q(!1),typeof Zone>"u")throw new y(908,!1);Zone.assertZonePatched();const r=this;r._nesting=0,r._outer=r._inner=Zone.current,Zone.TaskTrackingZoneSpec&&(r._inner=r._inner.fork(new Zone.TaskTrackingZoneSpec)),n&&Zone.longStackTraceZoneSpec&&(r._inner=r._inner.fork(Zone.longStackTraceZoneSpec)),r.shouldCoalesceEventChangeDetection=!i&&e,r.shouldCoalesceRunChangeDetection=i,r.lastRequestAnimationFrameId=-1,r.nativeRequestAnimationFrame=function EF(){const t="function"==typeof Ve.requestAnimationFrame;let n=Ve[t?"requestAnimationFrame":"setTimeout"],e=Ve[t?"cancelAnimationFrame":"clearTimeout"];if(typeof Zone<"u"&&n&&e){const i=n[Zone.__symbol__("OriginalDelegate")];i&&(n=i);const r=e[Zone.__symbol__("OriginalDelegate")];r&&(e=r)}return{nativeRequestAnimationFrame:n,nativeCancelAnimationFrame:e}}().nativeRequestAnimationFrame,function IF(t){const n=()=>{!function MF(t){t.isCheckStableRunning||-1!==t.lastRequestAnimationFrameId||(t.lastRequestAnimationFrameId=t.nativeRequestAnimationFrame.call(Ve,()=>{t.fakeTopEventTask||(t.fakeTopEventTask=Zone.root.scheduleEventTask("fakeTopEventTask",()=>{t.lastRequestAnimationFrameId=-1,Xf(t),t.isCheckStableRunning=!0,Qf(t),t.isCheckStableRunning=!1},void 0,()=>{},()=>{})),t.fakeTopEventTask.invoke()}),Xf(t))}(t)};t._inner=t._inner.fork({name:"angular",properties:{isAngularZone:!0},onInvokeTask:(e,i,r,o,s,a)=>{if(function AF(t){return!(!Array.isArray(t)||1!==t.length)&&!0===t[0].data?.__ignore_ng_zone__}(a))return e.invokeTask(r,o,s,a);try{return E0(t),e.invokeTask(r,o,s,a)}finally{(t.shouldCoalesceEventChangeDetection&&"eventTask"===o.type||t.shouldCoalesceRunChangeDetection)&&n(),S0(t)}},onInvoke:(e,i,r,o,s,a,c)=>{try{return E0(t),e.invoke(r,o,s,a,c)}finally{t.shouldCoalesceRunChangeDetection&&n(),S0(t)}},onHasTask:(e,i,r,o)=>{e.hasTask(r,o),i===r&&("microTask"==o.change?(t._hasPendingMicrotasks=o.microTask,Xf(t),Qf(t)):"macroTask"==o.change&&(t.hasPendingMacrotasks=o.macroTask))},onHandleError:(e,i,r,o)=>(e.handleError(r,o),t.runOutsideAngular(()=>t.onError.emit(o)),!1)})}(r)}static isInAngularZone(){return typeof Zone<"u"&&!0===Zone.current.get("isAngularZone")}static assertInAngularZone(){if(!j.isInAngularZone())throw new y(909,!1)}static assertNotInAngularZone(){if(j.isInAngularZone())throw new y(909,!1)}run(n,e,i){return this._inner.run(n,e,i)}runTask(n,e,i,r){const o=this._inner,s=o.scheduleEventTask("NgZoneEvent: "+r,n,SF,D0,D0);try{return o.runTask(s,e,i)}finally{o.cancelTask(s)}}runGuarded(n,e,i){return this._inner.runGuarded(n,e,i)}runOutsideAngular(n){return this._outer.run(n)}}const SF={};function Qf(t){if(0==t._nesting&&!t.hasPendingMicrotasks&&!t.isStable)try{t._nesting++,t.onMicrotaskEmpty.emit(null)}finally{if(t._nesting--,!t.hasPendingMicrotasks)try{t.runOutsideAngular(()=>t.onStable.emit(null))}finally{t.isStable=!0}}}function Xf(t){t.hasPendingMicrotasks=!!(t._hasPendingMicrotasks||(t.shouldCoalesceEventChangeDetection||t.shouldCoalesceRunChangeDetection)&&-1!==t.lastRequestAnimationFrameId)}function E0(t){t._nesting++,t.isStable&&(t.isStable=!1,t.onUnstable.emit(null))}function S0(t){t._nesting--,Qf(t)}class TF{constructor(){this.hasPendingMicrotasks=!1,this.hasPendingMacrotasks=!1,this.isStable=!0,this.onUnstable=new q,this.onMicrotaskEmpty=new q,this.onStable=new q,this.onError=new q}run(n,e,i){return n.apply(e,i)}runGuarded(n,e,i){return n.apply(e,i)}runOutsideAngular(n){return n()}runTask(n,e,i,r){return n.apply(e,i)}}const M0=new E("",{providedIn:"root",factory:I0});function I0(){const t=T(j);let n=!0;return An(new Ne(r=>{n=t.isStable&&!t.hasPendingMacrotasks&&!t.hasPendingMicrotasks,t.runOutsideAngular(()=>{r.next(n),r.complete()})}),new Ne(r=>{let o;t.runOutsideAngular(()=>{o=t.onStable.subscribe(()=>{j.assertNotInAngularZone(),queueMicrotask(()=>{!n&&!t.hasPendingMacrotasks&&!t.hasPendingMicrotasks&&(n=!0,r.next(!0))})})});const s=t.onUnstable.subscribe(()=>{j.assertInAngularZone(),n&&(n=!1,t.runOutsideAngular(()=>{r.next(!1)}))});return()=>{o.unsubscribe(),s.unsubscribe()}}).pipe(Sc()))}function wi(t){return t instanceof Function?t():t}let Jf=(()=>{class t{constructor(){this.renderDepth=0,this.handler=null}begin(){this.handler?.validateBegin(),this.renderDepth++}end(){this.renderDepth--,0===this.renderDepth&&this.handler?.execute()}ngOnDestroy(){this.handler?.destroy(),this.handler=null}static#e=this.\u0275prov=I({token:t,providedIn:"root",factory:()=>new t})}return t})();function oa(t){for(;t;){t[ce]|=64;const n=Ks(t);if(Oh(t)&&!n)return t;t=n}return null}const O0=new E("",{providedIn:"root",factory:()=>!1});let Ml=null;function L0(t,n){return t[n]??j0()}function B0(t,n){const e=j0();e.producerNode?.length&&(t[n]=Ml,e.lView=t,Ml=V0())}const jF={...lv,consumerIsAlwaysLive:!0,consumerMarkedDirty:t=>{oa(t.lView)},lView:null};function V0(){return Object.create(jF)}function j0(){return Ml??=V0(),Ml}const se={};function M(t){H0(we(),S(),Lt()+t,!1)}function H0(t,n,e,i){if(!i)if(3==(3&n[ce])){const o=t.preOrderCheckHooks;null!==o&&Wc(n,o,e)}else{const o=t.preOrderHooks;null!==o&&qc(n,o,0,e)}yr(e)}function f(t,n=ge.Default){const e=S();return null===e?b(t,n):ty(Et(),e,te(t),n)}function Il(){throw new Error("invalid")}function Tl(t,n,e,i,r,o,s,a,c,l,d){const u=n.blueprint.slice();return u[Xe]=r,u[ce]=140|i,(null!==l||t&&2048&t[ce])&&(u[ce]|=2048),Tv(u),u[Ge]=u[ao]=t,u[it]=e,u[so]=s||t&&t[so],u[re]=a||t&&t[re],u[zi]=c||t&&t[zi]||null,u[It]=o,u[Ns]=function $R(){return zR++}(),u[mi]=d,u[ov]=l,u[rt]=2==n.type?t[rt]:u,u}function Fo(t,n,e,i,r){let o=t.data[n];if(null===o)o=function ep(t,n,e,i,r){const o=Nv(),s=Hh(),c=t.data[n]=function YF(t,n,e,i,r,o){let s=n?n.injectorIndex:-1,a=0;return fo()&&(a|=128),{type:e,index:i,insertBeforeIndex:null,injectorIndex:s,directiveStart:-1,directiveEnd:-1,directiveStylingLast:-1,componentOffset:-1,propertyBindings:null,flags:a,providerIndexes:0,value:r,attrs:o,mergedAttrs:null,localNames:null,initialInputs:void 0,inputs:null,outputs:null,tView:null,next:null,prev:null,projectionNext:null,child:null,parent:n,projection:null,styles:null,stylesWithoutHost:null,residualStyles:void 0,classes:null,classesWithoutHost:null,residualClasses:void 0,classBindings:0,styleBindings:0}}(0,s?o:o&&o.parent,e,n,i,r);return null===t.firstChild&&(t.firstChild=c),null!==o&&(s?null==o.child&&null!==c.parent&&(o.child=c):null===o.next&&(o.next=c,c.prev=o)),c}(t,n,e,i,r),function U1(){return ee.lFrame.inI18n}()&&(o.flags|=32);else if(64&o.type){o.type=e,o.value=i,o.attrs=r;const s=function js(){const t=ee.lFrame,n=t.currentTNode;return t.isParent?n:n.parent}();o.injectorIndex=null===s?-1:s.injectorIndex}return Qn(o,!0),o}function sa(t,n,e,i){if(0===e)return-1;const r=n.length;for(let o=0;o<e;o++)n.push(i),t.blueprint.push(i),t.data.push(null);return r}function z0(t,n,e,i,r){const o=L0(n,Ps),s=Lt(),a=2&i;try{yr(-1),a&&n.length>_e&&H0(t,n,_e,!1),Kn(a?2:0,r);const l=a?o:null,d=Nh(l);try{null!==l&&(l.dirty=!1),e(i,r)}finally{Ph(l,d)}}finally{a&&null===n[Ps]&&B0(n,Ps),yr(s),Kn(a?3:1,r)}}function tp(t,n,e){if(Rh(n)){const i=_n(null);try{const o=n.directiveEnd;for(let s=n.directiveStart;s<o;s++){const a=t.data[s];a.contentQueries&&a.contentQueries(1,e[s],s)}}finally{_n(i)}}}function np(t,n,e){Fv()&&(function tN(t,n,e,i){const r=e.directiveStart,o=e.directiveEnd;br(e)&&function cN(t,n,e){const i=qt(n,t),r=$0(e);let s=16;e.signals?s=4096:e.onPush&&(s=64);const a=Al(t,Tl(t,r,null,s,i,n,null,t[so].rendererFactory.createRenderer(i,e),null,null,null));t[n.index]=a}(n,e,t.data[r+e.componentOffset]),t.firstCreatePass||Zc(e,n),Tt(i,n);const s=e.initialInputs;for(let a=r;a<o;a++){const c=t.data[a],l=wr(n,t,a,e);Tt(l,n),null!==s&&lN(0,a-r,l,c,0,s),On(c)&&(tn(e.index,n)[it]=wr(n,t,a,e))}}(t,n,e,qt(e,n)),64==(64&e.flags)&&Y0(t,n,e))}function ip(t,n,e=qt){const i=n.localNames;if(null!==i){let r=n.index+1;for(let o=0;o<i.length;o+=2){const s=i[o+1],a=-1===s?e(n,t):t[s];t[r++]=a}}}function $0(t){const n=t.tView;return null===n||n.incompleteFirstPass?t.tView=rp(1,null,t.template,t.decls,t.vars,t.directiveDefs,t.pipeDefs,t.viewQuery,t.schemas,t.consts,t.id):n}function rp(t,n,e,i,r,o,s,a,c,l,d){const u=_e+i,h=u+r,p=function zF(t,n){const e=[];for(let i=0;i<n;i++)e.push(i<t?null:se);return e}(u,h),m="function"==typeof l?l():l;return p[P]={type:t,blueprint:p,template:e,queries:null,viewQuery:a,declTNode:n,data:p.slice().fill(null,u),bindingStartIndex:u,expandoStartIndex:h,hostBindingOpCodes:null,firstCreatePass:!0,firstUpdatePass:!0,staticViewQueries:!1,staticContentQueries:!1,preOrderHooks:null,preOrderCheckHooks:null,contentHooks:null,contentCheckHooks:null,viewHooks:null,viewCheckHooks:null,destroyHooks:null,cleanup:null,contentQueries:null,components:null,directiveRegistry:"function"==typeof o?o():o,pipeRegistry:"function"==typeof s?s():s,firstChild:null,schemas:c,consts:m,incompleteFirstPass:!1,ssrId:d}}let U0=t=>null;function G0(t,n,e,i){for(let r in t)if(t.hasOwnProperty(r)){e=null===e?{}:e;const o=t[r];null===i?W0(e,n,r,o):i.hasOwnProperty(r)&&W0(e,n,i[r],o)}return e}function W0(t,n,e,i){t.hasOwnProperty(e)?t[e].push(n,i):t[e]=[n,i]}function sn(t,n,e,i,r,o,s,a){const c=qt(n,e);let d,l=n.inputs;!a&&null!=l&&(d=l[i])?(lp(t,e,d,i,r),br(n)&&function QF(t,n){const e=tn(n,t);16&e[ce]||(e[ce]|=64)}(e,n.index)):3&n.type&&(i=function KF(t){return"class"===t?"className":"for"===t?"htmlFor":"formaction"===t?"formAction":"innerHtml"===t?"innerHTML":"readonly"===t?"readOnly":"tabindex"===t?"tabIndex":t}(i),r=null!=s?s(r,n.value||"",i):r,o.setProperty(c,i,r))}function op(t,n,e,i){if(Fv()){const r=null===i?null:{"":-1},o=function iN(t,n){const e=t.directiveRegistry;let i=null,r=null;if(e)for(let o=0;o<e.length;o++){const s=e[o];if(Jb(n,s.selectors,!1))if(i||(i=[]),On(s))if(null!==s.findHostDirectiveDefs){const a=[];r=r||new Map,s.findHostDirectiveDefs(s,a,r),i.unshift(...a,s),sp(t,n,a.length)}else i.unshift(s),sp(t,n,0);else r=r||new Map,s.findHostDirectiveDefs?.(s,i,r),i.push(s)}return null===i?null:[i,r]}(t,e);let s,a;null===o?s=a=null:[s,a]=o,null!==s&&q0(t,n,e,s,r,a),r&&function rN(t,n,e){if(n){const i=t.localNames=[];for(let r=0;r<n.length;r+=2){const o=e[n[r+1]];if(null==o)throw new y(-301,!1);i.push(n[r],o)}}}(e,i,r)}e.mergedAttrs=ks(e.mergedAttrs,e.attrs)}function q0(t,n,e,i,r,o){for(let l=0;l<i.length;l++)Jh(Zc(e,n),t,i[l].type);!function sN(t,n,e){t.flags|=1,t.directiveStart=n,t.directiveEnd=n+e,t.providerIndexes=n}(e,t.data.length,i.length);for(let l=0;l<i.length;l++){const d=i[l];d.providersResolver&&d.providersResolver(d)}let s=!1,a=!1,c=sa(t,n,i.length,null);for(let l=0;l<i.length;l++){const d=i[l];e.mergedAttrs=ks(e.mergedAttrs,d.hostAttrs),aN(t,e,n,c,d),oN(c,d,r),null!==d.contentQueries&&(e.flags|=4),(null!==d.hostBindings||null!==d.hostAttrs||0!==d.hostVars)&&(e.flags|=64);const u=d.type.prototype;!s&&(u.ngOnChanges||u.ngOnInit||u.ngDoCheck)&&((t.preOrderHooks??=[]).push(e.index),s=!0),!a&&(u.ngOnChanges||u.ngDoCheck)&&((t.preOrderCheckHooks??=[]).push(e.index),a=!0),c++}!function ZF(t,n,e){const r=n.directiveEnd,o=t.data,s=n.attrs,a=[];let c=null,l=null;for(let d=n.directiveStart;d<r;d++){const u=o[d],h=e?e.get(u):null,m=h?h.outputs:null;c=G0(u.inputs,d,c,h?h.inputs:null),l=G0(u.outputs,d,l,m);const g=null===c||null===s||Xb(n)?null:dN(c,d,s);a.push(g)}null!==c&&(c.hasOwnProperty("class")&&(n.flags|=8),c.hasOwnProperty("style")&&(n.flags|=16)),n.initialInputs=a,n.inputs=c,n.outputs=l}(t,e,o)}function Y0(t,n,e){const i=e.directiveStart,r=e.directiveEnd,o=e.index,s=function W1(){return ee.lFrame.currentDirectiveIndex}();try{yr(o);for(let a=i;a<r;a++){const c=t.data[a],l=n[a];$h(a),(null!==c.hostBindings||0!==c.hostVars||null!==c.hostAttrs)&&nN(c,l)}}finally{yr(-1),$h(s)}}function nN(t,n){null!==t.hostBindings&&t.hostBindings(1,n)}function sp(t,n,e){n.componentOffset=e,(t.components??=[]).push(n.index)}function oN(t,n,e){if(e){if(n.exportAs)for(let i=0;i<n.exportAs.length;i++)e[n.exportAs[i]]=t;On(n)&&(e[""]=t)}}function aN(t,n,e,i,r){t.data[i]=r;const o=r.factory||(r.factory=vr(r.type)),s=new Hs(o,On(r),f);t.blueprint[i]=s,e[i]=s,function JF(t,n,e,i,r){const o=r.hostBindings;if(o){let s=t.hostBindingOpCodes;null===s&&(s=t.hostBindingOpCodes=[]);const a=~n.index;(function eN(t){let n=t.length;for(;n>0;){const e=t[--n];if("number"==typeof e&&e<0)return e}return 0})(s)!=a&&s.push(a),s.push(e,i,o)}}(t,n,i,sa(t,e,r.hostVars,se),r)}function ei(t,n,e,i,r,o){const s=qt(t,n);!function ap(t,n,e,i,r,o,s){if(null==o)t.removeAttribute(n,r,e);else{const a=null==s?ie(o):s(o,i||"",r);t.setAttribute(n,r,a,e)}}(n[re],s,o,t.value,e,i,r)}function lN(t,n,e,i,r,o){const s=o[n];if(null!==s)for(let a=0;a<s.length;)Z0(i,e,s[a++],s[a++],s[a++])}function Z0(t,n,e,i,r){const o=_n(null);try{const s=t.inputTransforms;null!==s&&s.hasOwnProperty(i)&&(r=s[i].call(n,r)),null!==t.setInput?t.setInput(n,r,e,i):n[i]=r}finally{_n(o)}}function dN(t,n,e){let i=null,r=0;for(;r<e.length;){const o=e[r];if(0!==o)if(5!==o){if("number"==typeof o)break;if(t.hasOwnProperty(o)){null===i&&(i=[]);const s=t[o];for(let a=0;a<s.length;a+=2)if(s[a]===n){i.push(o,s[a+1],e[r+1]);break}}r+=2}else r+=2;else r+=4}return i}function K0(t,n,e,i){return[t,!0,!1,n,null,0,i,e,null,null,null]}function Q0(t,n){const e=t.contentQueries;if(null!==e)for(let i=0;i<e.length;i+=2){const o=e[i+1];if(-1!==o){const s=t.data[o];Gh(e[i]),s.contentQueries(2,n[o],o)}}}function Al(t,n){return t[Os]?t[rv][Rn]=n:t[Os]=n,t[rv]=n,n}function cp(t,n,e){Gh(0);const i=_n(null);try{n(t,e)}finally{_n(i)}}function X0(t){return t[oo]||(t[oo]=[])}function J0(t){return t.cleanup||(t.cleanup=[])}function ew(t,n,e){return(null===t||On(t))&&(e=function jh(t){for(;Array.isArray(t);){if("object"==typeof t[kh])return t;t=t[Xe]}return null}(e[n.index])),e[re]}function tw(t,n){const e=t[zi],i=e?e.get(vn,null):null;i&&i.handleError(n)}function lp(t,n,e,i,r){for(let o=0;o<e.length;){const s=e[o++],a=e[o++];Z0(t.data[s],n[s],i,a,r)}}function xi(t,n,e){const i=zc(n,t);!function ky(t,n,e){t.setValue(n,e)}(t[re],i,e)}function uN(t,n){const e=tn(n,t),i=e[P];!function hN(t,n){for(let e=n.length;e<t.blueprint.length;e++)n.push(t.blueprint[e])}(i,e);const r=e[Xe];null!==r&&null===e[mi]&&(e[mi]=$f(r,e[zi])),dp(i,e,e[it])}function dp(t,n,e){Wh(n);try{const i=t.viewQuery;null!==i&&cp(1,i,e);const r=t.template;null!==r&&z0(t,n,r,1,e),t.firstCreatePass&&(t.firstCreatePass=!1),t.staticContentQueries&&Q0(t,n),t.staticViewQueries&&cp(2,t.viewQuery,e);const o=t.components;null!==o&&function fN(t,n){for(let e=0;e<n.length;e++)uN(t,n[e])}(n,o)}catch(i){throw t.firstCreatePass&&(t.incompleteFirstPass=!0,t.firstCreatePass=!1),i}finally{n[ce]&=-5,qh()}}let nw=(()=>{class t{constructor(){this.all=new Set,this.queue=new Map}create(e,i,r){const o=typeof Zone>"u"?null:Zone.current,s=function x1(t,n,e){const i=Object.create(C1);e&&(i.consumerAllowSignalWrites=!0),i.fn=t,i.schedule=n;const r=s=>{i.cleanupFn=s};return i.ref={notify:()=>fv(i),run:()=>{if(i.dirty=!1,i.hasRun&&!pv(i))return;i.hasRun=!0;const s=Nh(i);try{i.cleanupFn(),i.cleanupFn=xv,i.fn(r)}finally{Ph(i,s)}},cleanup:()=>i.cleanupFn()},i.ref}(e,l=>{this.all.has(l)&&this.queue.set(l,o)},r);let a;this.all.add(s),s.notify();const c=()=>{s.cleanup(),a?.(),this.all.delete(s),this.queue.delete(s)};return a=i?.onDestroy(c),{destroy:c}}flush(){if(0!==this.queue.size)for(const[e,i]of this.queue)this.queue.delete(e),i?i.run(()=>e.run()):e.run()}get isQueueEmpty(){return 0===this.queue.size}static#e=this.\u0275prov=I({token:t,providedIn:"root",factory:()=>new t})}return t})();function kl(t,n,e){let i=e?t.styles:null,r=e?t.classes:null,o=0;if(null!==n)for(let s=0;s<n.length;s++){const a=n[s];"number"==typeof a?o=a:1==o?r=gh(r,a):2==o&&(i=gh(i,a+": "+n[++s]+";"))}e?t.styles=i:t.stylesWithoutHost=i,e?t.classes=r:t.classesWithoutHost=r}function aa(t,n,e,i,r=!1){for(;null!==e;){const o=n[e.index];null!==o&&i.push(je(o)),Nt(o)&&iw(o,i);const s=e.type;if(8&s)aa(t,n,e.child,i);else if(32&s){const a=ff(e,n);let c;for(;c=a();)i.push(c)}else if(16&s){const a=jy(n,e);if(Array.isArray(a))i.push(...a);else{const c=Ks(n[rt]);aa(c[P],c,a,i,!0)}}e=r?e.projectionNext:e.next}return i}function iw(t,n){for(let e=Ct;e<t.length;e++){const i=t[e],r=i[P].firstChild;null!==r&&aa(i[P],i,r,n)}t[Zn]!==t[Xe]&&n.push(t[Zn])}function Rl(t,n,e,i=!0){const r=n[so],o=r.rendererFactory,s=r.afterRenderEventManager;o.begin?.(),s?.begin();try{rw(t,n,t.template,e)}catch(c){throw i&&tw(n,c),c}finally{o.end?.(),r.effectManager?.flush(),s?.end()}}function rw(t,n,e,i){const r=n[ce];if(256!=(256&r)){n[so].effectManager?.flush(),Wh(n);try{Tv(n),function Lv(t){return ee.lFrame.bindingIndex=t}(t.bindingStartIndex),null!==e&&z0(t,n,e,2,i);const s=3==(3&r);if(s){const l=t.preOrderCheckHooks;null!==l&&Wc(n,l,null)}else{const l=t.preOrderHooks;null!==l&&qc(n,l,0,null),Yh(n,0)}if(function gN(t){for(let n=Iy(t);null!==n;n=Ty(n)){if(!n[sv])continue;const e=n[lo];for(let i=0;i<e.length;i++){O1(e[i])}}}(n),ow(n,2),null!==t.contentQueries&&Q0(t,n),s){const l=t.contentCheckHooks;null!==l&&Wc(n,l)}else{const l=t.contentHooks;null!==l&&qc(n,l,1),Yh(n,1)}!function HF(t,n){const e=t.hostBindingOpCodes;if(null===e)return;const i=L0(n,Ls);try{for(let r=0;r<e.length;r++){const o=e[r];if(o<0)yr(~o);else{const s=o,a=e[++r],c=e[++r];G1(a,s),i.dirty=!1;const l=Nh(i);try{c(2,n[s])}finally{Ph(i,l)}}}}finally{null===n[Ls]&&B0(n,Ls),yr(-1)}}(t,n);const a=t.components;null!==a&&aw(n,a,0);const c=t.viewQuery;if(null!==c&&cp(2,c,i),s){const l=t.viewCheckHooks;null!==l&&Wc(n,l)}else{const l=t.viewHooks;null!==l&&qc(n,l,2),Yh(n,2)}!0===t.firstUpdatePass&&(t.firstUpdatePass=!1),n[ce]&=-73,Av(n)}finally{qh()}}}function ow(t,n){for(let e=Iy(t);null!==e;e=Ty(e))for(let i=Ct;i<e.length;i++)sw(e[i],n)}function _N(t,n,e){sw(tn(n,t),e)}function sw(t,n){if(!function k1(t){return 128==(128&t[ce])}(t))return;const e=t[P],i=t[ce];if(80&i&&0===n||1024&i||2===n)rw(e,t,e.template,t[it]);else if(t[Rs]>0){ow(t,1);const r=e.components;null!==r&&aw(t,r,1)}}function aw(t,n,e){for(let i=0;i<n.length;i++)_N(t,n[i],e)}class ca{get rootNodes(){const n=this._lView,e=n[P];return aa(e,n,e.firstChild,[])}constructor(n,e){this._lView=n,this._cdRefInjectingView=e,this._appRef=null,this._attachedToViewContainer=!1}get context(){return this._lView[it]}set context(n){this._lView[it]=n}get destroyed(){return 256==(256&this._lView[ce])}destroy(){if(this._appRef)this._appRef.detachView(this);else if(this._attachedToViewContainer){const n=this._lView[Ge];if(Nt(n)){const e=n[8],i=e?e.indexOf(this):-1;i>-1&&(cl(n,i),Xc(e,i))}this._attachedToViewContainer=!1}mf(this._lView[P],this._lView)}onDestroy(n){!function Rv(t,n){if(256==(256&t[ce]))throw new y(911,!1);null===t[$i]&&(t[$i]=[]),t[$i].push(n)}(this._lView,n)}markForCheck(){oa(this._cdRefInjectingView||this._lView)}detach(){this._lView[ce]&=-129}reattach(){this._lView[ce]|=128}detectChanges(){Rl(this._lView[P],this._lView,this.context)}checkNoChanges(){}attachToViewContainerRef(){if(this._appRef)throw new y(902,!1);this._attachedToViewContainer=!0}detachFromAppRef(){this._appRef=null,function iO(t,n){Xs(t,n,n[re],2,null,null)}(this._lView[P],this._lView)}attachToAppRef(n){if(this._attachedToViewContainer)throw new y(902,!1);this._appRef=n}}class bN extends ca{constructor(n){super(n),this._view=n}detectChanges(){const n=this._view;Rl(n[P],n,n[it],!1)}checkNoChanges(){}get context(){return null}}class cw extends Sr{constructor(n){super(),this.ngModule=n}resolveComponentFactory(n){const e=ye(n);return new la(e,this.ngModule)}}function lw(t){const n=[];for(let e in t)t.hasOwnProperty(e)&&n.push({propName:t[e],templateName:e});return n}class yN{constructor(n,e){this.injector=n,this.parentInjector=e}get(n,e,i){i=Fc(i);const r=this.injector.get(n,Wf,i);return r!==Wf||e===Wf?r:this.parentInjector.get(n,e,i)}}class la extends g0{get inputs(){const n=this.componentDef,e=n.inputTransforms,i=lw(n.inputs);if(null!==e)for(const r of i)e.hasOwnProperty(r.propName)&&(r.transform=e[r.propName]);return i}get outputs(){return lw(this.componentDef.outputs)}constructor(n,e){super(),this.componentDef=n,this.ngModule=e,this.componentType=n.type,this.selector=function n1(t){return t.map(t1).join(",")}(n.selectors),this.ngContentSelectors=n.ngContentSelectors?n.ngContentSelectors:[],this.isBoundToModule=!!e}create(n,e,i,r){let o=(r=r||this.ngModule)instanceof on?r:r?.injector;o&&null!==this.componentDef.getStandaloneInjector&&(o=this.componentDef.getStandaloneInjector(o)||o);const s=o?new yN(n,o):n,a=s.get(ra,null);if(null===a)throw new y(407,!1);const u={rendererFactory:a,sanitizer:s.get(gF,null),effectManager:s.get(nw,null),afterRenderEventManager:s.get(Jf,null)},h=a.createRenderer(null,this.componentDef),p=this.componentDef.selectors[0][0]||"div",m=i?function $F(t,n,e,i){const o=i.get(O0,!1)||e===gn.ShadowDom,s=t.selectRootElement(n,o);return function UF(t){U0(t)}(s),s}(h,i,this.componentDef.encapsulation,s):al(h,p,function vN(t){const n=t.toLowerCase();return"svg"===n?Sv:"math"===n?"math":null}(p)),C=this.componentDef.signals?4608:this.componentDef.onPush?576:528;let v=null;null!==m&&(v=$f(m,s,!0));const R=rp(0,null,null,1,0,null,null,null,null,null,null),L=Tl(null,R,null,C,null,null,u,h,s,null,v);let ne,Fe;Wh(L);try{const $e=this.componentDef;let Mt,fn=null;$e.findHostDirectiveDefs?(Mt=[],fn=new Map,$e.findHostDirectiveDefs($e,Mt,fn),Mt.push($e)):Mt=[$e];const Wn=function xN(t,n){const e=t[P],i=_e;return t[i]=n,Fo(e,i,2,"#host",null)}(L,m),th=function CN(t,n,e,i,r,o,s){const a=r[P];!function DN(t,n,e,i){for(const r of t)n.mergedAttrs=ks(n.mergedAttrs,r.hostAttrs);null!==n.mergedAttrs&&(kl(n,n.mergedAttrs,!0),null!==e&&Uy(i,e,n))}(i,t,n,s);let c=null;null!==n&&(c=$f(n,r[zi]));const l=o.rendererFactory.createRenderer(n,e);let d=16;e.signals?d=4096:e.onPush&&(d=64);const u=Tl(r,$0(e),null,d,r[t.index],t,o,l,null,null,c);return a.firstCreatePass&&sp(a,t,i.length-1),Al(r,u),r[t.index]=u}(Wn,m,$e,Mt,L,u,h);Fe=Iv(R,_e),m&&function SN(t,n,e,i){if(i)Ah(t,e,["ng-version",_F.full]);else{const{attrs:r,classes:o}=function r1(t){const n=[],e=[];let i=1,r=2;for(;i<t.length;){let o=t[i];if("string"==typeof o)2===r?""!==o&&n.push(o,t[++i]):8===r&&e.push(o);else{if(!kn(r))break;r=o}i++}return{attrs:n,classes:e}}(n.selectors[0]);r&&Ah(t,e,r),o&&o.length>0&&$y(t,e,o.join(" "))}}(h,$e,m,i),void 0!==e&&function MN(t,n,e){const i=t.projection=[];for(let r=0;r<n.length;r++){const o=e[r];i.push(null!=o?Array.from(o):null)}}(Fe,this.ngContentSelectors,e),ne=function EN(t,n,e,i,r,o){const s=Et(),a=r[P],c=qt(s,r);q0(a,r,s,e,null,i);for(let d=0;d<e.length;d++)Tt(wr(r,a,s.directiveStart+d,s),r);Y0(a,r,s),c&&Tt(c,r);const l=wr(r,a,s.directiveStart+s.componentOffset,s);if(t[it]=r[it]=l,null!==o)for(const d of o)d(l,n);return tp(a,s,t),l}(th,$e,Mt,fn,L,[IN]),dp(R,L,null)}finally{qh()}return new wN(this.componentType,ne,ko(Fe,L),L,Fe)}}class wN extends dF{constructor(n,e,i,r,o){super(),this.location=i,this._rootLView=r,this._tNode=o,this.previousInputValues=null,this.instance=e,this.hostView=this.changeDetectorRef=new bN(r),this.componentType=n}setInput(n,e){const i=this._tNode.inputs;let r;if(null!==i&&(r=i[n])){if(this.previousInputValues??=new Map,this.previousInputValues.has(n)&&Object.is(this.previousInputValues.get(n),e))return;const o=this._rootLView;lp(o[P],o,r,n,e),this.previousInputValues.set(n,e),oa(tn(this._tNode.index,o))}}get injector(){return new Bt(this._tNode,this._rootLView)}destroy(){this.hostView.destroy()}onDestroy(n){this.hostView.onDestroy(n)}}function IN(){const t=Et();Gc(S()[P],t)}function k(t){let n=function dw(t){return Object.getPrototypeOf(t.prototype).constructor}(t.type),e=!0;const i=[t];for(;n;){let r;if(On(t))r=n.\u0275cmp||n.\u0275dir;else{if(n.\u0275cmp)throw new y(903,!1);r=n.\u0275dir}if(r){if(e){i.push(r);const s=t;s.inputs=Ol(t.inputs),s.inputTransforms=Ol(t.inputTransforms),s.declaredInputs=Ol(t.declaredInputs),s.outputs=Ol(t.outputs);const a=r.hostBindings;a&&RN(t,a);const c=r.viewQuery,l=r.contentQueries;if(c&&AN(t,c),l&&kN(t,l),Ic(t.inputs,r.inputs),Ic(t.declaredInputs,r.declaredInputs),Ic(t.outputs,r.outputs),null!==r.inputTransforms&&(null===s.inputTransforms&&(s.inputTransforms={}),Ic(s.inputTransforms,r.inputTransforms)),On(r)&&r.data.animation){const d=t.data;d.animation=(d.animation||[]).concat(r.data.animation)}}const o=r.features;if(o)for(let s=0;s<o.length;s++){const a=o[s];a&&a.ngInherit&&a(t),a===k&&(e=!1)}}n=Object.getPrototypeOf(n)}!function TN(t){let n=0,e=null;for(let i=t.length-1;i>=0;i--){const r=t[i];r.hostVars=n+=r.hostVars,r.hostAttrs=ks(r.hostAttrs,e=ks(e,r.hostAttrs))}}(i)}function Ol(t){return t===qn?{}:t===De?[]:t}function AN(t,n){const e=t.viewQuery;t.viewQuery=e?(i,r)=>{n(i,r),e(i,r)}:n}function kN(t,n){const e=t.contentQueries;t.contentQueries=e?(i,r,o)=>{n(i,r,o),e(i,r,o)}:n}function RN(t,n){const e=t.hostBindings;t.hostBindings=e?(i,r)=>{n(i,r),e(i,r)}:n}function Fl(t){return!!up(t)&&(Array.isArray(t)||!(t instanceof Map)&&Symbol.iterator in t)}function up(t){return null!==t&&("function"==typeof t||"object"==typeof t)}function ti(t,n,e){return t[n]=e}function At(t,n,e){return!Object.is(t[n],e)&&(t[n]=e,!0)}function Ir(t,n,e,i){const r=At(t,n,e);return At(t,n+1,i)||r}function ue(t,n,e,i){const r=S();return At(r,po(),n)&&(we(),ei(Ye(),r,t,n,e,i)),ue}function Lo(t,n,e,i,r,o){const a=Ir(t,function gi(){return ee.lFrame.bindingIndex}(),e,r);return _i(2),a?n+ie(e)+i+ie(r)+o:se}function O(t,n,e,i,r,o,s,a){const c=S(),l=we(),d=t+_e,u=l.firstCreatePass?function iP(t,n,e,i,r,o,s,a,c){const l=n.consts,d=Fo(n,t,4,s||null,Gi(l,a));op(n,e,d,Gi(l,c)),Gc(n,d);const u=d.tView=rp(2,d,i,r,o,n.directiveRegistry,n.pipeRegistry,null,n.schemas,l,null);return null!==n.queries&&(n.queries.template(n,d),u.queries=n.queries.embeddedTView(d)),d}(d,l,c,n,e,i,r,o,s):l.data[d];Qn(u,!1);const h=Mw(l,c,u,t);Uc()&&dl(l,c,h,u),Tt(h,c),Al(c,c[d]=K0(h,c,h,u)),Vc(u)&&np(l,c,u),null!=s&&ip(c,u,a)}let Mw=function Iw(t,n,e,i){return Wi(!0),n[re].createComment("")};function Ci(t){return function ho(t,n){return t[n]}(function $1(){return ee.lFrame.contextLView}(),_e+t)}function F(t,n,e){const i=S();return At(i,po(),n)&&sn(we(),Ye(),i,t,n,i[re],e,!1),F}function _p(t,n,e,i,r){const s=r?"class":"style";lp(t,e,n.inputs[s],s,i)}function w(t,n,e,i){const r=S(),o=we(),s=_e+t,a=r[re],c=o.firstCreatePass?function aP(t,n,e,i,r,o){const s=n.consts,c=Fo(n,t,2,i,Gi(s,r));return op(n,e,c,Gi(s,o)),null!==c.attrs&&kl(c,c.attrs,!1),null!==c.mergedAttrs&&kl(c,c.mergedAttrs,!0),null!==n.queries&&n.queries.elementStart(n,c),c}(s,o,r,n,e,i):o.data[s],l=Tw(o,r,c,a,n,t);r[s]=l;const d=Vc(c);return Qn(c,!0),Uy(a,l,c),32!=(32&c.flags)&&Uc()&&dl(o,r,l,c),0===function N1(){return ee.lFrame.elementDepthCount}()&&Tt(l,r),function P1(){ee.lFrame.elementDepthCount++}(),d&&(np(o,r,c),tp(o,c,r)),null!==i&&ip(r,c),w}function D(){let t=Et();Hh()?zh():(t=t.parent,Qn(t,!1));const n=t;(function B1(t){return ee.skipHydrationRootTNode===t})(n)&&function z1(){ee.skipHydrationRootTNode=null}(),function L1(){ee.lFrame.elementDepthCount--}();const e=we();return e.firstCreatePass&&(Gc(e,t),Rh(t)&&e.queries.elementEnd(t)),null!=n.classesWithoutHost&&function tR(t){return 0!=(8&t.flags)}(n)&&_p(e,n,S(),n.classesWithoutHost,!0),null!=n.stylesWithoutHost&&function nR(t){return 0!=(16&t.flags)}(n)&&_p(e,n,S(),n.stylesWithoutHost,!1),D}function K(t,n,e,i){return w(t,n,e,i),D(),K}let Tw=(t,n,e,i,r,o)=>(Wi(!0),al(i,r,function Wv(){return ee.lFrame.currentNamespace}()));function ot(t,n,e){const i=S(),r=we(),o=t+_e,s=r.firstCreatePass?function dP(t,n,e,i,r){const o=n.consts,s=Gi(o,i),a=Fo(n,t,8,"ng-container",s);return null!==s&&kl(a,s,!0),op(n,e,a,Gi(o,r)),null!==n.queries&&n.queries.elementStart(n,a),a}(o,r,i,n,e):r.data[o];Qn(s,!0);const a=Aw(r,i,s,t);return i[o]=a,Uc()&&dl(r,i,a,s),Tt(a,i),Vc(s)&&(np(r,i,s),tp(r,s,i)),null!=e&&ip(i,s),ot}function st(){let t=Et();const n=we();return Hh()?zh():(t=t.parent,Qn(t,!1)),n.firstCreatePass&&(Gc(n,t),Rh(t)&&n.queries.elementEnd(t)),st}function an(t,n,e){return ot(t,n,e),st(),an}let Aw=(t,n,e,i)=>(Wi(!0),pf(n[re],""));function Zi(){return S()}function pa(t){return!!t&&"function"==typeof t.then}function kw(t){return!!t&&"function"==typeof t.subscribe}function Y(t,n,e,i){const r=S(),o=we(),s=Et();return Rw(o,r,r[re],s,t,n,i),Y}function bp(t,n){const e=Et(),i=S(),r=we();return Rw(r,i,ew(Uh(r.data),e,i),e,t,n),bp}function Rw(t,n,e,i,r,o,s){const a=Vc(i),l=t.firstCreatePass&&J0(t),d=n[it],u=X0(n);let h=!0;if(3&i.type||s){const g=qt(i,n),_=s?s(g):g,C=u.length,v=s?L=>s(je(L[i.index])):i.index;let R=null;if(!s&&a&&(R=function fP(t,n,e,i){const r=t.cleanup;if(null!=r)for(let o=0;o<r.length-1;o+=2){const s=r[o];if(s===e&&r[o+1]===i){const a=n[oo],c=r[o+2];return a.length>c?a[c]:null}"string"==typeof s&&(o+=2)}return null}(t,n,r,i.index)),null!==R)(R.__ngLastListenerFn__||R).__ngNextListenerFn__=o,R.__ngLastListenerFn__=o,h=!1;else{o=Fw(i,n,d,o,!1);const L=e.listen(_,r,o);u.push(o,L),l&&l.push(r,v,C,C+1)}}else o=Fw(i,n,d,o,!1);const p=i.outputs;let m;if(h&&null!==p&&(m=p[r])){const g=m.length;if(g)for(let _=0;_<g;_+=2){const ne=n[m[_]][m[_+1]].subscribe(o),Fe=u.length;u.push(o,ne),l&&l.push(r,i.index,Fe,-(Fe+1))}}}function Ow(t,n,e,i){try{return Kn(6,n,e),!1!==e(i)}catch(r){return tw(t,r),!1}finally{Kn(7,n,e)}}function Fw(t,n,e,i,r){return function o(s){if(s===Function)return i;oa(t.componentOffset>-1?tn(t.index,n):n);let c=Ow(n,e,i,s),l=o.__ngNextListenerFn__;for(;l;)c=Ow(n,e,l,s)&&c,l=l.__ngNextListenerFn__;return r&&!1===c&&s.preventDefault(),c}}function be(t=1){return function Y1(t){return(ee.lFrame.contextLView=function Z1(t,n){for(;t>0;)n=n[ao],t--;return n}(t,ee.lFrame.contextLView))[it]}(t)}function pP(t,n){let e=null;const i=function Qk(t){const n=t.attrs;if(null!=n){const e=n.indexOf(5);if(!(1&e))return n[e+1]}return null}(t);for(let r=0;r<n.length;r++){const o=n[r];if("*"!==o){if(null===i?Jb(t,o,!0):e1(i,o))return r}else e=r}return e}function at(t){const n=S()[rt][It];if(!n.projection){const i=n.projection=Ws(t?t.length:1,null),r=i.slice();let o=n.child;for(;null!==o;){const s=t?pP(o,t):0;null!==s&&(r[s]?r[s].projectionNext=o:i[s]=o,r[s]=o),o=o.next}}}function Q(t,n=0,e){const i=S(),r=we(),o=Fo(r,_e+t,16,null,e||null);null===o.projection&&(o.projection=n),zh(),(!i[mi]||fo())&&32!=(32&o.flags)&&function uO(t,n,e){zy(n[re],0,n,e,_f(t,e,n),Py(e.parent||n[It],e,n))}(r,i,o)}function Vl(t,n){return t<<17|n<<2}function Ki(t){return t>>17&32767}function yp(t){return 2|t}function Tr(t){return(131068&t)>>2}function wp(t,n){return-131069&t|n<<2}function xp(t){return 1|t}function Uw(t,n,e,i,r){const o=t[e+1],s=null===n;let a=i?Ki(o):Tr(o),c=!1;for(;0!==a&&(!1===c||s);){const d=t[a+1];yP(t[a],n)&&(c=!0,t[a+1]=i?xp(d):yp(d)),a=i?Ki(d):Tr(d)}c&&(t[e+1]=i?yp(o):xp(o))}function yP(t,n){return null===t||null==n||(Array.isArray(t)?t[1]:t)===n||!(!Array.isArray(t)||"string"!=typeof n)&&xo(t,n)>=0}function Pn(t,n,e){return Ln(t,n,e,!1),Pn}function he(t,n){return Ln(t,n,null,!0),he}function Ln(t,n,e,i){const r=S(),o=we(),s=_i(2);o.firstUpdatePass&&function Xw(t,n,e,i){const r=t.data;if(null===r[e+1]){const o=r[Lt()],s=function Qw(t,n){return n>=t.expandoStartIndex}(t,e);(function nx(t,n){return 0!=(t.flags&(n?8:16))})(o,i)&&null===n&&!s&&(n=!1),n=function TP(t,n,e,i){const r=Uh(t);let o=i?n.residualClasses:n.residualStyles;if(null===r)0===(i?n.classBindings:n.styleBindings)&&(e=ma(e=Cp(null,t,n,e,i),n.attrs,i),o=null);else{const s=n.directiveStylingLast;if(-1===s||t[s]!==r)if(e=Cp(r,t,n,e,i),null===o){let c=function AP(t,n,e){const i=e?n.classBindings:n.styleBindings;if(0!==Tr(i))return t[Ki(i)]}(t,n,i);void 0!==c&&Array.isArray(c)&&(c=Cp(null,t,n,c[1],i),c=ma(c,n.attrs,i),function kP(t,n,e,i){t[Ki(e?n.classBindings:n.styleBindings)]=i}(t,n,i,c))}else o=function RP(t,n,e){let i;const r=n.directiveEnd;for(let o=1+n.directiveStylingLast;o<r;o++)i=ma(i,t[o].hostAttrs,e);return ma(i,n.attrs,e)}(t,n,i)}return void 0!==o&&(i?n.residualClasses=o:n.residualStyles=o),e}(r,o,n,i),function bP(t,n,e,i,r,o){let s=o?n.classBindings:n.styleBindings,a=Ki(s),c=Tr(s);t[i]=e;let d,l=!1;if(Array.isArray(e)?(d=e[1],(null===d||xo(e,d)>0)&&(l=!0)):d=e,r)if(0!==c){const h=Ki(t[a+1]);t[i+1]=Vl(h,a),0!==h&&(t[h+1]=wp(t[h+1],i)),t[a+1]=function gP(t,n){return 131071&t|n<<17}(t[a+1],i)}else t[i+1]=Vl(a,0),0!==a&&(t[a+1]=wp(t[a+1],i)),a=i;else t[i+1]=Vl(c,0),0===a?a=i:t[c+1]=wp(t[c+1],i),c=i;l&&(t[i+1]=yp(t[i+1])),Uw(t,d,i,!0),Uw(t,d,i,!1),function vP(t,n,e,i,r){const o=r?t.residualClasses:t.residualStyles;null!=o&&"string"==typeof n&&xo(o,n)>=0&&(e[i+1]=xp(e[i+1]))}(n,d,t,i,o),s=Vl(a,c),o?n.classBindings=s:n.styleBindings=s}(r,o,n,e,s,i)}}(o,t,s,i),n!==se&&At(r,s,n)&&function ex(t,n,e,i,r,o,s,a){if(!(3&n.type))return;const c=t.data,l=c[a+1],d=function _P(t){return 1==(1&t)}(l)?tx(c,n,e,r,Tr(l),s):void 0;jl(d)||(jl(o)||function mP(t){return 2==(2&t)}(l)&&(o=tx(c,null,e,r,a,s)),function fO(t,n,e,i,r){if(n)r?t.addClass(e,i):t.removeClass(e,i);else{let o=-1===i.indexOf("-")?void 0:qi.DashCase;null==r?t.removeStyle(e,i,o):("string"==typeof r&&r.endsWith("!important")&&(r=r.slice(0,-10),o|=qi.Important),t.setStyle(e,i,r,o))}}(i,s,zc(Lt(),e),r,o))}(o,o.data[Lt()],r,r[re],t,r[s+1]=function PP(t,n){return null==t||""===t||("string"==typeof n?t+=n:"object"==typeof t&&(t=pt(rn(t)))),t}(n,e),i,s)}function Cp(t,n,e,i,r){let o=null;const s=e.directiveEnd;let a=e.directiveStylingLast;for(-1===a?a=e.directiveStart:a++;a<s&&(o=n[a],i=ma(i,o.hostAttrs,r),o!==t);)a++;return null!==t&&(e.directiveStylingLast=a),i}function ma(t,n,e){const i=e?1:2;let r=-1;if(null!==n)for(let o=0;o<n.length;o++){const s=n[o];"number"==typeof s?r=s:r===i&&(Array.isArray(t)||(t=void 0===t?[]:["",t]),nn(t,s,!!e||n[++o]))}return void 0===t?null:t}function tx(t,n,e,i,r,o){const s=null===n;let a;for(;r>0;){const c=t[r],l=Array.isArray(c),d=l?c[1]:c,u=null===d;let h=e[r+1];h===se&&(h=u?De:void 0);let p=u?nf(h,i):d===i?h:void 0;if(l&&!jl(p)&&(p=nf(c,i)),jl(p)&&(a=p,s))return a;const m=t[r+1];r=s?Ki(m):Tr(m)}if(null!==n){let c=o?n.residualClasses:n.residualStyles;null!=c&&(a=nf(c,i))}return a}function jl(t){return void 0!==t}function N(t,n=""){const e=S(),i=we(),r=t+_e,o=i.firstCreatePass?Fo(i,r,1,n,null):i.data[r],s=ix(i,e,o,n,t);e[r]=s,Uc()&&dl(i,e,s,o),Qn(o,!1)}let ix=(t,n,e,i,r)=>(Wi(!0),function sl(t,n){return t.createText(n)}(n[re],i));function Vn(t){return bt("",t,""),Vn}function bt(t,n,e){const i=S(),r=function Po(t,n,e,i){return At(t,po(),e)?n+ie(e)+i:se}(i,t,n,e);return r!==se&&xi(i,Lt(),r),bt}function Hl(t,n,e,i,r){const o=S(),s=Lo(o,t,n,e,i,r);return s!==se&&xi(o,Lt(),s),Hl}function wn(t,n,e){const i=S();return At(i,po(),n)&&sn(we(),Ye(),i,t,n,i[re],e,!0),wn}function Dp(t,n,e){const i=S();if(At(i,po(),n)){const o=we(),s=Ye();sn(o,s,i,t,n,ew(Uh(o.data),s,i),e,!0)}return Dp}const Wo="en-US";let xx=Wo;function Mp(t,n,e,i,r){if(t=te(t),Array.isArray(t))for(let o=0;o<t.length;o++)Mp(t[o],n,e,i,r);else{const o=we(),s=S(),a=Et();let c=Er(t)?t:te(t.provide);const l=u0(t),d=1048575&a.providerIndexes,u=a.directiveStart,h=a.providerIndexes>>20;if(Er(t)||!t.multi){const p=new Hs(l,r,f),m=Tp(c,n,r?d:d+h,u);-1===m?(Jh(Zc(a,s),o,c),Ip(o,t,n.length),n.push(c),a.directiveStart++,a.directiveEnd++,r&&(a.providerIndexes+=1048576),e.push(p),s.push(p)):(e[m]=p,s[m]=p)}else{const p=Tp(c,n,d+h,u),m=Tp(c,n,d,d+h),_=m>=0&&e[m];if(r&&!_||!r&&!(p>=0&&e[p])){Jh(Zc(a,s),o,c);const C=function r2(t,n,e,i,r){const o=new Hs(t,e,f);return o.multi=[],o.index=n,o.componentProviders=0,qx(o,r,i&&!e),o}(r?n2:t2,e.length,r,i,l);!r&&_&&(e[m].providerFactory=C),Ip(o,t,n.length,0),n.push(c),a.directiveStart++,a.directiveEnd++,r&&(a.providerIndexes+=1048576),e.push(C),s.push(C)}else Ip(o,t,p>-1?p:m,qx(e[r?m:p],l,!r&&i));!r&&i&&_&&e[m].componentProviders++}}}function Ip(t,n,e,i){const r=Er(n),o=function qO(t){return!!t.useClass}(n);if(r||o){const c=(o?te(n.useClass):n).prototype.ngOnDestroy;if(c){const l=t.destroyHooks||(t.destroyHooks=[]);if(!r&&n.multi){const d=l.indexOf(e);-1===d?l.push(e,[i,c]):l[d+1].push(i,c)}else l.push(e,c)}}}function qx(t,n,e){return e&&t.componentProviders++,t.multi.push(n)-1}function Tp(t,n,e,i){for(let r=e;r<i;r++)if(n[r]===t)return r;return-1}function t2(t,n,e,i){return Ap(this.multi,[])}function n2(t,n,e,i){const r=this.multi;let o;if(this.providerFactory){const s=this.providerFactory.componentProviders,a=wr(e,e[P],this.providerFactory.index,i);o=a.slice(0,s),Ap(r,o);for(let c=s;c<a.length;c++)o.push(a[c])}else o=[],Ap(r,o);return o}function Ap(t,n){for(let e=0;e<t.length;e++)n.push((0,t[e])());return n}function G(t,n=[]){return e=>{e.providersResolver=(i,r)=>function e2(t,n,e){const i=we();if(i.firstCreatePass){const r=On(t);Mp(e,i.data,i.blueprint,r,!0),Mp(n,i.data,i.blueprint,r,!1)}}(i,r?r(t):t,n)}}class kr{}class Yx{}class kp extends kr{constructor(n,e,i){super(),this._parent=e,this._bootstrapComponents=[],this.destroyCbs=[],this.componentFactoryResolver=new cw(this);const r=en(n);this._bootstrapComponents=wi(r.bootstrap),this._r3Injector=x0(n,e,[{provide:kr,useValue:this},{provide:Sr,useValue:this.componentFactoryResolver},...i],pt(n),new Set(["environment"])),this._r3Injector.resolveInjectorInitializers(),this.instance=this._r3Injector.get(n)}get injector(){return this._r3Injector}destroy(){const n=this._r3Injector;!n.destroyed&&n.destroy(),this.destroyCbs.forEach(e=>e()),this.destroyCbs=null}onDestroy(n){this.destroyCbs.push(n)}}class Rp extends Yx{constructor(n){super(),this.moduleType=n}create(n){return new kp(this.moduleType,n,[])}}class Zx extends kr{constructor(n){super(),this.componentFactoryResolver=new cw(this),this.instance=null;const e=new vl([...n.providers,{provide:kr,useValue:this},{provide:Sr,useValue:this.componentFactoryResolver}],n.parent||bl(),n.debugName,new Set(["environment"]));this.injector=e,n.runEnvironmentInitializers&&e.resolveInjectorInitializers()}destroy(){this.injector.destroy()}onDestroy(n){this.injector.onDestroy(n)}}function Op(t,n,e=null){return new Zx({providers:t,parent:n,debugName:e,runEnvironmentInitializers:!0}).injector}let a2=(()=>{class t{constructor(e){this._injector=e,this.cachedInjectors=new Map}getOrCreateStandaloneInjector(e){if(!e.standalone)return null;if(!this.cachedInjectors.has(e)){const i=a0(0,e.type),r=i.length>0?Op([i],this._injector,`Standalone[${e.type.name}]`):null;this.cachedInjectors.set(e,r)}return this.cachedInjectors.get(e)}ngOnDestroy(){try{for(const e of this.cachedInjectors.values())null!==e&&e.destroy()}finally{this.cachedInjectors.clear()}}static#e=this.\u0275prov=I({token:t,providedIn:"environment",factory:()=>new t(b(on))})}return t})();function Kx(t){t.getStandaloneInjector=n=>n.get(a2).getOrCreateStandaloneInjector(t)}function iC(t,n,e,i){return function oC(t,n,e,i,r,o){const s=n+e;return At(t,s,r)?ti(t,s+1,o?i.call(o,r):i(r)):wa(t,s+1)}(S(),Pt(),t,n,e,i)}function rC(t,n,e,i,r){return function sC(t,n,e,i,r,o,s){const a=n+e;return Ir(t,a,r,o)?ti(t,a+2,s?i.call(s,r,o):i(r,o)):wa(t,a+2)}(S(),Pt(),t,n,e,i,r)}function wa(t,n){const e=t[n];return e===se?void 0:e}function k2(){return this._results[Symbol.iterator]()}class Rr{static#e=Symbol.iterator;get changes(){return this._changes||(this._changes=new q)}constructor(n=!1){this._emitDistinctChangesOnly=n,this.dirty=!0,this._results=[],this._changesDetected=!1,this._changes=null,this.length=0,this.first=void 0,this.last=void 0;const e=Rr.prototype;e[Symbol.iterator]||(e[Symbol.iterator]=k2)}get(n){return this._results[n]}map(n){return this._results.map(n)}filter(n){return this._results.filter(n)}find(n){return this._results.find(n)}reduce(n,e){return this._results.reduce(n,e)}forEach(n){this._results.forEach(n)}some(n){return this._results.some(n)}toArray(){return this._results.slice()}toString(){return this._results.toString()}reset(n,e){const i=this;i.dirty=!1;const r=function bn(t){return t.flat(Number.POSITIVE_INFINITY)}(n);(this._changesDetected=!function gR(t,n,e){if(t.length!==n.length)return!1;for(let i=0;i<t.length;i++){let r=t[i],o=n[i];if(e&&(r=e(r),o=e(o)),o!==r)return!1}return!0}(i._results,r,e))&&(i._results=r,i.length=r.length,i.last=r[this.length-1],i.first=r[0])}notifyOnChanges(){this._changes&&(this._changesDetected||!this._emitDistinctChangesOnly)&&this._changes.emit(this)}setDirty(){this.dirty=!0}destroy(){this.changes.complete(),this.changes.unsubscribe()}}function O2(t,n,e,i=!0){const r=n[P];if(function oO(t,n,e,i){const r=Ct+i,o=e.length;i>0&&(e[r-1][Rn]=n),i<o-Ct?(n[Rn]=e[r],ay(e,Ct+i,n)):(e.push(n),n[Rn]=null),n[Ge]=e;const s=n[Fs];null!==s&&e!==s&&function sO(t,n){const e=t[lo];n[rt]!==n[Ge][Ge][rt]&&(t[sv]=!0),null===e?t[lo]=[n]:e.push(n)}(s,n);const a=n[Yn];null!==a&&a.insertView(t),n[ce]|=128}(r,n,t,e),i){const o=vf(e,t),s=n[re],a=ll(s,t[Zn]);null!==a&&function nO(t,n,e,i,r,o){i[Xe]=r,i[It]=n,Xs(t,i,e,1,r,o)}(r,t[It],s,n,a,o)}}let Ue=(()=>{class t{static#e=this.__NG_ELEMENT_ID__=P2}return t})();const F2=Ue,N2=class extends F2{constructor(n,e,i){super(),this._declarationLView=n,this._declarationTContainer=e,this.elementRef=i}get ssrId(){return this._declarationTContainer.tView?.ssrId||null}createEmbeddedView(n,e){return this.createEmbeddedViewImpl(n,e)}createEmbeddedViewImpl(n,e,i){const r=function R2(t,n,e,i){const r=n.tView,a=Tl(t,r,e,4096&t[ce]?4096:16,null,n,null,null,null,i?.injector??null,i?.hydrationInfo??null);a[Fs]=t[n.index];const l=t[Yn];return null!==l&&(a[Yn]=l.createEmbeddedView(r)),dp(r,a,e),a}(this._declarationLView,this._declarationTContainer,n,{injector:e,hydrationInfo:i});return new ca(r)}};function P2(){return Wl(Et(),S())}function Wl(t,n){return 4&t.type?new N2(n,t,ko(t,n)):null}let Je=(()=>{class t{static#e=this.__NG_ELEMENT_ID__=z2}return t})();function z2(){return gC(Et(),S())}const $2=Je,pC=class extends $2{constructor(n,e,i){super(),this._lContainer=n,this._hostTNode=e,this._hostLView=i}get element(){return ko(this._hostTNode,this._hostLView)}get injector(){return new Bt(this._hostTNode,this._hostLView)}get parentInjector(){const n=Kc(this._hostTNode,this._hostLView);if(Kh(n)){const e=$s(n,this._hostLView),i=zs(n);return new Bt(e[P].data[i+8],e)}return new Bt(null,this._hostLView)}clear(){for(;this.length>0;)this.remove(this.length-1)}get(n){const e=mC(this._lContainer);return null!==e&&e[n]||null}get length(){return this._lContainer.length-Ct}createEmbeddedView(n,e,i){let r,o;"number"==typeof i?r=i:null!=i&&(r=i.index,o=i.injector);const a=n.createEmbeddedViewImpl(e||{},o,null);return this.insertImpl(a,r,false),a}createComponent(n,e,i,r,o){const s=n&&!function Gs(t){return"function"==typeof t}(n);let a;if(s)a=e;else{const g=e||{};a=g.index,i=g.injector,r=g.projectableNodes,o=g.environmentInjector||g.ngModuleRef}const c=s?n:new la(ye(n)),l=i||this.parentInjector;if(!o&&null==c.ngModule){const _=(s?l:this.parentInjector).get(on,null);_&&(o=_)}ye(c.componentType??{});const p=c.create(l,r,null,o);return this.insertImpl(p.hostView,a,false),p}insert(n,e){return this.insertImpl(n,e,!1)}insertImpl(n,e,i){const r=n._lView;if(function R1(t){return Nt(t[Ge])}(r)){const c=this.indexOf(n);if(-1!==c)this.detach(c);else{const l=r[Ge],d=new pC(l,l[It],l[Ge]);d.detach(d.indexOf(n))}}const s=this._adjustIndex(e),a=this._lContainer;return O2(a,r,s,!i),n.attachToViewContainerRef(),ay(Np(a),s,n),n}move(n,e){return this.insert(n,e)}indexOf(n){const e=mC(this._lContainer);return null!==e?e.indexOf(n):-1}remove(n){const e=this._adjustIndex(n,-1),i=cl(this._lContainer,e);i&&(Xc(Np(this._lContainer),e),mf(i[P],i))}detach(n){const e=this._adjustIndex(n,-1),i=cl(this._lContainer,e);return i&&null!=Xc(Np(this._lContainer),e)?new ca(i):null}_adjustIndex(n,e=0){return n??this.length+e}};function mC(t){return t[8]}function Np(t){return t[8]||(t[8]=[])}function gC(t,n){let e;const i=n[t.index];return Nt(i)?e=i:(e=K0(i,n,null,t),n[t.index]=e,Al(n,e)),_C(e,n,t,i),new pC(e,t,n)}let _C=function bC(t,n,e,i){if(t[Zn])return;let r;r=8&e.type?je(i):function U2(t,n){const e=t[re],i=e.createComment(""),r=qt(n,t);return Cr(e,ll(e,r),i,function dO(t,n){return t.nextSibling(n)}(e,r),!1),i}(n,e),t[Zn]=r};class Pp{constructor(n){this.queryList=n,this.matches=null}clone(){return new Pp(this.queryList)}setDirty(){this.queryList.setDirty()}}class Lp{constructor(n=[]){this.queries=n}createEmbeddedView(n){const e=n.queries;if(null!==e){const i=null!==n.contentQueries?n.contentQueries[0]:e.length,r=[];for(let o=0;o<i;o++){const s=e.getByIndex(o);r.push(this.queries[s.indexInDeclarationView].clone())}return new Lp(r)}return null}insertView(n){this.dirtyQueriesWithMatches(n)}detachView(n){this.dirtyQueriesWithMatches(n)}dirtyQueriesWithMatches(n){for(let e=0;e<this.queries.length;e++)null!==CC(n,e).matches&&this.queries[e].setDirty()}}class vC{constructor(n,e,i=null){this.predicate=n,this.flags=e,this.read=i}}class Bp{constructor(n=[]){this.queries=n}elementStart(n,e){for(let i=0;i<this.queries.length;i++)this.queries[i].elementStart(n,e)}elementEnd(n){for(let e=0;e<this.queries.length;e++)this.queries[e].elementEnd(n)}embeddedTView(n){let e=null;for(let i=0;i<this.length;i++){const r=null!==e?e.length:0,o=this.getByIndex(i).embeddedTView(n,r);o&&(o.indexInDeclarationView=i,null!==e?e.push(o):e=[o])}return null!==e?new Bp(e):null}template(n,e){for(let i=0;i<this.queries.length;i++)this.queries[i].template(n,e)}getByIndex(n){return this.queries[n]}get length(){return this.queries.length}track(n){this.queries.push(n)}}class Vp{constructor(n,e=-1){this.metadata=n,this.matches=null,this.indexInDeclarationView=-1,this.crossesNgTemplate=!1,this._appliesToNextNode=!0,this._declarationNodeIndex=e}elementStart(n,e){this.isApplyingToNode(e)&&this.matchTNode(n,e)}elementEnd(n){this._declarationNodeIndex===n.index&&(this._appliesToNextNode=!1)}template(n,e){this.elementStart(n,e)}embeddedTView(n,e){return this.isApplyingToNode(n)?(this.crossesNgTemplate=!0,this.addMatch(-n.index,e),new Vp(this.metadata)):null}isApplyingToNode(n){if(this._appliesToNextNode&&1!=(1&this.metadata.flags)){const e=this._declarationNodeIndex;let i=n.parent;for(;null!==i&&8&i.type&&i.index!==e;)i=i.parent;return e===(null!==i?i.index:-1)}return this._appliesToNextNode}matchTNode(n,e){const i=this.metadata.predicate;if(Array.isArray(i))for(let r=0;r<i.length;r++){const o=i[r];this.matchTNodeWithReadOption(n,e,q2(e,o)),this.matchTNodeWithReadOption(n,e,Qc(e,n,o,!1,!1))}else i===Ue?4&e.type&&this.matchTNodeWithReadOption(n,e,-1):this.matchTNodeWithReadOption(n,e,Qc(e,n,i,!1,!1))}matchTNodeWithReadOption(n,e,i){if(null!==i){const r=this.metadata.read;if(null!==r)if(r===$||r===Je||r===Ue&&4&e.type)this.addMatch(e.index,-2);else{const o=Qc(e,n,r,!1,!1);null!==o&&this.addMatch(e.index,o)}else this.addMatch(e.index,i)}}addMatch(n,e){null===this.matches?this.matches=[n,e]:this.matches.push(n,e)}}function q2(t,n){const e=t.localNames;if(null!==e)for(let i=0;i<e.length;i+=2)if(e[i]===n)return e[i+1];return null}function Z2(t,n,e,i){return-1===e?function Y2(t,n){return 11&t.type?ko(t,n):4&t.type?Wl(t,n):null}(n,t):-2===e?function K2(t,n,e){return e===$?ko(n,t):e===Ue?Wl(n,t):e===Je?gC(n,t):void 0}(t,n,i):wr(t,t[P],e,n)}function yC(t,n,e,i){const r=n[Yn].queries[i];if(null===r.matches){const o=t.data,s=e.matches,a=[];for(let c=0;c<s.length;c+=2){const l=s[c];a.push(l<0?null:Z2(n,o[l],s[c+1],e.metadata.read))}r.matches=a}return r.matches}function jp(t,n,e,i){const r=t.queries.getByIndex(e),o=r.matches;if(null!==o){const s=yC(t,n,r,e);for(let a=0;a<o.length;a+=2){const c=o[a];if(c>0)i.push(s[a/2]);else{const l=o[a+1],d=n[-c];for(let u=Ct;u<d.length;u++){const h=d[u];h[Fs]===h[Ge]&&jp(h[P],h,l,i)}if(null!==d[lo]){const u=d[lo];for(let h=0;h<u.length;h++){const p=u[h];jp(p[P],p,l,i)}}}}}return i}function B(t){const n=S(),e=we(),i=Vv();Gh(i+1);const r=CC(e,i);if(t.dirty&&function A1(t){return 4==(4&t[ce])}(n)===(2==(2&r.metadata.flags))){if(null===r.matches)t.reset([]);else{const o=r.crossesNgTemplate?jp(e,n,i,[]):yC(e,n,r,i);t.reset(o,pF),t.notifyOnChanges()}return!0}return!1}function Se(t,n,e){const i=we();i.firstCreatePass&&(xC(i,new vC(t,n,e),-1),2==(2&n)&&(i.staticViewQueries=!0)),wC(i,S(),n)}function xe(t,n,e,i){const r=we();if(r.firstCreatePass){const o=Et();xC(r,new vC(n,e,i),o.index),function X2(t,n){const e=t.contentQueries||(t.contentQueries=[]);n!==(e.length?e[e.length-1]:-1)&&e.push(t.queries.length-1,n)}(r,t),2==(2&e)&&(r.staticContentQueries=!0)}wC(r,S(),e)}function V(){return function Q2(t,n){return t[Yn].queries[n].queryList}(S(),Vv())}function wC(t,n,e){const i=new Rr(4==(4&e));(function qF(t,n,e,i){const r=X0(n);r.push(e),t.firstCreatePass&&J0(t).push(i,r.length-1)})(t,n,i,i.destroy),null===n[Yn]&&(n[Yn]=new Lp),n[Yn].queries.push(new Pp(i))}function xC(t,n,e){null===t.queries&&(t.queries=new Bp),t.queries.track(new Vp(n,e))}function CC(t,n){return t.queries.getByIndex(n)}function Ca(t,n){return Wl(t,n)}const Wp=new E("Application Initializer");let qp=(()=>{class t{constructor(){this.initialized=!1,this.done=!1,this.donePromise=new Promise((e,i)=>{this.resolve=e,this.reject=i}),this.appInits=T(Wp,{optional:!0})??[]}runInitializers(){if(this.initialized)return;const e=[];for(const r of this.appInits){const o=r();if(pa(o))e.push(o);else if(kw(o)){const s=new Promise((a,c)=>{o.subscribe({complete:a,error:c})});e.push(s)}}const i=()=>{this.done=!0,this.resolve()};Promise.all(e).then(()=>{i()}).catch(r=>{this.reject(r)}),0===e.length&&i(),this.initialized=!0}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),jC=(()=>{class t{log(e){console.log(e)}warn(e){console.warn(e)}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"platform"})}return t})();const ri=new E("LocaleId",{providedIn:"root",factory:()=>T(ri,ge.Optional|ge.SkipSelf)||function vB(){return typeof $localize<"u"&&$localize.locale||Wo}()});let Zl=(()=>{class t{constructor(){this.taskId=0,this.pendingTasks=new Set,this.hasPendingTasks=new $t(!1)}add(){this.hasPendingTasks.next(!0);const e=this.taskId++;return this.pendingTasks.add(e),e}remove(e){this.pendingTasks.delete(e),0===this.pendingTasks.size&&this.hasPendingTasks.next(!1)}ngOnDestroy(){this.pendingTasks.clear(),this.hasPendingTasks.next(!1)}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();class xB{constructor(n,e){this.ngModuleFactory=n,this.componentFactories=e}}let HC=(()=>{class t{compileModuleSync(e){return new Rp(e)}compileModuleAsync(e){return Promise.resolve(this.compileModuleSync(e))}compileModuleAndAllComponentsSync(e){const i=this.compileModuleSync(e),o=wi(en(e).declarations).reduce((s,a)=>{const c=ye(a);return c&&s.push(new la(c)),s},[]);return new xB(i,o)}compileModuleAndAllComponentsAsync(e){return Promise.resolve(this.compileModuleAndAllComponentsSync(e))}clearCache(){}clearCacheFor(e){}getModuleId(e){}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();const GC=new E(""),Ql=new E("");let Xp,Kp=(()=>{class t{constructor(e,i,r){this._ngZone=e,this.registry=i,this._pendingCount=0,this._isZoneStable=!0,this._didWork=!1,this._callbacks=[],this.taskTrackingZone=null,Xp||(function zB(t){Xp=t}(r),r.addToWindow(i)),this._watchAngularEvents(),e.run(()=>{this.taskTrackingZone=typeof Zone>"u"?null:Zone.current.get("TaskTrackingZone")})}_watchAngularEvents(){this._ngZone.onUnstable.subscribe({next:()=>{this._didWork=!0,this._isZoneStable=!1}}),this._ngZone.runOutsideAngular(()=>{this._ngZone.onStable.subscribe({next:()=>{j.assertNotInAngularZone(),queueMicrotask(()=>{this._isZoneStable=!0,this._runCallbacksIfReady()})}})})}increasePendingRequestCount(){return this._pendingCount+=1,this._didWork=!0,this._pendingCount}decreasePendingRequestCount(){if(this._pendingCount-=1,this._pendingCount<0)throw new Error("pending async requests below zero");return this._runCallbacksIfReady(),this._pendingCount}isStable(){return this._isZoneStable&&0===this._pendingCount&&!this._ngZone.hasPendingMacrotasks}_runCallbacksIfReady(){if(this.isStable())queueMicrotask(()=>{for(;0!==this._callbacks.length;){let e=this._callbacks.pop();clearTimeout(e.timeoutId),e.doneCb(this._didWork)}this._didWork=!1});else{let e=this.getPendingTasks();this._callbacks=this._callbacks.filter(i=>!i.updateCb||!i.updateCb(e)||(clearTimeout(i.timeoutId),!1)),this._didWork=!0}}getPendingTasks(){return this.taskTrackingZone?this.taskTrackingZone.macroTasks.map(e=>({source:e.source,creationLocation:e.creationLocation,data:e.data})):[]}addCallback(e,i,r){let o=-1;i&&i>0&&(o=setTimeout(()=>{this._callbacks=this._callbacks.filter(s=>s.timeoutId!==o),e(this._didWork,this.getPendingTasks())},i)),this._callbacks.push({doneCb:e,timeoutId:o,updateCb:r})}whenStable(e,i,r){if(r&&!this.taskTrackingZone)throw new Error('Task tracking zone is required when passing an update callback to whenStable(). Is "zone.js/plugins/task-tracking" loaded?');this.addCallback(e,i,r),this._runCallbacksIfReady()}getPendingRequestCount(){return this._pendingCount}registerApplication(e){this.registry.registerApplication(e,this)}unregisterApplication(e){this.registry.unregisterApplication(e)}findProviders(e,i,r){return[]}static#e=this.\u0275fac=function(i){return new(i||t)(b(j),b(Qp),b(Ql))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})(),Qp=(()=>{class t{constructor(){this._applications=new Map}registerApplication(e,i){this._applications.set(e,i)}unregisterApplication(e){this._applications.delete(e)}unregisterAllApplications(){this._applications.clear()}getTestability(e){return this._applications.get(e)||null}getAllTestabilities(){return Array.from(this._applications.values())}getAllRootElements(){return Array.from(this._applications.keys())}findTestabilityInTree(e,i=!0){return Xp?.findTestabilityInTree(this,e,i)??null}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"platform"})}return t})(),Qi=null;const WC=new E("AllowMultipleToken"),Jp=new E("PlatformDestroyListeners"),em=new E("appBootstrapListener");class YC{constructor(n,e){this.name=n,this.token=e}}function KC(t,n,e=[]){const i=`Platform: ${n}`,r=new E(i);return(o=[])=>{let s=tm();if(!s||s.injector.get(WC,!1)){const a=[...e,...o,{provide:r,useValue:!0}];t?t(a):function GB(t){if(Qi&&!Qi.get(WC,!1))throw new y(400,!1);(function qC(){!function _1(t){bv=t}(()=>{throw new y(600,!1)})})(),Qi=t;const n=t.get(XC);(function ZC(t){t.get(h0,null)?.forEach(e=>e())})(t)}(function QC(t=[],n){return We.create({name:n,providers:[{provide:kf,useValue:"platform"},{provide:Jp,useValue:new Set([()=>Qi=null])},...t]})}(a,i))}return function qB(t){const n=tm();if(!n)throw new y(401,!1);return n}()}}function tm(){return Qi?.get(XC)??null}let XC=(()=>{class t{constructor(e){this._injector=e,this._modules=[],this._destroyListeners=[],this._destroyed=!1}bootstrapModuleFactory(e,i){const r=function YB(t="zone.js",n){return"noop"===t?new TF:"zone.js"===t?new j(n):t}(i?.ngZone,function JC(t){return{enableLongStackTrace:!1,shouldCoalesceEventChangeDetection:t?.eventCoalescing??!1,shouldCoalesceRunChangeDetection:t?.runCoalescing??!1}}({eventCoalescing:i?.ngZoneEventCoalescing,runCoalescing:i?.ngZoneRunCoalescing}));return r.run(()=>{const o=function s2(t,n,e){return new kp(t,n,e)}(e.moduleType,this.injector,function rD(t){return[{provide:j,useFactory:t},{provide:ta,multi:!0,useFactory:()=>{const n=T(KB,{optional:!0});return()=>n.initialize()}},{provide:iD,useFactory:ZB},{provide:M0,useFactory:I0}]}(()=>r)),s=o.injector.get(vn,null);return r.runOutsideAngular(()=>{const a=r.onError.subscribe({next:c=>{s.handleError(c)}});o.onDestroy(()=>{Xl(this._modules,o),a.unsubscribe()})}),function eD(t,n,e){try{const i=e();return pa(i)?i.catch(r=>{throw n.runOutsideAngular(()=>t.handleError(r)),r}):i}catch(i){throw n.runOutsideAngular(()=>t.handleError(i)),i}}(s,r,()=>{const a=o.injector.get(qp);return a.runInitializers(),a.donePromise.then(()=>(function Cx(t){mn(t,"Expected localeId to be defined"),"string"==typeof t&&(xx=t.toLowerCase().replace(/_/g,"-"))}(o.injector.get(ri,Wo)||Wo),this._moduleDoBootstrap(o),o))})})}bootstrapModule(e,i=[]){const r=tD({},i);return function $B(t,n,e){const i=new Rp(e);return Promise.resolve(i)}(0,0,e).then(o=>this.bootstrapModuleFactory(o,r))}_moduleDoBootstrap(e){const i=e.injector.get(Xi);if(e._bootstrapComponents.length>0)e._bootstrapComponents.forEach(r=>i.bootstrap(r));else{if(!e.instance.ngDoBootstrap)throw new y(-403,!1);e.instance.ngDoBootstrap(i)}this._modules.push(e)}onDestroy(e){this._destroyListeners.push(e)}get injector(){return this._injector}destroy(){if(this._destroyed)throw new y(404,!1);this._modules.slice().forEach(i=>i.destroy()),this._destroyListeners.forEach(i=>i());const e=this._injector.get(Jp,null);e&&(e.forEach(i=>i()),e.clear()),this._destroyed=!0}get destroyed(){return this._destroyed}static#e=this.\u0275fac=function(i){return new(i||t)(b(We))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"platform"})}return t})();function tD(t,n){return Array.isArray(n)?n.reduce(tD,t):{...t,...n}}let Xi=(()=>{class t{constructor(){this._bootstrapListeners=[],this._runningTick=!1,this._destroyed=!1,this._destroyListeners=[],this._views=[],this.internalErrorHandler=T(iD),this.zoneIsStable=T(M0),this.componentTypes=[],this.components=[],this.isStable=T(Zl).hasPendingTasks.pipe(Xt(e=>e?z(!1):this.zoneIsStable),Mc(),Sc()),this._injector=T(on)}get destroyed(){return this._destroyed}get injector(){return this._injector}bootstrap(e,i){const r=e instanceof g0;if(!this._injector.get(qp).done)throw!r&&function ro(t){const n=ye(t)||xt(t)||Ft(t);return null!==n&&n.standalone}(e),new y(405,!1);let s;s=r?e:this._injector.get(Sr).resolveComponentFactory(e),this.componentTypes.push(s.componentType);const a=function UB(t){return t.isBoundToModule}(s)?void 0:this._injector.get(kr),l=s.create(We.NULL,[],i||s.selector,a),d=l.location.nativeElement,u=l.injector.get(GC,null);return u?.registerApplication(d),l.onDestroy(()=>{this.detachView(l.hostView),Xl(this.components,l),u?.unregisterApplication(d)}),this._loadComponent(l),l}tick(){if(this._runningTick)throw new y(101,!1);try{this._runningTick=!0;for(let e of this._views)e.detectChanges()}catch(e){this.internalErrorHandler(e)}finally{this._runningTick=!1}}attachView(e){const i=e;this._views.push(i),i.attachToAppRef(this)}detachView(e){const i=e;Xl(this._views,i),i.detachFromAppRef()}_loadComponent(e){this.attachView(e.hostView),this.tick(),this.components.push(e);const i=this._injector.get(em,[]);i.push(...this._bootstrapListeners),i.forEach(r=>r(e))}ngOnDestroy(){if(!this._destroyed)try{this._destroyListeners.forEach(e=>e()),this._views.slice().forEach(e=>e.destroy())}finally{this._destroyed=!0,this._views=[],this._bootstrapListeners=[],this._destroyListeners=[]}}onDestroy(e){return this._destroyListeners.push(e),()=>Xl(this._destroyListeners,e)}destroy(){if(this._destroyed)throw new y(406,!1);const e=this._injector;e.destroy&&!e.destroyed&&e.destroy()}get viewCount(){return this._views.length}warnIfDestroyed(){}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();function Xl(t,n){const e=t.indexOf(n);e>-1&&t.splice(e,1)}const iD=new E("",{providedIn:"root",factory:()=>T(vn).handleError.bind(void 0)});function ZB(){const t=T(j),n=T(vn);return e=>t.runOutsideAngular(()=>n.handleError(e))}let KB=(()=>{class t{constructor(){this.zone=T(j),this.applicationRef=T(Xi)}initialize(){this._onMicrotaskEmptySubscription||(this._onMicrotaskEmptySubscription=this.zone.onMicrotaskEmpty.subscribe({next:()=>{this.zone.run(()=>{this.applicationRef.tick()})}}))}ngOnDestroy(){this._onMicrotaskEmptySubscription?.unsubscribe()}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();let ct=(()=>{class t{static#e=this.__NG_ELEMENT_ID__=XB}return t})();function XB(t){return function JB(t,n,e){if(br(t)&&!e){const i=tn(t.index,n);return new ca(i,i)}return 47&t.type?new ca(n[rt],n):null}(Et(),S(),16==(16&t))}class cD{constructor(){}supports(n){return Fl(n)}create(n){return new oV(n)}}const rV=(t,n)=>n;class oV{constructor(n){this.length=0,this._linkedRecords=null,this._unlinkedRecords=null,this._previousItHead=null,this._itHead=null,this._itTail=null,this._additionsHead=null,this._additionsTail=null,this._movesHead=null,this._movesTail=null,this._removalsHead=null,this._removalsTail=null,this._identityChangesHead=null,this._identityChangesTail=null,this._trackByFn=n||rV}forEachItem(n){let e;for(e=this._itHead;null!==e;e=e._next)n(e)}forEachOperation(n){let e=this._itHead,i=this._removalsHead,r=0,o=null;for(;e||i;){const s=!i||e&&e.currentIndex<dD(i,r,o)?e:i,a=dD(s,r,o),c=s.currentIndex;if(s===i)r--,i=i._nextRemoved;else if(e=e._next,null==s.previousIndex)r++;else{o||(o=[]);const l=a-r,d=c-r;if(l!=d){for(let h=0;h<l;h++){const p=h<o.length?o[h]:o[h]=0,m=p+h;d<=m&&m<l&&(o[h]=p+1)}o[s.previousIndex]=d-l}}a!==c&&n(s,a,c)}}forEachPreviousItem(n){let e;for(e=this._previousItHead;null!==e;e=e._nextPrevious)n(e)}forEachAddedItem(n){let e;for(e=this._additionsHead;null!==e;e=e._nextAdded)n(e)}forEachMovedItem(n){let e;for(e=this._movesHead;null!==e;e=e._nextMoved)n(e)}forEachRemovedItem(n){let e;for(e=this._removalsHead;null!==e;e=e._nextRemoved)n(e)}forEachIdentityChange(n){let e;for(e=this._identityChangesHead;null!==e;e=e._nextIdentityChange)n(e)}diff(n){if(null==n&&(n=[]),!Fl(n))throw new y(900,!1);return this.check(n)?this:null}onDestroy(){}check(n){this._reset();let r,o,s,e=this._itHead,i=!1;if(Array.isArray(n)){this.length=n.length;for(let a=0;a<this.length;a++)o=n[a],s=this._trackByFn(a,o),null!==e&&Object.is(e.trackById,s)?(i&&(e=this._verifyReinsertion(e,o,s,a)),Object.is(e.item,o)||this._addIdentityChange(e,o)):(e=this._mismatch(e,o,s,a),i=!0),e=e._next}else r=0,function VN(t,n){if(Array.isArray(t))for(let e=0;e<t.length;e++)n(t[e]);else{const e=t[Symbol.iterator]();let i;for(;!(i=e.next()).done;)n(i.value)}}(n,a=>{s=this._trackByFn(r,a),null!==e&&Object.is(e.trackById,s)?(i&&(e=this._verifyReinsertion(e,a,s,r)),Object.is(e.item,a)||this._addIdentityChange(e,a)):(e=this._mismatch(e,a,s,r),i=!0),e=e._next,r++}),this.length=r;return this._truncate(e),this.collection=n,this.isDirty}get isDirty(){return null!==this._additionsHead||null!==this._movesHead||null!==this._removalsHead||null!==this._identityChangesHead}_reset(){if(this.isDirty){let n;for(n=this._previousItHead=this._itHead;null!==n;n=n._next)n._nextPrevious=n._next;for(n=this._additionsHead;null!==n;n=n._nextAdded)n.previousIndex=n.currentIndex;for(this._additionsHead=this._additionsTail=null,n=this._movesHead;null!==n;n=n._nextMoved)n.previousIndex=n.currentIndex;this._movesHead=this._movesTail=null,this._removalsHead=this._removalsTail=null,this._identityChangesHead=this._identityChangesTail=null}}_mismatch(n,e,i,r){let o;return null===n?o=this._itTail:(o=n._prev,this._remove(n)),null!==(n=null===this._unlinkedRecords?null:this._unlinkedRecords.get(i,null))?(Object.is(n.item,e)||this._addIdentityChange(n,e),this._reinsertAfter(n,o,r)):null!==(n=null===this._linkedRecords?null:this._linkedRecords.get(i,r))?(Object.is(n.item,e)||this._addIdentityChange(n,e),this._moveAfter(n,o,r)):n=this._addAfter(new sV(e,i),o,r),n}_verifyReinsertion(n,e,i,r){let o=null===this._unlinkedRecords?null:this._unlinkedRecords.get(i,null);return null!==o?n=this._reinsertAfter(o,n._prev,r):n.currentIndex!=r&&(n.currentIndex=r,this._addToMoves(n,r)),n}_truncate(n){for(;null!==n;){const e=n._next;this._addToRemovals(this._unlink(n)),n=e}null!==this._unlinkedRecords&&this._unlinkedRecords.clear(),null!==this._additionsTail&&(this._additionsTail._nextAdded=null),null!==this._movesTail&&(this._movesTail._nextMoved=null),null!==this._itTail&&(this._itTail._next=null),null!==this._removalsTail&&(this._removalsTail._nextRemoved=null),null!==this._identityChangesTail&&(this._identityChangesTail._nextIdentityChange=null)}_reinsertAfter(n,e,i){null!==this._unlinkedRecords&&this._unlinkedRecords.remove(n);const r=n._prevRemoved,o=n._nextRemoved;return null===r?this._removalsHead=o:r._nextRemoved=o,null===o?this._removalsTail=r:o._prevRemoved=r,this._insertAfter(n,e,i),this._addToMoves(n,i),n}_moveAfter(n,e,i){return this._unlink(n),this._insertAfter(n,e,i),this._addToMoves(n,i),n}_addAfter(n,e,i){return this._insertAfter(n,e,i),this._additionsTail=null===this._additionsTail?this._additionsHead=n:this._additionsTail._nextAdded=n,n}_insertAfter(n,e,i){const r=null===e?this._itHead:e._next;return n._next=r,n._prev=e,null===r?this._itTail=n:r._prev=n,null===e?this._itHead=n:e._next=n,null===this._linkedRecords&&(this._linkedRecords=new lD),this._linkedRecords.put(n),n.currentIndex=i,n}_remove(n){return this._addToRemovals(this._unlink(n))}_unlink(n){null!==this._linkedRecords&&this._linkedRecords.remove(n);const e=n._prev,i=n._next;return null===e?this._itHead=i:e._next=i,null===i?this._itTail=e:i._prev=e,n}_addToMoves(n,e){return n.previousIndex===e||(this._movesTail=null===this._movesTail?this._movesHead=n:this._movesTail._nextMoved=n),n}_addToRemovals(n){return null===this._unlinkedRecords&&(this._unlinkedRecords=new lD),this._unlinkedRecords.put(n),n.currentIndex=null,n._nextRemoved=null,null===this._removalsTail?(this._removalsTail=this._removalsHead=n,n._prevRemoved=null):(n._prevRemoved=this._removalsTail,this._removalsTail=this._removalsTail._nextRemoved=n),n}_addIdentityChange(n,e){return n.item=e,this._identityChangesTail=null===this._identityChangesTail?this._identityChangesHead=n:this._identityChangesTail._nextIdentityChange=n,n}}class sV{constructor(n,e){this.item=n,this.trackById=e,this.currentIndex=null,this.previousIndex=null,this._nextPrevious=null,this._prev=null,this._next=null,this._prevDup=null,this._nextDup=null,this._prevRemoved=null,this._nextRemoved=null,this._nextAdded=null,this._nextMoved=null,this._nextIdentityChange=null}}class aV{constructor(){this._head=null,this._tail=null}add(n){null===this._head?(this._head=this._tail=n,n._nextDup=null,n._prevDup=null):(this._tail._nextDup=n,n._prevDup=this._tail,n._nextDup=null,this._tail=n)}get(n,e){let i;for(i=this._head;null!==i;i=i._nextDup)if((null===e||e<=i.currentIndex)&&Object.is(i.trackById,n))return i;return null}remove(n){const e=n._prevDup,i=n._nextDup;return null===e?this._head=i:e._nextDup=i,null===i?this._tail=e:i._prevDup=e,null===this._head}}class lD{constructor(){this.map=new Map}put(n){const e=n.trackById;let i=this.map.get(e);i||(i=new aV,this.map.set(e,i)),i.add(n)}get(n,e){const r=this.map.get(n);return r?r.get(n,e):null}remove(n){const e=n.trackById;return this.map.get(e).remove(n)&&this.map.delete(e),n}get isEmpty(){return 0===this.map.size}clear(){this.map.clear()}}function dD(t,n,e){const i=t.previousIndex;if(null===i)return i;let r=0;return e&&i<e.length&&(r=e[i]),i+n+r}class uD{constructor(){}supports(n){return n instanceof Map||up(n)}create(){return new cV}}class cV{constructor(){this._records=new Map,this._mapHead=null,this._appendAfter=null,this._previousMapHead=null,this._changesHead=null,this._changesTail=null,this._additionsHead=null,this._additionsTail=null,this._removalsHead=null,this._removalsTail=null}get isDirty(){return null!==this._additionsHead||null!==this._changesHead||null!==this._removalsHead}forEachItem(n){let e;for(e=this._mapHead;null!==e;e=e._next)n(e)}forEachPreviousItem(n){let e;for(e=this._previousMapHead;null!==e;e=e._nextPrevious)n(e)}forEachChangedItem(n){let e;for(e=this._changesHead;null!==e;e=e._nextChanged)n(e)}forEachAddedItem(n){let e;for(e=this._additionsHead;null!==e;e=e._nextAdded)n(e)}forEachRemovedItem(n){let e;for(e=this._removalsHead;null!==e;e=e._nextRemoved)n(e)}diff(n){if(n){if(!(n instanceof Map||up(n)))throw new y(900,!1)}else n=new Map;return this.check(n)?this:null}onDestroy(){}check(n){this._reset();let e=this._mapHead;if(this._appendAfter=null,this._forEach(n,(i,r)=>{if(e&&e.key===r)this._maybeAddToChanges(e,i),this._appendAfter=e,e=e._next;else{const o=this._getOrCreateRecordForKey(r,i);e=this._insertBeforeOrAppend(e,o)}}),e){e._prev&&(e._prev._next=null),this._removalsHead=e;for(let i=e;null!==i;i=i._nextRemoved)i===this._mapHead&&(this._mapHead=null),this._records.delete(i.key),i._nextRemoved=i._next,i.previousValue=i.currentValue,i.currentValue=null,i._prev=null,i._next=null}return this._changesTail&&(this._changesTail._nextChanged=null),this._additionsTail&&(this._additionsTail._nextAdded=null),this.isDirty}_insertBeforeOrAppend(n,e){if(n){const i=n._prev;return e._next=n,e._prev=i,n._prev=e,i&&(i._next=e),n===this._mapHead&&(this._mapHead=e),this._appendAfter=n,n}return this._appendAfter?(this._appendAfter._next=e,e._prev=this._appendAfter):this._mapHead=e,this._appendAfter=e,null}_getOrCreateRecordForKey(n,e){if(this._records.has(n)){const r=this._records.get(n);this._maybeAddToChanges(r,e);const o=r._prev,s=r._next;return o&&(o._next=s),s&&(s._prev=o),r._next=null,r._prev=null,r}const i=new lV(n);return this._records.set(n,i),i.currentValue=e,this._addToAdditions(i),i}_reset(){if(this.isDirty){let n;for(this._previousMapHead=this._mapHead,n=this._previousMapHead;null!==n;n=n._next)n._nextPrevious=n._next;for(n=this._changesHead;null!==n;n=n._nextChanged)n.previousValue=n.currentValue;for(n=this._additionsHead;null!=n;n=n._nextAdded)n.previousValue=n.currentValue;this._changesHead=this._changesTail=null,this._additionsHead=this._additionsTail=null,this._removalsHead=null}}_maybeAddToChanges(n,e){Object.is(e,n.currentValue)||(n.previousValue=n.currentValue,n.currentValue=e,this._addToChanges(n))}_addToAdditions(n){null===this._additionsHead?this._additionsHead=this._additionsTail=n:(this._additionsTail._nextAdded=n,this._additionsTail=n)}_addToChanges(n){null===this._changesHead?this._changesHead=this._changesTail=n:(this._changesTail._nextChanged=n,this._changesTail=n)}_forEach(n,e){n instanceof Map?n.forEach(e):Object.keys(n).forEach(i=>e(n[i],i))}}class lV{constructor(n){this.key=n,this.previousValue=null,this.currentValue=null,this._nextPrevious=null,this._next=null,this._prev=null,this._nextAdded=null,this._nextRemoved=null,this._nextChanged=null}}function hD(){return new Di([new cD])}let Di=(()=>{class t{static#e=this.\u0275prov=I({token:t,providedIn:"root",factory:hD});constructor(e){this.factories=e}static create(e,i){if(null!=i){const r=i.factories.slice();e=e.concat(r)}return new t(e)}static extend(e){return{provide:t,useFactory:i=>t.create(e,i||hD()),deps:[[t,new qs,new xr]]}}find(e){const i=this.factories.find(r=>r.supports(e));if(null!=i)return i;throw new y(901,!1)}}return t})();function fD(){return new Sa([new uD])}let Sa=(()=>{class t{static#e=this.\u0275prov=I({token:t,providedIn:"root",factory:fD});constructor(e){this.factories=e}static create(e,i){if(i){const r=i.factories.slice();e=e.concat(r)}return new t(e)}static extend(e){return{provide:t,useFactory:i=>t.create(e,i||fD()),deps:[[t,new qs,new xr]]}}find(e){const i=this.factories.find(r=>r.supports(e));if(i)return i;throw new y(901,!1)}}return t})();const hV=KC(null,"core",[]);let fV=(()=>{class t{constructor(e){}static#e=this.\u0275fac=function(i){return new(i||t)(b(Xi))};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({})}return t})();let am=null;function Ji(){return am}class MV{}const Z=new E("DocumentToken");let cm=(()=>{class t{historyGo(e){throw new Error("Not implemented")}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:function(){return T(TV)},providedIn:"platform"})}return t})();const IV=new E("Location Initialized");let TV=(()=>{class t extends cm{constructor(){super(),this._doc=T(Z),this._location=window.location,this._history=window.history}getBaseHrefFromDOM(){return Ji().getBaseHref(this._doc)}onPopState(e){const i=Ji().getGlobalEventTarget(this._doc,"window");return i.addEventListener("popstate",e,!1),()=>i.removeEventListener("popstate",e)}onHashChange(e){const i=Ji().getGlobalEventTarget(this._doc,"window");return i.addEventListener("hashchange",e,!1),()=>i.removeEventListener("hashchange",e)}get href(){return this._location.href}get protocol(){return this._location.protocol}get hostname(){return this._location.hostname}get port(){return this._location.port}get pathname(){return this._location.pathname}get search(){return this._location.search}get hash(){return this._location.hash}set pathname(e){this._location.pathname=e}pushState(e,i,r){this._history.pushState(e,i,r)}replaceState(e,i,r){this._history.replaceState(e,i,r)}forward(){this._history.forward()}back(){this._history.back()}historyGo(e=0){this._history.go(e)}getState(){return this._history.state}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:function(){return new t},providedIn:"platform"})}return t})();function lm(t,n){if(0==t.length)return n;if(0==n.length)return t;let e=0;return t.endsWith("/")&&e++,n.startsWith("/")&&e++,2==e?t+n.substring(1):1==e?t+n:t+"/"+n}function xD(t){const n=t.match(/#|\?|$/),e=n&&n.index||t.length;return t.slice(0,e-("/"===t[e-1]?1:0))+t.slice(e)}function Ei(t){return t&&"?"!==t[0]?"?"+t:t}let Fr=(()=>{class t{historyGo(e){throw new Error("Not implemented")}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:function(){return T(DD)},providedIn:"root"})}return t})();const CD=new E("appBaseHref");let DD=(()=>{class t extends Fr{constructor(e,i){super(),this._platformLocation=e,this._removeListenerFns=[],this._baseHref=i??this._platformLocation.getBaseHrefFromDOM()??T(Z).location?.origin??""}ngOnDestroy(){for(;this._removeListenerFns.length;)this._removeListenerFns.pop()()}onPopState(e){this._removeListenerFns.push(this._platformLocation.onPopState(e),this._platformLocation.onHashChange(e))}getBaseHref(){return this._baseHref}prepareExternalUrl(e){return lm(this._baseHref,e)}path(e=!1){const i=this._platformLocation.pathname+Ei(this._platformLocation.search),r=this._platformLocation.hash;return r&&e?`${i}${r}`:i}pushState(e,i,r,o){const s=this.prepareExternalUrl(r+Ei(o));this._platformLocation.pushState(e,i,s)}replaceState(e,i,r,o){const s=this.prepareExternalUrl(r+Ei(o));this._platformLocation.replaceState(e,i,s)}forward(){this._platformLocation.forward()}back(){this._platformLocation.back()}getState(){return this._platformLocation.getState()}historyGo(e=0){this._platformLocation.historyGo?.(e)}static#e=this.\u0275fac=function(i){return new(i||t)(b(cm),b(CD,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),AV=(()=>{class t extends Fr{constructor(e,i){super(),this._platformLocation=e,this._baseHref="",this._removeListenerFns=[],null!=i&&(this._baseHref=i)}ngOnDestroy(){for(;this._removeListenerFns.length;)this._removeListenerFns.pop()()}onPopState(e){this._removeListenerFns.push(this._platformLocation.onPopState(e),this._platformLocation.onHashChange(e))}getBaseHref(){return this._baseHref}path(e=!1){let i=this._platformLocation.hash;return null==i&&(i="#"),i.length>0?i.substring(1):i}prepareExternalUrl(e){const i=lm(this._baseHref,e);return i.length>0?"#"+i:i}pushState(e,i,r,o){let s=this.prepareExternalUrl(r+Ei(o));0==s.length&&(s=this._platformLocation.pathname),this._platformLocation.pushState(e,i,s)}replaceState(e,i,r,o){let s=this.prepareExternalUrl(r+Ei(o));0==s.length&&(s=this._platformLocation.pathname),this._platformLocation.replaceState(e,i,s)}forward(){this._platformLocation.forward()}back(){this._platformLocation.back()}getState(){return this._platformLocation.getState()}historyGo(e=0){this._platformLocation.historyGo?.(e)}static#e=this.\u0275fac=function(i){return new(i||t)(b(cm),b(CD,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})(),Ma=(()=>{class t{constructor(e){this._subject=new q,this._urlChangeListeners=[],this._urlChangeSubscription=null,this._locationStrategy=e;const i=this._locationStrategy.getBaseHref();this._basePath=function OV(t){if(new RegExp("^(https?:)?//").test(t)){const[,e]=t.split(/\/\/[^\/]+/);return e}return t}(xD(ED(i))),this._locationStrategy.onPopState(r=>{this._subject.emit({url:this.path(!0),pop:!0,state:r.state,type:r.type})})}ngOnDestroy(){this._urlChangeSubscription?.unsubscribe(),this._urlChangeListeners=[]}path(e=!1){return this.normalize(this._locationStrategy.path(e))}getState(){return this._locationStrategy.getState()}isCurrentPathEqualTo(e,i=""){return this.path()==this.normalize(e+Ei(i))}normalize(e){return t.stripTrailingSlash(function RV(t,n){if(!t||!n.startsWith(t))return n;const e=n.substring(t.length);return""===e||["/",";","?","#"].includes(e[0])?e:n}(this._basePath,ED(e)))}prepareExternalUrl(e){return e&&"/"!==e[0]&&(e="/"+e),this._locationStrategy.prepareExternalUrl(e)}go(e,i="",r=null){this._locationStrategy.pushState(r,"",e,i),this._notifyUrlChangeListeners(this.prepareExternalUrl(e+Ei(i)),r)}replaceState(e,i="",r=null){this._locationStrategy.replaceState(r,"",e,i),this._notifyUrlChangeListeners(this.prepareExternalUrl(e+Ei(i)),r)}forward(){this._locationStrategy.forward()}back(){this._locationStrategy.back()}historyGo(e=0){this._locationStrategy.historyGo?.(e)}onUrlChange(e){return this._urlChangeListeners.push(e),this._urlChangeSubscription||(this._urlChangeSubscription=this.subscribe(i=>{this._notifyUrlChangeListeners(i.url,i.state)})),()=>{const i=this._urlChangeListeners.indexOf(e);this._urlChangeListeners.splice(i,1),0===this._urlChangeListeners.length&&(this._urlChangeSubscription?.unsubscribe(),this._urlChangeSubscription=null)}}_notifyUrlChangeListeners(e="",i){this._urlChangeListeners.forEach(r=>r(e,i))}subscribe(e,i,r){return this._subject.subscribe({next:e,error:i,complete:r})}static#e=this.normalizeQueryParams=Ei;static#t=this.joinWithSlash=lm;static#n=this.stripTrailingSlash=xD;static#i=this.\u0275fac=function(i){return new(i||t)(b(Fr))};static#r=this.\u0275prov=I({token:t,factory:function(){return function kV(){return new Ma(b(Fr))}()},providedIn:"root"})}return t})();function ED(t){return t.replace(/\/index.html$/,"")}function FD(t,n){n=encodeURIComponent(n);for(const e of t.split(";")){const i=e.indexOf("="),[r,o]=-1==i?[e,""]:[e.slice(0,i),e.slice(i+1)];if(r.trim()===n)return decodeURIComponent(o)}return null}const vm=/\s+/,ND=[];let PD=(()=>{class t{constructor(e,i,r,o){this._iterableDiffers=e,this._keyValueDiffers=i,this._ngEl=r,this._renderer=o,this.initialClasses=ND,this.stateMap=new Map}set klass(e){this.initialClasses=null!=e?e.trim().split(vm):ND}set ngClass(e){this.rawClass="string"==typeof e?e.trim().split(vm):e}ngDoCheck(){for(const i of this.initialClasses)this._updateState(i,!0);const e=this.rawClass;if(Array.isArray(e)||e instanceof Set)for(const i of e)this._updateState(i,!0);else if(null!=e)for(const i of Object.keys(e))this._updateState(i,!!e[i]);this._applyStateDiff()}_updateState(e,i){const r=this.stateMap.get(e);void 0!==r?(r.enabled!==i&&(r.changed=!0,r.enabled=i),r.touched=!0):this.stateMap.set(e,{enabled:i,changed:!0,touched:!0})}_applyStateDiff(){for(const e of this.stateMap){const i=e[0],r=e[1];r.changed?(this._toggleClass(i,r.enabled),r.changed=!1):r.touched||(r.enabled&&this._toggleClass(i,!1),this.stateMap.delete(i)),r.touched=!1}}_toggleClass(e,i){(e=e.trim()).length>0&&e.split(vm).forEach(r=>{i?this._renderer.addClass(this._ngEl.nativeElement,r):this._renderer.removeClass(this._ngEl.nativeElement,r)})}static#e=this.\u0275fac=function(i){return new(i||t)(f(Di),f(Sa),f($),f(yi))};static#t=this.\u0275dir=x({type:t,selectors:[["","ngClass",""]],inputs:{klass:["class","klass"],ngClass:"ngClass"},standalone:!0})}return t})();class bj{constructor(n,e,i,r){this.$implicit=n,this.ngForOf=e,this.index=i,this.count=r}get first(){return 0===this.index}get last(){return this.index===this.count-1}get even(){return this.index%2==0}get odd(){return!this.even}}let ym=(()=>{class t{set ngForOf(e){this._ngForOf=e,this._ngForOfDirty=!0}set ngForTrackBy(e){this._trackByFn=e}get ngForTrackBy(){return this._trackByFn}constructor(e,i,r){this._viewContainer=e,this._template=i,this._differs=r,this._ngForOf=null,this._ngForOfDirty=!0,this._differ=null}set ngForTemplate(e){e&&(this._template=e)}ngDoCheck(){if(this._ngForOfDirty){this._ngForOfDirty=!1;const e=this._ngForOf;!this._differ&&e&&(this._differ=this._differs.find(e).create(this.ngForTrackBy))}if(this._differ){const e=this._differ.diff(this._ngForOf);e&&this._applyChanges(e)}}_applyChanges(e){const i=this._viewContainer;e.forEachOperation((r,o,s)=>{if(null==r.previousIndex)i.createEmbeddedView(this._template,new bj(r.item,this._ngForOf,-1,-1),null===s?void 0:s);else if(null==s)i.remove(null===o?void 0:o);else if(null!==o){const a=i.get(o);i.move(a,s),BD(a,r)}});for(let r=0,o=i.length;r<o;r++){const a=i.get(r).context;a.index=r,a.count=o,a.ngForOf=this._ngForOf}e.forEachIdentityChange(r=>{BD(i.get(r.currentIndex),r)})}static ngTemplateContextGuard(e,i){return!0}static#e=this.\u0275fac=function(i){return new(i||t)(f(Je),f(Ue),f(Di))};static#t=this.\u0275dir=x({type:t,selectors:[["","ngFor","","ngForOf",""]],inputs:{ngForOf:"ngForOf",ngForTrackBy:"ngForTrackBy",ngForTemplate:"ngForTemplate"},standalone:!0})}return t})();function BD(t,n){t.context.$implicit=n.item}let Ii=(()=>{class t{constructor(e,i){this._viewContainer=e,this._context=new vj,this._thenTemplateRef=null,this._elseTemplateRef=null,this._thenViewRef=null,this._elseViewRef=null,this._thenTemplateRef=i}set ngIf(e){this._context.$implicit=this._context.ngIf=e,this._updateView()}set ngIfThen(e){VD("ngIfThen",e),this._thenTemplateRef=e,this._thenViewRef=null,this._updateView()}set ngIfElse(e){VD("ngIfElse",e),this._elseTemplateRef=e,this._elseViewRef=null,this._updateView()}_updateView(){this._context.$implicit?this._thenViewRef||(this._viewContainer.clear(),this._elseViewRef=null,this._thenTemplateRef&&(this._thenViewRef=this._viewContainer.createEmbeddedView(this._thenTemplateRef,this._context))):this._elseViewRef||(this._viewContainer.clear(),this._thenViewRef=null,this._elseTemplateRef&&(this._elseViewRef=this._viewContainer.createEmbeddedView(this._elseTemplateRef,this._context)))}static ngTemplateContextGuard(e,i){return!0}static#e=this.\u0275fac=function(i){return new(i||t)(f(Je),f(Ue))};static#t=this.\u0275dir=x({type:t,selectors:[["","ngIf",""]],inputs:{ngIf:"ngIf",ngIfThen:"ngIfThen",ngIfElse:"ngIfElse"},standalone:!0})}return t})();class vj{constructor(){this.$implicit=null,this.ngIf=null}}function VD(t,n){if(n&&!n.createEmbeddedView)throw new Error(`${t} must be a TemplateRef, but received '${pt(n)}'.`)}class wm{constructor(n,e){this._viewContainerRef=n,this._templateRef=e,this._created=!1}create(){this._created=!0,this._viewContainerRef.createEmbeddedView(this._templateRef)}destroy(){this._created=!1,this._viewContainerRef.clear()}enforceState(n){n&&!this._created?this.create():!n&&this._created&&this.destroy()}}let Aa=(()=>{class t{constructor(){this._defaultViews=[],this._defaultUsed=!1,this._caseCount=0,this._lastCaseCheckIndex=0,this._lastCasesMatched=!1}set ngSwitch(e){this._ngSwitch=e,0===this._caseCount&&this._updateDefaultCases(!0)}_addCase(){return this._caseCount++}_addDefault(e){this._defaultViews.push(e)}_matchCase(e){const i=e==this._ngSwitch;return this._lastCasesMatched=this._lastCasesMatched||i,this._lastCaseCheckIndex++,this._lastCaseCheckIndex===this._caseCount&&(this._updateDefaultCases(!this._lastCasesMatched),this._lastCaseCheckIndex=0,this._lastCasesMatched=!1),i}_updateDefaultCases(e){if(this._defaultViews.length>0&&e!==this._defaultUsed){this._defaultUsed=e;for(const i of this._defaultViews)i.enforceState(e)}}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["","ngSwitch",""]],inputs:{ngSwitch:"ngSwitch"},standalone:!0})}return t})(),xm=(()=>{class t{constructor(e,i,r){this.ngSwitch=r,r._addCase(),this._view=new wm(e,i)}ngDoCheck(){this._view.enforceState(this.ngSwitch._matchCase(this.ngSwitchCase))}static#e=this.\u0275fac=function(i){return new(i||t)(f(Je),f(Ue),f(Aa,9))};static#t=this.\u0275dir=x({type:t,selectors:[["","ngSwitchCase",""]],inputs:{ngSwitchCase:"ngSwitchCase"},standalone:!0})}return t})(),Cm=(()=>{class t{constructor(e){this._viewContainerRef=e,this._viewRef=null,this.ngTemplateOutletContext=null,this.ngTemplateOutlet=null,this.ngTemplateOutletInjector=null}ngOnChanges(e){if(e.ngTemplateOutlet||e.ngTemplateOutletInjector){const i=this._viewContainerRef;if(this._viewRef&&i.remove(i.indexOf(this._viewRef)),this.ngTemplateOutlet){const{ngTemplateOutlet:r,ngTemplateOutletContext:o,ngTemplateOutletInjector:s}=this;this._viewRef=i.createEmbeddedView(r,o,s?{injector:s}:void 0)}else this._viewRef=null}else this._viewRef&&e.ngTemplateOutletContext&&this.ngTemplateOutletContext&&(this._viewRef.context=this.ngTemplateOutletContext)}static#e=this.\u0275fac=function(i){return new(i||t)(f(Je))};static#t=this.\u0275dir=x({type:t,selectors:[["","ngTemplateOutlet",""]],inputs:{ngTemplateOutletContext:"ngTemplateOutletContext",ngTemplateOutlet:"ngTemplateOutlet",ngTemplateOutletInjector:"ngTemplateOutletInjector"},standalone:!0,features:[ut]})}return t})(),oi=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({})}return t})();const zD="browser";function $D(t){return"server"===t}let Zj=(()=>{class t{static#e=this.\u0275prov=I({token:t,providedIn:"root",factory:()=>new Kj(b(Z),window)})}return t})();class Kj{constructor(n,e){this.document=n,this.window=e,this.offset=()=>[0,0]}setOffset(n){this.offset=Array.isArray(n)?()=>n:n}getScrollPosition(){return this.supportsScrolling()?[this.window.pageXOffset,this.window.pageYOffset]:[0,0]}scrollToPosition(n){this.supportsScrolling()&&this.window.scrollTo(n[0],n[1])}scrollToAnchor(n){if(!this.supportsScrolling())return;const e=function Qj(t,n){const e=t.getElementById(n)||t.getElementsByName(n)[0];if(e)return e;if("function"==typeof t.createTreeWalker&&t.body&&"function"==typeof t.body.attachShadow){const i=t.createTreeWalker(t.body,NodeFilter.SHOW_ELEMENT);let r=i.currentNode;for(;r;){const o=r.shadowRoot;if(o){const s=o.getElementById(n)||o.querySelector(`[name="${n}"]`);if(s)return s}r=i.nextNode()}}return null}(this.document,n);e&&(this.scrollToElement(e),e.focus())}setHistoryScrollRestoration(n){this.supportsScrolling()&&(this.window.history.scrollRestoration=n)}scrollToElement(n){const e=n.getBoundingClientRect(),i=e.left+this.window.pageXOffset,r=e.top+this.window.pageYOffset,o=this.offset();this.window.scrollTo(i-o[0],r-o[1])}supportsScrolling(){try{return!!this.window&&!!this.window.scrollTo&&"pageXOffset"in this.window}catch{return!1}}}class UD{}class yH extends MV{constructor(){super(...arguments),this.supportsDOMEvents=!0}}class Im extends yH{static makeCurrent(){!function SV(t){am||(am=t)}(new Im)}onAndCancel(n,e,i){return n.addEventListener(e,i),()=>{n.removeEventListener(e,i)}}dispatchEvent(n,e){n.dispatchEvent(e)}remove(n){n.parentNode&&n.parentNode.removeChild(n)}createElement(n,e){return(e=e||this.getDefaultDocument()).createElement(n)}createHtmlDocument(){return document.implementation.createHTMLDocument("fakeTitle")}getDefaultDocument(){return document}isElementNode(n){return n.nodeType===Node.ELEMENT_NODE}isShadowRoot(n){return n instanceof DocumentFragment}getGlobalEventTarget(n,e){return"window"===e?window:"document"===e?n:"body"===e?n.body:null}getBaseHref(n){const e=function wH(){return Ra=Ra||document.querySelector("base"),Ra?Ra.getAttribute("href"):null}();return null==e?null:function xH(t){pd=pd||document.createElement("a"),pd.setAttribute("href",t);const n=pd.pathname;return"/"===n.charAt(0)?n:`/${n}`}(e)}resetBaseElement(){Ra=null}getUserAgent(){return window.navigator.userAgent}getCookie(n){return FD(document.cookie,n)}}let pd,Ra=null,DH=(()=>{class t{build(){return new XMLHttpRequest}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();const Tm=new E("EventManagerPlugins");let ZD=(()=>{class t{constructor(e,i){this._zone=i,this._eventNameToPlugin=new Map,e.forEach(r=>{r.manager=this}),this._plugins=e.slice().reverse()}addEventListener(e,i,r){return this._findPluginFor(i).addEventListener(e,i,r)}getZone(){return this._zone}_findPluginFor(e){let i=this._eventNameToPlugin.get(e);if(i)return i;if(i=this._plugins.find(o=>o.supports(e)),!i)throw new y(5101,!1);return this._eventNameToPlugin.set(e,i),i}static#e=this.\u0275fac=function(i){return new(i||t)(b(Tm),b(j))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();class KD{constructor(n){this._doc=n}}const Am="ng-app-id";let QD=(()=>{class t{constructor(e,i,r,o={}){this.doc=e,this.appId=i,this.nonce=r,this.platformId=o,this.styleRef=new Map,this.hostNodes=new Set,this.styleNodesInDOM=this.collectServerRenderedStyles(),this.platformIsServer=$D(o),this.resetHostNodes()}addStyles(e){for(const i of e)1===this.changeUsageCount(i,1)&&this.onStyleAdded(i)}removeStyles(e){for(const i of e)this.changeUsageCount(i,-1)<=0&&this.onStyleRemoved(i)}ngOnDestroy(){const e=this.styleNodesInDOM;e&&(e.forEach(i=>i.remove()),e.clear());for(const i of this.getAllStyles())this.onStyleRemoved(i);this.resetHostNodes()}addHost(e){this.hostNodes.add(e);for(const i of this.getAllStyles())this.addStyleToHost(e,i)}removeHost(e){this.hostNodes.delete(e)}getAllStyles(){return this.styleRef.keys()}onStyleAdded(e){for(const i of this.hostNodes)this.addStyleToHost(i,e)}onStyleRemoved(e){const i=this.styleRef;i.get(e)?.elements?.forEach(r=>r.remove()),i.delete(e)}collectServerRenderedStyles(){const e=this.doc.head?.querySelectorAll(`style[${Am}="${this.appId}"]`);if(e?.length){const i=new Map;return e.forEach(r=>{null!=r.textContent&&i.set(r.textContent,r)}),i}return null}changeUsageCount(e,i){const r=this.styleRef;if(r.has(e)){const o=r.get(e);return o.usage+=i,o.usage}return r.set(e,{usage:i,elements:[]}),i}getStyleElement(e,i){const r=this.styleNodesInDOM,o=r?.get(i);if(o?.parentNode===e)return r.delete(i),o.removeAttribute(Am),o;{const s=this.doc.createElement("style");return this.nonce&&s.setAttribute("nonce",this.nonce),s.textContent=i,this.platformIsServer&&s.setAttribute(Am,this.appId),s}}addStyleToHost(e,i){const r=this.getStyleElement(e,i);e.appendChild(r);const o=this.styleRef,s=o.get(i)?.elements;s?s.push(r):o.set(i,{elements:[r],usage:1})}resetHostNodes(){const e=this.hostNodes;e.clear(),e.add(this.doc.head)}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z),b(yl),b(Nf,8),b(Yi))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();const km={svg:"http://www.w3.org/2000/svg",xhtml:"http://www.w3.org/1999/xhtml",xlink:"http://www.w3.org/1999/xlink",xml:"http://www.w3.org/XML/1998/namespace",xmlns:"http://www.w3.org/2000/xmlns/",math:"http://www.w3.org/1998/MathML/"},Rm=/%COMP%/g,IH=new E("RemoveStylesOnCompDestroy",{providedIn:"root",factory:()=>!1});function JD(t,n){return n.map(e=>e.replace(Rm,t))}let Om=(()=>{class t{constructor(e,i,r,o,s,a,c,l=null){this.eventManager=e,this.sharedStylesHost=i,this.appId=r,this.removeStylesOnCompDestroy=o,this.doc=s,this.platformId=a,this.ngZone=c,this.nonce=l,this.rendererByCompId=new Map,this.platformIsServer=$D(a),this.defaultRenderer=new Fm(e,s,c,this.platformIsServer)}createRenderer(e,i){if(!e||!i)return this.defaultRenderer;this.platformIsServer&&i.encapsulation===gn.ShadowDom&&(i={...i,encapsulation:gn.Emulated});const r=this.getOrCreateRenderer(e,i);return r instanceof tE?r.applyToHost(e):r instanceof Nm&&r.applyStyles(),r}getOrCreateRenderer(e,i){const r=this.rendererByCompId;let o=r.get(i.id);if(!o){const s=this.doc,a=this.ngZone,c=this.eventManager,l=this.sharedStylesHost,d=this.removeStylesOnCompDestroy,u=this.platformIsServer;switch(i.encapsulation){case gn.Emulated:o=new tE(c,l,i,this.appId,d,s,a,u);break;case gn.ShadowDom:return new RH(c,l,e,i,s,a,this.nonce,u);default:o=new Nm(c,l,i,d,s,a,u)}r.set(i.id,o)}return o}ngOnDestroy(){this.rendererByCompId.clear()}static#e=this.\u0275fac=function(i){return new(i||t)(b(ZD),b(QD),b(yl),b(IH),b(Z),b(Yi),b(j),b(Nf))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();class Fm{constructor(n,e,i,r){this.eventManager=n,this.doc=e,this.ngZone=i,this.platformIsServer=r,this.data=Object.create(null),this.destroyNode=null}destroy(){}createElement(n,e){return e?this.doc.createElementNS(km[e]||e,n):this.doc.createElement(n)}createComment(n){return this.doc.createComment(n)}createText(n){return this.doc.createTextNode(n)}appendChild(n,e){(eE(n)?n.content:n).appendChild(e)}insertBefore(n,e,i){n&&(eE(n)?n.content:n).insertBefore(e,i)}removeChild(n,e){n&&n.removeChild(e)}selectRootElement(n,e){let i="string"==typeof n?this.doc.querySelector(n):n;if(!i)throw new y(-5104,!1);return e||(i.textContent=""),i}parentNode(n){return n.parentNode}nextSibling(n){return n.nextSibling}setAttribute(n,e,i,r){if(r){e=r+":"+e;const o=km[r];o?n.setAttributeNS(o,e,i):n.setAttribute(e,i)}else n.setAttribute(e,i)}removeAttribute(n,e,i){if(i){const r=km[i];r?n.removeAttributeNS(r,e):n.removeAttribute(`${i}:${e}`)}else n.removeAttribute(e)}addClass(n,e){n.classList.add(e)}removeClass(n,e){n.classList.remove(e)}setStyle(n,e,i,r){r&(qi.DashCase|qi.Important)?n.style.setProperty(e,i,r&qi.Important?"important":""):n.style[e]=i}removeStyle(n,e,i){i&qi.DashCase?n.style.removeProperty(e):n.style[e]=""}setProperty(n,e,i){n[e]=i}setValue(n,e){n.nodeValue=e}listen(n,e,i){if("string"==typeof n&&!(n=Ji().getGlobalEventTarget(this.doc,n)))throw new Error(`Unsupported event target ${n} for event ${e}`);return this.eventManager.addEventListener(n,e,this.decoratePreventDefault(i))}decoratePreventDefault(n){return e=>{if("__ngUnwrap__"===e)return n;!1===(this.platformIsServer?this.ngZone.runGuarded(()=>n(e)):n(e))&&e.preventDefault()}}}function eE(t){return"TEMPLATE"===t.tagName&&void 0!==t.content}class RH extends Fm{constructor(n,e,i,r,o,s,a,c){super(n,o,s,c),this.sharedStylesHost=e,this.hostEl=i,this.shadowRoot=i.attachShadow({mode:"open"}),this.sharedStylesHost.addHost(this.shadowRoot);const l=JD(r.id,r.styles);for(const d of l){const u=document.createElement("style");a&&u.setAttribute("nonce",a),u.textContent=d,this.shadowRoot.appendChild(u)}}nodeOrShadowRoot(n){return n===this.hostEl?this.shadowRoot:n}appendChild(n,e){return super.appendChild(this.nodeOrShadowRoot(n),e)}insertBefore(n,e,i){return super.insertBefore(this.nodeOrShadowRoot(n),e,i)}removeChild(n,e){return super.removeChild(this.nodeOrShadowRoot(n),e)}parentNode(n){return this.nodeOrShadowRoot(super.parentNode(this.nodeOrShadowRoot(n)))}destroy(){this.sharedStylesHost.removeHost(this.shadowRoot)}}class Nm extends Fm{constructor(n,e,i,r,o,s,a,c){super(n,o,s,a),this.sharedStylesHost=e,this.removeStylesOnCompDestroy=r,this.styles=c?JD(c,i.styles):i.styles}applyStyles(){this.sharedStylesHost.addStyles(this.styles)}destroy(){this.removeStylesOnCompDestroy&&this.sharedStylesHost.removeStyles(this.styles)}}class tE extends Nm{constructor(n,e,i,r,o,s,a,c){const l=r+"-"+i.id;super(n,e,i,o,s,a,c,l),this.contentAttr=function TH(t){return"_ngcontent-%COMP%".replace(Rm,t)}(l),this.hostAttr=function AH(t){return"_nghost-%COMP%".replace(Rm,t)}(l)}applyToHost(n){this.applyStyles(),this.setAttribute(n,this.hostAttr,"")}createElement(n,e){const i=super.createElement(n,e);return super.setAttribute(i,this.contentAttr,""),i}}let OH=(()=>{class t extends KD{constructor(e){super(e)}supports(e){return!0}addEventListener(e,i,r){return e.addEventListener(i,r,!1),()=>this.removeEventListener(e,i,r)}removeEventListener(e,i,r){return e.removeEventListener(i,r)}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();const nE=["alt","control","meta","shift"],FH={"\b":"Backspace","\t":"Tab","\x7f":"Delete","\x1b":"Escape",Del:"Delete",Esc:"Escape",Left:"ArrowLeft",Right:"ArrowRight",Up:"ArrowUp",Down:"ArrowDown",Menu:"ContextMenu",Scroll:"ScrollLock",Win:"OS"},NH={alt:t=>t.altKey,control:t=>t.ctrlKey,meta:t=>t.metaKey,shift:t=>t.shiftKey};let PH=(()=>{class t extends KD{constructor(e){super(e)}supports(e){return null!=t.parseEventName(e)}addEventListener(e,i,r){const o=t.parseEventName(i),s=t.eventCallback(o.fullKey,r,this.manager.getZone());return this.manager.getZone().runOutsideAngular(()=>Ji().onAndCancel(e,o.domEventName,s))}static parseEventName(e){const i=e.toLowerCase().split("."),r=i.shift();if(0===i.length||"keydown"!==r&&"keyup"!==r)return null;const o=t._normalizeKey(i.pop());let s="",a=i.indexOf("code");if(a>-1&&(i.splice(a,1),s="code."),nE.forEach(l=>{const d=i.indexOf(l);d>-1&&(i.splice(d,1),s+=l+".")}),s+=o,0!=i.length||0===o.length)return null;const c={};return c.domEventName=r,c.fullKey=s,c}static matchEventFullKeyCode(e,i){let r=FH[e.key]||e.key,o="";return i.indexOf("code.")>-1&&(r=e.code,o="code."),!(null==r||!r)&&(r=r.toLowerCase()," "===r?r="space":"."===r&&(r="dot"),nE.forEach(s=>{s!==r&&(0,NH[s])(e)&&(o+=s+".")}),o+=r,o===i)}static eventCallback(e,i,r){return o=>{t.matchEventFullKeyCode(o,e)&&r.runGuarded(()=>i(o))}}static _normalizeKey(e){return"esc"===e?"escape":e}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();const jH=KC(hV,"browser",[{provide:Yi,useValue:zD},{provide:h0,useValue:function LH(){Im.makeCurrent()},multi:!0},{provide:Z,useFactory:function VH(){return function bO(t){xf=t}(document),document},deps:[]}]),HH=new E(""),oE=[{provide:Ql,useClass:class CH{addToWindow(n){Ve.getAngularTestability=(i,r=!0)=>{const o=n.findTestabilityInTree(i,r);if(null==o)throw new y(5103,!1);return o},Ve.getAllAngularTestabilities=()=>n.getAllTestabilities(),Ve.getAllAngularRootElements=()=>n.getAllRootElements(),Ve.frameworkStabilizers||(Ve.frameworkStabilizers=[]),Ve.frameworkStabilizers.push(i=>{const r=Ve.getAllAngularTestabilities();let o=r.length,s=!1;const a=function(c){s=s||c,o--,0==o&&i(s)};r.forEach(c=>{c.whenStable(a)})})}findTestabilityInTree(n,e,i){return null==e?null:n.getTestability(e)??(i?Ji().isShadowRoot(e)?this.findTestabilityInTree(n,e.host,!0):this.findTestabilityInTree(n,e.parentElement,!0):null)}},deps:[]},{provide:GC,useClass:Kp,deps:[j,Qp,Ql]},{provide:Kp,useClass:Kp,deps:[j,Qp,Ql]}],sE=[{provide:kf,useValue:"root"},{provide:vn,useFactory:function BH(){return new vn},deps:[]},{provide:Tm,useClass:OH,multi:!0,deps:[Z,j,Yi]},{provide:Tm,useClass:PH,multi:!0,deps:[Z]},Om,QD,ZD,{provide:ra,useExisting:Om},{provide:UD,useClass:DH,deps:[]},[]];let aE=(()=>{class t{constructor(e){}static withServerTransition(e){return{ngModule:t,providers:[{provide:yl,useValue:e.appId}]}}static#e=this.\u0275fac=function(i){return new(i||t)(b(HH,12))};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({providers:[...sE,...oE],imports:[oi,fV]})}return t})(),cE=(()=>{class t{constructor(e){this._doc=e}getTitle(){return this._doc.title}setTitle(e){this._doc.title=e||""}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z))};static#t=this.\u0275prov=I({token:t,factory:function(i){let r=null;return r=i?new i:function $H(){return new cE(b(Z))}(),r},providedIn:"root"})}return t})();typeof window<"u"&&window;let Bm,Lm=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:function(i){let r=null;return r=i?new(i||t):b(uE),r},providedIn:"root"})}return t})(),uE=(()=>{class t extends Lm{constructor(e){super(),this._doc=e}sanitize(e,i){if(null==i)return null;switch(e){case St.NONE:return i;case St.HTML:return Jn(i,"HTML")?rn(i):e0(this._doc,String(i)).toString();case St.STYLE:return Jn(i,"Style")?rn(i):i;case St.SCRIPT:if(Jn(i,"Script"))return rn(i);throw new y(5200,!1);case St.URL:return Jn(i,"URL")?rn(i):pl(String(i));case St.RESOURCE_URL:if(Jn(i,"ResourceURL"))return rn(i);throw new y(5201,!1);default:throw new y(5202,!1)}}bypassSecurityTrustHtml(e){return function EO(t){return new vO(t)}(e)}bypassSecurityTrustStyle(e){return function SO(t){return new yO(t)}(e)}bypassSecurityTrustScript(e){return function MO(t){return new wO(t)}(e)}bypassSecurityTrustUrl(e){return function IO(t){return new xO(t)}(e)}bypassSecurityTrustResourceUrl(e){return function TO(t){return new CO(t)}(e)}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z))};static#t=this.\u0275prov=I({token:t,factory:function(i){let r=null;return r=i?new i:function qH(t){return new uE(t.get(Z))}(b(We)),r},providedIn:"root"})}return t})();try{Bm=typeof Intl<"u"&&Intl.v8BreakIterator}catch{Bm=!1}let Qo,tt=(()=>{class t{constructor(e){this._platformId=e,this.isBrowser=this._platformId?function Yj(t){return t===zD}(this._platformId):"object"==typeof document&&!!document,this.EDGE=this.isBrowser&&/(edge)/i.test(navigator.userAgent),this.TRIDENT=this.isBrowser&&/(msie|trident)/i.test(navigator.userAgent),this.BLINK=this.isBrowser&&!(!window.chrome&&!Bm)&&typeof CSS<"u"&&!this.EDGE&&!this.TRIDENT,this.WEBKIT=this.isBrowser&&/AppleWebKit/i.test(navigator.userAgent)&&!this.BLINK&&!this.EDGE&&!this.TRIDENT,this.IOS=this.isBrowser&&/iPad|iPhone|iPod/.test(navigator.userAgent)&&!("MSStream"in window),this.FIREFOX=this.isBrowser&&/(firefox|minefield)/i.test(navigator.userAgent),this.ANDROID=this.isBrowser&&/android/i.test(navigator.userAgent)&&!this.TRIDENT,this.SAFARI=this.isBrowser&&/safari/i.test(navigator.userAgent)&&this.WEBKIT}static#e=this.\u0275fac=function(i){return new(i||t)(b(Yi))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();const fE=["color","button","checkbox","date","datetime-local","email","file","hidden","image","month","number","password","radio","range","reset","search","submit","tel","text","time","url","week"];function pE(){if(Qo)return Qo;if("object"!=typeof document||!document)return Qo=new Set(fE),Qo;let t=document.createElement("input");return Qo=new Set(fE.filter(n=>(t.setAttribute("type",n),t.type===n))),Qo}let Oa,Nr,Vm;function Xo(t){return function YH(){if(null==Oa&&typeof window<"u")try{window.addEventListener("test",null,Object.defineProperty({},"passive",{get:()=>Oa=!0}))}finally{Oa=Oa||!1}return Oa}()?t:!!t.capture}function ZH(){if(null==Nr){if("object"!=typeof document||!document||"function"!=typeof Element||!Element)return Nr=!1,Nr;if("scrollBehavior"in document.documentElement.style)Nr=!0;else{const t=Element.prototype.scrollTo;Nr=!!t&&!/\{\s*\[native code\]\s*\}/.test(t.toString())}}return Nr}function jm(){let t=typeof document<"u"&&document?document.activeElement:null;for(;t&&t.shadowRoot;){const n=t.shadowRoot.activeElement;if(n===t)break;t=n}return t}function tr(t){return t.composedPath?t.composedPath()[0]:t.target}function Hm(){return typeof __karma__<"u"&&!!__karma__||typeof jasmine<"u"&&!!jasmine||typeof jest<"u"&&!!jest||typeof Mocha<"u"&&!!Mocha}function Lr(t,...n){return n.length?n.some(e=>t[e]):t.altKey||t.shiftKey||t.ctrlKey||t.metaKey}function Ee(t,n,e){const i=ve(t)||n||e?{next:t,error:n,complete:e}:t;return i?nt((r,o)=>{var s;null===(s=i.subscribe)||void 0===s||s.call(i);let a=!0;r.subscribe(qe(o,c=>{var l;null===(l=i.next)||void 0===l||l.call(i,c),o.next(c)},()=>{var c;a=!1,null===(c=i.complete)||void 0===c||c.call(i),o.complete()},c=>{var l;a=!1,null===(l=i.error)||void 0===l||l.call(i,c),o.error(c)},()=>{var c,l;a&&(null===(c=i.unsubscribe)||void 0===c||c.call(i)),null===(l=i.finalize)||void 0===l||l.call(i)}))}):Vi}class _z extends Le{constructor(n,e){super()}schedule(n,e=0){return this}}const _d={setInterval(t,n,...e){const{delegate:i}=_d;return i?.setInterval?i.setInterval(t,n,...e):setInterval(t,n,...e)},clearInterval(t){const{delegate:n}=_d;return(n?.clearInterval||clearInterval)(t)},delegate:void 0};class Um extends _z{constructor(n,e){super(n,e),this.scheduler=n,this.work=e,this.pending=!1}schedule(n,e=0){var i;if(this.closed)return this;this.state=n;const r=this.id,o=this.scheduler;return null!=r&&(this.id=this.recycleAsyncId(o,r,e)),this.pending=!0,this.delay=e,this.id=null!==(i=this.id)&&void 0!==i?i:this.requestAsyncId(o,this.id,e),this}requestAsyncId(n,e,i=0){return _d.setInterval(n.flush.bind(n,this),i)}recycleAsyncId(n,e,i=0){if(null!=i&&this.delay===i&&!1===this.pending)return e;null!=e&&_d.clearInterval(e)}execute(n,e){if(this.closed)return new Error("executing a cancelled action");this.pending=!1;const i=this._execute(n,e);if(i)return i;!1===this.pending&&null!=this.id&&(this.id=this.recycleAsyncId(this.scheduler,this.id,null))}_execute(n,e){let r,i=!1;try{this.work(n)}catch(o){i=!0,r=o||new Error("Scheduled action threw falsy error")}if(i)return this.unsubscribe(),r}unsubscribe(){if(!this.closed){const{id:n,scheduler:e}=this,{actions:i}=e;this.work=this.state=this.scheduler=null,this.pending=!1,to(i,this),null!=n&&(this.id=this.recycleAsyncId(e,n,null)),this.delay=null,super.unsubscribe()}}}const Gm={now:()=>(Gm.delegate||Date).now(),delegate:void 0};class Fa{constructor(n,e=Fa.now){this.schedulerActionCtor=n,this.now=e}schedule(n,e=0,i){return new this.schedulerActionCtor(this,n).schedule(i,e)}}Fa.now=Gm.now;class Wm extends Fa{constructor(n,e=Fa.now){super(n,e),this.actions=[],this._active=!1}flush(n){const{actions:e}=this;if(this._active)return void e.push(n);let i;this._active=!0;do{if(i=n.execute(n.state,n.delay))break}while(n=e.shift());if(this._active=!1,i){for(;n=e.shift();)n.unsubscribe();throw i}}}const qm=new Wm(Um),bz=qm;function Ym(t,n=qm){return nt((e,i)=>{let r=null,o=null,s=null;const a=()=>{if(r){r.unsubscribe(),r=null;const l=o;o=null,i.next(l)}};function c(){const l=s+t,d=n.now();if(d<l)return r=this.schedule(void 0,l-d),void i.add(r);a()}e.subscribe(qe(i,l=>{o=l,s=n.now(),r||(r=n.schedule(c,t),i.add(r))},()=>{a(),i.complete()},void 0,()=>{o=r=null}))})}function Ze(t,n){return nt((e,i)=>{let r=0;e.subscribe(qe(i,o=>t.call(n,o,r++)&&i.next(o)))})}function vt(t){return t<=0?()=>Qt:nt((n,e)=>{let i=0;n.subscribe(qe(e,r=>{++i<=t&&(e.next(r),t<=i&&e.complete())}))})}function Zm(t){return Ze((n,e)=>t<=e)}function Re(t){return nt((n,e)=>{Ot(t).subscribe(qe(e,()=>e.complete(),ih)),!e.closed&&n.subscribe(e)})}function me(t){return null!=t&&"false"!=`${t}`}function si(t,n=0){return function vz(t){return!isNaN(parseFloat(t))&&!isNaN(Number(t))}(t)?Number(t):n}function bd(t){return Array.isArray(t)?t:[t]}function ft(t){return null==t?"":"string"==typeof t?t:`${t}px`}function Ti(t){return t instanceof $?t.nativeElement:t}let gE=(()=>{class t{create(e){return typeof MutationObserver>"u"?null:new MutationObserver(e)}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),yz=(()=>{class t{constructor(e){this._mutationObserverFactory=e,this._observedElements=new Map}ngOnDestroy(){this._observedElements.forEach((e,i)=>this._cleanupObserver(i))}observe(e){const i=Ti(e);return new Ne(r=>{const s=this._observeElement(i).subscribe(r);return()=>{s.unsubscribe(),this._unobserveElement(i)}})}_observeElement(e){if(this._observedElements.has(e))this._observedElements.get(e).count++;else{const i=new U,r=this._mutationObserverFactory.create(o=>i.next(o));r&&r.observe(e,{characterData:!0,childList:!0,subtree:!0}),this._observedElements.set(e,{observer:r,stream:i,count:1})}return this._observedElements.get(e).stream}_unobserveElement(e){this._observedElements.has(e)&&(this._observedElements.get(e).count--,this._observedElements.get(e).count||this._cleanupObserver(e))}_cleanupObserver(e){if(this._observedElements.has(e)){const{observer:i,stream:r}=this._observedElements.get(e);i&&i.disconnect(),r.complete(),this._observedElements.delete(e)}}static#e=this.\u0275fac=function(i){return new(i||t)(b(gE))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),wz=(()=>{class t{get disabled(){return this._disabled}set disabled(e){this._disabled=me(e),this._disabled?this._unsubscribe():this._subscribe()}get debounce(){return this._debounce}set debounce(e){this._debounce=si(e),this._subscribe()}constructor(e,i,r){this._contentObserver=e,this._elementRef=i,this._ngZone=r,this.event=new q,this._disabled=!1,this._currentSubscription=null}ngAfterContentInit(){!this._currentSubscription&&!this.disabled&&this._subscribe()}ngOnDestroy(){this._unsubscribe()}_subscribe(){this._unsubscribe();const e=this._contentObserver.observe(this._elementRef);this._ngZone.runOutsideAngular(()=>{this._currentSubscription=(this.debounce?e.pipe(Ym(this.debounce)):e).subscribe(this.event)})}_unsubscribe(){this._currentSubscription?.unsubscribe()}static#e=this.\u0275fac=function(i){return new(i||t)(f(yz),f($),f(j))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkObserveContent",""]],inputs:{disabled:["cdkObserveContentDisabled","disabled"],debounce:"debounce"},outputs:{event:"cdkObserveContent"},exportAs:["cdkObserveContent"]})}return t})(),Km=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({providers:[gE]})}return t})();const{isArray:xz}=Array,{getPrototypeOf:Cz,prototype:Dz,keys:Ez}=Object;function _E(t){if(1===t.length){const n=t[0];if(xz(n))return{args:n,keys:null};if(function Sz(t){return t&&"object"==typeof t&&Cz(t)===Dz}(n)){const e=Ez(n);return{args:e.map(i=>n[i]),keys:e}}}return{args:t,keys:null}}const{isArray:Mz}=Array;function Qm(t){return pe(n=>function Iz(t,n){return Mz(n)?t(...n):t(n)}(t,n))}function bE(t,n){return t.reduce((e,i,r)=>(e[i]=n[r],e),{})}function vd(...t){const n=Ms(t),e=Ob(t),{args:i,keys:r}=_E(t);if(0===i.length)return dt([],n);const o=new Ne(function Tz(t,n,e=Vi){return i=>{vE(n,()=>{const{length:r}=t,o=new Array(r);let s=r,a=r;for(let c=0;c<r;c++)vE(n,()=>{const l=dt(t[c],n);let d=!1;l.subscribe(qe(i,u=>{o[c]=u,d||(d=!0,a--),a||i.next(e(o.slice()))},()=>{--s||i.complete()}))},i)},i)}}(i,n,r?s=>bE(r,s):Vi));return e?o.pipe(Qm(e)):o}function vE(t,n,e){t?hi(e,t,n):n()}function yd(...t){return function Az(){return no(1)}()(dt(t,Ms(t)))}function Dn(...t){const n=Ms(t);return nt((e,i)=>{(n?yd(t,e,n):yd(t,e)).subscribe(i)})}const yE=new Set;let Br,kz=(()=>{class t{constructor(e,i){this._platform=e,this._nonce=i,this._matchMedia=this._platform.isBrowser&&window.matchMedia?window.matchMedia.bind(window):Oz}matchMedia(e){return(this._platform.WEBKIT||this._platform.BLINK)&&function Rz(t,n){if(!yE.has(t))try{Br||(Br=document.createElement("style"),n&&(Br.nonce=n),Br.setAttribute("type","text/css"),document.head.appendChild(Br)),Br.sheet&&(Br.sheet.insertRule(`@media ${t} {body{ }}`,0),yE.add(t))}catch(e){console.error(e)}}(e,this._nonce),this._matchMedia(e)}static#e=this.\u0275fac=function(i){return new(i||t)(b(tt),b(Nf,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();function Oz(t){return{matches:"all"===t||""===t,media:t,addListener:()=>{},removeListener:()=>{}}}let Xm=(()=>{class t{constructor(e,i){this._mediaMatcher=e,this._zone=i,this._queries=new Map,this._destroySubject=new U}ngOnDestroy(){this._destroySubject.next(),this._destroySubject.complete()}isMatched(e){return wE(bd(e)).some(r=>this._registerQuery(r).mql.matches)}observe(e){let o=vd(wE(bd(e)).map(s=>this._registerQuery(s).observable));return o=yd(o.pipe(vt(1)),o.pipe(Zm(1),Ym(0))),o.pipe(pe(s=>{const a={matches:!1,breakpoints:{}};return s.forEach(({matches:c,query:l})=>{a.matches=a.matches||c,a.breakpoints[l]=c}),a}))}_registerQuery(e){if(this._queries.has(e))return this._queries.get(e);const i=this._mediaMatcher.matchMedia(e),o={observable:new Ne(s=>{const a=c=>this._zone.run(()=>s.next(c));return i.addListener(a),()=>{i.removeListener(a)}}).pipe(Dn(i),pe(({matches:s})=>({query:e,matches:s})),Re(this._destroySubject)),mql:i};return this._queries.set(e,o),o}static#e=this.\u0275fac=function(i){return new(i||t)(b(kz),b(j))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();function wE(t){return t.map(n=>n.split(",")).reduce((n,e)=>n.concat(e)).map(n=>n.trim())}class Lz{constructor(n){this._items=n,this._activeItemIndex=-1,this._activeItem=null,this._wrap=!1,this._letterKeyStream=new U,this._typeaheadSubscription=Le.EMPTY,this._vertical=!0,this._allowedModifierKeys=[],this._homeAndEnd=!1,this._pageUpAndDown={enabled:!1,delta:10},this._skipPredicateFn=e=>e.disabled,this._pressedLetters=[],this.tabOut=new U,this.change=new U,n instanceof Rr&&(this._itemChangesSubscription=n.changes.subscribe(e=>{if(this._activeItem){const r=e.toArray().indexOf(this._activeItem);r>-1&&r!==this._activeItemIndex&&(this._activeItemIndex=r)}}))}skipPredicate(n){return this._skipPredicateFn=n,this}withWrap(n=!0){return this._wrap=n,this}withVerticalOrientation(n=!0){return this._vertical=n,this}withHorizontalOrientation(n){return this._horizontal=n,this}withAllowedModifierKeys(n){return this._allowedModifierKeys=n,this}withTypeAhead(n=200){return this._typeaheadSubscription.unsubscribe(),this._typeaheadSubscription=this._letterKeyStream.pipe(Ee(e=>this._pressedLetters.push(e)),Ym(n),Ze(()=>this._pressedLetters.length>0),pe(()=>this._pressedLetters.join(""))).subscribe(e=>{const i=this._getItemsArray();for(let r=1;r<i.length+1;r++){const o=(this._activeItemIndex+r)%i.length,s=i[o];if(!this._skipPredicateFn(s)&&0===s.getLabel().toUpperCase().trim().indexOf(e)){this.setActiveItem(o);break}}this._pressedLetters=[]}),this}cancelTypeahead(){return this._pressedLetters=[],this}withHomeAndEnd(n=!0){return this._homeAndEnd=n,this}withPageUpDown(n=!0,e=10){return this._pageUpAndDown={enabled:n,delta:e},this}setActiveItem(n){const e=this._activeItem;this.updateActiveItem(n),this._activeItem!==e&&this.change.next(this._activeItemIndex)}onKeydown(n){const e=n.keyCode,r=["altKey","ctrlKey","metaKey","shiftKey"].every(o=>!n[o]||this._allowedModifierKeys.indexOf(o)>-1);switch(e){case 9:return void this.tabOut.next();case 40:if(this._vertical&&r){this.setNextItemActive();break}return;case 38:if(this._vertical&&r){this.setPreviousItemActive();break}return;case 39:if(this._horizontal&&r){"rtl"===this._horizontal?this.setPreviousItemActive():this.setNextItemActive();break}return;case 37:if(this._horizontal&&r){"rtl"===this._horizontal?this.setNextItemActive():this.setPreviousItemActive();break}return;case 36:if(this._homeAndEnd&&r){this.setFirstItemActive();break}return;case 35:if(this._homeAndEnd&&r){this.setLastItemActive();break}return;case 33:if(this._pageUpAndDown.enabled&&r){const o=this._activeItemIndex-this._pageUpAndDown.delta;this._setActiveItemByIndex(o>0?o:0,1);break}return;case 34:if(this._pageUpAndDown.enabled&&r){const o=this._activeItemIndex+this._pageUpAndDown.delta,s=this._getItemsArray().length;this._setActiveItemByIndex(o<s?o:s-1,-1);break}return;default:return void((r||Lr(n,"shiftKey"))&&(n.key&&1===n.key.length?this._letterKeyStream.next(n.key.toLocaleUpperCase()):(e>=65&&e<=90||e>=48&&e<=57)&&this._letterKeyStream.next(String.fromCharCode(e))))}this._pressedLetters=[],n.preventDefault()}get activeItemIndex(){return this._activeItemIndex}get activeItem(){return this._activeItem}isTyping(){return this._pressedLetters.length>0}setFirstItemActive(){this._setActiveItemByIndex(0,1)}setLastItemActive(){this._setActiveItemByIndex(this._items.length-1,-1)}setNextItemActive(){this._activeItemIndex<0?this.setFirstItemActive():this._setActiveItemByDelta(1)}setPreviousItemActive(){this._activeItemIndex<0&&this._wrap?this.setLastItemActive():this._setActiveItemByDelta(-1)}updateActiveItem(n){const e=this._getItemsArray(),i="number"==typeof n?n:e.indexOf(n);this._activeItem=e[i]??null,this._activeItemIndex=i}destroy(){this._typeaheadSubscription.unsubscribe(),this._itemChangesSubscription?.unsubscribe(),this._letterKeyStream.complete(),this.tabOut.complete(),this.change.complete(),this._pressedLetters=[]}_setActiveItemByDelta(n){this._wrap?this._setActiveInWrapMode(n):this._setActiveInDefaultMode(n)}_setActiveInWrapMode(n){const e=this._getItemsArray();for(let i=1;i<=e.length;i++){const r=(this._activeItemIndex+n*i+e.length)%e.length;if(!this._skipPredicateFn(e[r]))return void this.setActiveItem(r)}}_setActiveInDefaultMode(n){this._setActiveItemByIndex(this._activeItemIndex+n,n)}_setActiveItemByIndex(n,e){const i=this._getItemsArray();if(i[n]){for(;this._skipPredicateFn(i[n]);)if(!i[n+=e])return;this.setActiveItem(n)}}_getItemsArray(){return this._items instanceof Rr?this._items.toArray():this._items}}class DE extends Lz{constructor(){super(...arguments),this._origin="program"}setFocusOrigin(n){return this._origin=n,this}setActiveItem(n){super.setActiveItem(n),this.activeItem&&this.activeItem.focus(this._origin)}}let wd=(()=>{class t{constructor(e){this._platform=e}isDisabled(e){return e.hasAttribute("disabled")}isVisible(e){return function Vz(t){return!!(t.offsetWidth||t.offsetHeight||"function"==typeof t.getClientRects&&t.getClientRects().length)}(e)&&"visible"===getComputedStyle(e).visibility}isTabbable(e){if(!this._platform.isBrowser)return!1;const i=function Bz(t){try{return t.frameElement}catch{return null}}(function qz(t){return t.ownerDocument&&t.ownerDocument.defaultView||window}(e));if(i&&(-1===SE(i)||!this.isVisible(i)))return!1;let r=e.nodeName.toLowerCase(),o=SE(e);return e.hasAttribute("contenteditable")?-1!==o:!("iframe"===r||"object"===r||this._platform.WEBKIT&&this._platform.IOS&&!function Gz(t){let n=t.nodeName.toLowerCase(),e="input"===n&&t.type;return"text"===e||"password"===e||"select"===n||"textarea"===n}(e))&&("audio"===r?!!e.hasAttribute("controls")&&-1!==o:"video"===r?-1!==o&&(null!==o||this._platform.FIREFOX||e.hasAttribute("controls")):e.tabIndex>=0)}isFocusable(e,i){return function Wz(t){return!function Hz(t){return function $z(t){return"input"==t.nodeName.toLowerCase()}(t)&&"hidden"==t.type}(t)&&(function jz(t){let n=t.nodeName.toLowerCase();return"input"===n||"select"===n||"button"===n||"textarea"===n}(t)||function zz(t){return function Uz(t){return"a"==t.nodeName.toLowerCase()}(t)&&t.hasAttribute("href")}(t)||t.hasAttribute("contenteditable")||EE(t))}(e)&&!this.isDisabled(e)&&(i?.ignoreVisibility||this.isVisible(e))}static#e=this.\u0275fac=function(i){return new(i||t)(b(tt))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();function EE(t){if(!t.hasAttribute("tabindex")||void 0===t.tabIndex)return!1;let n=t.getAttribute("tabindex");return!(!n||isNaN(parseInt(n,10)))}function SE(t){if(!EE(t))return null;const n=parseInt(t.getAttribute("tabindex")||"",10);return isNaN(n)?-1:n}class Yz{get enabled(){return this._enabled}set enabled(n){this._enabled=n,this._startAnchor&&this._endAnchor&&(this._toggleAnchorTabIndex(n,this._startAnchor),this._toggleAnchorTabIndex(n,this._endAnchor))}constructor(n,e,i,r,o=!1){this._element=n,this._checker=e,this._ngZone=i,this._document=r,this._hasAttached=!1,this.startAnchorListener=()=>this.focusLastTabbableElement(),this.endAnchorListener=()=>this.focusFirstTabbableElement(),this._enabled=!0,o||this.attachAnchors()}destroy(){const n=this._startAnchor,e=this._endAnchor;n&&(n.removeEventListener("focus",this.startAnchorListener),n.remove()),e&&(e.removeEventListener("focus",this.endAnchorListener),e.remove()),this._startAnchor=this._endAnchor=null,this._hasAttached=!1}attachAnchors(){return!!this._hasAttached||(this._ngZone.runOutsideAngular(()=>{this._startAnchor||(this._startAnchor=this._createAnchor(),this._startAnchor.addEventListener("focus",this.startAnchorListener)),this._endAnchor||(this._endAnchor=this._createAnchor(),this._endAnchor.addEventListener("focus",this.endAnchorListener))}),this._element.parentNode&&(this._element.parentNode.insertBefore(this._startAnchor,this._element),this._element.parentNode.insertBefore(this._endAnchor,this._element.nextSibling),this._hasAttached=!0),this._hasAttached)}focusInitialElementWhenReady(n){return new Promise(e=>{this._executeOnStable(()=>e(this.focusInitialElement(n)))})}focusFirstTabbableElementWhenReady(n){return new Promise(e=>{this._executeOnStable(()=>e(this.focusFirstTabbableElement(n)))})}focusLastTabbableElementWhenReady(n){return new Promise(e=>{this._executeOnStable(()=>e(this.focusLastTabbableElement(n)))})}_getRegionBoundary(n){const e=this._element.querySelectorAll(`[cdk-focus-region-${n}], [cdkFocusRegion${n}], [cdk-focus-${n}]`);return"start"==n?e.length?e[0]:this._getFirstTabbableElement(this._element):e.length?e[e.length-1]:this._getLastTabbableElement(this._element)}focusInitialElement(n){const e=this._element.querySelector("[cdk-focus-initial], [cdkFocusInitial]");if(e){if(!this._checker.isFocusable(e)){const i=this._getFirstTabbableElement(e);return i?.focus(n),!!i}return e.focus(n),!0}return this.focusFirstTabbableElement(n)}focusFirstTabbableElement(n){const e=this._getRegionBoundary("start");return e&&e.focus(n),!!e}focusLastTabbableElement(n){const e=this._getRegionBoundary("end");return e&&e.focus(n),!!e}hasAttached(){return this._hasAttached}_getFirstTabbableElement(n){if(this._checker.isFocusable(n)&&this._checker.isTabbable(n))return n;const e=n.children;for(let i=0;i<e.length;i++){const r=e[i].nodeType===this._document.ELEMENT_NODE?this._getFirstTabbableElement(e[i]):null;if(r)return r}return null}_getLastTabbableElement(n){if(this._checker.isFocusable(n)&&this._checker.isTabbable(n))return n;const e=n.children;for(let i=e.length-1;i>=0;i--){const r=e[i].nodeType===this._document.ELEMENT_NODE?this._getLastTabbableElement(e[i]):null;if(r)return r}return null}_createAnchor(){const n=this._document.createElement("div");return this._toggleAnchorTabIndex(this._enabled,n),n.classList.add("cdk-visually-hidden"),n.classList.add("cdk-focus-trap-anchor"),n.setAttribute("aria-hidden","true"),n}_toggleAnchorTabIndex(n,e){n?e.setAttribute("tabindex","0"):e.removeAttribute("tabindex")}toggleAnchors(n){this._startAnchor&&this._endAnchor&&(this._toggleAnchorTabIndex(n,this._startAnchor),this._toggleAnchorTabIndex(n,this._endAnchor))}_executeOnStable(n){this._ngZone.isStable?n():this._ngZone.onStable.pipe(vt(1)).subscribe(n)}}let Jm=(()=>{class t{constructor(e,i,r){this._checker=e,this._ngZone=i,this._document=r}create(e,i=!1){return new Yz(e,this._checker,this._ngZone,this._document,i)}static#e=this.\u0275fac=function(i){return new(i||t)(b(wd),b(j),b(Z))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();function ME(t){return 0===t.buttons||0===t.offsetX&&0===t.offsetY}function IE(t){const n=t.touches&&t.touches[0]||t.changedTouches&&t.changedTouches[0];return!(!n||-1!==n.identifier||null!=n.radiusX&&1!==n.radiusX||null!=n.radiusY&&1!==n.radiusY)}const Zz=new E("cdk-input-modality-detector-options"),Kz={ignoreKeys:[18,17,224,91,16]},Jo=Xo({passive:!0,capture:!0});let Qz=(()=>{class t{get mostRecentModality(){return this._modality.value}constructor(e,i,r,o){this._platform=e,this._mostRecentTarget=null,this._modality=new $t(null),this._lastTouchMs=0,this._onKeydown=s=>{this._options?.ignoreKeys?.some(a=>a===s.keyCode)||(this._modality.next("keyboard"),this._mostRecentTarget=tr(s))},this._onMousedown=s=>{Date.now()-this._lastTouchMs<650||(this._modality.next(ME(s)?"keyboard":"mouse"),this._mostRecentTarget=tr(s))},this._onTouchstart=s=>{IE(s)?this._modality.next("keyboard"):(this._lastTouchMs=Date.now(),this._modality.next("touch"),this._mostRecentTarget=tr(s))},this._options={...Kz,...o},this.modalityDetected=this._modality.pipe(Zm(1)),this.modalityChanged=this.modalityDetected.pipe(Mc()),e.isBrowser&&i.runOutsideAngular(()=>{r.addEventListener("keydown",this._onKeydown,Jo),r.addEventListener("mousedown",this._onMousedown,Jo),r.addEventListener("touchstart",this._onTouchstart,Jo)})}ngOnDestroy(){this._modality.complete(),this._platform.isBrowser&&(document.removeEventListener("keydown",this._onKeydown,Jo),document.removeEventListener("mousedown",this._onMousedown,Jo),document.removeEventListener("touchstart",this._onTouchstart,Jo))}static#e=this.\u0275fac=function(i){return new(i||t)(b(tt),b(j),b(Z),b(Zz,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();const Xz=new E("liveAnnouncerElement",{providedIn:"root",factory:function Jz(){return null}}),e3=new E("LIVE_ANNOUNCER_DEFAULT_OPTIONS");let t3=0,AE=(()=>{class t{constructor(e,i,r,o){this._ngZone=i,this._defaultOptions=o,this._document=r,this._liveElement=e||this._createLiveElement()}announce(e,...i){const r=this._defaultOptions;let o,s;return 1===i.length&&"number"==typeof i[0]?s=i[0]:[o,s]=i,this.clear(),clearTimeout(this._previousTimeout),o||(o=r&&r.politeness?r.politeness:"polite"),null==s&&r&&(s=r.duration),this._liveElement.setAttribute("aria-live",o),this._liveElement.id&&this._exposeAnnouncerToModals(this._liveElement.id),this._ngZone.runOutsideAngular(()=>(this._currentPromise||(this._currentPromise=new Promise(a=>this._currentResolve=a)),clearTimeout(this._previousTimeout),this._previousTimeout=setTimeout(()=>{this._liveElement.textContent=e,"number"==typeof s&&(this._previousTimeout=setTimeout(()=>this.clear(),s)),this._currentResolve(),this._currentPromise=this._currentResolve=void 0},100),this._currentPromise))}clear(){this._liveElement&&(this._liveElement.textContent="")}ngOnDestroy(){clearTimeout(this._previousTimeout),this._liveElement?.remove(),this._liveElement=null,this._currentResolve?.(),this._currentPromise=this._currentResolve=void 0}_createLiveElement(){const e="cdk-live-announcer-element",i=this._document.getElementsByClassName(e),r=this._document.createElement("div");for(let o=0;o<i.length;o++)i[o].remove();return r.classList.add(e),r.classList.add("cdk-visually-hidden"),r.setAttribute("aria-atomic","true"),r.setAttribute("aria-live","polite"),r.id="cdk-live-announcer-"+t3++,this._document.body.appendChild(r),r}_exposeAnnouncerToModals(e){const i=this._document.querySelectorAll('body > .cdk-overlay-container [aria-modal="true"]');for(let r=0;r<i.length;r++){const o=i[r],s=o.getAttribute("aria-owns");s?-1===s.indexOf(e)&&o.setAttribute("aria-owns",s+" "+e):o.setAttribute("aria-owns",e)}}static#e=this.\u0275fac=function(i){return new(i||t)(b(Xz,8),b(j),b(Z),b(e3,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();const n3=new E("cdk-focus-monitor-default-options"),xd=Xo({passive:!0,capture:!0});let nr=(()=>{class t{constructor(e,i,r,o,s){this._ngZone=e,this._platform=i,this._inputModalityDetector=r,this._origin=null,this._windowFocused=!1,this._originFromTouchInteraction=!1,this._elementInfo=new Map,this._monitoredElementCount=0,this._rootNodeFocusListenerCount=new Map,this._windowFocusListener=()=>{this._windowFocused=!0,this._windowFocusTimeoutId=window.setTimeout(()=>this._windowFocused=!1)},this._stopInputModalityDetector=new U,this._rootNodeFocusAndBlurListener=a=>{for(let l=tr(a);l;l=l.parentElement)"focus"===a.type?this._onFocus(a,l):this._onBlur(a,l)},this._document=o,this._detectionMode=s?.detectionMode||0}monitor(e,i=!1){const r=Ti(e);if(!this._platform.isBrowser||1!==r.nodeType)return z();const o=function QH(t){if(function KH(){if(null==Vm){const t=typeof document<"u"?document.head:null;Vm=!(!t||!t.createShadowRoot&&!t.attachShadow)}return Vm}()){const n=t.getRootNode?t.getRootNode():null;if(typeof ShadowRoot<"u"&&ShadowRoot&&n instanceof ShadowRoot)return n}return null}(r)||this._getDocument(),s=this._elementInfo.get(r);if(s)return i&&(s.checkChildren=!0),s.subject;const a={checkChildren:i,subject:new U,rootNode:o};return this._elementInfo.set(r,a),this._registerGlobalListeners(a),a.subject}stopMonitoring(e){const i=Ti(e),r=this._elementInfo.get(i);r&&(r.subject.complete(),this._setClasses(i),this._elementInfo.delete(i),this._removeGlobalListeners(r))}focusVia(e,i,r){const o=Ti(e);o===this._getDocument().activeElement?this._getClosestElementsInfo(o).forEach(([a,c])=>this._originChanged(a,i,c)):(this._setOrigin(i),"function"==typeof o.focus&&o.focus(r))}ngOnDestroy(){this._elementInfo.forEach((e,i)=>this.stopMonitoring(i))}_getDocument(){return this._document||document}_getWindow(){return this._getDocument().defaultView||window}_getFocusOrigin(e){return this._origin?this._originFromTouchInteraction?this._shouldBeAttributedToTouch(e)?"touch":"program":this._origin:this._windowFocused&&this._lastFocusOrigin?this._lastFocusOrigin:e&&this._isLastInteractionFromInputLabel(e)?"mouse":"program"}_shouldBeAttributedToTouch(e){return 1===this._detectionMode||!!e?.contains(this._inputModalityDetector._mostRecentTarget)}_setClasses(e,i){e.classList.toggle("cdk-focused",!!i),e.classList.toggle("cdk-touch-focused","touch"===i),e.classList.toggle("cdk-keyboard-focused","keyboard"===i),e.classList.toggle("cdk-mouse-focused","mouse"===i),e.classList.toggle("cdk-program-focused","program"===i)}_setOrigin(e,i=!1){this._ngZone.runOutsideAngular(()=>{this._origin=e,this._originFromTouchInteraction="touch"===e&&i,0===this._detectionMode&&(clearTimeout(this._originTimeoutId),this._originTimeoutId=setTimeout(()=>this._origin=null,this._originFromTouchInteraction?650:1))})}_onFocus(e,i){const r=this._elementInfo.get(i),o=tr(e);!r||!r.checkChildren&&i!==o||this._originChanged(i,this._getFocusOrigin(o),r)}_onBlur(e,i){const r=this._elementInfo.get(i);!r||r.checkChildren&&e.relatedTarget instanceof Node&&i.contains(e.relatedTarget)||(this._setClasses(i),this._emitOrigin(r,null))}_emitOrigin(e,i){e.subject.observers.length&&this._ngZone.run(()=>e.subject.next(i))}_registerGlobalListeners(e){if(!this._platform.isBrowser)return;const i=e.rootNode,r=this._rootNodeFocusListenerCount.get(i)||0;r||this._ngZone.runOutsideAngular(()=>{i.addEventListener("focus",this._rootNodeFocusAndBlurListener,xd),i.addEventListener("blur",this._rootNodeFocusAndBlurListener,xd)}),this._rootNodeFocusListenerCount.set(i,r+1),1==++this._monitoredElementCount&&(this._ngZone.runOutsideAngular(()=>{this._getWindow().addEventListener("focus",this._windowFocusListener)}),this._inputModalityDetector.modalityDetected.pipe(Re(this._stopInputModalityDetector)).subscribe(o=>{this._setOrigin(o,!0)}))}_removeGlobalListeners(e){const i=e.rootNode;if(this._rootNodeFocusListenerCount.has(i)){const r=this._rootNodeFocusListenerCount.get(i);r>1?this._rootNodeFocusListenerCount.set(i,r-1):(i.removeEventListener("focus",this._rootNodeFocusAndBlurListener,xd),i.removeEventListener("blur",this._rootNodeFocusAndBlurListener,xd),this._rootNodeFocusListenerCount.delete(i))}--this._monitoredElementCount||(this._getWindow().removeEventListener("focus",this._windowFocusListener),this._stopInputModalityDetector.next(),clearTimeout(this._windowFocusTimeoutId),clearTimeout(this._originTimeoutId))}_originChanged(e,i,r){this._setClasses(e,i),this._emitOrigin(r,i),this._lastFocusOrigin=i}_getClosestElementsInfo(e){const i=[];return this._elementInfo.forEach((r,o)=>{(o===e||r.checkChildren&&o.contains(e))&&i.push([o,r])}),i}_isLastInteractionFromInputLabel(e){const{_mostRecentTarget:i,mostRecentModality:r}=this._inputModalityDetector;if("mouse"!==r||!i||i===e||"INPUT"!==e.nodeName&&"TEXTAREA"!==e.nodeName||e.disabled)return!1;const o=e.labels;if(o)for(let s=0;s<o.length;s++)if(o[s].contains(i))return!0;return!1}static#e=this.\u0275fac=function(i){return new(i||t)(b(j),b(tt),b(Qz),b(Z,8),b(n3,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),r3=(()=>{class t{constructor(e,i){this._elementRef=e,this._focusMonitor=i,this._focusOrigin=null,this.cdkFocusChange=new q}get focusOrigin(){return this._focusOrigin}ngAfterViewInit(){const e=this._elementRef.nativeElement;this._monitorSubscription=this._focusMonitor.monitor(e,1===e.nodeType&&e.hasAttribute("cdkMonitorSubtreeFocus")).subscribe(i=>{this._focusOrigin=i,this.cdkFocusChange.emit(i)})}ngOnDestroy(){this._focusMonitor.stopMonitoring(this._elementRef),this._monitorSubscription&&this._monitorSubscription.unsubscribe()}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(nr))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkMonitorElementFocus",""],["","cdkMonitorSubtreeFocus",""]],outputs:{cdkFocusChange:"cdkFocusChange"},exportAs:["cdkMonitorFocus"]})}return t})();const kE="cdk-high-contrast-black-on-white",RE="cdk-high-contrast-white-on-black",eg="cdk-high-contrast-active";let OE=(()=>{class t{constructor(e,i){this._platform=e,this._document=i,this._breakpointSubscription=T(Xm).observe("(forced-colors: active)").subscribe(()=>{this._hasCheckedHighContrastMode&&(this._hasCheckedHighContrastMode=!1,this._applyBodyHighContrastModeCssClasses())})}getHighContrastMode(){if(!this._platform.isBrowser)return 0;const e=this._document.createElement("div");e.style.backgroundColor="rgb(1,2,3)",e.style.position="absolute",this._document.body.appendChild(e);const i=this._document.defaultView||window,r=i&&i.getComputedStyle?i.getComputedStyle(e):null,o=(r&&r.backgroundColor||"").replace(/ /g,"");switch(e.remove(),o){case"rgb(0,0,0)":case"rgb(45,50,54)":case"rgb(32,32,32)":return 2;case"rgb(255,255,255)":case"rgb(255,250,239)":return 1}return 0}ngOnDestroy(){this._breakpointSubscription.unsubscribe()}_applyBodyHighContrastModeCssClasses(){if(!this._hasCheckedHighContrastMode&&this._platform.isBrowser&&this._document.body){const e=this._document.body.classList;e.remove(eg,kE,RE),this._hasCheckedHighContrastMode=!0;const i=this.getHighContrastMode();1===i?e.add(eg,kE):2===i&&e.add(eg,RE)}}static#e=this.\u0275fac=function(i){return new(i||t)(b(tt),b(Z))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),FE=(()=>{class t{constructor(e){e._applyBodyHighContrastModeCssClasses()}static#e=this.\u0275fac=function(i){return new(i||t)(b(OE))};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Km]})}return t})();const o3=new E("cdk-dir-doc",{providedIn:"root",factory:function s3(){return T(Z)}}),a3=/^(ar|ckb|dv|he|iw|fa|nqo|ps|sd|ug|ur|yi|.*[-_](Adlm|Arab|Hebr|Nkoo|Rohg|Thaa))(?!.*[-_](Latn|Cyrl)($|-|_))($|-|_)/i;let En=(()=>{class t{constructor(e){this.value="ltr",this.change=new q,e&&(this.value=function c3(t){const n=t?.toLowerCase()||"";return"auto"===n&&typeof navigator<"u"&&navigator?.language?a3.test(navigator.language)?"rtl":"ltr":"rtl"===n?"rtl":"ltr"}((e.body?e.body.dir:null)||(e.documentElement?e.documentElement.dir:null)||"ltr"))}ngOnDestroy(){this.change.complete()}static#e=this.\u0275fac=function(i){return new(i||t)(b(o3,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),Na=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({})}return t})();const d3=new E("mat-sanity-checks",{providedIn:"root",factory:function l3(){return!0}});let Ae=(()=>{class t{constructor(e,i,r){this._sanityChecks=i,this._document=r,this._hasDoneGlobalChecks=!1,e._applyBodyHighContrastModeCssClasses(),this._hasDoneGlobalChecks||(this._hasDoneGlobalChecks=!0)}_checkIsEnabled(e){return!Hm()&&("boolean"==typeof this._sanityChecks?this._sanityChecks:!!this._sanityChecks[e])}static#e=this.\u0275fac=function(i){return new(i||t)(b(OE),b(d3,8),b(Z))};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Na,Na]})}return t})();function Cd(t){return class extends t{get disabled(){return this._disabled}set disabled(n){this._disabled=me(n)}constructor(...n){super(...n),this._disabled=!1}}}function es(t,n){return class extends t{get color(){return this._color}set color(e){const i=e||this.defaultColor;i!==this._color&&(this._color&&this._elementRef.nativeElement.classList.remove(`mat-${this._color}`),i&&this._elementRef.nativeElement.classList.add(`mat-${i}`),this._color=i)}constructor(...e){super(...e),this.defaultColor=n,this.color=n}}}function tg(t){return class extends t{get disableRipple(){return this._disableRipple}set disableRipple(n){this._disableRipple=me(n)}constructor(...n){super(...n),this._disableRipple=!1}}}function Dd(t,n=0){return class extends t{get tabIndex(){return this.disabled?-1:this._tabIndex}set tabIndex(e){this._tabIndex=null!=e?si(e):this.defaultTabIndex}constructor(...e){super(...e),this._tabIndex=n,this.defaultTabIndex=n}}}function LE(t){return class extends t{updateErrorState(){const n=this.errorState,o=(this.errorStateMatcher||this._defaultErrorStateMatcher).isErrorState(this.ngControl?this.ngControl.control:null,this._parentFormGroup||this._parentForm);o!==n&&(this.errorState=o,this.stateChanges.next())}constructor(...n){super(...n),this.errorState=!1}}}let ng=(()=>{class t{isErrorState(e,i){return!!(e&&e.invalid&&(e.touched||i&&i.submitted))}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();class h3{constructor(n,e,i,r=!1){this._renderer=n,this.element=e,this.config=i,this._animationForciblyDisabledThroughCss=r,this.state=3}fadeOut(){this._renderer.fadeOutRipple(this)}}const BE=Xo({passive:!0,capture:!0});class f3{constructor(){this._events=new Map,this._delegateEventHandler=n=>{const e=tr(n);e&&this._events.get(n.type)?.forEach((i,r)=>{(r===e||r.contains(e))&&i.forEach(o=>o.handleEvent(n))})}}addHandler(n,e,i,r){const o=this._events.get(e);if(o){const s=o.get(i);s?s.add(r):o.set(i,new Set([r]))}else this._events.set(e,new Map([[i,new Set([r])]])),n.runOutsideAngular(()=>{document.addEventListener(e,this._delegateEventHandler,BE)})}removeHandler(n,e,i){const r=this._events.get(n);if(!r)return;const o=r.get(e);o&&(o.delete(i),0===o.size&&r.delete(e),0===r.size&&(this._events.delete(n),document.removeEventListener(n,this._delegateEventHandler,BE)))}}const VE={enterDuration:225,exitDuration:150},jE=Xo({passive:!0,capture:!0}),HE=["mousedown","touchstart"],zE=["mouseup","mouseleave","touchend","touchcancel"];class Ed{static#e=this._eventManager=new f3;constructor(n,e,i,r){this._target=n,this._ngZone=e,this._platform=r,this._isPointerDown=!1,this._activeRipples=new Map,this._pointerUpEventsRegistered=!1,r.isBrowser&&(this._containerElement=Ti(i))}fadeInRipple(n,e,i={}){const r=this._containerRect=this._containerRect||this._containerElement.getBoundingClientRect(),o={...VE,...i.animation};i.centered&&(n=r.left+r.width/2,e=r.top+r.height/2);const s=i.radius||function m3(t,n,e){const i=Math.max(Math.abs(t-e.left),Math.abs(t-e.right)),r=Math.max(Math.abs(n-e.top),Math.abs(n-e.bottom));return Math.sqrt(i*i+r*r)}(n,e,r),a=n-r.left,c=e-r.top,l=o.enterDuration,d=document.createElement("div");d.classList.add("mat-ripple-element"),d.style.left=a-s+"px",d.style.top=c-s+"px",d.style.height=2*s+"px",d.style.width=2*s+"px",null!=i.color&&(d.style.backgroundColor=i.color),d.style.transitionDuration=`${l}ms`,this._containerElement.appendChild(d);const u=window.getComputedStyle(d),p=u.transitionDuration,m="none"===u.transitionProperty||"0s"===p||"0s, 0s"===p||0===r.width&&0===r.height,g=new h3(this,d,i,m);d.style.transform="scale3d(1, 1, 1)",g.state=0,i.persistent||(this._mostRecentTransientRipple=g);let _=null;return!m&&(l||o.exitDuration)&&this._ngZone.runOutsideAngular(()=>{const C=()=>this._finishRippleTransition(g),v=()=>this._destroyRipple(g);d.addEventListener("transitionend",C),d.addEventListener("transitioncancel",v),_={onTransitionEnd:C,onTransitionCancel:v}}),this._activeRipples.set(g,_),(m||!l)&&this._finishRippleTransition(g),g}fadeOutRipple(n){if(2===n.state||3===n.state)return;const e=n.element,i={...VE,...n.config.animation};e.style.transitionDuration=`${i.exitDuration}ms`,e.style.opacity="0",n.state=2,(n._animationForciblyDisabledThroughCss||!i.exitDuration)&&this._finishRippleTransition(n)}fadeOutAll(){this._getActiveRipples().forEach(n=>n.fadeOut())}fadeOutAllNonPersistent(){this._getActiveRipples().forEach(n=>{n.config.persistent||n.fadeOut()})}setupTriggerEvents(n){const e=Ti(n);!this._platform.isBrowser||!e||e===this._triggerElement||(this._removeTriggerEvents(),this._triggerElement=e,HE.forEach(i=>{Ed._eventManager.addHandler(this._ngZone,i,e,this)}))}handleEvent(n){"mousedown"===n.type?this._onMousedown(n):"touchstart"===n.type?this._onTouchStart(n):this._onPointerUp(),this._pointerUpEventsRegistered||(this._ngZone.runOutsideAngular(()=>{zE.forEach(e=>{this._triggerElement.addEventListener(e,this,jE)})}),this._pointerUpEventsRegistered=!0)}_finishRippleTransition(n){0===n.state?this._startFadeOutTransition(n):2===n.state&&this._destroyRipple(n)}_startFadeOutTransition(n){const e=n===this._mostRecentTransientRipple,{persistent:i}=n.config;n.state=1,!i&&(!e||!this._isPointerDown)&&n.fadeOut()}_destroyRipple(n){const e=this._activeRipples.get(n)??null;this._activeRipples.delete(n),this._activeRipples.size||(this._containerRect=null),n===this._mostRecentTransientRipple&&(this._mostRecentTransientRipple=null),n.state=3,null!==e&&(n.element.removeEventListener("transitionend",e.onTransitionEnd),n.element.removeEventListener("transitioncancel",e.onTransitionCancel)),n.element.remove()}_onMousedown(n){const e=ME(n),i=this._lastTouchStartEvent&&Date.now()<this._lastTouchStartEvent+800;!this._target.rippleDisabled&&!e&&!i&&(this._isPointerDown=!0,this.fadeInRipple(n.clientX,n.clientY,this._target.rippleConfig))}_onTouchStart(n){if(!this._target.rippleDisabled&&!IE(n)){this._lastTouchStartEvent=Date.now(),this._isPointerDown=!0;const e=n.changedTouches;if(e)for(let i=0;i<e.length;i++)this.fadeInRipple(e[i].clientX,e[i].clientY,this._target.rippleConfig)}}_onPointerUp(){this._isPointerDown&&(this._isPointerDown=!1,this._getActiveRipples().forEach(n=>{!n.config.persistent&&(1===n.state||n.config.terminateOnPointerUp&&0===n.state)&&n.fadeOut()}))}_getActiveRipples(){return Array.from(this._activeRipples.keys())}_removeTriggerEvents(){const n=this._triggerElement;n&&(HE.forEach(e=>Ed._eventManager.removeHandler(e,n,this)),this._pointerUpEventsRegistered&&zE.forEach(e=>n.removeEventListener(e,this,jE)))}}const Sd=new E("mat-ripple-global-options");let ig=(()=>{class t{get disabled(){return this._disabled}set disabled(e){e&&this.fadeOutAllNonPersistent(),this._disabled=e,this._setupTriggerEventsIfEnabled()}get trigger(){return this._trigger||this._elementRef.nativeElement}set trigger(e){this._trigger=e,this._setupTriggerEventsIfEnabled()}constructor(e,i,r,o,s){this._elementRef=e,this._animationMode=s,this.radius=0,this._disabled=!1,this._isInitialized=!1,this._globalOptions=o||{},this._rippleRenderer=new Ed(this,i,e,r)}ngOnInit(){this._isInitialized=!0,this._setupTriggerEventsIfEnabled()}ngOnDestroy(){this._rippleRenderer._removeTriggerEvents()}fadeOutAll(){this._rippleRenderer.fadeOutAll()}fadeOutAllNonPersistent(){this._rippleRenderer.fadeOutAllNonPersistent()}get rippleConfig(){return{centered:this.centered,radius:this.radius,color:this.color,animation:{...this._globalOptions.animation,..."NoopAnimations"===this._animationMode?{enterDuration:0,exitDuration:0}:{},...this.animation},terminateOnPointerUp:this._globalOptions.terminateOnPointerUp}}get rippleDisabled(){return this.disabled||!!this._globalOptions.disabled}_setupTriggerEventsIfEnabled(){!this.disabled&&this._isInitialized&&this._rippleRenderer.setupTriggerEvents(this.trigger)}launch(e,i=0,r){return"number"==typeof e?this._rippleRenderer.fadeInRipple(e,i,{...this.rippleConfig,...r}):this._rippleRenderer.fadeInRipple(0,0,{...this.rippleConfig,...e})}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(j),f(tt),f(Sd,8),f(gt,8))};static#t=this.\u0275dir=x({type:t,selectors:[["","mat-ripple",""],["","matRipple",""]],hostAttrs:[1,"mat-ripple"],hostVars:2,hostBindings:function(i,r){2&i&&he("mat-ripple-unbounded",r.unbounded)},inputs:{color:["matRippleColor","color"],unbounded:["matRippleUnbounded","unbounded"],centered:["matRippleCentered","centered"],radius:["matRippleRadius","radius"],animation:["matRippleAnimation","animation"],disabled:["matRippleDisabled","disabled"],trigger:["matRippleTrigger","trigger"]},exportAs:["matRipple"]})}return t})(),Md=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Ae,Ae]})}return t})(),g3=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Ae]})}return t})(),$E=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Md,oi,Ae,g3]})}return t})();const UE={capture:!0},GE=["focus","click","mouseenter","touchstart"],rg="mat-ripple-loader-uninitialized",og="mat-ripple-loader-class-name",WE="mat-ripple-loader-centered",Id="mat-ripple-loader-disabled";let qE=(()=>{class t{constructor(){this._document=T(Z,{optional:!0}),this._animationMode=T(gt,{optional:!0}),this._globalRippleOptions=T(Sd,{optional:!0}),this._platform=T(tt),this._ngZone=T(j),this._onInteraction=e=>{if(!(e.target instanceof HTMLElement))return;const r=e.target.closest(`[${rg}]`);r&&this.createRipple(r)},this._ngZone.runOutsideAngular(()=>{for(const e of GE)this._document?.addEventListener(e,this._onInteraction,UE)})}ngOnDestroy(){for(const e of GE)this._document?.removeEventListener(e,this._onInteraction,UE)}configureRipple(e,i){e.setAttribute(rg,""),(i.className||!e.hasAttribute(og))&&e.setAttribute(og,i.className||""),i.centered&&e.setAttribute(WE,""),i.disabled&&e.setAttribute(Id,"")}getRipple(e){return e.matRipple?e.matRipple:this.createRipple(e)}setDisabled(e,i){const r=e.matRipple;r?r.disabled=i:i?e.setAttribute(Id,""):e.removeAttribute(Id)}createRipple(e){if(!this._document)return;e.querySelector(".mat-ripple")?.remove();const i=this._document.createElement("span");i.classList.add("mat-ripple",e.getAttribute(og)),e.append(i);const r=new ig(new $(i),this._ngZone,this._platform,this._globalRippleOptions?this._globalRippleOptions:void 0,this._animationMode?this._animationMode:void 0);return r._isInitialized=!0,r.trigger=e,r.centered=e.hasAttribute(WE),r.disabled=e.hasAttribute(Id),this.attachRipple(e,r),r}attachRipple(e,i){e.removeAttribute(rg),e.matRipple=i}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();const _3=["*",[["mat-toolbar-row"]]],b3=["*","mat-toolbar-row"],v3=es(class{constructor(t){this._elementRef=t}});let y3=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["mat-toolbar-row"]],hostAttrs:[1,"mat-toolbar-row"],exportAs:["matToolbarRow"]})}return t})(),w3=(()=>{class t extends v3{constructor(e,i,r){super(e),this._platform=i,this._document=r}ngAfterViewInit(){this._platform.isBrowser&&(this._checkToolbarMixedModes(),this._toolbarRows.changes.subscribe(()=>this._checkToolbarMixedModes()))}_checkToolbarMixedModes(){}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(tt),f(Z))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-toolbar"]],contentQueries:function(i,r,o){if(1&i&&xe(o,y3,5),2&i){let s;B(s=V())&&(r._toolbarRows=s)}},hostAttrs:[1,"mat-toolbar"],hostVars:4,hostBindings:function(i,r){2&i&&he("mat-toolbar-multiple-rows",r._toolbarRows.length>0)("mat-toolbar-single-row",0===r._toolbarRows.length)},inputs:{color:"color"},exportAs:["matToolbar"],features:[k],ngContentSelectors:b3,decls:2,vars:0,template:function(i,r){1&i&&(at(_3),Q(0),Q(1,1))},styles:[".mat-toolbar{background:var(--mat-toolbar-container-background-color);color:var(--mat-toolbar-container-text-color)}.mat-toolbar,.mat-toolbar h1,.mat-toolbar h2,.mat-toolbar h3,.mat-toolbar h4,.mat-toolbar h5,.mat-toolbar h6{font-family:var(--mat-toolbar-title-text-font);font-size:var(--mat-toolbar-title-text-size);line-height:var(--mat-toolbar-title-text-line-height);font-weight:var(--mat-toolbar-title-text-weight);letter-spacing:var(--mat-toolbar-title-text-tracking);margin:0}.cdk-high-contrast-active .mat-toolbar{outline:solid 1px}.mat-toolbar .mat-form-field-underline,.mat-toolbar .mat-form-field-ripple,.mat-toolbar .mat-focused .mat-form-field-ripple{background-color:currentColor}.mat-toolbar .mat-form-field-label,.mat-toolbar .mat-focused .mat-form-field-label,.mat-toolbar .mat-select-value,.mat-toolbar .mat-select-arrow,.mat-toolbar .mat-form-field.mat-focused .mat-select-arrow{color:inherit}.mat-toolbar .mat-input-element{caret-color:currentColor}.mat-toolbar .mat-mdc-button-base.mat-unthemed{--mdc-text-button-label-text-color: inherit;--mdc-outlined-button-label-text-color: inherit}.mat-toolbar-row,.mat-toolbar-single-row{display:flex;box-sizing:border-box;padding:0 16px;width:100%;flex-direction:row;align-items:center;white-space:nowrap;height:var(--mat-toolbar-standard-height)}@media(max-width: 599px){.mat-toolbar-row,.mat-toolbar-single-row{height:var(--mat-toolbar-mobile-height)}}.mat-toolbar-multiple-rows{display:flex;box-sizing:border-box;flex-direction:column;width:100%;min-height:var(--mat-toolbar-standard-height)}@media(max-width: 599px){.mat-toolbar-multiple-rows{min-height:var(--mat-toolbar-mobile-height)}}"],encapsulation:2,changeDetection:0})}return t})(),x3=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Ae,Ae]})}return t})();class sg{attach(n){return this._attachedHost=n,n.attach(this)}detach(){let n=this._attachedHost;null!=n&&(this._attachedHost=null,n.detach())}get isAttached(){return null!=this._attachedHost}setAttachedHost(n){this._attachedHost=n}}class La extends sg{constructor(n,e,i,r,o){super(),this.component=n,this.viewContainerRef=e,this.injector=i,this.componentFactoryResolver=r,this.projectableNodes=o}}class ts extends sg{constructor(n,e,i,r){super(),this.templateRef=n,this.viewContainerRef=e,this.context=i,this.injector=r}get origin(){return this.templateRef.elementRef}attach(n,e=this.context){return this.context=e,super.attach(n)}detach(){return this.context=void 0,super.detach()}}class C3 extends sg{constructor(n){super(),this.element=n instanceof $?n.nativeElement:n}}class Td{constructor(){this._isDisposed=!1,this.attachDomPortal=null}hasAttached(){return!!this._attachedPortal}attach(n){return n instanceof La?(this._attachedPortal=n,this.attachComponentPortal(n)):n instanceof ts?(this._attachedPortal=n,this.attachTemplatePortal(n)):this.attachDomPortal&&n instanceof C3?(this._attachedPortal=n,this.attachDomPortal(n)):void 0}detach(){this._attachedPortal&&(this._attachedPortal.setAttachedHost(null),this._attachedPortal=null),this._invokeDisposeFn()}dispose(){this.hasAttached()&&this.detach(),this._invokeDisposeFn(),this._isDisposed=!0}setDisposeFn(n){this._disposeFn=n}_invokeDisposeFn(){this._disposeFn&&(this._disposeFn(),this._disposeFn=null)}}class D3 extends Td{constructor(n,e,i,r,o){super(),this.outletElement=n,this._componentFactoryResolver=e,this._appRef=i,this._defaultInjector=r,this.attachDomPortal=s=>{const a=s.element,c=this._document.createComment("dom-portal");a.parentNode.insertBefore(c,a),this.outletElement.appendChild(a),this._attachedPortal=s,super.setDisposeFn(()=>{c.parentNode&&c.parentNode.replaceChild(a,c)})},this._document=o}attachComponentPortal(n){const i=(n.componentFactoryResolver||this._componentFactoryResolver).resolveComponentFactory(n.component);let r;return n.viewContainerRef?(r=n.viewContainerRef.createComponent(i,n.viewContainerRef.length,n.injector||n.viewContainerRef.injector,n.projectableNodes||void 0),this.setDisposeFn(()=>r.destroy())):(r=i.create(n.injector||this._defaultInjector||We.NULL),this._appRef.attachView(r.hostView),this.setDisposeFn(()=>{this._appRef.viewCount>0&&this._appRef.detachView(r.hostView),r.destroy()})),this.outletElement.appendChild(this._getComponentRootNode(r)),this._attachedPortal=n,r}attachTemplatePortal(n){let e=n.viewContainerRef,i=e.createEmbeddedView(n.templateRef,n.context,{injector:n.injector});return i.rootNodes.forEach(r=>this.outletElement.appendChild(r)),i.detectChanges(),this.setDisposeFn(()=>{let r=e.indexOf(i);-1!==r&&e.remove(r)}),this._attachedPortal=n,i}dispose(){super.dispose(),this.outletElement.remove()}_getComponentRootNode(n){return n.hostView.rootNodes[0]}}let E3=(()=>{class t extends ts{constructor(e,i){super(e,i)}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue),f(Je))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkPortal",""]],exportAs:["cdkPortal"],features:[k]})}return t})(),Ai=(()=>{class t extends Td{constructor(e,i,r){super(),this._componentFactoryResolver=e,this._viewContainerRef=i,this._isInitialized=!1,this.attached=new q,this.attachDomPortal=o=>{const s=o.element,a=this._document.createComment("dom-portal");o.setAttachedHost(this),s.parentNode.insertBefore(a,s),this._getRootNode().appendChild(s),this._attachedPortal=o,super.setDisposeFn(()=>{a.parentNode&&a.parentNode.replaceChild(s,a)})},this._document=r}get portal(){return this._attachedPortal}set portal(e){this.hasAttached()&&!e&&!this._isInitialized||(this.hasAttached()&&super.detach(),e&&super.attach(e),this._attachedPortal=e||null)}get attachedRef(){return this._attachedRef}ngOnInit(){this._isInitialized=!0}ngOnDestroy(){super.dispose(),this._attachedRef=this._attachedPortal=null}attachComponentPortal(e){e.setAttachedHost(this);const i=null!=e.viewContainerRef?e.viewContainerRef:this._viewContainerRef,o=(e.componentFactoryResolver||this._componentFactoryResolver).resolveComponentFactory(e.component),s=i.createComponent(o,i.length,e.injector||i.injector,e.projectableNodes||void 0);return i!==this._viewContainerRef&&this._getRootNode().appendChild(s.hostView.rootNodes[0]),super.setDisposeFn(()=>s.destroy()),this._attachedPortal=e,this._attachedRef=s,this.attached.emit(s),s}attachTemplatePortal(e){e.setAttachedHost(this);const i=this._viewContainerRef.createEmbeddedView(e.templateRef,e.context,{injector:e.injector});return super.setDisposeFn(()=>this._viewContainerRef.clear()),this._attachedPortal=e,this._attachedRef=i,this.attached.emit(i),i}_getRootNode(){const e=this._viewContainerRef.element.nativeElement;return e.nodeType===e.ELEMENT_NODE?e:e.parentNode}static#e=this.\u0275fac=function(i){return new(i||t)(f(Sr),f(Je),f(Z))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkPortalOutlet",""]],inputs:{portal:["cdkPortalOutlet","portal"]},outputs:{attached:"attached"},exportAs:["cdkPortalOutlet"],features:[k]})}return t})(),Vr=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({})}return t})();const S3=["addListener","removeListener"],M3=["addEventListener","removeEventListener"],I3=["on","off"];function ns(t,n,e,i){if(ve(e)&&(i=e,e=void 0),i)return ns(t,n,e).pipe(Qm(i));const[r,o]=function k3(t){return ve(t.addEventListener)&&ve(t.removeEventListener)}(t)?M3.map(s=>a=>t[s](n,a,e)):function T3(t){return ve(t.addListener)&&ve(t.removeListener)}(t)?S3.map(YE(t,n)):function A3(t){return ve(t.on)&&ve(t.off)}(t)?I3.map(YE(t,n)):[];if(!r&&fh(t))return yt(s=>ns(s,n,e))(Ot(t));if(!r)throw new TypeError("Invalid event target");return new Ne(s=>{const a=(...c)=>s.next(1<c.length?c:c[0]);return r(a),()=>o(a)})}function YE(t,n){return e=>i=>t[e](n,i)}function ZE(t=0,n,e=bz){let i=-1;return null!=n&&(Rb(n)?e=n:i=n),new Ne(r=>{let o=function R3(t){return t instanceof Date&&!isNaN(t)}(t)?+t-e.now():t;o<0&&(o=0);let s=0;return e.schedule(function(){r.closed||(r.next(s++),0<=i?this.schedule(void 0,i):r.complete())},o)})}class KE{}class O3{}const ki="*";function ir(t,n){return{type:7,name:t,definitions:n,options:{}}}function Sn(t,n=null){return{type:4,styles:n,timings:t}}function XE(t,n=null){return{type:2,steps:t,options:n}}function Ke(t){return{type:6,styles:t,offset:null}}function ln(t,n,e){return{type:0,name:t,styles:n,options:e}}function dn(t,n,e=null){return{type:1,expr:t,animation:n,options:e}}class Ba{constructor(n=0,e=0){this._onDoneFns=[],this._onStartFns=[],this._onDestroyFns=[],this._originalOnDoneFns=[],this._originalOnStartFns=[],this._started=!1,this._destroyed=!1,this._finished=!1,this._position=0,this.parentPlayer=null,this.totalTime=n+e}_onFinish(){this._finished||(this._finished=!0,this._onDoneFns.forEach(n=>n()),this._onDoneFns=[])}onStart(n){this._originalOnStartFns.push(n),this._onStartFns.push(n)}onDone(n){this._originalOnDoneFns.push(n),this._onDoneFns.push(n)}onDestroy(n){this._onDestroyFns.push(n)}hasStarted(){return this._started}init(){}play(){this.hasStarted()||(this._onStart(),this.triggerMicrotask()),this._started=!0}triggerMicrotask(){queueMicrotask(()=>this._onFinish())}_onStart(){this._onStartFns.forEach(n=>n()),this._onStartFns=[]}pause(){}restart(){}finish(){this._onFinish()}destroy(){this._destroyed||(this._destroyed=!0,this.hasStarted()||this._onStart(),this.finish(),this._onDestroyFns.forEach(n=>n()),this._onDestroyFns=[])}reset(){this._started=!1,this._finished=!1,this._onStartFns=this._originalOnStartFns,this._onDoneFns=this._originalOnDoneFns}setPosition(n){this._position=this.totalTime?n*this.totalTime:1}getPosition(){return this.totalTime?this._position/this.totalTime:1}triggerCallback(n){const e="start"==n?this._onStartFns:this._onDoneFns;e.forEach(i=>i()),e.length=0}}class JE{constructor(n){this._onDoneFns=[],this._onStartFns=[],this._finished=!1,this._started=!1,this._destroyed=!1,this._onDestroyFns=[],this.parentPlayer=null,this.totalTime=0,this.players=n;let e=0,i=0,r=0;const o=this.players.length;0==o?queueMicrotask(()=>this._onFinish()):this.players.forEach(s=>{s.onDone(()=>{++e==o&&this._onFinish()}),s.onDestroy(()=>{++i==o&&this._onDestroy()}),s.onStart(()=>{++r==o&&this._onStart()})}),this.totalTime=this.players.reduce((s,a)=>Math.max(s,a.totalTime),0)}_onFinish(){this._finished||(this._finished=!0,this._onDoneFns.forEach(n=>n()),this._onDoneFns=[])}init(){this.players.forEach(n=>n.init())}onStart(n){this._onStartFns.push(n)}_onStart(){this.hasStarted()||(this._started=!0,this._onStartFns.forEach(n=>n()),this._onStartFns=[])}onDone(n){this._onDoneFns.push(n)}onDestroy(n){this._onDestroyFns.push(n)}hasStarted(){return this._started}play(){this.parentPlayer||this.init(),this._onStart(),this.players.forEach(n=>n.play())}pause(){this.players.forEach(n=>n.pause())}restart(){this.players.forEach(n=>n.restart())}finish(){this._onFinish(),this.players.forEach(n=>n.finish())}destroy(){this._onDestroy()}_onDestroy(){this._destroyed||(this._destroyed=!0,this._onFinish(),this.players.forEach(n=>n.destroy()),this._onDestroyFns.forEach(n=>n()),this._onDestroyFns=[])}reset(){this.players.forEach(n=>n.reset()),this._destroyed=!1,this._finished=!1,this._started=!1}setPosition(n){const e=n*this.totalTime;this.players.forEach(i=>{const r=i.totalTime?Math.min(1,e/i.totalTime):1;i.setPosition(r)})}getPosition(){const n=this.players.reduce((e,i)=>null===e||i.totalTime>e.totalTime?i:e,null);return null!=n?n.getPosition():0}beforeDestroy(){this.players.forEach(n=>{n.beforeDestroy&&n.beforeDestroy()})}triggerCallback(n){const e="start"==n?this._onStartFns:this._onDoneFns;e.forEach(i=>i()),e.length=0}}const Va={schedule(t){let n=requestAnimationFrame,e=cancelAnimationFrame;const{delegate:i}=Va;i&&(n=i.requestAnimationFrame,e=i.cancelAnimationFrame);const r=n(o=>{e=void 0,t(o)});return new Le(()=>e?.(r))},requestAnimationFrame(...t){const{delegate:n}=Va;return(n?.requestAnimationFrame||requestAnimationFrame)(...t)},cancelAnimationFrame(...t){const{delegate:n}=Va;return(n?.cancelAnimationFrame||cancelAnimationFrame)(...t)},delegate:void 0};new class N3 extends Wm{flush(n){this._active=!0;const e=this._scheduled;this._scheduled=void 0;const{actions:i}=this;let r;n=n||i.shift();do{if(r=n.execute(n.state,n.delay))break}while((n=i[0])&&n.id===e&&i.shift());if(this._active=!1,r){for(;(n=i[0])&&n.id===e&&i.shift();)n.unsubscribe();throw r}}}(class F3 extends Um{constructor(n,e){super(n,e),this.scheduler=n,this.work=e}requestAsyncId(n,e,i=0){return null!==i&&i>0?super.requestAsyncId(n,e,i):(n.actions.push(this),n._scheduled||(n._scheduled=Va.requestAnimationFrame(()=>n.flush(void 0))))}recycleAsyncId(n,e,i=0){var r;if(null!=i?i>0:this.delay>0)return super.recycleAsyncId(n,e,i);const{actions:o}=n;null!=e&&(null===(r=o[o.length-1])||void 0===r?void 0:r.id)!==e&&(Va.cancelAnimationFrame(e),n._scheduled=void 0)}});let dg,L3=1;const Ad={};function eS(t){return t in Ad&&(delete Ad[t],!0)}const B3={setImmediate(t){const n=L3++;return Ad[n]=!0,dg||(dg=Promise.resolve()),dg.then(()=>eS(n)&&t()),n},clearImmediate(t){eS(t)}},{setImmediate:V3,clearImmediate:j3}=B3,kd={setImmediate(...t){const{delegate:n}=kd;return(n?.setImmediate||V3)(...t)},clearImmediate(t){const{delegate:n}=kd;return(n?.clearImmediate||j3)(t)},delegate:void 0};new class z3 extends Wm{flush(n){this._active=!0;const e=this._scheduled;this._scheduled=void 0;const{actions:i}=this;let r;n=n||i.shift();do{if(r=n.execute(n.state,n.delay))break}while((n=i[0])&&n.id===e&&i.shift());if(this._active=!1,r){for(;(n=i[0])&&n.id===e&&i.shift();)n.unsubscribe();throw r}}}(class H3 extends Um{constructor(n,e){super(n,e),this.scheduler=n,this.work=e}requestAsyncId(n,e,i=0){return null!==i&&i>0?super.requestAsyncId(n,e,i):(n.actions.push(this),n._scheduled||(n._scheduled=kd.setImmediate(n.flush.bind(n,void 0))))}recycleAsyncId(n,e,i=0){var r;if(null!=i?i>0:this.delay>0)return super.recycleAsyncId(n,e,i);const{actions:o}=n;null!=e&&(null===(r=o[o.length-1])||void 0===r?void 0:r.id)!==e&&(kd.clearImmediate(e),n._scheduled===e&&(n._scheduled=void 0))}});function tS(t,n=qm){return function U3(t){return nt((n,e)=>{let i=!1,r=null,o=null,s=!1;const a=()=>{if(o?.unsubscribe(),o=null,i){i=!1;const l=r;r=null,e.next(l)}s&&e.complete()},c=()=>{o=null,s&&e.complete()};n.subscribe(qe(e,l=>{i=!0,r=l,o||Ot(t(l)).subscribe(o=qe(e,a,c))},()=>{s=!0,(!i||!o||o.closed)&&e.complete()}))})}(()=>ZE(t,n))}let W3=(()=>{class t{constructor(e,i,r){this._ngZone=e,this._platform=i,this._scrolled=new U,this._globalSubscription=null,this._scrolledCount=0,this.scrollContainers=new Map,this._document=r}register(e){this.scrollContainers.has(e)||this.scrollContainers.set(e,e.elementScrolled().subscribe(()=>this._scrolled.next(e)))}deregister(e){const i=this.scrollContainers.get(e);i&&(i.unsubscribe(),this.scrollContainers.delete(e))}scrolled(e=20){return this._platform.isBrowser?new Ne(i=>{this._globalSubscription||this._addGlobalListener();const r=e>0?this._scrolled.pipe(tS(e)).subscribe(i):this._scrolled.subscribe(i);return this._scrolledCount++,()=>{r.unsubscribe(),this._scrolledCount--,this._scrolledCount||this._removeGlobalListener()}}):z()}ngOnDestroy(){this._removeGlobalListener(),this.scrollContainers.forEach((e,i)=>this.deregister(i)),this._scrolled.complete()}ancestorScrolled(e,i){const r=this.getAncestorScrollContainers(e);return this.scrolled(i).pipe(Ze(o=>!o||r.indexOf(o)>-1))}getAncestorScrollContainers(e){const i=[];return this.scrollContainers.forEach((r,o)=>{this._scrollableContainsElement(o,e)&&i.push(o)}),i}_getWindow(){return this._document.defaultView||window}_scrollableContainsElement(e,i){let r=Ti(i),o=e.getElementRef().nativeElement;do{if(r==o)return!0}while(r=r.parentElement);return!1}_addGlobalListener(){this._globalSubscription=this._ngZone.runOutsideAngular(()=>ns(this._getWindow().document,"scroll").subscribe(()=>this._scrolled.next()))}_removeGlobalListener(){this._globalSubscription&&(this._globalSubscription.unsubscribe(),this._globalSubscription=null)}static#e=this.\u0275fac=function(i){return new(i||t)(b(j),b(tt),b(Z,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),is=(()=>{class t{constructor(e,i,r){this._platform=e,this._change=new U,this._changeListener=o=>{this._change.next(o)},this._document=r,i.runOutsideAngular(()=>{if(e.isBrowser){const o=this._getWindow();o.addEventListener("resize",this._changeListener),o.addEventListener("orientationchange",this._changeListener)}this.change().subscribe(()=>this._viewportSize=null)})}ngOnDestroy(){if(this._platform.isBrowser){const e=this._getWindow();e.removeEventListener("resize",this._changeListener),e.removeEventListener("orientationchange",this._changeListener)}this._change.complete()}getViewportSize(){this._viewportSize||this._updateViewportSize();const e={width:this._viewportSize.width,height:this._viewportSize.height};return this._platform.isBrowser||(this._viewportSize=null),e}getViewportRect(){const e=this.getViewportScrollPosition(),{width:i,height:r}=this.getViewportSize();return{top:e.top,left:e.left,bottom:e.top+r,right:e.left+i,height:r,width:i}}getViewportScrollPosition(){if(!this._platform.isBrowser)return{top:0,left:0};const e=this._document,i=this._getWindow(),r=e.documentElement,o=r.getBoundingClientRect();return{top:-o.top||e.body.scrollTop||i.scrollY||r.scrollTop||0,left:-o.left||e.body.scrollLeft||i.scrollX||r.scrollLeft||0}}change(e=20){return e>0?this._change.pipe(tS(e)):this._change}_getWindow(){return this._document.defaultView||window}_updateViewportSize(){const e=this._getWindow();this._viewportSize=this._platform.isBrowser?{width:e.innerWidth,height:e.innerHeight}:{width:0,height:0}}static#e=this.\u0275fac=function(i){return new(i||t)(b(tt),b(j),b(Z,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),ug=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({})}return t})(),hg=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Na,ug,Na,ug]})}return t})();function Y3(t,n){}const Z3=function(t){return{animationDuration:t}},K3=function(t,n){return{value:t,params:n}};function Q3(t,n){1&t&&Q(0)}const nS=["*"],X3=["tabListContainer"],J3=["tabList"],e$=["tabListInner"],t$=["nextPaginator"],n$=["previousPaginator"],i$=["tabBodyWrapper"],r$=["tabHeader"];function o$(t,n){}function s$(t,n){1&t&&O(0,o$,0,0,"ng-template",14),2&t&&F("cdkPortalOutlet",be().$implicit.templateLabel)}function a$(t,n){1&t&&N(0),2&t&&Vn(be().$implicit.textLabel)}function c$(t,n){if(1&t){const e=Zi();w(0,"div",6,7),Y("click",function(){const r=Fn(e),o=r.$implicit,s=r.index,a=be(),c=Ci(1);return Nn(a._handleClick(o,c,s))})("cdkFocusChange",function(r){const s=Fn(e).index;return Nn(be()._tabFocusChanged(r,s))}),K(2,"span",8)(3,"div",9),w(4,"span",10)(5,"span",11),O(6,s$,1,1,"ng-template",12),O(7,a$,1,1,"ng-template",null,13,Ca),D()()()}if(2&t){const e=n.$implicit,i=n.index,r=Ci(1),o=Ci(8),s=be();he("mdc-tab--active",s.selectedIndex===i),F("id",s._getTabLabelId(i))("ngClass",e.labelClass)("disabled",e.disabled)("fitInkBarToContent",s.fitInkBarToContent),ue("tabIndex",s._getTabIndex(i))("aria-posinset",i+1)("aria-setsize",s._tabs.length)("aria-controls",s._getTabContentId(i))("aria-selected",s.selectedIndex===i)("aria-label",e.ariaLabel||null)("aria-labelledby",!e.ariaLabel&&e.ariaLabelledby?e.ariaLabelledby:null),M(3),F("matRippleTrigger",r)("matRippleDisabled",e.disabled||s.disableRipple),M(3),F("ngIf",e.templateLabel)("ngIfElse",o)}}function l$(t,n){if(1&t){const e=Zi();w(0,"mat-tab-body",15),Y("_onCentered",function(){return Fn(e),Nn(be()._removeTabBodyWrapperHeight())})("_onCentering",function(r){return Fn(e),Nn(be()._setTabBodyWrapperHeight(r))}),D()}if(2&t){const e=n.$implicit,i=n.index,r=be();he("mat-mdc-tab-body-active",r.selectedIndex===i),F("id",r._getTabContentId(i))("ngClass",e.bodyClass)("content",e.content)("position",e.position)("origin",e.origin)("animationDuration",r.animationDuration)("preserveContent",r.preserveContent),ue("tabindex",null!=r.contentTabIndex&&r.selectedIndex===i?r.contentTabIndex:null)("aria-labelledby",r._getTabLabelId(i))("aria-hidden",r.selectedIndex!==i)}}const d$={translateTab:ir("translateTab",[ln("center, void, left-origin-center, right-origin-center",Ke({transform:"none"})),ln("left",Ke({transform:"translate3d(-100%, 0, 0)",minHeight:"1px",visibility:"hidden"})),ln("right",Ke({transform:"translate3d(100%, 0, 0)",minHeight:"1px",visibility:"hidden"})),dn("* => left, * => right, left => center, right => center",Sn("{{animationDuration}} cubic-bezier(0.35, 0, 0.25, 1)")),dn("void => left-origin-center",[Ke({transform:"translate3d(-100%, 0, 0)",visibility:"hidden"}),Sn("{{animationDuration}} cubic-bezier(0.35, 0, 0.25, 1)")]),dn("void => right-origin-center",[Ke({transform:"translate3d(100%, 0, 0)",visibility:"hidden"}),Sn("{{animationDuration}} cubic-bezier(0.35, 0, 0.25, 1)")])])};let u$=(()=>{class t extends Ai{constructor(e,i,r,o){super(e,i,o),this._host=r,this._centeringSub=Le.EMPTY,this._leavingSub=Le.EMPTY}ngOnInit(){super.ngOnInit(),this._centeringSub=this._host._beforeCentering.pipe(Dn(this._host._isCenterPosition(this._host._position))).subscribe(e=>{e&&!this.hasAttached()&&this.attach(this._host._content)}),this._leavingSub=this._host._afterLeavingCenter.subscribe(()=>{this._host.preserveContent||this.detach()})}ngOnDestroy(){super.ngOnDestroy(),this._centeringSub.unsubscribe(),this._leavingSub.unsubscribe()}static#e=this.\u0275fac=function(i){return new(i||t)(f(Sr),f(Je),f(Pe(()=>iS)),f(Z))};static#t=this.\u0275dir=x({type:t,selectors:[["","matTabBodyHost",""]],features:[k]})}return t})(),h$=(()=>{class t{set position(e){this._positionIndex=e,this._computePositionAnimationState()}constructor(e,i,r){this._elementRef=e,this._dir=i,this._dirChangeSubscription=Le.EMPTY,this._translateTabComplete=new U,this._onCentering=new q,this._beforeCentering=new q,this._afterLeavingCenter=new q,this._onCentered=new q(!0),this.animationDuration="500ms",this.preserveContent=!1,i&&(this._dirChangeSubscription=i.change.subscribe(o=>{this._computePositionAnimationState(o),r.markForCheck()})),this._translateTabComplete.pipe(Mc((o,s)=>o.fromState===s.fromState&&o.toState===s.toState)).subscribe(o=>{this._isCenterPosition(o.toState)&&this._isCenterPosition(this._position)&&this._onCentered.emit(),this._isCenterPosition(o.fromState)&&!this._isCenterPosition(this._position)&&this._afterLeavingCenter.emit()})}ngOnInit(){"center"==this._position&&null!=this.origin&&(this._position=this._computePositionFromOrigin(this.origin))}ngOnDestroy(){this._dirChangeSubscription.unsubscribe(),this._translateTabComplete.complete()}_onTranslateTabStarted(e){const i=this._isCenterPosition(e.toState);this._beforeCentering.emit(i),i&&this._onCentering.emit(this._elementRef.nativeElement.clientHeight)}_getLayoutDirection(){return this._dir&&"rtl"===this._dir.value?"rtl":"ltr"}_isCenterPosition(e){return"center"==e||"left-origin-center"==e||"right-origin-center"==e}_computePositionAnimationState(e=this._getLayoutDirection()){this._position=this._positionIndex<0?"ltr"==e?"left":"right":this._positionIndex>0?"ltr"==e?"right":"left":"center"}_computePositionFromOrigin(e){const i=this._getLayoutDirection();return"ltr"==i&&e<=0||"rtl"==i&&e>0?"left-origin-center":"right-origin-center"}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(En,8),f(ct))};static#t=this.\u0275dir=x({type:t,inputs:{_content:["content","_content"],origin:"origin",animationDuration:"animationDuration",preserveContent:"preserveContent",position:"position"},outputs:{_onCentering:"_onCentering",_beforeCentering:"_beforeCentering",_afterLeavingCenter:"_afterLeavingCenter",_onCentered:"_onCentered"}})}return t})(),iS=(()=>{class t extends h${constructor(e,i,r){super(e,i,r)}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(En,8),f(ct))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-tab-body"]],viewQuery:function(i,r){if(1&i&&Se(Ai,5),2&i){let o;B(o=V())&&(r._portalHost=o.first)}},hostAttrs:[1,"mat-mdc-tab-body"],features:[k],decls:3,vars:6,consts:[["cdkScrollable","",1,"mat-mdc-tab-body-content"],["content",""],["matTabBodyHost",""]],template:function(i,r){1&i&&(w(0,"div",0,1),Y("@translateTab.start",function(s){return r._onTranslateTabStarted(s)})("@translateTab.done",function(s){return r._translateTabComplete.next(s)}),O(2,Y3,0,0,"ng-template",2),D()),2&i&&F("@translateTab",rC(3,K3,r._position,iC(1,Z3,r.animationDuration)))},dependencies:[u$],styles:['.mat-mdc-tab-body{top:0;left:0;right:0;bottom:0;position:absolute;display:block;overflow:hidden;outline:0;flex-basis:100%}.mat-mdc-tab-body.mat-mdc-tab-body-active{position:relative;overflow-x:hidden;overflow-y:auto;z-index:1;flex-grow:1}.mat-mdc-tab-group.mat-mdc-tab-group-dynamic-height .mat-mdc-tab-body.mat-mdc-tab-body-active{overflow-y:hidden}.mat-mdc-tab-body-content{height:100%;overflow:auto}.mat-mdc-tab-group-dynamic-height .mat-mdc-tab-body-content{overflow:hidden}.mat-mdc-tab-body-content[style*="visibility: hidden"]{display:none}'],encapsulation:2,data:{animation:[d$.translateTab]}})}return t})();const f$=new E("MatTabContent");let p$=(()=>{class t{constructor(e){this.template=e}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue))};static#t=this.\u0275dir=x({type:t,selectors:[["","matTabContent",""]],features:[G([{provide:f$,useExisting:t}])]})}return t})();const m$=new E("MatTabLabel"),rS=new E("MAT_TAB");let g$=(()=>{class t extends E3{constructor(e,i,r){super(e,i),this._closestTab=r}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue),f(Je),f(rS,8))};static#t=this.\u0275dir=x({type:t,selectors:[["","mat-tab-label",""],["","matTabLabel",""]],features:[G([{provide:m$,useExisting:t}]),k]})}return t})();const fg="mdc-tab-indicator--active",oS="mdc-tab-indicator--no-transition";class _${constructor(n){this._items=n}hide(){this._items.forEach(n=>n.deactivateInkBar())}alignToElement(n){const e=this._items.find(r=>r.elementRef.nativeElement===n),i=this._currentItem;if(e!==i&&(i?.deactivateInkBar(),e)){const r=i?.elementRef.nativeElement.getBoundingClientRect?.();e.activateInkBar(r),this._currentItem=e}}}function b$(t){return class extends t{constructor(...n){super(...n),this._fitToContent=!1}get fitInkBarToContent(){return this._fitToContent}set fitInkBarToContent(n){const e=me(n);this._fitToContent!==e&&(this._fitToContent=e,this._inkBarElement&&this._appendInkBarElement())}activateInkBar(n){const e=this.elementRef.nativeElement;if(!n||!e.getBoundingClientRect||!this._inkBarContentElement)return void e.classList.add(fg);const i=e.getBoundingClientRect(),r=n.width/i.width,o=n.left-i.left;e.classList.add(oS),this._inkBarContentElement.style.setProperty("transform",`translateX(${o}px) scaleX(${r})`),e.getBoundingClientRect(),e.classList.remove(oS),e.classList.add(fg),this._inkBarContentElement.style.setProperty("transform","")}deactivateInkBar(){this.elementRef.nativeElement.classList.remove(fg)}ngOnInit(){this._createInkBarElement()}ngOnDestroy(){this._inkBarElement?.remove(),this._inkBarElement=this._inkBarContentElement=null}_createInkBarElement(){const n=this.elementRef.nativeElement.ownerDocument||document;this._inkBarElement=n.createElement("span"),this._inkBarContentElement=n.createElement("span"),this._inkBarElement.className="mdc-tab-indicator",this._inkBarContentElement.className="mdc-tab-indicator__content mdc-tab-indicator__content--underline",this._inkBarElement.appendChild(this._inkBarContentElement),this._appendInkBarElement()}_appendInkBarElement(){(this._fitToContent?this.elementRef.nativeElement.querySelector(".mdc-tab__content"):this.elementRef.nativeElement).appendChild(this._inkBarElement)}}}const y$=Cd(class{}),w$=b$((()=>{class t extends y${constructor(e){super(),this.elementRef=e}focus(){this.elementRef.nativeElement.focus()}getOffsetLeft(){return this.elementRef.nativeElement.offsetLeft}getOffsetWidth(){return this.elementRef.nativeElement.offsetWidth}static#e=this.\u0275fac=function(i){return new(i||t)(f($))};static#t=this.\u0275dir=x({type:t,features:[k]})}return t})());let sS=(()=>{class t extends w${static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275dir=x({type:t,selectors:[["","matTabLabelWrapper",""]],hostVars:3,hostBindings:function(i,r){2&i&&(ue("aria-disabled",!!r.disabled),he("mat-mdc-tab-disabled",r.disabled))},inputs:{disabled:"disabled",fitInkBarToContent:"fitInkBarToContent"},features:[k]})}return t})();const x$=Cd(class{}),aS=new E("MAT_TAB_GROUP");let C$=(()=>{class t extends x${get content(){return this._contentPortal}constructor(e,i){super(),this._viewContainerRef=e,this._closestTabGroup=i,this.textLabel="",this._contentPortal=null,this._stateChanges=new U,this.position=null,this.origin=null,this.isActive=!1}ngOnChanges(e){(e.hasOwnProperty("textLabel")||e.hasOwnProperty("disabled"))&&this._stateChanges.next()}ngOnDestroy(){this._stateChanges.complete()}ngOnInit(){this._contentPortal=new ts(this._explicitContent||this._implicitContent,this._viewContainerRef)}_setTemplateLabelInput(e){e&&e._closestTab===this&&(this._templateLabel=e)}static#e=this.\u0275fac=function(i){return new(i||t)(f(Je),f(aS,8))};static#t=this.\u0275dir=x({type:t,viewQuery:function(i,r){if(1&i&&Se(Ue,7),2&i){let o;B(o=V())&&(r._implicitContent=o.first)}},inputs:{textLabel:["label","textLabel"],ariaLabel:["aria-label","ariaLabel"],ariaLabelledby:["aria-labelledby","ariaLabelledby"],labelClass:"labelClass",bodyClass:"bodyClass"},features:[k,ut]})}return t})(),cS=(()=>{class t extends C${constructor(){super(...arguments),this._explicitContent=void 0}get templateLabel(){return this._templateLabel}set templateLabel(e){this._setTemplateLabelInput(e)}static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-tab"]],contentQueries:function(i,r,o){if(1&i&&(xe(o,p$,7,Ue),xe(o,g$,5)),2&i){let s;B(s=V())&&(r._explicitContent=s.first),B(s=V())&&(r.templateLabel=s.first)}},inputs:{disabled:"disabled"},exportAs:["matTab"],features:[G([{provide:rS,useExisting:t}]),k],ngContentSelectors:nS,decls:1,vars:0,template:function(i,r){1&i&&(at(),O(0,Q3,1,0,"ng-template"))},encapsulation:2})}return t})();const lS=Xo({passive:!0});let S$=(()=>{class t{get disablePagination(){return this._disablePagination}set disablePagination(e){this._disablePagination=me(e)}get selectedIndex(){return this._selectedIndex}set selectedIndex(e){e=si(e),this._selectedIndex!=e&&(this._selectedIndexChanged=!0,this._selectedIndex=e,this._keyManager&&this._keyManager.updateActiveItem(e))}constructor(e,i,r,o,s,a,c){this._elementRef=e,this._changeDetectorRef=i,this._viewportRuler=r,this._dir=o,this._ngZone=s,this._platform=a,this._animationMode=c,this._scrollDistance=0,this._selectedIndexChanged=!1,this._destroyed=new U,this._showPaginationControls=!1,this._disableScrollAfter=!0,this._disableScrollBefore=!0,this._stopScrolling=new U,this._disablePagination=!1,this._selectedIndex=0,this.selectFocusedIndex=new q,this.indexFocused=new q,s.runOutsideAngular(()=>{ns(e.nativeElement,"mouseleave").pipe(Re(this._destroyed)).subscribe(()=>{this._stopInterval()})})}ngAfterViewInit(){ns(this._previousPaginator.nativeElement,"touchstart",lS).pipe(Re(this._destroyed)).subscribe(()=>{this._handlePaginatorPress("before")}),ns(this._nextPaginator.nativeElement,"touchstart",lS).pipe(Re(this._destroyed)).subscribe(()=>{this._handlePaginatorPress("after")})}ngAfterContentInit(){const e=this._dir?this._dir.change:z("ltr"),i=this._viewportRuler.change(150),r=()=>{this.updatePagination(),this._alignInkBarToSelectedTab()};this._keyManager=new DE(this._items).withHorizontalOrientation(this._getLayoutDirection()).withHomeAndEnd().withWrap().skipPredicate(()=>!1),this._keyManager.updateActiveItem(this._selectedIndex),this._ngZone.onStable.pipe(vt(1)).subscribe(r),An(e,i,this._items.changes,this._itemsResized()).pipe(Re(this._destroyed)).subscribe(()=>{this._ngZone.run(()=>{Promise.resolve().then(()=>{this._scrollDistance=Math.max(0,Math.min(this._getMaxScrollDistance(),this._scrollDistance)),r()})}),this._keyManager.withHorizontalOrientation(this._getLayoutDirection())}),this._keyManager.change.subscribe(o=>{this.indexFocused.emit(o),this._setTabFocus(o)})}_itemsResized(){return"function"!=typeof ResizeObserver?Qt:this._items.changes.pipe(Dn(this._items),Xt(e=>new Ne(i=>this._ngZone.runOutsideAngular(()=>{const r=new ResizeObserver(o=>i.next(o));return e.forEach(o=>r.observe(o.elementRef.nativeElement)),()=>{r.disconnect()}}))),Zm(1),Ze(e=>e.some(i=>i.contentRect.width>0&&i.contentRect.height>0)))}ngAfterContentChecked(){this._tabLabelCount!=this._items.length&&(this.updatePagination(),this._tabLabelCount=this._items.length,this._changeDetectorRef.markForCheck()),this._selectedIndexChanged&&(this._scrollToLabel(this._selectedIndex),this._checkScrollingControls(),this._alignInkBarToSelectedTab(),this._selectedIndexChanged=!1,this._changeDetectorRef.markForCheck()),this._scrollDistanceChanged&&(this._updateTabScrollPosition(),this._scrollDistanceChanged=!1,this._changeDetectorRef.markForCheck())}ngOnDestroy(){this._keyManager?.destroy(),this._destroyed.next(),this._destroyed.complete(),this._stopScrolling.complete()}_handleKeydown(e){if(!Lr(e))switch(e.keyCode){case 13:case 32:if(this.focusIndex!==this.selectedIndex){const i=this._items.get(this.focusIndex);i&&!i.disabled&&(this.selectFocusedIndex.emit(this.focusIndex),this._itemSelected(e))}break;default:this._keyManager.onKeydown(e)}}_onContentChanges(){const e=this._elementRef.nativeElement.textContent;e!==this._currentTextContent&&(this._currentTextContent=e||"",this._ngZone.run(()=>{this.updatePagination(),this._alignInkBarToSelectedTab(),this._changeDetectorRef.markForCheck()}))}updatePagination(){this._checkPaginationEnabled(),this._checkScrollingControls(),this._updateTabScrollPosition()}get focusIndex(){return this._keyManager?this._keyManager.activeItemIndex:0}set focusIndex(e){!this._isValidIndex(e)||this.focusIndex===e||!this._keyManager||this._keyManager.setActiveItem(e)}_isValidIndex(e){return!this._items||!!this._items.toArray()[e]}_setTabFocus(e){if(this._showPaginationControls&&this._scrollToLabel(e),this._items&&this._items.length){this._items.toArray()[e].focus();const i=this._tabListContainer.nativeElement;i.scrollLeft="ltr"==this._getLayoutDirection()?0:i.scrollWidth-i.offsetWidth}}_getLayoutDirection(){return this._dir&&"rtl"===this._dir.value?"rtl":"ltr"}_updateTabScrollPosition(){if(this.disablePagination)return;const e=this.scrollDistance,i="ltr"===this._getLayoutDirection()?-e:e;this._tabList.nativeElement.style.transform=`translateX(${Math.round(i)}px)`,(this._platform.TRIDENT||this._platform.EDGE)&&(this._tabListContainer.nativeElement.scrollLeft=0)}get scrollDistance(){return this._scrollDistance}set scrollDistance(e){this._scrollTo(e)}_scrollHeader(e){return this._scrollTo(this._scrollDistance+("before"==e?-1:1)*this._tabListContainer.nativeElement.offsetWidth/3)}_handlePaginatorClick(e){this._stopInterval(),this._scrollHeader(e)}_scrollToLabel(e){if(this.disablePagination)return;const i=this._items?this._items.toArray()[e]:null;if(!i)return;const r=this._tabListContainer.nativeElement.offsetWidth,{offsetLeft:o,offsetWidth:s}=i.elementRef.nativeElement;let a,c;"ltr"==this._getLayoutDirection()?(a=o,c=a+s):(c=this._tabListInner.nativeElement.offsetWidth-o,a=c-s);const l=this.scrollDistance,d=this.scrollDistance+r;a<l?this.scrollDistance-=l-a:c>d&&(this.scrollDistance+=Math.min(c-d,a-l))}_checkPaginationEnabled(){if(this.disablePagination)this._showPaginationControls=!1;else{const e=this._tabListInner.nativeElement.scrollWidth>this._elementRef.nativeElement.offsetWidth;e||(this.scrollDistance=0),e!==this._showPaginationControls&&this._changeDetectorRef.markForCheck(),this._showPaginationControls=e}}_checkScrollingControls(){this.disablePagination?this._disableScrollAfter=this._disableScrollBefore=!0:(this._disableScrollBefore=0==this.scrollDistance,this._disableScrollAfter=this.scrollDistance==this._getMaxScrollDistance(),this._changeDetectorRef.markForCheck())}_getMaxScrollDistance(){return this._tabListInner.nativeElement.scrollWidth-this._tabListContainer.nativeElement.offsetWidth||0}_alignInkBarToSelectedTab(){const e=this._items&&this._items.length?this._items.toArray()[this.selectedIndex]:null,i=e?e.elementRef.nativeElement:null;i?this._inkBar.alignToElement(i):this._inkBar.hide()}_stopInterval(){this._stopScrolling.next()}_handlePaginatorPress(e,i){i&&null!=i.button&&0!==i.button||(this._stopInterval(),ZE(650,100).pipe(Re(An(this._stopScrolling,this._destroyed))).subscribe(()=>{const{maxScrollDistance:r,distance:o}=this._scrollHeader(e);(0===o||o>=r)&&this._stopInterval()}))}_scrollTo(e){if(this.disablePagination)return{maxScrollDistance:0,distance:0};const i=this._getMaxScrollDistance();return this._scrollDistance=Math.max(0,Math.min(i,e)),this._scrollDistanceChanged=!0,this._checkScrollingControls(),{maxScrollDistance:i,distance:this._scrollDistance}}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(ct),f(is),f(En,8),f(j),f(tt),f(gt,8))};static#t=this.\u0275dir=x({type:t,inputs:{disablePagination:"disablePagination"}})}return t})(),M$=(()=>{class t extends S${get disableRipple(){return this._disableRipple}set disableRipple(e){this._disableRipple=me(e)}constructor(e,i,r,o,s,a,c){super(e,i,r,o,s,a,c),this._disableRipple=!1}_itemSelected(e){e.preventDefault()}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(ct),f(is),f(En,8),f(j),f(tt),f(gt,8))};static#t=this.\u0275dir=x({type:t,inputs:{disableRipple:"disableRipple"},features:[k]})}return t})(),I$=(()=>{class t extends M${constructor(e,i,r,o,s,a,c){super(e,i,r,o,s,a,c)}ngAfterContentInit(){this._inkBar=new _$(this._items),super.ngAfterContentInit()}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(ct),f(is),f(En,8),f(j),f(tt),f(gt,8))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-tab-header"]],contentQueries:function(i,r,o){if(1&i&&xe(o,sS,4),2&i){let s;B(s=V())&&(r._items=s)}},viewQuery:function(i,r){if(1&i&&(Se(X3,7),Se(J3,7),Se(e$,7),Se(t$,5),Se(n$,5)),2&i){let o;B(o=V())&&(r._tabListContainer=o.first),B(o=V())&&(r._tabList=o.first),B(o=V())&&(r._tabListInner=o.first),B(o=V())&&(r._nextPaginator=o.first),B(o=V())&&(r._previousPaginator=o.first)}},hostAttrs:[1,"mat-mdc-tab-header"],hostVars:4,hostBindings:function(i,r){2&i&&he("mat-mdc-tab-header-pagination-controls-enabled",r._showPaginationControls)("mat-mdc-tab-header-rtl","rtl"==r._getLayoutDirection())},inputs:{selectedIndex:"selectedIndex"},outputs:{selectFocusedIndex:"selectFocusedIndex",indexFocused:"indexFocused"},features:[k],ngContentSelectors:nS,decls:13,vars:10,consts:[["aria-hidden","true","type","button","mat-ripple","","tabindex","-1",1,"mat-mdc-tab-header-pagination","mat-mdc-tab-header-pagination-before",3,"matRippleDisabled","disabled","click","mousedown","touchend"],["previousPaginator",""],[1,"mat-mdc-tab-header-pagination-chevron"],[1,"mat-mdc-tab-label-container",3,"keydown"],["tabListContainer",""],["role","tablist",1,"mat-mdc-tab-list",3,"cdkObserveContent"],["tabList",""],[1,"mat-mdc-tab-labels"],["tabListInner",""],["aria-hidden","true","type","button","mat-ripple","","tabindex","-1",1,"mat-mdc-tab-header-pagination","mat-mdc-tab-header-pagination-after",3,"matRippleDisabled","disabled","mousedown","click","touchend"],["nextPaginator",""]],template:function(i,r){1&i&&(at(),w(0,"button",0,1),Y("click",function(){return r._handlePaginatorClick("before")})("mousedown",function(s){return r._handlePaginatorPress("before",s)})("touchend",function(){return r._stopInterval()}),K(2,"div",2),D(),w(3,"div",3,4),Y("keydown",function(s){return r._handleKeydown(s)}),w(5,"div",5,6),Y("cdkObserveContent",function(){return r._onContentChanges()}),w(7,"div",7,8),Q(9),D()()(),w(10,"button",9,10),Y("mousedown",function(s){return r._handlePaginatorPress("after",s)})("click",function(){return r._handlePaginatorClick("after")})("touchend",function(){return r._stopInterval()}),K(12,"div",2),D()),2&i&&(he("mat-mdc-tab-header-pagination-disabled",r._disableScrollBefore),F("matRippleDisabled",r._disableScrollBefore||r.disableRipple)("disabled",r._disableScrollBefore||null),M(3),he("_mat-animation-noopable","NoopAnimations"===r._animationMode),M(7),he("mat-mdc-tab-header-pagination-disabled",r._disableScrollAfter),F("matRippleDisabled",r._disableScrollAfter||r.disableRipple)("disabled",r._disableScrollAfter||null))},dependencies:[ig,wz],styles:[".mat-mdc-tab-header{display:flex;overflow:hidden;position:relative;flex-shrink:0;--mdc-tab-indicator-active-indicator-height:2px;--mdc-tab-indicator-active-indicator-shape:0;--mdc-secondary-navigation-tab-container-height:48px}.mdc-tab-indicator .mdc-tab-indicator__content{transition-duration:var(--mat-tab-animation-duration, 250ms)}.mat-mdc-tab-header-pagination{-webkit-user-select:none;user-select:none;position:relative;display:none;justify-content:center;align-items:center;min-width:32px;cursor:pointer;z-index:2;-webkit-tap-highlight-color:rgba(0,0,0,0);touch-action:none;box-sizing:content-box;background:none;border:none;outline:0;padding:0}.mat-mdc-tab-header-pagination::-moz-focus-inner{border:0}.mat-mdc-tab-header-pagination .mat-ripple-element{opacity:.12;background-color:var(--mat-tab-header-inactive-ripple-color)}.mat-mdc-tab-header-pagination-controls-enabled .mat-mdc-tab-header-pagination{display:flex}.mat-mdc-tab-header-pagination-before,.mat-mdc-tab-header-rtl .mat-mdc-tab-header-pagination-after{padding-left:4px}.mat-mdc-tab-header-pagination-before .mat-mdc-tab-header-pagination-chevron,.mat-mdc-tab-header-rtl .mat-mdc-tab-header-pagination-after .mat-mdc-tab-header-pagination-chevron{transform:rotate(-135deg)}.mat-mdc-tab-header-rtl .mat-mdc-tab-header-pagination-before,.mat-mdc-tab-header-pagination-after{padding-right:4px}.mat-mdc-tab-header-rtl .mat-mdc-tab-header-pagination-before .mat-mdc-tab-header-pagination-chevron,.mat-mdc-tab-header-pagination-after .mat-mdc-tab-header-pagination-chevron{transform:rotate(45deg)}.mat-mdc-tab-header-pagination-chevron{border-style:solid;border-width:2px 2px 0 0;height:8px;width:8px;border-color:var(--mat-tab-header-pagination-icon-color)}.mat-mdc-tab-header-pagination-disabled{box-shadow:none;cursor:default;pointer-events:none}.mat-mdc-tab-header-pagination-disabled .mat-mdc-tab-header-pagination-chevron{opacity:.4}.mat-mdc-tab-list{flex-grow:1;position:relative;transition:transform 500ms cubic-bezier(0.35, 0, 0.25, 1)}._mat-animation-noopable .mat-mdc-tab-list{transition:none}._mat-animation-noopable span.mdc-tab-indicator__content,._mat-animation-noopable span.mdc-tab__text-label{transition:none}.mat-mdc-tab-label-container{display:flex;flex-grow:1;overflow:hidden;z-index:1}.mat-mdc-tab-labels{display:flex;flex:1 0 auto}[mat-align-tabs=center]>.mat-mdc-tab-header .mat-mdc-tab-labels{justify-content:center}[mat-align-tabs=end]>.mat-mdc-tab-header .mat-mdc-tab-labels{justify-content:flex-end}.mat-mdc-tab::before{margin:5px}.cdk-high-contrast-active .mat-mdc-tab[aria-disabled=true]{color:GrayText}"],encapsulation:2})}return t})();const dS=new E("MAT_TABS_CONFIG");let T$=0;const A$=es(tg(class{constructor(t){this._elementRef=t}}),"primary");let k$=(()=>{class t extends A${get dynamicHeight(){return this._dynamicHeight}set dynamicHeight(e){this._dynamicHeight=me(e)}get selectedIndex(){return this._selectedIndex}set selectedIndex(e){this._indexToSelect=si(e,null)}get animationDuration(){return this._animationDuration}set animationDuration(e){this._animationDuration=/^\d+$/.test(e+"")?e+"ms":e}get contentTabIndex(){return this._contentTabIndex}set contentTabIndex(e){this._contentTabIndex=si(e,null)}get disablePagination(){return this._disablePagination}set disablePagination(e){this._disablePagination=me(e)}get preserveContent(){return this._preserveContent}set preserveContent(e){this._preserveContent=me(e)}get backgroundColor(){return this._backgroundColor}set backgroundColor(e){const i=this._elementRef.nativeElement.classList;i.remove("mat-tabs-with-background",`mat-background-${this.backgroundColor}`),e&&i.add("mat-tabs-with-background",`mat-background-${e}`),this._backgroundColor=e}constructor(e,i,r,o){super(e),this._changeDetectorRef=i,this._animationMode=o,this._tabs=new Rr,this._indexToSelect=0,this._lastFocusedTabIndex=null,this._tabBodyWrapperHeight=0,this._tabsSubscription=Le.EMPTY,this._tabLabelSubscription=Le.EMPTY,this._dynamicHeight=!1,this._selectedIndex=null,this.headerPosition="above",this._disablePagination=!1,this._preserveContent=!1,this.selectedIndexChange=new q,this.focusChange=new q,this.animationDone=new q,this.selectedTabChange=new q(!0),this._groupId=T$++,this.animationDuration=r&&r.animationDuration?r.animationDuration:"500ms",this.disablePagination=!(!r||null==r.disablePagination)&&r.disablePagination,this.dynamicHeight=!(!r||null==r.dynamicHeight)&&r.dynamicHeight,this.contentTabIndex=r?.contentTabIndex??null,this.preserveContent=!!r?.preserveContent}ngAfterContentChecked(){const e=this._indexToSelect=this._clampTabIndex(this._indexToSelect);if(this._selectedIndex!=e){const i=null==this._selectedIndex;if(!i){this.selectedTabChange.emit(this._createChangeEvent(e));const r=this._tabBodyWrapper.nativeElement;r.style.minHeight=r.clientHeight+"px"}Promise.resolve().then(()=>{this._tabs.forEach((r,o)=>r.isActive=o===e),i||(this.selectedIndexChange.emit(e),this._tabBodyWrapper.nativeElement.style.minHeight="")})}this._tabs.forEach((i,r)=>{i.position=r-e,null!=this._selectedIndex&&0==i.position&&!i.origin&&(i.origin=e-this._selectedIndex)}),this._selectedIndex!==e&&(this._selectedIndex=e,this._lastFocusedTabIndex=null,this._changeDetectorRef.markForCheck())}ngAfterContentInit(){this._subscribeToAllTabChanges(),this._subscribeToTabLabels(),this._tabsSubscription=this._tabs.changes.subscribe(()=>{const e=this._clampTabIndex(this._indexToSelect);if(e===this._selectedIndex){const i=this._tabs.toArray();let r;for(let o=0;o<i.length;o++)if(i[o].isActive){this._indexToSelect=this._selectedIndex=o,this._lastFocusedTabIndex=null,r=i[o];break}!r&&i[e]&&Promise.resolve().then(()=>{i[e].isActive=!0,this.selectedTabChange.emit(this._createChangeEvent(e))})}this._changeDetectorRef.markForCheck()})}_subscribeToAllTabChanges(){this._allTabs.changes.pipe(Dn(this._allTabs)).subscribe(e=>{this._tabs.reset(e.filter(i=>i._closestTabGroup===this||!i._closestTabGroup)),this._tabs.notifyOnChanges()})}ngOnDestroy(){this._tabs.destroy(),this._tabsSubscription.unsubscribe(),this._tabLabelSubscription.unsubscribe()}realignInkBar(){this._tabHeader&&this._tabHeader._alignInkBarToSelectedTab()}updatePagination(){this._tabHeader&&this._tabHeader.updatePagination()}focusTab(e){const i=this._tabHeader;i&&(i.focusIndex=e)}_focusChanged(e){this._lastFocusedTabIndex=e,this.focusChange.emit(this._createChangeEvent(e))}_createChangeEvent(e){const i=new O$;return i.index=e,this._tabs&&this._tabs.length&&(i.tab=this._tabs.toArray()[e]),i}_subscribeToTabLabels(){this._tabLabelSubscription&&this._tabLabelSubscription.unsubscribe(),this._tabLabelSubscription=An(...this._tabs.map(e=>e._stateChanges)).subscribe(()=>this._changeDetectorRef.markForCheck())}_clampTabIndex(e){return Math.min(this._tabs.length-1,Math.max(e||0,0))}_getTabLabelId(e){return`mat-tab-label-${this._groupId}-${e}`}_getTabContentId(e){return`mat-tab-content-${this._groupId}-${e}`}_setTabBodyWrapperHeight(e){if(!this._dynamicHeight||!this._tabBodyWrapperHeight)return;const i=this._tabBodyWrapper.nativeElement;i.style.height=this._tabBodyWrapperHeight+"px",this._tabBodyWrapper.nativeElement.offsetHeight&&(i.style.height=e+"px")}_removeTabBodyWrapperHeight(){const e=this._tabBodyWrapper.nativeElement;this._tabBodyWrapperHeight=e.clientHeight,e.style.height="",this.animationDone.emit()}_handleClick(e,i,r){i.focusIndex=r,e.disabled||(this.selectedIndex=r)}_getTabIndex(e){return e===(this._lastFocusedTabIndex??this.selectedIndex)?0:-1}_tabFocusChanged(e,i){e&&"mouse"!==e&&"touch"!==e&&(this._tabHeader.focusIndex=i)}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(ct),f(dS,8),f(gt,8))};static#t=this.\u0275dir=x({type:t,inputs:{dynamicHeight:"dynamicHeight",selectedIndex:"selectedIndex",headerPosition:"headerPosition",animationDuration:"animationDuration",contentTabIndex:"contentTabIndex",disablePagination:"disablePagination",preserveContent:"preserveContent",backgroundColor:"backgroundColor"},outputs:{selectedIndexChange:"selectedIndexChange",focusChange:"focusChange",animationDone:"animationDone",selectedTabChange:"selectedTabChange"},features:[k]})}return t})(),R$=(()=>{class t extends k${get fitInkBarToContent(){return this._fitInkBarToContent}set fitInkBarToContent(e){this._fitInkBarToContent=me(e),this._changeDetectorRef.markForCheck()}get stretchTabs(){return this._stretchTabs}set stretchTabs(e){this._stretchTabs=me(e)}constructor(e,i,r,o){super(e,i,r,o),this._fitInkBarToContent=!1,this._stretchTabs=!0,this.fitInkBarToContent=!(!r||null==r.fitInkBarToContent)&&r.fitInkBarToContent,this.stretchTabs=!r||null==r.stretchTabs||r.stretchTabs}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(ct),f(dS,8),f(gt,8))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-tab-group"]],contentQueries:function(i,r,o){if(1&i&&xe(o,cS,5),2&i){let s;B(s=V())&&(r._allTabs=s)}},viewQuery:function(i,r){if(1&i&&(Se(i$,5),Se(r$,5)),2&i){let o;B(o=V())&&(r._tabBodyWrapper=o.first),B(o=V())&&(r._tabHeader=o.first)}},hostAttrs:["ngSkipHydration","",1,"mat-mdc-tab-group"],hostVars:8,hostBindings:function(i,r){2&i&&(Pn("--mat-tab-animation-duration",r.animationDuration),he("mat-mdc-tab-group-dynamic-height",r.dynamicHeight)("mat-mdc-tab-group-inverted-header","below"===r.headerPosition)("mat-mdc-tab-group-stretch-tabs",r.stretchTabs))},inputs:{color:"color",disableRipple:"disableRipple",fitInkBarToContent:"fitInkBarToContent",stretchTabs:["mat-stretch-tabs","stretchTabs"]},exportAs:["matTabGroup"],features:[G([{provide:aS,useExisting:t}]),k],decls:6,vars:7,consts:[[3,"selectedIndex","disableRipple","disablePagination","indexFocused","selectFocusedIndex"],["tabHeader",""],["class","mdc-tab mat-mdc-tab mat-mdc-focus-indicator","role","tab","matTabLabelWrapper","","cdkMonitorElementFocus","",3,"id","mdc-tab--active","ngClass","disabled","fitInkBarToContent","click","cdkFocusChange",4,"ngFor","ngForOf"],[1,"mat-mdc-tab-body-wrapper"],["tabBodyWrapper",""],["role","tabpanel",3,"id","mat-mdc-tab-body-active","ngClass","content","position","origin","animationDuration","preserveContent","_onCentered","_onCentering",4,"ngFor","ngForOf"],["role","tab","matTabLabelWrapper","","cdkMonitorElementFocus","",1,"mdc-tab","mat-mdc-tab","mat-mdc-focus-indicator",3,"id","ngClass","disabled","fitInkBarToContent","click","cdkFocusChange"],["tabNode",""],[1,"mdc-tab__ripple"],["mat-ripple","",1,"mat-mdc-tab-ripple",3,"matRippleTrigger","matRippleDisabled"],[1,"mdc-tab__content"],[1,"mdc-tab__text-label"],[3,"ngIf","ngIfElse"],["tabTextLabel",""],[3,"cdkPortalOutlet"],["role","tabpanel",3,"id","ngClass","content","position","origin","animationDuration","preserveContent","_onCentered","_onCentering"]],template:function(i,r){1&i&&(w(0,"mat-tab-header",0,1),Y("indexFocused",function(s){return r._focusChanged(s)})("selectFocusedIndex",function(s){return r.selectedIndex=s}),O(2,c$,9,17,"div",2),D(),w(3,"div",3,4),O(5,l$,1,12,"mat-tab-body",5),D()),2&i&&(F("selectedIndex",r.selectedIndex||0)("disableRipple",r.disableRipple)("disablePagination",r.disablePagination),M(2),F("ngForOf",r._tabs),M(1),he("_mat-animation-noopable","NoopAnimations"===r._animationMode),M(2),F("ngForOf",r._tabs))},dependencies:[PD,ym,Ii,Ai,ig,r3,iS,sS,I$],styles:['.mdc-tab{min-width:90px;padding-right:24px;padding-left:24px;display:flex;flex:1 0 auto;justify-content:center;box-sizing:border-box;margin:0;padding-top:0;padding-bottom:0;border:none;outline:none;text-align:center;white-space:nowrap;cursor:pointer;-webkit-appearance:none;z-index:1}.mdc-tab::-moz-focus-inner{padding:0;border:0}.mdc-tab[hidden]{display:none}.mdc-tab--min-width{flex:0 1 auto}.mdc-tab__content{display:flex;align-items:center;justify-content:center;height:inherit;pointer-events:none}.mdc-tab__text-label{transition:150ms color linear;display:inline-block;line-height:1;z-index:2}.mdc-tab__icon{transition:150ms color linear;z-index:2}.mdc-tab--stacked .mdc-tab__content{flex-direction:column;align-items:center;justify-content:center}.mdc-tab--stacked .mdc-tab__text-label{padding-top:6px;padding-bottom:4px}.mdc-tab--active .mdc-tab__text-label,.mdc-tab--active .mdc-tab__icon{transition-delay:100ms}.mdc-tab:not(.mdc-tab--stacked) .mdc-tab__icon+.mdc-tab__text-label{padding-left:8px;padding-right:0}[dir=rtl] .mdc-tab:not(.mdc-tab--stacked) .mdc-tab__icon+.mdc-tab__text-label,.mdc-tab:not(.mdc-tab--stacked) .mdc-tab__icon+.mdc-tab__text-label[dir=rtl]{padding-left:0;padding-right:8px}.mdc-tab-indicator{display:flex;position:absolute;top:0;left:0;justify-content:center;width:100%;height:100%;pointer-events:none;z-index:1}.mdc-tab-indicator__content{transform-origin:left;opacity:0}.mdc-tab-indicator__content--underline{align-self:flex-end;box-sizing:border-box;width:100%;border-top-style:solid}.mdc-tab-indicator__content--icon{align-self:center;margin:0 auto}.mdc-tab-indicator--active .mdc-tab-indicator__content{opacity:1}.mdc-tab-indicator .mdc-tab-indicator__content{transition:250ms transform cubic-bezier(0.4, 0, 0.2, 1)}.mdc-tab-indicator--no-transition .mdc-tab-indicator__content{transition:none}.mdc-tab-indicator--fade .mdc-tab-indicator__content{transition:150ms opacity linear}.mdc-tab-indicator--active.mdc-tab-indicator--fade .mdc-tab-indicator__content{transition-delay:100ms}.mat-mdc-tab-ripple{position:absolute;top:0;left:0;bottom:0;right:0;pointer-events:none}.mat-mdc-tab{-webkit-tap-highlight-color:rgba(0,0,0,0);-webkit-font-smoothing:antialiased;-moz-osx-font-smoothing:grayscale;text-decoration:none;background:none;font-family:var(--mat-tab-header-label-text-font);font-size:var(--mat-tab-header-label-text-size);letter-spacing:var(--mat-tab-header-label-text-tracking);line-height:var(--mat-tab-header-label-text-line-height);font-weight:var(--mat-tab-header-label-text-weight)}.mat-mdc-tab .mdc-tab-indicator__content--underline{border-color:var(--mdc-tab-indicator-active-indicator-color)}.mat-mdc-tab .mdc-tab-indicator__content--underline{border-top-width:var(--mdc-tab-indicator-active-indicator-height)}.mat-mdc-tab .mdc-tab-indicator__content--underline{border-radius:var(--mdc-tab-indicator-active-indicator-shape)}.mat-mdc-tab:not(.mdc-tab--stacked){height:var(--mdc-secondary-navigation-tab-container-height)}.mat-mdc-tab:not(:disabled).mdc-tab--active .mdc-tab__icon{fill:currentColor}.mat-mdc-tab:not(:disabled):hover.mdc-tab--active .mdc-tab__icon{fill:currentColor}.mat-mdc-tab:not(:disabled):focus.mdc-tab--active .mdc-tab__icon{fill:currentColor}.mat-mdc-tab:not(:disabled):active.mdc-tab--active .mdc-tab__icon{fill:currentColor}.mat-mdc-tab:disabled.mdc-tab--active .mdc-tab__icon{fill:currentColor}.mat-mdc-tab:not(:disabled):not(.mdc-tab--active) .mdc-tab__icon{fill:currentColor}.mat-mdc-tab:not(:disabled):hover:not(.mdc-tab--active) .mdc-tab__icon{fill:currentColor}.mat-mdc-tab:not(:disabled):focus:not(.mdc-tab--active) .mdc-tab__icon{fill:currentColor}.mat-mdc-tab:not(:disabled):active:not(.mdc-tab--active) .mdc-tab__icon{fill:currentColor}.mat-mdc-tab:disabled:not(.mdc-tab--active) .mdc-tab__icon{fill:currentColor}.mat-mdc-tab.mdc-tab{flex-grow:0}.mat-mdc-tab:hover .mdc-tab__text-label{color:var(--mat-tab-header-inactive-hover-label-text-color)}.mat-mdc-tab:focus .mdc-tab__text-label{color:var(--mat-tab-header-inactive-focus-label-text-color)}.mat-mdc-tab.mdc-tab--active .mdc-tab__text-label{color:var(--mat-tab-header-active-label-text-color)}.mat-mdc-tab.mdc-tab--active .mdc-tab__ripple::before,.mat-mdc-tab.mdc-tab--active .mat-ripple-element{background-color:var(--mat-tab-header-active-ripple-color)}.mat-mdc-tab.mdc-tab--active:hover .mdc-tab__text-label{color:var(--mat-tab-header-active-hover-label-text-color)}.mat-mdc-tab.mdc-tab--active:hover .mdc-tab-indicator__content--underline{border-color:var(--mat-tab-header-active-hover-indicator-color)}.mat-mdc-tab.mdc-tab--active:focus .mdc-tab__text-label{color:var(--mat-tab-header-active-focus-label-text-color)}.mat-mdc-tab.mdc-tab--active:focus .mdc-tab-indicator__content--underline{border-color:var(--mat-tab-header-active-focus-indicator-color)}.mat-mdc-tab.mat-mdc-tab-disabled{opacity:.4;pointer-events:none}.mat-mdc-tab.mat-mdc-tab-disabled .mdc-tab__content{pointer-events:none}.mat-mdc-tab.mat-mdc-tab-disabled .mdc-tab__ripple::before,.mat-mdc-tab.mat-mdc-tab-disabled .mat-ripple-element{background-color:var(--mat-tab-header-disabled-ripple-color)}.mat-mdc-tab .mdc-tab__ripple::before{content:"";display:block;position:absolute;top:0;left:0;right:0;bottom:0;opacity:0;pointer-events:none;background-color:var(--mat-tab-header-inactive-ripple-color)}.mat-mdc-tab .mdc-tab__text-label{color:var(--mat-tab-header-inactive-label-text-color);display:inline-flex;align-items:center}.mat-mdc-tab .mdc-tab__content{position:relative;pointer-events:auto}.mat-mdc-tab:hover .mdc-tab__ripple::before{opacity:.04}.mat-mdc-tab.cdk-program-focused .mdc-tab__ripple::before,.mat-mdc-tab.cdk-keyboard-focused .mdc-tab__ripple::before{opacity:.12}.mat-mdc-tab .mat-ripple-element{opacity:.12;background-color:var(--mat-tab-header-inactive-ripple-color)}.mat-mdc-tab-group.mat-mdc-tab-group-stretch-tabs>.mat-mdc-tab-header .mat-mdc-tab{flex-grow:1}.mat-mdc-tab-group{display:flex;flex-direction:column;max-width:100%}.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header,.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header-pagination{background-color:var(--mat-tab-header-with-background-background-color)}.mat-mdc-tab-group.mat-tabs-with-background.mat-primary>.mat-mdc-tab-header .mat-mdc-tab .mdc-tab__text-label{color:var(--mat-tab-header-with-background-foreground-color)}.mat-mdc-tab-group.mat-tabs-with-background.mat-primary>.mat-mdc-tab-header .mdc-tab-indicator__content--underline{border-color:var(--mat-tab-header-with-background-foreground-color)}.mat-mdc-tab-group.mat-tabs-with-background:not(.mat-primary)>.mat-mdc-tab-header .mat-mdc-tab:not(.mdc-tab--active) .mdc-tab__text-label{color:var(--mat-tab-header-with-background-foreground-color)}.mat-mdc-tab-group.mat-tabs-with-background:not(.mat-primary)>.mat-mdc-tab-header .mat-mdc-tab:not(.mdc-tab--active) .mdc-tab-indicator__content--underline{border-color:var(--mat-tab-header-with-background-foreground-color)}.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header .mat-mdc-tab-header-pagination-chevron,.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header .mat-mdc-focus-indicator::before,.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header-pagination .mat-mdc-tab-header-pagination-chevron,.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header-pagination .mat-mdc-focus-indicator::before{border-color:var(--mat-tab-header-with-background-foreground-color)}.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header .mat-ripple-element,.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header .mdc-tab__ripple::before,.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header-pagination .mat-ripple-element,.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header-pagination .mdc-tab__ripple::before{background-color:var(--mat-tab-header-with-background-foreground-color)}.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header .mat-mdc-tab-header-pagination-chevron,.mat-mdc-tab-group.mat-tabs-with-background>.mat-mdc-tab-header-pagination .mat-mdc-tab-header-pagination-chevron{color:var(--mat-tab-header-with-background-foreground-color)}.mat-mdc-tab-group.mat-mdc-tab-group-inverted-header{flex-direction:column-reverse}.mat-mdc-tab-group.mat-mdc-tab-group-inverted-header .mdc-tab-indicator__content--underline{align-self:flex-start}.mat-mdc-tab-body-wrapper{position:relative;overflow:hidden;display:flex;transition:height 500ms cubic-bezier(0.35, 0, 0.25, 1)}.mat-mdc-tab-body-wrapper._mat-animation-noopable{transition:none !important;animation:none !important}'],encapsulation:2})}return t})();class O${}let F$=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[oi,Ae,Vr,Md,Km,FE,Ae]})}return t})();function rs(t,n){return ve(n)?yt(t,n,1):yt(t,1)}function rr(t){return nt((n,e)=>{try{n.subscribe(e)}finally{e.add(t)}})}class Rd{}class Od{}class zn{constructor(n){this.normalizedNames=new Map,this.lazyUpdate=null,n?"string"==typeof n?this.lazyInit=()=>{this.headers=new Map,n.split("\n").forEach(e=>{const i=e.indexOf(":");if(i>0){const r=e.slice(0,i),o=r.toLowerCase(),s=e.slice(i+1).trim();this.maybeSetNormalizedName(r,o),this.headers.has(o)?this.headers.get(o).push(s):this.headers.set(o,[s])}})}:typeof Headers<"u"&&n instanceof Headers?(this.headers=new Map,n.forEach((e,i)=>{this.setHeaderEntries(i,e)})):this.lazyInit=()=>{this.headers=new Map,Object.entries(n).forEach(([e,i])=>{this.setHeaderEntries(e,i)})}:this.headers=new Map}has(n){return this.init(),this.headers.has(n.toLowerCase())}get(n){this.init();const e=this.headers.get(n.toLowerCase());return e&&e.length>0?e[0]:null}keys(){return this.init(),Array.from(this.normalizedNames.values())}getAll(n){return this.init(),this.headers.get(n.toLowerCase())||null}append(n,e){return this.clone({name:n,value:e,op:"a"})}set(n,e){return this.clone({name:n,value:e,op:"s"})}delete(n,e){return this.clone({name:n,value:e,op:"d"})}maybeSetNormalizedName(n,e){this.normalizedNames.has(e)||this.normalizedNames.set(e,n)}init(){this.lazyInit&&(this.lazyInit instanceof zn?this.copyFrom(this.lazyInit):this.lazyInit(),this.lazyInit=null,this.lazyUpdate&&(this.lazyUpdate.forEach(n=>this.applyUpdate(n)),this.lazyUpdate=null))}copyFrom(n){n.init(),Array.from(n.headers.keys()).forEach(e=>{this.headers.set(e,n.headers.get(e)),this.normalizedNames.set(e,n.normalizedNames.get(e))})}clone(n){const e=new zn;return e.lazyInit=this.lazyInit&&this.lazyInit instanceof zn?this.lazyInit:this,e.lazyUpdate=(this.lazyUpdate||[]).concat([n]),e}applyUpdate(n){const e=n.name.toLowerCase();switch(n.op){case"a":case"s":let i=n.value;if("string"==typeof i&&(i=[i]),0===i.length)return;this.maybeSetNormalizedName(n.name,e);const r=("a"===n.op?this.headers.get(e):void 0)||[];r.push(...i),this.headers.set(e,r);break;case"d":const o=n.value;if(o){let s=this.headers.get(e);if(!s)return;s=s.filter(a=>-1===o.indexOf(a)),0===s.length?(this.headers.delete(e),this.normalizedNames.delete(e)):this.headers.set(e,s)}else this.headers.delete(e),this.normalizedNames.delete(e)}}setHeaderEntries(n,e){const i=(Array.isArray(e)?e:[e]).map(o=>o.toString()),r=n.toLowerCase();this.headers.set(r,i),this.maybeSetNormalizedName(n,r)}forEach(n){this.init(),Array.from(this.normalizedNames.keys()).forEach(e=>n(this.normalizedNames.get(e),this.headers.get(e)))}}class N${encodeKey(n){return uS(n)}encodeValue(n){return uS(n)}decodeKey(n){return decodeURIComponent(n)}decodeValue(n){return decodeURIComponent(n)}}const L$=/%(\d[a-f0-9])/gi,B$={40:"@","3A":":",24:"$","2C":",","3B":";","3D":"=","3F":"?","2F":"/"};function uS(t){return encodeURIComponent(t).replace(L$,(n,e)=>B$[e]??n)}function Fd(t){return`${t}`}class or{constructor(n={}){if(this.updates=null,this.cloneFrom=null,this.encoder=n.encoder||new N$,n.fromString){if(n.fromObject)throw new Error("Cannot specify both fromString and fromObject.");this.map=function P$(t,n){const e=new Map;return t.length>0&&t.replace(/^\?/,"").split("&").forEach(r=>{const o=r.indexOf("="),[s,a]=-1==o?[n.decodeKey(r),""]:[n.decodeKey(r.slice(0,o)),n.decodeValue(r.slice(o+1))],c=e.get(s)||[];c.push(a),e.set(s,c)}),e}(n.fromString,this.encoder)}else n.fromObject?(this.map=new Map,Object.keys(n.fromObject).forEach(e=>{const i=n.fromObject[e],r=Array.isArray(i)?i.map(Fd):[Fd(i)];this.map.set(e,r)})):this.map=null}has(n){return this.init(),this.map.has(n)}get(n){this.init();const e=this.map.get(n);return e?e[0]:null}getAll(n){return this.init(),this.map.get(n)||null}keys(){return this.init(),Array.from(this.map.keys())}append(n,e){return this.clone({param:n,value:e,op:"a"})}appendAll(n){const e=[];return Object.keys(n).forEach(i=>{const r=n[i];Array.isArray(r)?r.forEach(o=>{e.push({param:i,value:o,op:"a"})}):e.push({param:i,value:r,op:"a"})}),this.clone(e)}set(n,e){return this.clone({param:n,value:e,op:"s"})}delete(n,e){return this.clone({param:n,value:e,op:"d"})}toString(){return this.init(),this.keys().map(n=>{const e=this.encoder.encodeKey(n);return this.map.get(n).map(i=>e+"="+this.encoder.encodeValue(i)).join("&")}).filter(n=>""!==n).join("&")}clone(n){const e=new or({encoder:this.encoder});return e.cloneFrom=this.cloneFrom||this,e.updates=(this.updates||[]).concat(n),e}init(){null===this.map&&(this.map=new Map),null!==this.cloneFrom&&(this.cloneFrom.init(),this.cloneFrom.keys().forEach(n=>this.map.set(n,this.cloneFrom.map.get(n))),this.updates.forEach(n=>{switch(n.op){case"a":case"s":const e=("a"===n.op?this.map.get(n.param):void 0)||[];e.push(Fd(n.value)),this.map.set(n.param,e);break;case"d":if(void 0===n.value){this.map.delete(n.param);break}{let i=this.map.get(n.param)||[];const r=i.indexOf(Fd(n.value));-1!==r&&i.splice(r,1),i.length>0?this.map.set(n.param,i):this.map.delete(n.param)}}}),this.cloneFrom=this.updates=null)}}class V${constructor(){this.map=new Map}set(n,e){return this.map.set(n,e),this}get(n){return this.map.has(n)||this.map.set(n,n.defaultValue()),this.map.get(n)}delete(n){return this.map.delete(n),this}has(n){return this.map.has(n)}keys(){return this.map.keys()}}function hS(t){return typeof ArrayBuffer<"u"&&t instanceof ArrayBuffer}function fS(t){return typeof Blob<"u"&&t instanceof Blob}function pS(t){return typeof FormData<"u"&&t instanceof FormData}class ja{constructor(n,e,i,r){let o;if(this.url=e,this.body=null,this.reportProgress=!1,this.withCredentials=!1,this.responseType="json",this.method=n.toUpperCase(),function j$(t){switch(t){case"DELETE":case"GET":case"HEAD":case"OPTIONS":case"JSONP":return!1;default:return!0}}(this.method)||r?(this.body=void 0!==i?i:null,o=r):o=i,o&&(this.reportProgress=!!o.reportProgress,this.withCredentials=!!o.withCredentials,o.responseType&&(this.responseType=o.responseType),o.headers&&(this.headers=o.headers),o.context&&(this.context=o.context),o.params&&(this.params=o.params)),this.headers||(this.headers=new zn),this.context||(this.context=new V$),this.params){const s=this.params.toString();if(0===s.length)this.urlWithParams=e;else{const a=e.indexOf("?");this.urlWithParams=e+(-1===a?"?":a<e.length-1?"&":"")+s}}else this.params=new or,this.urlWithParams=e}serializeBody(){return null===this.body?null:hS(this.body)||fS(this.body)||pS(this.body)||function H$(t){return typeof URLSearchParams<"u"&&t instanceof URLSearchParams}(this.body)||"string"==typeof this.body?this.body:this.body instanceof or?this.body.toString():"object"==typeof this.body||"boolean"==typeof this.body||Array.isArray(this.body)?JSON.stringify(this.body):this.body.toString()}detectContentTypeHeader(){return null===this.body||pS(this.body)?null:fS(this.body)?this.body.type||null:hS(this.body)?null:"string"==typeof this.body?"text/plain":this.body instanceof or?"application/x-www-form-urlencoded;charset=UTF-8":"object"==typeof this.body||"number"==typeof this.body||"boolean"==typeof this.body?"application/json":null}clone(n={}){const e=n.method||this.method,i=n.url||this.url,r=n.responseType||this.responseType,o=void 0!==n.body?n.body:this.body,s=void 0!==n.withCredentials?n.withCredentials:this.withCredentials,a=void 0!==n.reportProgress?n.reportProgress:this.reportProgress;let c=n.headers||this.headers,l=n.params||this.params;const d=n.context??this.context;return void 0!==n.setHeaders&&(c=Object.keys(n.setHeaders).reduce((u,h)=>u.set(h,n.setHeaders[h]),c)),n.setParams&&(l=Object.keys(n.setParams).reduce((u,h)=>u.set(h,n.setParams[h]),l)),new ja(e,i,o,{params:l,headers:c,context:d,reportProgress:a,responseType:r,withCredentials:s})}}var os=function(t){return t[t.Sent=0]="Sent",t[t.UploadProgress=1]="UploadProgress",t[t.ResponseHeader=2]="ResponseHeader",t[t.DownloadProgress=3]="DownloadProgress",t[t.Response=4]="Response",t[t.User=5]="User",t}(os||{});class pg{constructor(n,e=200,i="OK"){this.headers=n.headers||new zn,this.status=void 0!==n.status?n.status:e,this.statusText=n.statusText||i,this.url=n.url||null,this.ok=this.status>=200&&this.status<300}}class mg extends pg{constructor(n={}){super(n),this.type=os.ResponseHeader}clone(n={}){return new mg({headers:n.headers||this.headers,status:void 0!==n.status?n.status:this.status,statusText:n.statusText||this.statusText,url:n.url||this.url||void 0})}}class ss extends pg{constructor(n={}){super(n),this.type=os.Response,this.body=void 0!==n.body?n.body:null}clone(n={}){return new ss({body:void 0!==n.body?n.body:this.body,headers:n.headers||this.headers,status:void 0!==n.status?n.status:this.status,statusText:n.statusText||this.statusText,url:n.url||this.url||void 0})}}class mS extends pg{constructor(n){super(n,0,"Unknown Error"),this.name="HttpErrorResponse",this.ok=!1,this.message=this.status>=200&&this.status<300?`Http failure during parsing for ${n.url||"(unknown url)"}`:`Http failure response for ${n.url||"(unknown url)"}: ${n.status} ${n.statusText}`,this.error=n.error||null}}function gg(t,n){return{body:n,headers:t.headers,context:t.context,observe:t.observe,params:t.params,reportProgress:t.reportProgress,responseType:t.responseType,withCredentials:t.withCredentials}}let Nd=(()=>{class t{constructor(e){this.handler=e}request(e,i,r={}){let o;if(e instanceof ja)o=e;else{let c,l;c=r.headers instanceof zn?r.headers:new zn(r.headers),r.params&&(l=r.params instanceof or?r.params:new or({fromObject:r.params})),o=new ja(e,i,void 0!==r.body?r.body:null,{headers:c,context:r.context,params:l,reportProgress:r.reportProgress,responseType:r.responseType||"json",withCredentials:r.withCredentials})}const s=z(o).pipe(rs(c=>this.handler.handle(c)));if(e instanceof ja||"events"===r.observe)return s;const a=s.pipe(Ze(c=>c instanceof ss));switch(r.observe||"body"){case"body":switch(o.responseType){case"arraybuffer":return a.pipe(pe(c=>{if(null!==c.body&&!(c.body instanceof ArrayBuffer))throw new Error("Response is not an ArrayBuffer.");return c.body}));case"blob":return a.pipe(pe(c=>{if(null!==c.body&&!(c.body instanceof Blob))throw new Error("Response is not a Blob.");return c.body}));case"text":return a.pipe(pe(c=>{if(null!==c.body&&"string"!=typeof c.body)throw new Error("Response is not a string.");return c.body}));default:return a.pipe(pe(c=>c.body))}case"response":return a;default:throw new Error(`Unreachable: unhandled observe type ${r.observe}}`)}}delete(e,i={}){return this.request("DELETE",e,i)}get(e,i={}){return this.request("GET",e,i)}head(e,i={}){return this.request("HEAD",e,i)}jsonp(e,i){return this.request("JSONP",e,{params:(new or).append(i,"JSONP_CALLBACK"),observe:"body",responseType:"json"})}options(e,i={}){return this.request("OPTIONS",e,i)}patch(e,i,r={}){return this.request("PATCH",e,gg(r,i))}post(e,i,r={}){return this.request("POST",e,gg(r,i))}put(e,i,r={}){return this.request("PUT",e,gg(r,i))}static#e=this.\u0275fac=function(i){return new(i||t)(b(Rd))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();function bS(t,n){return n(t)}function $$(t,n){return(e,i)=>n.intercept(e,{handle:r=>t(r,i)})}const G$=new E(""),Ha=new E(""),vS=new E("");function W$(){let t=null;return(n,e)=>{null===t&&(t=(T(G$,{optional:!0})??[]).reduceRight($$,bS));const i=T(Zl),r=i.add();return t(n,e).pipe(rr(()=>i.remove(r)))}}let yS=(()=>{class t extends Rd{constructor(e,i){super(),this.backend=e,this.injector=i,this.chain=null,this.pendingTasks=T(Zl)}handle(e){if(null===this.chain){const r=Array.from(new Set([...this.injector.get(Ha),...this.injector.get(vS,[])]));this.chain=r.reduceRight((o,s)=>function U$(t,n,e){return(i,r)=>e.runInContext(()=>n(i,o=>t(o,r)))}(o,s,this.injector),bS)}const i=this.pendingTasks.add();return this.chain(e,r=>this.backend.handle(r)).pipe(rr(()=>this.pendingTasks.remove(i)))}static#e=this.\u0275fac=function(i){return new(i||t)(b(Od),b(on))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();const K$=/^\)\]\}',?\n/;let xS=(()=>{class t{constructor(e){this.xhrFactory=e}handle(e){if("JSONP"===e.method)throw new y(-2800,!1);const i=this.xhrFactory;return(i.\u0275loadImpl?dt(i.\u0275loadImpl()):z(null)).pipe(Xt(()=>new Ne(o=>{const s=i.build();if(s.open(e.method,e.urlWithParams),e.withCredentials&&(s.withCredentials=!0),e.headers.forEach((g,_)=>s.setRequestHeader(g,_.join(","))),e.headers.has("Accept")||s.setRequestHeader("Accept","application/json, text/plain, */*"),!e.headers.has("Content-Type")){const g=e.detectContentTypeHeader();null!==g&&s.setRequestHeader("Content-Type",g)}if(e.responseType){const g=e.responseType.toLowerCase();s.responseType="json"!==g?g:"text"}const a=e.serializeBody();let c=null;const l=()=>{if(null!==c)return c;const g=s.statusText||"OK",_=new zn(s.getAllResponseHeaders()),C=function Q$(t){return"responseURL"in t&&t.responseURL?t.responseURL:/^X-Request-URL:/m.test(t.getAllResponseHeaders())?t.getResponseHeader("X-Request-URL"):null}(s)||e.url;return c=new mg({headers:_,status:s.status,statusText:g,url:C}),c},d=()=>{let{headers:g,status:_,statusText:C,url:v}=l(),R=null;204!==_&&(R=typeof s.response>"u"?s.responseText:s.response),0===_&&(_=R?200:0);let L=_>=200&&_<300;if("json"===e.responseType&&"string"==typeof R){const ne=R;R=R.replace(K$,"");try{R=""!==R?JSON.parse(R):null}catch(Fe){R=ne,L&&(L=!1,R={error:Fe,text:R})}}L?(o.next(new ss({body:R,headers:g,status:_,statusText:C,url:v||void 0})),o.complete()):o.error(new mS({error:R,headers:g,status:_,statusText:C,url:v||void 0}))},u=g=>{const{url:_}=l(),C=new mS({error:g,status:s.status||0,statusText:s.statusText||"Unknown Error",url:_||void 0});o.error(C)};let h=!1;const p=g=>{h||(o.next(l()),h=!0);let _={type:os.DownloadProgress,loaded:g.loaded};g.lengthComputable&&(_.total=g.total),"text"===e.responseType&&s.responseText&&(_.partialText=s.responseText),o.next(_)},m=g=>{let _={type:os.UploadProgress,loaded:g.loaded};g.lengthComputable&&(_.total=g.total),o.next(_)};return s.addEventListener("load",d),s.addEventListener("error",u),s.addEventListener("timeout",u),s.addEventListener("abort",u),e.reportProgress&&(s.addEventListener("progress",p),null!==a&&s.upload&&s.upload.addEventListener("progress",m)),s.send(a),o.next({type:os.Sent}),()=>{s.removeEventListener("error",u),s.removeEventListener("abort",u),s.removeEventListener("load",d),s.removeEventListener("timeout",u),e.reportProgress&&(s.removeEventListener("progress",p),null!==a&&s.upload&&s.upload.removeEventListener("progress",m)),s.readyState!==s.DONE&&s.abort()}})))}static#e=this.\u0275fac=function(i){return new(i||t)(b(UD))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();const _g=new E("XSRF_ENABLED"),CS=new E("XSRF_COOKIE_NAME",{providedIn:"root",factory:()=>"XSRF-TOKEN"}),DS=new E("XSRF_HEADER_NAME",{providedIn:"root",factory:()=>"X-XSRF-TOKEN"});class ES{}let eU=(()=>{class t{constructor(e,i,r){this.doc=e,this.platform=i,this.cookieName=r,this.lastCookieString="",this.lastToken=null,this.parseCount=0}getToken(){if("server"===this.platform)return null;const e=this.doc.cookie||"";return e!==this.lastCookieString&&(this.parseCount++,this.lastToken=FD(e,this.cookieName),this.lastCookieString=e),this.lastToken}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z),b(Yi),b(CS))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();function tU(t,n){const e=t.url.toLowerCase();if(!T(_g)||"GET"===t.method||"HEAD"===t.method||e.startsWith("http://")||e.startsWith("https://"))return n(t);const i=T(ES).getToken(),r=T(DS);return null!=i&&!t.headers.has(r)&&(t=t.clone({headers:t.headers.set(r,i)})),n(t)}var sr=function(t){return t[t.Interceptors=0]="Interceptors",t[t.LegacyInterceptors=1]="LegacyInterceptors",t[t.CustomXsrfConfiguration=2]="CustomXsrfConfiguration",t[t.NoXsrfProtection=3]="NoXsrfProtection",t[t.JsonpSupport=4]="JsonpSupport",t[t.RequestsMadeViaParent=5]="RequestsMadeViaParent",t[t.Fetch=6]="Fetch",t}(sr||{});function nU(...t){const n=[Nd,xS,yS,{provide:Rd,useExisting:yS},{provide:Od,useExisting:xS},{provide:Ha,useValue:tU,multi:!0},{provide:_g,useValue:!0},{provide:ES,useClass:eU}];for(const e of t)n.push(...e.\u0275providers);return function If(t){return{\u0275providers:t}}(n)}const SS=new E("LEGACY_INTERCEPTOR_FN");function iU(){return function jr(t,n){return{\u0275kind:t,\u0275providers:n}}(sr.LegacyInterceptors,[{provide:SS,useFactory:W$},{provide:Ha,useExisting:SS,multi:!0}])}let rU=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({providers:[nU(iU())]})}return t})();function Ri(t){return nt((n,e)=>{let o,i=null,r=!1;i=n.subscribe(qe(e,void 0,void 0,s=>{o=Ot(t(s,Ri(t)(n))),i?(i.unsubscribe(),i=null,o.subscribe(e)):r=!0})),r&&(i.unsubscribe(),i=null,o.subscribe(e))})}function za(t,n,e){return console.log("error => "+n),t.open(n,e,{duration:3e5,panelClass:"error-snack-bar"}),z({})}function ar(t,n){return function dU(t,n,e,i){return n&&n(),i?(console.log("With errorer "+i),t().pipe(Ee(r=>console.log("Trap Begin...")),Ri(i),rr(()=>{console.log("Trap End.."),e&&e()}))):t().pipe(Ee(r=>console.log("Trap Begin...")),rr(()=>{console.log("Trap End.."),e&&e()}))}(t,uU,hU,n)}function uU(){console.log("Showing loading..."),document.getElementById("loading-layer").style.display="block"}function hU(){console.log("Hiding loading..."),document.getElementById("loading-layer").style.display="none"}function Hr(t,n,e){console.log("success => "+n),t.open(n,e,{duration:3e5,panelClass:"error-snack-bar"})}let MS=(()=>{class t{constructor(e){this.http=e,this.baseUrl="/apiserver",this.httpOptions={headers:new zn({"Content-Type":"application/json"})},this.configETag=null}rememberETag(e){const i=e.headers.get("ETag");return i&&(this.configETag=i),e.body}editOptions(){let e=this.httpOptions.headers;return this.configETag&&(e=e.set("If-Match",this.configETag)),{headers:e,observe:"response"}}getListeners(){return this.http.get(this.baseUrl+"/config/listeners",{observe:"response"}).pipe(pe(e=>this.rememberETag(e)),Ee(e=>this.log(`fetched listeners ${JSON.stringify(e)}`)))}getStats(){return this.http.get(this.baseUrl+"/stats/listeners").pipe(Ee(e=>this.log(`fetched stats ${JSON.stringify(e)}`)))}getHistory(e,i,r){return this.http.get(`${this.baseUrl}/stats/history/${encodeURIComponent(e)}?resolution=${i}&limit=${r}`).pipe(Ee(o=>this.log(`fetched history of ${e}: ${o.points.length} points`)))}getDNS(){return this.http.get(this.baseUrl+"/config/dns",{observe:"response"}).pipe(pe(e=>this.rememberETag(e)),Ee(e=>this.log(`fetched dns ${JSON.stringify(e)}`)))}saveDNS(e){var r=JSON.stringify(e);return console.log(`After conversion: ${JSON.stringify(r)}`),this.http.put(this.baseUrl+"/config/dns",r,this.editOptions()).pipe(pe(o=>this.rememberETag(o)),Ee(o=>this.log(`Update DNS:${e} result ${JSON.stringify(o)}`)))}getListenerStatuses(){return this.http.get(this.baseUrl+"/status/listeners").pipe(Ee(e=>this.log(`Get listener statuses ${JSON.stringify(e)}`)))}saveListeners(e){var r=JSON.stringify(e);return console.log(`After conversion: ${JSON.stringify(r)}`),this.http.put(this.baseUrl+"/config/listeners",r,this.editOptions()).pipe(pe(o=>this.rememberETag(o)),Ee(o=>this.log(`Update Listeners:${e} result ${JSON.stringify(o)}`)))}saveListener(e,i,r){const o=`${this.baseUrl}/config/listeners/${encodeURIComponent(e)}`,s=JSON.stringify(i);return(r?this.http.post(o,s,this.editOptions()):this.http.put(o,s,this.editOptions())).pipe(pe(c=>this.rememberETag(c)),Ee(c=>this.log(`${r?"Add":"Update"} listener ${e} result ${JSON.stringify(c)}`)))}deleteListener(e){return this.http.delete(`${this.baseUrl}/config/listeners/${encodeURIComponent(e)}`,this.editOptions()).pipe(pe(i=>this.rememberETag(i)),Ee(i=>this.log(`Delete listener ${e} result ${JSON.stringify(i)}`)))}saveDNSEntry(e,i,r){const o=`${this.baseUrl}/config/dns/${encodeURIComponent(e)}`,s=JSON.stringify(i);return(r?this.http.post(o,s,this.editOptions()):this.http.put(o,s,this.editOptions())).pipe(pe(c=>this.rememberETag(c)),Ee(c=>this.log(`${r?"Add":"Update"} dns ${e} result ${JSON.stringify(c)}`)))}deleteDNSEntry(e){return this.http.delete(`${this.baseUrl}/config/dns/${encodeURIComponent(e)}`,this.editOptions()).pipe(pe(i=>this.rememberETag(i)),Ee(i=>this.log(`Delete dns ${e} result ${JSON.stringify(i)}`)))}restore(){return this.http.post(this.baseUrl+"/config/reset","").pipe(Ee(e=>this.log(`Reset config result ${e}`)))}restart(){return this.http.post(this.baseUrl+"/config/apply","").pipe(Ee(e=>this.log(`Restart result ${e}`)))}stop(){return this.http.post(this.baseUrl+"/config/stop","").pipe(Ee(e=>this.log(`Stop result ${e}`)))}startListener(e){return this.listenerOperation(e,"start")}stopListener(e){return this.listenerOperation(e,"stop")}restartListener(e){return this.listenerOperation(e,"restart")}listenerOperation(e,i){return this.http.post(`${this.baseUrl}/listeners/${encodeURIComponent(e)}/${i}`,"").pipe(Ee(r=>this.log(`${i} ${e} result ${JSON.stringify(r)}`)))}whoami(){return this.http.get(this.baseUrl+"/whoami").pipe(Ee(e=>this.log(`logged in as ${e.username} (${e.role})`)))}getTokens(){return this.http.get(this.baseUrl+"/tokens").pipe(Ee(e=>this.log(`fetched ${e.length} tokens`)))}createToken(e,i,r){return this.http.post(this.baseUrl+"/tokens",{name:e,role:i,expires_in_secs:r}).pipe(Ee(o=>this.log(`created token ${o.id} (${o.name})`)))}revokeToken(e){return this.http.delete(`${this.baseUrl}/tokens/${e}`).pipe(Ee(i=>this.log(`revoke token ${e} result ${JSON.stringify(i)}`)))}getAudit(e,i){return this.http.get(`${this.baseUrl}/audit?offset=${e}&limit=${i}`).pipe(Ee(r=>this.log(`fetched ${r.records.length} of ${r.total} audit records`)))}getConfigHistory(){return this.http.get(this.baseUrl+"/config/history").pipe(Ee(e=>this.log(`fetched ${e.length} config versions`)))}getConfigDiff(e,i){return this.http.get(`${this.baseUrl}/config/history/diff?from=${e}&to=${i}`).pipe(Ee(r=>this.log(`fetched diff ${e}..${i}: ${JSON.stringify(r.changes)}`)))}rollbackConfig(e){return this.http.post(`${this.baseUrl}/config/history/${e}/rollback`,"").pipe(Ee(i=>this.log(`Rollback to ${e} result ${JSON.stringify(i)}`)))}start(){return this.http.post(this.baseUrl+"/config/start","").pipe(Ee(e=>this.log(`Start result ${JSON.stringify(e)}`)))}events(){return new Ne(e=>{const i=new EventSource(this.baseUrl+"/events"),r=o=>e.next(o);for(const o of["stats","connection_opened","connection_closed","health"])i.addEventListener(o,r);return i.onerror=o=>this.log(`event stream error ${JSON.stringify(o)}`),()=>i.close()})}log(e){console.log(`PFService: ${e}`)}handleError(e="operation",i){return r=>(console.error(r),this.log(`${e} failed: ${r.message}`),z(i))}static#e=this.\u0275fac=function(i){return new(i||t)(b(Nd))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();function bg(...t){const n=Ob(t),{args:e,keys:i}=_E(t),r=new Ne(o=>{const{length:s}=e;if(!s)return void o.complete();const a=new Array(s);let c=s,l=s;for(let d=0;d<s;d++){let u=!1;Ot(e[d]).subscribe(qe(o,h=>{u||(u=!0,l--),a[d]=h},()=>c--,void 0,()=>{(!c||!u)&&(l||o.next(i?bE(i,a):a),o.complete())}))}});return n?r.pipe(Qm(n)):r}const IS=ZH();class fU{constructor(n,e){this._viewportRuler=n,this._previousHTMLStyles={top:"",left:""},this._isEnabled=!1,this._document=e}attach(){}enable(){if(this._canBeEnabled()){const n=this._document.documentElement;this._previousScrollPosition=this._viewportRuler.getViewportScrollPosition(),this._previousHTMLStyles.left=n.style.left||"",this._previousHTMLStyles.top=n.style.top||"",n.style.left=ft(-this._previousScrollPosition.left),n.style.top=ft(-this._previousScrollPosition.top),n.classList.add("cdk-global-scrollblock"),this._isEnabled=!0}}disable(){if(this._isEnabled){const n=this._document.documentElement,i=n.style,r=this._document.body.style,o=i.scrollBehavior||"",s=r.scrollBehavior||"";this._isEnabled=!1,i.left=this._previousHTMLStyles.left,i.top=this._previousHTMLStyles.top,n.classList.remove("cdk-global-scrollblock"),IS&&(i.scrollBehavior=r.scrollBehavior="auto"),window.scroll(this._previousScrollPosition.left,this._previousScrollPosition.top),IS&&(i.scrollBehavior=o,r.scrollBehavior=s)}}_canBeEnabled(){if(this._document.documentElement.classList.contains("cdk-global-scrollblock")||this._isEnabled)return!1;const e=this._document.body,i=this._viewportRuler.getViewportSize();return e.scrollHeight>i.height||e.scrollWidth>i.width}}class pU{constructor(n,e,i,r){this._scrollDispatcher=n,this._ngZone=e,this._viewportRuler=i,this._config=r,this._scrollSubscription=null,this._detach=()=>{this.disable(),this._overlayRef.hasAttached()&&this._ngZone.run(()=>this._overlayRef.detach())}}attach(n){this._overlayRef=n}enable(){if(this._scrollSubscription)return;const n=this._scrollDispatcher.scrolled(0).pipe(Ze(e=>!e||!this._overlayRef.overlayElement.contains(e.getElementRef().nativeElement)));this._config&&this._config.threshold&&this._config.threshold>1?(this._initialScrollPosition=this._viewportRuler.getViewportScrollPosition().top,this._scrollSubscription=n.subscribe(()=>{const e=this._viewportRuler.getViewportScrollPosition().top;Math.abs(e-this._initialScrollPosition)>this._config.threshold?this._detach():this._overlayRef.updatePosition()})):this._scrollSubscription=n.subscribe(this._detach)}disable(){this._scrollSubscription&&(this._scrollSubscription.unsubscribe(),this._scrollSubscription=null)}detach(){this.disable(),this._overlayRef=null}}class TS{enable(){}disable(){}attach(){}}function vg(t,n){return n.some(e=>t.bottom<e.top||t.top>e.bottom||t.right<e.left||t.left>e.right)}function AS(t,n){return n.some(e=>t.top<e.top||t.bottom>e.bottom||t.left<e.left||t.right>e.right)}class mU{constructor(n,e,i,r){this._scrollDispatcher=n,this._viewportRuler=e,this._ngZone=i,this._config=r,this._scrollSubscription=null}attach(n){this._overlayRef=n}enable(){this._scrollSubscription||(this._scrollSubscription=this._scrollDispatcher.scrolled(this._config?this._config.scrollThrottle:0).subscribe(()=>{if(this._overlayRef.updatePosition(),this._config&&this._config.autoClose){const e=this._overlayRef.overlayElement.getBoundingClientRect(),{width:i,height:r}=this._viewportRuler.getViewportSize();vg(e,[{width:i,height:r,bottom:r,right:i,top:0,left:0}])&&(this.disable(),this._ngZone.run(()=>this._overlayRef.detach()))}}))}disable(){this._scrollSubscription&&(this._scrollSubscription.unsubscribe(),this._scrollSubscription=null)}detach(){this.disable(),this._overlayRef=null}}let gU=(()=>{class t{constructor(e,i,r,o){this._scrollDispatcher=e,this._viewportRuler=i,this._ngZone=r,this.noop=()=>new TS,this.close=s=>new pU(this._scrollDispatcher,this._ngZone,this._viewportRuler,s),this.block=()=>new fU(this._viewportRuler,this._document),this.reposition=s=>new mU(this._scrollDispatcher,this._viewportRuler,this._ngZone,s),this._document=o}static#e=this.\u0275fac=function(i){return new(i||t)(b(W3),b(is),b(j),b(Z))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();class yg{constructor(n){if(this.scrollStrategy=new TS,this.panelClass="",this.hasBackdrop=!1,this.backdropClass="cdk-overlay-dark-backdrop",this.disposeOnNavigation=!1,n){const e=Object.keys(n);for(const i of e)void 0!==n[i]&&(this[i]=n[i])}}}class _U{constructor(n,e){this.connectionPair=n,this.scrollableViewProperties=e}}let kS=(()=>{class t{constructor(e){this._attachedOverlays=[],this._document=e}ngOnDestroy(){this.detach()}add(e){this.remove(e),this._attachedOverlays.push(e)}remove(e){const i=this._attachedOverlays.indexOf(e);i>-1&&this._attachedOverlays.splice(i,1),0===this._attachedOverlays.length&&this.detach()}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),bU=(()=>{class t extends kS{constructor(e,i){super(e),this._ngZone=i,this._keydownListener=r=>{const o=this._attachedOverlays;for(let s=o.length-1;s>-1;s--)if(o[s]._keydownEvents.observers.length>0){const a=o[s]._keydownEvents;this._ngZone?this._ngZone.run(()=>a.next(r)):a.next(r);break}}}add(e){super.add(e),this._isAttached||(this._ngZone?this._ngZone.runOutsideAngular(()=>this._document.body.addEventListener("keydown",this._keydownListener)):this._document.body.addEventListener("keydown",this._keydownListener),this._isAttached=!0)}detach(){this._isAttached&&(this._document.body.removeEventListener("keydown",this._keydownListener),this._isAttached=!1)}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z),b(j,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),vU=(()=>{class t extends kS{constructor(e,i,r){super(e),this._platform=i,this._ngZone=r,this._cursorStyleIsSet=!1,this._pointerDownListener=o=>{this._pointerDownEventTarget=tr(o)},this._clickListener=o=>{const s=tr(o),a="click"===o.type&&this._pointerDownEventTarget?this._pointerDownEventTarget:s;this._pointerDownEventTarget=null;const c=this._attachedOverlays.slice();for(let l=c.length-1;l>-1;l--){const d=c[l];if(d._outsidePointerEvents.observers.length<1||!d.hasAttached())continue;if(d.overlayElement.contains(s)||d.overlayElement.contains(a))break;const u=d._outsidePointerEvents;this._ngZone?this._ngZone.run(()=>u.next(o)):u.next(o)}}}add(e){if(super.add(e),!this._isAttached){const i=this._document.body;this._ngZone?this._ngZone.runOutsideAngular(()=>this._addEventListeners(i)):this._addEventListeners(i),this._platform.IOS&&!this._cursorStyleIsSet&&(this._cursorOriginalValue=i.style.cursor,i.style.cursor="pointer",this._cursorStyleIsSet=!0),this._isAttached=!0}}detach(){if(this._isAttached){const e=this._document.body;e.removeEventListener("pointerdown",this._pointerDownListener,!0),e.removeEventListener("click",this._clickListener,!0),e.removeEventListener("auxclick",this._clickListener,!0),e.removeEventListener("contextmenu",this._clickListener,!0),this._platform.IOS&&this._cursorStyleIsSet&&(e.style.cursor=this._cursorOriginalValue,this._cursorStyleIsSet=!1),this._isAttached=!1}}_addEventListeners(e){e.addEventListener("pointerdown",this._pointerDownListener,!0),e.addEventListener("click",this._clickListener,!0),e.addEventListener("auxclick",this._clickListener,!0),e.addEventListener("contextmenu",this._clickListener,!0)}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z),b(tt),b(j,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),Ld=(()=>{class t{constructor(e,i){this._platform=i,this._document=e}ngOnDestroy(){this._containerElement?.remove()}getContainerElement(){return this._containerElement||this._createContainer(),this._containerElement}_createContainer(){const e="cdk-overlay-container";if(this._platform.isBrowser||Hm()){const r=this._document.querySelectorAll(`.${e}[platform="server"], .${e}[platform="test"]`);for(let o=0;o<r.length;o++)r[o].remove()}const i=this._document.createElement("div");i.classList.add(e),Hm()?i.setAttribute("platform","test"):this._platform.isBrowser||i.setAttribute("platform","server"),this._document.body.appendChild(i),this._containerElement=i}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z),b(tt))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();class $a{constructor(n,e,i,r,o,s,a,c,l,d=!1){this._portalOutlet=n,this._host=e,this._pane=i,this._config=r,this._ngZone=o,this._keyboardDispatcher=s,this._document=a,this._location=c,this._outsideClickDispatcher=l,this._animationsDisabled=d,this._backdropElement=null,this._backdropClick=new U,this._attachments=new U,this._detachments=new U,this._locationChanges=Le.EMPTY,this._backdropClickHandler=u=>this._backdropClick.next(u),this._backdropTransitionendHandler=u=>{this._disposeBackdrop(u.target)},this._keydownEvents=new U,this._outsidePointerEvents=new U,r.scrollStrategy&&(this._scrollStrategy=r.scrollStrategy,this._scrollStrategy.attach(this)),this._positionStrategy=r.positionStrategy}get overlayElement(){return this._pane}get backdropElement(){return this._backdropElement}get hostElement(){return this._host}attach(n){!this._host.parentElement&&this._previousHostParent&&this._previousHostParent.appendChild(this._host);const e=this._portalOutlet.attach(n);return this._positionStrategy&&this._positionStrategy.attach(this),this._updateStackingOrder(),this._updateElementSize(),this._updateElementDirection(),this._scrollStrategy&&this._scrollStrategy.enable(),this._ngZone.onStable.pipe(vt(1)).subscribe(()=>{this.hasAttached()&&this.updatePosition()}),this._togglePointerEvents(!0),this._config.hasBackdrop&&this._attachBackdrop(),this._config.panelClass&&this._toggleClasses(this._pane,this._config.panelClass,!0),this._attachments.next(),this._keyboardDispatcher.add(this),this._config.disposeOnNavigation&&(this._locationChanges=this._location.subscribe(()=>this.dispose())),this._outsideClickDispatcher.add(this),"function"==typeof e?.onDestroy&&e.onDestroy(()=>{this.hasAttached()&&this._ngZone.runOutsideAngular(()=>Promise.resolve().then(()=>this.detach()))}),e}detach(){if(!this.hasAttached())return;this.detachBackdrop(),this._togglePointerEvents(!1),this._positionStrategy&&this._positionStrategy.detach&&this._positionStrategy.detach(),this._scrollStrategy&&this._scrollStrategy.disable();const n=this._portalOutlet.detach();return this._detachments.next(),this._keyboardDispatcher.remove(this),this._detachContentWhenStable(),this._locationChanges.unsubscribe(),this._outsideClickDispatcher.remove(this),n}dispose(){const n=this.hasAttached();this._positionStrategy&&this._positionStrategy.dispose(),this._disposeScrollStrategy(),this._disposeBackdrop(this._backdropElement),this._locationChanges.unsubscribe(),this._keyboardDispatcher.remove(this),this._portalOutlet.dispose(),this._attachments.complete(),this._backdropClick.complete(),this._keydownEvents.complete(),this._outsidePointerEvents.complete(),this._outsideClickDispatcher.remove(this),this._host?.remove(),this._previousHostParent=this._pane=this._host=null,n&&this._detachments.next(),this._detachments.complete()}hasAttached(){return this._portalOutlet.hasAttached()}backdropClick(){return this._backdropClick}attachments(){return this._attachments}detachments(){return this._detachments}keydownEvents(){return this._keydownEvents}outsidePointerEvents(){return this._outsidePointerEvents}getConfig(){return this._config}updatePosition(){this._positionStrategy&&this._positionStrategy.apply()}updatePositionStrategy(n){n!==this._positionStrategy&&(this._positionStrategy&&this._positionStrategy.dispose(),this._positionStrategy=n,this.hasAttached()&&(n.attach(this),this.updatePosition()))}updateSize(n){this._config={...this._config,...n},this._updateElementSize()}setDirection(n){this._config={...this._config,direction:n},this._updateElementDirection()}addPanelClass(n){this._pane&&this._toggleClasses(this._pane,n,!0)}removePanelClass(n){this._pane&&this._toggleClasses(this._pane,n,!1)}getDirection(){const n=this._config.direction;return n?"string"==typeof n?n:n.value:"ltr"}updateScrollStrategy(n){n!==this._scrollStrategy&&(this._disposeScrollStrategy(),this._scrollStrategy=n,this.hasAttached()&&(n.attach(this),n.enable()))}_updateElementDirection(){this._host.setAttribute("dir",this.getDirection())}_updateElementSize(){if(!this._pane)return;const n=this._pane.style;n.width=ft(this._config.width),n.height=ft(this._config.height),n.minWidth=ft(this._config.minWidth),n.minHeight=ft(this._config.minHeight),n.maxWidth=ft(this._config.maxWidth),n.maxHeight=ft(this._config.maxHeight)}_togglePointerEvents(n){this._pane.style.pointerEvents=n?"":"none"}_attachBackdrop(){const n="cdk-overlay-backdrop-showing";this._backdropElement=this._document.createElement("div"),this._backdropElement.classList.add("cdk-overlay-backdrop"),this._animationsDisabled&&this._backdropElement.classList.add("cdk-overlay-backdrop-noop-animation"),this._config.backdropClass&&this._toggleClasses(this._backdropElement,this._config.backdropClass,!0),this._host.parentElement.insertBefore(this._backdropElement,this._host),this._backdropElement.addEventListener("click",this._backdropClickHandler),!this._animationsDisabled&&typeof requestAnimationFrame<"u"?this._ngZone.runOutsideAngular(()=>{requestAnimationFrame(()=>{this._backdropElement&&this._backdropElement.classList.add(n)})}):this._backdropElement.classList.add(n)}_updateStackingOrder(){this._host.nextSibling&&this._host.parentNode.appendChild(this._host)}detachBackdrop(){const n=this._backdropElement;if(n){if(this._animationsDisabled)return void this._disposeBackdrop(n);n.classList.remove("cdk-overlay-backdrop-showing"),this._ngZone.runOutsideAngular(()=>{n.addEventListener("transitionend",this._backdropTransitionendHandler)}),n.style.pointerEvents="none",this._backdropTimeout=this._ngZone.runOutsideAngular(()=>setTimeout(()=>{this._disposeBackdrop(n)},500))}}_toggleClasses(n,e,i){const r=bd(e||[]).filter(o=>!!o);r.length&&(i?n.classList.add(...r):n.classList.remove(...r))}_detachContentWhenStable(){this._ngZone.runOutsideAngular(()=>{const n=this._ngZone.onStable.pipe(Re(An(this._attachments,this._detachments))).subscribe(()=>{(!this._pane||!this._host||0===this._pane.children.length)&&(this._pane&&this._config.panelClass&&this._toggleClasses(this._pane,this._config.panelClass,!1),this._host&&this._host.parentElement&&(this._previousHostParent=this._host.parentElement,this._host.remove()),n.unsubscribe())})})}_disposeScrollStrategy(){const n=this._scrollStrategy;n&&(n.disable(),n.detach&&n.detach())}_disposeBackdrop(n){n&&(n.removeEventListener("click",this._backdropClickHandler),n.removeEventListener("transitionend",this._backdropTransitionendHandler),n.remove(),this._backdropElement===n&&(this._backdropElement=null)),this._backdropTimeout&&(clearTimeout(this._backdropTimeout),this._backdropTimeout=void 0)}}const RS="cdk-overlay-connected-position-bounding-box",yU=/([A-Za-z%]+)$/;class wU{get positions(){return this._preferredPositions}constructor(n,e,i,r,o){this._viewportRuler=e,this._document=i,this._platform=r,this._overlayContainer=o,this._lastBoundingBoxSize={width:0,height:0},this._isPushed=!1,this._canPush=!0,this._growAfterOpen=!1,this._hasFlexibleDimensions=!0,this._positionLocked=!1,this._viewportMargin=0,this._scrollables=[],this._preferredPositions=[],this._positionChanges=new U,this._resizeSubscription=Le.EMPTY,this._offsetX=0,this._offsetY=0,this._appliedPanelClasses=[],this.positionChanges=this._positionChanges,this.setOrigin(n)}attach(n){this._validatePositions(),n.hostElement.classList.add(RS),this._overlayRef=n,this._boundingBox=n.hostElement,this._pane=n.overlayElement,this._isDisposed=!1,this._isInitialRender=!0,this._lastPosition=null,this._resizeSubscription.unsubscribe(),this._resizeSubscription=this._viewportRuler.change().subscribe(()=>{this._isInitialRender=!0,this.apply()})}apply(){if(this._isDisposed||!this._platform.isBrowser)return;if(!this._isInitialRender&&this._positionLocked&&this._lastPosition)return void this.reapplyLastPosition();this._clearPanelClasses(),this._resetOverlayElementStyles(),this._resetBoundingBoxStyles(),this._viewportRect=this._getNarrowedViewportRect(),this._originRect=this._getOriginRect(),this._overlayRect=this._pane.getBoundingClientRect(),this._containerRect=this._overlayContainer.getContainerElement().getBoundingClientRect();const n=this._originRect,e=this._overlayRect,i=this._viewportRect,r=this._containerRect,o=[];let s;for(let a of this._preferredPositions){let c=this._getOriginPoint(n,r,a),l=this._getOverlayPoint(c,e,a),d=this._getOverlayFit(l,e,i,a);if(d.isCompletelyWithinViewport)return this._isPushed=!1,void this._applyPosition(a,c);this._canFitWithFlexibleDimensions(d,l,i)?o.push({position:a,origin:c,overlayRect:e,boundingBoxRect:this._calculateBoundingBoxRect(c,a)}):(!s||s.overlayFit.visibleArea<d.visibleArea)&&(s={overlayFit:d,overlayPoint:l,originPoint:c,position:a,overlayRect:e})}if(o.length){let a=null,c=-1;for(const l of o){const d=l.boundingBoxRect.width*l.boundingBoxRect.height*(l.position.weight||1);d>c&&(c=d,a=l)}return this._isPushed=!1,void this._applyPosition(a.position,a.origin)}if(this._canPush)return this._isPushed=!0,void this._applyPosition(s.position,s.originPoint);this._applyPosition(s.position,s.originPoint)}detach(){this._clearPanelClasses(),this._lastPosition=null,this._previousPushAmount=null,this._resizeSubscription.unsubscribe()}dispose(){this._isDisposed||(this._boundingBox&&zr(this._boundingBox.style,{top:"",left:"",right:"",bottom:"",height:"",width:"",alignItems:"",justifyContent:""}),this._pane&&this._resetOverlayElementStyles(),this._overlayRef&&this._overlayRef.hostElement.classList.remove(RS),this.detach(),this._positionChanges.complete(),this._overlayRef=this._boundingBox=null,this._isDisposed=!0)}reapplyLastPosition(){if(this._isDisposed||!this._platform.isBrowser)return;const n=this._lastPosition;if(n){this._originRect=this._getOriginRect(),this._overlayRect=this._pane.getBoundingClientRect(),this._viewportRect=this._getNarrowedViewportRect(),this._containerRect=this._overlayContainer.getContainerElement().getBoundingClientRect();const e=this._getOriginPoint(this._originRect,this._containerRect,n);this._applyPosition(n,e)}else this.apply()}withScrollableContainers(n){return this._scrollables=n,this}withPositions(n){return this._preferredPositions=n,-1===n.indexOf(this._lastPosition)&&(this._lastPosition=null),this._validatePositions(),this}withViewportMargin(n){return this._viewportMargin=n,this}withFlexibleDimensions(n=!0){return this._hasFlexibleDimensions=n,this}withGrowAfterOpen(n=!0){return this._growAfterOpen=n,this}withPush(n=!0){return this._canPush=n,this}withLockedPosition(n=!0){return this._positionLocked=n,this}setOrigin(n){return this._origin=n,this}withDefaultOffsetX(n){return this._offsetX=n,this}withDefaultOffsetY(n){return this._offsetY=n,this}withTransformOriginOn(n){return this._transformOriginSelector=n,this}_getOriginPoint(n,e,i){let r,o;if("center"==i.originX)r=n.left+n.width/2;else{const s=this._isRtl()?n.right:n.left,a=this._isRtl()?n.left:n.right;r="start"==i.originX?s:a}return e.left<0&&(r-=e.left),o="center"==i.originY?n.top+n.height/2:"top"==i.originY?n.top:n.bottom,e.top<0&&(o-=e.top),{x:r,y:o}}_getOverlayPoint(n,e,i){let r,o;return r="center"==i.overlayX?-e.width/2:"start"===i.overlayX?this._isRtl()?-e.width:0:this._isRtl()?0:-e.width,o="center"==i.overlayY?-e.height/2:"top"==i.overlayY?0:-e.height,{x:n.x+r,y:n.y+o}}_getOverlayFit(n,e,i,r){const o=FS(e);let{x:s,y:a}=n,c=this._getOffset(r,"x"),l=this._getOffset(r,"y");c&&(s+=c),l&&(a+=l);let h=0-a,p=a+o.height-i.height,m=this._subtractOverflows(o.width,0-s,s+o.width-i.width),g=this._subtractOverflows(o.height,h,p),_=m*g;return{visibleArea:_,isCompletelyWithinViewport:o.width*o.height===_,fitsInViewportVertically:g===o.height,fitsInViewportHorizontally:m==o.width}}_canFitWithFlexibleDimensions(n,e,i){if(this._hasFlexibleDimensions){const r=i.bottom-e.y,o=i.right-e.x,s=OS(this._overlayRef.getConfig().minHeight),a=OS(this._overlayRef.getConfig().minWidth);return(n.fitsInViewportVertically||null!=s&&s<=r)&&(n.fitsInViewportHorizontally||null!=a&&a<=o)}return!1}_pushOverlayOnScreen(n,e,i){if(this._previousPushAmount&&this._positionLocked)return{x:n.x+this._previousPushAmount.x,y:n.y+this._previousPushAmount.y};const r=FS(e),o=this._viewportRect,s=Math.max(n.x+r.width-o.width,0),a=Math.max(n.y+r.height-o.height,0),c=Math.max(o.top-i.top-n.y,0),l=Math.max(o.left-i.left-n.x,0);let d=0,u=0;return d=r.width<=o.width?l||-s:n.x<this._viewportMargin?o.left-i.left-n.x:0,u=r.height<=o.height?c||-a:n.y<this._viewportMargin?o.top-i.top-n.y:0,this._previousPushAmount={x:d,y:u},{x:n.x+d,y:n.y+u}}_applyPosition(n,e){if(this._setTransformOrigin(n),this._setOverlayElementStyles(e,n),this._setBoundingBoxStyles(e,n),n.panelClass&&this._addPanelClasses(n.panelClass),this._lastPosition=n,this._positionChanges.observers.length){const i=this._getScrollVisibility(),r=new _U(n,i);this._positionChanges.next(r)}this._isInitialRender=!1}_setTransformOrigin(n){if(!this._transformOriginSelector)return;const e=this._boundingBox.querySelectorAll(this._transformOriginSelector);let i,r=n.overlayY;i="center"===n.overlayX?"center":this._isRtl()?"start"===n.overlayX?"right":"left":"start"===n.overlayX?"left":"right";for(let o=0;o<e.length;o++)e[o].style.transformOrigin=`${i} ${r}`}_calculateBoundingBoxRect(n,e){const i=this._viewportRect,r=this._isRtl();let o,s,a,d,u,h;if("top"===e.overlayY)s=n.y,o=i.height-s+this._viewportMargin;else if("bottom"===e.overlayY)a=i.height-n.y+2*this._viewportMargin,o=i.height-a+this._viewportMargin;else{const p=Math.min(i.bottom-n.y+i.top,n.y),m=this._lastBoundingBoxSize.height;o=2*p,s=n.y-p,o>m&&!this._isInitialRender&&!this._growAfterOpen&&(s=n.y-m/2)}if("end"===e.overlayX&&!r||"start"===e.overlayX&&r)h=i.width-n.x+this._viewportMargin,d=n.x-this._viewportMargin;else if("start"===e.overlayX&&!r||"end"===e.overlayX&&r)u=n.x,d=i.right-n.x;else{const p=Math.min(i.right-n.x+i.left,n.x),m=this._lastBoundingBoxSize.width;d=2*p,u=n.x-p,d>m&&!this._isInitialRender&&!this._growAfterOpen&&(u=n.x-m/2)}return{top:s,left:u,bottom:a,right:h,width:d,height:o}}_setBoundingBoxStyles(n,e){const i=this._calculateBoundingBoxRect(n,e);!this._isInitialRender&&!this._growAfterOpen&&(i.height=Math.min(i.height,this._lastBoundingBoxSize.height),i.width=Math.min(i.width,this._lastBoundingBoxSize.width));const r={};if(this._hasExactPosition())r.top=r.left="0",r.bottom=r.right=r.maxHeight=r.maxWidth="",r.width=r.height="100%";else{const o=this._overlayRef.getConfig().maxHeight,s=this._overlayRef.getConfig().maxWidth;r.height=ft(i.height),r.top=ft(i.top),r.bottom=ft(i.bottom),r.width=ft(i.width),r.left=ft(i.left),r.right=ft(i.right),r.alignItems="center"===e.overlayX?"center":"end"===e.overlayX?"flex-end":"flex-start",r.justifyContent="center"===e.overlayY?"center":"bottom"===e.overlayY?"flex-end":"flex-start",o&&(r.maxHeight=ft(o)),s&&(r.maxWidth=ft(s))}this._lastBoundingBoxSize=i,zr(this._boundingBox.style,r)}_resetBoundingBoxStyles(){zr(this._boundingBox.style,{top:"0",left:"0",right:"0",bottom:"0",height:"",width:"",alignItems:"",justifyContent:""})}_resetOverlayElementStyles(){zr(this._pane.style,{top:"",left:"",bottom:"",right:"",position:"",transform:""})}_setOverlayElementStyles(n,e){const i={},r=this._hasExactPosition(),o=this._hasFlexibleDimensions,s=this._overlayRef.getConfig();if(r){const d=this._viewportRuler.getViewportScrollPosition();zr(i,this._getExactOverlayY(e,n,d)),zr(i,this._getExactOverlayX(e,n,d))}else i.position="static";let a="",c=this._getOffset(e,"x"),l=this._getOffset(e,"y");c&&(a+=`translateX(${c}px) `),l&&(a+=`translateY(${l}px)`),i.transform=a.trim(),s.maxHeight&&(r?i.maxHeight=ft(s.maxHeight):o&&(i.maxHeight="")),s.maxWidth&&(r?i.maxWidth=ft(s.maxWidth):o&&(i.maxWidth="")),zr(this._pane.style,i)}_getExactOverlayY(n,e,i){let r={top:"",bottom:""},o=this._getOverlayPoint(e,this._overlayRect,n);return this._isPushed&&(o=this._pushOverlayOnScreen(o,this._overlayRect,i)),"bottom"===n.overlayY?r.bottom=this._document.documentElement.clientHeight-(o.y+this._overlayRect.height)+"px":r.top=ft(o.y),r}_getExactOverlayX(n,e,i){let s,r={left:"",right:""},o=this._getOverlayPoint(e,this._overlayRect,n);return this._isPushed&&(o=this._pushOverlayOnScreen(o,this._overlayRect,i)),s=this._isRtl()?"end"===n.overlayX?"left":"right":"end"===n.overlayX?"right":"left","right"===s?r.right=this._document.documentElement.clientWidth-(o.x+this._overlayRect.width)+"px":r.left=ft(o.x),r}_getScrollVisibility(){const n=this._getOriginRect(),e=this._pane.getBoundingClientRect(),i=this._scrollables.map(r=>r.getElementRef().nativeElement.getBoundingClientRect());return{isOriginClipped:AS(n,i),isOriginOutsideView:vg(n,i),isOverlayClipped:AS(e,i),isOverlayOutsideView:vg(e,i)}}_subtractOverflows(n,...e){return e.reduce((i,r)=>i-Math.max(r,0),n)}_getNarrowedViewportRect(){const n=this._document.documentElement.clientWidth,e=this._document.documentElement.clientHeight,i=this._viewportRuler.getViewportScrollPosition();return{top:i.top+this._viewportMargin,left:i.left+this._viewportMargin,right:i.left+n-this._viewportMargin,bottom:i.top+e-this._viewportMargin,width:n-2*this._viewportMargin,height:e-2*this._viewportMargin}}_isRtl(){return"rtl"===this._overlayRef.getDirection()}_hasExactPosition(){return!this._hasFlexibleDimensions||this._isPushed}_getOffset(n,e){return"x"===e?null==n.offsetX?this._offsetX:n.offsetX:null==n.offsetY?this._offsetY:n.offsetY}_validatePositions(){}_addPanelClasses(n){this._pane&&bd(n).forEach(e=>{""!==e&&-1===this._appliedPanelClasses.indexOf(e)&&(this._appliedPanelClasses.push(e),this._pane.classList.add(e))})}_clearPanelClasses(){this._pane&&(this._appliedPanelClasses.forEach(n=>{this._pane.classList.remove(n)}),this._appliedPanelClasses=[])}_getOriginRect(){const n=this._origin;if(n instanceof $)return n.nativeElement.getBoundingClientRect();if(n instanceof Element)return n.getBoundingClientRect();const e=n.width||0,i=n.height||0;return{top:n.y,bottom:n.y+i,left:n.x,right:n.x+e,height:i,width:e}}}function zr(t,n){for(let e in n)n.hasOwnProperty(e)&&(t[e]=n[e]);return t}function OS(t){if("number"!=typeof t&&null!=t){const[n,e]=t.split(yU);return e&&"px"!==e?null:parseFloat(n)}return t||null}function FS(t){return{top:Math.floor(t.top),right:Math.floor(t.right),bottom:Math.floor(t.bottom),left:Math.floor(t.left),width:Math.floor(t.width),height:Math.floor(t.height)}}const NS="cdk-global-overlay-wrapper";class xU{constructor(){this._cssPosition="static",this._topOffset="",this._bottomOffset="",this._alignItems="",this._xPosition="",this._xOffset="",this._width="",this._height="",this._isDisposed=!1}attach(n){const e=n.getConfig();this._overlayRef=n,this._width&&!e.width&&n.updateSize({width:this._width}),this._height&&!e.height&&n.updateSize({height:this._height}),n.hostElement.classList.add(NS),this._isDisposed=!1}top(n=""){return this._bottomOffset="",this._topOffset=n,this._alignItems="flex-start",this}left(n=""){return this._xOffset=n,this._xPosition="left",this}bottom(n=""){return this._topOffset="",this._bottomOffset=n,this._alignItems="flex-end",this}right(n=""){return this._xOffset=n,this._xPosition="right",this}start(n=""){return this._xOffset=n,this._xPosition="start",this}end(n=""){return this._xOffset=n,this._xPosition="end",this}width(n=""){return this._overlayRef?this._overlayRef.updateSize({width:n}):this._width=n,this}height(n=""){return this._overlayRef?this._overlayRef.updateSize({height:n}):this._height=n,this}centerHorizontally(n=""){return this.left(n),this._xPosition="center",this}centerVertically(n=""){return this.top(n),this._alignItems="center",this}apply(){if(!this._overlayRef||!this._overlayRef.hasAttached())return;const n=this._overlayRef.overlayElement.style,e=this._overlayRef.hostElement.style,i=this._overlayRef.getConfig(),{width:r,height:o,maxWidth:s,maxHeight:a}=i,c=!("100%"!==r&&"100vw"!==r||s&&"100%"!==s&&"100vw"!==s),l=!("100%"!==o&&"100vh"!==o||a&&"100%"!==a&&"100vh"!==a),d=this._xPosition,u=this._xOffset,h="rtl"===this._overlayRef.getConfig().direction;let p="",m="",g="";c?g="flex-start":"center"===d?(g="center",h?m=u:p=u):h?"left"===d||"end"===d?(g="flex-end",p=u):("right"===d||"start"===d)&&(g="flex-start",m=u):"left"===d||"start"===d?(g="flex-start",p=u):("right"===d||"end"===d)&&(g="flex-end",m=u),n.position=this._cssPosition,n.marginLeft=c?"0":p,n.marginTop=l?"0":this._topOffset,n.marginBottom=this._bottomOffset,n.marginRight=c?"0":m,e.justifyContent=g,e.alignItems=l?"flex-start":this._alignItems}dispose(){if(this._isDisposed||!this._overlayRef)return;const n=this._overlayRef.overlayElement.style,e=this._overlayRef.hostElement,i=e.style;e.classList.remove(NS),i.justifyContent=i.alignItems=n.marginTop=n.marginBottom=n.marginLeft=n.marginRight=n.position="",this._overlayRef=null,this._isDisposed=!0}}let CU=(()=>{class t{constructor(e,i,r,o){this._viewportRuler=e,this._document=i,this._platform=r,this._overlayContainer=o}global(){return new xU}flexibleConnectedTo(e){return new wU(e,this._viewportRuler,this._document,this._platform,this._overlayContainer)}static#e=this.\u0275fac=function(i){return new(i||t)(b(is),b(Z),b(tt),b(Ld))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),DU=0,Oi=(()=>{class t{constructor(e,i,r,o,s,a,c,l,d,u,h,p){this.scrollStrategies=e,this._overlayContainer=i,this._componentFactoryResolver=r,this._positionBuilder=o,this._keyboardDispatcher=s,this._injector=a,this._ngZone=c,this._document=l,this._directionality=d,this._location=u,this._outsideClickDispatcher=h,this._animationsModuleType=p}create(e){const i=this._createHostElement(),r=this._createPaneElement(i),o=this._createPortalOutlet(r),s=new yg(e);return s.direction=s.direction||this._directionality.value,new $a(o,i,r,s,this._ngZone,this._keyboardDispatcher,this._document,this._location,this._outsideClickDispatcher,"NoopAnimations"===this._animationsModuleType)}position(){return this._positionBuilder}_createPaneElement(e){const i=this._document.createElement("div");return i.id="cdk-overlay-"+DU++,i.classList.add("cdk-overlay-pane"),e.appendChild(i),i}_createHostElement(){const e=this._document.createElement("div");return this._overlayContainer.getContainerElement().appendChild(e),e}_createPortalOutlet(e){return this._appRef||(this._appRef=this._injector.get(Xi)),new D3(e,this._componentFactoryResolver,this._appRef,this._injector,this._document)}static#e=this.\u0275fac=function(i){return new(i||t)(b(gU),b(Ld),b(Sr),b(CU),b(bU),b(We),b(j),b(Z),b(En),b(Ma),b(vU),b(gt,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();const MU={provide:new E("cdk-connected-overlay-scroll-strategy"),deps:[Oi],useFactory:function SU(t){return()=>t.scrollStrategies.reposition()}};let Bd=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({providers:[Oi,MU],imports:[Na,Vr,hg,hg]})}return t})();function Vd(t){return new Ne(n=>{Ot(t()).subscribe(n)})}function IU(t,n){}class jd{constructor(){this.role="dialog",this.panelClass="",this.hasBackdrop=!0,this.backdropClass="",this.disableClose=!1,this.width="",this.height="",this.data=null,this.ariaDescribedBy=null,this.ariaLabelledBy=null,this.ariaLabel=null,this.ariaModal=!0,this.autoFocus="first-tabbable",this.restoreFocus=!0,this.closeOnNavigation=!0,this.closeOnDestroy=!0,this.closeOnOverlayDetachments=!0}}let PS=(()=>{class t extends Td{constructor(e,i,r,o,s,a,c,l){super(),this._elementRef=e,this._focusTrapFactory=i,this._config=o,this._interactivityChecker=s,this._ngZone=a,this._overlayRef=c,this._focusMonitor=l,this._elementFocusedBeforeDialogWasOpened=null,this._closeInteractionType=null,this._ariaLabelledByQueue=[],this.attachDomPortal=d=>{this._portalOutlet.hasAttached();const u=this._portalOutlet.attachDomPortal(d);return this._contentAttached(),u},this._document=r,this._config.ariaLabelledBy&&this._ariaLabelledByQueue.push(this._config.ariaLabelledBy)}_contentAttached(){this._initializeFocusTrap(),this._handleBackdropClicks(),this._captureInitialFocus()}_captureInitialFocus(){this._trapFocus()}ngOnDestroy(){this._restoreFocus()}attachComponentPortal(e){this._portalOutlet.hasAttached();const i=this._portalOutlet.attachComponentPortal(e);return this._contentAttached(),i}attachTemplatePortal(e){this._portalOutlet.hasAttached();const i=this._portalOutlet.attachTemplatePortal(e);return this._contentAttached(),i}_recaptureFocus(){this._containsFocus()||this._trapFocus()}_forceFocus(e,i){this._interactivityChecker.isFocusable(e)||(e.tabIndex=-1,this._ngZone.runOutsideAngular(()=>{const r=()=>{e.removeEventListener("blur",r),e.removeEventListener("mousedown",r),e.removeAttribute("tabindex")};e.addEventListener("blur",r),e.addEventListener("mousedown",r)})),e.focus(i)}_focusByCssSelector(e,i){let r=this._elementRef.nativeElement.querySelector(e);r&&this._forceFocus(r,i)}_trapFocus(){const e=this._elementRef.nativeElement;switch(this._config.autoFocus){case!1:case"dialog":this._containsFocus()||e.focus();break;case!0:case"first-tabbable":this._focusTrap.focusInitialElementWhenReady().then(i=>{i||this._focusDialogContainer()});break;case"first-heading":this._focusByCssSelector('h1, h2, h3, h4, h5, h6, [role="heading"]');break;default:this._focusByCssSelector(this._config.autoFocus)}}_restoreFocus(){const e=this._config.restoreFocus;let i=null;if("string"==typeof e?i=this._document.querySelector(e):"boolean"==typeof e?i=e?this._elementFocusedBeforeDialogWasOpened:null:e&&(i=e),this._config.restoreFocus&&i&&"function"==typeof i.focus){const r=jm(),o=this._elementRef.nativeElement;(!r||r===this._document.body||r===o||o.contains(r))&&(this._focusMonitor?(this._focusMonitor.focusVia(i,this._closeInteractionType),this._closeInteractionType=null):i.focus())}this._focusTrap&&this._focusTrap.destroy()}_focusDialogContainer(){this._elementRef.nativeElement.focus&&this._elementRef.nativeElement.focus()}_containsFocus(){const e=this._elementRef.nativeElement,i=jm();return e===i||e.contains(i)}_initializeFocusTrap(){this._focusTrap=this._focusTrapFactory.create(this._elementRef.nativeElement),this._document&&(this._elementFocusedBeforeDialogWasOpened=jm())}_handleBackdropClicks(){this._overlayRef.backdropClick().subscribe(()=>{this._config.disableClose&&this._recaptureFocus()})}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(Jm),f(Z,8),f(jd),f(wd),f(j),f($a),f(nr))};static#t=this.\u0275cmp=oe({type:t,selectors:[["cdk-dialog-container"]],viewQuery:function(i,r){if(1&i&&Se(Ai,7),2&i){let o;B(o=V())&&(r._portalOutlet=o.first)}},hostAttrs:["tabindex","-1",1,"cdk-dialog-container"],hostVars:6,hostBindings:function(i,r){2&i&&ue("id",r._config.id||null)("role",r._config.role)("aria-modal",r._config.ariaModal)("aria-labelledby",r._config.ariaLabel?null:r._ariaLabelledByQueue[0])("aria-label",r._config.ariaLabel)("aria-describedby",r._config.ariaDescribedBy||null)},features:[k],decls:1,vars:0,consts:[["cdkPortalOutlet",""]],template:function(i,r){1&i&&O(0,IU,0,0,"ng-template",0)},dependencies:[Ai],styles:[".cdk-dialog-container{display:block;width:100%;height:100%;min-height:inherit;max-height:inherit}"],encapsulation:2})}return t})();class wg{constructor(n,e){this.overlayRef=n,this.config=e,this.closed=new U,this.disableClose=e.disableClose,this.backdropClick=n.backdropClick(),this.keydownEvents=n.keydownEvents(),this.outsidePointerEvents=n.outsidePointerEvents(),this.id=e.id,this.keydownEvents.subscribe(i=>{27===i.keyCode&&!this.disableClose&&!Lr(i)&&(i.preventDefault(),this.close(void 0,{focusOrigin:"keyboard"}))}),this.backdropClick.subscribe(()=>{this.disableClose||this.close(void 0,{focusOrigin:"mouse"})}),this._detachSubscription=n.detachments().subscribe(()=>{!1!==e.closeOnOverlayDetachments&&this.close()})}close(n,e){if(this.containerInstance){const i=this.closed;this.containerInstance._closeInteractionType=e?.focusOrigin||"program",this._detachSubscription.unsubscribe(),this.overlayRef.dispose(),i.next(n),i.complete(),this.componentInstance=this.containerInstance=null}}updatePosition(){return this.overlayRef.updatePosition(),this}updateSize(n="",e=""){return this.overlayRef.updateSize({width:n,height:e}),this}addPanelClass(n){return this.overlayRef.addPanelClass(n),this}removePanelClass(n){return this.overlayRef.removePanelClass(n),this}}const LS=new E("DialogScrollStrategy"),TU=new E("DialogData"),AU=new E("DefaultDialogConfig"),RU={provide:LS,deps:[Oi],useFactory:function kU(t){return()=>t.scrollStrategies.block()}};let OU=0,BS=(()=>{class t{get openDialogs(){return this._parentDialog?this._parentDialog.openDialogs:this._openDialogsAtThisLevel}get afterOpened(){return this._parentDialog?this._parentDialog.afterOpened:this._afterOpenedAtThisLevel}constructor(e,i,r,o,s,a){this._overlay=e,this._injector=i,this._defaultOptions=r,this._parentDialog=o,this._overlayContainer=s,this._openDialogsAtThisLevel=[],this._afterAllClosedAtThisLevel=new U,this._afterOpenedAtThisLevel=new U,this._ariaHiddenElements=new Map,this.afterAllClosed=Vd(()=>this.openDialogs.length?this._getAfterAllClosed():this._getAfterAllClosed().pipe(Dn(void 0))),this._scrollStrategy=a}open(e,i){(i={...this._defaultOptions||new jd,...i}).id=i.id||"cdk-dialog-"+OU++,i.id&&this.getDialogById(i.id);const o=this._getOverlayConfig(i),s=this._overlay.create(o),a=new wg(s,i),c=this._attachContainer(s,a,i);return a.containerInstance=c,this._attachDialogContent(e,a,c,i),this.openDialogs.length||this._hideNonDialogContentFromAssistiveTechnology(),this.openDialogs.push(a),a.closed.subscribe(()=>this._removeOpenDialog(a,!0)),this.afterOpened.next(a),a}closeAll(){xg(this.openDialogs,e=>e.close())}getDialogById(e){return this.openDialogs.find(i=>i.id===e)}ngOnDestroy(){xg(this._openDialogsAtThisLevel,e=>{!1===e.config.closeOnDestroy&&this._removeOpenDialog(e,!1)}),xg(this._openDialogsAtThisLevel,e=>e.close()),this._afterAllClosedAtThisLevel.complete(),this._afterOpenedAtThisLevel.complete(),this._openDialogsAtThisLevel=[]}_getOverlayConfig(e){const i=new yg({positionStrategy:e.positionStrategy||this._overlay.position().global().centerHorizontally().centerVertically(),scrollStrategy:e.scrollStrategy||this._scrollStrategy(),panelClass:e.panelClass,hasBackdrop:e.hasBackdrop,direction:e.direction,minWidth:e.minWidth,minHeight:e.minHeight,maxWidth:e.maxWidth,maxHeight:e.maxHeight,width:e.width,height:e.height,disposeOnNavigation:e.closeOnNavigation});return e.backdropClass&&(i.backdropClass=e.backdropClass),i}_attachContainer(e,i,r){const o=r.injector||r.viewContainerRef?.injector,s=[{provide:jd,useValue:r},{provide:wg,useValue:i},{provide:$a,useValue:e}];let a;r.container?"function"==typeof r.container?a=r.container:(a=r.container.type,s.push(...r.container.providers(r))):a=PS;const c=new La(a,r.viewContainerRef,We.create({parent:o||this._injector,providers:s}),r.componentFactoryResolver);return e.attach(c).instance}_attachDialogContent(e,i,r,o){if(e instanceof Ue){const s=this._createInjector(o,i,r,void 0);let a={$implicit:o.data,dialogRef:i};o.templateContext&&(a={...a,..."function"==typeof o.templateContext?o.templateContext():o.templateContext}),r.attachTemplatePortal(new ts(e,null,a,s))}else{const s=this._createInjector(o,i,r,this._injector),a=r.attachComponentPortal(new La(e,o.viewContainerRef,s,o.componentFactoryResolver));i.componentRef=a,i.componentInstance=a.instance}}_createInjector(e,i,r,o){const s=e.injector||e.viewContainerRef?.injector,a=[{provide:TU,useValue:e.data},{provide:wg,useValue:i}];return e.providers&&("function"==typeof e.providers?a.push(...e.providers(i,e,r)):a.push(...e.providers)),e.direction&&(!s||!s.get(En,null,{optional:!0}))&&a.push({provide:En,useValue:{value:e.direction,change:z()}}),We.create({parent:s||o,providers:a})}_removeOpenDialog(e,i){const r=this.openDialogs.indexOf(e);r>-1&&(this.openDialogs.splice(r,1),this.openDialogs.length||(this._ariaHiddenElements.forEach((o,s)=>{o?s.setAttribute("aria-hidden",o):s.removeAttribute("aria-hidden")}),this._ariaHiddenElements.clear(),i&&this._getAfterAllClosed().next()))}_hideNonDialogContentFromAssistiveTechnology(){const e=this._overlayContainer.getContainerElement();if(e.parentElement){const i=e.parentElement.children;for(let r=i.length-1;r>-1;r--){const o=i[r];o!==e&&"SCRIPT"!==o.nodeName&&"STYLE"!==o.nodeName&&!o.hasAttribute("aria-live")&&(this._ariaHiddenElements.set(o,o.getAttribute("aria-hidden")),o.setAttribute("aria-hidden","true"))}}}_getAfterAllClosed(){const e=this._parentDialog;return e?e._getAfterAllClosed():this._afterAllClosedAtThisLevel}static#e=this.\u0275fac=function(i){return new(i||t)(b(Oi),b(We),b(AU,8),b(t,12),b(Ld),b(LS))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();function xg(t,n){let e=t.length;for(;e--;)n(t[e])}let FU=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({providers:[BS,RU],imports:[Bd,Vr,FE,Vr]})}return t})();function NU(t,n){}class Hd{constructor(){this.role="dialog",this.panelClass="",this.hasBackdrop=!0,this.backdropClass="",this.disableClose=!1,this.width="",this.height="",this.maxWidth="80vw",this.data=null,this.ariaDescribedBy=null,this.ariaLabelledBy=null,this.ariaLabel=null,this.ariaModal=!0,this.autoFocus="first-tabbable",this.restoreFocus=!0,this.delayFocusTrap=!0,this.closeOnNavigation=!0}}const Cg="mdc-dialog--open",VS="mdc-dialog--opening",jS="mdc-dialog--closing";let BU=(()=>{class t extends PS{constructor(e,i,r,o,s,a,c,l){super(e,i,r,o,s,a,c,l),this._animationStateChanged=new q}_captureInitialFocus(){this._config.delayFocusTrap||this._trapFocus()}_openAnimationDone(e){this._config.delayFocusTrap&&this._trapFocus(),this._animationStateChanged.next({state:"opened",totalTime:e})}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(Jm),f(Z,8),f(Hd),f(wd),f(j),f($a),f(nr))};static#t=this.\u0275cmp=oe({type:t,selectors:[["ng-component"]],features:[k],decls:0,vars:0,template:function(i,r){},encapsulation:2})}return t})();const HS="--mat-dialog-transition-duration";function zS(t){return null==t?null:"number"==typeof t?t:t.endsWith("ms")?si(t.substring(0,t.length-2)):t.endsWith("s")?1e3*si(t.substring(0,t.length-1)):"0"===t?0:null}let VU=(()=>{class t extends BU{constructor(e,i,r,o,s,a,c,l,d){super(e,i,r,o,s,a,c,d),this._animationMode=l,this._animationsEnabled="NoopAnimations"!==this._animationMode,this._hostElement=this._elementRef.nativeElement,this._enterAnimationDuration=this._animationsEnabled?zS(this._config.enterAnimationDuration)??150:0,this._exitAnimationDuration=this._animationsEnabled?zS(this._config.exitAnimationDuration)??75:0,this._animationTimer=null,this._finishDialogOpen=()=>{this._clearAnimationClasses(),this._openAnimationDone(this._enterAnimationDuration)},this._finishDialogClose=()=>{this._clearAnimationClasses(),this._animationStateChanged.emit({state:"closed",totalTime:this._exitAnimationDuration})}}_contentAttached(){super._contentAttached(),this._startOpenAnimation()}ngOnDestroy(){super.ngOnDestroy(),null!==this._animationTimer&&clearTimeout(this._animationTimer)}_startOpenAnimation(){this._animationStateChanged.emit({state:"opening",totalTime:this._enterAnimationDuration}),this._animationsEnabled?(this._hostElement.style.setProperty(HS,`${this._enterAnimationDuration}ms`),this._requestAnimationFrame(()=>this._hostElement.classList.add(VS,Cg)),this._waitForAnimationToComplete(this._enterAnimationDuration,this._finishDialogOpen)):(this._hostElement.classList.add(Cg),Promise.resolve().then(()=>this._finishDialogOpen()))}_startExitAnimation(){this._animationStateChanged.emit({state:"closing",totalTime:this._exitAnimationDuration}),this._hostElement.classList.remove(Cg),this._animationsEnabled?(this._hostElement.style.setProperty(HS,`${this._exitAnimationDuration}ms`),this._requestAnimationFrame(()=>this._hostElement.classList.add(jS)),this._waitForAnimationToComplete(this._exitAnimationDuration,this._finishDialogClose)):Promise.resolve().then(()=>this._finishDialogClose())}_clearAnimationClasses(){this._hostElement.classList.remove(VS,jS)}_waitForAnimationToComplete(e,i){null!==this._animationTimer&&clearTimeout(this._animationTimer),this._animationTimer=setTimeout(i,e)}_requestAnimationFrame(e){this._ngZone.runOutsideAngular(()=>{"function"==typeof requestAnimationFrame?requestAnimationFrame(e):e()})}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(Jm),f(Z,8),f(Hd),f(wd),f(j),f($a),f(gt,8),f(nr))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-dialog-container"]],hostAttrs:["tabindex","-1",1,"mat-mdc-dialog-container","mdc-dialog"],hostVars:8,hostBindings:function(i,r){2&i&&(wn("id",r._config.id),ue("aria-modal",r._config.ariaModal)("role",r._config.role)("aria-labelledby",r._config.ariaLabel?null:r._ariaLabelledByQueue[0])("aria-label",r._config.ariaLabel)("aria-describedby",r._config.ariaDescribedBy||null),he("_mat-animation-noopable",!r._animationsEnabled))},features:[k],decls:3,vars:0,consts:[[1,"mdc-dialog__container"],[1,"mat-mdc-dialog-surface","mdc-dialog__surface"],["cdkPortalOutlet",""]],template:function(i,r){1&i&&(w(0,"div",0)(1,"div",1),O(2,NU,0,0,"ng-template",2),D()())},dependencies:[Ai],styles:['.mdc-elevation-overlay{position:absolute;border-radius:inherit;pointer-events:none;opacity:var(--mdc-elevation-overlay-opacity, 0);transition:opacity 280ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-dialog,.mdc-dialog__scrim{position:fixed;top:0;left:0;align-items:center;justify-content:center;box-sizing:border-box;width:100%;height:100%}.mdc-dialog{display:none;z-index:var(--mdc-dialog-z-index, 7)}.mdc-dialog .mdc-dialog__content{padding:20px 24px 20px 24px}.mdc-dialog .mdc-dialog__surface{min-width:280px}@media(max-width: 592px){.mdc-dialog .mdc-dialog__surface{max-width:calc(100vw - 32px)}}@media(min-width: 592px){.mdc-dialog .mdc-dialog__surface{max-width:560px}}.mdc-dialog .mdc-dialog__surface{max-height:calc(100% - 32px)}.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface{max-width:none}@media(max-width: 960px){.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface{max-height:560px;width:560px}.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface .mdc-dialog__close{right:-12px}}@media(max-width: 720px)and (max-width: 672px){.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface{width:calc(100vw - 112px)}}@media(max-width: 720px)and (min-width: 672px){.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface{width:560px}}@media(max-width: 720px)and (max-height: 720px){.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface{max-height:calc(100vh - 160px)}}@media(max-width: 720px)and (min-height: 720px){.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface{max-height:560px}}@media(max-width: 720px){.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface .mdc-dialog__close{right:-12px}}@media(max-width: 720px)and (max-height: 400px),(max-width: 600px),(min-width: 720px)and (max-height: 400px){.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface{height:100%;max-height:100vh;max-width:100vw;width:100vw;border-radius:0}.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface .mdc-dialog__close{order:-1;left:-12px}.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface .mdc-dialog__header{padding:0 16px 9px;justify-content:flex-start}.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface .mdc-dialog__title{margin-left:calc(16px - 2 * 12px)}}@media(min-width: 960px){.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface{width:calc(100vw - 400px)}.mdc-dialog.mdc-dialog--fullscreen .mdc-dialog__surface .mdc-dialog__close{right:-12px}}.mdc-dialog.mdc-dialog__scrim--hidden .mdc-dialog__scrim{opacity:0}.mdc-dialog__scrim{opacity:0;z-index:-1}.mdc-dialog__container{display:flex;flex-direction:row;align-items:center;justify-content:space-around;box-sizing:border-box;height:100%;transform:scale(0.8);opacity:0;pointer-events:none}.mdc-dialog__surface{position:relative;display:flex;flex-direction:column;flex-grow:0;flex-shrink:0;box-sizing:border-box;max-width:100%;max-height:100%;pointer-events:auto;overflow-y:auto;outline:0}.mdc-dialog__surface .mdc-elevation-overlay{width:100%;height:100%;top:0;left:0}[dir=rtl] .mdc-dialog__surface,.mdc-dialog__surface[dir=rtl]{text-align:right}@media screen and (forced-colors: active),(-ms-high-contrast: active){.mdc-dialog__surface{outline:2px solid windowText}}.mdc-dialog__surface::before{position:absolute;box-sizing:border-box;width:100%;height:100%;top:0;left:0;border:2px solid rgba(0,0,0,0);border-radius:inherit;content:"";pointer-events:none}@media screen and (forced-colors: active){.mdc-dialog__surface::before{border-color:CanvasText}}@media screen and (-ms-high-contrast: active),screen and (-ms-high-contrast: none){.mdc-dialog__surface::before{content:none}}.mdc-dialog__title{display:block;margin-top:0;position:relative;flex-shrink:0;box-sizing:border-box;margin:0 0 1px;padding:0 24px 9px}.mdc-dialog__title::before{display:inline-block;width:0;height:40px;content:"";vertical-align:0}[dir=rtl] .mdc-dialog__title,.mdc-dialog__title[dir=rtl]{text-align:right}.mdc-dialog--scrollable .mdc-dialog__title{margin-bottom:1px;padding-bottom:15px}.mdc-dialog--fullscreen .mdc-dialog__header{align-items:baseline;border-bottom:1px solid rgba(0,0,0,0);display:inline-flex;justify-content:space-between;padding:0 24px 9px;z-index:1}@media screen and (forced-colors: active){.mdc-dialog--fullscreen .mdc-dialog__header{border-bottom-color:CanvasText}}.mdc-dialog--fullscreen .mdc-dialog__header .mdc-dialog__close{right:-12px}.mdc-dialog--fullscreen .mdc-dialog__title{margin-bottom:0;padding:0;border-bottom:0}.mdc-dialog--fullscreen.mdc-dialog--scrollable .mdc-dialog__title{border-bottom:0;margin-bottom:0}.mdc-dialog--fullscreen .mdc-dialog__close{top:5px}.mdc-dialog--fullscreen.mdc-dialog--scrollable .mdc-dialog__actions{border-top:1px solid rgba(0,0,0,0)}@media screen and (forced-colors: active){.mdc-dialog--fullscreen.mdc-dialog--scrollable .mdc-dialog__actions{border-top-color:CanvasText}}.mdc-dialog--fullscreen--titleless .mdc-dialog__close{margin-top:4px}.mdc-dialog--fullscreen--titleless.mdc-dialog--scrollable .mdc-dialog__close{margin-top:0}.mdc-dialog__content{flex-grow:1;box-sizing:border-box;margin:0;overflow:auto}.mdc-dialog__content>:first-child{margin-top:0}.mdc-dialog__content>:last-child{margin-bottom:0}.mdc-dialog__title+.mdc-dialog__content,.mdc-dialog__header+.mdc-dialog__content{padding-top:0}.mdc-dialog--scrollable .mdc-dialog__title+.mdc-dialog__content{padding-top:8px;padding-bottom:8px}.mdc-dialog__content .mdc-deprecated-list:first-child:last-child{padding:6px 0 0}.mdc-dialog--scrollable .mdc-dialog__content .mdc-deprecated-list:first-child:last-child{padding:0}.mdc-dialog__actions{display:flex;position:relative;flex-shrink:0;flex-wrap:wrap;align-items:center;justify-content:flex-end;box-sizing:border-box;min-height:52px;margin:0;padding:8px;border-top:1px solid rgba(0,0,0,0)}@media screen and (forced-colors: active){.mdc-dialog__actions{border-top-color:CanvasText}}.mdc-dialog--stacked .mdc-dialog__actions{flex-direction:column;align-items:flex-end}.mdc-dialog__button{margin-left:8px;margin-right:0;max-width:100%;text-align:right}[dir=rtl] .mdc-dialog__button,.mdc-dialog__button[dir=rtl]{margin-left:0;margin-right:8px}.mdc-dialog__button:first-child{margin-left:0;margin-right:0}[dir=rtl] .mdc-dialog__button:first-child,.mdc-dialog__button:first-child[dir=rtl]{margin-left:0;margin-right:0}[dir=rtl] .mdc-dialog__button,.mdc-dialog__button[dir=rtl]{text-align:left}.mdc-dialog--stacked .mdc-dialog__button:not(:first-child){margin-top:12px}.mdc-dialog--open,.mdc-dialog--opening,.mdc-dialog--closing{display:flex}.mdc-dialog--opening .mdc-dialog__scrim{transition:opacity 150ms linear}.mdc-dialog--opening .mdc-dialog__container{transition:opacity 75ms linear,transform 150ms 0ms cubic-bezier(0, 0, 0.2, 1)}.mdc-dialog--closing .mdc-dialog__scrim,.mdc-dialog--closing .mdc-dialog__container{transition:opacity 75ms linear}.mdc-dialog--closing .mdc-dialog__container{transform:none}.mdc-dialog--open .mdc-dialog__scrim{opacity:1}.mdc-dialog--open .mdc-dialog__container{transform:none;opacity:1}.mdc-dialog--open.mdc-dialog__surface-scrim--shown .mdc-dialog__surface-scrim{opacity:1}.mdc-dialog--open.mdc-dialog__surface-scrim--hiding .mdc-dialog__surface-scrim{transition:opacity 75ms linear}.mdc-dialog--open.mdc-dialog__surface-scrim--showing .mdc-dialog__surface-scrim{transition:opacity 150ms linear}.mdc-dialog__surface-scrim{display:none;opacity:0;position:absolute;width:100%;height:100%;z-index:1}.mdc-dialog__surface-scrim--shown .mdc-dialog__surface-scrim,.mdc-dialog__surface-scrim--showing .mdc-dialog__surface-scrim,.mdc-dialog__surface-scrim--hiding .mdc-dialog__surface-scrim{display:block}.mdc-dialog-scroll-lock{overflow:hidden}.mdc-dialog--no-content-padding .mdc-dialog__content{padding:0}.mdc-dialog--sheet .mdc-dialog__container .mdc-dialog__close{right:12px;top:9px;position:absolute;z-index:1}.mdc-dialog__scrim--removed{pointer-events:none}.mdc-dialog__scrim--removed .mdc-dialog__scrim,.mdc-dialog__scrim--removed .mdc-dialog__surface-scrim{display:none}.mat-mdc-dialog-content{max-height:65vh}.mat-mdc-dialog-container{position:static;display:block}.mat-mdc-dialog-container,.mat-mdc-dialog-container .mdc-dialog__container,.mat-mdc-dialog-container .mdc-dialog__surface{max-height:inherit;min-height:inherit;min-width:inherit;max-width:inherit}.mat-mdc-dialog-container .mdc-dialog__surface{display:block;width:100%;height:100%}.mat-mdc-dialog-container{--mdc-dialog-container-elevation-shadow:0px 11px 15px -7px rgba(0, 0, 0, 0.2), 0px 24px 38px 3px rgba(0, 0, 0, 0.14), 0px 9px 46px 8px rgba(0, 0, 0, 0.12);--mdc-dialog-container-shadow-color:#000;--mdc-dialog-container-shape:4px;--mdc-dialog-container-elevation: var(--mdc-dialog-container-elevation-shadow);outline:0}.mat-mdc-dialog-container .mdc-dialog__surface{background-color:var(--mdc-dialog-container-color, white)}.mat-mdc-dialog-container .mdc-dialog__surface{box-shadow:var(--mdc-dialog-container-elevation, 0px 11px 15px -7px rgba(0, 0, 0, 0.2), 0px 24px 38px 3px rgba(0, 0, 0, 0.14), 0px 9px 46px 8px rgba(0, 0, 0, 0.12))}.mat-mdc-dialog-container .mdc-dialog__surface{border-radius:var(--mdc-dialog-container-shape, 4px)}.mat-mdc-dialog-container .mdc-dialog__title{font-family:var(--mdc-dialog-subhead-font, Roboto, sans-serif);line-height:var(--mdc-dialog-subhead-line-height, 1.5rem);font-size:var(--mdc-dialog-subhead-size, 1rem);font-weight:var(--mdc-dialog-subhead-weight, 400);letter-spacing:var(--mdc-dialog-subhead-tracking, 0.03125em)}.mat-mdc-dialog-container .mdc-dialog__title{color:var(--mdc-dialog-subhead-color, rgba(0, 0, 0, 0.87))}.mat-mdc-dialog-container .mdc-dialog__content{font-family:var(--mdc-dialog-supporting-text-font, Roboto, sans-serif);line-height:var(--mdc-dialog-supporting-text-line-height, 1.5rem);font-size:var(--mdc-dialog-supporting-text-size, 1rem);font-weight:var(--mdc-dialog-supporting-text-weight, 400);letter-spacing:var(--mdc-dialog-supporting-text-tracking, 0.03125em)}.mat-mdc-dialog-container .mdc-dialog__content{color:var(--mdc-dialog-supporting-text-color, rgba(0, 0, 0, 0.6))}.mat-mdc-dialog-container .mdc-dialog__container{transition-duration:var(--mat-dialog-transition-duration, 0ms)}.mat-mdc-dialog-container._mat-animation-noopable .mdc-dialog__container{transition:none}.mat-mdc-dialog-content{display:block}.mat-mdc-dialog-actions{justify-content:start}.mat-mdc-dialog-actions.mat-mdc-dialog-actions-align-center,.mat-mdc-dialog-actions[align=center]{justify-content:center}.mat-mdc-dialog-actions.mat-mdc-dialog-actions-align-end,.mat-mdc-dialog-actions[align=end]{justify-content:flex-end}.mat-mdc-dialog-actions .mat-button-base+.mat-button-base,.mat-mdc-dialog-actions .mat-mdc-button-base+.mat-mdc-button-base{margin-left:8px}[dir=rtl] .mat-mdc-dialog-actions .mat-button-base+.mat-button-base,[dir=rtl] .mat-mdc-dialog-actions .mat-mdc-button-base+.mat-mdc-button-base{margin-left:0;margin-right:8px}'],encapsulation:2})}return t})();class zd{constructor(n,e,i){this._ref=n,this._containerInstance=i,this._afterOpened=new U,this._beforeClosed=new U,this._state=0,this.disableClose=e.disableClose,this.id=n.id,i._animationStateChanged.pipe(Ze(r=>"opened"===r.state),vt(1)).subscribe(()=>{this._afterOpened.next(),this._afterOpened.complete()}),i._animationStateChanged.pipe(Ze(r=>"closed"===r.state),vt(1)).subscribe(()=>{clearTimeout(this._closeFallbackTimeout),this._finishDialogClose()}),n.overlayRef.detachments().subscribe(()=>{this._beforeClosed.next(this._result),this._beforeClosed.complete(),this._finishDialogClose()}),An(this.backdropClick(),this.keydownEvents().pipe(Ze(r=>27===r.keyCode&&!this.disableClose&&!Lr(r)))).subscribe(r=>{this.disableClose||(r.preventDefault(),$S(this,"keydown"===r.type?"keyboard":"mouse"))})}close(n){this._result=n,this._containerInstance._animationStateChanged.pipe(Ze(e=>"closing"===e.state),vt(1)).subscribe(e=>{this._beforeClosed.next(n),this._beforeClosed.complete(),this._ref.overlayRef.detachBackdrop(),this._closeFallbackTimeout=setTimeout(()=>this._finishDialogClose(),e.totalTime+100)}),this._state=1,this._containerInstance._startExitAnimation()}afterOpened(){return this._afterOpened}afterClosed(){return this._ref.closed}beforeClosed(){return this._beforeClosed}backdropClick(){return this._ref.backdropClick}keydownEvents(){return this._ref.keydownEvents}updatePosition(n){let e=this._ref.config.positionStrategy;return n&&(n.left||n.right)?n.left?e.left(n.left):e.right(n.right):e.centerHorizontally(),n&&(n.top||n.bottom)?n.top?e.top(n.top):e.bottom(n.bottom):e.centerVertically(),this._ref.updatePosition(),this}updateSize(n="",e=""){return this._ref.updateSize(n,e),this}addPanelClass(n){return this._ref.addPanelClass(n),this}removePanelClass(n){return this._ref.removePanelClass(n),this}getState(){return this._state}_finishDialogClose(){this._state=2,this._ref.close(this._result,{focusOrigin:this._closeInteractionType}),this.componentInstance=null}}function $S(t,n,e){return t._closeInteractionType=n,t.close(e)}const US=new E("MatMdcDialogData"),jU=new E("mat-mdc-dialog-default-options"),GS=new E("mat-mdc-dialog-scroll-strategy"),zU={provide:GS,deps:[Oi],useFactory:function HU(t){return()=>t.scrollStrategies.block()}};let $U=0,UU=(()=>{class t{get openDialogs(){return this._parentDialog?this._parentDialog.openDialogs:this._openDialogsAtThisLevel}get afterOpened(){return this._parentDialog?this._parentDialog.afterOpened:this._afterOpenedAtThisLevel}_getAfterAllClosed(){const e=this._parentDialog;return e?e._getAfterAllClosed():this._afterAllClosedAtThisLevel}constructor(e,i,r,o,s,a,c,l,d,u){this._overlay=e,this._defaultOptions=r,this._parentDialog=o,this._dialogRefConstructor=c,this._dialogContainerType=l,this._dialogDataToken=d,this._openDialogsAtThisLevel=[],this._afterAllClosedAtThisLevel=new U,this._afterOpenedAtThisLevel=new U,this._idPrefix="mat-dialog-",this.dialogConfigClass=Hd,this.afterAllClosed=Vd(()=>this.openDialogs.length?this._getAfterAllClosed():this._getAfterAllClosed().pipe(Dn(void 0))),this._scrollStrategy=a,this._dialog=i.get(BS)}open(e,i){let r;(i={...this._defaultOptions||new Hd,...i}).id=i.id||`${this._idPrefix}${$U++}`,i.scrollStrategy=i.scrollStrategy||this._scrollStrategy();const o=this._dialog.open(e,{...i,positionStrategy:this._overlay.position().global().centerHorizontally().centerVertically(),disableClose:!0,closeOnDestroy:!1,closeOnOverlayDetachments:!1,container:{type:this._dialogContainerType,providers:()=>[{provide:this.dialogConfigClass,useValue:i},{provide:jd,useValue:i}]},templateContext:()=>({dialogRef:r}),providers:(s,a,c)=>(r=new this._dialogRefConstructor(s,i,c),r.updatePosition(i?.position),[{provide:this._dialogContainerType,useValue:c},{provide:this._dialogDataToken,useValue:a.data},{provide:this._dialogRefConstructor,useValue:r}])});return r.componentRef=o.componentRef,r.componentInstance=o.componentInstance,this.openDialogs.push(r),this.afterOpened.next(r),r.afterClosed().subscribe(()=>{const s=this.openDialogs.indexOf(r);s>-1&&(this.openDialogs.splice(s,1),this.openDialogs.length||this._getAfterAllClosed().next())}),r}closeAll(){this._closeDialogs(this.openDialogs)}getDialogById(e){return this.openDialogs.find(i=>i.id===e)}ngOnDestroy(){this._closeDialogs(this._openDialogsAtThisLevel),this._afterAllClosedAtThisLevel.complete(),this._afterOpenedAtThisLevel.complete()}_closeDialogs(e){let i=e.length;for(;i--;)e[i].close()}static#e=this.\u0275fac=function(i){Il()};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})(),Ua=(()=>{class t extends UU{constructor(e,i,r,o,s,a,c,l){super(e,i,o,a,c,s,zd,VU,US,l),this._idPrefix="mat-mdc-dialog-"}static#e=this.\u0275fac=function(i){return new(i||t)(b(Oi),b(We),b(Ma,8),b(jU,8),b(GS),b(t,12),b(Ld),b(gt,8))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})(),GU=0,WU=(()=>{class t{constructor(e,i,r){this.dialogRef=e,this._elementRef=i,this._dialog=r,this.type="button"}ngOnInit(){this.dialogRef||(this.dialogRef=WS(this._elementRef,this._dialog.openDialogs))}ngOnChanges(e){const i=e._matDialogClose||e._matDialogCloseResult;i&&(this.dialogResult=i.currentValue)}_onButtonClick(e){$S(this.dialogRef,0===e.screenX&&0===e.screenY?"keyboard":"mouse",this.dialogResult)}static#e=this.\u0275fac=function(i){return new(i||t)(f(zd,8),f($),f(Ua))};static#t=this.\u0275dir=x({type:t,selectors:[["","mat-dialog-close",""],["","matDialogClose",""]],hostVars:2,hostBindings:function(i,r){1&i&&Y("click",function(s){return r._onButtonClick(s)}),2&i&&ue("aria-label",r.ariaLabel||null)("type",r.type)},inputs:{ariaLabel:["aria-label","ariaLabel"],type:"type",dialogResult:["mat-dialog-close","dialogResult"],_matDialogClose:["matDialogClose","_matDialogClose"]},exportAs:["matDialogClose"],features:[ut]})}return t})(),qU=(()=>{class t{constructor(e,i,r){this._dialogRef=e,this._elementRef=i,this._dialog=r,this.id="mat-mdc-dialog-title-"+GU++}ngOnInit(){this._dialogRef||(this._dialogRef=WS(this._elementRef,this._dialog.openDialogs)),this._dialogRef&&Promise.resolve().then(()=>{this._dialogRef._containerInstance?._ariaLabelledByQueue?.push(this.id)})}ngOnDestroy(){const e=this._dialogRef?._containerInstance?._ariaLabelledByQueue;e&&Promise.resolve().then(()=>{const i=e.indexOf(this.id);i>-1&&e.splice(i,1)})}static#e=this.\u0275fac=function(i){return new(i||t)(f(zd,8),f($),f(Ua))};static#t=this.\u0275dir=x({type:t,selectors:[["","mat-dialog-title",""],["","matDialogTitle",""]],hostAttrs:[1,"mat-mdc-dialog-title","mdc-dialog__title"],hostVars:1,hostBindings:function(i,r){2&i&&wn("id",r.id)},inputs:{id:"id"},exportAs:["matDialogTitle"]})}return t})(),YU=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["","mat-dialog-content",""],["mat-dialog-content"],["","matDialogContent",""]],hostAttrs:[1,"mat-mdc-dialog-content","mdc-dialog__content"]})}return t})(),ZU=(()=>{class t{constructor(){this.align="start"}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["","mat-dialog-actions",""],["mat-dialog-actions"],["","matDialogActions",""]],hostAttrs:[1,"mat-mdc-dialog-actions","mdc-dialog__actions"],hostVars:4,hostBindings:function(i,r){2&i&&he("mat-mdc-dialog-actions-align-center","center"===r.align)("mat-mdc-dialog-actions-align-end","end"===r.align)},inputs:{align:"align"}})}return t})();function WS(t,n){let e=t.nativeElement.parentElement;for(;e&&!e.classList.contains("mat-mdc-dialog-container");)e=e.parentElement;return e?n.find(i=>i.id===e.id):null}let KU=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({providers:[Ua,zU],imports:[FU,Bd,Vr,Ae,Ae]})}return t})();const QU=["mat-button",""],XU=[[["",8,"material-icons",3,"iconPositionEnd",""],["mat-icon",3,"iconPositionEnd",""],["","matButtonIcon","",3,"iconPositionEnd",""]],"*",[["","iconPositionEnd","",8,"material-icons"],["mat-icon","iconPositionEnd",""],["","matButtonIcon","","iconPositionEnd",""]]],JU=[".material-icons:not([iconPositionEnd]), mat-icon:not([iconPositionEnd]), [matButtonIcon]:not([iconPositionEnd])","*",".material-icons[iconPositionEnd], mat-icon[iconPositionEnd], [matButtonIcon][iconPositionEnd]"],e4=[{selector:"mat-button",mdcClasses:["mdc-button","mat-mdc-button"]},{selector:"mat-flat-button",mdcClasses:["mdc-button","mdc-button--unelevated","mat-mdc-unelevated-button"]},{selector:"mat-raised-button",mdcClasses:["mdc-button","mdc-button--raised","mat-mdc-raised-button"]},{selector:"mat-stroked-button",mdcClasses:["mdc-button","mdc-button--outlined","mat-mdc-outlined-button"]},{selector:"mat-fab",mdcClasses:["mdc-fab","mat-mdc-fab"]},{selector:"mat-mini-fab",mdcClasses:["mdc-fab","mdc-fab--mini","mat-mdc-mini-fab"]},{selector:"mat-icon-button",mdcClasses:["mdc-icon-button","mat-mdc-icon-button"]}],t4=es(Cd(tg(class{constructor(t){this._elementRef=t}})));let n4=(()=>{class t extends t4{get ripple(){return this._rippleLoader?.getRipple(this._elementRef.nativeElement)}set ripple(e){this._rippleLoader?.attachRipple(this._elementRef.nativeElement,e)}get disableRipple(){return this._disableRipple}set disableRipple(e){this._disableRipple=me(e),this._updateRippleDisabled()}get disabled(){return this._disabled}set disabled(e){this._disabled=me(e),this._updateRippleDisabled()}constructor(e,i,r,o){super(e),this._platform=i,this._ngZone=r,this._animationMode=o,this._focusMonitor=T(nr),this._rippleLoader=T(qE),this._isFab=!1,this._disableRipple=!1,this._disabled=!1,this._rippleLoader?.configureRipple(this._elementRef.nativeElement,{className:"mat-mdc-button-ripple"});const s=e.nativeElement.classList;for(const a of e4)this._hasHostAttributes(a.selector)&&a.mdcClasses.forEach(c=>{s.add(c)})}ngAfterViewInit(){this._focusMonitor.monitor(this._elementRef,!0)}ngOnDestroy(){this._focusMonitor.stopMonitoring(this._elementRef)}focus(e="program",i){e?this._focusMonitor.focusVia(this._elementRef.nativeElement,e,i):this._elementRef.nativeElement.focus(i)}_hasHostAttributes(...e){return e.some(i=>this._elementRef.nativeElement.hasAttribute(i))}_updateRippleDisabled(){this._rippleLoader?.setDisabled(this._elementRef.nativeElement,this.disableRipple||this.disabled)}static#e=this.\u0275fac=function(i){Il()};static#t=this.\u0275dir=x({type:t,features:[k]})}return t})(),Dg=(()=>{class t extends n4{constructor(e,i,r,o){super(e,i,r,o)}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(tt),f(j),f(gt,8))};static#t=this.\u0275cmp=oe({type:t,selectors:[["button","mat-button",""],["button","mat-raised-button",""],["button","mat-flat-button",""],["button","mat-stroked-button",""]],hostVars:7,hostBindings:function(i,r){2&i&&(ue("disabled",r.disabled||null),he("_mat-animation-noopable","NoopAnimations"===r._animationMode)("mat-unthemed",!r.color)("mat-mdc-button-base",!0))},inputs:{disabled:"disabled",disableRipple:"disableRipple",color:"color"},exportAs:["matButton"],features:[k],attrs:QU,ngContentSelectors:JU,decls:7,vars:4,consts:[[1,"mat-mdc-button-persistent-ripple"],[1,"mdc-button__label"],[1,"mat-mdc-focus-indicator"],[1,"mat-mdc-button-touch-target"]],template:function(i,r){1&i&&(at(XU),K(0,"span",0),Q(1),w(2,"span",1),Q(3,1),D(),Q(4,2),K(5,"span",2)(6,"span",3)),2&i&&he("mdc-button__ripple",!r._isFab)("mdc-fab__ripple",r._isFab)},styles:['.mdc-touch-target-wrapper{display:inline}.mdc-elevation-overlay{position:absolute;border-radius:inherit;pointer-events:none;opacity:var(--mdc-elevation-overlay-opacity, 0);transition:opacity 280ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-button{position:relative;display:inline-flex;align-items:center;justify-content:center;box-sizing:border-box;min-width:64px;border:none;outline:none;line-height:inherit;user-select:none;-webkit-appearance:none;overflow:visible;vertical-align:middle;background:rgba(0,0,0,0)}.mdc-button .mdc-elevation-overlay{width:100%;height:100%;top:0;left:0}.mdc-button::-moz-focus-inner{padding:0;border:0}.mdc-button:active{outline:none}.mdc-button:hover{cursor:pointer}.mdc-button:disabled{cursor:default;pointer-events:none}.mdc-button[hidden]{display:none}.mdc-button .mdc-button__icon{margin-left:0;margin-right:8px;display:inline-block;position:relative;vertical-align:top}[dir=rtl] .mdc-button .mdc-button__icon,.mdc-button .mdc-button__icon[dir=rtl]{margin-left:8px;margin-right:0}.mdc-button .mdc-button__progress-indicator{font-size:0;position:absolute;transform:translate(-50%, -50%);top:50%;left:50%;line-height:initial}.mdc-button .mdc-button__label{position:relative}.mdc-button .mdc-button__focus-ring{pointer-events:none;border:2px solid rgba(0,0,0,0);border-radius:6px;box-sizing:content-box;position:absolute;top:50%;left:50%;transform:translate(-50%, -50%);height:calc(\n      100% + 4px\n    );width:calc(\n      100% + 4px\n    );display:none}@media screen and (forced-colors: active){.mdc-button .mdc-button__focus-ring{border-color:CanvasText}}.mdc-button .mdc-button__focus-ring::after{content:"";border:2px solid rgba(0,0,0,0);border-radius:8px;display:block;position:absolute;top:50%;left:50%;transform:translate(-50%, -50%);height:calc(100% + 4px);width:calc(100% + 4px)}@media screen and (forced-colors: active){.mdc-button .mdc-button__focus-ring::after{border-color:CanvasText}}@media screen and (forced-colors: active){.mdc-button.mdc-ripple-upgraded--background-focused .mdc-button__focus-ring,.mdc-button:not(.mdc-ripple-upgraded):focus .mdc-button__focus-ring{display:block}}.mdc-button .mdc-button__touch{position:absolute;top:50%;height:48px;left:0;right:0;transform:translateY(-50%)}.mdc-button__label+.mdc-button__icon{margin-left:8px;margin-right:0}[dir=rtl] .mdc-button__label+.mdc-button__icon,.mdc-button__label+.mdc-button__icon[dir=rtl]{margin-left:0;margin-right:8px}svg.mdc-button__icon{fill:currentColor}.mdc-button--touch{margin-top:6px;margin-bottom:6px}.mdc-button{padding:0 8px 0 8px}.mdc-button--unelevated{transition:box-shadow 280ms cubic-bezier(0.4, 0, 0.2, 1);padding:0 16px 0 16px}.mdc-button--unelevated.mdc-button--icon-trailing{padding:0 12px 0 16px}.mdc-button--unelevated.mdc-button--icon-leading{padding:0 16px 0 12px}.mdc-button--raised{transition:box-shadow 280ms cubic-bezier(0.4, 0, 0.2, 1);padding:0 16px 0 16px}.mdc-button--raised.mdc-button--icon-trailing{padding:0 12px 0 16px}.mdc-button--raised.mdc-button--icon-leading{padding:0 16px 0 12px}.mdc-button--outlined{border-style:solid;transition:border 280ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-button--outlined .mdc-button__ripple{border-style:solid;border-color:rgba(0,0,0,0)}.mat-mdc-button{height:var(--mdc-text-button-container-height, 36px);border-radius:var(--mdc-text-button-container-shape, var(--mdc-shape-small, 4px))}.mat-mdc-button:not(:disabled){color:var(--mdc-text-button-label-text-color, inherit)}.mat-mdc-button:disabled{color:var(--mdc-text-button-disabled-label-text-color, rgba(0, 0, 0, 0.38))}.mat-mdc-button .mdc-button__ripple{border-radius:var(--mdc-text-button-container-shape, var(--mdc-shape-small, 4px))}.mat-mdc-unelevated-button{height:var(--mdc-filled-button-container-height, 36px);border-radius:var(--mdc-filled-button-container-shape, var(--mdc-shape-small, 4px))}.mat-mdc-unelevated-button:not(:disabled){background-color:var(--mdc-filled-button-container-color, transparent)}.mat-mdc-unelevated-button:disabled{background-color:var(--mdc-filled-button-disabled-container-color, rgba(0, 0, 0, 0.12))}.mat-mdc-unelevated-button:not(:disabled){color:var(--mdc-filled-button-label-text-color, inherit)}.mat-mdc-unelevated-button:disabled{color:var(--mdc-filled-button-disabled-label-text-color, rgba(0, 0, 0, 0.38))}.mat-mdc-unelevated-button .mdc-button__ripple{border-radius:var(--mdc-filled-button-container-shape, var(--mdc-shape-small, 4px))}.mat-mdc-raised-button{height:var(--mdc-protected-button-container-height, 36px);border-radius:var(--mdc-protected-button-container-shape, var(--mdc-shape-small, 4px));box-shadow:var(--mdc-protected-button-container-elevation, 0px 3px 1px -2px rgba(0, 0, 0, 0.2), 0px 2px 2px 0px rgba(0, 0, 0, 0.14), 0px 1px 5px 0px rgba(0, 0, 0, 0.12))}.mat-mdc-raised-button:not(:disabled){background-color:var(--mdc-protected-button-container-color, transparent)}.mat-mdc-raised-button:disabled{background-color:var(--mdc-protected-button-disabled-container-color, rgba(0, 0, 0, 0.12))}.mat-mdc-raised-button:not(:disabled){color:var(--mdc-protected-button-label-text-color, inherit)}.mat-mdc-raised-button:disabled{color:var(--mdc-protected-button-disabled-label-text-color, rgba(0, 0, 0, 0.38))}.mat-mdc-raised-button .mdc-button__ripple{border-radius:var(--mdc-protected-button-container-shape, var(--mdc-shape-small, 4px))}.mat-mdc-raised-button.mdc-ripple-upgraded--background-focused,.mat-mdc-raised-button:not(.mdc-ripple-upgraded):focus{box-shadow:var(--mdc-protected-button-focus-container-elevation, 0px 2px 4px -1px rgba(0, 0, 0, 0.2), 0px 4px 5px 0px rgba(0, 0, 0, 0.14), 0px 1px 10px 0px rgba(0, 0, 0, 0.12))}.mat-mdc-raised-button:hover{box-shadow:var(--mdc-protected-button-hover-container-elevation, 0px 2px 4px -1px rgba(0, 0, 0, 0.2), 0px 4px 5px 0px rgba(0, 0, 0, 0.14), 0px 1px 10px 0px rgba(0, 0, 0, 0.12))}.mat-mdc-raised-button:not(:disabled):active{box-shadow:var(--mdc-protected-button-pressed-container-elevation, 0px 5px 5px -3px rgba(0, 0, 0, 0.2), 0px 8px 10px 1px rgba(0, 0, 0, 0.14), 0px 3px 14px 2px rgba(0, 0, 0, 0.12))}.mat-mdc-raised-button:disabled{box-shadow:var(--mdc-protected-button-disabled-container-elevation, 0px 0px 0px 0px rgba(0, 0, 0, 0.2), 0px 0px 0px 0px rgba(0, 0, 0, 0.14), 0px 0px 0px 0px rgba(0, 0, 0, 0.12))}.mat-mdc-outlined-button{height:var(--mdc-outlined-button-container-height, 36px);border-radius:var(--mdc-outlined-button-container-shape, var(--mdc-shape-small, 4px));padding:0 15px 0 15px;border-width:var(--mdc-outlined-button-outline-width, 1px)}.mat-mdc-outlined-button:not(:disabled){color:var(--mdc-outlined-button-label-text-color, inherit)}.mat-mdc-outlined-button:disabled{color:var(--mdc-outlined-button-disabled-label-text-color, rgba(0, 0, 0, 0.38))}.mat-mdc-outlined-button .mdc-button__ripple{border-radius:var(--mdc-outlined-button-container-shape, var(--mdc-shape-small, 4px))}.mat-mdc-outlined-button:not(:disabled){border-color:var(--mdc-outlined-button-outline-color, rgba(0, 0, 0, 0.12))}.mat-mdc-outlined-button:disabled{border-color:var(--mdc-outlined-button-disabled-outline-color, rgba(0, 0, 0, 0.12))}.mat-mdc-outlined-button.mdc-button--icon-trailing{padding:0 11px 0 15px}.mat-mdc-outlined-button.mdc-button--icon-leading{padding:0 15px 0 11px}.mat-mdc-outlined-button .mdc-button__ripple{top:-1px;left:-1px;bottom:-1px;right:-1px;border-width:var(--mdc-outlined-button-outline-width, 1px)}.mat-mdc-outlined-button .mdc-button__touch{left:calc(-1 * var(--mdc-outlined-button-outline-width, 1px));width:calc(100% + 2 * var(--mdc-outlined-button-outline-width, 1px))}.mat-mdc-button,.mat-mdc-unelevated-button,.mat-mdc-raised-button,.mat-mdc-outlined-button{-webkit-tap-highlight-color:rgba(0,0,0,0)}.mat-mdc-button .mat-mdc-button-ripple,.mat-mdc-button .mat-mdc-button-persistent-ripple,.mat-mdc-button .mat-mdc-button-persistent-ripple::before,.mat-mdc-unelevated-button .mat-mdc-button-ripple,.mat-mdc-unelevated-button .mat-mdc-button-persistent-ripple,.mat-mdc-unelevated-button .mat-mdc-button-persistent-ripple::before,.mat-mdc-raised-button .mat-mdc-button-ripple,.mat-mdc-raised-button .mat-mdc-button-persistent-ripple,.mat-mdc-raised-button .mat-mdc-button-persistent-ripple::before,.mat-mdc-outlined-button .mat-mdc-button-ripple,.mat-mdc-outlined-button .mat-mdc-button-persistent-ripple,.mat-mdc-outlined-button .mat-mdc-button-persistent-ripple::before{top:0;left:0;right:0;bottom:0;position:absolute;pointer-events:none;border-radius:inherit}.mat-mdc-button .mat-mdc-button-ripple,.mat-mdc-unelevated-button .mat-mdc-button-ripple,.mat-mdc-raised-button .mat-mdc-button-ripple,.mat-mdc-outlined-button .mat-mdc-button-ripple{overflow:hidden}.mat-mdc-button .mat-mdc-button-persistent-ripple::before,.mat-mdc-unelevated-button .mat-mdc-button-persistent-ripple::before,.mat-mdc-raised-button .mat-mdc-button-persistent-ripple::before,.mat-mdc-outlined-button .mat-mdc-button-persistent-ripple::before{content:"";opacity:0;background-color:var(--mat-mdc-button-persistent-ripple-color)}.mat-mdc-button .mat-ripple-element,.mat-mdc-unelevated-button .mat-ripple-element,.mat-mdc-raised-button .mat-ripple-element,.mat-mdc-outlined-button .mat-ripple-element{background-color:var(--mat-mdc-button-ripple-color)}.mat-mdc-button .mdc-button__label,.mat-mdc-unelevated-button .mdc-button__label,.mat-mdc-raised-button .mdc-button__label,.mat-mdc-outlined-button .mdc-button__label{z-index:1}.mat-mdc-button .mat-mdc-focus-indicator,.mat-mdc-unelevated-button .mat-mdc-focus-indicator,.mat-mdc-raised-button .mat-mdc-focus-indicator,.mat-mdc-outlined-button .mat-mdc-focus-indicator{top:0;left:0;right:0;bottom:0;position:absolute}.mat-mdc-button:focus .mat-mdc-focus-indicator::before,.mat-mdc-unelevated-button:focus .mat-mdc-focus-indicator::before,.mat-mdc-raised-button:focus .mat-mdc-focus-indicator::before,.mat-mdc-outlined-button:focus .mat-mdc-focus-indicator::before{content:""}.mat-mdc-button[disabled],.mat-mdc-unelevated-button[disabled],.mat-mdc-raised-button[disabled],.mat-mdc-outlined-button[disabled]{cursor:default;pointer-events:none}.mat-mdc-button .mat-mdc-button-touch-target,.mat-mdc-unelevated-button .mat-mdc-button-touch-target,.mat-mdc-raised-button .mat-mdc-button-touch-target,.mat-mdc-outlined-button .mat-mdc-button-touch-target{position:absolute;top:50%;height:48px;left:0;right:0;transform:translateY(-50%)}.mat-mdc-button._mat-animation-noopable,.mat-mdc-unelevated-button._mat-animation-noopable,.mat-mdc-raised-button._mat-animation-noopable,.mat-mdc-outlined-button._mat-animation-noopable{transition:none !important;animation:none !important}.mat-mdc-button>.mat-icon{margin-left:0;margin-right:8px;display:inline-block;position:relative;vertical-align:top;font-size:1.125rem;height:1.125rem;width:1.125rem}[dir=rtl] .mat-mdc-button>.mat-icon,.mat-mdc-button>.mat-icon[dir=rtl]{margin-left:8px;margin-right:0}.mat-mdc-button .mdc-button__label+.mat-icon{margin-left:8px;margin-right:0}[dir=rtl] .mat-mdc-button .mdc-button__label+.mat-icon,.mat-mdc-button .mdc-button__label+.mat-icon[dir=rtl]{margin-left:0;margin-right:8px}.mat-mdc-unelevated-button>.mat-icon,.mat-mdc-raised-button>.mat-icon,.mat-mdc-outlined-button>.mat-icon{margin-left:0;margin-right:8px;display:inline-block;position:relative;vertical-align:top;font-size:1.125rem;height:1.125rem;width:1.125rem;margin-left:-4px;margin-right:8px}[dir=rtl] .mat-mdc-unelevated-button>.mat-icon,[dir=rtl] .mat-mdc-raised-button>.mat-icon,[dir=rtl] .mat-mdc-outlined-button>.mat-icon,.mat-mdc-unelevated-button>.mat-icon[dir=rtl],.mat-mdc-raised-button>.mat-icon[dir=rtl],.mat-mdc-outlined-button>.mat-icon[dir=rtl]{margin-left:8px;margin-right:0}[dir=rtl] .mat-mdc-unelevated-button>.mat-icon,[dir=rtl] .mat-mdc-raised-button>.mat-icon,[dir=rtl] .mat-mdc-outlined-button>.mat-icon,.mat-mdc-unelevated-button>.mat-icon[dir=rtl],.mat-mdc-raised-button>.mat-icon[dir=rtl],.mat-mdc-outlined-button>.mat-icon[dir=rtl]{margin-left:8px;margin-right:-4px}.mat-mdc-unelevated-button .mdc-button__label+.mat-icon,.mat-mdc-raised-button .mdc-button__label+.mat-icon,.mat-mdc-outlined-button .mdc-button__label+.mat-icon{margin-left:8px;margin-right:-4px}[dir=rtl] .mat-mdc-unelevated-button .mdc-button__label+.mat-icon,[dir=rtl] .mat-mdc-raised-button .mdc-button__label+.mat-icon,[dir=rtl] .mat-mdc-outlined-button .mdc-button__label+.mat-icon,.mat-mdc-unelevated-button .mdc-button__label+.mat-icon[dir=rtl],.mat-mdc-raised-button .mdc-button__label+.mat-icon[dir=rtl],.mat-mdc-outlined-button .mdc-button__label+.mat-icon[dir=rtl]{margin-left:-4px;margin-right:8px}.mat-mdc-outlined-button .mat-mdc-button-ripple,.mat-mdc-outlined-button .mdc-button__ripple{top:-1px;left:-1px;bottom:-1px;right:-1px;border-width:-1px}.mat-mdc-unelevated-button .mat-mdc-focus-indicator::before,.mat-mdc-raised-button .mat-mdc-focus-indicator::before{margin:calc(calc(var(--mat-mdc-focus-indicator-border-width, 3px) + 2px) * -1)}.mat-mdc-outlined-button .mat-mdc-focus-indicator::before{margin:calc(calc(var(--mat-mdc-focus-indicator-border-width, 3px) + 3px) * -1)}',".cdk-high-contrast-active .mat-mdc-button:not(.mdc-button--outlined),.cdk-high-contrast-active .mat-mdc-unelevated-button:not(.mdc-button--outlined),.cdk-high-contrast-active .mat-mdc-raised-button:not(.mdc-button--outlined),.cdk-high-contrast-active .mat-mdc-outlined-button:not(.mdc-button--outlined),.cdk-high-contrast-active .mat-mdc-icon-button{outline:solid 1px}"],encapsulation:2,changeDetection:0})}return t})(),YS=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Ae,Md,Ae]})}return t})(),Ga=(()=>{class t{constructor(e,i){this.data=e,this.dialogRef=i,this.message="selected entry",this.confirmButtonText="Confirm",this.cancelButtonText="Cancel",this.title="Confirmation",e&&(this.message=e.message||this.message,e.buttonText&&(this.confirmButtonText=e.buttonText.ok||this.confirmButtonText,this.cancelButtonText=e.buttonText.cancel||this.cancelButtonText),this.title=e.title||this.title)}onConfirmClick(){this.dialogRef.close(!0)}static#e=this.\u0275fac=function(i){return new(i||t)(f(US),f(zd))};static#t=this.\u0275cmp=oe({type:t,selectors:[["dialog-animations-example-dialog"]],decls:9,vars:4,consts:[["mat-dialog-title",""],["mat-dialog-content",""],["mat-dialog-actions","","align","end"],["mat-raised-button","","color","warn","mat-dialog-close","",3,"click"],["mat-raised-button","","color","primary","mat-dialog-close","","cdkFocusInitial",""]],template:function(i,r){1&i&&(w(0,"h1",0),N(1),D(),w(2,"div",1),N(3),D(),w(4,"div",2)(5,"button",3),Y("click",function(){return r.onConfirmClick()}),N(6),D(),w(7,"button",4),N(8),D()()),2&i&&(M(1),Vn(r.title),M(2),bt(" ",r.message,"\n"),M(3),Vn(r.confirmButtonText),M(2),Vn(r.cancelButtonText))},dependencies:[Dg,WU,qU,YU,ZU],encapsulation:2})}return t})();function s4(t,n){if(1&t){const e=Zi();w(0,"div",2)(1,"button",3),Y("click",function(){return Fn(e),Nn(be().action())}),N(2),D()()}if(2&t){const e=be();M(2),bt(" ",e.data.action," ")}}const a4=["label"];function c4(t,n){}const l4=Math.pow(2,31)-1;class Eg{constructor(n,e){this._overlayRef=e,this._afterDismissed=new U,this._afterOpened=new U,this._onAction=new U,this._dismissedByAction=!1,this.containerInstance=n,n._onExit.subscribe(()=>this._finishDismiss())}dismiss(){this._afterDismissed.closed||this.containerInstance.exit(),clearTimeout(this._durationTimeoutId)}dismissWithAction(){this._onAction.closed||(this._dismissedByAction=!0,this._onAction.next(),this._onAction.complete(),this.dismiss()),clearTimeout(this._durationTimeoutId)}closeWithAction(){this.dismissWithAction()}_dismissAfter(n){this._durationTimeoutId=setTimeout(()=>this.dismiss(),Math.min(n,l4))}_open(){this._afterOpened.closed||(this._afterOpened.next(),this._afterOpened.complete())}_finishDismiss(){this._overlayRef.dispose(),this._onAction.closed||this._onAction.complete(),this._afterDismissed.next({dismissedByAction:this._dismissedByAction}),this._afterDismissed.complete(),this._dismissedByAction=!1}afterDismissed(){return this._afterDismissed}afterOpened(){return this.containerInstance._onEnter}onAction(){return this._onAction}}const ZS=new E("MatSnackBarData");class $d{constructor(){this.politeness="assertive",this.announcementMessage="",this.duration=0,this.data=null,this.horizontalPosition="center",this.verticalPosition="bottom"}}let d4=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["","matSnackBarLabel",""]],hostAttrs:[1,"mat-mdc-snack-bar-label","mdc-snackbar__label"]})}return t})(),u4=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["","matSnackBarActions",""]],hostAttrs:[1,"mat-mdc-snack-bar-actions","mdc-snackbar__actions"]})}return t})(),h4=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["","matSnackBarAction",""]],hostAttrs:[1,"mat-mdc-snack-bar-action","mdc-snackbar__action"]})}return t})(),f4=(()=>{class t{constructor(e,i){this.snackBarRef=e,this.data=i}action(){this.snackBarRef.dismissWithAction()}get hasAction(){return!!this.data.action}static#e=this.\u0275fac=function(i){return new(i||t)(f(Eg),f(ZS))};static#t=this.\u0275cmp=oe({type:t,selectors:[["simple-snack-bar"]],hostAttrs:[1,"mat-mdc-simple-snack-bar"],exportAs:["matSnackBar"],decls:3,vars:2,consts:[["matSnackBarLabel",""],["matSnackBarActions","",4,"ngIf"],["matSnackBarActions",""],["mat-button","","matSnackBarAction","",3,"click"]],template:function(i,r){1&i&&(w(0,"div",0),N(1),D(),O(2,s4,3,1,"div",1)),2&i&&(M(1),bt(" ",r.data.message,"\n"),M(1),F("ngIf",r.hasAction))},dependencies:[Ii,Dg,d4,u4,h4],styles:[".mat-mdc-simple-snack-bar{display:flex}"],encapsulation:2,changeDetection:0})}return t})();const p4={snackBarState:ir("state",[ln("void, hidden",Ke({transform:"scale(0.8)",opacity:0})),ln("visible",Ke({transform:"scale(1)",opacity:1})),dn("* => visible",Sn("150ms cubic-bezier(0, 0, 0.2, 1)")),dn("* => void, * => hidden",Sn("75ms cubic-bezier(0.4, 0.0, 1, 1)",Ke({opacity:0})))])};let m4=0,g4=(()=>{class t extends Td{constructor(e,i,r,o,s){super(),this._ngZone=e,this._elementRef=i,this._changeDetectorRef=r,this._platform=o,this.snackBarConfig=s,this._document=T(Z),this._trackedModals=new Set,this._announceDelay=150,this._destroyed=!1,this._onAnnounce=new U,this._onExit=new U,this._onEnter=new U,this._animationState="void",this._liveElementId="mat-snack-bar-container-live-"+m4++,this.attachDomPortal=a=>{this._assertNotAttached();const c=this._portalOutlet.attachDomPortal(a);return this._afterPortalAttached(),c},this._live="assertive"!==s.politeness||s.announcementMessage?"off"===s.politeness?"off":"polite":"assertive",this._platform.FIREFOX&&("polite"===this._live&&(this._role="status"),"assertive"===this._live&&(this._role="alert"))}attachComponentPortal(e){this._assertNotAttached();const i=this._portalOutlet.attachComponentPortal(e);return this._afterPortalAttached(),i}attachTemplatePortal(e){this._assertNotAttached();const i=this._portalOutlet.attachTemplatePortal(e);return this._afterPortalAttached(),i}onAnimationEnd(e){const{fromState:i,toState:r}=e;if(("void"===r&&"void"!==i||"hidden"===r)&&this._completeExit(),"visible"===r){const o=this._onEnter;this._ngZone.run(()=>{o.next(),o.complete()})}}enter(){this._destroyed||(this._animationState="visible",this._changeDetectorRef.detectChanges(),this._screenReaderAnnounce())}exit(){return this._ngZone.run(()=>{this._animationState="hidden",this._elementRef.nativeElement.setAttribute("mat-exit",""),clearTimeout(this._announceTimeoutId)}),this._onExit}ngOnDestroy(){this._destroyed=!0,this._clearFromModals(),this._completeExit()}_completeExit(){this._ngZone.onMicrotaskEmpty.pipe(vt(1)).subscribe(()=>{this._ngZone.run(()=>{this._onExit.next(),this._onExit.complete()})})}_afterPortalAttached(){const e=this._elementRef.nativeElement,i=this.snackBarConfig.panelClass;i&&(Array.isArray(i)?i.forEach(r=>e.classList.add(r)):e.classList.add(i)),this._exposeToModals()}_exposeToModals(){const e=this._liveElementId,i=this._document.querySelectorAll('body > .cdk-overlay-container [aria-modal="true"]');for(let r=0;r<i.length;r++){const o=i[r],s=o.getAttribute("aria-owns");this._trackedModals.add(o),s?-1===s.indexOf(e)&&o.setAttribute("aria-owns",s+" "+e):o.setAttribute("aria-owns",e)}}_clearFromModals(){this._trackedModals.forEach(e=>{const i=e.getAttribute("aria-owns");if(i){const r=i.replace(this._liveElementId,"").trim();r.length>0?e.setAttribute("aria-owns",r):e.removeAttribute("aria-owns")}}),this._trackedModals.clear()}_assertNotAttached(){this._portalOutlet.hasAttached()}_screenReaderAnnounce(){this._announceTimeoutId||this._ngZone.runOutsideAngular(()=>{this._announceTimeoutId=setTimeout(()=>{const e=this._elementRef.nativeElement.querySelector("[aria-hidden]"),i=this._elementRef.nativeElement.querySelector("[aria-live]");if(e&&i){let r=null;this._platform.isBrowser&&document.activeElement instanceof HTMLElement&&e.contains(document.activeElement)&&(r=document.activeElement),e.removeAttribute("aria-hidden"),i.appendChild(e),r?.focus(),this._onAnnounce.next(),this._onAnnounce.complete()}},this._announceDelay)})}static#e=this.\u0275fac=function(i){return new(i||t)(f(j),f($),f(ct),f(tt),f($d))};static#t=this.\u0275dir=x({type:t,viewQuery:function(i,r){if(1&i&&Se(Ai,7),2&i){let o;B(o=V())&&(r._portalOutlet=o.first)}},features:[k]})}return t})(),_4=(()=>{class t extends g4{_afterPortalAttached(){super._afterPortalAttached();const e=this._label.nativeElement,i="mdc-snackbar__label";e.classList.toggle(i,!e.querySelector(`.${i}`))}static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-snack-bar-container"]],viewQuery:function(i,r){if(1&i&&Se(a4,7),2&i){let o;B(o=V())&&(r._label=o.first)}},hostAttrs:[1,"mdc-snackbar","mat-mdc-snack-bar-container","mdc-snackbar--open"],hostVars:1,hostBindings:function(i,r){1&i&&bp("@state.done",function(s){return r.onAnimationEnd(s)}),2&i&&Dp("@state",r._animationState)},features:[k],decls:6,vars:3,consts:[[1,"mdc-snackbar__surface"],[1,"mat-mdc-snack-bar-label"],["label",""],["aria-hidden","true"],["cdkPortalOutlet",""]],template:function(i,r){1&i&&(w(0,"div",0)(1,"div",1,2)(3,"div",3),O(4,c4,0,0,"ng-template",4),D(),K(5,"div"),D()()),2&i&&(M(5),ue("aria-live",r._live)("role",r._role)("id",r._liveElementId))},dependencies:[Ai],styles:['.mdc-snackbar{display:none;position:fixed;right:0;bottom:0;left:0;align-items:center;justify-content:center;box-sizing:border-box;pointer-events:none;-webkit-tap-highlight-color:rgba(0,0,0,0)}.mdc-snackbar--opening,.mdc-snackbar--open,.mdc-snackbar--closing{display:flex}.mdc-snackbar--open .mdc-snackbar__label,.mdc-snackbar--open .mdc-snackbar__actions{visibility:visible}.mdc-snackbar__surface{padding-left:0;padding-right:8px;display:flex;align-items:center;justify-content:flex-start;box-sizing:border-box;transform:scale(0.8);opacity:0}.mdc-snackbar__surface::before{position:absolute;box-sizing:border-box;width:100%;height:100%;top:0;left:0;border:1px solid rgba(0,0,0,0);border-radius:inherit;content:"";pointer-events:none}@media screen and (forced-colors: active){.mdc-snackbar__surface::before{border-color:CanvasText}}[dir=rtl] .mdc-snackbar__surface,.mdc-snackbar__surface[dir=rtl]{padding-left:8px;padding-right:0}.mdc-snackbar--open .mdc-snackbar__surface{transform:scale(1);opacity:1;pointer-events:auto}.mdc-snackbar--closing .mdc-snackbar__surface{transform:scale(1)}.mdc-snackbar__label{padding-left:16px;padding-right:8px;width:100%;flex-grow:1;box-sizing:border-box;margin:0;visibility:hidden;padding-top:14px;padding-bottom:14px}[dir=rtl] .mdc-snackbar__label,.mdc-snackbar__label[dir=rtl]{padding-left:8px;padding-right:16px}.mdc-snackbar__label::before{display:inline;content:attr(data-mdc-snackbar-label-text)}.mdc-snackbar__actions{display:flex;flex-shrink:0;align-items:center;box-sizing:border-box;visibility:hidden}.mdc-snackbar__action+.mdc-snackbar__dismiss{margin-left:8px;margin-right:0}[dir=rtl] .mdc-snackbar__action+.mdc-snackbar__dismiss,.mdc-snackbar__action+.mdc-snackbar__dismiss[dir=rtl]{margin-left:0;margin-right:8px}.mat-mdc-snack-bar-container{margin:8px;--mdc-snackbar-container-shape:4px;position:static}.mat-mdc-snack-bar-container .mdc-snackbar__surface{min-width:344px}@media(max-width: 480px),(max-width: 344px){.mat-mdc-snack-bar-container .mdc-snackbar__surface{min-width:100%}}@media(max-width: 480px),(max-width: 344px){.mat-mdc-snack-bar-container{width:100vw}}.mat-mdc-snack-bar-container .mdc-snackbar__surface{max-width:672px}.mat-mdc-snack-bar-container .mdc-snackbar__surface{box-shadow:0px 3px 5px -1px rgba(0, 0, 0, 0.2), 0px 6px 10px 0px rgba(0, 0, 0, 0.14), 0px 1px 18px 0px rgba(0, 0, 0, 0.12)}.mat-mdc-snack-bar-container .mdc-snackbar__surface{background-color:var(--mdc-snackbar-container-color)}.mat-mdc-snack-bar-container .mdc-snackbar__surface{border-radius:var(--mdc-snackbar-container-shape)}.mat-mdc-snack-bar-container .mdc-snackbar__label{color:var(--mdc-snackbar-supporting-text-color)}.mat-mdc-snack-bar-container .mdc-snackbar__label{font-size:var(--mdc-snackbar-supporting-text-size);font-family:var(--mdc-snackbar-supporting-text-font);font-weight:var(--mdc-snackbar-supporting-text-weight);line-height:var(--mdc-snackbar-supporting-text-line-height)}.mat-mdc-snack-bar-container .mat-mdc-button.mat-mdc-snack-bar-action:not(:disabled){color:var(--mat-snack-bar-button-color);--mat-mdc-button-persistent-ripple-color: currentColor}.mat-mdc-snack-bar-container .mat-mdc-button.mat-mdc-snack-bar-action:not(:disabled) .mat-ripple-element{background-color:currentColor;opacity:.1}.mat-mdc-snack-bar-container .mdc-snackbar__label::before{display:none}.mat-mdc-snack-bar-handset,.mat-mdc-snack-bar-container,.mat-mdc-snack-bar-label{flex:1 1 auto}.mat-mdc-snack-bar-handset .mdc-snackbar__surface{width:100%}'],encapsulation:2,data:{animation:[p4.snackBarState]}})}return t})(),KS=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Bd,Vr,oi,YS,Ae,Ae]})}return t})();const QS=new E("mat-snack-bar-default-options",{providedIn:"root",factory:function b4(){return new $d}});let v4=(()=>{class t{get _openedSnackBarRef(){const e=this._parentSnackBar;return e?e._openedSnackBarRef:this._snackBarRefAtThisLevel}set _openedSnackBarRef(e){this._parentSnackBar?this._parentSnackBar._openedSnackBarRef=e:this._snackBarRefAtThisLevel=e}constructor(e,i,r,o,s,a){this._overlay=e,this._live=i,this._injector=r,this._breakpointObserver=o,this._parentSnackBar=s,this._defaultConfig=a,this._snackBarRefAtThisLevel=null}openFromComponent(e,i){return this._attach(e,i)}openFromTemplate(e,i){return this._attach(e,i)}open(e,i="",r){const o={...this._defaultConfig,...r};return o.data={message:e,action:i},o.announcementMessage===e&&(o.announcementMessage=void 0),this.openFromComponent(this.simpleSnackBarComponent,o)}dismiss(){this._openedSnackBarRef&&this._openedSnackBarRef.dismiss()}ngOnDestroy(){this._snackBarRefAtThisLevel&&this._snackBarRefAtThisLevel.dismiss()}_attachSnackBarContainer(e,i){const o=We.create({parent:i&&i.viewContainerRef&&i.viewContainerRef.injector||this._injector,providers:[{provide:$d,useValue:i}]}),s=new La(this.snackBarContainerComponent,i.viewContainerRef,o),a=e.attach(s);return a.instance.snackBarConfig=i,a.instance}_attach(e,i){const r={...new $d,...this._defaultConfig,...i},o=this._createOverlay(r),s=this._attachSnackBarContainer(o,r),a=new Eg(s,o);if(e instanceof Ue){const c=new ts(e,null,{$implicit:r.data,snackBarRef:a});a.instance=s.attachTemplatePortal(c)}else{const c=this._createInjector(r,a),l=new La(e,void 0,c),d=s.attachComponentPortal(l);a.instance=d.instance}return this._breakpointObserver.observe("(max-width: 599.98px) and (orientation: portrait)").pipe(Re(o.detachments())).subscribe(c=>{o.overlayElement.classList.toggle(this.handsetCssClass,c.matches)}),r.announcementMessage&&s._onAnnounce.subscribe(()=>{this._live.announce(r.announcementMessage,r.politeness)}),this._animateSnackBar(a,r),this._openedSnackBarRef=a,this._openedSnackBarRef}_animateSnackBar(e,i){e.afterDismissed().subscribe(()=>{this._openedSnackBarRef==e&&(this._openedSnackBarRef=null),i.announcementMessage&&this._live.clear()}),this._openedSnackBarRef?(this._openedSnackBarRef.afterDismissed().subscribe(()=>{e.containerInstance.enter()}),this._openedSnackBarRef.dismiss()):e.containerInstance.enter(),i.duration&&i.duration>0&&e.afterOpened().subscribe(()=>e._dismissAfter(i.duration))}_createOverlay(e){const i=new yg;i.direction=e.direction;let r=this._overlay.position().global();const o="rtl"===e.direction,s="left"===e.horizontalPosition||"start"===e.horizontalPosition&&!o||"end"===e.horizontalPosition&&o,a=!s&&"center"!==e.horizontalPosition;return s?r.left("0"):a?r.right("0"):r.centerHorizontally(),"top"===e.verticalPosition?r.top("0"):r.bottom("0"),i.positionStrategy=r,this._overlay.create(i)}_createInjector(e,i){return We.create({parent:e&&e.viewContainerRef&&e.viewContainerRef.injector||this._injector,providers:[{provide:Eg,useValue:i},{provide:ZS,useValue:e.data}]})}static#e=this.\u0275fac=function(i){return new(i||t)(b(Oi),b(AE),b(We),b(Xm),b(t,12),b(QS))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})(),Sg=(()=>{class t extends v4{constructor(e,i,r,o,s,a){super(e,i,r,o,s,a),this.simpleSnackBarComponent=f4,this.snackBarContainerComponent=_4,this.handsetCssClass="mat-mdc-snack-bar-handset"}static#e=this.\u0275fac=function(i){return new(i||t)(b(Oi),b(AE),b(We),b(Xm),b(t,12),b(QS))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:KS})}return t})();function Mg(){return nt((t,n)=>{let e=null;t._refCount++;const i=qe(n,void 0,void 0,void 0,()=>{if(!t||t._refCount<=0||0<--t._refCount)return void(e=null);const r=t._connection,o=e;e=null,r&&(!o||r===o)&&r.unsubscribe(),n.unsubscribe()});t.subscribe(i),i.closed||(e=t.connect())})}class Ig extends Ne{constructor(n,e){super(),this.source=n,this.subjectFactory=e,this._subject=null,this._refCount=0,this._connection=null,bb(n)&&(this.lift=n.lift)}_subscribe(n){return this.getSubject().subscribe(n)}getSubject(){const n=this._subject;return(!n||n.isStopped)&&(this._subject=this.subjectFactory()),this._subject}_teardown(){this._refCount=0;const{_connection:n}=this;this._subject=this._connection=null,n?.unsubscribe()}connect(){let n=this._connection;if(!n){n=this._connection=new Le;const e=this.getSubject();n.add(this.source.subscribe(qe(e,void 0,()=>{this._teardown(),e.complete()},i=>{this._teardown(),e.error(i)},()=>this._teardown()))),n.closed&&(this._connection=null,n=Le.EMPTY)}return n}refCount(){return Mg()(this)}}function Tg(t){return t&&"function"==typeof t.connect&&!(t instanceof Ig)}class XS{applyChanges(n,e,i,r,o){n.forEachOperation((s,a,c)=>{let l,d;if(null==s.previousIndex){const u=i(s,a,c);l=e.createEmbeddedView(u.templateRef,u.context,u.index),d=1}else null==c?(e.remove(a),d=3):(l=e.get(a),e.move(l,c),d=2);o&&o({context:l?.context,operation:d,record:s})})}detach(){}}let eM=(()=>{class t{constructor(){this._listeners=[]}notify(e,i){for(let r of this._listeners)r(e,i)}listen(e){return this._listeners.push(e),()=>{this._listeners=this._listeners.filter(i=>e!==i)}}ngOnDestroy(){this._listeners=[]}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();const Wa=new E("_ViewRepeater");function tM(t){return!!t&&(t instanceof Ne||ve(t.lift)&&ve(t.subscribe))}const y4=[[["caption"]],[["colgroup"],["col"]]],w4=["caption","colgroup, col"];function Ag(t){return class extends t{get sticky(){return this._sticky}set sticky(n){const e=this._sticky;this._sticky=me(n),this._hasStickyChanged=e!==this._sticky}hasStickyChanged(){const n=this._hasStickyChanged;return this._hasStickyChanged=!1,n}resetStickyChanged(){this._hasStickyChanged=!1}constructor(...n){super(...n),this._sticky=!1,this._hasStickyChanged=!1}}}const as=new E("CDK_TABLE");let cs=(()=>{class t{constructor(e){this.template=e}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkCellDef",""]]})}return t})(),ls=(()=>{class t{constructor(e){this.template=e}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkHeaderCellDef",""]]})}return t})(),Ud=(()=>{class t{constructor(e){this.template=e}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkFooterCellDef",""]]})}return t})();class E4{}const S4=Ag(E4);let Fi=(()=>{class t extends S4{get name(){return this._name}set name(e){this._setNameInput(e)}get stickyEnd(){return this._stickyEnd}set stickyEnd(e){const i=this._stickyEnd;this._stickyEnd=me(e),this._hasStickyChanged=i!==this._stickyEnd}constructor(e){super(),this._table=e,this._stickyEnd=!1}_updateColumnCssClassName(){this._columnCssClassName=[`cdk-column-${this.cssClassFriendlyName}`]}_setNameInput(e){e&&(this._name=e,this.cssClassFriendlyName=e.replace(/[^a-z0-9_-]/gi,"-"),this._updateColumnCssClassName())}static#e=this.\u0275fac=function(i){return new(i||t)(f(as,8))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkColumnDef",""]],contentQueries:function(i,r,o){if(1&i&&(xe(o,cs,5),xe(o,ls,5),xe(o,Ud,5)),2&i){let s;B(s=V())&&(r.cell=s.first),B(s=V())&&(r.headerCell=s.first),B(s=V())&&(r.footerCell=s.first)}},inputs:{sticky:"sticky",name:["cdkColumnDef","name"],stickyEnd:"stickyEnd"},features:[G([{provide:"MAT_SORT_HEADER_COLUMN_DEF",useExisting:t}]),k]})}return t})();class kg{constructor(n,e){e.nativeElement.classList.add(...n._columnCssClassName)}}let Rg=(()=>{class t extends kg{constructor(e,i){super(e,i)}static#e=this.\u0275fac=function(i){return new(i||t)(f(Fi),f($))};static#t=this.\u0275dir=x({type:t,selectors:[["cdk-header-cell"],["th","cdk-header-cell",""]],hostAttrs:["role","columnheader",1,"cdk-header-cell"],features:[k]})}return t})(),Og=(()=>{class t extends kg{constructor(e,i){if(super(e,i),1===e._table?._elementRef.nativeElement.nodeType){const r=e._table._elementRef.nativeElement.getAttribute("role");i.nativeElement.setAttribute("role","grid"===r||"treegrid"===r?"gridcell":"cell")}}static#e=this.\u0275fac=function(i){return new(i||t)(f(Fi),f($))};static#t=this.\u0275dir=x({type:t,selectors:[["cdk-cell"],["td","cdk-cell",""]],hostAttrs:[1,"cdk-cell"],features:[k]})}return t})();class iM{constructor(){this.tasks=[],this.endTasks=[]}}const Fg=new E("_COALESCED_STYLE_SCHEDULER");let rM=(()=>{class t{constructor(e){this._ngZone=e,this._currentSchedule=null,this._destroyed=new U}schedule(e){this._createScheduleIfNeeded(),this._currentSchedule.tasks.push(e)}scheduleEnd(e){this._createScheduleIfNeeded(),this._currentSchedule.endTasks.push(e)}ngOnDestroy(){this._destroyed.next(),this._destroyed.complete()}_createScheduleIfNeeded(){this._currentSchedule||(this._currentSchedule=new iM,this._getScheduleObservable().pipe(Re(this._destroyed)).subscribe(()=>{for(;this._currentSchedule.tasks.length||this._currentSchedule.endTasks.length;){const e=this._currentSchedule;this._currentSchedule=new iM;for(const i of e.tasks)i();for(const i of e.endTasks)i()}this._currentSchedule=null}))}_getScheduleObservable(){return this._ngZone.isStable?dt(Promise.resolve(void 0)):this._ngZone.onStable.pipe(vt(1))}static#e=this.\u0275fac=function(i){return new(i||t)(b(j))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})(),Ng=(()=>{class t{constructor(e,i){this.template=e,this._differs=i}ngOnChanges(e){if(!this._columnsDiffer){const i=e.columns&&e.columns.currentValue||[];this._columnsDiffer=this._differs.find(i).create(),this._columnsDiffer.diff(i)}}getColumnsDiff(){return this._columnsDiffer.diff(this.columns)}extractCellTemplate(e){return this instanceof qa?e.headerCell.template:this instanceof Ya?e.footerCell.template:e.cell.template}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue),f(Di))};static#t=this.\u0275dir=x({type:t,features:[ut]})}return t})();class M4 extends Ng{}const I4=Ag(M4);let qa=(()=>{class t extends I4{constructor(e,i,r){super(e,i),this._table=r}ngOnChanges(e){super.ngOnChanges(e)}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue),f(Di),f(as,8))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkHeaderRowDef",""]],inputs:{columns:["cdkHeaderRowDef","columns"],sticky:["cdkHeaderRowDefSticky","sticky"]},features:[k,ut]})}return t})();class T4 extends Ng{}const A4=Ag(T4);let Ya=(()=>{class t extends A4{constructor(e,i,r){super(e,i),this._table=r}ngOnChanges(e){super.ngOnChanges(e)}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue),f(Di),f(as,8))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkFooterRowDef",""]],inputs:{columns:["cdkFooterRowDef","columns"],sticky:["cdkFooterRowDefSticky","sticky"]},features:[k,ut]})}return t})(),Gd=(()=>{class t extends Ng{constructor(e,i,r){super(e,i),this._table=r}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue),f(Di),f(as,8))};static#t=this.\u0275dir=x({type:t,selectors:[["","cdkRowDef",""]],inputs:{columns:["cdkRowDefColumns","columns"],when:["cdkRowDefWhen","when"]},features:[k]})}return t})(),Ni=(()=>{class t{static#e=this.mostRecentCellOutlet=null;constructor(e){this._viewContainer=e,t.mostRecentCellOutlet=this}ngOnDestroy(){t.mostRecentCellOutlet===this&&(t.mostRecentCellOutlet=null)}static#t=this.\u0275fac=function(i){return new(i||t)(f(Je))};static#n=this.\u0275dir=x({type:t,selectors:[["","cdkCellOutlet",""]]})}return t})(),Pg=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275cmp=oe({type:t,selectors:[["cdk-header-row"],["tr","cdk-header-row",""]],hostAttrs:["role","row",1,"cdk-header-row"],decls:1,vars:0,consts:[["cdkCellOutlet",""]],template:function(i,r){1&i&&an(0,0)},dependencies:[Ni],encapsulation:2})}return t})(),Bg=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275cmp=oe({type:t,selectors:[["cdk-row"],["tr","cdk-row",""]],hostAttrs:["role","row",1,"cdk-row"],decls:1,vars:0,consts:[["cdkCellOutlet",""]],template:function(i,r){1&i&&an(0,0)},dependencies:[Ni],encapsulation:2})}return t})(),Wd=(()=>{class t{constructor(e){this.templateRef=e,this._contentClassName="cdk-no-data-row"}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue))};static#t=this.\u0275dir=x({type:t,selectors:[["ng-template","cdkNoDataRow",""]]})}return t})();const oM=["top","bottom","left","right"];class k4{constructor(n,e,i,r,o=!0,s=!0,a){this._isNativeHtmlTable=n,this._stickCellCss=e,this.direction=i,this._coalescedStyleScheduler=r,this._isBrowser=o,this._needsPositionStickyOnElement=s,this._positionListener=a,this._cachedCellWidths=[],this._borderCellCss={top:`${e}-border-elem-top`,bottom:`${e}-border-elem-bottom`,left:`${e}-border-elem-left`,right:`${e}-border-elem-right`}}clearStickyPositioning(n,e){const i=[];for(const r of n)if(r.nodeType===r.ELEMENT_NODE){i.push(r);for(let o=0;o<r.children.length;o++)i.push(r.children[o])}this._coalescedStyleScheduler.schedule(()=>{for(const r of i)this._removeStickyStyle(r,e)})}updateStickyColumns(n,e,i,r=!0){if(!n.length||!this._isBrowser||!e.some(h=>h)&&!i.some(h=>h))return void(this._positionListener&&(this._positionListener.stickyColumnsUpdated({sizes:[]}),this._positionListener.stickyEndColumnsUpdated({sizes:[]})));const o=n[0],s=o.children.length,a=this._getCellWidths(o,r),c=this._getStickyStartColumnPositions(a,e),l=this._getStickyEndColumnPositions(a,i),d=e.lastIndexOf(!0),u=i.indexOf(!0);this._coalescedStyleScheduler.schedule(()=>{const h="rtl"===this.direction,p=h?"right":"left",m=h?"left":"right";for(const g of n)for(let _=0;_<s;_++){const C=g.children[_];e[_]&&this._addStickyStyle(C,p,c[_],_===d),i[_]&&this._addStickyStyle(C,m,l[_],_===u)}this._positionListener&&(this._positionListener.stickyColumnsUpdated({sizes:-1===d?[]:a.slice(0,d+1).map((g,_)=>e[_]?g:null)}),this._positionListener.stickyEndColumnsUpdated({sizes:-1===u?[]:a.slice(u).map((g,_)=>i[_+u]?g:null).reverse()}))})}stickRows(n,e,i){if(!this._isBrowser)return;const r="bottom"===i?n.slice().reverse():n,o="bottom"===i?e.slice().reverse():e,s=[],a=[],c=[];for(let d=0,u=0;d<r.length;d++){if(!o[d])continue;s[d]=u;const h=r[d];c[d]=this._isNativeHtmlTable?Array.from(h.children):[h];const p=h.getBoundingClientRect().height;u+=p,a[d]=p}const l=o.lastIndexOf(!0);this._coalescedStyleScheduler.schedule(()=>{for(let d=0;d<r.length;d++){if(!o[d])continue;const u=s[d],h=d===l;for(const p of c[d])this._addStickyStyle(p,i,u,h)}"top"===i?this._positionListener?.stickyHeaderRowsUpdated({sizes:a,offsets:s,elements:c}):this._positionListener?.stickyFooterRowsUpdated({sizes:a,offsets:s,elements:c})})}updateStickyFooterContainer(n,e){if(!this._isNativeHtmlTable)return;const i=n.querySelector("tfoot");this._coalescedStyleScheduler.schedule(()=>{e.some(r=>!r)?this._removeStickyStyle(i,["bottom"]):this._addStickyStyle(i,"bottom",0,!1)})}_removeStickyStyle(n,e){for(const r of e)n.style[r]="",n.classList.remove(this._borderCellCss[r]);oM.some(r=>-1===e.indexOf(r)&&n.style[r])?n.style.zIndex=this._getCalculatedZIndex(n):(n.style.zIndex="",this._needsPositionStickyOnElement&&(n.style.position=""),n.classList.remove(this._stickCellCss))}_addStickyStyle(n,e,i,r){n.classList.add(this._stickCellCss),r&&n.classList.add(this._borderCellCss[e]),n.style[e]=`${i}px`,n.style.zIndex=this._getCalculatedZIndex(n),this._needsPositionStickyOnElement&&(n.style.cssText+="position: -webkit-sticky; position: sticky; ")}_getCalculatedZIndex(n){const e={top:100,bottom:10,left:1,right:1};let i=0;for(const r of oM)n.style[r]&&(i+=e[r]);return i?`${i}`:""}_getCellWidths(n,e=!0){if(!e&&this._cachedCellWidths.length)return this._cachedCellWidths;const i=[],r=n.children;for(let o=0;o<r.length;o++)i.push(r[o].getBoundingClientRect().width);return this._cachedCellWidths=i,i}_getStickyStartColumnPositions(n,e){const i=[];let r=0;for(let o=0;o<n.length;o++)e[o]&&(i[o]=r,r+=n[o]);return i}_getStickyEndColumnPositions(n,e){const i=[];let r=0;for(let o=n.length;o>0;o--)e[o]&&(i[o]=r,r+=n[o]);return i}}const Vg=new E("CDK_SPL");let qd=(()=>{class t{constructor(e,i){this.viewContainer=e,this.elementRef=i}static#e=this.\u0275fac=function(i){return new(i||t)(f(Je),f($))};static#t=this.\u0275dir=x({type:t,selectors:[["","rowOutlet",""]]})}return t})(),Yd=(()=>{class t{constructor(e,i){this.viewContainer=e,this.elementRef=i}static#e=this.\u0275fac=function(i){return new(i||t)(f(Je),f($))};static#t=this.\u0275dir=x({type:t,selectors:[["","headerRowOutlet",""]]})}return t})(),Zd=(()=>{class t{constructor(e,i){this.viewContainer=e,this.elementRef=i}static#e=this.\u0275fac=function(i){return new(i||t)(f(Je),f($))};static#t=this.\u0275dir=x({type:t,selectors:[["","footerRowOutlet",""]]})}return t})(),Kd=(()=>{class t{constructor(e,i){this.viewContainer=e,this.elementRef=i}static#e=this.\u0275fac=function(i){return new(i||t)(f(Je),f($))};static#t=this.\u0275dir=x({type:t,selectors:[["","noDataRowOutlet",""]]})}return t})(),Qd=(()=>{class t{get trackBy(){return this._trackByFn}set trackBy(e){this._trackByFn=e}get dataSource(){return this._dataSource}set dataSource(e){this._dataSource!==e&&this._switchDataSource(e)}get multiTemplateDataRows(){return this._multiTemplateDataRows}set multiTemplateDataRows(e){this._multiTemplateDataRows=me(e),this._rowOutlet&&this._rowOutlet.viewContainer.length&&(this._forceRenderDataRows(),this.updateStickyColumnStyles())}get fixedLayout(){return this._fixedLayout}set fixedLayout(e){this._fixedLayout=me(e),this._forceRecalculateCellWidths=!0,this._stickyColumnStylesNeedReset=!0}constructor(e,i,r,o,s,a,c,l,d,u,h,p){this._differs=e,this._changeDetectorRef=i,this._elementRef=r,this._dir=s,this._platform=c,this._viewRepeater=l,this._coalescedStyleScheduler=d,this._viewportRuler=u,this._stickyPositioningListener=h,this._ngZone=p,this._onDestroy=new U,this._columnDefsByName=new Map,this._customColumnDefs=new Set,this._customRowDefs=new Set,this._customHeaderRowDefs=new Set,this._customFooterRowDefs=new Set,this._headerRowDefChanged=!0,this._footerRowDefChanged=!0,this._stickyColumnStylesNeedReset=!0,this._forceRecalculateCellWidths=!0,this._cachedRenderRowsMap=new Map,this.stickyCssClass="cdk-table-sticky",this.needsPositionStickyOnElement=!0,this._isShowingNoDataRow=!1,this._multiTemplateDataRows=!1,this._fixedLayout=!1,this.contentChanged=new q,this.viewChange=new $t({start:0,end:Number.MAX_VALUE}),o||this._elementRef.nativeElement.setAttribute("role","table"),this._document=a,this._isNativeHtmlTable="TABLE"===this._elementRef.nativeElement.nodeName}ngOnInit(){this._setupStickyStyler(),this._isNativeHtmlTable&&this._applyNativeTableSections(),this._dataDiffer=this._differs.find([]).create((e,i)=>this.trackBy?this.trackBy(i.dataIndex,i.data):i),this._viewportRuler.change().pipe(Re(this._onDestroy)).subscribe(()=>{this._forceRecalculateCellWidths=!0})}ngAfterContentChecked(){this._cacheRowDefs(),this._cacheColumnDefs();const i=this._renderUpdatedColumns()||this._headerRowDefChanged||this._footerRowDefChanged;this._stickyColumnStylesNeedReset=this._stickyColumnStylesNeedReset||i,this._forceRecalculateCellWidths=i,this._headerRowDefChanged&&(this._forceRenderHeaderRows(),this._headerRowDefChanged=!1),this._footerRowDefChanged&&(this._forceRenderFooterRows(),this._footerRowDefChanged=!1),this.dataSource&&this._rowDefs.length>0&&!this._renderChangeSubscription?this._observeRenderChanges():this._stickyColumnStylesNeedReset&&this.updateStickyColumnStyles(),this._checkStickyStates()}ngOnDestroy(){[this._rowOutlet.viewContainer,this._headerRowOutlet.viewContainer,this._footerRowOutlet.viewContainer,this._cachedRenderRowsMap,this._customColumnDefs,this._customRowDefs,this._customHeaderRowDefs,this._customFooterRowDefs,this._columnDefsByName].forEach(e=>{e.clear()}),this._headerRowDefs=[],this._footerRowDefs=[],this._defaultRowDef=null,this._onDestroy.next(),this._onDestroy.complete(),Tg(this.dataSource)&&this.dataSource.disconnect(this)}renderRows(){this._renderRows=this._getAllRenderRows();const e=this._dataDiffer.diff(this._renderRows);if(!e)return this._updateNoDataRow(),void this.contentChanged.next();const i=this._rowOutlet.viewContainer;this._viewRepeater.applyChanges(e,i,(r,o,s)=>this._getEmbeddedViewArgs(r.item,s),r=>r.item.data,r=>{1===r.operation&&r.context&&this._renderCellTemplateForItem(r.record.item.rowDef,r.context)}),this._updateRowIndexContext(),e.forEachIdentityChange(r=>{i.get(r.currentIndex).context.$implicit=r.item.data}),this._updateNoDataRow(),this._ngZone&&j.isInAngularZone()?this._ngZone.onStable.pipe(vt(1),Re(this._onDestroy)).subscribe(()=>{this.updateStickyColumnStyles()}):this.updateStickyColumnStyles(),this.contentChanged.next()}addColumnDef(e){this._customColumnDefs.add(e)}removeColumnDef(e){this._customColumnDefs.delete(e)}addRowDef(e){this._customRowDefs.add(e)}removeRowDef(e){this._customRowDefs.delete(e)}addHeaderRowDef(e){this._customHeaderRowDefs.add(e),this._headerRowDefChanged=!0}removeHeaderRowDef(e){this._customHeaderRowDefs.delete(e),this._headerRowDefChanged=!0}addFooterRowDef(e){this._customFooterRowDefs.add(e),this._footerRowDefChanged=!0}removeFooterRowDef(e){this._customFooterRowDefs.delete(e),this._footerRowDefChanged=!0}setNoDataRow(e){this._customNoDataRow=e}updateStickyHeaderRowStyles(){const e=this._getRenderedRows(this._headerRowOutlet),r=this._elementRef.nativeElement.querySelector("thead");r&&(r.style.display=e.length?"":"none");const o=this._headerRowDefs.map(s=>s.sticky);this._stickyStyler.clearStickyPositioning(e,["top"]),this._stickyStyler.stickRows(e,o,"top"),this._headerRowDefs.forEach(s=>s.resetStickyChanged())}updateStickyFooterRowStyles(){const e=this._getRenderedRows(this._footerRowOutlet),r=this._elementRef.nativeElement.querySelector("tfoot");r&&(r.style.display=e.length?"":"none");const o=this._footerRowDefs.map(s=>s.sticky);this._stickyStyler.clearStickyPositioning(e,["bottom"]),this._stickyStyler.stickRows(e,o,"bottom"),this._stickyStyler.updateStickyFooterContainer(this._elementRef.nativeElement,o),this._footerRowDefs.forEach(s=>s.resetStickyChanged())}updateStickyColumnStyles(){const e=this._getRenderedRows(this._headerRowOutlet),i=this._getRenderedRows(this._rowOutlet),r=this._getRenderedRows(this._footerRowOutlet);(this._isNativeHtmlTable&&!this._fixedLayout||this._stickyColumnStylesNeedReset)&&(this._stickyStyler.clearStickyPositioning([...e,...i,...r],["left","right"]),this._stickyColumnStylesNeedReset=!1),e.forEach((o,s)=>{this._addStickyColumnStyles([o],this._headerRowDefs[s])}),this._rowDefs.forEach(o=>{const s=[];for(let a=0;a<i.length;a++)this._renderRows[a].rowDef===o&&s.push(i[a]);this._addStickyColumnStyles(s,o)}),r.forEach((o,s)=>{this._addStickyColumnStyles([o],this._footerRowDefs[s])}),Array.from(this._columnDefsByName.values()).forEach(o=>o.resetStickyChanged())}_getAllRenderRows(){const e=[],i=this._cachedRenderRowsMap;this._cachedRenderRowsMap=new Map;for(let r=0;r<this._data.length;r++){let o=this._data[r];const s=this._getRenderRowsForData(o,r,i.get(o));this._cachedRenderRowsMap.has(o)||this._cachedRenderRowsMap.set(o,new WeakMap);for(let a=0;a<s.length;a++){let c=s[a];const l=this._cachedRenderRowsMap.get(c.data);l.has(c.rowDef)?l.get(c.rowDef).push(c):l.set(c.rowDef,[c]),e.push(c)}}return e}_getRenderRowsForData(e,i,r){return this._getRowDefs(e,i).map(s=>{const a=r&&r.has(s)?r.get(s):[];if(a.length){const c=a.shift();return c.dataIndex=i,c}return{data:e,rowDef:s,dataIndex:i}})}_cacheColumnDefs(){this._columnDefsByName.clear(),Xd(this._getOwnDefs(this._contentColumnDefs),this._customColumnDefs).forEach(i=>{this._columnDefsByName.has(i.name),this._columnDefsByName.set(i.name,i)})}_cacheRowDefs(){this._headerRowDefs=Xd(this._getOwnDefs(this._contentHeaderRowDefs),this._customHeaderRowDefs),this._footerRowDefs=Xd(this._getOwnDefs(this._contentFooterRowDefs),this._customFooterRowDefs),this._rowDefs=Xd(this._getOwnDefs(this._contentRowDefs),this._customRowDefs);const e=this._rowDefs.filter(i=>!i.when);this._defaultRowDef=e[0]}_renderUpdatedColumns(){const e=(s,a)=>s||!!a.getColumnsDiff(),i=this._rowDefs.reduce(e,!1);i&&this._forceRenderDataRows();const r=this._headerRowDefs.reduce(e,!1);r&&this._forceRenderHeaderRows();const o=this._footerRowDefs.reduce(e,!1);return o&&this._forceRenderFooterRows(),i||r||o}_switchDataSource(e){this._data=[],Tg(this.dataSource)&&this.dataSource.disconnect(this),this._renderChangeSubscription&&(this._renderChangeSubscription.unsubscribe(),this._renderChangeSubscription=null),e||(this._dataDiffer&&this._dataDiffer.diff([]),this._rowOutlet.viewContainer.clear()),this._dataSource=e}_observeRenderChanges(){if(!this.dataSource)return;let e;Tg(this.dataSource)?e=this.dataSource.connect(this):tM(this.dataSource)?e=this.dataSource:Array.isArray(this.dataSource)&&(e=z(this.dataSource)),this._renderChangeSubscription=e.pipe(Re(this._onDestroy)).subscribe(i=>{this._data=i||[],this.renderRows()})}_forceRenderHeaderRows(){this._headerRowOutlet.viewContainer.length>0&&this._headerRowOutlet.viewContainer.clear(),this._headerRowDefs.forEach((e,i)=>this._renderRow(this._headerRowOutlet,e,i)),this.updateStickyHeaderRowStyles()}_forceRenderFooterRows(){this._footerRowOutlet.viewContainer.length>0&&this._footerRowOutlet.viewContainer.clear(),this._footerRowDefs.forEach((e,i)=>this._renderRow(this._footerRowOutlet,e,i)),this.updateStickyFooterRowStyles()}_addStickyColumnStyles(e,i){const r=Array.from(i.columns||[]).map(a=>this._columnDefsByName.get(a)),o=r.map(a=>a.sticky),s=r.map(a=>a.stickyEnd);this._stickyStyler.updateStickyColumns(e,o,s,!this._fixedLayout||this._forceRecalculateCellWidths)}_getRenderedRows(e){const i=[];for(let r=0;r<e.viewContainer.length;r++){const o=e.viewContainer.get(r);i.push(o.rootNodes[0])}return i}_getRowDefs(e,i){if(1==this._rowDefs.length)return[this._rowDefs[0]];let r=[];if(this.multiTemplateDataRows)r=this._rowDefs.filter(o=>!o.when||o.when(i,e));else{let o=this._rowDefs.find(s=>s.when&&s.when(i,e))||this._defaultRowDef;o&&r.push(o)}return r}_getEmbeddedViewArgs(e,i){return{templateRef:e.rowDef.template,context:{$implicit:e.data},index:i}}_renderRow(e,i,r,o={}){const s=e.viewContainer.createEmbeddedView(i.template,o,r);return this._renderCellTemplateForItem(i,o),s}_renderCellTemplateForItem(e,i){for(let r of this._getCellTemplates(e))Ni.mostRecentCellOutlet&&Ni.mostRecentCellOutlet._viewContainer.createEmbeddedView(r,i);this._changeDetectorRef.markForCheck()}_updateRowIndexContext(){const e=this._rowOutlet.viewContainer;for(let i=0,r=e.length;i<r;i++){const s=e.get(i).context;s.count=r,s.first=0===i,s.last=i===r-1,s.even=i%2==0,s.odd=!s.even,this.multiTemplateDataRows?(s.dataIndex=this._renderRows[i].dataIndex,s.renderIndex=i):s.index=this._renderRows[i].dataIndex}}_getCellTemplates(e){return e&&e.columns?Array.from(e.columns,i=>{const r=this._columnDefsByName.get(i);return e.extractCellTemplate(r)}):[]}_applyNativeTableSections(){const e=this._document.createDocumentFragment(),i=[{tag:"thead",outlets:[this._headerRowOutlet]},{tag:"tbody",outlets:[this._rowOutlet,this._noDataRowOutlet]},{tag:"tfoot",outlets:[this._footerRowOutlet]}];for(const r of i){const o=this._document.createElement(r.tag);o.setAttribute("role","rowgroup");for(const s of r.outlets)o.appendChild(s.elementRef.nativeElement);e.appendChild(o)}this._elementRef.nativeElement.appendChild(e)}_forceRenderDataRows(){this._dataDiffer.diff([]),this._rowOutlet.viewContainer.clear(),this.renderRows()}_checkStickyStates(){const e=(i,r)=>i||r.hasStickyChanged();this._headerRowDefs.reduce(e,!1)&&this.updateStickyHeaderRowStyles(),this._footerRowDefs.reduce(e,!1)&&this.updateStickyFooterRowStyles(),Array.from(this._columnDefsByName.values()).reduce(e,!1)&&(this._stickyColumnStylesNeedReset=!0,this.updateStickyColumnStyles())}_setupStickyStyler(){this._stickyStyler=new k4(this._isNativeHtmlTable,this.stickyCssClass,this._dir?this._dir.value:"ltr",this._coalescedStyleScheduler,this._platform.isBrowser,this.needsPositionStickyOnElement,this._stickyPositioningListener),(this._dir?this._dir.change:z()).pipe(Re(this._onDestroy)).subscribe(i=>{this._stickyStyler.direction=i,this.updateStickyColumnStyles()})}_getOwnDefs(e){return e.filter(i=>!i._table||i._table===this)}_updateNoDataRow(){const e=this._customNoDataRow||this._noDataRow;if(!e)return;const i=0===this._rowOutlet.viewContainer.length;if(i===this._isShowingNoDataRow)return;const r=this._noDataRowOutlet.viewContainer;if(i){const o=r.createEmbeddedView(e.templateRef),s=o.rootNodes[0];1===o.rootNodes.length&&s?.nodeType===this._document.ELEMENT_NODE&&(s.setAttribute("role","row"),s.classList.add(e._contentClassName))}else r.clear();this._isShowingNoDataRow=i,this._changeDetectorRef.markForCheck()}static#e=this.\u0275fac=function(i){return new(i||t)(f(Di),f(ct),f($),bi("role"),f(En,8),f(Z),f(tt),f(Wa),f(Fg),f(is),f(Vg,12),f(j,8))};static#t=this.\u0275cmp=oe({type:t,selectors:[["cdk-table"],["table","cdk-table",""]],contentQueries:function(i,r,o){if(1&i&&(xe(o,Wd,5),xe(o,Fi,5),xe(o,Gd,5),xe(o,qa,5),xe(o,Ya,5)),2&i){let s;B(s=V())&&(r._noDataRow=s.first),B(s=V())&&(r._contentColumnDefs=s),B(s=V())&&(r._contentRowDefs=s),B(s=V())&&(r._contentHeaderRowDefs=s),B(s=V())&&(r._contentFooterRowDefs=s)}},viewQuery:function(i,r){if(1&i&&(Se(qd,7),Se(Yd,7),Se(Zd,7),Se(Kd,7)),2&i){let o;B(o=V())&&(r._rowOutlet=o.first),B(o=V())&&(r._headerRowOutlet=o.first),B(o=V())&&(r._footerRowOutlet=o.first),B(o=V())&&(r._noDataRowOutlet=o.first)}},hostAttrs:["ngSkipHydration","",1,"cdk-table"],hostVars:2,hostBindings:function(i,r){2&i&&he("cdk-table-fixed-layout",r.fixedLayout)},inputs:{trackBy:"trackBy",dataSource:"dataSource",multiTemplateDataRows:"multiTemplateDataRows",fixedLayout:"fixedLayout"},outputs:{contentChanged:"contentChanged"},exportAs:["cdkTable"],features:[G([{provide:as,useExisting:t},{provide:Wa,useClass:XS},{provide:Fg,useClass:rM},{provide:Vg,useValue:null}])],ngContentSelectors:w4,decls:6,vars:0,consts:[["headerRowOutlet",""],["rowOutlet",""],["noDataRowOutlet",""],["footerRowOutlet",""]],template:function(i,r){1&i&&(at(y4),Q(0),Q(1,1),an(2,0)(3,1)(4,2)(5,3))},dependencies:[qd,Yd,Zd,Kd],styles:[".cdk-table-fixed-layout{table-layout:fixed}"],encapsulation:2})}return t})();function Xd(t,n){return t.concat(Array.from(n))}let O4=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[hg]})}return t})();const F4=[[["caption"]],[["colgroup"],["col"]]],N4=["caption","colgroup, col"];let jg=(()=>{class t extends Qd{constructor(){super(...arguments),this.stickyCssClass="mat-mdc-table-sticky",this.needsPositionStickyOnElement=!1}ngOnInit(){super.ngOnInit(),this._isNativeHtmlTable&&this._elementRef.nativeElement.querySelector("tbody").classList.add("mdc-data-table__content")}static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-table"],["table","mat-table",""]],hostAttrs:["ngSkipHydration","",1,"mat-mdc-table","mdc-data-table__table"],hostVars:2,hostBindings:function(i,r){2&i&&he("mdc-table-fixed-layout",r.fixedLayout)},exportAs:["matTable"],features:[G([{provide:Qd,useExisting:t},{provide:as,useExisting:t},{provide:Fg,useClass:rM},{provide:Wa,useClass:XS},{provide:Vg,useValue:null}]),k],ngContentSelectors:N4,decls:6,vars:0,consts:[["headerRowOutlet",""],["rowOutlet",""],["noDataRowOutlet",""],["footerRowOutlet",""]],template:function(i,r){1&i&&(at(F4),Q(0),Q(1,1),an(2,0)(3,1)(4,2)(5,3))},dependencies:[qd,Yd,Zd,Kd],styles:[".mat-mdc-table-sticky{position:sticky !important}.mdc-data-table{-webkit-overflow-scrolling:touch;display:inline-flex;flex-direction:column;box-sizing:border-box;position:relative}.mdc-data-table__table-container{-webkit-overflow-scrolling:touch;overflow-x:auto;width:100%}.mdc-data-table__table{min-width:100%;border:0;white-space:nowrap;border-spacing:0;table-layout:fixed}.mdc-data-table__cell{box-sizing:border-box;overflow:hidden;text-align:left;text-overflow:ellipsis}[dir=rtl] .mdc-data-table__cell,.mdc-data-table__cell[dir=rtl]{text-align:right}.mdc-data-table__cell--numeric{text-align:right}[dir=rtl] .mdc-data-table__cell--numeric,.mdc-data-table__cell--numeric[dir=rtl]{text-align:left}.mdc-data-table__header-cell{box-sizing:border-box;text-overflow:ellipsis;overflow:hidden;outline:none;text-align:left}[dir=rtl] .mdc-data-table__header-cell,.mdc-data-table__header-cell[dir=rtl]{text-align:right}.mdc-data-table__header-cell--numeric{text-align:right}[dir=rtl] .mdc-data-table__header-cell--numeric,.mdc-data-table__header-cell--numeric[dir=rtl]{text-align:left}.mdc-data-table__header-cell-wrapper{align-items:center;display:inline-flex;vertical-align:middle}.mdc-data-table__cell,.mdc-data-table__header-cell{padding:0 16px 0 16px}.mdc-data-table__header-cell--checkbox,.mdc-data-table__cell--checkbox{padding-left:4px;padding-right:0}[dir=rtl] .mdc-data-table__header-cell--checkbox,[dir=rtl] .mdc-data-table__cell--checkbox,.mdc-data-table__header-cell--checkbox[dir=rtl],.mdc-data-table__cell--checkbox[dir=rtl]{padding-left:0;padding-right:4px}mat-table{display:block}mat-header-row{min-height:56px}mat-row,mat-footer-row{min-height:48px}mat-row,mat-header-row,mat-footer-row{display:flex;border-width:0;border-bottom-width:1px;border-style:solid;align-items:center;box-sizing:border-box}mat-cell:first-of-type,mat-header-cell:first-of-type,mat-footer-cell:first-of-type{padding-left:24px}[dir=rtl] mat-cell:first-of-type:not(:only-of-type),[dir=rtl] mat-header-cell:first-of-type:not(:only-of-type),[dir=rtl] mat-footer-cell:first-of-type:not(:only-of-type){padding-left:0;padding-right:24px}mat-cell:last-of-type,mat-header-cell:last-of-type,mat-footer-cell:last-of-type{padding-right:24px}[dir=rtl] mat-cell:last-of-type:not(:only-of-type),[dir=rtl] mat-header-cell:last-of-type:not(:only-of-type),[dir=rtl] mat-footer-cell:last-of-type:not(:only-of-type){padding-right:0;padding-left:24px}mat-cell,mat-header-cell,mat-footer-cell{flex:1;display:flex;align-items:center;overflow:hidden;word-wrap:break-word;min-height:inherit}.mat-mdc-table{--mat-table-row-item-outline-width:1px;table-layout:auto;white-space:normal;background-color:var(--mat-table-background-color)}.mat-mdc-header-row{-moz-osx-font-smoothing:grayscale;-webkit-font-smoothing:antialiased;height:var(--mat-table-header-container-height, 56px);color:var(--mat-table-header-headline-color, rgba(0, 0, 0, 0.87));font-family:var(--mat-table-header-headline-font, Roboto, sans-serif);line-height:var(--mat-table-header-headline-line-height);font-size:var(--mat-table-header-headline-size, 14px);font-weight:var(--mat-table-header-headline-weight, 500)}.mat-mdc-row{height:var(--mat-table-row-item-container-height, 52px);color:var(--mat-table-row-item-label-text-color, rgba(0, 0, 0, 0.87))}.mat-mdc-row,.mdc-data-table__content{-moz-osx-font-smoothing:grayscale;-webkit-font-smoothing:antialiased;font-family:var(--mat-table-row-item-label-text-font, Roboto, sans-serif);line-height:var(--mat-table-row-item-label-text-line-height);font-size:var(--mat-table-row-item-label-text-size, 14px);font-weight:var(--mat-table-row-item-label-text-weight)}.mat-mdc-footer-row{-moz-osx-font-smoothing:grayscale;-webkit-font-smoothing:antialiased;height:var(--mat-table-footer-container-height, 52px);color:var(--mat-table-row-item-label-text-color, rgba(0, 0, 0, 0.87));font-family:var(--mat-table-footer-supporting-text-font, Roboto, sans-serif);line-height:var(--mat-table-footer-supporting-text-line-height);font-size:var(--mat-table-footer-supporting-text-size, 14px);font-weight:var(--mat-table-footer-supporting-text-weight);letter-spacing:var(--mat-table-footer-supporting-text-tracking)}.mat-mdc-header-cell{border-bottom-color:var(--mat-table-row-item-outline-color, rgba(0, 0, 0, 0.12));border-bottom-width:var(--mat-table-row-item-outline-width, 1px);border-bottom-style:solid;letter-spacing:var(--mat-table-header-headline-tracking);font-weight:inherit;line-height:inherit}.mat-mdc-cell{border-bottom-color:var(--mat-table-row-item-outline-color, rgba(0, 0, 0, 0.12));border-bottom-width:var(--mat-table-row-item-outline-width, 1px);border-bottom-style:solid;letter-spacing:var(--mat-table-row-item-label-text-tracking);line-height:inherit}.mdc-data-table__row:last-child .mat-mdc-cell{border-bottom:none}.mat-mdc-footer-cell{letter-spacing:var(--mat-table-row-item-label-text-tracking)}mat-row.mat-mdc-row,mat-header-row.mat-mdc-header-row,mat-footer-row.mat-mdc-footer-row{border-bottom:none}.mat-mdc-table tbody,.mat-mdc-table tfoot,.mat-mdc-table thead,.mat-mdc-cell,.mat-mdc-footer-cell,.mat-mdc-header-row,.mat-mdc-row,.mat-mdc-footer-row,.mat-mdc-table .mat-mdc-header-cell{background:inherit}.mat-mdc-table mat-header-row.mat-mdc-header-row,.mat-mdc-table mat-row.mat-mdc-row,.mat-mdc-table mat-footer-row.mat-mdc-footer-cell{height:unset}mat-header-cell.mat-mdc-header-cell,mat-cell.mat-mdc-cell,mat-footer-cell.mat-mdc-footer-cell{align-self:stretch}"],encapsulation:2})}return t})(),Jd=(()=>{class t extends cs{static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275dir=x({type:t,selectors:[["","matCellDef",""]],features:[G([{provide:cs,useExisting:t}]),k]})}return t})(),eu=(()=>{class t extends ls{static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275dir=x({type:t,selectors:[["","matHeaderCellDef",""]],features:[G([{provide:ls,useExisting:t}]),k]})}return t})(),tu=(()=>{class t extends Fi{get name(){return this._name}set name(e){this._setNameInput(e)}_updateColumnCssClassName(){super._updateColumnCssClassName(),this._columnCssClassName.push(`mat-column-${this.cssClassFriendlyName}`)}static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275dir=x({type:t,selectors:[["","matColumnDef",""]],inputs:{sticky:"sticky",name:["matColumnDef","name"]},features:[G([{provide:Fi,useExisting:t},{provide:"MAT_SORT_HEADER_COLUMN_DEF",useExisting:t}]),k]})}return t})(),nu=(()=>{class t extends Rg{static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275dir=x({type:t,selectors:[["mat-header-cell"],["th","mat-header-cell",""]],hostAttrs:["role","columnheader",1,"mat-mdc-header-cell","mdc-data-table__header-cell"],features:[k]})}return t})(),iu=(()=>{class t extends Og{static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275dir=x({type:t,selectors:[["mat-cell"],["td","mat-cell",""]],hostAttrs:[1,"mat-mdc-cell","mdc-data-table__cell"],features:[k]})}return t})(),Hg=(()=>{class t extends qa{static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275dir=x({type:t,selectors:[["","matHeaderRowDef",""]],inputs:{columns:["matHeaderRowDef","columns"],sticky:["matHeaderRowDefSticky","sticky"]},features:[G([{provide:qa,useExisting:t}]),k]})}return t})(),zg=(()=>{class t extends Gd{static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275dir=x({type:t,selectors:[["","matRowDef",""]],inputs:{columns:["matRowDefColumns","columns"],when:["matRowDefWhen","when"]},features:[G([{provide:Gd,useExisting:t}]),k]})}return t})(),$g=(()=>{class t extends Pg{static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-header-row"],["tr","mat-header-row",""]],hostAttrs:["role","row",1,"mat-mdc-header-row","mdc-data-table__header-row"],exportAs:["matHeaderRow"],features:[G([{provide:Pg,useExisting:t}]),k],decls:1,vars:0,consts:[["cdkCellOutlet",""]],template:function(i,r){1&i&&an(0,0)},dependencies:[Ni],encapsulation:2})}return t})(),Ug=(()=>{class t extends Bg{static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-row"],["tr","mat-row",""]],hostAttrs:["role","row",1,"mat-mdc-row","mdc-data-table__row"],exportAs:["matRow"],features:[G([{provide:Bg,useExisting:t}]),k],decls:1,vars:0,consts:[["cdkCellOutlet",""]],template:function(i,r){1&i&&an(0,0)},dependencies:[Ni],encapsulation:2})}return t})(),$4=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Ae,O4,Ae]})}return t})();const aM=Xo({passive:!0});let G4=(()=>{class t{constructor(e,i){this._platform=e,this._ngZone=i,this._monitoredElements=new Map}monitor(e){if(!this._platform.isBrowser)return Qt;const i=Ti(e),r=this._monitoredElements.get(i);if(r)return r.subject;const o=new U,s="cdk-text-field-autofilled",a=c=>{"cdk-text-field-autofill-start"!==c.animationName||i.classList.contains(s)?"cdk-text-field-autofill-end"===c.animationName&&i.classList.contains(s)&&(i.classList.remove(s),this._ngZone.run(()=>o.next({target:c.target,isAutofilled:!1}))):(i.classList.add(s),this._ngZone.run(()=>o.next({target:c.target,isAutofilled:!0})))};return this._ngZone.runOutsideAngular(()=>{i.addEventListener("animationstart",a,aM),i.classList.add("cdk-text-field-autofill-monitored")}),this._monitoredElements.set(i,{subject:o,unlisten:()=>{i.removeEventListener("animationstart",a,aM)}}),o}stopMonitoring(e){const i=Ti(e),r=this._monitoredElements.get(i);r&&(r.unlisten(),r.subject.complete(),i.classList.remove("cdk-text-field-autofill-monitored"),i.classList.remove("cdk-text-field-autofilled"),this._monitoredElements.delete(i))}ngOnDestroy(){this._monitoredElements.forEach((e,i)=>this.stopMonitoring(i))}static#e=this.\u0275fac=function(i){return new(i||t)(b(tt),b(j))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),W4=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({})}return t})(),cM=(()=>{class t{constructor(e,i){this._renderer=e,this._elementRef=i,this.onChange=r=>{},this.onTouched=()=>{}}setProperty(e,i){this._renderer.setProperty(this._elementRef.nativeElement,e,i)}registerOnTouched(e){this.onTouched=e}registerOnChange(e){this.onChange=e}setDisabledState(e){this.setProperty("disabled",e)}static#e=this.\u0275fac=function(i){return new(i||t)(f(yi),f($))};static#t=this.\u0275dir=x({type:t})}return t})(),$r=(()=>{class t extends cM{static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275dir=x({type:t,features:[k]})}return t})();const $n=new E("NgValueAccessor"),Y4={provide:$n,useExisting:Pe(()=>ru),multi:!0},K4=new E("CompositionEventMode");let ru=(()=>{class t extends cM{constructor(e,i,r){super(e,i),this._compositionMode=r,this._composing=!1,null==this._compositionMode&&(this._compositionMode=!function Z4(){const t=Ji()?Ji().getUserAgent():"";return/android (\d+)/.test(t.toLowerCase())}())}writeValue(e){this.setProperty("value",e??"")}_handleInput(e){(!this._compositionMode||this._compositionMode&&!this._composing)&&this.onChange(e)}_compositionStart(){this._composing=!0}_compositionEnd(e){this._composing=!1,this._compositionMode&&this.onChange(e)}static#e=this.\u0275fac=function(i){return new(i||t)(f(yi),f($),f(K4,8))};static#t=this.\u0275dir=x({type:t,selectors:[["input","formControlName","",3,"type","checkbox"],["textarea","formControlName",""],["input","formControl","",3,"type","checkbox"],["textarea","formControl",""],["input","ngModel","",3,"type","checkbox"],["textarea","ngModel",""],["","ngDefaultControl",""]],hostBindings:function(i,r){1&i&&Y("input",function(s){return r._handleInput(s.target.value)})("blur",function(){return r.onTouched()})("compositionstart",function(){return r._compositionStart()})("compositionend",function(s){return r._compositionEnd(s.target.value)})},features:[G([Y4]),k]})}return t})();function cr(t){return null==t||("string"==typeof t||Array.isArray(t))&&0===t.length}function dM(t){return null!=t&&"number"==typeof t.length}const kt=new E("NgValidators"),lr=new E("NgAsyncValidators"),Q4=/^(?=.{1,254}$)(?=.{1,64}@)[a-zA-Z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[a-zA-Z0-9!#$%&'*+/=?^_`{|}~-]+)*@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$/;class uM{static min(n){return function hM(t){return n=>{if(cr(n.value)||cr(t))return null;const e=parseFloat(n.value);return!isNaN(e)&&e<t?{min:{min:t,actual:n.value}}:null}}(n)}static max(n){return function fM(t){return n=>{if(cr(n.value)||cr(t))return null;const e=parseFloat(n.value);return!isNaN(e)&&e>t?{max:{max:t,actual:n.value}}:null}}(n)}static required(n){return function pM(t){return cr(t.value)?{required:!0}:null}(n)}static requiredTrue(n){return function mM(t){return!0===t.value?null:{required:!0}}(n)}static email(n){return function gM(t){return cr(t.value)||Q4.test(t.value)?null:{email:!0}}(n)}static minLength(n){return function _M(t){return n=>cr(n.value)||!dM(n.value)?null:n.value.length<t?{minlength:{requiredLength:t,actualLength:n.value.length}}:null}(n)}static maxLength(n){return function bM(t){return n=>dM(n.value)&&n.value.length>t?{maxlength:{requiredLength:t,actualLength:n.value.length}}:null}(n)}static pattern(n){return function vM(t){if(!t)return ou;let n,e;return"string"==typeof t?(e="","^"!==t.charAt(0)&&(e+="^"),e+=t,"$"!==t.charAt(t.length-1)&&(e+="$"),n=new RegExp(e)):(e=t.toString(),n=t),i=>{if(cr(i.value))return null;const r=i.value;return n.test(r)?null:{pattern:{requiredPattern:e,actualValue:r}}}}(n)}static nullValidator(n){return null}static compose(n){return EM(n)}static composeAsync(n){return SM(n)}}function ou(t){return null}function yM(t){return null!=t}function wM(t){return pa(t)?dt(t):t}function xM(t){let n={};return t.forEach(e=>{n=null!=e?{...n,...e}:n}),0===Object.keys(n).length?null:n}function CM(t,n){return n.map(e=>e(t))}function DM(t){return t.map(n=>function X4(t){return!t.validate}(n)?n:e=>n.validate(e))}function EM(t){if(!t)return null;const n=t.filter(yM);return 0==n.length?null:function(e){return xM(CM(e,n))}}function Gg(t){return null!=t?EM(DM(t)):null}function SM(t){if(!t)return null;const n=t.filter(yM);return 0==n.length?null:function(e){return bg(CM(e,n).map(wM)).pipe(pe(xM))}}function Wg(t){return null!=t?SM(DM(t)):null}function MM(t,n){return null===t?[n]:Array.isArray(t)?[...t,n]:[t,n]}function IM(t){return t._rawValidators}function TM(t){return t._rawAsyncValidators}function qg(t){return t?Array.isArray(t)?t:[t]:[]}function su(t,n){return Array.isArray(t)?t.includes(n):t===n}function AM(t,n){const e=qg(n);return qg(t).forEach(r=>{su(e,r)||e.push(r)}),e}function kM(t,n){return qg(n).filter(e=>!su(t,e))}class RM{constructor(){this._rawValidators=[],this._rawAsyncValidators=[],this._onDestroyCallbacks=[]}get value(){return this.control?this.control.value:null}get valid(){return this.control?this.control.valid:null}get invalid(){return this.control?this.control.invalid:null}get pending(){return this.control?this.control.pending:null}get disabled(){return this.control?this.control.disabled:null}get enabled(){return this.control?this.control.enabled:null}get errors(){return this.control?this.control.errors:null}get pristine(){return this.control?this.control.pristine:null}get dirty(){return this.control?this.control.dirty:null}get touched(){return this.control?this.control.touched:null}get status(){return this.control?this.control.status:null}get untouched(){return this.control?this.control.untouched:null}get statusChanges(){return this.control?this.control.statusChanges:null}get valueChanges(){return this.control?this.control.valueChanges:null}get path(){return null}_setValidators(n){this._rawValidators=n||[],this._composedValidatorFn=Gg(this._rawValidators)}_setAsyncValidators(n){this._rawAsyncValidators=n||[],this._composedAsyncValidatorFn=Wg(this._rawAsyncValidators)}get validator(){return this._composedValidatorFn||null}get asyncValidator(){return this._composedAsyncValidatorFn||null}_registerOnDestroy(n){this._onDestroyCallbacks.push(n)}_invokeOnDestroyCallbacks(){this._onDestroyCallbacks.forEach(n=>n()),this._onDestroyCallbacks=[]}reset(n=void 0){this.control&&this.control.reset(n)}hasError(n,e){return!!this.control&&this.control.hasError(n,e)}getError(n,e){return this.control?this.control.getError(n,e):null}}class zt extends RM{get formDirective(){return null}get path(){return null}}class ai extends RM{constructor(){super(...arguments),this._parent=null,this.name=null,this.valueAccessor=null}}class OM{constructor(n){this._cd=n}get isTouched(){return!!this._cd?.control?.touched}get isUntouched(){return!!this._cd?.control?.untouched}get isPristine(){return!!this._cd?.control?.pristine}get isDirty(){return!!this._cd?.control?.dirty}get isValid(){return!!this._cd?.control?.valid}get isInvalid(){return!!this._cd?.control?.invalid}get isPending(){return!!this._cd?.control?.pending}get isSubmitted(){return!!this._cd?.submitted}}let FM=(()=>{class t extends OM{constructor(e){super(e)}static#e=this.\u0275fac=function(i){return new(i||t)(f(ai,2))};static#t=this.\u0275dir=x({type:t,selectors:[["","formControlName",""],["","ngModel",""],["","formControl",""]],hostVars:14,hostBindings:function(i,r){2&i&&he("ng-untouched",r.isUntouched)("ng-touched",r.isTouched)("ng-pristine",r.isPristine)("ng-dirty",r.isDirty)("ng-valid",r.isValid)("ng-invalid",r.isInvalid)("ng-pending",r.isPending)},features:[k]})}return t})(),NM=(()=>{class t extends OM{constructor(e){super(e)}static#e=this.\u0275fac=function(i){return new(i||t)(f(zt,10))};static#t=this.\u0275dir=x({type:t,selectors:[["","formGroupName",""],["","formArrayName",""],["","ngModelGroup",""],["","formGroup",""],["form",3,"ngNoForm",""],["","ngForm",""]],hostVars:16,hostBindings:function(i,r){2&i&&he("ng-untouched",r.isUntouched)("ng-touched",r.isTouched)("ng-pristine",r.isPristine)("ng-dirty",r.isDirty)("ng-valid",r.isValid)("ng-invalid",r.isInvalid)("ng-pending",r.isPending)("ng-submitted",r.isSubmitted)},features:[k]})}return t})();const Za="VALID",cu="INVALID",ds="PENDING",Ka="DISABLED";function Kg(t){return(lu(t)?t.validators:t)||null}function Qg(t,n){return(lu(n)?n.asyncValidators:t)||null}function lu(t){return null!=t&&!Array.isArray(t)&&"object"==typeof t}class VM{constructor(n,e){this._pendingDirty=!1,this._hasOwnPendingAsyncValidator=!1,this._pendingTouched=!1,this._onCollectionChange=()=>{},this._parent=null,this.pristine=!0,this.touched=!1,this._onDisabledChange=[],this._assignValidators(n),this._assignAsyncValidators(e)}get validator(){return this._composedValidatorFn}set validator(n){this._rawValidators=this._composedValidatorFn=n}get asyncValidator(){return this._composedAsyncValidatorFn}set asyncValidator(n){this._rawAsyncValidators=this._composedAsyncValidatorFn=n}get parent(){return this._parent}get valid(){return this.status===Za}get invalid(){return this.status===cu}get pending(){return this.status==ds}get disabled(){return this.status===Ka}get enabled(){return this.status!==Ka}get dirty(){return!this.pristine}get untouched(){return!this.touched}get updateOn(){return this._updateOn?this._updateOn:this.parent?this.parent.updateOn:"change"}setValidators(n){this._assignValidators(n)}setAsyncValidators(n){this._assignAsyncValidators(n)}addValidators(n){this.setValidators(AM(n,this._rawValidators))}addAsyncValidators(n){this.setAsyncValidators(AM(n,this._rawAsyncValidators))}removeValidators(n){this.setValidators(kM(n,this._rawValidators))}removeAsyncValidators(n){this.setAsyncValidators(kM(n,this._rawAsyncValidators))}hasValidator(n){return su(this._rawValidators,n)}hasAsyncValidator(n){return su(this._rawAsyncValidators,n)}clearValidators(){this.validator=null}clearAsyncValidators(){this.asyncValidator=null}markAsTouched(n={}){this.touched=!0,this._parent&&!n.onlySelf&&this._parent.markAsTouched(n)}markAllAsTouched(){this.markAsTouched({onlySelf:!0}),this._forEachChild(n=>n.markAllAsTouched())}markAsUntouched(n={}){this.touched=!1,this._pendingTouched=!1,this._forEachChild(e=>{e.markAsUntouched({onlySelf:!0})}),this._parent&&!n.onlySelf&&this._parent._updateTouched(n)}markAsDirty(n={}){this.pristine=!1,this._parent&&!n.onlySelf&&this._parent.markAsDirty(n)}markAsPristine(n={}){this.pristine=!0,this._pendingDirty=!1,this._forEachChild(e=>{e.markAsPristine({onlySelf:!0})}),this._parent&&!n.onlySelf&&this._parent._updatePristine(n)}markAsPending(n={}){this.status=ds,!1!==n.emitEvent&&this.statusChanges.emit(this.status),this._parent&&!n.onlySelf&&this._parent.markAsPending(n)}disable(n={}){const e=this._parentMarkedDirty(n.onlySelf);this.status=Ka,this.errors=null,this._forEachChild(i=>{i.disable({...n,onlySelf:!0})}),this._updateValue(),!1!==n.emitEvent&&(this.valueChanges.emit(this.value),this.statusChanges.emit(this.status)),this._updateAncestors({...n,skipPristineCheck:e}),this._onDisabledChange.forEach(i=>i(!0))}enable(n={}){const e=this._parentMarkedDirty(n.onlySelf);this.status=Za,this._forEachChild(i=>{i.enable({...n,onlySelf:!0})}),this.updateValueAndValidity({onlySelf:!0,emitEvent:n.emitEvent}),this._updateAncestors({...n,skipPristineCheck:e}),this._onDisabledChange.forEach(i=>i(!1))}_updateAncestors(n){this._parent&&!n.onlySelf&&(this._parent.updateValueAndValidity(n),n.skipPristineCheck||this._parent._updatePristine(),this._parent._updateTouched())}setParent(n){this._parent=n}getRawValue(){return this.value}updateValueAndValidity(n={}){this._setInitialStatus(),this._updateValue(),this.enabled&&(this._cancelExistingSubscription(),this.errors=this._runValidator(),this.status=this._calculateStatus(),(this.status===Za||this.status===ds)&&this._runAsyncValidator(n.emitEvent)),!1!==n.emitEvent&&(this.valueChanges.emit(this.value),this.statusChanges.emit(this.status)),this._parent&&!n.onlySelf&&this._parent.updateValueAndValidity(n)}_updateTreeValidity(n={emitEvent:!0}){this._forEachChild(e=>e._updateTreeValidity(n)),this.updateValueAndValidity({onlySelf:!0,emitEvent:n.emitEvent})}_setInitialStatus(){this.status=this._allControlsDisabled()?Ka:Za}_runValidator(){return this.validator?this.validator(this):null}_runAsyncValidator(n){if(this.asyncValidator){this.status=ds,this._hasOwnPendingAsyncValidator=!0;const e=wM(this.asyncValidator(this));this._asyncValidationSubscription=e.subscribe(i=>{this._hasOwnPendingAsyncValidator=!1,this.setErrors(i,{emitEvent:n})})}}_cancelExistingSubscription(){this._asyncValidationSubscription&&(this._asyncValidationSubscription.unsubscribe(),this._hasOwnPendingAsyncValidator=!1)}setErrors(n,e={}){this.errors=n,this._updateControlsErrors(!1!==e.emitEvent)}get(n){let e=n;return null==e||(Array.isArray(e)||(e=e.split(".")),0===e.length)?null:e.reduce((i,r)=>i&&i._find(r),this)}getError(n,e){const i=e?this.get(e):this;return i&&i.errors?i.errors[n]:null}hasError(n,e){return!!this.getError(n,e)}get root(){let n=this;for(;n._parent;)n=n._parent;return n}_updateControlsErrors(n){this.status=this._calculateStatus(),n&&this.statusChanges.emit(this.status),this._parent&&this._parent._updateControlsErrors(n)}_initObservables(){this.valueChanges=new q,this.statusChanges=new q}_calculateStatus(){return this._allControlsDisabled()?Ka:this.errors?cu:this._hasOwnPendingAsyncValidator||this._anyControlsHaveStatus(ds)?ds:this._anyControlsHaveStatus(cu)?cu:Za}_anyControlsHaveStatus(n){return this._anyControls(e=>e.status===n)}_anyControlsDirty(){return this._anyControls(n=>n.dirty)}_anyControlsTouched(){return this._anyControls(n=>n.touched)}_updatePristine(n={}){this.pristine=!this._anyControlsDirty(),this._parent&&!n.onlySelf&&this._parent._updatePristine(n)}_updateTouched(n={}){this.touched=this._anyControlsTouched(),this._parent&&!n.onlySelf&&this._parent._updateTouched(n)}_registerOnCollectionChange(n){this._onCollectionChange=n}_setUpdateStrategy(n){lu(n)&&null!=n.updateOn&&(this._updateOn=n.updateOn)}_parentMarkedDirty(n){return!n&&!(!this._parent||!this._parent.dirty)&&!this._parent._anyControlsDirty()}_find(n){return null}_assignValidators(n){this._rawValidators=Array.isArray(n)?n.slice():n,this._composedValidatorFn=function n5(t){return Array.isArray(t)?Gg(t):t||null}(this._rawValidators)}_assignAsyncValidators(n){this._rawAsyncValidators=Array.isArray(n)?n.slice():n,this._composedAsyncValidatorFn=function r5(t){return Array.isArray(t)?Wg(t):t||null}(this._rawAsyncValidators)}}class Xg extends VM{constructor(n,e,i){super(Kg(e),Qg(i,e)),this.controls=n,this._initObservables(),this._setUpdateStrategy(e),this._setUpControls(),this.updateValueAndValidity({onlySelf:!0,emitEvent:!!this.asyncValidator})}registerControl(n,e){return this.controls[n]?this.controls[n]:(this.controls[n]=e,e.setParent(this),e._registerOnCollectionChange(this._onCollectionChange),e)}addControl(n,e,i={}){this.registerControl(n,e),this.updateValueAndValidity({emitEvent:i.emitEvent}),this._onCollectionChange()}removeControl(n,e={}){this.controls[n]&&this.controls[n]._registerOnCollectionChange(()=>{}),delete this.controls[n],this.updateValueAndValidity({emitEvent:e.emitEvent}),this._onCollectionChange()}setControl(n,e,i={}){this.controls[n]&&this.controls[n]._registerOnCollectionChange(()=>{}),delete this.controls[n],e&&this.registerControl(n,e),this.updateValueAndValidity({emitEvent:i.emitEvent}),this._onCollectionChange()}contains(n){return this.controls.hasOwnProperty(n)&&this.controls[n].enabled}setValue(n,e={}){(function BM(t,n,e){t._forEachChild((i,r)=>{if(void 0===e[r])throw new y(1002,"")})})(this,0,n),Object.keys(n).forEach(i=>{(function LM(t,n,e){const i=t.controls;if(!(n?Object.keys(i):i).length)throw new y(1e3,"");if(!i[e])throw new y(1001,"")})(this,!0,i),this.controls[i].setValue(n[i],{onlySelf:!0,emitEvent:e.emitEvent})}),this.updateValueAndValidity(e)}patchValue(n,e={}){null!=n&&(Object.keys(n).forEach(i=>{const r=this.controls[i];r&&r.patchValue(n[i],{onlySelf:!0,emitEvent:e.emitEvent})}),this.updateValueAndValidity(e))}reset(n={},e={}){this._forEachChild((i,r)=>{i.reset(n[r],{onlySelf:!0,emitEvent:e.emitEvent})}),this._updatePristine(e),this._updateTouched(e),this.updateValueAndValidity(e)}getRawValue(){return this._reduceChildren({},(n,e,i)=>(n[i]=e.getRawValue(),n))}_syncPendingControls(){let n=this._reduceChildren(!1,(e,i)=>!!i._syncPendingControls()||e);return n&&this.updateValueAndValidity({onlySelf:!0}),n}_forEachChild(n){Object.keys(this.controls).forEach(e=>{const i=this.controls[e];i&&n(i,e)})}_setUpControls(){this._forEachChild(n=>{n.setParent(this),n._registerOnCollectionChange(this._onCollectionChange)})}_updateValue(){this.value=this._reduceValue()}_anyControls(n){for(const[e,i]of Object.entries(this.controls))if(this.contains(e)&&n(i))return!0;return!1}_reduceValue(){return this._reduceChildren({},(e,i,r)=>((i.enabled||this.disabled)&&(e[r]=i.value),e))}_reduceChildren(n,e){let i=n;return this._forEachChild((r,o)=>{i=e(i,r,o)}),i}_allControlsDisabled(){for(const n of Object.keys(this.controls))if(this.controls[n].enabled)return!1;return Object.keys(this.controls).length>0||this.disabled}_find(n){return this.controls.hasOwnProperty(n)?this.controls[n]:null}}const Ur=new E("CallSetDisabledState",{providedIn:"root",factory:()=>Qa}),Qa="always";function Xa(t,n,e=Qa){Jg(t,n),n.valueAccessor.writeValue(t.value),(t.disabled||"always"===e)&&n.valueAccessor.setDisabledState?.(t.disabled),function a5(t,n){n.valueAccessor.registerOnChange(e=>{t._pendingValue=e,t._pendingChange=!0,t._pendingDirty=!0,"change"===t.updateOn&&jM(t,n)})}(t,n),function l5(t,n){const e=(i,r)=>{n.valueAccessor.writeValue(i),r&&n.viewToModelUpdate(i)};t.registerOnChange(e),n._registerOnDestroy(()=>{t._unregisterOnChange(e)})}(t,n),function c5(t,n){n.valueAccessor.registerOnTouched(()=>{t._pendingTouched=!0,"blur"===t.updateOn&&t._pendingChange&&jM(t,n),"submit"!==t.updateOn&&t.markAsTouched()})}(t,n),function s5(t,n){if(n.valueAccessor.setDisabledState){const e=i=>{n.valueAccessor.setDisabledState(i)};t.registerOnDisabledChange(e),n._registerOnDestroy(()=>{t._unregisterOnDisabledChange(e)})}}(t,n)}function uu(t,n,e=!0){const i=()=>{};n.valueAccessor&&(n.valueAccessor.registerOnChange(i),n.valueAccessor.registerOnTouched(i)),fu(t,n),t&&(n._invokeOnDestroyCallbacks(),t._registerOnCollectionChange(()=>{}))}function hu(t,n){t.forEach(e=>{e.registerOnValidatorChange&&e.registerOnValidatorChange(n)})}function Jg(t,n){const e=IM(t);null!==n.validator?t.setValidators(MM(e,n.validator)):"function"==typeof e&&t.setValidators([e]);const i=TM(t);null!==n.asyncValidator?t.setAsyncValidators(MM(i,n.asyncValidator)):"function"==typeof i&&t.setAsyncValidators([i]);const r=()=>t.updateValueAndValidity();hu(n._rawValidators,r),hu(n._rawAsyncValidators,r)}function fu(t,n){let e=!1;if(null!==t){if(null!==n.validator){const r=IM(t);if(Array.isArray(r)&&r.length>0){const o=r.filter(s=>s!==n.validator);o.length!==r.length&&(e=!0,t.setValidators(o))}}if(null!==n.asyncValidator){const r=TM(t);if(Array.isArray(r)&&r.length>0){const o=r.filter(s=>s!==n.asyncValidator);o.length!==r.length&&(e=!0,t.setAsyncValidators(o))}}}const i=()=>{};return hu(n._rawValidators,i),hu(n._rawAsyncValidators,i),e}function jM(t,n){t._pendingDirty&&t.markAsDirty(),t.setValue(t._pendingValue,{emitModelToViewChange:!1}),n.viewToModelUpdate(t._pendingValue),t._pendingChange=!1}function HM(t,n){Jg(t,n)}function zM(t,n){t._syncPendingControls(),n.forEach(e=>{const i=e.control;"submit"===i.updateOn&&i._pendingChange&&(e.viewToModelUpdate(i._pendingValue),i._pendingChange=!1)})}const p5={provide:zt,useExisting:Pe(()=>us)},Ja=(()=>Promise.resolve())();let us=(()=>{class t extends zt{constructor(e,i,r){super(),this.callSetDisabledState=r,this.submitted=!1,this._directives=new Set,this.ngSubmit=new q,this.form=new Xg({},Gg(e),Wg(i))}ngAfterViewInit(){this._setUpdateStrategy()}get formDirective(){return this}get control(){return this.form}get path(){return[]}get controls(){return this.form.controls}addControl(e){Ja.then(()=>{const i=this._findContainer(e.path);e.control=i.registerControl(e.name,e.control),Xa(e.control,e,this.callSetDisabledState),e.control.updateValueAndValidity({emitEvent:!1}),this._directives.add(e)})}getControl(e){return this.form.get(e.path)}removeControl(e){Ja.then(()=>{const i=this._findContainer(e.path);i&&i.removeControl(e.name),this._directives.delete(e)})}addFormGroup(e){Ja.then(()=>{const i=this._findContainer(e.path),r=new Xg({});HM(r,e),i.registerControl(e.name,r),r.updateValueAndValidity({emitEvent:!1})})}removeFormGroup(e){Ja.then(()=>{const i=this._findContainer(e.path);i&&i.removeControl(e.name)})}getFormGroup(e){return this.form.get(e.path)}updateModel(e,i){Ja.then(()=>{this.form.get(e.path).setValue(i)})}setValue(e){this.control.setValue(e)}onSubmit(e){return this.submitted=!0,zM(this.form,this._directives),this.ngSubmit.emit(e),"dialog"===e?.target?.method}onReset(){this.resetForm()}resetForm(e=void 0){this.form.reset(e),this.submitted=!1}_setUpdateStrategy(){this.options&&null!=this.options.updateOn&&(this.form._updateOn=this.options.updateOn)}_findContainer(e){return e.pop(),e.length?this.form.get(e):this.form}static#e=this.\u0275fac=function(i){return new(i||t)(f(kt,10),f(lr,10),f(Ur,8))};static#t=this.\u0275dir=x({type:t,selectors:[["form",3,"ngNoForm","",3,"formGroup",""],["ng-form"],["","ngForm",""]],hostBindings:function(i,r){1&i&&Y("submit",function(s){return r.onSubmit(s)})("reset",function(){return r.onReset()})},inputs:{options:["ngFormOptions","options"]},outputs:{ngSubmit:"ngSubmit"},exportAs:["ngForm"],features:[G([p5]),k]})}return t})();function $M(t,n){const e=t.indexOf(n);e>-1&&t.splice(e,1)}function UM(t){return"object"==typeof t&&null!==t&&2===Object.keys(t).length&&"value"in t&&"disabled"in t}const GM=class extends VM{constructor(n=null,e,i){super(Kg(e),Qg(i,e)),this.defaultValue=null,this._onChange=[],this._pendingChange=!1,this._applyFormState(n),this._setUpdateStrategy(e),this._initObservables(),this.updateValueAndValidity({onlySelf:!0,emitEvent:!!this.asyncValidator}),lu(e)&&(e.nonNullable||e.initialValueIsDefault)&&(this.defaultValue=UM(n)?n.value:n)}setValue(n,e={}){this.value=this._pendingValue=n,this._onChange.length&&!1!==e.emitModelToViewChange&&this._onChange.forEach(i=>i(this.value,!1!==e.emitViewToModelChange)),this.updateValueAndValidity(e)}patchValue(n,e={}){this.setValue(n,e)}reset(n=this.defaultValue,e={}){this._applyFormState(n),this.markAsPristine(e),this.markAsUntouched(e),this.setValue(this.value,e),this._pendingChange=!1}_updateValue(){}_anyControls(n){return!1}_allControlsDisabled(){return this.disabled}registerOnChange(n){this._onChange.push(n)}_unregisterOnChange(n){$M(this._onChange,n)}registerOnDisabledChange(n){this._onDisabledChange.push(n)}_unregisterOnDisabledChange(n){$M(this._onDisabledChange,n)}_forEachChild(n){}_syncPendingControls(){return!("submit"!==this.updateOn||(this._pendingDirty&&this.markAsDirty(),this._pendingTouched&&this.markAsTouched(),!this._pendingChange)||(this.setValue(this._pendingValue,{onlySelf:!0,emitModelToViewChange:!1}),0))}_applyFormState(n){UM(n)?(this.value=this._pendingValue=n.value,n.disabled?this.disable({onlySelf:!0,emitEvent:!1}):this.enable({onlySelf:!0,emitEvent:!1})):this.value=this._pendingValue=n}},_5={provide:ai,useExisting:Pe(()=>i_)},YM=(()=>Promise.resolve())();let i_=(()=>{class t extends ai{constructor(e,i,r,o,s,a){super(),this._changeDetectorRef=s,this.callSetDisabledState=a,this.control=new GM,this._registered=!1,this.name="",this.update=new q,this._parent=e,this._setValidators(i),this._setAsyncValidators(r),this.valueAccessor=function n_(t,n){if(!n)return null;let e,i,r;return Array.isArray(n),n.forEach(o=>{o.constructor===ru?e=o:function h5(t){return Object.getPrototypeOf(t.constructor)===$r}(o)?i=o:r=o}),r||i||e||null}(0,o)}ngOnChanges(e){if(this._checkForErrors(),!this._registered||"name"in e){if(this._registered&&(this._checkName(),this.formDirective)){const i=e.name.previousValue;this.formDirective.removeControl({name:i,path:this._getPath(i)})}this._setUpControl()}"isDisabled"in e&&this._updateDisabled(e),function t_(t,n){if(!t.hasOwnProperty("model"))return!1;const e=t.model;return!!e.isFirstChange()||!Object.is(n,e.currentValue)}(e,this.viewModel)&&(this._updateValue(this.model),this.viewModel=this.model)}ngOnDestroy(){this.formDirective&&this.formDirective.removeControl(this)}get path(){return this._getPath(this.name)}get formDirective(){return this._parent?this._parent.formDirective:null}viewToModelUpdate(e){this.viewModel=e,this.update.emit(e)}_setUpControl(){this._setUpdateStrategy(),this._isStandalone()?this._setUpStandalone():this.formDirective.addControl(this),this._registered=!0}_setUpdateStrategy(){this.options&&null!=this.options.updateOn&&(this.control._updateOn=this.options.updateOn)}_isStandalone(){return!this._parent||!(!this.options||!this.options.standalone)}_setUpStandalone(){Xa(this.control,this,this.callSetDisabledState),this.control.updateValueAndValidity({emitEvent:!1})}_checkForErrors(){this._isStandalone()||this._checkParentType(),this._checkName()}_checkParentType(){}_checkName(){this.options&&this.options.name&&(this.name=this.options.name),this._isStandalone()}_updateValue(e){YM.then(()=>{this.control.setValue(e,{emitViewToModelChange:!1}),this._changeDetectorRef?.markForCheck()})}_updateDisabled(e){const i=e.isDisabled.currentValue,r=0!==i&&function Zo(t){return"boolean"==typeof t?t:null!=t&&"false"!==t}(i);YM.then(()=>{r&&!this.control.disabled?this.control.disable():!r&&this.control.disabled&&this.control.enable(),this._changeDetectorRef?.markForCheck()})}_getPath(e){return this._parent?function du(t,n){return[...n.path,t]}(e,this._parent):[e]}static#e=this.\u0275fac=function(i){return new(i||t)(f(zt,9),f(kt,10),f(lr,10),f($n,10),f(ct,8),f(Ur,8))};static#t=this.\u0275dir=x({type:t,selectors:[["","ngModel","",3,"formControlName","",3,"formControl",""]],inputs:{name:"name",isDisabled:["disabled","isDisabled"],model:["ngModel","model"],options:["ngModelOptions","options"]},outputs:{update:"ngModelChange"},exportAs:["ngModel"],features:[G([_5]),k,ut]})}return t})(),ZM=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["form",3,"ngNoForm","",3,"ngNativeValidate",""]],hostAttrs:["novalidate",""]})}return t})(),QM=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({})}return t})();const r_=new E("NgModelWithFormControlWarning"),C5={provide:zt,useExisting:Pe(()=>ec)};let ec=(()=>{class t extends zt{constructor(e,i,r){super(),this.callSetDisabledState=r,this.submitted=!1,this._onCollectionChange=()=>this._updateDomValue(),this.directives=[],this.form=null,this.ngSubmit=new q,this._setValidators(e),this._setAsyncValidators(i)}ngOnChanges(e){this._checkFormPresent(),e.hasOwnProperty("form")&&(this._updateValidators(),this._updateDomValue(),this._updateRegistrations(),this._oldForm=this.form)}ngOnDestroy(){this.form&&(fu(this.form,this),this.form._onCollectionChange===this._onCollectionChange&&this.form._registerOnCollectionChange(()=>{}))}get formDirective(){return this}get control(){return this.form}get path(){return[]}addControl(e){const i=this.form.get(e.path);return Xa(i,e,this.callSetDisabledState),i.updateValueAndValidity({emitEvent:!1}),this.directives.push(e),i}getControl(e){return this.form.get(e.path)}removeControl(e){uu(e.control||null,e,!1),function f5(t,n){const e=t.indexOf(n);e>-1&&t.splice(e,1)}(this.directives,e)}addFormGroup(e){this._setUpFormContainer(e)}removeFormGroup(e){this._cleanUpFormContainer(e)}getFormGroup(e){return this.form.get(e.path)}addFormArray(e){this._setUpFormContainer(e)}removeFormArray(e){this._cleanUpFormContainer(e)}getFormArray(e){return this.form.get(e.path)}updateModel(e,i){this.form.get(e.path).setValue(i)}onSubmit(e){return this.submitted=!0,zM(this.form,this.directives),this.ngSubmit.emit(e),"dialog"===e?.target?.method}onReset(){this.resetForm()}resetForm(e=void 0){this.form.reset(e),this.submitted=!1}_updateDomValue(){this.directives.forEach(e=>{const i=e.control,r=this.form.get(e.path);i!==r&&(uu(i||null,e),(t=>t instanceof GM)(r)&&(Xa(r,e,this.callSetDisabledState),e.control=r))}),this.form._updateTreeValidity({emitEvent:!1})}_setUpFormContainer(e){const i=this.form.get(e.path);HM(i,e),i.updateValueAndValidity({emitEvent:!1})}_cleanUpFormContainer(e){if(this.form){const i=this.form.get(e.path);i&&function d5(t,n){return fu(t,n)}(i,e)&&i.updateValueAndValidity({emitEvent:!1})}}_updateRegistrations(){this.form._registerOnCollectionChange(this._onCollectionChange),this._oldForm&&this._oldForm._registerOnCollectionChange(()=>{})}_updateValidators(){Jg(this.form,this),this._oldForm&&fu(this._oldForm,this)}_checkFormPresent(){}static#e=this.\u0275fac=function(i){return new(i||t)(f(kt,10),f(lr,10),f(Ur,8))};static#t=this.\u0275dir=x({type:t,selectors:[["","formGroup",""]],hostBindings:function(i,r){1&i&&Y("submit",function(s){return r.onSubmit(s)})("reset",function(){return r.onReset()})},inputs:{form:["formGroup","form"]},outputs:{ngSubmit:"ngSubmit"},exportAs:["ngForm"],features:[G([C5]),k,ut]})}return t})(),pI=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[QM]})}return t})(),z5=(()=>{class t{static withConfig(e){return{ngModule:t,providers:[{provide:Ur,useValue:e.callSetDisabledState??Qa}]}}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[pI]})}return t})(),$5=(()=>{class t{static withConfig(e){return{ngModule:t,providers:[{provide:r_,useValue:e.warnOnNgModelWithFormControl??"always"},{provide:Ur,useValue:e.callSetDisabledState??Qa}]}}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[pI]})}return t})();class U5 extends U{constructor(n=1/0,e=1/0,i=Gm){super(),this._bufferSize=n,this._windowTime=e,this._timestampProvider=i,this._buffer=[],this._infiniteTimeWindow=!0,this._infiniteTimeWindow=e===1/0,this._bufferSize=Math.max(1,n),this._windowTime=Math.max(1,e)}next(n){const{isStopped:e,_buffer:i,_infiniteTimeWindow:r,_timestampProvider:o,_windowTime:s}=this;e||(i.push(n),!r&&i.push(o.now()+s)),this._trimBuffer(),super.next(n)}_subscribe(n){this._throwIfClosed(),this._trimBuffer();const e=this._innerSubscribe(n),{_infiniteTimeWindow:i,_buffer:r}=this,o=r.slice();for(let s=0;s<o.length&&!n.closed;s+=i?1:2)n.next(o[s]);return this._checkFinalizedStatuses(n),e}_trimBuffer(){const{_bufferSize:n,_timestampProvider:e,_buffer:i,_infiniteTimeWindow:r}=this,o=(r?1:2)*n;if(n<1/0&&o<i.length&&i.splice(0,i.length-o),!r){const s=e.now();let a=0;for(let c=1;c<i.length&&i[c]<=s;c+=2)a=c;a&&i.splice(0,a+1)}}}class W5{constructor(n){this._box=n,this._destroyed=new U,this._resizeSubject=new U,this._elementObservables=new Map,typeof ResizeObserver<"u"&&(this._resizeObserver=new ResizeObserver(e=>this._resizeSubject.next(e)))}observe(n){return this._elementObservables.has(n)||this._elementObservables.set(n,new Ne(e=>{const i=this._resizeSubject.subscribe(e);return this._resizeObserver?.observe(n,{box:this._box}),()=>{this._resizeObserver?.unobserve(n),i.unsubscribe(),this._elementObservables.delete(n)}}).pipe(Ze(e=>e.some(i=>i.target===n)),function G5(t,n,e){let i,r=!1;return t&&"object"==typeof t?({bufferSize:i=1/0,windowTime:n=1/0,refCount:r=!1,scheduler:e}=t):i=t??1/0,Sc({connector:()=>new U5(i,n,e),resetOnError:!0,resetOnComplete:!1,resetOnRefCountZero:r})}({bufferSize:1,refCount:!0}),Re(this._destroyed))),this._elementObservables.get(n)}destroy(){this._destroyed.next(),this._destroyed.complete(),this._resizeSubject.complete(),this._elementObservables.clear()}}let q5=(()=>{class t{constructor(){this._observers=new Map,this._ngZone=T(j)}ngOnDestroy(){for(const[,e]of this._observers)e.destroy();this._observers.clear()}observe(e,i){const r=i?.box||"content-box";return this._observers.has(r)||this._observers.set(r,new W5(r)),this._observers.get(r).observe(e)}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();const Y5=["notch"],Z5=["matFormFieldNotchedOutline",""],K5=["*"],Q5=["textField"],X5=["iconPrefixContainer"],J5=["textPrefixContainer"];function e8(t,n){1&t&&K(0,"span",19)}function t8(t,n){if(1&t&&(w(0,"label",17),Q(1,1),O(2,e8,1,0,"span",18),D()),2&t){const e=be(2);F("floating",e._shouldLabelFloat())("monitorResize",e._hasOutline())("id",e._labelId),ue("for",e._control.id),M(2),F("ngIf",!e.hideRequiredMarker&&e._control.required)}}function n8(t,n){1&t&&O(0,t8,3,5,"label",16),2&t&&F("ngIf",be()._hasFloatingLabel())}function r8(t,n){1&t&&K(0,"div",20)}function o8(t,n){}function s8(t,n){1&t&&O(0,o8,0,0,"ng-template",22),2&t&&(be(2),F("ngTemplateOutlet",Ci(1)))}function a8(t,n){if(1&t&&(w(0,"div",21),O(1,s8,1,1,"ng-template",9),D()),2&t){const e=be();F("matFormFieldNotchedOutlineOpen",e._shouldLabelFloat()),M(1),F("ngIf",!e._forceDisplayInfixLabel())}}function c8(t,n){1&t&&(w(0,"div",23,24),Q(2,2),D())}function l8(t,n){1&t&&(w(0,"div",25,26),Q(2,3),D())}function d8(t,n){}function u8(t,n){1&t&&O(0,d8,0,0,"ng-template",22),2&t&&(be(),F("ngTemplateOutlet",Ci(1)))}function h8(t,n){1&t&&(w(0,"div",27),Q(1,4),D())}function f8(t,n){1&t&&(w(0,"div",28),Q(1,5),D())}function p8(t,n){1&t&&K(0,"div",29)}function m8(t,n){1&t&&(w(0,"div",30),Q(1,6),D()),2&t&&F("@transitionMessages",be()._subscriptAnimationState)}function g8(t,n){if(1&t&&(w(0,"mat-hint",34),N(1),D()),2&t){const e=be(2);F("id",e._hintLabelId),M(1),Vn(e.hintLabel)}}function _8(t,n){if(1&t&&(w(0,"div",31),O(1,g8,2,2,"mat-hint",32),Q(2,7),K(3,"div",33),Q(4,8),D()),2&t){const e=be();F("@transitionMessages",e._subscriptAnimationState),M(1),F("ngIf",e.hintLabel)}}const b8=["*",[["mat-label"]],[["","matPrefix",""],["","matIconPrefix",""]],[["","matTextPrefix",""]],[["","matTextSuffix",""]],[["","matSuffix",""],["","matIconSuffix",""]],[["mat-error"],["","matError",""]],[["mat-hint",3,"align","end"]],[["mat-hint","align","end"]]],v8=["*","mat-label","[matPrefix], [matIconPrefix]","[matTextPrefix]","[matTextSuffix]","[matSuffix], [matIconSuffix]","mat-error, [matError]","mat-hint:not([align='end'])","mat-hint[align='end']"];let pu=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["mat-label"]]})}return t})();const y8=new E("MatError");let w8=0,mI=(()=>{class t{constructor(){this.align="start",this.id="mat-mdc-hint-"+w8++}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["mat-hint"]],hostAttrs:[1,"mat-mdc-form-field-hint","mat-mdc-form-field-bottom-align"],hostVars:4,hostBindings:function(i,r){2&i&&(wn("id",r.id),ue("align",null),he("mat-mdc-form-field-hint-end","end"===r.align))},inputs:{align:"align",id:"id"}})}return t})();const x8=new E("MatPrefix"),C8=new E("MatSuffix"),gI=new E("FloatingLabelParent");let _I=(()=>{class t{get floating(){return this._floating}set floating(e){this._floating=e,this.monitorResize&&this._handleResize()}get monitorResize(){return this._monitorResize}set monitorResize(e){this._monitorResize=e,this._monitorResize?this._subscribeToResize():this._resizeSubscription.unsubscribe()}constructor(e){this._elementRef=e,this._floating=!1,this._monitorResize=!1,this._resizeObserver=T(q5),this._ngZone=T(j),this._parent=T(gI),this._resizeSubscription=new Le}ngOnDestroy(){this._resizeSubscription.unsubscribe()}getWidth(){return function D8(t){if(null!==t.offsetParent)return t.scrollWidth;const e=t.cloneNode(!0);e.style.setProperty("position","absolute"),e.style.setProperty("transform","translate(-9999px, -9999px)"),document.documentElement.appendChild(e);const i=e.scrollWidth;return e.remove(),i}(this._elementRef.nativeElement)}get element(){return this._elementRef.nativeElement}_handleResize(){setTimeout(()=>this._parent._handleLabelResized())}_subscribeToResize(){this._resizeSubscription.unsubscribe(),this._ngZone.runOutsideAngular(()=>{this._resizeSubscription=this._resizeObserver.observe(this._elementRef.nativeElement,{box:"border-box"}).subscribe(()=>this._handleResize())})}static#e=this.\u0275fac=function(i){return new(i||t)(f($))};static#t=this.\u0275dir=x({type:t,selectors:[["label","matFormFieldFloatingLabel",""]],hostAttrs:[1,"mdc-floating-label","mat-mdc-floating-label"],hostVars:2,hostBindings:function(i,r){2&i&&he("mdc-floating-label--float-above",r.floating)},inputs:{floating:"floating",monitorResize:"monitorResize"}})}return t})();const bI="mdc-line-ripple--active",mu="mdc-line-ripple--deactivating";let vI=(()=>{class t{constructor(e,i){this._elementRef=e,this._handleTransitionEnd=r=>{const o=this._elementRef.nativeElement.classList,s=o.contains(mu);"opacity"===r.propertyName&&s&&o.remove(bI,mu)},i.runOutsideAngular(()=>{e.nativeElement.addEventListener("transitionend",this._handleTransitionEnd)})}activate(){const e=this._elementRef.nativeElement.classList;e.remove(mu),e.add(bI)}deactivate(){this._elementRef.nativeElement.classList.add(mu)}ngOnDestroy(){this._elementRef.nativeElement.removeEventListener("transitionend",this._handleTransitionEnd)}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(j))};static#t=this.\u0275dir=x({type:t,selectors:[["div","matFormFieldLineRipple",""]],hostAttrs:[1,"mdc-line-ripple"]})}return t})(),yI=(()=>{class t{constructor(e,i){this._elementRef=e,this._ngZone=i,this.open=!1}ngAfterViewInit(){const e=this._elementRef.nativeElement.querySelector(".mdc-floating-label");e?(this._elementRef.nativeElement.classList.add("mdc-notched-outline--upgraded"),"function"==typeof requestAnimationFrame&&(e.style.transitionDuration="0s",this._ngZone.runOutsideAngular(()=>{requestAnimationFrame(()=>e.style.transitionDuration="")}))):this._elementRef.nativeElement.classList.add("mdc-notched-outline--no-label")}_setNotchWidth(e){this._notch.nativeElement.style.width=this.open&&e?`calc(${e}px * var(--mat-mdc-form-field-floating-label-scale, 0.75) + 9px)`:""}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(j))};static#t=this.\u0275cmp=oe({type:t,selectors:[["div","matFormFieldNotchedOutline",""]],viewQuery:function(i,r){if(1&i&&Se(Y5,5),2&i){let o;B(o=V())&&(r._notch=o.first)}},hostAttrs:[1,"mdc-notched-outline"],hostVars:2,hostBindings:function(i,r){2&i&&he("mdc-notched-outline--notched",r.open)},inputs:{open:["matFormFieldNotchedOutlineOpen","open"]},attrs:Z5,ngContentSelectors:K5,decls:5,vars:0,consts:[[1,"mdc-notched-outline__leading"],[1,"mdc-notched-outline__notch"],["notch",""],[1,"mdc-notched-outline__trailing"]],template:function(i,r){1&i&&(at(),K(0,"div",0),w(1,"div",1,2),Q(3),D(),K(4,"div",3))},encapsulation:2,changeDetection:0})}return t})();const E8={transitionMessages:ir("transitionMessages",[ln("enter",Ke({opacity:1,transform:"translateY(0%)"})),dn("void => enter",[Ke({opacity:0,transform:"translateY(-5px)"}),Sn("300ms cubic-bezier(0.55, 0, 0.55, 0.2)")])])};let d_=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t})}return t})();const u_=new E("MatFormField"),S8=new E("MAT_FORM_FIELD_DEFAULT_OPTIONS");let wI=0,T8=(()=>{class t{get hideRequiredMarker(){return this._hideRequiredMarker}set hideRequiredMarker(e){this._hideRequiredMarker=me(e)}get floatLabel(){return this._floatLabel||this._defaults?.floatLabel||"auto"}set floatLabel(e){e!==this._floatLabel&&(this._floatLabel=e,this._changeDetectorRef.markForCheck())}get appearance(){return this._appearance}set appearance(e){const i=this._appearance;this._appearance=e||this._defaults?.appearance||"fill","outline"===this._appearance&&this._appearance!==i&&(this._needsOutlineLabelOffsetUpdateOnStable=!0)}get subscriptSizing(){return this._subscriptSizing||this._defaults?.subscriptSizing||"fixed"}set subscriptSizing(e){this._subscriptSizing=e||this._defaults?.subscriptSizing||"fixed"}get hintLabel(){return this._hintLabel}set hintLabel(e){this._hintLabel=e,this._processHints()}get _control(){return this._explicitFormFieldControl||this._formFieldControl}set _control(e){this._explicitFormFieldControl=e}constructor(e,i,r,o,s,a,c,l){this._elementRef=e,this._changeDetectorRef=i,this._ngZone=r,this._dir=o,this._platform=s,this._defaults=a,this._animationMode=c,this._hideRequiredMarker=!1,this.color="primary",this._appearance="fill",this._subscriptSizing=null,this._hintLabel="",this._hasIconPrefix=!1,this._hasTextPrefix=!1,this._hasIconSuffix=!1,this._hasTextSuffix=!1,this._labelId="mat-mdc-form-field-label-"+wI++,this._hintLabelId="mat-mdc-hint-"+wI++,this._subscriptAnimationState="",this._destroyed=new U,this._isFocused=null,this._needsOutlineLabelOffsetUpdateOnStable=!1,a&&(a.appearance&&(this.appearance=a.appearance),this._hideRequiredMarker=!!a?.hideRequiredMarker,a.color&&(this.color=a.color))}ngAfterViewInit(){this._updateFocusState(),this._subscriptAnimationState="enter",this._changeDetectorRef.detectChanges()}ngAfterContentInit(){this._assertFormFieldControl(),this._initializeControl(),this._initializeSubscript(),this._initializePrefixAndSuffix(),this._initializeOutlineLabelOffsetSubscriptions()}ngAfterContentChecked(){this._assertFormFieldControl()}ngOnDestroy(){this._destroyed.next(),this._destroyed.complete()}getLabelId(){return this._hasFloatingLabel()?this._labelId:null}getConnectedOverlayOrigin(){return this._textField||this._elementRef}_animateAndLockLabel(){this._hasFloatingLabel()&&(this.floatLabel="always")}_initializeControl(){const e=this._control;e.controlType&&this._elementRef.nativeElement.classList.add(`mat-mdc-form-field-type-${e.controlType}`),e.stateChanges.subscribe(()=>{this._updateFocusState(),this._syncDescribedByIds(),this._changeDetectorRef.markForCheck()}),e.ngControl&&e.ngControl.valueChanges&&e.ngControl.valueChanges.pipe(Re(this._destroyed)).subscribe(()=>this._changeDetectorRef.markForCheck())}_checkPrefixAndSuffixTypes(){this._hasIconPrefix=!!this._prefixChildren.find(e=>!e._isText),this._hasTextPrefix=!!this._prefixChildren.find(e=>e._isText),this._hasIconSuffix=!!this._suffixChildren.find(e=>!e._isText),this._hasTextSuffix=!!this._suffixChildren.find(e=>e._isText)}_initializePrefixAndSuffix(){this._checkPrefixAndSuffixTypes(),An(this._prefixChildren.changes,this._suffixChildren.changes).subscribe(()=>{this._checkPrefixAndSuffixTypes(),this._changeDetectorRef.markForCheck()})}_initializeSubscript(){this._hintChildren.changes.subscribe(()=>{this._processHints(),this._changeDetectorRef.markForCheck()}),this._errorChildren.changes.subscribe(()=>{this._syncDescribedByIds(),this._changeDetectorRef.markForCheck()}),this._validateHints(),this._syncDescribedByIds()}_assertFormFieldControl(){}_updateFocusState(){this._control.focused&&!this._isFocused?(this._isFocused=!0,this._lineRipple?.activate()):!this._control.focused&&(this._isFocused||null===this._isFocused)&&(this._isFocused=!1,this._lineRipple?.deactivate()),this._textField?.nativeElement.classList.toggle("mdc-text-field--focused",this._control.focused)}_initializeOutlineLabelOffsetSubscriptions(){this._prefixChildren.changes.subscribe(()=>this._needsOutlineLabelOffsetUpdateOnStable=!0),this._ngZone.runOutsideAngular(()=>{this._ngZone.onStable.pipe(Re(this._destroyed)).subscribe(()=>{this._needsOutlineLabelOffsetUpdateOnStable&&(this._needsOutlineLabelOffsetUpdateOnStable=!1,this._updateOutlineLabelOffset())})}),this._dir.change.pipe(Re(this._destroyed)).subscribe(()=>this._needsOutlineLabelOffsetUpdateOnStable=!0)}_shouldAlwaysFloat(){return"always"===this.floatLabel}_hasOutline(){return"outline"===this.appearance}_forceDisplayInfixLabel(){return!this._platform.isBrowser&&this._prefixChildren.length&&!this._shouldLabelFloat()}_hasFloatingLabel(){return!!this._labelChildNonStatic||!!this._labelChildStatic}_shouldLabelFloat(){return this._control.shouldLabelFloat||this._shouldAlwaysFloat()}_shouldForward(e){const i=this._control?this._control.ngControl:null;return i&&i[e]}_getDisplayedMessages(){return this._errorChildren&&this._errorChildren.length>0&&this._control.errorState?"error":"hint"}_handleLabelResized(){this._refreshOutlineNotchWidth()}_refreshOutlineNotchWidth(){this._hasOutline()&&this._floatingLabel&&this._shouldLabelFloat()?this._notchedOutline?._setNotchWidth(this._floatingLabel.getWidth()):this._notchedOutline?._setNotchWidth(0)}_processHints(){this._validateHints(),this._syncDescribedByIds()}_validateHints(){}_syncDescribedByIds(){if(this._control){let e=[];if(this._control.userAriaDescribedBy&&"string"==typeof this._control.userAriaDescribedBy&&e.push(...this._control.userAriaDescribedBy.split(" ")),"hint"===this._getDisplayedMessages()){const i=this._hintChildren?this._hintChildren.find(o=>"start"===o.align):null,r=this._hintChildren?this._hintChildren.find(o=>"end"===o.align):null;i?e.push(i.id):this._hintLabel&&e.push(this._hintLabelId),r&&e.push(r.id)}else this._errorChildren&&e.push(...this._errorChildren.map(i=>i.id));this._control.setDescribedByIds(e)}}_updateOutlineLabelOffset(){if(!this._platform.isBrowser||!this._hasOutline()||!this._floatingLabel)return;const e=this._floatingLabel.element;if(!this._iconPrefixContainer&&!this._textPrefixContainer)return void(e.style.transform="");if(!this._isAttachedToDom())return void(this._needsOutlineLabelOffsetUpdateOnStable=!0);const i=this._iconPrefixContainer?.nativeElement,r=this._textPrefixContainer?.nativeElement,o=i?.getBoundingClientRect().width??0,s=r?.getBoundingClientRect().width??0;e.style.transform=`var(\n        --mat-mdc-form-field-label-transform,\n        translateY(-50%) translateX(calc(${"rtl"===this._dir.value?"-1":"1"} * (${o+s}px + var(--mat-mdc-form-field-label-offset-x, 0px))))\n    )`}_isAttachedToDom(){const e=this._elementRef.nativeElement;if(e.getRootNode){const i=e.getRootNode();return i&&i!==e}return document.documentElement.contains(e)}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(ct),f(j),f(En),f(tt),f(S8,8),f(gt,8),f(Z))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-form-field"]],contentQueries:function(i,r,o){if(1&i&&(xe(o,pu,5),xe(o,pu,7),xe(o,d_,5),xe(o,x8,5),xe(o,C8,5),xe(o,y8,5),xe(o,mI,5)),2&i){let s;B(s=V())&&(r._labelChildNonStatic=s.first),B(s=V())&&(r._labelChildStatic=s.first),B(s=V())&&(r._formFieldControl=s.first),B(s=V())&&(r._prefixChildren=s),B(s=V())&&(r._suffixChildren=s),B(s=V())&&(r._errorChildren=s),B(s=V())&&(r._hintChildren=s)}},viewQuery:function(i,r){if(1&i&&(Se(Q5,5),Se(X5,5),Se(J5,5),Se(_I,5),Se(yI,5),Se(vI,5)),2&i){let o;B(o=V())&&(r._textField=o.first),B(o=V())&&(r._iconPrefixContainer=o.first),B(o=V())&&(r._textPrefixContainer=o.first),B(o=V())&&(r._floatingLabel=o.first),B(o=V())&&(r._notchedOutline=o.first),B(o=V())&&(r._lineRipple=o.first)}},hostAttrs:[1,"mat-mdc-form-field"],hostVars:42,hostBindings:function(i,r){2&i&&he("mat-mdc-form-field-label-always-float",r._shouldAlwaysFloat())("mat-mdc-form-field-has-icon-prefix",r._hasIconPrefix)("mat-mdc-form-field-has-icon-suffix",r._hasIconSuffix)("mat-form-field-invalid",r._control.errorState)("mat-form-field-disabled",r._control.disabled)("mat-form-field-autofilled",r._control.autofilled)("mat-form-field-no-animations","NoopAnimations"===r._animationMode)("mat-form-field-appearance-fill","fill"==r.appearance)("mat-form-field-appearance-outline","outline"==r.appearance)("mat-form-field-hide-placeholder",r._hasFloatingLabel()&&!r._shouldLabelFloat())("mat-focused",r._control.focused)("mat-primary","accent"!==r.color&&"warn"!==r.color)("mat-accent","accent"===r.color)("mat-warn","warn"===r.color)("ng-untouched",r._shouldForward("untouched"))("ng-touched",r._shouldForward("touched"))("ng-pristine",r._shouldForward("pristine"))("ng-dirty",r._shouldForward("dirty"))("ng-valid",r._shouldForward("valid"))("ng-invalid",r._shouldForward("invalid"))("ng-pending",r._shouldForward("pending"))},inputs:{hideRequiredMarker:"hideRequiredMarker",color:"color",floatLabel:"floatLabel",appearance:"appearance",subscriptSizing:"subscriptSizing",hintLabel:"hintLabel"},exportAs:["matFormField"],features:[G([{provide:u_,useExisting:t},{provide:gI,useExisting:t}])],ngContentSelectors:v8,decls:18,vars:23,consts:[["labelTemplate",""],[1,"mat-mdc-text-field-wrapper","mdc-text-field",3,"click"],["textField",""],["class","mat-mdc-form-field-focus-overlay",4,"ngIf"],[1,"mat-mdc-form-field-flex"],["matFormFieldNotchedOutline","",3,"matFormFieldNotchedOutlineOpen",4,"ngIf"],["class","mat-mdc-form-field-icon-prefix",4,"ngIf"],["class","mat-mdc-form-field-text-prefix",4,"ngIf"],[1,"mat-mdc-form-field-infix"],[3,"ngIf"],["class","mat-mdc-form-field-text-suffix",4,"ngIf"],["class","mat-mdc-form-field-icon-suffix",4,"ngIf"],["matFormFieldLineRipple","",4,"ngIf"],[1,"mat-mdc-form-field-subscript-wrapper","mat-mdc-form-field-bottom-align",3,"ngSwitch"],["class","mat-mdc-form-field-error-wrapper",4,"ngSwitchCase"],["class","mat-mdc-form-field-hint-wrapper",4,"ngSwitchCase"],["matFormFieldFloatingLabel","",3,"floating","monitorResize","id",4,"ngIf"],["matFormFieldFloatingLabel","",3,"floating","monitorResize","id"],["aria-hidden","true","class","mat-mdc-form-field-required-marker mdc-floating-label--required",4,"ngIf"],["aria-hidden","true",1,"mat-mdc-form-field-required-marker","mdc-floating-label--required"],[1,"mat-mdc-form-field-focus-overlay"],["matFormFieldNotchedOutline","",3,"matFormFieldNotchedOutlineOpen"],[3,"ngTemplateOutlet"],[1,"mat-mdc-form-field-icon-prefix"],["iconPrefixContainer",""],[1,"mat-mdc-form-field-text-prefix"],["textPrefixContainer",""],[1,"mat-mdc-form-field-text-suffix"],[1,"mat-mdc-form-field-icon-suffix"],["matFormFieldLineRipple",""],[1,"mat-mdc-form-field-error-wrapper"],[1,"mat-mdc-form-field-hint-wrapper"],[3,"id",4,"ngIf"],[1,"mat-mdc-form-field-hint-spacer"],[3,"id"]],template:function(i,r){1&i&&(at(b8),O(0,n8,1,1,"ng-template",null,0,Ca),w(2,"div",1,2),Y("click",function(s){return r._control.onContainerClick(s)}),O(4,r8,1,0,"div",3),w(5,"div",4),O(6,a8,2,2,"div",5),O(7,c8,3,0,"div",6),O(8,l8,3,0,"div",7),w(9,"div",8),O(10,u8,1,1,"ng-template",9),Q(11),D(),O(12,h8,2,0,"div",10),O(13,f8,2,0,"div",11),D(),O(14,p8,1,0,"div",12),D(),w(15,"div",13),O(16,m8,2,1,"div",14),O(17,_8,5,2,"div",15),D()),2&i&&(M(2),he("mdc-text-field--filled",!r._hasOutline())("mdc-text-field--outlined",r._hasOutline())("mdc-text-field--no-label",!r._hasFloatingLabel())("mdc-text-field--disabled",r._control.disabled)("mdc-text-field--invalid",r._control.errorState),M(2),F("ngIf",!r._hasOutline()&&!r._control.disabled),M(2),F("ngIf",r._hasOutline()),M(1),F("ngIf",r._hasIconPrefix),M(1),F("ngIf",r._hasTextPrefix),M(2),F("ngIf",!r._hasOutline()||r._forceDisplayInfixLabel()),M(2),F("ngIf",r._hasTextSuffix),M(1),F("ngIf",r._hasIconSuffix),M(1),F("ngIf",!r._hasOutline()),M(1),he("mat-mdc-form-field-subscript-dynamic-size","dynamic"===r.subscriptSizing),F("ngSwitch",r._getDisplayedMessages()),M(1),F("ngSwitchCase","error"),M(1),F("ngSwitchCase","hint"))},dependencies:[Ii,Cm,Aa,xm,mI,_I,yI,vI],styles:['.mdc-text-field{border-top-left-radius:4px;border-top-left-radius:var(--mdc-shape-small, 4px);border-top-right-radius:4px;border-top-right-radius:var(--mdc-shape-small, 4px);border-bottom-right-radius:0;border-bottom-left-radius:0;display:inline-flex;align-items:baseline;padding:0 16px;position:relative;box-sizing:border-box;overflow:hidden;will-change:opacity,transform,color}.mdc-text-field .mdc-floating-label{top:50%;transform:translateY(-50%);pointer-events:none}.mdc-text-field__input{height:28px;width:100%;min-width:0;border:none;border-radius:0;background:none;appearance:none;padding:0}.mdc-text-field__input::-ms-clear{display:none}.mdc-text-field__input::-webkit-calendar-picker-indicator{display:none}.mdc-text-field__input:focus{outline:none}.mdc-text-field__input:invalid{box-shadow:none}@media all{.mdc-text-field__input::placeholder{opacity:0}}@media all{.mdc-text-field__input:-ms-input-placeholder{opacity:0}}@media all{.mdc-text-field--no-label .mdc-text-field__input::placeholder,.mdc-text-field--focused .mdc-text-field__input::placeholder{opacity:1}}@media all{.mdc-text-field--no-label .mdc-text-field__input:-ms-input-placeholder,.mdc-text-field--focused .mdc-text-field__input:-ms-input-placeholder{opacity:1}}.mdc-text-field__affix{height:28px;opacity:0;white-space:nowrap}.mdc-text-field--label-floating .mdc-text-field__affix,.mdc-text-field--no-label .mdc-text-field__affix{opacity:1}@supports(-webkit-hyphens: none){.mdc-text-field--outlined .mdc-text-field__affix{align-items:center;align-self:center;display:inline-flex;height:100%}}.mdc-text-field__affix--prefix{padding-left:0;padding-right:2px}[dir=rtl] .mdc-text-field__affix--prefix,.mdc-text-field__affix--prefix[dir=rtl]{padding-left:2px;padding-right:0}.mdc-text-field--end-aligned .mdc-text-field__affix--prefix{padding-left:0;padding-right:12px}[dir=rtl] .mdc-text-field--end-aligned .mdc-text-field__affix--prefix,.mdc-text-field--end-aligned .mdc-text-field__affix--prefix[dir=rtl]{padding-left:12px;padding-right:0}.mdc-text-field__affix--suffix{padding-left:12px;padding-right:0}[dir=rtl] .mdc-text-field__affix--suffix,.mdc-text-field__affix--suffix[dir=rtl]{padding-left:0;padding-right:12px}.mdc-text-field--end-aligned .mdc-text-field__affix--suffix{padding-left:2px;padding-right:0}[dir=rtl] .mdc-text-field--end-aligned .mdc-text-field__affix--suffix,.mdc-text-field--end-aligned .mdc-text-field__affix--suffix[dir=rtl]{padding-left:0;padding-right:2px}.mdc-text-field--filled{height:56px}.mdc-text-field--filled::before{display:inline-block;width:0;height:40px;content:"";vertical-align:0}.mdc-text-field--filled .mdc-floating-label{left:16px;right:initial}[dir=rtl] .mdc-text-field--filled .mdc-floating-label,.mdc-text-field--filled .mdc-floating-label[dir=rtl]{left:initial;right:16px}.mdc-text-field--filled .mdc-floating-label--float-above{transform:translateY(-106%) scale(0.75)}.mdc-text-field--filled.mdc-text-field--no-label .mdc-text-field__input{height:100%}.mdc-text-field--filled.mdc-text-field--no-label .mdc-floating-label{display:none}.mdc-text-field--filled.mdc-text-field--no-label::before{display:none}@supports(-webkit-hyphens: none){.mdc-text-field--filled.mdc-text-field--no-label .mdc-text-field__affix{align-items:center;align-self:center;display:inline-flex;height:100%}}.mdc-text-field--outlined{height:56px;overflow:visible}.mdc-text-field--outlined .mdc-floating-label--float-above{transform:translateY(-37.25px) scale(1)}.mdc-text-field--outlined .mdc-floating-label--float-above{font-size:.75rem}.mdc-text-field--outlined.mdc-notched-outline--upgraded .mdc-floating-label--float-above,.mdc-text-field--outlined .mdc-notched-outline--upgraded .mdc-floating-label--float-above{transform:translateY(-34.75px) scale(0.75)}.mdc-text-field--outlined.mdc-notched-outline--upgraded .mdc-floating-label--float-above,.mdc-text-field--outlined .mdc-notched-outline--upgraded .mdc-floating-label--float-above{font-size:1rem}.mdc-text-field--outlined .mdc-text-field__input{height:100%}.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__leading{border-top-left-radius:4px;border-top-left-radius:var(--mdc-shape-small, 4px);border-top-right-radius:0;border-bottom-right-radius:0;border-bottom-left-radius:4px;border-bottom-left-radius:var(--mdc-shape-small, 4px)}[dir=rtl] .mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__leading,.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__leading[dir=rtl]{border-top-left-radius:0;border-top-right-radius:4px;border-top-right-radius:var(--mdc-shape-small, 4px);border-bottom-right-radius:4px;border-bottom-right-radius:var(--mdc-shape-small, 4px);border-bottom-left-radius:0}@supports(top: max(0%)){.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__leading{width:max(12px, var(--mdc-shape-small, 4px))}}@supports(top: max(0%)){.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__notch{max-width:calc(100% - max(12px, var(--mdc-shape-small, 4px))*2)}}.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__trailing{border-top-left-radius:0;border-top-right-radius:4px;border-top-right-radius:var(--mdc-shape-small, 4px);border-bottom-right-radius:4px;border-bottom-right-radius:var(--mdc-shape-small, 4px);border-bottom-left-radius:0}[dir=rtl] .mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__trailing,.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__trailing[dir=rtl]{border-top-left-radius:4px;border-top-left-radius:var(--mdc-shape-small, 4px);border-top-right-radius:0;border-bottom-right-radius:0;border-bottom-left-radius:4px;border-bottom-left-radius:var(--mdc-shape-small, 4px)}@supports(top: max(0%)){.mdc-text-field--outlined{padding-left:max(16px, calc(var(--mdc-shape-small, 4px) + 4px))}}@supports(top: max(0%)){.mdc-text-field--outlined{padding-right:max(16px, var(--mdc-shape-small, 4px))}}@supports(top: max(0%)){.mdc-text-field--outlined+.mdc-text-field-helper-line{padding-left:max(16px, calc(var(--mdc-shape-small, 4px) + 4px))}}@supports(top: max(0%)){.mdc-text-field--outlined+.mdc-text-field-helper-line{padding-right:max(16px, var(--mdc-shape-small, 4px))}}.mdc-text-field--outlined.mdc-text-field--with-leading-icon{padding-left:0}@supports(top: max(0%)){.mdc-text-field--outlined.mdc-text-field--with-leading-icon{padding-right:max(16px, var(--mdc-shape-small, 4px))}}[dir=rtl] .mdc-text-field--outlined.mdc-text-field--with-leading-icon,.mdc-text-field--outlined.mdc-text-field--with-leading-icon[dir=rtl]{padding-right:0}@supports(top: max(0%)){[dir=rtl] .mdc-text-field--outlined.mdc-text-field--with-leading-icon,.mdc-text-field--outlined.mdc-text-field--with-leading-icon[dir=rtl]{padding-left:max(16px, var(--mdc-shape-small, 4px))}}.mdc-text-field--outlined.mdc-text-field--with-trailing-icon{padding-right:0}@supports(top: max(0%)){.mdc-text-field--outlined.mdc-text-field--with-trailing-icon{padding-left:max(16px, calc(var(--mdc-shape-small, 4px) + 4px))}}[dir=rtl] .mdc-text-field--outlined.mdc-text-field--with-trailing-icon,.mdc-text-field--outlined.mdc-text-field--with-trailing-icon[dir=rtl]{padding-left:0}@supports(top: max(0%)){[dir=rtl] .mdc-text-field--outlined.mdc-text-field--with-trailing-icon,.mdc-text-field--outlined.mdc-text-field--with-trailing-icon[dir=rtl]{padding-right:max(16px, calc(var(--mdc-shape-small, 4px) + 4px))}}.mdc-text-field--outlined.mdc-text-field--with-leading-icon.mdc-text-field--with-trailing-icon{padding-left:0;padding-right:0}.mdc-text-field--outlined .mdc-notched-outline--notched .mdc-notched-outline__notch{padding-top:1px}.mdc-text-field--outlined .mdc-floating-label{left:4px;right:initial}[dir=rtl] .mdc-text-field--outlined .mdc-floating-label,.mdc-text-field--outlined .mdc-floating-label[dir=rtl]{left:initial;right:4px}.mdc-text-field--outlined .mdc-text-field__input{display:flex;border:none !important;background-color:rgba(0,0,0,0)}.mdc-text-field--outlined .mdc-notched-outline{z-index:1}.mdc-text-field--textarea{flex-direction:column;align-items:center;width:auto;height:auto;padding:0}.mdc-text-field--textarea .mdc-floating-label{top:19px}.mdc-text-field--textarea .mdc-floating-label:not(.mdc-floating-label--float-above){transform:none}.mdc-text-field--textarea .mdc-text-field__input{flex-grow:1;height:auto;min-height:1.5rem;overflow-x:hidden;overflow-y:auto;box-sizing:border-box;resize:none;padding:0 16px}.mdc-text-field--textarea.mdc-text-field--filled::before{display:none}.mdc-text-field--textarea.mdc-text-field--filled .mdc-floating-label--float-above{transform:translateY(-10.25px) scale(0.75)}.mdc-text-field--textarea.mdc-text-field--filled .mdc-text-field__input{margin-top:23px;margin-bottom:9px}.mdc-text-field--textarea.mdc-text-field--filled.mdc-text-field--no-label .mdc-text-field__input{margin-top:16px;margin-bottom:16px}.mdc-text-field--textarea.mdc-text-field--outlined .mdc-notched-outline--notched .mdc-notched-outline__notch{padding-top:0}.mdc-text-field--textarea.mdc-text-field--outlined .mdc-floating-label--float-above{transform:translateY(-27.25px) scale(1)}.mdc-text-field--textarea.mdc-text-field--outlined .mdc-floating-label--float-above{font-size:.75rem}.mdc-text-field--textarea.mdc-text-field--outlined.mdc-notched-outline--upgraded .mdc-floating-label--float-above,.mdc-text-field--textarea.mdc-text-field--outlined .mdc-notched-outline--upgraded .mdc-floating-label--float-above{transform:translateY(-24.75px) scale(0.75)}.mdc-text-field--textarea.mdc-text-field--outlined.mdc-notched-outline--upgraded .mdc-floating-label--float-above,.mdc-text-field--textarea.mdc-text-field--outlined .mdc-notched-outline--upgraded .mdc-floating-label--float-above{font-size:1rem}.mdc-text-field--textarea.mdc-text-field--outlined .mdc-text-field__input{margin-top:16px;margin-bottom:16px}.mdc-text-field--textarea.mdc-text-field--outlined .mdc-floating-label{top:18px}.mdc-text-field--textarea.mdc-text-field--with-internal-counter .mdc-text-field__input{margin-bottom:2px}.mdc-text-field--textarea.mdc-text-field--with-internal-counter .mdc-text-field-character-counter{align-self:flex-end;padding:0 16px}.mdc-text-field--textarea.mdc-text-field--with-internal-counter .mdc-text-field-character-counter::after{display:inline-block;width:0;height:16px;content:"";vertical-align:-16px}.mdc-text-field--textarea.mdc-text-field--with-internal-counter .mdc-text-field-character-counter::before{display:none}.mdc-text-field__resizer{align-self:stretch;display:inline-flex;flex-direction:column;flex-grow:1;max-height:100%;max-width:100%;min-height:56px;min-width:fit-content;min-width:-moz-available;min-width:-webkit-fill-available;overflow:hidden;resize:both}.mdc-text-field--filled .mdc-text-field__resizer{transform:translateY(-1px)}.mdc-text-field--filled .mdc-text-field__resizer .mdc-text-field__input,.mdc-text-field--filled .mdc-text-field__resizer .mdc-text-field-character-counter{transform:translateY(1px)}.mdc-text-field--outlined .mdc-text-field__resizer{transform:translateX(-1px) translateY(-1px)}[dir=rtl] .mdc-text-field--outlined .mdc-text-field__resizer,.mdc-text-field--outlined .mdc-text-field__resizer[dir=rtl]{transform:translateX(1px) translateY(-1px)}.mdc-text-field--outlined .mdc-text-field__resizer .mdc-text-field__input,.mdc-text-field--outlined .mdc-text-field__resizer .mdc-text-field-character-counter{transform:translateX(1px) translateY(1px)}[dir=rtl] .mdc-text-field--outlined .mdc-text-field__resizer .mdc-text-field__input,[dir=rtl] .mdc-text-field--outlined .mdc-text-field__resizer .mdc-text-field-character-counter,.mdc-text-field--outlined .mdc-text-field__resizer .mdc-text-field__input[dir=rtl],.mdc-text-field--outlined .mdc-text-field__resizer .mdc-text-field-character-counter[dir=rtl]{transform:translateX(-1px) translateY(1px)}.mdc-text-field--with-leading-icon{padding-left:0;padding-right:16px}[dir=rtl] .mdc-text-field--with-leading-icon,.mdc-text-field--with-leading-icon[dir=rtl]{padding-left:16px;padding-right:0}.mdc-text-field--with-leading-icon.mdc-text-field--filled .mdc-floating-label{max-width:calc(100% - 48px);left:48px;right:initial}[dir=rtl] .mdc-text-field--with-leading-icon.mdc-text-field--filled .mdc-floating-label,.mdc-text-field--with-leading-icon.mdc-text-field--filled .mdc-floating-label[dir=rtl]{left:initial;right:48px}.mdc-text-field--with-leading-icon.mdc-text-field--filled .mdc-floating-label--float-above{max-width:calc(100% / 0.75 - 64px / 0.75)}.mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-floating-label{left:36px;right:initial}[dir=rtl] .mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-floating-label,.mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-floating-label[dir=rtl]{left:initial;right:36px}.mdc-text-field--with-leading-icon.mdc-text-field--outlined :not(.mdc-notched-outline--notched) .mdc-notched-outline__notch{max-width:calc(100% - 60px)}.mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-floating-label--float-above{transform:translateY(-37.25px) translateX(-32px) scale(1)}[dir=rtl] .mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-floating-label--float-above,.mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-floating-label--float-above[dir=rtl]{transform:translateY(-37.25px) translateX(32px) scale(1)}.mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-floating-label--float-above{font-size:.75rem}.mdc-text-field--with-leading-icon.mdc-text-field--outlined.mdc-notched-outline--upgraded .mdc-floating-label--float-above,.mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-notched-outline--upgraded .mdc-floating-label--float-above{transform:translateY(-34.75px) translateX(-32px) scale(0.75)}[dir=rtl] .mdc-text-field--with-leading-icon.mdc-text-field--outlined.mdc-notched-outline--upgraded .mdc-floating-label--float-above,[dir=rtl] .mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-notched-outline--upgraded .mdc-floating-label--float-above,.mdc-text-field--with-leading-icon.mdc-text-field--outlined.mdc-notched-outline--upgraded .mdc-floating-label--float-above[dir=rtl],.mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-notched-outline--upgraded .mdc-floating-label--float-above[dir=rtl]{transform:translateY(-34.75px) translateX(32px) scale(0.75)}.mdc-text-field--with-leading-icon.mdc-text-field--outlined.mdc-notched-outline--upgraded .mdc-floating-label--float-above,.mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-notched-outline--upgraded .mdc-floating-label--float-above{font-size:1rem}.mdc-text-field--with-trailing-icon{padding-left:16px;padding-right:0}[dir=rtl] .mdc-text-field--with-trailing-icon,.mdc-text-field--with-trailing-icon[dir=rtl]{padding-left:0;padding-right:16px}.mdc-text-field--with-trailing-icon.mdc-text-field--filled .mdc-floating-label{max-width:calc(100% - 64px)}.mdc-text-field--with-trailing-icon.mdc-text-field--filled .mdc-floating-label--float-above{max-width:calc(100% / 0.75 - 64px / 0.75)}.mdc-text-field--with-trailing-icon.mdc-text-field--outlined :not(.mdc-notched-outline--notched) .mdc-notched-outline__notch{max-width:calc(100% - 60px)}.mdc-text-field--with-leading-icon.mdc-text-field--with-trailing-icon{padding-left:0;padding-right:0}.mdc-text-field--with-leading-icon.mdc-text-field--with-trailing-icon.mdc-text-field--filled .mdc-floating-label{max-width:calc(100% - 96px)}.mdc-text-field--with-leading-icon.mdc-text-field--with-trailing-icon.mdc-text-field--filled .mdc-floating-label--float-above{max-width:calc(100% / 0.75 - 96px / 0.75)}.mdc-text-field-helper-line{display:flex;justify-content:space-between;box-sizing:border-box}.mdc-text-field+.mdc-text-field-helper-line{padding-right:16px;padding-left:16px}.mdc-form-field>.mdc-text-field+label{align-self:flex-start}.mdc-text-field--focused .mdc-notched-outline__leading,.mdc-text-field--focused .mdc-notched-outline__notch,.mdc-text-field--focused .mdc-notched-outline__trailing{border-width:2px}.mdc-text-field--focused+.mdc-text-field-helper-line .mdc-text-field-helper-text:not(.mdc-text-field-helper-text--validation-msg){opacity:1}.mdc-text-field--focused.mdc-text-field--outlined .mdc-notched-outline--notched .mdc-notched-outline__notch{padding-top:2px}.mdc-text-field--focused.mdc-text-field--outlined.mdc-text-field--textarea .mdc-notched-outline--notched .mdc-notched-outline__notch{padding-top:0}.mdc-text-field--invalid+.mdc-text-field-helper-line .mdc-text-field-helper-text--validation-msg{opacity:1}.mdc-text-field--disabled{pointer-events:none}@media screen and (forced-colors: active){.mdc-text-field--disabled .mdc-text-field__input{background-color:Window}.mdc-text-field--disabled .mdc-floating-label{z-index:1}}.mdc-text-field--disabled .mdc-floating-label{cursor:default}.mdc-text-field--disabled.mdc-text-field--filled .mdc-text-field__ripple{display:none}.mdc-text-field--disabled .mdc-text-field__input{pointer-events:auto}.mdc-text-field--end-aligned .mdc-text-field__input{text-align:right}[dir=rtl] .mdc-text-field--end-aligned .mdc-text-field__input,.mdc-text-field--end-aligned .mdc-text-field__input[dir=rtl]{text-align:left}[dir=rtl] .mdc-text-field--ltr-text .mdc-text-field__input,[dir=rtl] .mdc-text-field--ltr-text .mdc-text-field__affix,.mdc-text-field--ltr-text[dir=rtl] .mdc-text-field__input,.mdc-text-field--ltr-text[dir=rtl] .mdc-text-field__affix{direction:ltr}[dir=rtl] .mdc-text-field--ltr-text .mdc-text-field__affix--prefix,.mdc-text-field--ltr-text[dir=rtl] .mdc-text-field__affix--prefix{padding-left:0;padding-right:2px}[dir=rtl] .mdc-text-field--ltr-text .mdc-text-field__affix--suffix,.mdc-text-field--ltr-text[dir=rtl] .mdc-text-field__affix--suffix{padding-left:12px;padding-right:0}[dir=rtl] .mdc-text-field--ltr-text .mdc-text-field__icon--leading,.mdc-text-field--ltr-text[dir=rtl] .mdc-text-field__icon--leading{order:1}[dir=rtl] .mdc-text-field--ltr-text .mdc-text-field__affix--suffix,.mdc-text-field--ltr-text[dir=rtl] .mdc-text-field__affix--suffix{order:2}[dir=rtl] .mdc-text-field--ltr-text .mdc-text-field__input,.mdc-text-field--ltr-text[dir=rtl] .mdc-text-field__input{order:3}[dir=rtl] .mdc-text-field--ltr-text .mdc-text-field__affix--prefix,.mdc-text-field--ltr-text[dir=rtl] .mdc-text-field__affix--prefix{order:4}[dir=rtl] .mdc-text-field--ltr-text .mdc-text-field__icon--trailing,.mdc-text-field--ltr-text[dir=rtl] .mdc-text-field__icon--trailing{order:5}[dir=rtl] .mdc-text-field--ltr-text.mdc-text-field--end-aligned .mdc-text-field__input,.mdc-text-field--ltr-text.mdc-text-field--end-aligned[dir=rtl] .mdc-text-field__input{text-align:right}[dir=rtl] .mdc-text-field--ltr-text.mdc-text-field--end-aligned .mdc-text-field__affix--prefix,.mdc-text-field--ltr-text.mdc-text-field--end-aligned[dir=rtl] .mdc-text-field__affix--prefix{padding-right:12px}[dir=rtl] .mdc-text-field--ltr-text.mdc-text-field--end-aligned .mdc-text-field__affix--suffix,.mdc-text-field--ltr-text.mdc-text-field--end-aligned[dir=rtl] .mdc-text-field__affix--suffix{padding-left:2px}.mdc-floating-label{position:absolute;left:0;-webkit-transform-origin:left top;transform-origin:left top;line-height:1.15rem;text-align:left;text-overflow:ellipsis;white-space:nowrap;cursor:text;overflow:hidden;will-change:transform}[dir=rtl] .mdc-floating-label,.mdc-floating-label[dir=rtl]{right:0;left:auto;-webkit-transform-origin:right top;transform-origin:right top;text-align:right}.mdc-floating-label--float-above{cursor:auto}.mdc-floating-label--required:not(.mdc-floating-label--hide-required-marker)::after{margin-left:1px;margin-right:0px;content:"*"}[dir=rtl] .mdc-floating-label--required:not(.mdc-floating-label--hide-required-marker)::after,.mdc-floating-label--required:not(.mdc-floating-label--hide-required-marker)[dir=rtl]::after{margin-left:0;margin-right:1px}.mdc-notched-outline{display:flex;position:absolute;top:0;right:0;left:0;box-sizing:border-box;width:100%;max-width:100%;height:100%;text-align:left;pointer-events:none}[dir=rtl] .mdc-notched-outline,.mdc-notched-outline[dir=rtl]{text-align:right}.mdc-notched-outline__leading,.mdc-notched-outline__notch,.mdc-notched-outline__trailing{box-sizing:border-box;height:100%;pointer-events:none}.mdc-notched-outline__trailing{flex-grow:1}.mdc-notched-outline__notch{flex:0 0 auto;width:auto}.mdc-notched-outline .mdc-floating-label{display:inline-block;position:relative;max-width:100%}.mdc-notched-outline .mdc-floating-label--float-above{text-overflow:clip}.mdc-notched-outline--upgraded .mdc-floating-label--float-above{max-width:133.3333333333%}.mdc-notched-outline--notched .mdc-notched-outline__notch{padding-left:0;padding-right:8px;border-top:none}[dir=rtl] .mdc-notched-outline--notched .mdc-notched-outline__notch,.mdc-notched-outline--notched .mdc-notched-outline__notch[dir=rtl]{padding-left:8px;padding-right:0}.mdc-notched-outline--no-label .mdc-notched-outline__notch{display:none}.mdc-line-ripple::before,.mdc-line-ripple::after{position:absolute;bottom:0;left:0;width:100%;border-bottom-style:solid;content:""}.mdc-line-ripple::before{z-index:1}.mdc-line-ripple::after{transform:scaleX(0);opacity:0;z-index:2}.mdc-line-ripple--active::after{transform:scaleX(1);opacity:1}.mdc-line-ripple--deactivating::after{opacity:0}.mdc-floating-label--float-above{transform:translateY(-106%) scale(0.75)}.mdc-notched-outline__leading,.mdc-notched-outline__notch,.mdc-notched-outline__trailing{border-top:1px solid;border-bottom:1px solid}.mdc-notched-outline__leading{border-left:1px solid;border-right:none;width:12px}[dir=rtl] .mdc-notched-outline__leading,.mdc-notched-outline__leading[dir=rtl]{border-left:none;border-right:1px solid}.mdc-notched-outline__trailing{border-left:none;border-right:1px solid}[dir=rtl] .mdc-notched-outline__trailing,.mdc-notched-outline__trailing[dir=rtl]{border-left:1px solid;border-right:none}.mdc-notched-outline__notch{max-width:calc(100% - 12px * 2)}.mdc-line-ripple::before{border-bottom-width:1px}.mdc-line-ripple::after{border-bottom-width:2px}.mdc-text-field--filled{--mdc-filled-text-field-active-indicator-height:1px;--mdc-filled-text-field-focus-active-indicator-height:2px;--mdc-filled-text-field-container-shape:4px;border-top-left-radius:var(--mdc-filled-text-field-container-shape);border-top-right-radius:var(--mdc-filled-text-field-container-shape);border-bottom-right-radius:0;border-bottom-left-radius:0}.mdc-text-field--filled:not(.mdc-text-field--disabled) .mdc-text-field__input{caret-color:var(--mdc-filled-text-field-caret-color)}.mdc-text-field--filled.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-text-field__input{caret-color:var(--mdc-filled-text-field-error-caret-color)}.mdc-text-field--filled:not(.mdc-text-field--disabled) .mdc-text-field__input{color:var(--mdc-filled-text-field-input-text-color)}.mdc-text-field--filled.mdc-text-field--disabled .mdc-text-field__input{color:var(--mdc-filled-text-field-disabled-input-text-color)}.mdc-text-field--filled:not(.mdc-text-field--disabled) .mdc-floating-label,.mdc-text-field--filled:not(.mdc-text-field--disabled) .mdc-floating-label--float-above{color:var(--mdc-filled-text-field-label-text-color)}.mdc-text-field--filled:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-floating-label,.mdc-text-field--filled:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-floating-label--float-above{color:var(--mdc-filled-text-field-focus-label-text-color)}.mdc-text-field--filled.mdc-text-field--disabled .mdc-floating-label,.mdc-text-field--filled.mdc-text-field--disabled .mdc-floating-label--float-above{color:var(--mdc-filled-text-field-disabled-label-text-color)}.mdc-text-field--filled.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-floating-label,.mdc-text-field--filled.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-floating-label--float-above{color:var(--mdc-filled-text-field-error-label-text-color)}.mdc-text-field--filled.mdc-text-field--invalid:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-floating-label,.mdc-text-field--filled.mdc-text-field--invalid:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-floating-label--float-above{color:var(--mdc-filled-text-field-error-focus-label-text-color)}.mdc-text-field--filled .mdc-floating-label{font-family:var(--mdc-filled-text-field-label-text-font);font-size:var(--mdc-filled-text-field-label-text-size);font-weight:var(--mdc-filled-text-field-label-text-weight);letter-spacing:var(--mdc-filled-text-field-label-text-tracking)}@media all{.mdc-text-field--filled:not(.mdc-text-field--disabled) .mdc-text-field__input::placeholder{color:var(--mdc-filled-text-field-input-text-placeholder-color)}}@media all{.mdc-text-field--filled:not(.mdc-text-field--disabled) .mdc-text-field__input:-ms-input-placeholder{color:var(--mdc-filled-text-field-input-text-placeholder-color)}}.mdc-text-field--filled:not(.mdc-text-field--disabled){background-color:var(--mdc-filled-text-field-container-color)}.mdc-text-field--filled.mdc-text-field--disabled{background-color:var(--mdc-filled-text-field-disabled-container-color)}.mdc-text-field--filled:not(.mdc-text-field--disabled) .mdc-line-ripple::before{border-bottom-color:var(--mdc-filled-text-field-active-indicator-color)}.mdc-text-field--filled:not(.mdc-text-field--disabled):not(.mdc-text-field--focused):hover .mdc-line-ripple::before{border-bottom-color:var(--mdc-filled-text-field-hover-active-indicator-color)}.mdc-text-field--filled:not(.mdc-text-field--disabled) .mdc-line-ripple::after{border-bottom-color:var(--mdc-filled-text-field-focus-active-indicator-color)}.mdc-text-field--filled.mdc-text-field--disabled .mdc-line-ripple::before{border-bottom-color:var(--mdc-filled-text-field-disabled-active-indicator-color)}.mdc-text-field--filled.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-line-ripple::before{border-bottom-color:var(--mdc-filled-text-field-error-active-indicator-color)}.mdc-text-field--filled.mdc-text-field--invalid:not(.mdc-text-field--disabled):not(.mdc-text-field--focused):hover .mdc-line-ripple::before{border-bottom-color:var(--mdc-filled-text-field-error-hover-active-indicator-color)}.mdc-text-field--filled.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-line-ripple::after{border-bottom-color:var(--mdc-filled-text-field-error-focus-active-indicator-color)}.mdc-text-field--filled .mdc-line-ripple::before{border-bottom-width:var(--mdc-filled-text-field-active-indicator-height)}.mdc-text-field--filled .mdc-line-ripple::after{border-bottom-width:var(--mdc-filled-text-field-focus-active-indicator-height)}.mdc-text-field--outlined{--mdc-outlined-text-field-outline-width:1px;--mdc-outlined-text-field-focus-outline-width:2px;--mdc-outlined-text-field-container-shape:4px}.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-text-field__input{caret-color:var(--mdc-outlined-text-field-caret-color)}.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-text-field__input{caret-color:var(--mdc-outlined-text-field-error-caret-color)}.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-text-field__input{color:var(--mdc-outlined-text-field-input-text-color)}.mdc-text-field--outlined.mdc-text-field--disabled .mdc-text-field__input{color:var(--mdc-outlined-text-field-disabled-input-text-color)}.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-floating-label,.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-floating-label--float-above{color:var(--mdc-outlined-text-field-label-text-color)}.mdc-text-field--outlined:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-floating-label,.mdc-text-field--outlined:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-floating-label--float-above{color:var(--mdc-outlined-text-field-focus-label-text-color)}.mdc-text-field--outlined.mdc-text-field--disabled .mdc-floating-label,.mdc-text-field--outlined.mdc-text-field--disabled .mdc-floating-label--float-above{color:var(--mdc-outlined-text-field-disabled-label-text-color)}.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-floating-label,.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-floating-label--float-above{color:var(--mdc-outlined-text-field-error-label-text-color)}.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-floating-label,.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-floating-label--float-above{color:var(--mdc-outlined-text-field-error-focus-label-text-color)}.mdc-text-field--outlined .mdc-floating-label{font-family:var(--mdc-outlined-text-field-label-text-font);font-size:var(--mdc-outlined-text-field-label-text-size);font-weight:var(--mdc-outlined-text-field-label-text-weight);letter-spacing:var(--mdc-outlined-text-field-label-text-tracking)}@media all{.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-text-field__input::placeholder{color:var(--mdc-outlined-text-field-input-text-placeholder-color)}}@media all{.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-text-field__input:-ms-input-placeholder{color:var(--mdc-outlined-text-field-input-text-placeholder-color)}}.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__leading{border-top-left-radius:var(--mdc-outlined-text-field-container-shape);border-top-right-radius:0;border-bottom-right-radius:0;border-bottom-left-radius:var(--mdc-outlined-text-field-container-shape)}[dir=rtl] .mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__leading,.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__leading[dir=rtl]{border-top-left-radius:0;border-top-right-radius:var(--mdc-outlined-text-field-container-shape);border-bottom-right-radius:var(--mdc-outlined-text-field-container-shape);border-bottom-left-radius:0}@supports(top: max(0%)){.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__leading{width:max(12px, var(--mdc-outlined-text-field-container-shape))}}@supports(top: max(0%)){.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__notch{max-width:calc(100% - max(12px, var(--mdc-outlined-text-field-container-shape))*2)}}.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__trailing{border-top-left-radius:0;border-top-right-radius:var(--mdc-outlined-text-field-container-shape);border-bottom-right-radius:var(--mdc-outlined-text-field-container-shape);border-bottom-left-radius:0}[dir=rtl] .mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__trailing,.mdc-text-field--outlined .mdc-notched-outline .mdc-notched-outline__trailing[dir=rtl]{border-top-left-radius:var(--mdc-outlined-text-field-container-shape);border-top-right-radius:0;border-bottom-right-radius:0;border-bottom-left-radius:var(--mdc-outlined-text-field-container-shape)}@supports(top: max(0%)){.mdc-text-field--outlined{padding-left:max(16px, calc(var(--mdc-outlined-text-field-container-shape) + 4px))}}@supports(top: max(0%)){.mdc-text-field--outlined{padding-right:max(16px, var(--mdc-outlined-text-field-container-shape))}}@supports(top: max(0%)){.mdc-text-field--outlined+.mdc-text-field-helper-line{padding-left:max(16px, calc(var(--mdc-outlined-text-field-container-shape) + 4px))}}@supports(top: max(0%)){.mdc-text-field--outlined+.mdc-text-field-helper-line{padding-right:max(16px, var(--mdc-outlined-text-field-container-shape))}}.mdc-text-field--outlined.mdc-text-field--with-leading-icon{padding-left:0}@supports(top: max(0%)){.mdc-text-field--outlined.mdc-text-field--with-leading-icon{padding-right:max(16px, var(--mdc-outlined-text-field-container-shape))}}[dir=rtl] .mdc-text-field--outlined.mdc-text-field--with-leading-icon,.mdc-text-field--outlined.mdc-text-field--with-leading-icon[dir=rtl]{padding-right:0}@supports(top: max(0%)){[dir=rtl] .mdc-text-field--outlined.mdc-text-field--with-leading-icon,.mdc-text-field--outlined.mdc-text-field--with-leading-icon[dir=rtl]{padding-left:max(16px, var(--mdc-outlined-text-field-container-shape))}}.mdc-text-field--outlined.mdc-text-field--with-trailing-icon{padding-right:0}@supports(top: max(0%)){.mdc-text-field--outlined.mdc-text-field--with-trailing-icon{padding-left:max(16px, calc(var(--mdc-outlined-text-field-container-shape) + 4px))}}[dir=rtl] .mdc-text-field--outlined.mdc-text-field--with-trailing-icon,.mdc-text-field--outlined.mdc-text-field--with-trailing-icon[dir=rtl]{padding-left:0}@supports(top: max(0%)){[dir=rtl] .mdc-text-field--outlined.mdc-text-field--with-trailing-icon,.mdc-text-field--outlined.mdc-text-field--with-trailing-icon[dir=rtl]{padding-right:max(16px, calc(var(--mdc-outlined-text-field-container-shape) + 4px))}}.mdc-text-field--outlined.mdc-text-field--with-leading-icon.mdc-text-field--with-trailing-icon{padding-left:0;padding-right:0}.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-notched-outline__leading,.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-notched-outline__notch,.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-notched-outline__trailing{border-color:var(--mdc-outlined-text-field-outline-color)}.mdc-text-field--outlined:not(.mdc-text-field--disabled):not(.mdc-text-field--focused):hover .mdc-notched-outline .mdc-notched-outline__leading,.mdc-text-field--outlined:not(.mdc-text-field--disabled):not(.mdc-text-field--focused):hover .mdc-notched-outline .mdc-notched-outline__notch,.mdc-text-field--outlined:not(.mdc-text-field--disabled):not(.mdc-text-field--focused):hover .mdc-notched-outline .mdc-notched-outline__trailing{border-color:var(--mdc-outlined-text-field-hover-outline-color)}.mdc-text-field--outlined:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-notched-outline__leading,.mdc-text-field--outlined:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-notched-outline__notch,.mdc-text-field--outlined:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-notched-outline__trailing{border-color:var(--mdc-outlined-text-field-focus-outline-color)}.mdc-text-field--outlined.mdc-text-field--disabled .mdc-notched-outline__leading,.mdc-text-field--outlined.mdc-text-field--disabled .mdc-notched-outline__notch,.mdc-text-field--outlined.mdc-text-field--disabled .mdc-notched-outline__trailing{border-color:var(--mdc-outlined-text-field-disabled-outline-color)}.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-notched-outline__leading,.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-notched-outline__notch,.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled) .mdc-notched-outline__trailing{border-color:var(--mdc-outlined-text-field-error-outline-color)}.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled):not(.mdc-text-field--focused):hover .mdc-notched-outline .mdc-notched-outline__leading,.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled):not(.mdc-text-field--focused):hover .mdc-notched-outline .mdc-notched-outline__notch,.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled):not(.mdc-text-field--focused):hover .mdc-notched-outline .mdc-notched-outline__trailing{border-color:var(--mdc-outlined-text-field-error-hover-outline-color)}.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-notched-outline__leading,.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-notched-outline__notch,.mdc-text-field--outlined.mdc-text-field--invalid:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-notched-outline__trailing{border-color:var(--mdc-outlined-text-field-error-focus-outline-color)}.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-notched-outline .mdc-notched-outline__leading,.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-notched-outline .mdc-notched-outline__notch,.mdc-text-field--outlined:not(.mdc-text-field--disabled) .mdc-notched-outline .mdc-notched-outline__trailing{border-width:var(--mdc-outlined-text-field-outline-width)}.mdc-text-field--outlined:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-notched-outline .mdc-notched-outline__leading,.mdc-text-field--outlined:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-notched-outline .mdc-notched-outline__notch,.mdc-text-field--outlined:not(.mdc-text-field--disabled).mdc-text-field--focused .mdc-notched-outline .mdc-notched-outline__trailing{border-width:var(--mdc-outlined-text-field-focus-outline-width)}.mat-mdc-form-field-textarea-control{vertical-align:middle;resize:vertical;box-sizing:border-box;height:auto;margin:0;padding:0;border:none;overflow:auto}.mat-mdc-form-field-input-control.mat-mdc-form-field-input-control{-moz-osx-font-smoothing:grayscale;-webkit-font-smoothing:antialiased;font:inherit;letter-spacing:inherit;text-decoration:inherit;text-transform:inherit;border:none}.mat-mdc-form-field .mat-mdc-floating-label.mdc-floating-label{-moz-osx-font-smoothing:grayscale;-webkit-font-smoothing:antialiased;line-height:normal;pointer-events:all}.mat-mdc-form-field:not(.mat-form-field-disabled) .mat-mdc-floating-label.mdc-floating-label{cursor:inherit}.mdc-text-field--no-label:not(.mdc-text-field--textarea) .mat-mdc-form-field-input-control.mdc-text-field__input,.mat-mdc-text-field-wrapper .mat-mdc-form-field-input-control{height:auto}.mat-mdc-text-field-wrapper .mat-mdc-form-field-input-control.mdc-text-field__input[type=color]{height:23px}.mat-mdc-text-field-wrapper{height:auto;flex:auto}.mat-mdc-form-field-has-icon-prefix .mat-mdc-text-field-wrapper{padding-left:0;--mat-mdc-form-field-label-offset-x: -16px}.mat-mdc-form-field-has-icon-suffix .mat-mdc-text-field-wrapper{padding-right:0}[dir=rtl] .mat-mdc-text-field-wrapper{padding-left:16px;padding-right:16px}[dir=rtl] .mat-mdc-form-field-has-icon-suffix .mat-mdc-text-field-wrapper{padding-left:0}[dir=rtl] .mat-mdc-form-field-has-icon-prefix .mat-mdc-text-field-wrapper{padding-right:0}.mat-form-field-disabled .mdc-text-field__input::placeholder{color:var(--mat-form-field-disabled-input-text-placeholder-color)}.mat-form-field-disabled .mdc-text-field__input::-moz-placeholder{color:var(--mat-form-field-disabled-input-text-placeholder-color)}.mat-form-field-disabled .mdc-text-field__input::-webkit-input-placeholder{color:var(--mat-form-field-disabled-input-text-placeholder-color)}.mat-form-field-disabled .mdc-text-field__input:-ms-input-placeholder{color:var(--mat-form-field-disabled-input-text-placeholder-color)}.mat-mdc-form-field-label-always-float .mdc-text-field__input::placeholder{transition-delay:40ms;transition-duration:110ms;opacity:1}.mat-mdc-text-field-wrapper .mat-mdc-form-field-infix .mat-mdc-floating-label{left:auto;right:auto}.mat-mdc-text-field-wrapper.mdc-text-field--outlined .mdc-text-field__input{display:inline-block}.mat-mdc-form-field .mat-mdc-text-field-wrapper.mdc-text-field .mdc-notched-outline__notch{padding-top:0}.mat-mdc-text-field-wrapper::before{content:none}.mat-mdc-form-field-subscript-wrapper{box-sizing:border-box;width:100%;position:relative}.mat-mdc-form-field-hint-wrapper,.mat-mdc-form-field-error-wrapper{position:absolute;top:0;left:0;right:0;padding:0 16px}.mat-mdc-form-field-subscript-dynamic-size .mat-mdc-form-field-hint-wrapper,.mat-mdc-form-field-subscript-dynamic-size .mat-mdc-form-field-error-wrapper{position:static}.mat-mdc-form-field-bottom-align::before{content:"";display:inline-block;height:16px}.mat-mdc-form-field-bottom-align.mat-mdc-form-field-subscript-dynamic-size::before{content:unset}.mat-mdc-form-field-hint-end{order:1}.mat-mdc-form-field-hint-wrapper{display:flex}.mat-mdc-form-field-hint-spacer{flex:1 0 1em}.mat-mdc-form-field-error{display:block}.mat-mdc-form-field-focus-overlay{top:0;left:0;right:0;bottom:0;position:absolute;opacity:0;pointer-events:none}select.mat-mdc-form-field-input-control{-moz-appearance:none;-webkit-appearance:none;background-color:rgba(0,0,0,0);display:inline-flex;box-sizing:border-box}select.mat-mdc-form-field-input-control:not(:disabled){cursor:pointer}.mat-mdc-form-field-type-mat-native-select .mat-mdc-form-field-infix::after{content:"";width:0;height:0;border-left:5px solid rgba(0,0,0,0);border-right:5px solid rgba(0,0,0,0);border-top:5px solid;position:absolute;right:0;top:50%;margin-top:-2.5px;pointer-events:none}[dir=rtl] .mat-mdc-form-field-type-mat-native-select .mat-mdc-form-field-infix::after{right:auto;left:0}.mat-mdc-form-field-type-mat-native-select .mat-mdc-form-field-input-control{padding-right:15px}[dir=rtl] .mat-mdc-form-field-type-mat-native-select .mat-mdc-form-field-input-control{padding-right:0;padding-left:15px}.cdk-high-contrast-active .mat-form-field-appearance-fill .mat-mdc-text-field-wrapper{outline:solid 1px}.cdk-high-contrast-active .mat-form-field-appearance-fill.mat-form-field-disabled .mat-mdc-text-field-wrapper{outline-color:GrayText}.cdk-high-contrast-active .mat-form-field-appearance-fill.mat-focused .mat-mdc-text-field-wrapper{outline:dashed 3px}.cdk-high-contrast-active .mat-mdc-form-field.mat-focused .mdc-notched-outline{border:dashed 3px}.mat-mdc-form-field-input-control[type=date],.mat-mdc-form-field-input-control[type=datetime],.mat-mdc-form-field-input-control[type=datetime-local],.mat-mdc-form-field-input-control[type=month],.mat-mdc-form-field-input-control[type=week],.mat-mdc-form-field-input-control[type=time]{line-height:1}.mat-mdc-form-field-input-control::-webkit-datetime-edit{line-height:1;padding:0;margin-bottom:-2px}.mat-mdc-form-field{--mat-mdc-form-field-floating-label-scale: 0.75;display:inline-flex;flex-direction:column;min-width:0;text-align:left;-moz-osx-font-smoothing:grayscale;-webkit-font-smoothing:antialiased;font-family:var(--mat-form-field-container-text-font);line-height:var(--mat-form-field-container-text-line-height);font-size:var(--mat-form-field-container-text-size);letter-spacing:var(--mat-form-field-container-text-tracking);font-weight:var(--mat-form-field-container-text-weight)}[dir=rtl] .mat-mdc-form-field{text-align:right}.mat-mdc-form-field .mdc-text-field--outlined .mdc-floating-label--float-above{font-size:calc(var(--mat-form-field-outlined-label-text-populated-size) * var(--mat-mdc-form-field-floating-label-scale))}.mat-mdc-form-field .mdc-text-field--outlined .mdc-notched-outline--upgraded .mdc-floating-label--float-above{font-size:var(--mat-form-field-outlined-label-text-populated-size)}.mat-mdc-form-field-flex{display:inline-flex;align-items:baseline;box-sizing:border-box;width:100%}.mat-mdc-text-field-wrapper{width:100%}.mat-mdc-form-field-icon-prefix,.mat-mdc-form-field-icon-suffix{align-self:center;line-height:0;pointer-events:auto;position:relative;z-index:1}.mat-mdc-form-field-icon-prefix,[dir=rtl] .mat-mdc-form-field-icon-suffix{padding:0 4px 0 0}.mat-mdc-form-field-icon-suffix,[dir=rtl] .mat-mdc-form-field-icon-prefix{padding:0 0 0 4px}.mat-mdc-form-field-icon-prefix>.mat-icon,.mat-mdc-form-field-icon-suffix>.mat-icon{padding:12px;box-sizing:content-box}.mat-mdc-form-field-subscript-wrapper .mat-icon,.mat-mdc-form-field label .mat-icon{width:1em;height:1em;font-size:inherit}.mat-mdc-form-field-infix{flex:auto;min-width:0;width:180px;position:relative;box-sizing:border-box}.mat-mdc-form-field .mdc-notched-outline__notch{margin-left:-1px;-webkit-clip-path:inset(-9em -999em -9em 1px);clip-path:inset(-9em -999em -9em 1px)}[dir=rtl] .mat-mdc-form-field .mdc-notched-outline__notch{margin-left:0;margin-right:-1px;-webkit-clip-path:inset(-9em 1px -9em -999em);clip-path:inset(-9em 1px -9em -999em)}.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field__input{transition:opacity 150ms 0ms cubic-bezier(0.4, 0, 0.2, 1)}@media all{.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field__input::placeholder{transition:opacity 67ms 0ms cubic-bezier(0.4, 0, 0.2, 1)}}@media all{.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field__input:-ms-input-placeholder{transition:opacity 67ms 0ms cubic-bezier(0.4, 0, 0.2, 1)}}@media all{.mdc-text-field--no-label .mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field__input::placeholder,.mdc-text-field--focused .mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field__input::placeholder{transition-delay:40ms;transition-duration:110ms}}@media all{.mdc-text-field--no-label .mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field__input:-ms-input-placeholder,.mdc-text-field--focused .mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field__input:-ms-input-placeholder{transition-delay:40ms;transition-duration:110ms}}.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field__affix{transition:opacity 150ms 0ms cubic-bezier(0.4, 0, 0.2, 1)}.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field--filled.mdc-ripple-upgraded--background-focused .mdc-text-field__ripple::before,.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field--filled:not(.mdc-ripple-upgraded):focus .mdc-text-field__ripple::before{transition-duration:75ms}.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field--outlined .mdc-floating-label--shake{animation:mdc-floating-label-shake-float-above-text-field-outlined 250ms 1}@keyframes mdc-floating-label-shake-float-above-text-field-outlined{0%{transform:translateX(calc(0% - 0%)) translateY(calc(0% - 34.75px)) scale(0.75)}33%{animation-timing-function:cubic-bezier(0.5, 0, 0.701732, 0.495819);transform:translateX(calc(4% - 0%)) translateY(calc(0% - 34.75px)) scale(0.75)}66%{animation-timing-function:cubic-bezier(0.302435, 0.381352, 0.55, 0.956352);transform:translateX(calc(-4% - 0%)) translateY(calc(0% - 34.75px)) scale(0.75)}100%{transform:translateX(calc(0% - 0%)) translateY(calc(0% - 34.75px)) scale(0.75)}}.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field--textarea{transition:none}.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field--textarea.mdc-text-field--filled .mdc-floating-label--shake{animation:mdc-floating-label-shake-float-above-textarea-filled 250ms 1}@keyframes mdc-floating-label-shake-float-above-textarea-filled{0%{transform:translateX(calc(0% - 0%)) translateY(calc(0% - 10.25px)) scale(0.75)}33%{animation-timing-function:cubic-bezier(0.5, 0, 0.701732, 0.495819);transform:translateX(calc(4% - 0%)) translateY(calc(0% - 10.25px)) scale(0.75)}66%{animation-timing-function:cubic-bezier(0.302435, 0.381352, 0.55, 0.956352);transform:translateX(calc(-4% - 0%)) translateY(calc(0% - 10.25px)) scale(0.75)}100%{transform:translateX(calc(0% - 0%)) translateY(calc(0% - 10.25px)) scale(0.75)}}.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field--textarea.mdc-text-field--outlined .mdc-floating-label--shake{animation:mdc-floating-label-shake-float-above-textarea-outlined 250ms 1}@keyframes mdc-floating-label-shake-float-above-textarea-outlined{0%{transform:translateX(calc(0% - 0%)) translateY(calc(0% - 24.75px)) scale(0.75)}33%{animation-timing-function:cubic-bezier(0.5, 0, 0.701732, 0.495819);transform:translateX(calc(4% - 0%)) translateY(calc(0% - 24.75px)) scale(0.75)}66%{animation-timing-function:cubic-bezier(0.302435, 0.381352, 0.55, 0.956352);transform:translateX(calc(-4% - 0%)) translateY(calc(0% - 24.75px)) scale(0.75)}100%{transform:translateX(calc(0% - 0%)) translateY(calc(0% - 24.75px)) scale(0.75)}}.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-floating-label--shake{animation:mdc-floating-label-shake-float-above-text-field-outlined-leading-icon 250ms 1}@keyframes mdc-floating-label-shake-float-above-text-field-outlined-leading-icon{0%{transform:translateX(calc(0% - 32px)) translateY(calc(0% - 34.75px)) scale(0.75)}33%{animation-timing-function:cubic-bezier(0.5, 0, 0.701732, 0.495819);transform:translateX(calc(4% - 32px)) translateY(calc(0% - 34.75px)) scale(0.75)}66%{animation-timing-function:cubic-bezier(0.302435, 0.381352, 0.55, 0.956352);transform:translateX(calc(-4% - 32px)) translateY(calc(0% - 34.75px)) scale(0.75)}100%{transform:translateX(calc(0% - 32px)) translateY(calc(0% - 34.75px)) scale(0.75)}}[dir=rtl] .mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field--with-leading-icon.mdc-text-field--outlined .mdc-floating-label--shake,.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-text-field--with-leading-icon.mdc-text-field--outlined[dir=rtl] .mdc-floating-label--shake{animation:mdc-floating-label-shake-float-above-text-field-outlined-leading-icon 250ms 1}@keyframes mdc-floating-label-shake-float-above-text-field-outlined-leading-icon-rtl{0%{transform:translateX(calc(0% - -32px)) translateY(calc(0% - 34.75px)) scale(0.75)}33%{animation-timing-function:cubic-bezier(0.5, 0, 0.701732, 0.495819);transform:translateX(calc(4% - -32px)) translateY(calc(0% - 34.75px)) scale(0.75)}66%{animation-timing-function:cubic-bezier(0.302435, 0.381352, 0.55, 0.956352);transform:translateX(calc(-4% - -32px)) translateY(calc(0% - 34.75px)) scale(0.75)}100%{transform:translateX(calc(0% - -32px)) translateY(calc(0% - 34.75px)) scale(0.75)}}.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-floating-label{transition:transform 150ms cubic-bezier(0.4, 0, 0.2, 1),color 150ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-floating-label--shake{animation:mdc-floating-label-shake-float-above-standard 250ms 1}@keyframes mdc-floating-label-shake-float-above-standard{0%{transform:translateX(calc(0% - 0%)) translateY(calc(0% - 106%)) scale(0.75)}33%{animation-timing-function:cubic-bezier(0.5, 0, 0.701732, 0.495819);transform:translateX(calc(4% - 0%)) translateY(calc(0% - 106%)) scale(0.75)}66%{animation-timing-function:cubic-bezier(0.302435, 0.381352, 0.55, 0.956352);transform:translateX(calc(-4% - 0%)) translateY(calc(0% - 106%)) scale(0.75)}100%{transform:translateX(calc(0% - 0%)) translateY(calc(0% - 106%)) scale(0.75)}}.mat-mdc-form-field:not(.mat-form-field-no-animations) .mdc-line-ripple::after{transition:transform 180ms cubic-bezier(0.4, 0, 0.2, 1),opacity 180ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-notched-outline .mdc-floating-label{max-width:calc(100% + 1px)}.mdc-notched-outline--upgraded .mdc-floating-label--float-above{max-width:calc(133.3333333333% + 1px)}'],encapsulation:2,data:{animation:[E8.transitionMessages]},changeDetection:0})}return t})(),gu=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Ae,oi,Km,Ae]})}return t})();const A8=new E("MAT_INPUT_VALUE_ACCESSOR"),k8=["button","checkbox","file","hidden","image","radio","range","reset","submit"];let R8=0;const O8=LE(class{constructor(t,n,e,i){this._defaultErrorStateMatcher=t,this._parentForm=n,this._parentFormGroup=e,this.ngControl=i,this.stateChanges=new U}});let F8=(()=>{class t extends O8{get disabled(){return this._disabled}set disabled(e){this._disabled=me(e),this.focused&&(this.focused=!1,this.stateChanges.next())}get id(){return this._id}set id(e){this._id=e||this._uid}get required(){return this._required??this.ngControl?.control?.hasValidator(uM.required)??!1}set required(e){this._required=me(e)}get type(){return this._type}set type(e){this._type=e||"text",this._validateType(),!this._isTextarea&&pE().has(this._type)&&(this._elementRef.nativeElement.type=this._type)}get value(){return this._inputValueAccessor.value}set value(e){e!==this.value&&(this._inputValueAccessor.value=e,this.stateChanges.next())}get readonly(){return this._readonly}set readonly(e){this._readonly=me(e)}constructor(e,i,r,o,s,a,c,l,d,u){super(a,o,s,r),this._elementRef=e,this._platform=i,this._autofillMonitor=l,this._formField=u,this._uid="mat-input-"+R8++,this.focused=!1,this.stateChanges=new U,this.controlType="mat-input",this.autofilled=!1,this._disabled=!1,this._type="text",this._readonly=!1,this._neverEmptyInputTypes=["date","datetime","datetime-local","month","time","week"].filter(m=>pE().has(m)),this._iOSKeyupListener=m=>{const g=m.target;!g.value&&0===g.selectionStart&&0===g.selectionEnd&&(g.setSelectionRange(1,1),g.setSelectionRange(0,0))};const h=this._elementRef.nativeElement,p=h.nodeName.toLowerCase();this._inputValueAccessor=c||h,this._previousNativeValue=this.value,this.id=this.id,i.IOS&&d.runOutsideAngular(()=>{e.nativeElement.addEventListener("keyup",this._iOSKeyupListener)}),this._isServer=!this._platform.isBrowser,this._isNativeSelect="select"===p,this._isTextarea="textarea"===p,this._isInFormField=!!u,this._isNativeSelect&&(this.controlType=h.multiple?"mat-native-select-multiple":"mat-native-select")}ngAfterViewInit(){this._platform.isBrowser&&this._autofillMonitor.monitor(this._elementRef.nativeElement).subscribe(e=>{this.autofilled=e.isAutofilled,this.stateChanges.next()})}ngOnChanges(){this.stateChanges.next()}ngOnDestroy(){this.stateChanges.complete(),this._platform.isBrowser&&this._autofillMonitor.stopMonitoring(this._elementRef.nativeElement),this._platform.IOS&&this._elementRef.nativeElement.removeEventListener("keyup",this._iOSKeyupListener)}ngDoCheck(){this.ngControl&&(this.updateErrorState(),null!==this.ngControl.disabled&&this.ngControl.disabled!==this.disabled&&(this.disabled=this.ngControl.disabled,this.stateChanges.next())),this._dirtyCheckNativeValue(),this._dirtyCheckPlaceholder()}focus(e){this._elementRef.nativeElement.focus(e)}_focusChanged(e){e!==this.focused&&(this.focused=e,this.stateChanges.next())}_onInput(){}_dirtyCheckNativeValue(){const e=this._elementRef.nativeElement.value;this._previousNativeValue!==e&&(this._previousNativeValue=e,this.stateChanges.next())}_dirtyCheckPlaceholder(){const e=this._getPlaceholder();if(e!==this._previousPlaceholder){const i=this._elementRef.nativeElement;this._previousPlaceholder=e,e?i.setAttribute("placeholder",e):i.removeAttribute("placeholder")}}_getPlaceholder(){return this.placeholder||null}_validateType(){k8.indexOf(this._type)}_isNeverEmpty(){return this._neverEmptyInputTypes.indexOf(this._type)>-1}_isBadInput(){let e=this._elementRef.nativeElement.validity;return e&&e.badInput}get empty(){return!(this._isNeverEmpty()||this._elementRef.nativeElement.value||this._isBadInput()||this.autofilled)}get shouldLabelFloat(){if(this._isNativeSelect){const e=this._elementRef.nativeElement,i=e.options[0];return this.focused||e.multiple||!this.empty||!!(e.selectedIndex>-1&&i&&i.label)}return this.focused||!this.empty}setDescribedByIds(e){e.length?this._elementRef.nativeElement.setAttribute("aria-describedby",e.join(" ")):this._elementRef.nativeElement.removeAttribute("aria-describedby")}onContainerClick(){this.focused||this.focus()}_isInlineSelect(){const e=this._elementRef.nativeElement;return this._isNativeSelect&&(e.multiple||e.size>1)}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(tt),f(ai,10),f(us,8),f(ec,8),f(ng),f(A8,10),f(G4),f(j),f(u_,8))};static#t=this.\u0275dir=x({type:t,selectors:[["input","matInput",""],["textarea","matInput",""],["select","matNativeControl",""],["input","matNativeControl",""],["textarea","matNativeControl",""]],hostAttrs:[1,"mat-mdc-input-element"],hostVars:18,hostBindings:function(i,r){1&i&&Y("focus",function(){return r._focusChanged(!0)})("blur",function(){return r._focusChanged(!1)})("input",function(){return r._onInput()}),2&i&&(wn("id",r.id)("disabled",r.disabled)("required",r.required),ue("name",r.name||null)("readonly",r.readonly&&!r._isNativeSelect||null)("aria-invalid",r.empty&&r.required?null:r.errorState)("aria-required",r.required)("id",r.id),he("mat-input-server",r._isServer)("mat-mdc-form-field-textarea-control",r._isInFormField&&r._isTextarea)("mat-mdc-form-field-input-control",r._isInFormField)("mdc-text-field__input",r._isInFormField)("mat-mdc-native-select-inline",r._isInlineSelect()))},inputs:{disabled:"disabled",id:"id",placeholder:"placeholder",name:"name",required:"required",type:"type",errorStateMatcher:"errorStateMatcher",userAriaDescribedBy:["aria-describedby","userAriaDescribedBy"],value:"value",readonly:"readonly"},exportAs:["matInput"],features:[G([{provide:d_,useExisting:t}]),k,ut]})}return t})(),N8=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Ae,gu,gu,W4,Ae]})}return t})();function hs(t,n){const e=ve(t)?t:()=>t,i=r=>r.error(e());return new Ne(n?r=>n.schedule(i,0,r):i)}const P8=["*"];let _u;function tc(t){return function L8(){if(void 0===_u&&(_u=null,typeof window<"u")){const t=window;void 0!==t.trustedTypes&&(_u=t.trustedTypes.createPolicy("angular#components",{createHTML:n=>n}))}return _u}()?.createHTML(t)||t}function DI(t){return Error(`Unable to find icon with the name "${t}"`)}function EI(t){return Error(`The URL provided to MatIconRegistry was not trusted as a resource URL via Angular's DomSanitizer. Attempted URL was "${t}".`)}function SI(t){return Error(`The literal provided to MatIconRegistry was not trusted as safe HTML by Angular's DomSanitizer. Attempted literal was "${t}".`)}class Wr{constructor(n,e,i){this.url=n,this.svgText=e,this.options=i}}let bu=(()=>{class t{constructor(e,i,r,o){this._httpClient=e,this._sanitizer=i,this._errorHandler=o,this._svgIconConfigs=new Map,this._iconSetConfigs=new Map,this._cachedIconsByUrl=new Map,this._inProgressUrlFetches=new Map,this._fontCssClassesByAlias=new Map,this._resolvers=[],this._defaultFontSetClass=["material-icons","mat-ligature-font"],this._document=r}addSvgIcon(e,i,r){return this.addSvgIconInNamespace("",e,i,r)}addSvgIconLiteral(e,i,r){return this.addSvgIconLiteralInNamespace("",e,i,r)}addSvgIconInNamespace(e,i,r,o){return this._addSvgIconConfig(e,i,new Wr(r,null,o))}addSvgIconResolver(e){return this._resolvers.push(e),this}addSvgIconLiteralInNamespace(e,i,r,o){const s=this._sanitizer.sanitize(St.HTML,r);if(!s)throw SI(r);const a=tc(s);return this._addSvgIconConfig(e,i,new Wr("",a,o))}addSvgIconSet(e,i){return this.addSvgIconSetInNamespace("",e,i)}addSvgIconSetLiteral(e,i){return this.addSvgIconSetLiteralInNamespace("",e,i)}addSvgIconSetInNamespace(e,i,r){return this._addSvgIconSetConfig(e,new Wr(i,null,r))}addSvgIconSetLiteralInNamespace(e,i,r){const o=this._sanitizer.sanitize(St.HTML,i);if(!o)throw SI(i);const s=tc(o);return this._addSvgIconSetConfig(e,new Wr("",s,r))}registerFontClassAlias(e,i=e){return this._fontCssClassesByAlias.set(e,i),this}classNameForFontAlias(e){return this._fontCssClassesByAlias.get(e)||e}setDefaultFontSetClass(...e){return this._defaultFontSetClass=e,this}getDefaultFontSetClass(){return this._defaultFontSetClass}getSvgIconFromUrl(e){const i=this._sanitizer.sanitize(St.RESOURCE_URL,e);if(!i)throw EI(e);const r=this._cachedIconsByUrl.get(i);return r?z(vu(r)):this._loadSvgIconFromConfig(new Wr(e,null)).pipe(Ee(o=>this._cachedIconsByUrl.set(i,o)),pe(o=>vu(o)))}getNamedSvgIcon(e,i=""){const r=MI(i,e);let o=this._svgIconConfigs.get(r);if(o)return this._getSvgFromConfig(o);if(o=this._getIconConfigFromResolvers(i,e),o)return this._svgIconConfigs.set(r,o),this._getSvgFromConfig(o);const s=this._iconSetConfigs.get(i);return s?this._getSvgFromIconSetConfigs(e,s):hs(DI(r))}ngOnDestroy(){this._resolvers=[],this._svgIconConfigs.clear(),this._iconSetConfigs.clear(),this._cachedIconsByUrl.clear()}_getSvgFromConfig(e){return e.svgText?z(vu(this._svgElementFromConfig(e))):this._loadSvgIconFromConfig(e).pipe(pe(i=>vu(i)))}_getSvgFromIconSetConfigs(e,i){const r=this._extractIconWithNameFromAnySet(e,i);return r?z(r):bg(i.filter(s=>!s.svgText).map(s=>this._loadSvgIconSetFromConfig(s).pipe(Ri(a=>{const l=`Loading icon set URL: ${this._sanitizer.sanitize(St.RESOURCE_URL,s.url)} failed: ${a.message}`;return this._errorHandler.handleError(new Error(l)),z(null)})))).pipe(pe(()=>{const s=this._extractIconWithNameFromAnySet(e,i);if(!s)throw DI(e);return s}))}_extractIconWithNameFromAnySet(e,i){for(let r=i.length-1;r>=0;r--){const o=i[r];if(o.svgText&&o.svgText.toString().indexOf(e)>-1){const s=this._svgElementFromConfig(o),a=this._extractSvgIconFromSet(s,e,o.options);if(a)return a}}return null}_loadSvgIconFromConfig(e){return this._fetchIcon(e).pipe(Ee(i=>e.svgText=i),pe(()=>this._svgElementFromConfig(e)))}_loadSvgIconSetFromConfig(e){return e.svgText?z(null):this._fetchIcon(e).pipe(Ee(i=>e.svgText=i))}_extractSvgIconFromSet(e,i,r){const o=e.querySelector(`[id="${i}"]`);if(!o)return null;const s=o.cloneNode(!0);if(s.removeAttribute("id"),"svg"===s.nodeName.toLowerCase())return this._setSvgAttributes(s,r);if("symbol"===s.nodeName.toLowerCase())return this._setSvgAttributes(this._toSvgElement(s),r);const a=this._svgElementFromString(tc("<svg></svg>"));return a.appendChild(s),this._setSvgAttributes(a,r)}_svgElementFromString(e){const i=this._document.createElement("DIV");i.innerHTML=e;const r=i.querySelector("svg");if(!r)throw Error("<svg> tag not found");return r}_toSvgElement(e){const i=this._svgElementFromString(tc("<svg></svg>")),r=e.attributes;for(let o=0;o<r.length;o++){const{name:s,value:a}=r[o];"id"!==s&&i.setAttribute(s,a)}for(let o=0;o<e.childNodes.length;o++)e.childNodes[o].nodeType===this._document.ELEMENT_NODE&&i.appendChild(e.childNodes[o].cloneNode(!0));return i}_setSvgAttributes(e,i){return e.setAttribute("fit",""),e.setAttribute("height","100%"),e.setAttribute("width","100%"),e.setAttribute("preserveAspectRatio","xMidYMid meet"),e.setAttribute("focusable","false"),i&&i.viewBox&&e.setAttribute("viewBox",i.viewBox),e}_fetchIcon(e){const{url:i,options:r}=e,o=r?.withCredentials??!1;if(!this._httpClient)throw function B8(){return Error("Could not find HttpClient provider for use with Angular Material icons. Please include the HttpClientModule from @angular/common/http in your app imports.")}();if(null==i)throw Error(`Cannot fetch icon from URL "${i}".`);const s=this._sanitizer.sanitize(St.RESOURCE_URL,i);if(!s)throw EI(i);const a=this._inProgressUrlFetches.get(s);if(a)return a;const c=this._httpClient.get(s,{responseType:"text",withCredentials:o}).pipe(pe(l=>tc(l)),rr(()=>this._inProgressUrlFetches.delete(s)),Sc());return this._inProgressUrlFetches.set(s,c),c}_addSvgIconConfig(e,i,r){return this._svgIconConfigs.set(MI(e,i),r),this}_addSvgIconSetConfig(e,i){const r=this._iconSetConfigs.get(e);return r?r.push(i):this._iconSetConfigs.set(e,[i]),this}_svgElementFromConfig(e){if(!e.svgElement){const i=this._svgElementFromString(e.svgText);this._setSvgAttributes(i,e.options),e.svgElement=i}return e.svgElement}_getIconConfigFromResolvers(e,i){for(let r=0;r<this._resolvers.length;r++){const o=this._resolvers[r](i,e);if(o)return j8(o)?new Wr(o.url,null,o.options):new Wr(o,null)}}static#e=this.\u0275fac=function(i){return new(i||t)(b(Nd,8),b(Lm),b(Z,8),b(vn))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();function vu(t){return t.cloneNode(!0)}function MI(t,n){return t+":"+n}function j8(t){return!(!t.url||!t.options)}const H8=es(class{constructor(t){this._elementRef=t}}),z8=new E("MAT_ICON_DEFAULT_OPTIONS"),$8=new E("mat-icon-location",{providedIn:"root",factory:function U8(){const t=T(Z),n=t?t.location:null;return{getPathname:()=>n?n.pathname+n.search:""}}}),II=["clip-path","color-profile","src","cursor","fill","filter","marker","marker-start","marker-mid","marker-end","mask","stroke"],G8=II.map(t=>`[${t}]`).join(", "),W8=/^url\(['"]?#(.*?)['"]?\)$/;let q8=(()=>{class t extends H8{get inline(){return this._inline}set inline(e){this._inline=me(e)}get svgIcon(){return this._svgIcon}set svgIcon(e){e!==this._svgIcon&&(e?this._updateSvgIcon(e):this._svgIcon&&this._clearSvgElement(),this._svgIcon=e)}get fontSet(){return this._fontSet}set fontSet(e){const i=this._cleanupFontValue(e);i!==this._fontSet&&(this._fontSet=i,this._updateFontIconClasses())}get fontIcon(){return this._fontIcon}set fontIcon(e){const i=this._cleanupFontValue(e);i!==this._fontIcon&&(this._fontIcon=i,this._updateFontIconClasses())}constructor(e,i,r,o,s,a){super(e),this._iconRegistry=i,this._location=o,this._errorHandler=s,this._inline=!1,this._previousFontSetClass=[],this._currentIconFetch=Le.EMPTY,a&&(a.color&&(this.color=this.defaultColor=a.color),a.fontSet&&(this.fontSet=a.fontSet)),r||e.nativeElement.setAttribute("aria-hidden","true")}_splitIconName(e){if(!e)return["",""];const i=e.split(":");switch(i.length){case 1:return["",i[0]];case 2:return i;default:throw Error(`Invalid icon name: "${e}"`)}}ngOnInit(){this._updateFontIconClasses()}ngAfterViewChecked(){const e=this._elementsWithExternalReferences;if(e&&e.size){const i=this._location.getPathname();i!==this._previousPath&&(this._previousPath=i,this._prependPathToReferences(i))}}ngOnDestroy(){this._currentIconFetch.unsubscribe(),this._elementsWithExternalReferences&&this._elementsWithExternalReferences.clear()}_usingFontIcon(){return!this.svgIcon}_setSvgElement(e){this._clearSvgElement();const i=this._location.getPathname();this._previousPath=i,this._cacheChildrenWithExternalReferences(e),this._prependPathToReferences(i),this._elementRef.nativeElement.appendChild(e)}_clearSvgElement(){const e=this._elementRef.nativeElement;let i=e.childNodes.length;for(this._elementsWithExternalReferences&&this._elementsWithExternalReferences.clear();i--;){const r=e.childNodes[i];(1!==r.nodeType||"svg"===r.nodeName.toLowerCase())&&r.remove()}}_updateFontIconClasses(){if(!this._usingFontIcon())return;const e=this._elementRef.nativeElement,i=(this.fontSet?this._iconRegistry.classNameForFontAlias(this.fontSet).split(/ +/):this._iconRegistry.getDefaultFontSetClass()).filter(r=>r.length>0);this._previousFontSetClass.forEach(r=>e.classList.remove(r)),i.forEach(r=>e.classList.add(r)),this._previousFontSetClass=i,this.fontIcon!==this._previousFontIconClass&&!i.includes("mat-ligature-font")&&(this._previousFontIconClass&&e.classList.remove(this._previousFontIconClass),this.fontIcon&&e.classList.add(this.fontIcon),this._previousFontIconClass=this.fontIcon)}_cleanupFontValue(e){return"string"==typeof e?e.trim().split(" ")[0]:e}_prependPathToReferences(e){const i=this._elementsWithExternalReferences;i&&i.forEach((r,o)=>{r.forEach(s=>{o.setAttribute(s.name,`url('${e}#${s.value}')`)})})}_cacheChildrenWithExternalReferences(e){const i=e.querySelectorAll(G8),r=this._elementsWithExternalReferences=this._elementsWithExternalReferences||new Map;for(let o=0;o<i.length;o++)II.forEach(s=>{const a=i[o],c=a.getAttribute(s),l=c?c.match(W8):null;if(l){let d=r.get(a);d||(d=[],r.set(a,d)),d.push({name:s,value:l[1]})}})}_updateSvgIcon(e){if(this._svgNamespace=null,this._svgName=null,this._currentIconFetch.unsubscribe(),e){const[i,r]=this._splitIconName(e);i&&(this._svgNamespace=i),r&&(this._svgName=r),this._currentIconFetch=this._iconRegistry.getNamedSvgIcon(r,i).pipe(vt(1)).subscribe(o=>this._setSvgElement(o),o=>{this._errorHandler.handleError(new Error(`Error retrieving icon ${i}:${r}! ${o.message}`))})}}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(bu),bi("aria-hidden"),f($8),f(vn),f(z8,8))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-icon"]],hostAttrs:["role","img",1,"mat-icon","notranslate"],hostVars:8,hostBindings:function(i,r){2&i&&(ue("data-mat-icon-type",r._usingFontIcon()?"font":"svg")("data-mat-icon-name",r._svgName||r.fontIcon)("data-mat-icon-namespace",r._svgNamespace||r.fontSet)("fontIcon",r._usingFontIcon()?r.fontIcon:null),he("mat-icon-inline",r.inline)("mat-icon-no-color","primary"!==r.color&&"accent"!==r.color&&"warn"!==r.color))},inputs:{color:"color",inline:"inline",svgIcon:"svgIcon",fontSet:"fontSet",fontIcon:"fontIcon"},exportAs:["matIcon"],features:[k],ngContentSelectors:P8,decls:1,vars:0,template:function(i,r){1&i&&(at(),Q(0))},styles:["mat-icon,mat-icon.mat-primary,mat-icon.mat-accent,mat-icon.mat-warn{color:var(--mat-icon-color)}.mat-icon{-webkit-user-select:none;user-select:none;background-repeat:no-repeat;display:inline-block;fill:currentColor;height:24px;width:24px;overflow:hidden}.mat-icon.mat-icon-inline{font-size:inherit;height:inherit;line-height:inherit;width:inherit}.mat-icon.mat-ligature-font[fontIcon]::before{content:attr(fontIcon)}[dir=rtl] .mat-icon-rtl-mirror{transform:scale(-1, 1)}.mat-form-field:not(.mat-form-field-appearance-legacy) .mat-form-field-prefix .mat-icon,.mat-form-field:not(.mat-form-field-appearance-legacy) .mat-form-field-suffix .mat-icon{display:block}.mat-form-field:not(.mat-form-field-appearance-legacy) .mat-form-field-prefix .mat-icon-button .mat-icon,.mat-form-field:not(.mat-form-field-appearance-legacy) .mat-form-field-suffix .mat-icon-button .mat-icon{margin:auto}"],encapsulation:2,changeDetection:0})}return t})(),Y8=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Ae,Ae]})}return t})();function Z8(t,n){1&t&&(w(0,"span",7),Q(1,1),D())}function K8(t,n){1&t&&(w(0,"span",8),Q(1,2),D())}const TI=["*",[["mat-chip-avatar"],["","matChipAvatar",""]],[["mat-chip-trailing-icon"],["","matChipRemove",""],["","matChipTrailingIcon",""]]],AI=["*","mat-chip-avatar, [matChipAvatar]","mat-chip-trailing-icon,[matChipRemove],[matChipTrailingIcon]"];function J8(t,n){1&t&&(ot(0),K(1,"span",8),st())}function e6(t,n){1&t&&(w(0,"span",9),Q(1),D())}function t6(t,n){1&t&&(ot(0),Q(1,1),st())}function n6(t,n){1&t&&Q(0,2,["*ngIf","contentEditInput; else defaultMatChipEditInput"])}function i6(t,n){1&t&&K(0,"span",12)}function r6(t,n){if(1&t&&(ot(0),O(1,n6,1,0,"ng-content",10),O(2,i6,1,0,"ng-template",null,11,Ca),st()),2&t){const e=Ci(3),i=be();M(1),F("ngIf",i.contentEditInput)("ngIfElse",e)}}function o6(t,n){1&t&&(w(0,"span",13),Q(1,3),D())}const s6=[[["mat-chip-avatar"],["","matChipAvatar",""]],"*",[["","matChipEditInput",""]],[["mat-chip-trailing-icon"],["","matChipRemove",""],["","matChipTrailingIcon",""]]],a6=["mat-chip-avatar, [matChipAvatar]","*","[matChipEditInput]","mat-chip-trailing-icon,[matChipRemove],[matChipTrailingIcon]"],h_=["*"],yu=new E("mat-chips-default-options"),f_=new E("MatChipAvatar"),p_=new E("MatChipTrailingIcon"),m_=new E("MatChipRemove"),wu=new E("MatChip");class c6{}const l6=Dd(c6,-1);let fs=(()=>{class t extends l6{get disabled(){return this._disabled||this._parentChip.disabled}set disabled(e){this._disabled=me(e)}_getDisabledAttribute(){return this.disabled&&!this._allowFocusWhenDisabled?"":null}_getTabindex(){return this.disabled&&!this._allowFocusWhenDisabled||!this.isInteractive?null:this.tabIndex.toString()}constructor(e,i){super(),this._elementRef=e,this._parentChip=i,this.isInteractive=!0,this._isPrimary=!0,this._disabled=!1,this._allowFocusWhenDisabled=!1,"BUTTON"===e.nativeElement.nodeName&&e.nativeElement.setAttribute("type","button")}focus(){this._elementRef.nativeElement.focus()}_handleClick(e){!this.disabled&&this.isInteractive&&this._isPrimary&&(e.preventDefault(),this._parentChip._handlePrimaryActionInteraction())}_handleKeydown(e){(13===e.keyCode||32===e.keyCode)&&!this.disabled&&this.isInteractive&&this._isPrimary&&!this._parentChip._isEditing&&(e.preventDefault(),this._parentChip._handlePrimaryActionInteraction())}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(wu))};static#t=this.\u0275dir=x({type:t,selectors:[["","matChipAction",""]],hostAttrs:[1,"mdc-evolution-chip__action","mat-mdc-chip-action"],hostVars:9,hostBindings:function(i,r){1&i&&Y("click",function(s){return r._handleClick(s)})("keydown",function(s){return r._handleKeydown(s)}),2&i&&(ue("tabindex",r._getTabindex())("disabled",r._getDisabledAttribute())("aria-disabled",r.disabled),he("mdc-evolution-chip__action--primary",r._isPrimary)("mdc-evolution-chip__action--presentational",!r.isInteractive)("mdc-evolution-chip__action--trailing",!r._isPrimary))},inputs:{disabled:"disabled",tabIndex:"tabIndex",isInteractive:"isInteractive",_allowFocusWhenDisabled:"_allowFocusWhenDisabled"},features:[k]})}return t})(),OI=(()=>{class t extends fs{constructor(){super(...arguments),this._isPrimary=!1}_handleClick(e){this.disabled||(e.stopPropagation(),e.preventDefault(),this._parentChip.remove())}_handleKeydown(e){(13===e.keyCode||32===e.keyCode)&&!this.disabled&&(e.stopPropagation(),e.preventDefault(),this._parentChip.remove())}static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275dir=x({type:t,selectors:[["","matChipRemove",""]],hostAttrs:["role","button",1,"mat-mdc-chip-remove","mat-mdc-chip-trailing-icon","mat-mdc-focus-indicator","mdc-evolution-chip__icon","mdc-evolution-chip__icon--trailing"],hostVars:1,hostBindings:function(i,r){2&i&&ue("aria-hidden",null)},features:[G([{provide:m_,useExisting:t}]),k]})}return t})(),h6=0;const f6=Dd(es(tg(Cd(class{constructor(t){this._elementRef=t}})),"primary"),-1);let ps=(()=>{class t extends f6{_hasFocus(){return this._hasFocusInternal}get value(){return void 0!==this._value?this._value:this._textElement.textContent.trim()}set value(e){this._value=e}get removable(){return this._removable}set removable(e){this._removable=me(e)}get highlighted(){return this._highlighted}set highlighted(e){this._highlighted=me(e)}get ripple(){return this._rippleLoader?.getRipple(this._elementRef.nativeElement)}set ripple(e){this._rippleLoader?.attachRipple(this._elementRef.nativeElement,e)}constructor(e,i,r,o,s,a,c,l){super(i),this._changeDetectorRef=e,this._ngZone=r,this._focusMonitor=o,this._globalRippleOptions=c,this._onFocus=new U,this._onBlur=new U,this.role=null,this._hasFocusInternal=!1,this.id="mat-mdc-chip-"+h6++,this.ariaLabel=null,this.ariaDescription=null,this._ariaDescriptionId=`${this.id}-aria-description`,this._removable=!0,this._highlighted=!1,this.removed=new q,this.destroyed=new q,this.basicChipAttrName="mat-basic-chip",this._rippleLoader=T(qE),this._document=s,this._animationsDisabled="NoopAnimations"===a,null!=l&&(this.tabIndex=parseInt(l)??this.defaultTabIndex),this._monitorFocus(),this._rippleLoader?.configureRipple(this._elementRef.nativeElement,{className:"mat-mdc-chip-ripple",disabled:this._isRippleDisabled()})}ngOnInit(){const e=this._elementRef.nativeElement;this._isBasicChip=e.hasAttribute(this.basicChipAttrName)||e.tagName.toLowerCase()===this.basicChipAttrName}ngAfterViewInit(){this._textElement=this._elementRef.nativeElement.querySelector(".mat-mdc-chip-action-label"),this._pendingFocus&&(this._pendingFocus=!1,this.focus())}ngAfterContentInit(){this._actionChanges=An(this._allLeadingIcons.changes,this._allTrailingIcons.changes,this._allRemoveIcons.changes).subscribe(()=>this._changeDetectorRef.markForCheck())}ngDoCheck(){this._rippleLoader.setDisabled(this._elementRef.nativeElement,this._isRippleDisabled())}ngOnDestroy(){this._focusMonitor.stopMonitoring(this._elementRef),this._actionChanges?.unsubscribe(),this.destroyed.emit({chip:this}),this.destroyed.complete()}remove(){this.removable&&this.removed.emit({chip:this})}_isRippleDisabled(){return this.disabled||this.disableRipple||this._animationsDisabled||this._isBasicChip||!!this._globalRippleOptions?.disabled}_hasTrailingIcon(){return!(!this.trailingIcon&&!this.removeIcon)}_handleKeydown(e){(8===e.keyCode||46===e.keyCode)&&(e.preventDefault(),this.remove())}focus(){this.disabled||(this.primaryAction?this.primaryAction.focus():this._pendingFocus=!0)}_getSourceAction(e){return this._getActions().find(i=>{const r=i._elementRef.nativeElement;return r===e||r.contains(e)})}_getActions(){const e=[];return this.primaryAction&&e.push(this.primaryAction),this.removeIcon&&e.push(this.removeIcon),this.trailingIcon&&e.push(this.trailingIcon),e}_handlePrimaryActionInteraction(){}_monitorFocus(){this._focusMonitor.monitor(this._elementRef,!0).subscribe(e=>{const i=null!==e;i!==this._hasFocusInternal&&(this._hasFocusInternal=i,i?this._onFocus.next({chip:this}):this._ngZone.onStable.pipe(vt(1)).subscribe(()=>this._ngZone.run(()=>this._onBlur.next({chip:this}))))})}static#e=this.\u0275fac=function(i){return new(i||t)(f(ct),f($),f(j),f(nr),f(Z),f(gt,8),f(Sd,8),bi("tabindex"))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-basic-chip"],["","mat-basic-chip",""],["mat-chip"],["","mat-chip",""]],contentQueries:function(i,r,o){if(1&i&&(xe(o,f_,5),xe(o,p_,5),xe(o,m_,5),xe(o,f_,5),xe(o,p_,5),xe(o,m_,5)),2&i){let s;B(s=V())&&(r.leadingIcon=s.first),B(s=V())&&(r.trailingIcon=s.first),B(s=V())&&(r.removeIcon=s.first),B(s=V())&&(r._allLeadingIcons=s),B(s=V())&&(r._allTrailingIcons=s),B(s=V())&&(r._allRemoveIcons=s)}},viewQuery:function(i,r){if(1&i&&Se(fs,5),2&i){let o;B(o=V())&&(r.primaryAction=o.first)}},hostAttrs:[1,"mat-mdc-chip"],hostVars:30,hostBindings:function(i,r){1&i&&Y("keydown",function(s){return r._handleKeydown(s)}),2&i&&(wn("id",r.id),ue("role",r.role)("tabindex",r.role?r.tabIndex:null)("aria-label",r.ariaLabel),he("mdc-evolution-chip",!r._isBasicChip)("mdc-evolution-chip--disabled",r.disabled)("mdc-evolution-chip--with-trailing-action",r._hasTrailingIcon())("mdc-evolution-chip--with-primary-graphic",r.leadingIcon)("mdc-evolution-chip--with-primary-icon",r.leadingIcon)("mdc-evolution-chip--with-avatar",r.leadingIcon)("mat-mdc-chip-with-avatar",r.leadingIcon)("mat-mdc-chip-highlighted",r.highlighted)("mat-mdc-chip-disabled",r.disabled)("mat-mdc-basic-chip",r._isBasicChip)("mat-mdc-standard-chip",!r._isBasicChip)("mat-mdc-chip-with-trailing-icon",r._hasTrailingIcon())("_mat-animation-noopable",r._animationsDisabled))},inputs:{color:"color",disabled:"disabled",disableRipple:"disableRipple",tabIndex:"tabIndex",role:"role",id:"id",ariaLabel:["aria-label","ariaLabel"],ariaDescription:["aria-description","ariaDescription"],value:"value",removable:"removable",highlighted:"highlighted"},outputs:{removed:"removed",destroyed:"destroyed"},exportAs:["matChip"],features:[G([{provide:wu,useExisting:t}]),k],ngContentSelectors:AI,decls:8,vars:3,consts:[[1,"mat-mdc-chip-focus-overlay"],[1,"mdc-evolution-chip__cell","mdc-evolution-chip__cell--primary"],["matChipAction","",3,"isInteractive"],["class","mdc-evolution-chip__graphic mat-mdc-chip-graphic",4,"ngIf"],[1,"mdc-evolution-chip__text-label","mat-mdc-chip-action-label"],[1,"mat-mdc-chip-primary-focus-indicator","mat-mdc-focus-indicator"],["class","mdc-evolution-chip__cell mdc-evolution-chip__cell--trailing",4,"ngIf"],[1,"mdc-evolution-chip__graphic","mat-mdc-chip-graphic"],[1,"mdc-evolution-chip__cell","mdc-evolution-chip__cell--trailing"]],template:function(i,r){1&i&&(at(TI),K(0,"span",0),w(1,"span",1)(2,"span",2),O(3,Z8,2,0,"span",3),w(4,"span",4),Q(5),K(6,"span",5),D()()(),O(7,K8,2,0,"span",6)),2&i&&(M(2),F("isInteractive",!1),M(1),F("ngIf",r.leadingIcon),M(4),F("ngIf",r._hasTrailingIcon()))},dependencies:[Ii,fs],styles:['.mdc-evolution-chip,.mdc-evolution-chip__cell,.mdc-evolution-chip__action{display:inline-flex;align-items:center}.mdc-evolution-chip{position:relative;max-width:100%}.mdc-evolution-chip .mdc-elevation-overlay{width:100%;height:100%;top:0;left:0}.mdc-evolution-chip__cell,.mdc-evolution-chip__action{height:100%}.mdc-evolution-chip__cell--primary{overflow-x:hidden}.mdc-evolution-chip__cell--trailing{flex:1 0 auto}.mdc-evolution-chip__action{align-items:center;background:none;border:none;box-sizing:content-box;cursor:pointer;display:inline-flex;justify-content:center;outline:none;padding:0;text-decoration:none;color:inherit}.mdc-evolution-chip__action--presentational{cursor:auto}.mdc-evolution-chip--disabled,.mdc-evolution-chip__action:disabled{pointer-events:none}.mdc-evolution-chip__action--primary{overflow-x:hidden}.mdc-evolution-chip__action--trailing{position:relative;overflow:visible}.mdc-evolution-chip__action--primary:before{box-sizing:border-box;content:"";height:100%;left:0;position:absolute;pointer-events:none;top:0;width:100%;z-index:1}.mdc-evolution-chip--touch{margin-top:8px;margin-bottom:8px}.mdc-evolution-chip__action-touch{position:absolute;top:50%;height:48px;left:0;right:0;transform:translateY(-50%)}.mdc-evolution-chip__text-label{white-space:nowrap;user-select:none;text-overflow:ellipsis;overflow:hidden}.mdc-evolution-chip__graphic{align-items:center;display:inline-flex;justify-content:center;overflow:hidden;pointer-events:none;position:relative;flex:1 0 auto}.mdc-evolution-chip__checkmark{position:absolute;opacity:0;top:50%;left:50%}.mdc-evolution-chip--selectable:not(.mdc-evolution-chip--selected):not(.mdc-evolution-chip--with-primary-icon) .mdc-evolution-chip__graphic{width:0}.mdc-evolution-chip__checkmark-background{opacity:0}.mdc-evolution-chip__checkmark-svg{display:block}.mdc-evolution-chip__checkmark-path{stroke-width:2px;stroke-dasharray:29.7833385;stroke-dashoffset:29.7833385;stroke:currentColor}.mdc-evolution-chip--selecting .mdc-evolution-chip__graphic{transition:width 150ms 0ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--selecting .mdc-evolution-chip__checkmark{transition:transform 150ms 0ms cubic-bezier(0.4, 0, 0.2, 1);transform:translate(-75%, -50%)}.mdc-evolution-chip--selecting .mdc-evolution-chip__checkmark-path{transition:stroke-dashoffset 150ms 45ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--deselecting .mdc-evolution-chip__graphic{transition:width 100ms 0ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--deselecting .mdc-evolution-chip__checkmark{transition:opacity 50ms 0ms linear,transform 100ms 0ms cubic-bezier(0.4, 0, 0.2, 1);transform:translate(-75%, -50%)}.mdc-evolution-chip--deselecting .mdc-evolution-chip__checkmark-path{stroke-dashoffset:0}.mdc-evolution-chip--selecting-with-primary-icon .mdc-evolution-chip__icon--primary{transition:opacity 75ms 0ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--selecting-with-primary-icon .mdc-evolution-chip__checkmark-path{transition:stroke-dashoffset 150ms 75ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--deselecting-with-primary-icon .mdc-evolution-chip__icon--primary{transition:opacity 150ms 75ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--deselecting-with-primary-icon .mdc-evolution-chip__checkmark{transition:opacity 75ms 0ms cubic-bezier(0.4, 0, 0.2, 1);transform:translate(-50%, -50%)}.mdc-evolution-chip--deselecting-with-primary-icon .mdc-evolution-chip__checkmark-path{stroke-dashoffset:0}.mdc-evolution-chip--selected .mdc-evolution-chip__icon--primary{opacity:0}.mdc-evolution-chip--selected .mdc-evolution-chip__checkmark{transform:translate(-50%, -50%);opacity:1}.mdc-evolution-chip--selected .mdc-evolution-chip__checkmark-path{stroke-dashoffset:0}@keyframes mdc-evolution-chip-enter{from{transform:scale(0.8);opacity:.4}to{transform:scale(1);opacity:1}}.mdc-evolution-chip--enter{animation:mdc-evolution-chip-enter 100ms 0ms cubic-bezier(0, 0, 0.2, 1)}@keyframes mdc-evolution-chip-exit{from{opacity:1}to{opacity:0}}.mdc-evolution-chip--exit{animation:mdc-evolution-chip-exit 75ms 0ms cubic-bezier(0.4, 0, 1, 1)}.mdc-evolution-chip--hidden{opacity:0;pointer-events:none;transition:width 150ms 0ms cubic-bezier(0.4, 0, 1, 1)}.mat-mdc-standard-chip{border-radius:var(--mdc-chip-container-shape-radius);height:var(--mdc-chip-container-height);--mdc-chip-container-shape-family:rounded;--mdc-chip-container-shape-radius:16px 16px 16px 16px;--mdc-chip-with-avatar-avatar-shape-family:rounded;--mdc-chip-with-avatar-avatar-shape-radius:14px 14px 14px 14px;--mdc-chip-with-avatar-avatar-size:28px;--mdc-chip-with-icon-icon-size:18px}.mat-mdc-standard-chip .mdc-evolution-chip__ripple{border-radius:var(--mdc-chip-container-shape-radius)}.mat-mdc-standard-chip .mdc-evolution-chip__action--primary:before{border-radius:var(--mdc-chip-container-shape-radius)}.mat-mdc-standard-chip .mdc-evolution-chip__icon--primary{border-radius:var(--mdc-chip-with-avatar-avatar-shape-radius)}.mat-mdc-standard-chip.mdc-evolution-chip--selectable:not(.mdc-evolution-chip--with-primary-icon){--mdc-chip-graphic-selected-width:var(--mdc-chip-with-avatar-avatar-size)}.mat-mdc-standard-chip .mdc-evolution-chip__graphic{height:var(--mdc-chip-with-avatar-avatar-size);width:var(--mdc-chip-with-avatar-avatar-size);font-size:var(--mdc-chip-with-avatar-avatar-size)}.mat-mdc-standard-chip:not(.mdc-evolution-chip--disabled){background-color:var(--mdc-chip-elevated-container-color)}.mat-mdc-standard-chip.mdc-evolution-chip--disabled{background-color:var(--mdc-chip-elevated-disabled-container-color)}.mat-mdc-standard-chip.mdc-evolution-chip--selected.mdc-evolution-chip--disabled{background-color:var(--mdc-chip-elevated-disabled-container-color)}.mat-mdc-standard-chip .mdc-evolution-chip__text-label{font-family:var(--mdc-chip-label-text-font);line-height:var(--mdc-chip-label-text-line-height);font-size:var(--mdc-chip-label-text-size);font-weight:var(--mdc-chip-label-text-weight);letter-spacing:var(--mdc-chip-label-text-tracking)}.mat-mdc-standard-chip:not(.mdc-evolution-chip--disabled) .mdc-evolution-chip__text-label{color:var(--mdc-chip-label-text-color)}.mat-mdc-standard-chip.mdc-evolution-chip--disabled .mdc-evolution-chip__text-label{color:var(--mdc-chip-disabled-label-text-color)}.mat-mdc-standard-chip.mdc-evolution-chip--selected.mdc-evolution-chip--disabled .mdc-evolution-chip__text-label{color:var(--mdc-chip-disabled-label-text-color)}.mat-mdc-standard-chip .mdc-evolution-chip__icon--primary{height:var(--mdc-chip-with-icon-icon-size);width:var(--mdc-chip-with-icon-icon-size);font-size:var(--mdc-chip-with-icon-icon-size)}.mat-mdc-standard-chip:not(.mdc-evolution-chip--disabled) .mdc-evolution-chip__icon--primary{color:var(--mdc-chip-with-icon-icon-color)}.mat-mdc-standard-chip.mdc-evolution-chip--disabled .mdc-evolution-chip__icon--primary{color:var(--mdc-chip-with-icon-disabled-icon-color)}.mat-mdc-standard-chip:not(.mdc-evolution-chip--disabled) .mdc-evolution-chip__checkmark{color:var(--mdc-chip-with-icon-selected-icon-color)}.mat-mdc-standard-chip.mdc-evolution-chip--disabled .mdc-evolution-chip__checkmark{color:var(--mdc-chip-with-icon-disabled-icon-color)}.mat-mdc-standard-chip:not(.mdc-evolution-chip--disabled) .mdc-evolution-chip__icon--trailing{color:var(--mdc-chip-with-trailing-icon-trailing-icon-color)}.mat-mdc-standard-chip.mdc-evolution-chip--disabled .mdc-evolution-chip__icon--trailing{color:var(--mdc-chip-with-trailing-icon-disabled-trailing-icon-color)}.mat-mdc-standard-chip .mdc-evolution-chip__action--primary.mdc-ripple-upgraded--background-focused .mdc-evolution-chip__ripple::before,.mat-mdc-standard-chip .mdc-evolution-chip__action--primary:not(.mdc-ripple-upgraded):focus .mdc-evolution-chip__ripple::before{transition-duration:75ms;opacity:var(--mdc-chip-focus-state-layer-opacity)}.mat-mdc-chip-focus-overlay{background:var(--mdc-chip-focus-state-layer-color);opacity:var(--mdc-chip-focus-state-layer-opacity)}.mat-mdc-standard-chip .mdc-evolution-chip__checkmark{height:20px;width:20px}.mat-mdc-standard-chip .mdc-evolution-chip__icon--trailing{height:18px;width:18px;font-size:18px}.mat-mdc-standard-chip .mdc-evolution-chip__action--primary{padding-left:12px;padding-right:12px}[dir=rtl] .mat-mdc-standard-chip .mdc-evolution-chip__action--primary,.mat-mdc-standard-chip .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:12px;padding-right:12px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic{padding-left:6px;padding-right:6px}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic[dir=rtl]{padding-left:6px;padding-right:6px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary{padding-left:0;padding-right:12px}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:12px;padding-right:0}.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing{padding-left:8px;padding-right:8px}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing,.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing[dir=rtl]{padding-left:8px;padding-right:8px}.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing{left:8px;right:initial}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing,.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing[dir=rtl]{left:initial;right:8px}.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary{padding-left:12px;padding-right:0}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary,.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:0;padding-right:12px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic{padding-left:6px;padding-right:6px}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic[dir=rtl]{padding-left:6px;padding-right:6px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing{padding-left:8px;padding-right:8px}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing[dir=rtl]{padding-left:8px;padding-right:8px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing{left:8px;right:initial}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing[dir=rtl]{left:initial;right:8px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary{padding-left:0;padding-right:0}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:0;padding-right:0}.mat-mdc-standard-chip.mdc-evolution-chip--disabled .mdc-evolution-chip__checkmark{color:var(--mdc-chip-with-icon-selected-icon-color, currentColor)}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic{padding-left:4px;padding-right:8px}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic[dir=rtl]{padding-left:8px;padding-right:4px}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary{padding-left:0;padding-right:12px}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:12px;padding-right:0}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic{padding-left:4px;padding-right:8px}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic[dir=rtl]{padding-left:8px;padding-right:4px}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing{padding-left:8px;padding-right:8px}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing[dir=rtl]{padding-left:8px;padding-right:8px}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing{left:8px;right:initial}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing[dir=rtl]{left:initial;right:8px}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary{padding-left:0;padding-right:0}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:0;padding-right:0}.mat-mdc-standard-chip{-webkit-tap-highlight-color:rgba(0,0,0,0)}.cdk-high-contrast-active .mat-mdc-standard-chip{outline:solid 1px}.cdk-high-contrast-active .mat-mdc-standard-chip .mdc-evolution-chip__checkmark-path{stroke:CanvasText !important}.mat-mdc-standard-chip.mdc-evolution-chip--disabled{opacity:.4}.mat-mdc-standard-chip .mdc-evolution-chip__cell--primary,.mat-mdc-standard-chip .mdc-evolution-chip__action--primary,.mat-mdc-standard-chip .mat-mdc-chip-action-label{overflow:visible}.mat-mdc-standard-chip .mdc-evolution-chip__cell--primary{flex-basis:100%}.mat-mdc-standard-chip .mdc-evolution-chip__action--primary{font:inherit;letter-spacing:inherit;white-space:inherit}.mat-mdc-standard-chip .mat-mdc-chip-graphic,.mat-mdc-standard-chip .mat-mdc-chip-trailing-icon{box-sizing:content-box}.mat-mdc-standard-chip._mat-animation-noopable,.mat-mdc-standard-chip._mat-animation-noopable .mdc-evolution-chip__graphic,.mat-mdc-standard-chip._mat-animation-noopable .mdc-evolution-chip__checkmark,.mat-mdc-standard-chip._mat-animation-noopable .mdc-evolution-chip__checkmark-path{transition-duration:1ms;animation-duration:1ms}.mat-mdc-basic-chip .mdc-evolution-chip__action--primary{font:inherit}.mat-mdc-chip-focus-overlay{top:0;left:0;right:0;bottom:0;position:absolute;pointer-events:none;opacity:0;border-radius:inherit;transition:opacity 150ms linear}._mat-animation-noopable .mat-mdc-chip-focus-overlay{transition:none}.mat-mdc-basic-chip .mat-mdc-chip-focus-overlay{display:none}.mat-mdc-chip:hover .mat-mdc-chip-focus-overlay{opacity:.04}.mat-mdc-chip.cdk-focused .mat-mdc-chip-focus-overlay{opacity:.12}.mat-mdc-chip .mat-ripple.mat-mdc-chip-ripple{top:0;left:0;right:0;bottom:0;position:absolute;pointer-events:none;border-radius:inherit}.mat-mdc-chip-avatar{text-align:center;line-height:1;color:var(--mdc-chip-with-icon-icon-color, currentColor)}.mat-mdc-chip{position:relative;z-index:0}.mat-mdc-chip-action-label{text-align:left;z-index:1}[dir=rtl] .mat-mdc-chip-action-label{text-align:right}.mat-mdc-chip.mdc-evolution-chip--with-trailing-action .mat-mdc-chip-action-label{position:relative}.mat-mdc-chip-action-label .mat-mdc-chip-primary-focus-indicator{position:absolute;top:0;right:0;bottom:0;left:0;pointer-events:none}.mat-mdc-chip-action-label .mat-mdc-focus-indicator::before{margin:calc(calc(var(--mat-mdc-focus-indicator-border-width, 3px) + 2px) * -1)}.mat-mdc-chip-remove{opacity:.54}.mat-mdc-chip-remove:focus{opacity:1}.mat-mdc-chip-remove::before{margin:calc(var(--mat-mdc-focus-indicator-border-width, 3px) * -1);left:8px;right:8px}.mat-mdc-chip-remove .mat-icon{width:inherit;height:inherit;font-size:inherit;box-sizing:content-box}.mat-chip-edit-input{cursor:text;display:inline-block;color:inherit;outline:0}.cdk-high-contrast-active .mat-mdc-chip-selected:not(.mat-mdc-chip-multiple){outline-width:3px}.mat-mdc-chip-action:focus .mat-mdc-focus-indicator::before{content:""}'],encapsulation:2,changeDetection:0})}return t})(),xu=(()=>{class t{constructor(e,i){this._elementRef=e,this._document=i}initialize(e){this.getNativeElement().focus(),this.setValue(e)}getNativeElement(){return this._elementRef.nativeElement}setValue(e){this.getNativeElement().textContent=e,this._moveCursorToEndOfInput()}getValue(){return this.getNativeElement().textContent||""}_moveCursorToEndOfInput(){const e=this._document.createRange();e.selectNodeContents(this.getNativeElement()),e.collapse(!1);const i=window.getSelection();i.removeAllRanges(),i.addRange(e)}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(Z))};static#t=this.\u0275dir=x({type:t,selectors:[["span","matChipEditInput",""]],hostAttrs:["role","textbox","tabindex","-1","contenteditable","true",1,"mat-chip-edit-input"]})}return t})(),g_=(()=>{class t extends ps{constructor(e,i,r,o,s,a,c,l){super(e,i,r,o,s,a,c,l),this.basicChipAttrName="mat-basic-chip-row",this._editStartPending=!1,this.editable=!1,this.edited=new q,this._isEditing=!1,this.role="row",this._onBlur.pipe(Re(this.destroyed)).subscribe(()=>{this._isEditing&&!this._editStartPending&&this._onEditFinish()})}_hasTrailingIcon(){return!this._isEditing&&super._hasTrailingIcon()}_handleFocus(){!this._isEditing&&!this.disabled&&this.focus()}_handleKeydown(e){13!==e.keyCode||this.disabled?this._isEditing?e.stopPropagation():super._handleKeydown(e):this._isEditing?(e.preventDefault(),this._onEditFinish()):this.editable&&this._startEditing(e)}_handleDoubleclick(e){!this.disabled&&this.editable&&this._startEditing(e)}_startEditing(e){if(!this.primaryAction||this.removeIcon&&this._getSourceAction(e.target)===this.removeIcon)return;const i=this.value;this._isEditing=this._editStartPending=!0,this._changeDetectorRef.detectChanges(),setTimeout(()=>{this._getEditInput().initialize(i),this._editStartPending=!1})}_onEditFinish(){this._isEditing=this._editStartPending=!1,this.edited.emit({chip:this,value:this._getEditInput().getValue()}),(this._document.activeElement===this._getEditInput().getNativeElement()||this._document.activeElement===this._document.body)&&this.primaryAction.focus()}_isRippleDisabled(){return super._isRippleDisabled()||this._isEditing}_getEditInput(){return this.contentEditInput||this.defaultEditInput}static#e=this.\u0275fac=function(i){return new(i||t)(f(ct),f($),f(j),f(nr),f(Z),f(gt,8),f(Sd,8),bi("tabindex"))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-chip-row"],["","mat-chip-row",""],["mat-basic-chip-row"],["","mat-basic-chip-row",""]],contentQueries:function(i,r,o){if(1&i&&xe(o,xu,5),2&i){let s;B(s=V())&&(r.contentEditInput=s.first)}},viewQuery:function(i,r){if(1&i&&Se(xu,5),2&i){let o;B(o=V())&&(r.defaultEditInput=o.first)}},hostAttrs:[1,"mat-mdc-chip","mat-mdc-chip-row","mdc-evolution-chip"],hostVars:27,hostBindings:function(i,r){1&i&&Y("focus",function(s){return r._handleFocus(s)})("dblclick",function(s){return r._handleDoubleclick(s)}),2&i&&(wn("id",r.id),ue("tabindex",r.disabled?null:-1)("aria-label",null)("aria-description",null)("role",r.role),he("mat-mdc-chip-with-avatar",r.leadingIcon)("mat-mdc-chip-disabled",r.disabled)("mat-mdc-chip-editing",r._isEditing)("mat-mdc-chip-editable",r.editable)("mdc-evolution-chip--disabled",r.disabled)("mdc-evolution-chip--with-trailing-action",r._hasTrailingIcon())("mdc-evolution-chip--with-primary-graphic",r.leadingIcon)("mdc-evolution-chip--with-primary-icon",r.leadingIcon)("mdc-evolution-chip--with-avatar",r.leadingIcon)("mat-mdc-chip-highlighted",r.highlighted)("mat-mdc-chip-with-trailing-icon",r._hasTrailingIcon()))},inputs:{color:"color",disabled:"disabled",disableRipple:"disableRipple",tabIndex:"tabIndex",editable:"editable"},outputs:{edited:"edited"},features:[G([{provide:ps,useExisting:t},{provide:wu,useExisting:t}]),k],ngContentSelectors:a6,decls:10,vars:12,consts:[[4,"ngIf"],["role","gridcell","matChipAction","",1,"mdc-evolution-chip__cell","mdc-evolution-chip__cell--primary",3,"tabIndex","disabled"],["class","mdc-evolution-chip__graphic mat-mdc-chip-graphic",4,"ngIf"],[1,"mdc-evolution-chip__text-label","mat-mdc-chip-action-label",3,"ngSwitch"],[4,"ngSwitchCase"],["aria-hidden","true",1,"mat-mdc-chip-primary-focus-indicator","mat-mdc-focus-indicator"],["class","mdc-evolution-chip__cell mdc-evolution-chip__cell--trailing","role","gridcell",4,"ngIf"],[1,"cdk-visually-hidden",3,"id"],[1,"mat-mdc-chip-focus-overlay"],[1,"mdc-evolution-chip__graphic","mat-mdc-chip-graphic"],[4,"ngIf","ngIfElse"],["defaultMatChipEditInput",""],["matChipEditInput",""],["role","gridcell",1,"mdc-evolution-chip__cell","mdc-evolution-chip__cell--trailing"]],template:function(i,r){1&i&&(at(s6),O(0,J8,2,0,"ng-container",0),w(1,"span",1),O(2,e6,2,0,"span",2),w(3,"span",3),O(4,t6,2,0,"ng-container",4),O(5,r6,4,2,"ng-container",4),K(6,"span",5),D()(),O(7,o6,2,0,"span",6),w(8,"span",7),N(9),D()),2&i&&(F("ngIf",!r._isEditing),M(1),F("tabIndex",r.tabIndex)("disabled",r.disabled),ue("aria-label",r.ariaLabel)("aria-describedby",r._ariaDescriptionId),M(1),F("ngIf",r.leadingIcon),M(1),F("ngSwitch",r._isEditing),M(1),F("ngSwitchCase",!1),M(1),F("ngSwitchCase",!0),M(2),F("ngIf",r._hasTrailingIcon()),M(1),F("id",r._ariaDescriptionId),M(1),Vn(r.ariaDescription))},dependencies:[Ii,Aa,xm,fs,xu],styles:['.mdc-evolution-chip,.mdc-evolution-chip__cell,.mdc-evolution-chip__action{display:inline-flex;align-items:center}.mdc-evolution-chip{position:relative;max-width:100%}.mdc-evolution-chip .mdc-elevation-overlay{width:100%;height:100%;top:0;left:0}.mdc-evolution-chip__cell,.mdc-evolution-chip__action{height:100%}.mdc-evolution-chip__cell--primary{overflow-x:hidden}.mdc-evolution-chip__cell--trailing{flex:1 0 auto}.mdc-evolution-chip__action{align-items:center;background:none;border:none;box-sizing:content-box;cursor:pointer;display:inline-flex;justify-content:center;outline:none;padding:0;text-decoration:none;color:inherit}.mdc-evolution-chip__action--presentational{cursor:auto}.mdc-evolution-chip--disabled,.mdc-evolution-chip__action:disabled{pointer-events:none}.mdc-evolution-chip__action--primary{overflow-x:hidden}.mdc-evolution-chip__action--trailing{position:relative;overflow:visible}.mdc-evolution-chip__action--primary:before{box-sizing:border-box;content:"";height:100%;left:0;position:absolute;pointer-events:none;top:0;width:100%;z-index:1}.mdc-evolution-chip--touch{margin-top:8px;margin-bottom:8px}.mdc-evolution-chip__action-touch{position:absolute;top:50%;height:48px;left:0;right:0;transform:translateY(-50%)}.mdc-evolution-chip__text-label{white-space:nowrap;user-select:none;text-overflow:ellipsis;overflow:hidden}.mdc-evolution-chip__graphic{align-items:center;display:inline-flex;justify-content:center;overflow:hidden;pointer-events:none;position:relative;flex:1 0 auto}.mdc-evolution-chip__checkmark{position:absolute;opacity:0;top:50%;left:50%}.mdc-evolution-chip--selectable:not(.mdc-evolution-chip--selected):not(.mdc-evolution-chip--with-primary-icon) .mdc-evolution-chip__graphic{width:0}.mdc-evolution-chip__checkmark-background{opacity:0}.mdc-evolution-chip__checkmark-svg{display:block}.mdc-evolution-chip__checkmark-path{stroke-width:2px;stroke-dasharray:29.7833385;stroke-dashoffset:29.7833385;stroke:currentColor}.mdc-evolution-chip--selecting .mdc-evolution-chip__graphic{transition:width 150ms 0ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--selecting .mdc-evolution-chip__checkmark{transition:transform 150ms 0ms cubic-bezier(0.4, 0, 0.2, 1);transform:translate(-75%, -50%)}.mdc-evolution-chip--selecting .mdc-evolution-chip__checkmark-path{transition:stroke-dashoffset 150ms 45ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--deselecting .mdc-evolution-chip__graphic{transition:width 100ms 0ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--deselecting .mdc-evolution-chip__checkmark{transition:opacity 50ms 0ms linear,transform 100ms 0ms cubic-bezier(0.4, 0, 0.2, 1);transform:translate(-75%, -50%)}.mdc-evolution-chip--deselecting .mdc-evolution-chip__checkmark-path{stroke-dashoffset:0}.mdc-evolution-chip--selecting-with-primary-icon .mdc-evolution-chip__icon--primary{transition:opacity 75ms 0ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--selecting-with-primary-icon .mdc-evolution-chip__checkmark-path{transition:stroke-dashoffset 150ms 75ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--deselecting-with-primary-icon .mdc-evolution-chip__icon--primary{transition:opacity 150ms 75ms cubic-bezier(0.4, 0, 0.2, 1)}.mdc-evolution-chip--deselecting-with-primary-icon .mdc-evolution-chip__checkmark{transition:opacity 75ms 0ms cubic-bezier(0.4, 0, 0.2, 1);transform:translate(-50%, -50%)}.mdc-evolution-chip--deselecting-with-primary-icon .mdc-evolution-chip__checkmark-path{stroke-dashoffset:0}.mdc-evolution-chip--selected .mdc-evolution-chip__icon--primary{opacity:0}.mdc-evolution-chip--selected .mdc-evolution-chip__checkmark{transform:translate(-50%, -50%);opacity:1}.mdc-evolution-chip--selected .mdc-evolution-chip__checkmark-path{stroke-dashoffset:0}@keyframes mdc-evolution-chip-enter{from{transform:scale(0.8);opacity:.4}to{transform:scale(1);opacity:1}}.mdc-evolution-chip--enter{animation:mdc-evolution-chip-enter 100ms 0ms cubic-bezier(0, 0, 0.2, 1)}@keyframes mdc-evolution-chip-exit{from{opacity:1}to{opacity:0}}.mdc-evolution-chip--exit{animation:mdc-evolution-chip-exit 75ms 0ms cubic-bezier(0.4, 0, 1, 1)}.mdc-evolution-chip--hidden{opacity:0;pointer-events:none;transition:width 150ms 0ms cubic-bezier(0.4, 0, 1, 1)}.mat-mdc-standard-chip{border-radius:var(--mdc-chip-container-shape-radius);height:var(--mdc-chip-container-height);--mdc-chip-container-shape-family:rounded;--mdc-chip-container-shape-radius:16px 16px 16px 16px;--mdc-chip-with-avatar-avatar-shape-family:rounded;--mdc-chip-with-avatar-avatar-shape-radius:14px 14px 14px 14px;--mdc-chip-with-avatar-avatar-size:28px;--mdc-chip-with-icon-icon-size:18px}.mat-mdc-standard-chip .mdc-evolution-chip__ripple{border-radius:var(--mdc-chip-container-shape-radius)}.mat-mdc-standard-chip .mdc-evolution-chip__action--primary:before{border-radius:var(--mdc-chip-container-shape-radius)}.mat-mdc-standard-chip .mdc-evolution-chip__icon--primary{border-radius:var(--mdc-chip-with-avatar-avatar-shape-radius)}.mat-mdc-standard-chip.mdc-evolution-chip--selectable:not(.mdc-evolution-chip--with-primary-icon){--mdc-chip-graphic-selected-width:var(--mdc-chip-with-avatar-avatar-size)}.mat-mdc-standard-chip .mdc-evolution-chip__graphic{height:var(--mdc-chip-with-avatar-avatar-size);width:var(--mdc-chip-with-avatar-avatar-size);font-size:var(--mdc-chip-with-avatar-avatar-size)}.mat-mdc-standard-chip:not(.mdc-evolution-chip--disabled){background-color:var(--mdc-chip-elevated-container-color)}.mat-mdc-standard-chip.mdc-evolution-chip--disabled{background-color:var(--mdc-chip-elevated-disabled-container-color)}.mat-mdc-standard-chip.mdc-evolution-chip--selected.mdc-evolution-chip--disabled{background-color:var(--mdc-chip-elevated-disabled-container-color)}.mat-mdc-standard-chip .mdc-evolution-chip__text-label{font-family:var(--mdc-chip-label-text-font);line-height:var(--mdc-chip-label-text-line-height);font-size:var(--mdc-chip-label-text-size);font-weight:var(--mdc-chip-label-text-weight);letter-spacing:var(--mdc-chip-label-text-tracking)}.mat-mdc-standard-chip:not(.mdc-evolution-chip--disabled) .mdc-evolution-chip__text-label{color:var(--mdc-chip-label-text-color)}.mat-mdc-standard-chip.mdc-evolution-chip--disabled .mdc-evolution-chip__text-label{color:var(--mdc-chip-disabled-label-text-color)}.mat-mdc-standard-chip.mdc-evolution-chip--selected.mdc-evolution-chip--disabled .mdc-evolution-chip__text-label{color:var(--mdc-chip-disabled-label-text-color)}.mat-mdc-standard-chip .mdc-evolution-chip__icon--primary{height:var(--mdc-chip-with-icon-icon-size);width:var(--mdc-chip-with-icon-icon-size);font-size:var(--mdc-chip-with-icon-icon-size)}.mat-mdc-standard-chip:not(.mdc-evolution-chip--disabled) .mdc-evolution-chip__icon--primary{color:var(--mdc-chip-with-icon-icon-color)}.mat-mdc-standard-chip.mdc-evolution-chip--disabled .mdc-evolution-chip__icon--primary{color:var(--mdc-chip-with-icon-disabled-icon-color)}.mat-mdc-standard-chip:not(.mdc-evolution-chip--disabled) .mdc-evolution-chip__checkmark{color:var(--mdc-chip-with-icon-selected-icon-color)}.mat-mdc-standard-chip.mdc-evolution-chip--disabled .mdc-evolution-chip__checkmark{color:var(--mdc-chip-with-icon-disabled-icon-color)}.mat-mdc-standard-chip:not(.mdc-evolution-chip--disabled) .mdc-evolution-chip__icon--trailing{color:var(--mdc-chip-with-trailing-icon-trailing-icon-color)}.mat-mdc-standard-chip.mdc-evolution-chip--disabled .mdc-evolution-chip__icon--trailing{color:var(--mdc-chip-with-trailing-icon-disabled-trailing-icon-color)}.mat-mdc-standard-chip .mdc-evolution-chip__action--primary.mdc-ripple-upgraded--background-focused .mdc-evolution-chip__ripple::before,.mat-mdc-standard-chip .mdc-evolution-chip__action--primary:not(.mdc-ripple-upgraded):focus .mdc-evolution-chip__ripple::before{transition-duration:75ms;opacity:var(--mdc-chip-focus-state-layer-opacity)}.mat-mdc-chip-focus-overlay{background:var(--mdc-chip-focus-state-layer-color);opacity:var(--mdc-chip-focus-state-layer-opacity)}.mat-mdc-standard-chip .mdc-evolution-chip__checkmark{height:20px;width:20px}.mat-mdc-standard-chip .mdc-evolution-chip__icon--trailing{height:18px;width:18px;font-size:18px}.mat-mdc-standard-chip .mdc-evolution-chip__action--primary{padding-left:12px;padding-right:12px}[dir=rtl] .mat-mdc-standard-chip .mdc-evolution-chip__action--primary,.mat-mdc-standard-chip .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:12px;padding-right:12px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic{padding-left:6px;padding-right:6px}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic[dir=rtl]{padding-left:6px;padding-right:6px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary{padding-left:0;padding-right:12px}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:12px;padding-right:0}.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing{padding-left:8px;padding-right:8px}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing,.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing[dir=rtl]{padding-left:8px;padding-right:8px}.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing{left:8px;right:initial}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing,.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing[dir=rtl]{left:initial;right:8px}.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary{padding-left:12px;padding-right:0}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary,.mat-mdc-standard-chip.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:0;padding-right:12px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic{padding-left:6px;padding-right:6px}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic[dir=rtl]{padding-left:6px;padding-right:6px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing{padding-left:8px;padding-right:8px}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing[dir=rtl]{padding-left:8px;padding-right:8px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing{left:8px;right:initial}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing[dir=rtl]{left:initial;right:8px}.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary{padding-left:0;padding-right:0}[dir=rtl] .mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary,.mat-mdc-standard-chip.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:0;padding-right:0}.mat-mdc-standard-chip.mdc-evolution-chip--disabled .mdc-evolution-chip__checkmark{color:var(--mdc-chip-with-icon-selected-icon-color, currentColor)}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic{padding-left:4px;padding-right:8px}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__graphic[dir=rtl]{padding-left:8px;padding-right:4px}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary{padding-left:0;padding-right:12px}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:12px;padding-right:0}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic{padding-left:4px;padding-right:8px}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__graphic[dir=rtl]{padding-left:8px;padding-right:4px}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing{padding-left:8px;padding-right:8px}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--trailing[dir=rtl]{padding-left:8px;padding-right:8px}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing{left:8px;right:initial}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__ripple--trailing[dir=rtl]{left:initial;right:8px}.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary{padding-left:0;padding-right:0}[dir=rtl] .mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary,.mdc-evolution-chip--with-avatar.mdc-evolution-chip--with-primary-graphic.mdc-evolution-chip--with-trailing-action .mdc-evolution-chip__action--primary[dir=rtl]{padding-left:0;padding-right:0}.mat-mdc-standard-chip{-webkit-tap-highlight-color:rgba(0,0,0,0)}.cdk-high-contrast-active .mat-mdc-standard-chip{outline:solid 1px}.cdk-high-contrast-active .mat-mdc-standard-chip .mdc-evolution-chip__checkmark-path{stroke:CanvasText !important}.mat-mdc-standard-chip.mdc-evolution-chip--disabled{opacity:.4}.mat-mdc-standard-chip .mdc-evolution-chip__cell--primary,.mat-mdc-standard-chip .mdc-evolution-chip__action--primary,.mat-mdc-standard-chip .mat-mdc-chip-action-label{overflow:visible}.mat-mdc-standard-chip .mdc-evolution-chip__cell--primary{flex-basis:100%}.mat-mdc-standard-chip .mdc-evolution-chip__action--primary{font:inherit;letter-spacing:inherit;white-space:inherit}.mat-mdc-standard-chip .mat-mdc-chip-graphic,.mat-mdc-standard-chip .mat-mdc-chip-trailing-icon{box-sizing:content-box}.mat-mdc-standard-chip._mat-animation-noopable,.mat-mdc-standard-chip._mat-animation-noopable .mdc-evolution-chip__graphic,.mat-mdc-standard-chip._mat-animation-noopable .mdc-evolution-chip__checkmark,.mat-mdc-standard-chip._mat-animation-noopable .mdc-evolution-chip__checkmark-path{transition-duration:1ms;animation-duration:1ms}.mat-mdc-basic-chip .mdc-evolution-chip__action--primary{font:inherit}.mat-mdc-chip-focus-overlay{top:0;left:0;right:0;bottom:0;position:absolute;pointer-events:none;opacity:0;border-radius:inherit;transition:opacity 150ms linear}._mat-animation-noopable .mat-mdc-chip-focus-overlay{transition:none}.mat-mdc-basic-chip .mat-mdc-chip-focus-overlay{display:none}.mat-mdc-chip:hover .mat-mdc-chip-focus-overlay{opacity:.04}.mat-mdc-chip.cdk-focused .mat-mdc-chip-focus-overlay{opacity:.12}.mat-mdc-chip .mat-ripple.mat-mdc-chip-ripple{top:0;left:0;right:0;bottom:0;position:absolute;pointer-events:none;border-radius:inherit}.mat-mdc-chip-avatar{text-align:center;line-height:1;color:var(--mdc-chip-with-icon-icon-color, currentColor)}.mat-mdc-chip{position:relative;z-index:0}.mat-mdc-chip-action-label{text-align:left;z-index:1}[dir=rtl] .mat-mdc-chip-action-label{text-align:right}.mat-mdc-chip.mdc-evolution-chip--with-trailing-action .mat-mdc-chip-action-label{position:relative}.mat-mdc-chip-action-label .mat-mdc-chip-primary-focus-indicator{position:absolute;top:0;right:0;bottom:0;left:0;pointer-events:none}.mat-mdc-chip-action-label .mat-mdc-focus-indicator::before{margin:calc(calc(var(--mat-mdc-focus-indicator-border-width, 3px) + 2px) * -1)}.mat-mdc-chip-remove{opacity:.54}.mat-mdc-chip-remove:focus{opacity:1}.mat-mdc-chip-remove::before{margin:calc(var(--mat-mdc-focus-indicator-border-width, 3px) * -1);left:8px;right:8px}.mat-mdc-chip-remove .mat-icon{width:inherit;height:inherit;font-size:inherit;box-sizing:content-box}.mat-chip-edit-input{cursor:text;display:inline-block;color:inherit;outline:0}.cdk-high-contrast-active .mat-mdc-chip-selected:not(.mat-mdc-chip-multiple){outline-width:3px}.mat-mdc-chip-action:focus .mat-mdc-focus-indicator::before{content:""}'],encapsulation:2,changeDetection:0})}return t})();class p6{constructor(n){}}const m6=Dd(p6);let __=(()=>{class t extends m6{get chipFocusChanges(){return this._getChipStream(e=>e._onFocus)}get chipDestroyedChanges(){return this._getChipStream(e=>e.destroyed)}get disabled(){return this._disabled}set disabled(e){this._disabled=me(e),this._syncChipsState()}get empty(){return!this._chips||0===this._chips.length}get role(){return this._explicitRole?this._explicitRole:this.empty?null:this._defaultRole}set role(e){this._explicitRole=e}get focused(){return this._hasFocusedChip()}constructor(e,i,r){super(e),this._elementRef=e,this._changeDetectorRef=i,this._dir=r,this._lastDestroyedFocusedChipIndex=null,this._destroyed=new U,this._defaultRole="presentation",this._disabled=!1,this._explicitRole=null,this._chipActions=new Rr}ngAfterViewInit(){this._setUpFocusManagement(),this._trackChipSetChanges(),this._trackDestroyedFocusedChip()}ngOnDestroy(){this._keyManager?.destroy(),this._chipActions.destroy(),this._destroyed.next(),this._destroyed.complete()}_hasFocusedChip(){return this._chips&&this._chips.some(e=>e._hasFocus())}_syncChipsState(){this._chips&&this._chips.forEach(e=>{e.disabled=this._disabled,e._changeDetectorRef.markForCheck()})}focus(){}_handleKeydown(e){this._originatesFromChip(e)&&this._keyManager.onKeydown(e)}_isValidIndex(e){return e>=0&&e<this._chips.length}_allowFocusEscape(){if(-1!==this.tabIndex){const e=this.tabIndex;this.tabIndex=-1,setTimeout(()=>this.tabIndex=e)}}_getChipStream(e){return this._chips.changes.pipe(Dn(null),Xt(()=>An(...this._chips.map(e))))}_originatesFromChip(e){let i=e.target;for(;i&&i!==this._elementRef.nativeElement;){if(i.classList.contains("mat-mdc-chip"))return!0;i=i.parentElement}return!1}_setUpFocusManagement(){this._chips.changes.pipe(Dn(this._chips)).subscribe(e=>{const i=[];e.forEach(r=>r._getActions().forEach(o=>i.push(o))),this._chipActions.reset(i),this._chipActions.notifyOnChanges()}),this._keyManager=new DE(this._chipActions).withVerticalOrientation().withHorizontalOrientation(this._dir?this._dir.value:"ltr").withHomeAndEnd().skipPredicate(e=>this._skipPredicate(e)),this.chipFocusChanges.pipe(Re(this._destroyed)).subscribe(({chip:e})=>{const i=e._getSourceAction(document.activeElement);i&&this._keyManager.updateActiveItem(i)}),this._dir?.change.pipe(Re(this._destroyed)).subscribe(e=>this._keyManager.withHorizontalOrientation(e))}_skipPredicate(e){return!e.isInteractive||e.disabled}_trackChipSetChanges(){this._chips.changes.pipe(Dn(null),Re(this._destroyed)).subscribe(()=>{this.disabled&&Promise.resolve().then(()=>this._syncChipsState()),this._redirectDestroyedChipFocus()})}_trackDestroyedFocusedChip(){this.chipDestroyedChanges.pipe(Re(this._destroyed)).subscribe(e=>{const r=this._chips.toArray().indexOf(e.chip);this._isValidIndex(r)&&e.chip._hasFocus()&&(this._lastDestroyedFocusedChipIndex=r)})}_redirectDestroyedChipFocus(){if(null!=this._lastDestroyedFocusedChipIndex){if(this._chips.length){const e=Math.min(this._lastDestroyedFocusedChipIndex,this._chips.length-1),i=this._chips.toArray()[e];i.disabled?1===this._chips.length?this.focus():this._keyManager.setPreviousItemActive():i.focus()}else this.focus();this._lastDestroyedFocusedChipIndex=null}}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(ct),f(En,8))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-chip-set"]],contentQueries:function(i,r,o){if(1&i&&xe(o,ps,5),2&i){let s;B(s=V())&&(r._chips=s)}},hostAttrs:[1,"mat-mdc-chip-set","mdc-evolution-chip-set"],hostVars:1,hostBindings:function(i,r){1&i&&Y("keydown",function(s){return r._handleKeydown(s)}),2&i&&ue("role",r.role)},inputs:{disabled:"disabled",role:"role"},features:[k],ngContentSelectors:h_,decls:2,vars:0,consts:[["role","presentation",1,"mdc-evolution-chip-set__chips"]],template:function(i,r){1&i&&(at(),w(0,"div",0),Q(1),D())},styles:[".mdc-evolution-chip-set{display:flex}.mdc-evolution-chip-set:focus{outline:none}.mdc-evolution-chip-set__chips{display:flex;flex-flow:wrap;min-width:0}.mdc-evolution-chip-set--overflow .mdc-evolution-chip-set__chips{flex-flow:nowrap}.mdc-evolution-chip-set .mdc-evolution-chip-set__chips{margin-left:-8px;margin-right:0}[dir=rtl] .mdc-evolution-chip-set .mdc-evolution-chip-set__chips,.mdc-evolution-chip-set .mdc-evolution-chip-set__chips[dir=rtl]{margin-left:0;margin-right:-8px}.mdc-evolution-chip-set .mdc-evolution-chip{margin-left:8px;margin-right:0}[dir=rtl] .mdc-evolution-chip-set .mdc-evolution-chip,.mdc-evolution-chip-set .mdc-evolution-chip[dir=rtl]{margin-left:0;margin-right:8px}.mdc-evolution-chip-set .mdc-evolution-chip{margin-top:4px;margin-bottom:4px}.mat-mdc-chip-set .mdc-evolution-chip-set__chips{min-width:100%}.mat-mdc-chip-set-stacked{flex-direction:column;align-items:flex-start}.mat-mdc-chip-set-stacked .mat-mdc-chip{width:100%}.mat-mdc-chip-set-stacked .mdc-evolution-chip__graphic{flex-grow:0}.mat-mdc-chip-set-stacked .mdc-evolution-chip__action--primary{flex-basis:100%;justify-content:start}input.mat-mdc-chip-input{flex:1 0 150px;margin-left:8px}[dir=rtl] input.mat-mdc-chip-input{margin-left:0;margin-right:8px}"],encapsulation:2,changeDetection:0})}return t})();class b6{constructor(n,e){this.source=n,this.value=e}}class v6 extends __{constructor(n,e,i,r,o,s,a){super(n,e,i),this._defaultErrorStateMatcher=r,this._parentForm=o,this._parentFormGroup=s,this.ngControl=a,this.stateChanges=new U}}const y6=LE(v6);let PI=(()=>{class t extends y6{get disabled(){return this.ngControl?!!this.ngControl.disabled:this._disabled}set disabled(e){this._disabled=me(e),this._syncChipsState()}get id(){return this._chipInput.id}get empty(){return(!this._chipInput||this._chipInput.empty)&&(!this._chips||0===this._chips.length)}get placeholder(){return this._chipInput?this._chipInput.placeholder:this._placeholder}set placeholder(e){this._placeholder=e,this.stateChanges.next()}get focused(){return this._chipInput.focused||this._hasFocusedChip()}get required(){return this._required??this.ngControl?.control?.hasValidator(uM.required)??!1}set required(e){this._required=me(e),this.stateChanges.next()}get shouldLabelFloat(){return!this.empty||this.focused}get value(){return this._value}set value(e){this._value=e}get chipBlurChanges(){return this._getChipStream(e=>e._onBlur)}constructor(e,i,r,o,s,a,c){super(e,i,r,a,o,s,c),this.controlType="mat-chip-grid",this._defaultRole="grid",this._ariaDescribedbyIds=[],this._onTouched=()=>{},this._onChange=()=>{},this._value=[],this.change=new q,this.valueChange=new q,this._chips=void 0,this.ngControl&&(this.ngControl.valueAccessor=this)}ngAfterContentInit(){this.chipBlurChanges.pipe(Re(this._destroyed)).subscribe(()=>{this._blur(),this.stateChanges.next()}),An(this.chipFocusChanges,this._chips.changes).pipe(Re(this._destroyed)).subscribe(()=>this.stateChanges.next())}ngAfterViewInit(){super.ngAfterViewInit()}ngDoCheck(){this.ngControl&&this.updateErrorState()}ngOnDestroy(){super.ngOnDestroy(),this.stateChanges.complete()}registerInput(e){this._chipInput=e,this._chipInput.setDescribedByIds(this._ariaDescribedbyIds)}onContainerClick(e){!this.disabled&&!this._originatesFromChip(e)&&this.focus()}focus(){this.disabled||this._chipInput.focused||(!this._chips.length||this._chips.first.disabled?Promise.resolve().then(()=>this._chipInput.focus()):this._chips.length&&this._keyManager.setFirstItemActive(),this.stateChanges.next())}setDescribedByIds(e){this._ariaDescribedbyIds=e,this._chipInput?.setDescribedByIds(e)}writeValue(e){this._value=e}registerOnChange(e){this._onChange=e}registerOnTouched(e){this._onTouched=e}setDisabledState(e){this.disabled=e,this.stateChanges.next()}_blur(){this.disabled||setTimeout(()=>{this.focused||(this._propagateChanges(),this._markAsTouched())})}_allowFocusEscape(){this._chipInput.focused||super._allowFocusEscape()}_handleKeydown(e){9===e.keyCode?this._chipInput.focused&&Lr(e,"shiftKey")&&this._chips.length&&!this._chips.last.disabled?(e.preventDefault(),this._keyManager.activeItem?this._keyManager.setActiveItem(this._keyManager.activeItem):this._focusLastChip()):super._allowFocusEscape():this._chipInput.focused||super._handleKeydown(e),this.stateChanges.next()}_focusLastChip(){this._chips.length&&this._chips.last.focus()}_propagateChanges(){const e=this._chips.length?this._chips.toArray().map(i=>i.value):[];this._value=e,this.change.emit(new b6(this,e)),this.valueChange.emit(e),this._onChange(e),this._changeDetectorRef.markForCheck()}_markAsTouched(){this._onTouched(),this._changeDetectorRef.markForCheck(),this.stateChanges.next()}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(ct),f(En,8),f(us,8),f(ec,8),f(ng),f(ai,10))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-chip-grid"]],contentQueries:function(i,r,o){if(1&i&&xe(o,g_,5),2&i){let s;B(s=V())&&(r._chips=s)}},hostAttrs:[1,"mat-mdc-chip-set","mat-mdc-chip-grid","mdc-evolution-chip-set"],hostVars:10,hostBindings:function(i,r){1&i&&Y("focus",function(){return r.focus()})("blur",function(){return r._blur()}),2&i&&(wn("tabIndex",r._chips&&0===r._chips.length?-1:r.tabIndex),ue("role",r.role)("aria-disabled",r.disabled.toString())("aria-invalid",r.errorState),he("mat-mdc-chip-list-disabled",r.disabled)("mat-mdc-chip-list-invalid",r.errorState)("mat-mdc-chip-list-required",r.required))},inputs:{tabIndex:"tabIndex",disabled:"disabled",placeholder:"placeholder",required:"required",value:"value",errorStateMatcher:"errorStateMatcher"},outputs:{change:"change",valueChange:"valueChange"},features:[G([{provide:d_,useExisting:t}]),k],ngContentSelectors:h_,decls:2,vars:0,consts:[["role","presentation",1,"mdc-evolution-chip-set__chips"]],template:function(i,r){1&i&&(at(),w(0,"div",0),Q(1),D())},styles:[".mdc-evolution-chip-set{display:flex}.mdc-evolution-chip-set:focus{outline:none}.mdc-evolution-chip-set__chips{display:flex;flex-flow:wrap;min-width:0}.mdc-evolution-chip-set--overflow .mdc-evolution-chip-set__chips{flex-flow:nowrap}.mdc-evolution-chip-set .mdc-evolution-chip-set__chips{margin-left:-8px;margin-right:0}[dir=rtl] .mdc-evolution-chip-set .mdc-evolution-chip-set__chips,.mdc-evolution-chip-set .mdc-evolution-chip-set__chips[dir=rtl]{margin-left:0;margin-right:-8px}.mdc-evolution-chip-set .mdc-evolution-chip{margin-left:8px;margin-right:0}[dir=rtl] .mdc-evolution-chip-set .mdc-evolution-chip,.mdc-evolution-chip-set .mdc-evolution-chip[dir=rtl]{margin-left:0;margin-right:8px}.mdc-evolution-chip-set .mdc-evolution-chip{margin-top:4px;margin-bottom:4px}.mat-mdc-chip-set .mdc-evolution-chip-set__chips{min-width:100%}.mat-mdc-chip-set-stacked{flex-direction:column;align-items:flex-start}.mat-mdc-chip-set-stacked .mat-mdc-chip{width:100%}.mat-mdc-chip-set-stacked .mdc-evolution-chip__graphic{flex-grow:0}.mat-mdc-chip-set-stacked .mdc-evolution-chip__action--primary{flex-basis:100%;justify-content:start}input.mat-mdc-chip-input{flex:1 0 150px;margin-left:8px}[dir=rtl] input.mat-mdc-chip-input{margin-left:0;margin-right:8px}"],encapsulation:2,changeDetection:0})}return t})(),w6=0,LI=(()=>{class t{set chipGrid(e){e&&(this._chipGrid=e,this._chipGrid.registerInput(this))}get addOnBlur(){return this._addOnBlur}set addOnBlur(e){this._addOnBlur=me(e)}get disabled(){return this._disabled||this._chipGrid&&this._chipGrid.disabled}set disabled(e){this._disabled=me(e)}get empty(){return!this.inputElement.value}constructor(e,i,r){this._elementRef=e,this.focused=!1,this._addOnBlur=!1,this.chipEnd=new q,this.placeholder="",this.id="mat-mdc-chip-list-input-"+w6++,this._disabled=!1,this.inputElement=this._elementRef.nativeElement,this.separatorKeyCodes=i.separatorKeyCodes,r&&this.inputElement.classList.add("mat-mdc-form-field-input-control")}ngOnChanges(){this._chipGrid.stateChanges.next()}ngOnDestroy(){this.chipEnd.complete()}ngAfterContentInit(){this._focusLastChipOnBackspace=this.empty}_keydown(e){if(e){if(8===e.keyCode&&this._focusLastChipOnBackspace)return this._chipGrid._focusLastChip(),void e.preventDefault();this._focusLastChipOnBackspace=!1}this._emitChipEnd(e)}_keyup(e){!this._focusLastChipOnBackspace&&8===e.keyCode&&this.empty&&(this._focusLastChipOnBackspace=!0,e.preventDefault())}_blur(){this.addOnBlur&&this._emitChipEnd(),this.focused=!1,this._chipGrid.focused||this._chipGrid._blur(),this._chipGrid.stateChanges.next()}_focus(){this.focused=!0,this._focusLastChipOnBackspace=this.empty,this._chipGrid.stateChanges.next()}_emitChipEnd(e){(!e||this._isSeparatorKey(e))&&(this.chipEnd.emit({input:this.inputElement,value:this.inputElement.value,chipInput:this}),e?.preventDefault())}_onInput(){this._chipGrid.stateChanges.next()}focus(){this.inputElement.focus()}clear(){this.inputElement.value="",this._focusLastChipOnBackspace=!0}setDescribedByIds(e){const i=this._elementRef.nativeElement;e.length?i.setAttribute("aria-describedby",e.join(" ")):i.removeAttribute("aria-describedby")}_isSeparatorKey(e){return!Lr(e)&&new Set(this.separatorKeyCodes).has(e.keyCode)}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(yu),f(u_,8))};static#t=this.\u0275dir=x({type:t,selectors:[["input","matChipInputFor",""]],hostAttrs:[1,"mat-mdc-chip-input","mat-mdc-input-element","mdc-text-field__input","mat-input-element"],hostVars:6,hostBindings:function(i,r){1&i&&Y("keydown",function(s){return r._keydown(s)})("keyup",function(s){return r._keyup(s)})("blur",function(){return r._blur()})("focus",function(){return r._focus()})("input",function(){return r._onInput()}),2&i&&(wn("id",r.id),ue("disabled",r.disabled||null)("placeholder",r.placeholder||null)("aria-invalid",r._chipGrid&&r._chipGrid.ngControl?r._chipGrid.ngControl.invalid:null)("aria-required",r._chipGrid&&r._chipGrid.required||null)("required",r._chipGrid&&r._chipGrid.required||null))},inputs:{chipGrid:["matChipInputFor","chipGrid"],addOnBlur:["matChipInputAddOnBlur","addOnBlur"],separatorKeyCodes:["matChipInputSeparatorKeyCodes","separatorKeyCodes"],placeholder:"placeholder",id:"id",disabled:"disabled"},outputs:{chipEnd:"matChipInputTokenEnd"},exportAs:["matChipInput","matChipInputFor"],features:[ut]})}return t})(),x6=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({providers:[ng,{provide:yu,useValue:{separatorKeyCodes:[13]}}],imports:[Ae,oi,Md,Ae]})}return t})();const BI=new E("CdkAccordion");let C6=0,D6=(()=>{class t{get expanded(){return this._expanded}set expanded(e){e=me(e),this._expanded!==e&&(this._expanded=e,this.expandedChange.emit(e),e?(this.opened.emit(),this._expansionDispatcher.notify(this.id,this.accordion?this.accordion.id:this.id)):this.closed.emit(),this._changeDetectorRef.markForCheck())}get disabled(){return this._disabled}set disabled(e){this._disabled=me(e)}constructor(e,i,r){this.accordion=e,this._changeDetectorRef=i,this._expansionDispatcher=r,this._openCloseAllSubscription=Le.EMPTY,this.closed=new q,this.opened=new q,this.destroyed=new q,this.expandedChange=new q,this.id="cdk-accordion-child-"+C6++,this._expanded=!1,this._disabled=!1,this._removeUniqueSelectionListener=()=>{},this._removeUniqueSelectionListener=r.listen((o,s)=>{this.accordion&&!this.accordion.multi&&this.accordion.id===s&&this.id!==o&&(this.expanded=!1)}),this.accordion&&(this._openCloseAllSubscription=this._subscribeToOpenCloseAllActions())}ngOnDestroy(){this.opened.complete(),this.closed.complete(),this.destroyed.emit(),this.destroyed.complete(),this._removeUniqueSelectionListener(),this._openCloseAllSubscription.unsubscribe()}toggle(){this.disabled||(this.expanded=!this.expanded)}close(){this.disabled||(this.expanded=!1)}open(){this.disabled||(this.expanded=!0)}_subscribeToOpenCloseAllActions(){return this.accordion._openCloseAllActions.subscribe(e=>{this.disabled||(this.expanded=e)})}static#e=this.\u0275fac=function(i){return new(i||t)(f(BI,12),f(ct),f(eM))};static#t=this.\u0275dir=x({type:t,selectors:[["cdk-accordion-item"],["","cdkAccordionItem",""]],inputs:{expanded:"expanded",disabled:"disabled"},outputs:{closed:"closed",opened:"opened",destroyed:"destroyed",expandedChange:"expandedChange"},exportAs:["cdkAccordionItem"],features:[G([{provide:BI,useValue:void 0}])]})}return t})(),E6=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({})}return t})();const S6=["body"];function M6(t,n){}const I6=[[["mat-expansion-panel-header"]],"*",[["mat-action-row"]]],T6=["mat-expansion-panel-header","*","mat-action-row"];function A6(t,n){1&t&&K(0,"span",2),2&t&&F("@indicatorRotate",be()._getExpandedState())}const k6=[[["mat-panel-title"]],[["mat-panel-description"]],"*"],R6=["mat-panel-title","mat-panel-description","*"],VI=new E("MAT_ACCORDION"),jI="225ms cubic-bezier(0.4,0.0,0.2,1)",HI={indicatorRotate:ir("indicatorRotate",[ln("collapsed, void",Ke({transform:"rotate(0deg)"})),ln("expanded",Ke({transform:"rotate(180deg)"})),dn("expanded <=> collapsed, void => collapsed",Sn(jI))]),bodyExpansion:ir("bodyExpansion",[ln("collapsed, void",Ke({height:"0px",visibility:"hidden"})),ln("expanded",Ke({height:"*",visibility:""})),dn("expanded <=> collapsed, void => collapsed",Sn(jI))])},zI=new E("MAT_EXPANSION_PANEL");let O6=(()=>{class t{constructor(e,i){this._template=e,this._expansionPanel=i}static#e=this.\u0275fac=function(i){return new(i||t)(f(Ue),f(zI,8))};static#t=this.\u0275dir=x({type:t,selectors:[["ng-template","matExpansionPanelContent",""]]})}return t})(),F6=0;const $I=new E("MAT_EXPANSION_PANEL_DEFAULT_OPTIONS");let UI=(()=>{class t extends D6{get hideToggle(){return this._hideToggle||this.accordion&&this.accordion.hideToggle}set hideToggle(e){this._hideToggle=me(e)}get togglePosition(){return this._togglePosition||this.accordion&&this.accordion.togglePosition}set togglePosition(e){this._togglePosition=e}constructor(e,i,r,o,s,a,c){super(e,i,r),this._viewContainerRef=o,this._animationMode=a,this._hideToggle=!1,this.afterExpand=new q,this.afterCollapse=new q,this._inputChanges=new U,this._headerId="mat-expansion-panel-header-"+F6++,this._bodyAnimationDone=new U,this.accordion=e,this._document=s,this._bodyAnimationDone.pipe(Mc((l,d)=>l.fromState===d.fromState&&l.toState===d.toState)).subscribe(l=>{"void"!==l.fromState&&("expanded"===l.toState?this.afterExpand.emit():"collapsed"===l.toState&&this.afterCollapse.emit())}),c&&(this.hideToggle=c.hideToggle)}_hasSpacing(){return!!this.accordion&&this.expanded&&"default"===this.accordion.displayMode}_getExpandedState(){return this.expanded?"expanded":"collapsed"}toggle(){this.expanded=!this.expanded}close(){this.expanded=!1}open(){this.expanded=!0}ngAfterContentInit(){this._lazyContent&&this._lazyContent._expansionPanel===this&&this.opened.pipe(Dn(null),Ze(()=>this.expanded&&!this._portal),vt(1)).subscribe(()=>{this._portal=new ts(this._lazyContent._template,this._viewContainerRef)})}ngOnChanges(e){this._inputChanges.next(e)}ngOnDestroy(){super.ngOnDestroy(),this._bodyAnimationDone.complete(),this._inputChanges.complete()}_containsFocus(){if(this._body){const e=this._document.activeElement,i=this._body.nativeElement;return e===i||i.contains(e)}return!1}static#e=this.\u0275fac=function(i){return new(i||t)(f(VI,12),f(ct),f(eM),f(Je),f(Z),f(gt,8),f($I,8))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-expansion-panel"]],contentQueries:function(i,r,o){if(1&i&&xe(o,O6,5),2&i){let s;B(s=V())&&(r._lazyContent=s.first)}},viewQuery:function(i,r){if(1&i&&Se(S6,5),2&i){let o;B(o=V())&&(r._body=o.first)}},hostAttrs:[1,"mat-expansion-panel"],hostVars:6,hostBindings:function(i,r){2&i&&he("mat-expanded",r.expanded)("_mat-animation-noopable","NoopAnimations"===r._animationMode)("mat-expansion-panel-spacing",r._hasSpacing())},inputs:{disabled:"disabled",expanded:"expanded",hideToggle:"hideToggle",togglePosition:"togglePosition"},outputs:{opened:"opened",closed:"closed",expandedChange:"expandedChange",afterExpand:"afterExpand",afterCollapse:"afterCollapse"},exportAs:["matExpansionPanel"],features:[G([{provide:VI,useValue:void 0},{provide:zI,useExisting:t}]),k,ut],ngContentSelectors:T6,decls:7,vars:4,consts:[["role","region",1,"mat-expansion-panel-content",3,"id"],["body",""],[1,"mat-expansion-panel-body"],[3,"cdkPortalOutlet"]],template:function(i,r){1&i&&(at(I6),Q(0),w(1,"div",0,1),Y("@bodyExpansion.done",function(s){return r._bodyAnimationDone.next(s)}),w(3,"div",2),Q(4,1),O(5,M6,0,0,"ng-template",3),D(),Q(6,2),D()),2&i&&(M(1),F("@bodyExpansion",r._getExpandedState())("id",r.id),ue("aria-labelledby",r._headerId),M(4),F("cdkPortalOutlet",r._portal))},dependencies:[Ai],styles:['.mat-expansion-panel{--mat-expansion-container-shape:4px;box-sizing:content-box;display:block;margin:0;overflow:hidden;transition:margin 225ms cubic-bezier(0.4, 0, 0.2, 1),box-shadow 280ms cubic-bezier(0.4, 0, 0.2, 1);position:relative;background:var(--mat-expansion-container-background-color);color:var(--mat-expansion-container-text-color);border-radius:var(--mat-expansion-container-shape)}.mat-expansion-panel:not([class*=mat-elevation-z]){box-shadow:0px 3px 1px -2px rgba(0, 0, 0, 0.2), 0px 2px 2px 0px rgba(0, 0, 0, 0.14), 0px 1px 5px 0px rgba(0, 0, 0, 0.12)}.mat-accordion .mat-expansion-panel:not(.mat-expanded),.mat-accordion .mat-expansion-panel:not(.mat-expansion-panel-spacing){border-radius:0}.mat-accordion .mat-expansion-panel:first-of-type{border-top-right-radius:var(--mat-expansion-container-shape);border-top-left-radius:var(--mat-expansion-container-shape)}.mat-accordion .mat-expansion-panel:last-of-type{border-bottom-right-radius:var(--mat-expansion-container-shape);border-bottom-left-radius:var(--mat-expansion-container-shape)}.cdk-high-contrast-active .mat-expansion-panel{outline:solid 1px}.mat-expansion-panel.ng-animate-disabled,.ng-animate-disabled .mat-expansion-panel,.mat-expansion-panel._mat-animation-noopable{transition:none}.mat-expansion-panel-content{display:flex;flex-direction:column;overflow:visible;font-family:var(--mat-expansion-container-text-font);font-size:var(--mat-expansion-container-text-size);font-weight:var(--mat-expansion-container-text-weight);line-height:var(--mat-expansion-container-text-line-height);letter-spacing:var(--mat-expansion-container-text-tracking)}.mat-expansion-panel-content[style*="visibility: hidden"] *{visibility:hidden !important}.mat-expansion-panel-body{padding:0 24px 16px}.mat-expansion-panel-spacing{margin:16px 0}.mat-accordion>.mat-expansion-panel-spacing:first-child,.mat-accordion>*:first-child:not(.mat-expansion-panel) .mat-expansion-panel-spacing{margin-top:0}.mat-accordion>.mat-expansion-panel-spacing:last-child,.mat-accordion>*:last-child:not(.mat-expansion-panel) .mat-expansion-panel-spacing{margin-bottom:0}.mat-action-row{border-top-style:solid;border-top-width:1px;display:flex;flex-direction:row;justify-content:flex-end;padding:16px 8px 16px 24px;border-top-color:var(--mat-expansion-actions-divider-color)}.mat-action-row .mat-button-base,.mat-action-row .mat-mdc-button-base{margin-left:8px}[dir=rtl] .mat-action-row .mat-button-base,[dir=rtl] .mat-action-row .mat-mdc-button-base{margin-left:0;margin-right:8px}'],encapsulation:2,data:{animation:[HI.bodyExpansion]},changeDetection:0})}return t})();class N6{}const P6=Dd(N6);let L6=(()=>{class t extends P6{constructor(e,i,r,o,s,a,c){super(),this.panel=e,this._element=i,this._focusMonitor=r,this._changeDetectorRef=o,this._animationMode=a,this._parentChangeSubscription=Le.EMPTY;const l=e.accordion?e.accordion._stateChanges.pipe(Ze(d=>!(!d.hideToggle&&!d.togglePosition))):Qt;this.tabIndex=parseInt(c||"")||0,this._parentChangeSubscription=An(e.opened,e.closed,l,e._inputChanges.pipe(Ze(d=>!!(d.hideToggle||d.disabled||d.togglePosition)))).subscribe(()=>this._changeDetectorRef.markForCheck()),e.closed.pipe(Ze(()=>e._containsFocus())).subscribe(()=>r.focusVia(i,"program")),s&&(this.expandedHeight=s.expandedHeight,this.collapsedHeight=s.collapsedHeight)}get disabled(){return this.panel.disabled}_toggle(){this.disabled||this.panel.toggle()}_isExpanded(){return this.panel.expanded}_getExpandedState(){return this.panel._getExpandedState()}_getPanelId(){return this.panel.id}_getTogglePosition(){return this.panel.togglePosition}_showToggle(){return!this.panel.hideToggle&&!this.panel.disabled}_getHeaderHeight(){const e=this._isExpanded();return e&&this.expandedHeight?this.expandedHeight:!e&&this.collapsedHeight?this.collapsedHeight:null}_keydown(e){switch(e.keyCode){case 32:case 13:Lr(e)||(e.preventDefault(),this._toggle());break;default:return void(this.panel.accordion&&this.panel.accordion._handleHeaderKeydown(e))}}focus(e,i){e?this._focusMonitor.focusVia(this._element,e,i):this._element.nativeElement.focus(i)}ngAfterViewInit(){this._focusMonitor.monitor(this._element).subscribe(e=>{e&&this.panel.accordion&&this.panel.accordion._handleHeaderFocus(this)})}ngOnDestroy(){this._parentChangeSubscription.unsubscribe(),this._focusMonitor.stopMonitoring(this._element)}static#e=this.\u0275fac=function(i){return new(i||t)(f(UI,1),f($),f(nr),f(ct),f($I,8),f(gt,8),bi("tabindex"))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-expansion-panel-header"]],hostAttrs:["role","button",1,"mat-expansion-panel-header","mat-focus-indicator"],hostVars:15,hostBindings:function(i,r){1&i&&Y("click",function(){return r._toggle()})("keydown",function(s){return r._keydown(s)}),2&i&&(ue("id",r.panel._headerId)("tabindex",r.tabIndex)("aria-controls",r._getPanelId())("aria-expanded",r._isExpanded())("aria-disabled",r.panel.disabled),Pn("height",r._getHeaderHeight()),he("mat-expanded",r._isExpanded())("mat-expansion-toggle-indicator-after","after"===r._getTogglePosition())("mat-expansion-toggle-indicator-before","before"===r._getTogglePosition())("_mat-animation-noopable","NoopAnimations"===r._animationMode))},inputs:{tabIndex:"tabIndex",expandedHeight:"expandedHeight",collapsedHeight:"collapsedHeight"},features:[k],ngContentSelectors:R6,decls:5,vars:3,consts:[[1,"mat-content"],["class","mat-expansion-indicator",4,"ngIf"],[1,"mat-expansion-indicator"]],template:function(i,r){1&i&&(at(k6),w(0,"span",0),Q(1),Q(2,1),Q(3,2),D(),O(4,A6,1,1,"span",1)),2&i&&(he("mat-content-hide-toggle",!r._showToggle()),M(4),F("ngIf",r._showToggle()))},dependencies:[Ii],styles:['.mat-expansion-panel-header{display:flex;flex-direction:row;align-items:center;padding:0 24px;border-radius:inherit;transition:height 225ms cubic-bezier(0.4, 0, 0.2, 1);height:var(--mat-expansion-header-collapsed-state-height);font-family:var(--mat-expansion-header-text-font);font-size:var(--mat-expansion-header-text-size);font-weight:var(--mat-expansion-header-text-weight);line-height:var(--mat-expansion-header-text-line-height);letter-spacing:var(--mat-expansion-header-text-tracking)}.mat-expansion-panel-header.mat-expanded{height:var(--mat-expansion-header-expanded-state-height)}.mat-expansion-panel-header[aria-disabled=true]{color:var(--mat-expansion-header-disabled-state-text-color)}.mat-expansion-panel-header:not([aria-disabled=true]){cursor:pointer}.mat-expansion-panel:not(.mat-expanded) .mat-expansion-panel-header:not([aria-disabled=true]):hover{background:var(--mat-expansion-header-hover-state-layer-color)}@media(hover: none){.mat-expansion-panel:not(.mat-expanded) .mat-expansion-panel-header:not([aria-disabled=true]):hover{background:var(--mat-expansion-container-background-color)}}.mat-expansion-panel .mat-expansion-panel-header:not([aria-disabled=true]).cdk-keyboard-focused,.mat-expansion-panel .mat-expansion-panel-header:not([aria-disabled=true]).cdk-program-focused{background:var(--mat-expansion-header-focus-state-layer-color)}.mat-expansion-panel-header._mat-animation-noopable{transition:none}.mat-expansion-panel-header:focus,.mat-expansion-panel-header:hover{outline:none}.mat-expansion-panel-header.mat-expanded:focus,.mat-expansion-panel-header.mat-expanded:hover{background:inherit}.mat-expansion-panel-header.mat-expansion-toggle-indicator-before{flex-direction:row-reverse}.mat-expansion-panel-header.mat-expansion-toggle-indicator-before .mat-expansion-indicator{margin:0 16px 0 0}[dir=rtl] .mat-expansion-panel-header.mat-expansion-toggle-indicator-before .mat-expansion-indicator{margin:0 0 0 16px}.mat-content{display:flex;flex:1;flex-direction:row;overflow:hidden}.mat-content.mat-content-hide-toggle{margin-right:8px}[dir=rtl] .mat-content.mat-content-hide-toggle{margin-right:0;margin-left:8px}.mat-expansion-toggle-indicator-before .mat-content.mat-content-hide-toggle{margin-left:24px;margin-right:0}[dir=rtl] .mat-expansion-toggle-indicator-before .mat-content.mat-content-hide-toggle{margin-right:24px;margin-left:0}.mat-expansion-panel-header-title{color:var(--mat-expansion-header-text-color)}.mat-expansion-panel-header-title,.mat-expansion-panel-header-description{display:flex;flex-grow:1;flex-basis:0;margin-right:16px;align-items:center}[dir=rtl] .mat-expansion-panel-header-title,[dir=rtl] .mat-expansion-panel-header-description{margin-right:0;margin-left:16px}.mat-expansion-panel-header[aria-disabled=true] .mat-expansion-panel-header-title,.mat-expansion-panel-header[aria-disabled=true] .mat-expansion-panel-header-description{color:inherit}.mat-expansion-panel-header-description{flex-grow:2;color:var(--mat-expansion-header-description-color)}.mat-expansion-indicator::after{border-style:solid;border-width:0 2px 2px 0;content:"";display:inline-block;padding:3px;transform:rotate(45deg);vertical-align:middle;color:var(--mat-expansion-header-indicator-color)}.cdk-high-contrast-active .mat-expansion-panel-content{border-top:1px solid;border-top-left-radius:0;border-top-right-radius:0}'],encapsulation:2,data:{animation:[HI.indicatorRotate]},changeDetection:0})}return t})(),B6=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["mat-panel-description"]],hostAttrs:[1,"mat-expansion-panel-header-description"]})}return t})(),V6=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["mat-panel-title"]],hostAttrs:[1,"mat-expansion-panel-header-title"]})}return t})(),j6=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[oi,Ae,E6,Vr]})}return t})();function H6(t,n){1&t&&(w(0,"th",21),N(1," Name "),D())}function z6(t,n){if(1&t){const e=Zi();w(0,"td",22)(1,"button",2),Y("click",function(){const o=Fn(e).$implicit;return Nn(be().deleteListener(o[0]))}),N(2,"Delete"),D(),N(3),D()}if(2&t){const e=n.$implicit;M(3),bt(" \xa0 \xa0 \xa0 ",e[0]," ")}}function $6(t,n){1&t&&(w(0,"th",21),N(1," Bind Address "),D())}function U6(t,n){if(1&t&&(w(0,"td",22),N(1),D()),2&t){const e=n.$implicit;M(1),bt(" ",e[1].bind," ")}}function G6(t,n){1&t&&(w(0,"th",21),N(1," Target Addresses "),D())}function W6(t,n){if(1&t){const e=Zi();w(0,"div",28)(1,"mat-chip-row",29),Y("removed",function(){const o=Fn(e).$implicit,s=be().$implicit;return Nn(be().removeTarget(s[0],o))})("edited",function(r){const s=Fn(e).$implicit,a=be().$implicit;return Nn(be().editTarget(a[0],s,r))}),w(2,"div",30),N(3),D(),w(4,"button",31)(5,"mat-icon"),N(6,"cancel"),D()()()()}if(2&t){const e=n.$implicit;M(1),F("editable",!0)("aria-description","press enter to edit "+e),M(2),Vn(e),M(1),ue("aria-label","remove "+e)}}function q6(t,n){if(1&t){const e=Zi();w(0,"td",22)(1,"mat-form-field",23)(2,"mat-chip-grid",24,25),O(4,W6,7,4,"div",26),w(5,"input",27),Y("matChipInputTokenEnd",function(r){const s=Fn(e).$implicit;return Nn(be().addTargetEvent(s[0],r))}),D()()()()}if(2&t){const e=n.$implicit,i=Ci(3),r=be();M(4),F("ngForOf",e[1].targets),M(1),F("matChipInputFor",i)("matChipInputSeparatorKeyCodes",r.separatorKeysCodes)("matChipInputAddOnBlur",r.targetAddOnBlur)}}function Y6(t,n){1&t&&K(0,"tr",32)}function Z6(t,n){1&t&&K(0,"tr",33)}function K6(t,n){1&t&&(w(0,"th",21),N(1," From (host:port format)"),D())}function Q6(t,n){if(1&t&&(w(0,"td",22),N(1),D()),2&t){const e=n.$implicit;M(1),bt(" ",e[0]," ")}}function X6(t,n){1&t&&(w(0,"th",21),N(1," To (host:port format) "),D())}function J6(t,n){if(1&t&&(w(0,"td",22),N(1),D()),2&t){const e=n.$implicit;M(1),bt(" ",e[1]," ")}}function eG(t,n){1&t&&(w(0,"th",21),N(1," Action "),D())}function tG(t,n){if(1&t){const e=Zi();w(0,"td",22)(1,"button",2),Y("click",function(){const o=Fn(e).$implicit;return Nn(be().replaceDns(o[0],""))}),N(2,"Delete"),D()()}}function nG(t,n){1&t&&K(0,"tr",32)}function iG(t,n){1&t&&K(0,"tr",33)}let rG=(()=>{class t{ngOnInit(){console.log("Configuration Component Init is called"),this.fetchData()}fetchData(){bg({dns:ar(()=>this.pfService.getDNS()),listeners:ar(()=>this.pfService.getListeners())}).subscribe(e=>{console.log("Fork Join completed"),e.dns?this.setDNS(e.dns):za(this._snackBar,"Error fetching data","Dismiss"),e.listeners?this.setListener(e.listeners):za(this._snackBar,"Error fetching data","Dismiss")})}addDNS(){console.log(`Add dns ${this.newFrom} => ${this.newTo}`),this.newFrom=this.newFrom.trim(),this.newTo=this.newTo.trim(),""!=this.newFrom.trim()&&""!=this.newTo.trim()&&this.replaceDns(this.newFrom,this.newTo),this.newFrom="",this.newTo=""}saveData(){this.dialog.open(Ga,{data:{message:"This will save the configuration to the server (write to file) but does not restart server.",buttonText:{ok:"Save without restart",cancel:"Don't save"}}}).afterClosed().subscribe(i=>{i&&(console.log("Restarting..."),this.performSaveData())})}performSaveData(){console.log("I am saving data");let e={},i={};this.dns.forEach(r=>{let o=r[0],s=r[1];console.log(`Adding dns ${o} - > ${s}`),e[o]=s}),this.listenersList.forEach(r=>{let o=r[0],s=r[1];console.log(`Adding listener ${o} -> ${s}`),i[o]=s}),console.log(`final dns ${JSON.stringify(e)} final listener ${JSON.stringify(i)}`),console.log("Saving DNS data..."),ar(()=>this.pfService.saveDNS(e)).subscribe(r=>{console.log("DNS done. Saving listener data..."),ar(()=>this.pfService.saveListeners(i)).subscribe(o=>{console.log("Both done. Fetching new data..."),Hr(this._snackBar,"Configuration saved successfully","dismiss"),this.fetchData()})})}start(){this.dialog.open(Ga,{data:{message:"If server is not started, it would be started now. No effect if it is already running",buttonText:{ok:"Start",cancel:"Don't start"}}}).afterClosed().subscribe(i=>{i&&(console.log("Starting..."),ar(()=>this.pfService.start()).subscribe(r=>{if(console.log(`Raw start result is ${JSON.stringify(r)}`),null!=r.success&&null!=r.changed)return void Hr(this._snackBar,"Server is already running. You need to stop it first.","Dismiss");const s=r;var a=0,c=0;for(const l in s)null==s[l].Ok?a++:c++;0==a?Hr(this._snackBar,`Server started, ${c} listeners OK`,"Dismiss"):za(this._snackBar,`Server started. ${c} listeners OK, ${a} listeners failed`,"Dismiss"),console.log(`Start result is ${JSON.stringify(s)}`)}))})}stop(){this.dialog.open(Ga,{data:{message:"This will stop all listeners. All connections will be force closed immediately.",buttonText:{ok:"Stop Anyway",cancel:"Don't stop"}}}).afterClosed().subscribe(i=>{i&&(console.log("Stopping..."),ar(()=>this.pfService.stop()).subscribe(r=>{r.success?Hr(this._snackBar,r.changed?"Server stopped":"Server is already stopped","Dismiss"):za(this._snackBar,`Can't stop server: ${r.message}`,"Dismiss"),console.log(`Stop result is ${r}`)}))})}restart(){this.dialog.open(Ga,{data:{message:'This will restart server accroding to the last "Saved" configuration. Are you sure?\nAll connections will be interrupted.',buttonText:{ok:"Restart Anyway",cancel:"Don't restart"}}}).afterClosed().subscribe(i=>{i&&(console.log("Restarting..."),ar(()=>this.pfService.restart()).subscribe(r=>{var o=0,s=0;for(const a in r)null==r[a].Ok?o++:s++;0==o?Hr(this._snackBar,`Server restarted, ${s} listeners OK`,"Dismiss"):za(this._snackBar,`Server restarted. ${s} listeners OK, ${o} listeners failed`,"Dismiss"),console.log(`Restart result is ${JSON.stringify(r)}`)}))})}restore(){this.dialog.open(Ga,{data:{message:"This will restore the server's config to last applied config and discard any unapplied config. Are you sure?",buttonText:{ok:"Restore anyway",cancel:"Don't restore"}}}).afterClosed().subscribe(i=>{i&&(console.log("Restoring..."),ar(()=>this.pfService.restore()).subscribe(r=>{console.log(`Restore result ${JSON.stringify(r)}`),Hr(this._snackBar,"Configuration restored successfully","dismiss"),this.fetchData()}))})}deleteListener(e){var i=this.listenersList.filter(r=>r[0]!=e);this.listenersList=i}addListener(){if(this.newListenerName=this.newListenerName.trim(),this.newListenerBind=this.newListenerBind.trim(),""!=this.newListenerBind&&""!=this.newListenerName)if(console.log(`Add listener ${this.newListenerName} => ${this.newListenerBind}`),0==this.listenersList.filter(r=>r[0]==this.newListenerName).length){this.listenersList.push([this.newListenerName,{bind:this.newListenerBind,targets:[]}]);var i=this.listenersList.filter(r=>!0);i.sort((r,o)=>r[0].localeCompare(o[0])),this.listenersList=i,console.log(`${this.listenersList}`),this.newListenerBind="",this.newListenerName=""}else console.log("Name already exists")}replaceDns(e,i){e=e.trim(),i=i.trim();var r=this.dns.filter(o=>o[0]!=e);""!=i&&r.push([e,i]),r.sort((o,s)=>o[0].localeCompare(s[0])),this.dns=r}setListener(e){console.log(`Setting listener ${e} ${JSON.stringify(e)}`);var i=[];for(const r in e)i.push([r,e[r]]);i.sort((r,o)=>r[0].localeCompare(o[0])),this.listenersList=i}setDNS(e){console.log(`Setting DNS ${e} ${JSON.stringify(e)}`);var i=[];for(const r in e)i.push([r,e[r]]);i.sort((r,o)=>r[0].localeCompare(o[0])),this.dns=i}editTarget(e,i,r){const o=r.value.trim();console.log(`edit ${e} -> ${i} -> ${o}`),o?this.replaceTarget(e,i,o):this.removeTarget(e,i)}addTargetEvent(e,i){const r=i.value.trim();""!=r&&this.addTarget(e,r),i.chipInput.clear()}removeTarget(e,i){this.replaceTarget(e,i,"")}addTarget(e,i){const r=this.targetIndex(e);if(-1!=r){const s=this.listenersList[r][1].targets;s.includes(i)||s.push(i)}}replaceTarget(e,i,r){const o=this.targetIndex(e);if(-1!=o){const s=this.listenersList[o],a=s[1].targets;s[1].targets=""==r?a.filter(c=>c!=i):a.map(c=>c==i?r:c)}}targetIndex(e){var i=-1;return this.listenersList.forEach((r,o)=>{r[0]==e&&(i=o)}),i}constructor(e,i,r){this.pfService=e,this._snackBar=i,this.dialog=r,this.newFrom="",this.newTo="",this.newListenerName="",this.newListenerBind="",this.separatorKeysCodes=[13,188],this.targetAddOnBlur=!0,this.displayedColumns=["name","bind","targets"],this.dnsDisplayedColumns=["from","to","action"],this.dns=[],this.listenersList=[]}static#e=this.\u0275fac=function(i){return new(i||t)(f(MS),f(Sg),f(Ua))};static#t=this.\u0275cmp=oe({type:t,selectors:[["app-config"]],decls:110,vars:10,consts:[["mat-raised-button","","color","primary",3,"click"],["mat-raised-button","","color","secondary",3,"click"],["mat-raised-button","","color","warn",3,"click"],["mat-raised-button","","color","accent",3,"click"],["hideToggle",""],["mat-table","",1,"mat-elevation-z8",3,"dataSource"],["matColumnDef","name"],["mat-header-cell","",4,"matHeaderCellDef"],["mat-cell","",4,"matCellDef"],["matColumnDef","bind"],["matColumnDef","targets"],["mat-header-row","",4,"matHeaderRowDef"],["mat-row","",4,"matRowDef","matRowDefColumns"],[1,"example-form"],[1,"example-full-width"],["matInput","","placeholder","SSH",3,"ngModel","ngModelChange"],["matInput","","placeholder","127.0.0.1:1443",3,"ngModel","ngModelChange"],["matColumnDef","from"],["matColumnDef","to"],["matColumnDef","action"],["matInput","","placeholder","www.google.com:443",3,"ngModel","ngModelChange"],["mat-header-cell",""],["mat-cell",""],[1,"example-chip-list",2,"width","100%","background-color","#ffffff"],["aria-label","Enter targets"],["chipGrid",""],["style","flex-basis: 100%",4,"ngFor","ngForOf"],["placeholder","New target...",3,"matChipInputFor","matChipInputSeparatorKeyCodes","matChipInputAddOnBlur","matChipInputTokenEnd"],[2,"flex-basis","100%"],["color","primary","highlighted","",3,"editable","aria-description","removed","edited"],[2,"font-size","20px","font-weight","bold"],["matChipRemove",""],["mat-header-row",""],["mat-row",""]],template:function(i,r){1&i&&(K(0,"p")(1,"p")(2,"p"),w(3,"button",0),Y("click",function(){return r.saveData()}),N(4,"Save"),D(),N(5,"\n\xa0\n\xa0\n"),w(6,"button",1),Y("click",function(){return r.fetchData()}),N(7,"Reload"),D(),N(8,"\n\xa0\n\xa0\n"),w(9,"button",2),Y("click",function(){return r.restart()}),N(10,"Restart"),D(),N(11,"\n\xa0\n\xa0\n"),w(12,"button",2),Y("click",function(){return r.start()}),N(13,"Start"),D(),N(14,"\n\xa0\n\xa0\n"),w(15,"button",2),Y("click",function(){return r.stop()}),N(16,"Stop"),D(),N(17,"\n\xa0\n\xa0\n"),w(18,"button",3),Y("click",function(){return r.restore()}),N(19,"Restore configuration"),D(),K(20,"p")(21,"p")(22,"p"),w(23,"mat-expansion-panel",4)(24,"mat-expansion-panel-header")(25,"mat-panel-title"),N(26," Listeners "),D(),w(27,"mat-panel-description"),N(28," The listeners configuration (click to expand) "),D()(),w(29,"div"),K(30,"p")(31,"p")(32,"p"),w(33,"table",5),ot(34,6),O(35,H6,2,0,"th",7),O(36,z6,4,1,"td",8),st(),ot(37,9),O(38,$6,2,0,"th",7),O(39,U6,2,1,"td",8),st(),ot(40,10),O(41,G6,2,0,"th",7),O(42,q6,6,4,"td",8),st(),O(43,Y6,1,0,"tr",11),O(44,Z6,1,0,"tr",12),D(),K(45,"p")(46,"p")(47,"p")(48,"p"),w(49,"div")(50,"mat-label"),N(51,"Add a new Listener"),D(),w(52,"form",13)(53,"mat-form-field",14)(54,"mat-label"),N(55,"Listener Name"),D(),w(56,"input",15),Y("ngModelChange",function(s){return r.newListenerName=s}),D()(),N(57," \xa0 \xa0 \xa0 \xa0 "),w(58,"mat-form-field",14)(59,"mat-label"),N(60,"Bind address"),D(),w(61,"input",16),Y("ngModelChange",function(s){return r.newListenerBind=s}),D()(),N(62," \xa0 \xa0 \xa0 \xa0 "),w(63,"button",0),Y("click",function(){return r.addListener()}),N(64,"Add"),D()()()()(),K(65,"p")(66,"p")(67,"p"),w(68,"mat-expansion-panel",4)(69,"mat-expansion-panel-header")(70,"mat-panel-title"),N(71," DNS overrides "),D(),w(72,"mat-panel-description"),N(73," DNS overrides (click to expand) "),D()(),w(74,"div"),K(75,"p")(76,"p")(77,"p"),w(78,"table",5),ot(79,17),O(80,K6,2,0,"th",7),O(81,Q6,2,1,"td",8),st(),ot(82,18),O(83,X6,2,0,"th",7),O(84,J6,2,1,"td",8),st(),ot(85,19),O(86,eG,2,0,"th",7),O(87,tG,3,0,"td",8),st(),O(88,nG,1,0,"tr",11),O(89,iG,1,0,"tr",12),D(),K(90,"p")(91,"p")(92,"p")(93,"p"),w(94,"div")(95,"mat-label"),N(96,"Add or replace DNS Binding"),D(),w(97,"form",13)(98,"mat-form-field",14)(99,"mat-label"),N(100,"From"),D(),w(101,"input",20),Y("ngModelChange",function(s){return r.newFrom=s}),D()(),N(102," \xa0 \xa0 \xa0 \xa0 "),w(103,"mat-form-field",14)(104,"mat-label"),N(105,"Resolve to"),D(),w(106,"input",16),Y("ngModelChange",function(s){return r.newTo=s}),D()(),N(107," \xa0 \xa0 \xa0 \xa0 "),w(108,"button",0),Y("click",function(){return r.addDNS()}),N(109,"Add or Replace"),D()()()()()),2&i&&(M(33),F("dataSource",r.listenersList),M(10),F("matHeaderRowDef",r.displayedColumns),M(1),F("matRowDefColumns",r.displayedColumns),M(12),F("ngModel",r.newListenerName),M(5),F("ngModel",r.newListenerBind),M(17),F("dataSource",r.dns),M(10),F("matHeaderRowDef",r.dnsDisplayedColumns),M(1),F("matRowDefColumns",r.dnsDisplayedColumns),M(12),F("ngModel",r.newFrom),M(5),F("ngModel",r.newTo))},dependencies:[jg,eu,Hg,tu,Jd,zg,nu,iu,$g,Ug,F8,T8,pu,q8,PI,LI,OI,g_,ym,UI,L6,V6,B6,Dg,ZM,ru,FM,NM,i_,us],styles:["table[_ngcontent-%COMP%]{width:100%}.mat-column-name[_ngcontent-%COMP%]{word-wrap:break-word!important;white-space:unset!important;flex:0 0 10%!important;width:10%!important;overflow-wrap:break-word;word-wrap:break-word;word-break:break-word;-webkit-hyphens:auto;hyphens:auto}.mat-column-bind[_ngcontent-%COMP%]{word-wrap:break-word!important;white-space:unset!important;flex:0 0 15%!important;width:15%!important;overflow-wrap:break-word;word-wrap:break-word;word-break:break-word;-webkit-hyphens:auto;hyphens:auto}mat-chip-row[_ngcontent-%COMP%]{padding:4px 9px!important;font-size:14px;border:2px solid black;flex-basis:100%}.mat-column-targets[_ngcontent-%COMP%]{word-wrap:break-word!important;white-space:unset!important;flex:0 0 75%!important;width:75%!important;overflow-wrap:break-word;word-wrap:break-word;word-break:break-word;-webkit-hyphens:auto;hyphens:auto}"]})}return t})();const oG=["determinateSpinner"];function sG(t,n){if(1&t&&($c(),w(0,"svg",11),K(1,"circle",12),D()),2&t){const e=be();ue("viewBox",e._viewBox()),M(1),Pn("stroke-dasharray",e._strokeCircumference(),"px")("stroke-dashoffset",e._strokeCircumference()/2,"px")("stroke-width",e._circleStrokeWidth(),"%"),ue("r",e._circleRadius())}}const aG=es(class{constructor(t){this._elementRef=t}},"primary"),cG=new E("mat-progress-spinner-default-options",{providedIn:"root",factory:function lG(){return{diameter:GI}}}),GI=100;let uG=(()=>{class t extends aG{constructor(e,i,r){super(e),this.mode="mat-spinner"===this._elementRef.nativeElement.nodeName.toLowerCase()?"indeterminate":"determinate",this._value=0,this._diameter=GI,this._noopAnimations="NoopAnimations"===i&&!!r&&!r._forceAnimations,r&&(r.color&&(this.color=this.defaultColor=r.color),r.diameter&&(this.diameter=r.diameter),r.strokeWidth&&(this.strokeWidth=r.strokeWidth))}get value(){return"determinate"===this.mode?this._value:0}set value(e){this._value=Math.max(0,Math.min(100,si(e)))}get diameter(){return this._diameter}set diameter(e){this._diameter=si(e)}get strokeWidth(){return this._strokeWidth??this.diameter/10}set strokeWidth(e){this._strokeWidth=si(e)}_circleRadius(){return(this.diameter-10)/2}_viewBox(){const e=2*this._circleRadius()+this.strokeWidth;return`0 0 ${e} ${e}`}_strokeCircumference(){return 2*Math.PI*this._circleRadius()}_strokeDashOffset(){return"determinate"===this.mode?this._strokeCircumference()*(100-this._value)/100:null}_circleStrokeWidth(){return this.strokeWidth/this.diameter*100}static#e=this.\u0275fac=function(i){return new(i||t)(f($),f(gt,8),f(cG))};static#t=this.\u0275cmp=oe({type:t,selectors:[["mat-progress-spinner"],["mat-spinner"]],viewQuery:function(i,r){if(1&i&&Se(oG,5),2&i){let o;B(o=V())&&(r._determinateCircle=o.first)}},hostAttrs:["role","progressbar","tabindex","-1",1,"mat-mdc-progress-spinner","mdc-circular-progress"],hostVars:16,hostBindings:function(i,r){2&i&&(ue("aria-valuemin",0)("aria-valuemax",100)("aria-valuenow","determinate"===r.mode?r.value:null)("mode",r.mode),Pn("width",r.diameter,"px")("height",r.diameter,"px")("--mdc-circular-progress-size",r.diameter+"px")("--mdc-circular-progress-active-indicator-width",r.diameter+"px"),he("_mat-animation-noopable",r._noopAnimations)("mdc-circular-progress--indeterminate","indeterminate"===r.mode))},inputs:{color:"color",mode:"mode",value:"value",diameter:"diameter",strokeWidth:"strokeWidth"},exportAs:["matProgressSpinner"],features:[k],decls:14,vars:11,consts:[["circle",""],["aria-hidden","true",1,"mdc-circular-progress__determinate-container"],["determinateSpinner",""],["xmlns","http://www.w3.org/2000/svg","focusable","false",1,"mdc-circular-progress__determinate-circle-graphic"],["cx","50%","cy","50%",1,"mdc-circular-progress__determinate-circle"],["aria-hidden","true",1,"mdc-circular-progress__indeterminate-container"],[1,"mdc-circular-progress__spinner-layer"],[1,"mdc-circular-progress__circle-clipper","mdc-circular-progress__circle-left"],[3,"ngTemplateOutlet"],[1,"mdc-circular-progress__gap-patch"],[1,"mdc-circular-progress__circle-clipper","mdc-circular-progress__circle-right"],["xmlns","http://www.w3.org/2000/svg","focusable","false",1,"mdc-circular-progress__indeterminate-circle-graphic"],["cx","50%","cy","50%"]],template:function(i,r){if(1&i&&(O(0,sG,2,8,"ng-template",null,0,Ca),w(2,"div",1,2),$c(),w(4,"svg",3),K(5,"circle",4),D()(),function Gv(){!function Q1(){ee.lFrame.currentNamespace=null}()}(),w(6,"div",5)(7,"div",6)(8,"div",7),an(9,8),D(),w(10,"div",9),an(11,8),D(),w(12,"div",10),an(13,8),D()()()),2&i){const o=Ci(1);M(4),ue("viewBox",r._viewBox()),M(1),Pn("stroke-dasharray",r._strokeCircumference(),"px")("stroke-dashoffset",r._strokeDashOffset(),"px")("stroke-width",r._circleStrokeWidth(),"%"),ue("r",r._circleRadius()),M(4),F("ngTemplateOutlet",o),M(2),F("ngTemplateOutlet",o),M(2),F("ngTemplateOutlet",o)}},dependencies:[Cm],styles:["@keyframes mdc-circular-progress-container-rotate{to{transform:rotate(360deg)}}@keyframes mdc-circular-progress-spinner-layer-rotate{12.5%{transform:rotate(135deg)}25%{transform:rotate(270deg)}37.5%{transform:rotate(405deg)}50%{transform:rotate(540deg)}62.5%{transform:rotate(675deg)}75%{transform:rotate(810deg)}87.5%{transform:rotate(945deg)}100%{transform:rotate(1080deg)}}@keyframes mdc-circular-progress-color-1-fade-in-out{from{opacity:.99}25%{opacity:.99}26%{opacity:0}89%{opacity:0}90%{opacity:.99}to{opacity:.99}}@keyframes mdc-circular-progress-color-2-fade-in-out{from{opacity:0}15%{opacity:0}25%{opacity:.99}50%{opacity:.99}51%{opacity:0}to{opacity:0}}@keyframes mdc-circular-progress-color-3-fade-in-out{from{opacity:0}40%{opacity:0}50%{opacity:.99}75%{opacity:.99}76%{opacity:0}to{opacity:0}}@keyframes mdc-circular-progress-color-4-fade-in-out{from{opacity:0}65%{opacity:0}75%{opacity:.99}90%{opacity:.99}to{opacity:0}}@keyframes mdc-circular-progress-left-spin{from{transform:rotate(265deg)}50%{transform:rotate(130deg)}to{transform:rotate(265deg)}}@keyframes mdc-circular-progress-right-spin{from{transform:rotate(-265deg)}50%{transform:rotate(-130deg)}to{transform:rotate(-265deg)}}.mdc-circular-progress{display:inline-flex;position:relative;direction:ltr;line-height:0;transition:opacity 250ms 0ms cubic-bezier(0.4, 0, 0.6, 1)}.mdc-circular-progress__determinate-container,.mdc-circular-progress__indeterminate-circle-graphic,.mdc-circular-progress__indeterminate-container,.mdc-circular-progress__spinner-layer{position:absolute;width:100%;height:100%}.mdc-circular-progress__determinate-container{transform:rotate(-90deg)}.mdc-circular-progress__indeterminate-container{font-size:0;letter-spacing:0;white-space:nowrap;opacity:0}.mdc-circular-progress__determinate-circle-graphic,.mdc-circular-progress__indeterminate-circle-graphic{fill:rgba(0,0,0,0)}.mdc-circular-progress__determinate-circle{transition:stroke-dashoffset 500ms 0ms cubic-bezier(0, 0, 0.2, 1)}.mdc-circular-progress__gap-patch{position:absolute;top:0;left:47.5%;box-sizing:border-box;width:5%;height:100%;overflow:hidden}.mdc-circular-progress__gap-patch .mdc-circular-progress__indeterminate-circle-graphic{left:-900%;width:2000%;transform:rotate(180deg)}.mdc-circular-progress__circle-clipper{display:inline-flex;position:relative;width:50%;height:100%;overflow:hidden}.mdc-circular-progress__circle-clipper .mdc-circular-progress__indeterminate-circle-graphic{width:200%}.mdc-circular-progress__circle-right .mdc-circular-progress__indeterminate-circle-graphic{left:-100%}.mdc-circular-progress--indeterminate .mdc-circular-progress__determinate-container{opacity:0}.mdc-circular-progress--indeterminate .mdc-circular-progress__indeterminate-container{opacity:1}.mdc-circular-progress--indeterminate .mdc-circular-progress__indeterminate-container{animation:mdc-circular-progress-container-rotate 1568.2352941176ms linear infinite}.mdc-circular-progress--indeterminate .mdc-circular-progress__spinner-layer{animation:mdc-circular-progress-spinner-layer-rotate 5332ms cubic-bezier(0.4, 0, 0.2, 1) infinite both}.mdc-circular-progress--indeterminate .mdc-circular-progress__color-1{animation:mdc-circular-progress-spinner-layer-rotate 5332ms cubic-bezier(0.4, 0, 0.2, 1) infinite both,mdc-circular-progress-color-1-fade-in-out 5332ms cubic-bezier(0.4, 0, 0.2, 1) infinite both}.mdc-circular-progress--indeterminate .mdc-circular-progress__color-2{animation:mdc-circular-progress-spinner-layer-rotate 5332ms cubic-bezier(0.4, 0, 0.2, 1) infinite both,mdc-circular-progress-color-2-fade-in-out 5332ms cubic-bezier(0.4, 0, 0.2, 1) infinite both}.mdc-circular-progress--indeterminate .mdc-circular-progress__color-3{animation:mdc-circular-progress-spinner-layer-rotate 5332ms cubic-bezier(0.4, 0, 0.2, 1) infinite both,mdc-circular-progress-color-3-fade-in-out 5332ms cubic-bezier(0.4, 0, 0.2, 1) infinite both}.mdc-circular-progress--indeterminate .mdc-circular-progress__color-4{animation:mdc-circular-progress-spinner-layer-rotate 5332ms cubic-bezier(0.4, 0, 0.2, 1) infinite both,mdc-circular-progress-color-4-fade-in-out 5332ms cubic-bezier(0.4, 0, 0.2, 1) infinite both}.mdc-circular-progress--indeterminate .mdc-circular-progress__circle-left .mdc-circular-progress__indeterminate-circle-graphic{animation:mdc-circular-progress-left-spin 1333ms cubic-bezier(0.4, 0, 0.2, 1) infinite both}.mdc-circular-progress--indeterminate .mdc-circular-progress__circle-right .mdc-circular-progress__indeterminate-circle-graphic{animation:mdc-circular-progress-right-spin 1333ms cubic-bezier(0.4, 0, 0.2, 1) infinite both}.mdc-circular-progress--closed{opacity:0}.mat-mdc-progress-spinner{--mdc-circular-progress-active-indicator-width:4px;--mdc-circular-progress-size:48px}.mat-mdc-progress-spinner .mdc-circular-progress__determinate-circle,.mat-mdc-progress-spinner .mdc-circular-progress__indeterminate-circle-graphic{stroke:var(--mdc-circular-progress-active-indicator-color)}@media screen and (forced-colors: active),(-ms-high-contrast: active){.mat-mdc-progress-spinner .mdc-circular-progress__determinate-circle,.mat-mdc-progress-spinner .mdc-circular-progress__indeterminate-circle-graphic{stroke:CanvasText}}.mat-mdc-progress-spinner circle{stroke-width:var(--mdc-circular-progress-active-indicator-width)}@media screen and (forced-colors: active),(-ms-high-contrast: active){.mat-mdc-progress-spinner .mdc-circular-progress--four-color .mdc-circular-progress__color-1 .mdc-circular-progress__indeterminate-circle-graphic{stroke:CanvasText}}@media screen and (forced-colors: active),(-ms-high-contrast: active){.mat-mdc-progress-spinner .mdc-circular-progress--four-color .mdc-circular-progress__color-2 .mdc-circular-progress__indeterminate-circle-graphic{stroke:CanvasText}}@media screen and (forced-colors: active),(-ms-high-contrast: active){.mat-mdc-progress-spinner .mdc-circular-progress--four-color .mdc-circular-progress__color-3 .mdc-circular-progress__indeterminate-circle-graphic{stroke:CanvasText}}@media screen and (forced-colors: active),(-ms-high-contrast: active){.mat-mdc-progress-spinner .mdc-circular-progress--four-color .mdc-circular-progress__color-4 .mdc-circular-progress__indeterminate-circle-graphic{stroke:CanvasText}}.mat-mdc-progress-spinner .mdc-circular-progress{width:var(--mdc-circular-progress-size) !important;height:var(--mdc-circular-progress-size) !important}.mat-mdc-progress-spinner{display:block;overflow:hidden;line-height:0}.mat-mdc-progress-spinner._mat-animation-noopable,.mat-mdc-progress-spinner._mat-animation-noopable .mdc-circular-progress__determinate-circle{transition:none}.mat-mdc-progress-spinner._mat-animation-noopable .mdc-circular-progress__indeterminate-circle-graphic,.mat-mdc-progress-spinner._mat-animation-noopable .mdc-circular-progress__spinner-layer,.mat-mdc-progress-spinner._mat-animation-noopable .mdc-circular-progress__indeterminate-container{animation:none}.mat-mdc-progress-spinner._mat-animation-noopable .mdc-circular-progress__indeterminate-container circle{stroke-dasharray:0 !important}.cdk-high-contrast-active .mat-mdc-progress-spinner .mdc-circular-progress__indeterminate-circle-graphic,.cdk-high-contrast-active .mat-mdc-progress-spinner .mdc-circular-progress__determinate-circle{stroke:currentColor;stroke:CanvasText}"],encapsulation:2,changeDetection:0})}return t})(),hG=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[oi,Ae]})}return t})(),fG=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275cmp=oe({type:t,selectors:[["app-spinner"]],decls:2,vars:0,consts:[[1,"loading"],[1,"overlay"]],template:function(i,r){1&i&&(w(0,"div",0),K(1,"mat-spinner",1),D())},dependencies:[uG],styles:[".overlay[_ngcontent-%COMP%]{position:fixed;top:20%;left:50%;z-index:100;text-align:center}.loading[_ngcontent-%COMP%]{position:absolute;top:0;left:0;width:100%;height:100%;z-index:100;display:block;-webkit-backdrop-filter:blur(5px);backdrop-filter:blur(5px)}"]})}return t})(),pG=(()=>{class t{constructor(e){this._snackBar=e}say(e,i){this._snackBar.open(e,i)}static#e=this.\u0275fac=function(i){return new(i||t)(f(Sg))};static#t=this.\u0275cmp=oe({type:t,selectors:[["app-toaster"]],decls:0,vars:0,template:function(i,r){}})}return t})();function mG(t,n){1&t&&(w(0,"th",13),N(1," Listener Name "),D())}function gG(t,n){if(1&t&&(w(0,"td",14),N(1),D()),2&t){const e=n.$implicit,i=be();M(1),Hl(" ",e.name," (",i.getBind(e.name),") ")}}function _G(t,n){1&t&&(w(0,"th",13),N(1," Total connections "),D())}function bG(t,n){if(1&t&&(w(0,"td",14),N(1),D()),2&t){const e=n.$implicit;M(1),bt(" ",e.total," ")}}function vG(t,n){1&t&&(w(0,"th",13),N(1," Active connections "),D())}function yG(t,n){if(1&t&&(w(0,"td",14),N(1),D()),2&t){const e=n.$implicit;M(1),bt(" ",e.active," ")}}function wG(t,n){1&t&&(w(0,"th",13),N(1," Total downloaded bytes from server"),D())}function xG(t,n){if(1&t&&(w(0,"td",14),N(1),D()),2&t){const e=n.$implicit;M(1),bt(" ",e.downloaded_bytes," ")}}function CG(t,n){1&t&&(w(0,"th",13),N(1," Total uploaded bytes from client "),D())}function DG(t,n){if(1&t&&(w(0,"td",14),N(1),D()),2&t){const e=n.$implicit;M(1),bt(" ",e.uploaded_bytes," ")}}function EG(t,n){1&t&&K(0,"tr",15)}function SG(t,n){1&t&&K(0,"tr",16)}function MG(t,n){1&t&&(w(0,"th",13),N(1," Name "),D())}function IG(t,n){if(1&t&&(w(0,"td",14),N(1),D()),2&t){const e=n.$implicit,i=be(2);M(1),Hl(" ",e[0]," (",i.getBind(e[0]),") ")}}function TG(t,n){1&t&&(w(0,"th",13),N(1," Why it did not work "),D())}function AG(t,n){if(1&t&&(w(0,"td",14)(1,"div",18),N(2),D()()),2&t){const e=n.$implicit;M(2),Vn(e[1])}}function kG(t,n){1&t&&K(0,"tr",15)}function RG(t,n){1&t&&K(0,"tr",16)}function OG(t,n){if(1&t&&(w(0,"div")(1,"p")(2,"b"),N(3,"Failed Listeners"),D()(),w(4,"div",1)(5,"table",2),ot(6,3),O(7,MG,2,0,"th",4),O(8,IG,2,2,"td",5),st(),ot(9,17),O(10,TG,2,0,"th",4),O(11,AG,3,1,"td",5),st(),O(12,kG,1,0,"tr",10),O(13,RG,1,0,"tr",11),D()()()),2&t){const e=be();M(5),F("dataSource",e.failedListeners),M(7),F("matHeaderRowDef",e.failedDisplayedColumns),M(1),F("matRowDefColumns",e.failedDisplayedColumns)}}let FG=(()=>{class t{ngOnInit(){console.log("Configuration Component Init is called"),this.refresh()}setStats(e){var i=[];for(const r in e)i.push(e[r]);i.sort((r,o)=>r.name.localeCompare(o.name)),this.stats=i}setFailed(e){var i=[];for(const o in e){const s=e[o];void 0===s.Ok&&i.push([o,s.Err.message])}console.log(`Updating failed to ${JSON.stringify(i)}`),this.failedListeners=i}refresh(){setTimeout(()=>this.refresh(),3e3),this.pfService.getStats().subscribe(e=>{this.setStats(e),this.updateTimestamp=(new Date).toUTCString()}),this.pfService.getListenerStatuses().subscribe(e=>{this.setFailed(e)}),this.pfService.getListeners().subscribe(e=>{this.setListeners(e)}),console.log("Refreshing..."),this.counter=this.counter+1}setListeners(e){var i=[];for(const r in e)i.push([r,e[r]]);this.listeners=i,console.log(`Listener data ${JSON.stringify(this.listeners)}`)}getBind(e){let i=this.listeners.filter(r=>r[0]==e);return 0==i.length?"":i[0][1].bind}constructor(e,i,r){this.pfService=e,this._snackBar=i,this.dialog=r,this.displayedColumns=["name","total","active","downloaded","uploaded"],this.failedDisplayedColumns=["name","reason"],this.listeners=[],this.updateTimestamp="",this.counter=0,this.failedListeners=[],this.stats=[]}static#e=this.\u0275fac=function(i){return new(i||t)(f(MS),f(Sg),f(Ua))};static#t=this.\u0275cmp=oe({type:t,selectors:[["app-stats"]],decls:37,vars:5,consts:[["color","primary"],[2,"padding","10px"],["mat-table","",1,"mat-elevation-z8",3,"dataSource"],["matColumnDef","name"],["mat-header-cell","",4,"matHeaderCellDef"],["mat-cell","",4,"matCellDef"],["matColumnDef","total"],["matColumnDef","active"],["matColumnDef","downloaded"],["matColumnDef","uploaded"],["mat-header-row","",4,"matHeaderRowDef"],["mat-row","",4,"matRowDef","matRowDefColumns"],[4,"ngIf"],["mat-header-cell",""],["mat-cell",""],["mat-header-row",""],["mat-row",""],["matColumnDef","reason"],[2,"color","red"]],template:function(i,r){1&i&&(w(0,"mat-label",0),N(1),D(),K(2,"p")(3,"p")(4,"p")(5,"p"),w(6,"p")(7,"b"),N(8,"Active Listeners"),D()(),K(9,"p")(10,"p")(11,"p")(12,"p"),w(13,"div",1)(14,"table",2),ot(15,3),O(16,mG,2,0,"th",4),O(17,gG,2,2,"td",5),st(),ot(18,6),O(19,_G,2,0,"th",4),O(20,bG,2,1,"td",5),st(),ot(21,7),O(22,vG,2,0,"th",4),O(23,yG,2,1,"td",5),st(),ot(24,8),O(25,wG,2,0,"th",4),O(26,xG,2,1,"td",5),st(),ot(27,9),O(28,CG,2,0,"th",4),O(29,DG,2,1,"td",5),st(),O(30,EG,1,0,"tr",10),O(31,SG,1,0,"tr",11),D()(),K(32,"p")(33,"p")(34,"p")(35,"p"),O(36,OG,14,3,"div",12)),2&i&&(M(1),bt("As of ",r.updateTimestamp,""),M(13),F("dataSource",r.stats),M(16),F("matHeaderRowDef",r.displayedColumns),M(1),F("matRowDefColumns",r.displayedColumns),M(5),F("ngIf",r.failedListeners.length>0))},dependencies:[jg,eu,Hg,tu,Jd,zg,nu,iu,$g,Ug,pu,Ii]})}return t})(),WI=(()=>{class t{constructor(){this.activeBG="primary",this.inactiveBG="accent",this.activeLink="",this.links=["link1","link2","link3"],this.title="ykcui"}construct(e){}ngOnInit(){console.log("AppComponent init")}select(e){}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275cmp=oe({type:t,selectors:[["app-root"]],decls:23,vars:0,consts:[[1,"bodydiv"],["color","primary"],[1,"example-spacer"],["dynamicHeight",""],["label","Configuration"],["label","Status"],["id","loading-layer",2,"display","none"],["id","toaster007"],[1,"SMARTAAA"]],template:function(i,r){1&i&&(w(0,"div",0)(1,"p")(2,"mat-toolbar",1)(3,"span"),N(4,"Port Forwarder"),D(),K(5,"span",2),D()(),w(6,"div")(7,"mat-tab-group",3)(8,"mat-tab",4),K(9,"br")(10,"br")(11,"app-config")(12,"br")(13,"br"),D(),w(14,"mat-tab",5),K(15,"br")(16,"br")(17,"app-stats")(18,"br")(19,"br"),D()()(),K(20,"app-spinner",6)(21,"app-toaster",7)(22,"div",8),D())},dependencies:[w3,cS,R$,rG,fG,pG,FG],styles:["div.bodydiv[_ngcontent-%COMP%]{padding:20px;margin:20px}.mat-tab-group.mat-primary[_ngcontent-%COMP%]   .mat-ink-bar[_ngcontent-%COMP%], .mat-tab-nav-bar.mat-primary[_ngcontent-%COMP%]   .mat-ink-bar[_ngcontent-%COMP%]{background:black}"]})}return t})();const Cu=Es(t=>function(){t(this),this.name="EmptyError",this.message="no elements in sequence"});function Du(t){return nt((n,e)=>{let i=!1;n.subscribe(qe(e,r=>{i=!0,e.next(r)},()=>{i||e.next(t),e.complete()}))})}function qI(t=NG){return nt((n,e)=>{let i=!1;n.subscribe(qe(e,r=>{i=!0,e.next(r)},()=>i?e.complete():e.error(t())))})}function NG(){return new Cu}function qr(t,n){const e=arguments.length>=2;return i=>i.pipe(t?Ze((r,o)=>t(r,o,i)):Vi,vt(1),e?Du(n):qI(()=>new Cu))}function b_(t){return t<=0?()=>Qt:nt((n,e)=>{let i=[];n.subscribe(qe(e,r=>{i.push(r),t<i.length&&i.shift()},()=>{for(const r of i)e.next(r);e.complete()},void 0,()=>{i=null}))})}const de="primary",nc=Symbol("RouteTitle");class jG{constructor(n){this.params=n||{}}has(n){return Object.prototype.hasOwnProperty.call(this.params,n)}get(n){if(this.has(n)){const e=this.params[n];return Array.isArray(e)?e[0]:e}return null}getAll(n){if(this.has(n)){const e=this.params[n];return Array.isArray(e)?e:[e]}return[]}get keys(){return Object.keys(this.params)}}function ms(t){return new jG(t)}function HG(t,n,e){const i=e.path.split("/");if(i.length>t.length||"full"===e.pathMatch&&(n.hasChildren()||i.length<t.length))return null;const r={};for(let o=0;o<i.length;o++){const s=i[o],a=t[o];if(s.startsWith(":"))r[s.substring(1)]=a;else if(s!==a.path)return null}return{consumed:t.slice(0,i.length),posParams:r}}function ci(t,n){const e=t?Object.keys(t):void 0,i=n?Object.keys(n):void 0;if(!e||!i||e.length!=i.length)return!1;let r;for(let o=0;o<e.length;o++)if(r=e[o],!YI(t[r],n[r]))return!1;return!0}function YI(t,n){if(Array.isArray(t)&&Array.isArray(n)){if(t.length!==n.length)return!1;const e=[...t].sort(),i=[...n].sort();return e.every((r,o)=>i[o]===r)}return t===n}function ZI(t){return t.length>0?t[t.length-1]:null}function dr(t){return tM(t)?t:pa(t)?dt(Promise.resolve(t)):z(t)}const $G={exact:function XI(t,n,e){if(!Yr(t.segments,n.segments)||!Eu(t.segments,n.segments,e)||t.numberOfChildren!==n.numberOfChildren)return!1;for(const i in n.children)if(!t.children[i]||!XI(t.children[i],n.children[i],e))return!1;return!0},subset:JI},KI={exact:function UG(t,n){return ci(t,n)},subset:function GG(t,n){return Object.keys(n).length<=Object.keys(t).length&&Object.keys(n).every(e=>YI(t[e],n[e]))},ignored:()=>!0};function QI(t,n,e){return $G[e.paths](t.root,n.root,e.matrixParams)&&KI[e.queryParams](t.queryParams,n.queryParams)&&!("exact"===e.fragment&&t.fragment!==n.fragment)}function JI(t,n,e){return eT(t,n,n.segments,e)}function eT(t,n,e,i){if(t.segments.length>e.length){const r=t.segments.slice(0,e.length);return!(!Yr(r,e)||n.hasChildren()||!Eu(r,e,i))}if(t.segments.length===e.length){if(!Yr(t.segments,e)||!Eu(t.segments,e,i))return!1;for(const r in n.children)if(!t.children[r]||!JI(t.children[r],n.children[r],i))return!1;return!0}{const r=e.slice(0,t.segments.length),o=e.slice(t.segments.length);return!!(Yr(t.segments,r)&&Eu(t.segments,r,i)&&t.children[de])&&eT(t.children[de],n,o,i)}}function Eu(t,n,e){return n.every((i,r)=>KI[e](t[r].parameters,i.parameters))}class gs{constructor(n=new Oe([],{}),e={},i=null){this.root=n,this.queryParams=e,this.fragment=i}get queryParamMap(){return this._queryParamMap||(this._queryParamMap=ms(this.queryParams)),this._queryParamMap}toString(){return YG.serialize(this)}}class Oe{constructor(n,e){this.segments=n,this.children=e,this.parent=null,Object.values(e).forEach(i=>i.parent=this)}hasChildren(){return this.numberOfChildren>0}get numberOfChildren(){return Object.keys(this.children).length}toString(){return Su(this)}}class ic{constructor(n,e){this.path=n,this.parameters=e}get parameterMap(){return this._parameterMap||(this._parameterMap=ms(this.parameters)),this._parameterMap}toString(){return iT(this)}}function Yr(t,n){return t.length===n.length&&t.every((e,i)=>e.path===n[i].path)}let rc=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:function(){return new v_},providedIn:"root"})}return t})();class v_{parse(n){const e=new oW(n);return new gs(e.parseRootSegment(),e.parseQueryParams(),e.parseFragment())}serialize(n){const e=`/${oc(n.root,!0)}`,i=function QG(t){const n=Object.keys(t).map(e=>{const i=t[e];return Array.isArray(i)?i.map(r=>`${Mu(e)}=${Mu(r)}`).join("&"):`${Mu(e)}=${Mu(i)}`}).filter(e=>!!e);return n.length?`?${n.join("&")}`:""}(n.queryParams);return`${e}${i}${"string"==typeof n.fragment?`#${function ZG(t){return encodeURI(t)}(n.fragment)}`:""}`}}const YG=new v_;function Su(t){return t.segments.map(n=>iT(n)).join("/")}function oc(t,n){if(!t.hasChildren())return Su(t);if(n){const e=t.children[de]?oc(t.children[de],!1):"",i=[];return Object.entries(t.children).forEach(([r,o])=>{r!==de&&i.push(`${r}:${oc(o,!1)}`)}),i.length>0?`${e}(${i.join("//")})`:e}{const e=function qG(t,n){let e=[];return Object.entries(t.children).forEach(([i,r])=>{i===de&&(e=e.concat(n(r,i)))}),Object.entries(t.children).forEach(([i,r])=>{i!==de&&(e=e.concat(n(r,i)))}),e}(t,(i,r)=>r===de?[oc(t.children[de],!1)]:[`${r}:${oc(i,!1)}`]);return 1===Object.keys(t.children).length&&null!=t.children[de]?`${Su(t)}/${e[0]}`:`${Su(t)}/(${e.join("//")})`}}function tT(t){return encodeURIComponent(t).replace(/%40/g,"@").replace(/%3A/gi,":").replace(/%24/g,"$").replace(/%2C/gi,",")}function Mu(t){return tT(t).replace(/%3B/gi,";")}function y_(t){return tT(t).replace(/\(/g,"%28").replace(/\)/g,"%29").replace(/%26/gi,"&")}function Iu(t){return decodeURIComponent(t)}function nT(t){return Iu(t.replace(/\+/g,"%20"))}function iT(t){return`${y_(t.path)}${function KG(t){return Object.keys(t).map(n=>`;${y_(n)}=${y_(t[n])}`).join("")}(t.parameters)}`}const XG=/^[^\/()?;#]+/;function w_(t){const n=t.match(XG);return n?n[0]:""}const JG=/^[^\/()?;=#]+/,tW=/^[^=?&#]+/,iW=/^[^&#]+/;class oW{constructor(n){this.url=n,this.remaining=n}parseRootSegment(){return this.consumeOptional("/"),""===this.remaining||this.peekStartsWith("?")||this.peekStartsWith("#")?new Oe([],{}):new Oe([],this.parseChildren())}parseQueryParams(){const n={};if(this.consumeOptional("?"))do{this.parseQueryParam(n)}while(this.consumeOptional("&"));return n}parseFragment(){return this.consumeOptional("#")?decodeURIComponent(this.remaining):null}parseChildren(){if(""===this.remaining)return{};this.consumeOptional("/");const n=[];for(this.peekStartsWith("(")||n.push(this.parseSegment());this.peekStartsWith("/")&&!this.peekStartsWith("//")&&!this.peekStartsWith("/(");)this.capture("/"),n.push(this.parseSegment());let e={};this.peekStartsWith("/(")&&(this.capture("/"),e=this.parseParens(!0));let i={};return this.peekStartsWith("(")&&(i=this.parseParens(!1)),(n.length>0||Object.keys(e).length>0)&&(i[de]=new Oe(n,e)),i}parseSegment(){const n=w_(this.remaining);if(""===n&&this.peekStartsWith(";"))throw new y(4009,!1);return this.capture(n),new ic(Iu(n),this.parseMatrixParams())}parseMatrixParams(){const n={};for(;this.consumeOptional(";");)this.parseParam(n);return n}parseParam(n){const e=function eW(t){const n=t.match(JG);return n?n[0]:""}(this.remaining);if(!e)return;this.capture(e);let i="";if(this.consumeOptional("=")){const r=w_(this.remaining);r&&(i=r,this.capture(i))}n[Iu(e)]=Iu(i)}parseQueryParam(n){const e=function nW(t){const n=t.match(tW);return n?n[0]:""}(this.remaining);if(!e)return;this.capture(e);let i="";if(this.consumeOptional("=")){const s=function rW(t){const n=t.match(iW);return n?n[0]:""}(this.remaining);s&&(i=s,this.capture(i))}const r=nT(e),o=nT(i);if(n.hasOwnProperty(r)){let s=n[r];Array.isArray(s)||(s=[s],n[r]=s),s.push(o)}else n[r]=o}parseParens(n){const e={};for(this.capture("(");!this.consumeOptional(")")&&this.remaining.length>0;){const i=w_(this.remaining),r=this.remaining[i.length];if("/"!==r&&")"!==r&&";"!==r)throw new y(4010,!1);let o;i.indexOf(":")>-1?(o=i.slice(0,i.indexOf(":")),this.capture(o),this.capture(":")):n&&(o=de);const s=this.parseChildren();e[o]=1===Object.keys(s).length?s[de]:new Oe([],s),this.consumeOptional("//")}return e}peekStartsWith(n){return this.remaining.startsWith(n)}consumeOptional(n){return!!this.peekStartsWith(n)&&(this.remaining=this.remaining.substring(n.length),!0)}capture(n){if(!this.consumeOptional(n))throw new y(4011,!1)}}function rT(t){return t.segments.length>0?new Oe([],{[de]:t}):t}function oT(t){const n={};for(const i of Object.keys(t.children)){const o=oT(t.children[i]);if(i===de&&0===o.segments.length&&o.hasChildren())for(const[s,a]of Object.entries(o.children))n[s]=a;else(o.segments.length>0||o.hasChildren())&&(n[i]=o)}return function sW(t){if(1===t.numberOfChildren&&t.children[de]){const n=t.children[de];return new Oe(t.segments.concat(n.segments),n.children)}return t}(new Oe(t.segments,n))}function Zr(t){return t instanceof gs}function sT(t){let n;const r=rT(function e(o){const s={};for(const c of o.children){const l=e(c);s[c.outlet]=l}const a=new Oe(o.url,s);return o===t&&(n=a),a}(t.root));return n??r}function aT(t,n,e,i){let r=t;for(;r.parent;)r=r.parent;if(0===n.length)return x_(r,r,r,e,i);const o=function cW(t){if("string"==typeof t[0]&&1===t.length&&"/"===t[0])return new lT(!0,0,t);let n=0,e=!1;const i=t.reduce((r,o,s)=>{if("object"==typeof o&&null!=o){if(o.outlets){const a={};return Object.entries(o.outlets).forEach(([c,l])=>{a[c]="string"==typeof l?l.split("/"):l}),[...r,{outlets:a}]}if(o.segmentPath)return[...r,o.segmentPath]}return"string"!=typeof o?[...r,o]:0===s?(o.split("/").forEach((a,c)=>{0==c&&"."===a||(0==c&&""===a?e=!0:".."===a?n++:""!=a&&r.push(a))}),r):[...r,o]},[]);return new lT(e,n,i)}(n);if(o.toRoot())return x_(r,r,new Oe([],{}),e,i);const s=function lW(t,n,e){if(t.isAbsolute)return new Au(n,!0,0);if(!e)return new Au(n,!1,NaN);if(null===e.parent)return new Au(e,!0,0);const i=Tu(t.commands[0])?0:1;return function dW(t,n,e){let i=t,r=n,o=e;for(;o>r;){if(o-=r,i=i.parent,!i)throw new y(4005,!1);r=i.segments.length}return new Au(i,!1,r-o)}(e,e.segments.length-1+i,t.numberOfDoubleDots)}(o,r,t),a=s.processChildren?ac(s.segmentGroup,s.index,o.commands):dT(s.segmentGroup,s.index,o.commands);return x_(r,s.segmentGroup,a,e,i)}function Tu(t){return"object"==typeof t&&null!=t&&!t.outlets&&!t.segmentPath}function sc(t){return"object"==typeof t&&null!=t&&t.outlets}function x_(t,n,e,i,r){let s,o={};i&&Object.entries(i).forEach(([c,l])=>{o[c]=Array.isArray(l)?l.map(d=>`${d}`):`${l}`}),s=t===n?e:cT(t,n,e);const a=rT(oT(s));return new gs(a,o,r)}function cT(t,n,e){const i={};return Object.entries(t.children).forEach(([r,o])=>{i[r]=o===n?e:cT(o,n,e)}),new Oe(t.segments,i)}class lT{constructor(n,e,i){if(this.isAbsolute=n,this.numberOfDoubleDots=e,this.commands=i,n&&i.length>0&&Tu(i[0]))throw new y(4003,!1);const r=i.find(sc);if(r&&r!==ZI(i))throw new y(4004,!1)}toRoot(){return this.isAbsolute&&1===this.commands.length&&"/"==this.commands[0]}}class Au{constructor(n,e,i){this.segmentGroup=n,this.processChildren=e,this.index=i}}function dT(t,n,e){if(t||(t=new Oe([],{})),0===t.segments.length&&t.hasChildren())return ac(t,n,e);const i=function hW(t,n,e){let i=0,r=n;const o={match:!1,pathIndex:0,commandIndex:0};for(;r<t.segments.length;){if(i>=e.length)return o;const s=t.segments[r],a=e[i];if(sc(a))break;const c=`${a}`,l=i<e.length-1?e[i+1]:null;if(r>0&&void 0===c)break;if(c&&l&&"object"==typeof l&&void 0===l.outlets){if(!hT(c,l,s))return o;i+=2}else{if(!hT(c,{},s))return o;i++}r++}return{match:!0,pathIndex:r,commandIndex:i}}(t,n,e),r=e.slice(i.commandIndex);if(i.match&&i.pathIndex<t.segments.length){const o=new Oe(t.segments.slice(0,i.pathIndex),{});return o.children[de]=new Oe(t.segments.slice(i.pathIndex),t.children),ac(o,0,r)}return i.match&&0===r.length?new Oe(t.segments,{}):i.match&&!t.hasChildren()?C_(t,n,e):i.match?ac(t,0,r):C_(t,n,e)}function ac(t,n,e){if(0===e.length)return new Oe(t.segments,{});{const i=function uW(t){return sc(t[0])?t[0].outlets:{[de]:t}}(e),r={};if(Object.keys(i).some(o=>o!==de)&&t.children[de]&&1===t.numberOfChildren&&0===t.children[de].segments.length){const o=ac(t.children[de],n,e);return new Oe(t.segments,o.children)}return Object.entries(i).forEach(([o,s])=>{"string"==typeof s&&(s=[s]),null!==s&&(r[o]=dT(t.children[o],n,s))}),Object.entries(t.children).forEach(([o,s])=>{void 0===i[o]&&(r[o]=s)}),new Oe(t.segments,r)}}function C_(t,n,e){const i=t.segments.slice(0,n);let r=0;for(;r<e.length;){const o=e[r];if(sc(o)){const c=fW(o.outlets);return new Oe(i,c)}if(0===r&&Tu(e[0])){i.push(new ic(t.segments[n].path,uT(e[0]))),r++;continue}const s=sc(o)?o.outlets[de]:`${o}`,a=r<e.length-1?e[r+1]:null;s&&a&&Tu(a)?(i.push(new ic(s,uT(a))),r+=2):(i.push(new ic(s,{})),r++)}return new Oe(i,{})}function fW(t){const n={};return Object.entries(t).forEach(([e,i])=>{"string"==typeof i&&(i=[i]),null!==i&&(n[e]=C_(new Oe([],{}),0,i))}),n}function uT(t){const n={};return Object.entries(t).forEach(([e,i])=>n[e]=`${i}`),n}function hT(t,n,e){return t==e.path&&ci(n,e.parameters)}const cc="imperative";class li{constructor(n,e){this.id=n,this.url=e}}class ku extends li{constructor(n,e,i="imperative",r=null){super(n,e),this.type=0,this.navigationTrigger=i,this.restoredState=r}toString(){return`NavigationStart(id: ${this.id}, url: '${this.url}')`}}class ur extends li{constructor(n,e,i){super(n,e),this.urlAfterRedirects=i,this.type=1}toString(){return`NavigationEnd(id: ${this.id}, url: '${this.url}', urlAfterRedirects: '${this.urlAfterRedirects}')`}}class lc extends li{constructor(n,e,i,r){super(n,e),this.reason=i,this.code=r,this.type=2}toString(){return`NavigationCancel(id: ${this.id}, url: '${this.url}')`}}class _s extends li{constructor(n,e,i,r){super(n,e),this.reason=i,this.code=r,this.type=16}}class Ru extends li{constructor(n,e,i,r){super(n,e),this.error=i,this.target=r,this.type=3}toString(){return`NavigationError(id: ${this.id}, url: '${this.url}', error: ${this.error})`}}class fT extends li{constructor(n,e,i,r){super(n,e),this.urlAfterRedirects=i,this.state=r,this.type=4}toString(){return`RoutesRecognized(id: ${this.id}, url: '${this.url}', urlAfterRedirects: '${this.urlAfterRedirects}', state: ${this.state})`}}class pW extends li{constructor(n,e,i,r){super(n,e),this.urlAfterRedirects=i,this.state=r,this.type=7}toString(){return`GuardsCheckStart(id: ${this.id}, url: '${this.url}', urlAfterRedirects: '${this.urlAfterRedirects}', state: ${this.state})`}}class mW extends li{constructor(n,e,i,r,o){super(n,e),this.urlAfterRedirects=i,this.state=r,this.shouldActivate=o,this.type=8}toString(){return`GuardsCheckEnd(id: ${this.id}, url: '${this.url}', urlAfterRedirects: '${this.urlAfterRedirects}', state: ${this.state}, shouldActivate: ${this.shouldActivate})`}}class gW extends li{constructor(n,e,i,r){super(n,e),this.urlAfterRedirects=i,this.state=r,this.type=5}toString(){return`ResolveStart(id: ${this.id}, url: '${this.url}', urlAfterRedirects: '${this.urlAfterRedirects}', state: ${this.state})`}}class _W extends li{constructor(n,e,i,r){super(n,e),this.urlAfterRedirects=i,this.state=r,this.type=6}toString(){return`ResolveEnd(id: ${this.id}, url: '${this.url}', urlAfterRedirects: '${this.urlAfterRedirects}', state: ${this.state})`}}class bW{constructor(n){this.route=n,this.type=9}toString(){return`RouteConfigLoadStart(path: ${this.route.path})`}}class vW{constructor(n){this.route=n,this.type=10}toString(){return`RouteConfigLoadEnd(path: ${this.route.path})`}}class yW{constructor(n){this.snapshot=n,this.type=11}toString(){return`ChildActivationStart(path: '${this.snapshot.routeConfig&&this.snapshot.routeConfig.path||""}')`}}class wW{constructor(n){this.snapshot=n,this.type=12}toString(){return`ChildActivationEnd(path: '${this.snapshot.routeConfig&&this.snapshot.routeConfig.path||""}')`}}class xW{constructor(n){this.snapshot=n,this.type=13}toString(){return`ActivationStart(path: '${this.snapshot.routeConfig&&this.snapshot.routeConfig.path||""}')`}}class CW{constructor(n){this.snapshot=n,this.type=14}toString(){return`ActivationEnd(path: '${this.snapshot.routeConfig&&this.snapshot.routeConfig.path||""}')`}}class pT{constructor(n,e,i){this.routerEvent=n,this.position=e,this.anchor=i,this.type=15}toString(){return`Scroll(anchor: '${this.anchor}', position: '${this.position?`${this.position[0]}, ${this.position[1]}`:null}')`}}class D_{}class E_{constructor(n){this.url=n}}class DW{constructor(){this.outlet=null,this.route=null,this.injector=null,this.children=new dc,this.attachRef=null}}let dc=(()=>{class t{constructor(){this.contexts=new Map}onChildOutletCreated(e,i){const r=this.getOrCreateContext(e);r.outlet=i,this.contexts.set(e,r)}onChildOutletDestroyed(e){const i=this.getContext(e);i&&(i.outlet=null,i.attachRef=null)}onOutletDeactivated(){const e=this.contexts;return this.contexts=new Map,e}onOutletReAttached(e){this.contexts=e}getOrCreateContext(e){let i=this.getContext(e);return i||(i=new DW,this.contexts.set(e,i)),i}getContext(e){return this.contexts.get(e)||null}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();class mT{constructor(n){this._root=n}get root(){return this._root.value}parent(n){const e=this.pathFromRoot(n);return e.length>1?e[e.length-2]:null}children(n){const e=S_(n,this._root);return e?e.children.map(i=>i.value):[]}firstChild(n){const e=S_(n,this._root);return e&&e.children.length>0?e.children[0].value:null}siblings(n){const e=M_(n,this._root);return e.length<2?[]:e[e.length-2].children.map(r=>r.value).filter(r=>r!==n)}pathFromRoot(n){return M_(n,this._root).map(e=>e.value)}}function S_(t,n){if(t===n.value)return n;for(const e of n.children){const i=S_(t,e);if(i)return i}return null}function M_(t,n){if(t===n.value)return[n];for(const e of n.children){const i=M_(t,e);if(i.length)return i.unshift(n),i}return[]}class Pi{constructor(n,e){this.value=n,this.children=e}toString(){return`TreeNode(${this.value})`}}function bs(t){const n={};return t&&t.children.forEach(e=>n[e.value.outlet]=e),n}class gT extends mT{constructor(n,e){super(n),this.snapshot=e,I_(this,n)}toString(){return this.snapshot.toString()}}function _T(t,n){const e=function EW(t,n){const s=new Ou([],{},{},"",{},de,n,null,{});return new vT("",new Pi(s,[]))}(0,n),i=new $t([new ic("",{})]),r=new $t({}),o=new $t({}),s=new $t({}),a=new $t(""),c=new vs(i,r,s,a,o,de,n,e.root);return c.snapshot=e.root,new gT(new Pi(c,[]),e)}class vs{constructor(n,e,i,r,o,s,a,c){this.urlSubject=n,this.paramsSubject=e,this.queryParamsSubject=i,this.fragmentSubject=r,this.dataSubject=o,this.outlet=s,this.component=a,this._futureSnapshot=c,this.title=this.dataSubject?.pipe(pe(l=>l[nc]))??z(void 0),this.url=n,this.params=e,this.queryParams=i,this.fragment=r,this.data=o}get routeConfig(){return this._futureSnapshot.routeConfig}get root(){return this._routerState.root}get parent(){return this._routerState.parent(this)}get firstChild(){return this._routerState.firstChild(this)}get children(){return this._routerState.children(this)}get pathFromRoot(){return this._routerState.pathFromRoot(this)}get paramMap(){return this._paramMap||(this._paramMap=this.params.pipe(pe(n=>ms(n)))),this._paramMap}get queryParamMap(){return this._queryParamMap||(this._queryParamMap=this.queryParams.pipe(pe(n=>ms(n)))),this._queryParamMap}toString(){return this.snapshot?this.snapshot.toString():`Future(${this._futureSnapshot})`}}function bT(t,n="emptyOnly"){const e=t.pathFromRoot;let i=0;if("always"!==n)for(i=e.length-1;i>=1;){const r=e[i],o=e[i-1];if(r.routeConfig&&""===r.routeConfig.path)i--;else{if(o.component)break;i--}}return function SW(t){return t.reduce((n,e)=>({params:{...n.params,...e.params},data:{...n.data,...e.data},resolve:{...e.data,...n.resolve,...e.routeConfig?.data,...e._resolvedData}}),{params:{},data:{},resolve:{}})}(e.slice(i))}class Ou{get title(){return this.data?.[nc]}constructor(n,e,i,r,o,s,a,c,l){this.url=n,this.params=e,this.queryParams=i,this.fragment=r,this.data=o,this.outlet=s,this.component=a,this.routeConfig=c,this._resolve=l}get root(){return this._routerState.root}get parent(){return this._routerState.parent(this)}get firstChild(){return this._routerState.firstChild(this)}get children(){return this._routerState.children(this)}get pathFromRoot(){return this._routerState.pathFromRoot(this)}get paramMap(){return this._paramMap||(this._paramMap=ms(this.params)),this._paramMap}get queryParamMap(){return this._queryParamMap||(this._queryParamMap=ms(this.queryParams)),this._queryParamMap}toString(){return`Route(url:'${this.url.map(i=>i.toString()).join("/")}', path:'${this.routeConfig?this.routeConfig.path:""}')`}}class vT extends mT{constructor(n,e){super(e),this.url=n,I_(this,e)}toString(){return yT(this._root)}}function I_(t,n){n.value._routerState=t,n.children.forEach(e=>I_(t,e))}function yT(t){const n=t.children.length>0?` { ${t.children.map(yT).join(", ")} } `:"";return`${t.value}${n}`}function T_(t){if(t.snapshot){const n=t.snapshot,e=t._futureSnapshot;t.snapshot=e,ci(n.queryParams,e.queryParams)||t.queryParamsSubject.next(e.queryParams),n.fragment!==e.fragment&&t.fragmentSubject.next(e.fragment),ci(n.params,e.params)||t.paramsSubject.next(e.params),function zG(t,n){if(t.length!==n.length)return!1;for(let e=0;e<t.length;++e)if(!ci(t[e],n[e]))return!1;return!0}(n.url,e.url)||t.urlSubject.next(e.url),ci(n.data,e.data)||t.dataSubject.next(e.data)}else t.snapshot=t._futureSnapshot,t.dataSubject.next(t._futureSnapshot.data)}function A_(t,n){const e=ci(t.params,n.params)&&function WG(t,n){return Yr(t,n)&&t.every((e,i)=>ci(e.parameters,n[i].parameters))}(t.url,n.url);return e&&!(!t.parent!=!n.parent)&&(!t.parent||A_(t.parent,n.parent))}let wT=(()=>{class t{constructor(){this.activated=null,this._activatedRoute=null,this.name=de,this.activateEvents=new q,this.deactivateEvents=new q,this.attachEvents=new q,this.detachEvents=new q,this.parentContexts=T(dc),this.location=T(Je),this.changeDetector=T(ct),this.environmentInjector=T(on),this.inputBinder=T(Fu,{optional:!0}),this.supportsBindingToComponentInputs=!0}get activatedComponentRef(){return this.activated}ngOnChanges(e){if(e.name){const{firstChange:i,previousValue:r}=e.name;if(i)return;this.isTrackedInParentContexts(r)&&(this.deactivate(),this.parentContexts.onChildOutletDestroyed(r)),this.initializeOutletWithName()}}ngOnDestroy(){this.isTrackedInParentContexts(this.name)&&this.parentContexts.onChildOutletDestroyed(this.name),this.inputBinder?.unsubscribeFromRouteData(this)}isTrackedInParentContexts(e){return this.parentContexts.getContext(e)?.outlet===this}ngOnInit(){this.initializeOutletWithName()}initializeOutletWithName(){if(this.parentContexts.onChildOutletCreated(this.name,this),this.activated)return;const e=this.parentContexts.getContext(this.name);e?.route&&(e.attachRef?this.attach(e.attachRef,e.route):this.activateWith(e.route,e.injector))}get isActivated(){return!!this.activated}get component(){if(!this.activated)throw new y(4012,!1);return this.activated.instance}get activatedRoute(){if(!this.activated)throw new y(4012,!1);return this._activatedRoute}get activatedRouteData(){return this._activatedRoute?this._activatedRoute.snapshot.data:{}}detach(){if(!this.activated)throw new y(4012,!1);this.location.detach();const e=this.activated;return this.activated=null,this._activatedRoute=null,this.detachEvents.emit(e.instance),e}attach(e,i){this.activated=e,this._activatedRoute=i,this.location.insert(e.hostView),this.inputBinder?.bindActivatedRouteToOutletComponent(this),this.attachEvents.emit(e.instance)}deactivate(){if(this.activated){const e=this.component;this.activated.destroy(),this.activated=null,this._activatedRoute=null,this.deactivateEvents.emit(e)}}activateWith(e,i){if(this.isActivated)throw new y(4013,!1);this._activatedRoute=e;const r=this.location,s=e.snapshot.component,a=this.parentContexts.getOrCreateContext(this.name).children,c=new MW(e,a,r.injector);this.activated=r.createComponent(s,{index:r.length,injector:c,environmentInjector:i??this.environmentInjector}),this.changeDetector.markForCheck(),this.inputBinder?.bindActivatedRouteToOutletComponent(this),this.activateEvents.emit(this.activated.instance)}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275dir=x({type:t,selectors:[["router-outlet"]],inputs:{name:"name"},outputs:{activateEvents:"activate",deactivateEvents:"deactivate",attachEvents:"attach",detachEvents:"detach"},exportAs:["outlet"],standalone:!0,features:[ut]})}return t})();class MW{constructor(n,e,i){this.route=n,this.childContexts=e,this.parent=i}get(n,e){return n===vs?this.route:n===dc?this.childContexts:this.parent.get(n,e)}}const Fu=new E("");let xT=(()=>{class t{constructor(){this.outletDataSubscriptions=new Map}bindActivatedRouteToOutletComponent(e){this.unsubscribeFromRouteData(e),this.subscribeToRouteData(e)}unsubscribeFromRouteData(e){this.outletDataSubscriptions.get(e)?.unsubscribe(),this.outletDataSubscriptions.delete(e)}subscribeToRouteData(e){const{activatedRoute:i}=e,r=vd([i.queryParams,i.params,i.data]).pipe(Xt(([o,s,a],c)=>(a={...o,...s,...a},0===c?z(a):Promise.resolve(a)))).subscribe(o=>{if(!e.isActivated||!e.activatedComponentRef||e.activatedRoute!==i||null===i.component)return void this.unsubscribeFromRouteData(e);const s=function EV(t){const n=ye(t);if(!n)return null;const e=new la(n);return{get selector(){return e.selector},get type(){return e.componentType},get inputs(){return e.inputs},get outputs(){return e.outputs},get ngContentSelectors(){return e.ngContentSelectors},get isStandalone(){return n.standalone},get isSignal(){return n.signals}}}(i.component);if(s)for(const{templateName:a}of s.inputs)e.activatedComponentRef.setInput(a,o[a]);else this.unsubscribeFromRouteData(e)});this.outletDataSubscriptions.set(e,r)}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();function uc(t,n,e){if(e&&t.shouldReuseRoute(n.value,e.value.snapshot)){const i=e.value;i._futureSnapshot=n.value;const r=function TW(t,n,e){return n.children.map(i=>{for(const r of e.children)if(t.shouldReuseRoute(i.value,r.value.snapshot))return uc(t,i,r);return uc(t,i)})}(t,n,e);return new Pi(i,r)}{if(t.shouldAttach(n.value)){const o=t.retrieve(n.value);if(null!==o){const s=o.route;return s.value._futureSnapshot=n.value,s.children=n.children.map(a=>uc(t,a)),s}}const i=function AW(t){return new vs(new $t(t.url),new $t(t.params),new $t(t.queryParams),new $t(t.fragment),new $t(t.data),t.outlet,t.component,t)}(n.value),r=n.children.map(o=>uc(t,o));return new Pi(i,r)}}const k_="ngNavigationCancelingError";function CT(t,n){const{redirectTo:e,navigationBehaviorOptions:i}=Zr(n)?{redirectTo:n,navigationBehaviorOptions:void 0}:n,r=DT(!1,0,n);return r.url=e,r.navigationBehaviorOptions=i,r}function DT(t,n,e){const i=new Error("NavigationCancelingError: "+(t||""));return i[k_]=!0,i.cancellationCode=n,e&&(i.url=e),i}function ET(t){return t&&t[k_]}let ST=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275cmp=oe({type:t,selectors:[["ng-component"]],standalone:!0,features:[Kx],decls:1,vars:0,template:function(i,r){1&i&&K(0,"router-outlet")},dependencies:[wT],encapsulation:2})}return t})();function R_(t){const n=t.children&&t.children.map(R_),e=n?{...t,children:n}:{...t};return!e.component&&!e.loadComponent&&(n||e.loadChildren)&&e.outlet&&e.outlet!==de&&(e.component=ST),e}function Un(t){return t.outlet||de}function hc(t){if(!t)return null;if(t.routeConfig?._injector)return t.routeConfig._injector;for(let n=t.parent;n;n=n.parent){const e=n.routeConfig;if(e?._loadedInjector)return e._loadedInjector;if(e?._injector)return e._injector}return null}class BW{constructor(n,e,i,r,o){this.routeReuseStrategy=n,this.futureState=e,this.currState=i,this.forwardEvent=r,this.inputBindingEnabled=o}activate(n){const e=this.futureState._root,i=this.currState?this.currState._root:null;this.deactivateChildRoutes(e,i,n),T_(this.futureState.root),this.activateChildRoutes(e,i,n)}deactivateChildRoutes(n,e,i){const r=bs(e);n.children.forEach(o=>{const s=o.value.outlet;this.deactivateRoutes(o,r[s],i),delete r[s]}),Object.values(r).forEach(o=>{this.deactivateRouteAndItsChildren(o,i)})}deactivateRoutes(n,e,i){const r=n.value,o=e?e.value:null;if(r===o)if(r.component){const s=i.getContext(r.outlet);s&&this.deactivateChildRoutes(n,e,s.children)}else this.deactivateChildRoutes(n,e,i);else o&&this.deactivateRouteAndItsChildren(e,i)}deactivateRouteAndItsChildren(n,e){n.value.component&&this.routeReuseStrategy.shouldDetach(n.value.snapshot)?this.detachAndStoreRouteSubtree(n,e):this.deactivateRouteAndOutlet(n,e)}detachAndStoreRouteSubtree(n,e){const i=e.getContext(n.value.outlet),r=i&&n.value.component?i.children:e,o=bs(n);for(const s of Object.keys(o))this.deactivateRouteAndItsChildren(o[s],r);if(i&&i.outlet){const s=i.outlet.detach(),a=i.children.onOutletDeactivated();this.routeReuseStrategy.store(n.value.snapshot,{componentRef:s,route:n,contexts:a})}}deactivateRouteAndOutlet(n,e){const i=e.getContext(n.value.outlet),r=i&&n.value.component?i.children:e,o=bs(n);for(const s of Object.keys(o))this.deactivateRouteAndItsChildren(o[s],r);i&&(i.outlet&&(i.outlet.deactivate(),i.children.onOutletDeactivated()),i.attachRef=null,i.route=null)}activateChildRoutes(n,e,i){const r=bs(e);n.children.forEach(o=>{this.activateRoutes(o,r[o.value.outlet],i),this.forwardEvent(new CW(o.value.snapshot))}),n.children.length&&this.forwardEvent(new wW(n.value.snapshot))}activateRoutes(n,e,i){const r=n.value,o=e?e.value:null;if(T_(r),r===o)if(r.component){const s=i.getOrCreateContext(r.outlet);this.activateChildRoutes(n,e,s.children)}else this.activateChildRoutes(n,e,i);else if(r.component){const s=i.getOrCreateContext(r.outlet);if(this.routeReuseStrategy.shouldAttach(r.snapshot)){const a=this.routeReuseStrategy.retrieve(r.snapshot);this.routeReuseStrategy.store(r.snapshot,null),s.children.onOutletReAttached(a.contexts),s.attachRef=a.componentRef,s.route=a.route.value,s.outlet&&s.outlet.attach(a.componentRef,a.route.value),T_(a.route.value),this.activateChildRoutes(n,null,s.children)}else{const a=hc(r.snapshot);s.attachRef=null,s.route=r,s.injector=a,s.outlet&&s.outlet.activateWith(r,s.injector),this.activateChildRoutes(n,null,s.children)}}else this.activateChildRoutes(n,null,i)}}class MT{constructor(n){this.path=n,this.route=this.path[this.path.length-1]}}class Nu{constructor(n,e){this.component=n,this.route=e}}function VW(t,n,e){const i=t._root;return fc(i,n?n._root:null,e,[i.value])}function ys(t,n){const e=Symbol(),i=n.get(t,e);return i===e?"function"!=typeof t||function Nk(t){return null!==Ac(t)}(t)?n.get(t):t:i}function fc(t,n,e,i,r={canDeactivateChecks:[],canActivateChecks:[]}){const o=bs(n);return t.children.forEach(s=>{(function HW(t,n,e,i,r={canDeactivateChecks:[],canActivateChecks:[]}){const o=t.value,s=n?n.value:null,a=e?e.getContext(t.value.outlet):null;if(s&&o.routeConfig===s.routeConfig){const c=function zW(t,n,e){if("function"==typeof e)return e(t,n);switch(e){case"pathParamsChange":return!Yr(t.url,n.url);case"pathParamsOrQueryParamsChange":return!Yr(t.url,n.url)||!ci(t.queryParams,n.queryParams);case"always":return!0;case"paramsOrQueryParamsChange":return!A_(t,n)||!ci(t.queryParams,n.queryParams);default:return!A_(t,n)}}(s,o,o.routeConfig.runGuardsAndResolvers);c?r.canActivateChecks.push(new MT(i)):(o.data=s.data,o._resolvedData=s._resolvedData),fc(t,n,o.component?a?a.children:null:e,i,r),c&&a&&a.outlet&&a.outlet.isActivated&&r.canDeactivateChecks.push(new Nu(a.outlet.component,s))}else s&&pc(n,a,r),r.canActivateChecks.push(new MT(i)),fc(t,null,o.component?a?a.children:null:e,i,r)})(s,o[s.value.outlet],e,i.concat([s.value]),r),delete o[s.value.outlet]}),Object.entries(o).forEach(([s,a])=>pc(a,e.getContext(s),r)),r}function pc(t,n,e){const i=bs(t),r=t.value;Object.entries(i).forEach(([o,s])=>{pc(s,r.component?n?n.children.getContext(o):null:n,e)}),e.canDeactivateChecks.push(new Nu(r.component&&n&&n.outlet&&n.outlet.isActivated?n.outlet.component:null,r))}function mc(t){return"function"==typeof t}function IT(t){return t instanceof Cu||"EmptyError"===t?.name}const Pu=Symbol("INITIAL_VALUE");function ws(){return Xt(t=>vd(t.map(n=>n.pipe(vt(1),Dn(Pu)))).pipe(pe(n=>{for(const e of n)if(!0!==e){if(e===Pu)return Pu;if(!1===e||e instanceof gs)return e}return!0}),Ze(n=>n!==Pu),vt(1)))}function TT(t){return function jA(...t){return mb(t)}(Ee(n=>{if(Zr(n))throw CT(0,n)}),pe(n=>!0===n))}class Lu{constructor(n){this.segmentGroup=n||null}}class AT{constructor(n){this.urlTree=n}}function xs(t){return hs(new Lu(t))}function kT(t){return hs(new AT(t))}class cq{constructor(n,e){this.urlSerializer=n,this.urlTree=e}noMatchError(n){return new y(4002,!1)}lineralizeSegments(n,e){let i=[],r=e.root;for(;;){if(i=i.concat(r.segments),0===r.numberOfChildren)return z(i);if(r.numberOfChildren>1||!r.children[de])return hs(new y(4e3,!1));r=r.children[de]}}applyRedirectCommands(n,e,i){return this.applyRedirectCreateUrlTree(e,this.urlSerializer.parse(e),n,i)}applyRedirectCreateUrlTree(n,e,i,r){const o=this.createSegmentGroup(n,e.root,i,r);return new gs(o,this.createQueryParams(e.queryParams,this.urlTree.queryParams),e.fragment)}createQueryParams(n,e){const i={};return Object.entries(n).forEach(([r,o])=>{if("string"==typeof o&&o.startsWith(":")){const a=o.substring(1);i[r]=e[a]}else i[r]=o}),i}createSegmentGroup(n,e,i,r){const o=this.createSegments(n,e.segments,i,r);let s={};return Object.entries(e.children).forEach(([a,c])=>{s[a]=this.createSegmentGroup(n,c,i,r)}),new Oe(o,s)}createSegments(n,e,i,r){return e.map(o=>o.path.startsWith(":")?this.findPosParam(n,o,r):this.findOrReturn(o,i))}findPosParam(n,e,i){const r=i[e.path.substring(1)];if(!r)throw new y(4001,!1);return r}findOrReturn(n,e){let i=0;for(const r of e){if(r.path===n.path)return e.splice(i),r;i++}return n}}const O_={matched:!1,consumedSegments:[],remainingSegments:[],parameters:{},positionalParamSegments:{}};function lq(t,n,e,i,r){const o=F_(t,n,e);return o.matched?(i=function RW(t,n){return t.providers&&!t._injector&&(t._injector=Op(t.providers,n,`Route: ${t.path}`)),t._injector??n}(n,i),function oq(t,n,e,i){const r=n.canMatch;return r&&0!==r.length?z(r.map(s=>{const a=ys(s,t);return dr(function YW(t){return t&&mc(t.canMatch)}(a)?a.canMatch(n,e):t.runInContext(()=>a(n,e)))})).pipe(ws(),TT()):z(!0)}(i,n,e).pipe(pe(s=>!0===s?o:{...O_}))):z(o)}function F_(t,n,e){if(""===n.path)return"full"===n.pathMatch&&(t.hasChildren()||e.length>0)?{...O_}:{matched:!0,consumedSegments:[],remainingSegments:e,parameters:{},positionalParamSegments:{}};const r=(n.matcher||HG)(e,t,n);if(!r)return{...O_};const o={};Object.entries(r.posParams??{}).forEach(([a,c])=>{o[a]=c.path});const s=r.consumed.length>0?{...o,...r.consumed[r.consumed.length-1].parameters}:o;return{matched:!0,consumedSegments:r.consumed,remainingSegments:e.slice(r.consumed.length),parameters:s,positionalParamSegments:r.posParams??{}}}function RT(t,n,e,i){return e.length>0&&function hq(t,n,e){return e.some(i=>Bu(t,n,i)&&Un(i)!==de)}(t,e,i)?{segmentGroup:new Oe(n,uq(i,new Oe(e,t.children))),slicedSegments:[]}:0===e.length&&function fq(t,n,e){return e.some(i=>Bu(t,n,i))}(t,e,i)?{segmentGroup:new Oe(t.segments,dq(t,0,e,i,t.children)),slicedSegments:e}:{segmentGroup:new Oe(t.segments,t.children),slicedSegments:e}}function dq(t,n,e,i,r){const o={};for(const s of i)if(Bu(t,e,s)&&!r[Un(s)]){const a=new Oe([],{});o[Un(s)]=a}return{...r,...o}}function uq(t,n){const e={};e[de]=n;for(const i of t)if(""===i.path&&Un(i)!==de){const r=new Oe([],{});e[Un(i)]=r}return e}function Bu(t,n,e){return(!(t.hasChildren()||n.length>0)||"full"!==e.pathMatch)&&""===e.path}class _q{constructor(n,e,i,r,o,s,a){this.injector=n,this.configLoader=e,this.rootComponentType=i,this.config=r,this.urlTree=o,this.paramsInheritanceStrategy=s,this.urlSerializer=a,this.allowRedirects=!0,this.applyRedirects=new cq(this.urlSerializer,this.urlTree)}noMatchError(n){return new y(4002,!1)}recognize(){const n=RT(this.urlTree.root,[],[],this.config).segmentGroup;return this.processSegmentGroup(this.injector,this.config,n,de).pipe(Ri(e=>{if(e instanceof AT)return this.allowRedirects=!1,this.urlTree=e.urlTree,this.match(e.urlTree);throw e instanceof Lu?this.noMatchError(e):e}),pe(e=>{const i=new Ou([],Object.freeze({}),Object.freeze({...this.urlTree.queryParams}),this.urlTree.fragment,{},de,this.rootComponentType,null,{}),r=new Pi(i,e),o=new vT("",r),s=function aW(t,n,e=null,i=null){return aT(sT(t),n,e,i)}(i,[],this.urlTree.queryParams,this.urlTree.fragment);return s.queryParams=this.urlTree.queryParams,o.url=this.urlSerializer.serialize(s),this.inheritParamsAndData(o._root),{state:o,tree:s}}))}match(n){return this.processSegmentGroup(this.injector,this.config,n.root,de).pipe(Ri(i=>{throw i instanceof Lu?this.noMatchError(i):i}))}inheritParamsAndData(n){const e=n.value,i=bT(e,this.paramsInheritanceStrategy);e.params=Object.freeze(i.params),e.data=Object.freeze(i.data),n.children.forEach(r=>this.inheritParamsAndData(r))}processSegmentGroup(n,e,i,r){return 0===i.segments.length&&i.hasChildren()?this.processChildren(n,e,i):this.processSegment(n,e,i,i.segments,r,!0)}processChildren(n,e,i){const r=[];for(const o of Object.keys(i.children))"primary"===o?r.unshift(o):r.push(o);return dt(r).pipe(rs(o=>{const s=i.children[o],a=function PW(t,n){const e=t.filter(i=>Un(i)===n);return e.push(...t.filter(i=>Un(i)!==n)),e}(e,o);return this.processSegmentGroup(n,a,s,o)}),function LG(t,n){return nt(function PG(t,n,e,i,r){return(o,s)=>{let a=e,c=n,l=0;o.subscribe(qe(s,d=>{const u=l++;c=a?t(c,d,u):(a=!0,d),i&&s.next(c)},r&&(()=>{a&&s.next(c),s.complete()})))}}(t,n,arguments.length>=2,!0))}((o,s)=>(o.push(...s),o)),Du(null),function BG(t,n){const e=arguments.length>=2;return i=>i.pipe(t?Ze((r,o)=>t(r,o,i)):Vi,b_(1),e?Du(n):qI(()=>new Cu))}(),yt(o=>{if(null===o)return xs(i);const s=OT(o);return function bq(t){t.sort((n,e)=>n.value.outlet===de?-1:e.value.outlet===de?1:n.value.outlet.localeCompare(e.value.outlet))}(s),z(s)}))}processSegment(n,e,i,r,o,s){return dt(e).pipe(rs(a=>this.processSegmentAgainstRoute(a._injector??n,e,a,i,r,o,s).pipe(Ri(c=>{if(c instanceof Lu)return z(null);throw c}))),qr(a=>!!a),Ri(a=>{if(IT(a))return function mq(t,n,e){return 0===n.length&&!t.children[e]}(i,r,o)?z([]):xs(i);throw a}))}processSegmentAgainstRoute(n,e,i,r,o,s,a){return function pq(t,n,e,i){return!!(Un(t)===i||i!==de&&Bu(n,e,t))&&("**"===t.path||F_(n,t,e).matched)}(i,r,o,s)?void 0===i.redirectTo?this.matchSegmentAgainstRoute(n,r,i,o,s,a):a&&this.allowRedirects?this.expandSegmentAgainstRouteUsingRedirect(n,r,e,i,o,s):xs(r):xs(r)}expandSegmentAgainstRouteUsingRedirect(n,e,i,r,o,s){return"**"===r.path?this.expandWildCardWithParamsAgainstRouteUsingRedirect(n,i,r,s):this.expandRegularSegmentAgainstRouteUsingRedirect(n,e,i,r,o,s)}expandWildCardWithParamsAgainstRouteUsingRedirect(n,e,i,r){const o=this.applyRedirects.applyRedirectCommands([],i.redirectTo,{});return i.redirectTo.startsWith("/")?kT(o):this.applyRedirects.lineralizeSegments(i,o).pipe(yt(s=>{const a=new Oe(s,{});return this.processSegment(n,e,a,s,r,!1)}))}expandRegularSegmentAgainstRouteUsingRedirect(n,e,i,r,o,s){const{matched:a,consumedSegments:c,remainingSegments:l,positionalParamSegments:d}=F_(e,r,o);if(!a)return xs(e);const u=this.applyRedirects.applyRedirectCommands(c,r.redirectTo,d);return r.redirectTo.startsWith("/")?kT(u):this.applyRedirects.lineralizeSegments(r,u).pipe(yt(h=>this.processSegment(n,i,e,h.concat(l),s,!1)))}matchSegmentAgainstRoute(n,e,i,r,o,s){let a;if("**"===i.path){const c=r.length>0?ZI(r).parameters:{};a=z({snapshot:new Ou(r,c,Object.freeze({...this.urlTree.queryParams}),this.urlTree.fragment,FT(i),Un(i),i.component??i._loadedComponent??null,i,NT(i)),consumedSegments:[],remainingSegments:[]}),e.children={}}else a=lq(e,i,r,n).pipe(pe(({matched:c,consumedSegments:l,remainingSegments:d,parameters:u})=>c?{snapshot:new Ou(l,u,Object.freeze({...this.urlTree.queryParams}),this.urlTree.fragment,FT(i),Un(i),i.component??i._loadedComponent??null,i,NT(i)),consumedSegments:l,remainingSegments:d}:null));return a.pipe(Xt(c=>null===c?xs(e):this.getChildConfig(n=i._injector??n,i,r).pipe(Xt(({routes:l})=>{const d=i._loadedInjector??n,{snapshot:u,consumedSegments:h,remainingSegments:p}=c,{segmentGroup:m,slicedSegments:g}=RT(e,h,p,l);if(0===g.length&&m.hasChildren())return this.processChildren(d,l,m).pipe(pe(C=>null===C?null:[new Pi(u,C)]));if(0===l.length&&0===g.length)return z([new Pi(u,[])]);const _=Un(i)===o;return this.processSegment(d,l,m,g,_?de:o,!0).pipe(pe(C=>[new Pi(u,C)]))}))))}getChildConfig(n,e,i){return e.children?z({routes:e.children,injector:n}):e.loadChildren?void 0!==e._loadedRoutes?z({routes:e._loadedRoutes,injector:e._loadedInjector}):function rq(t,n,e,i){const r=n.canLoad;return void 0===r||0===r.length?z(!0):z(r.map(s=>{const a=ys(s,t);return dr(function UW(t){return t&&mc(t.canLoad)}(a)?a.canLoad(n,e):t.runInContext(()=>a(n,e)))})).pipe(ws(),TT())}(n,e,i).pipe(yt(r=>r?this.configLoader.loadChildren(n,e).pipe(Ee(o=>{e._loadedRoutes=o.routes,e._loadedInjector=o.injector})):function aq(t){return hs(DT(!1,3))}())):z({routes:[],injector:n})}}function vq(t){const n=t.value.routeConfig;return n&&""===n.path}function OT(t){const n=[],e=new Set;for(const i of t){if(!vq(i)){n.push(i);continue}const r=n.find(o=>i.value.routeConfig===o.value.routeConfig);void 0!==r?(r.children.push(...i.children),e.add(r)):n.push(i)}for(const i of e){const r=OT(i.children);n.push(new Pi(i.value,r))}return n.filter(i=>!e.has(i))}function FT(t){return t.data||{}}function NT(t){return t.resolve||{}}function PT(t){return"string"==typeof t.title||null===t.title}function N_(t){return Xt(n=>{const e=t(n);return e?dt(e).pipe(pe(()=>n)):z(n)})}const Cs=new E("ROUTES");let P_=(()=>{class t{constructor(){this.componentLoaders=new WeakMap,this.childrenLoaders=new WeakMap,this.compiler=T(HC)}loadComponent(e){if(this.componentLoaders.get(e))return this.componentLoaders.get(e);if(e._loadedComponent)return z(e._loadedComponent);this.onLoadStartListener&&this.onLoadStartListener(e);const i=dr(e.loadComponent()).pipe(pe(LT),Ee(o=>{this.onLoadEndListener&&this.onLoadEndListener(e),e._loadedComponent=o}),rr(()=>{this.componentLoaders.delete(e)})),r=new Ig(i,()=>new U).pipe(Mg());return this.componentLoaders.set(e,r),r}loadChildren(e,i){if(this.childrenLoaders.get(i))return this.childrenLoaders.get(i);if(i._loadedRoutes)return z({routes:i._loadedRoutes,injector:i._loadedInjector});this.onLoadStartListener&&this.onLoadStartListener(i);const o=function Sq(t,n,e,i){return dr(t.loadChildren()).pipe(pe(LT),yt(r=>r instanceof Yx||Array.isArray(r)?z(r):dt(n.compileModuleAsync(r))),pe(r=>{i&&i(t);let o,s,a=!1;return Array.isArray(r)?(s=r,!0):(o=r.create(e).injector,s=o.get(Cs,[],{optional:!0,self:!0}).flat()),{routes:s.map(R_),injector:o}}))}(i,this.compiler,e,this.onLoadEndListener).pipe(rr(()=>{this.childrenLoaders.delete(i)})),s=new Ig(o,()=>new U).pipe(Mg());return this.childrenLoaders.set(i,s),s}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();function LT(t){return function Mq(t){return t&&"object"==typeof t&&"default"in t}(t)?t.default:t}let Vu=(()=>{class t{get hasRequestedNavigation(){return 0!==this.navigationId}constructor(){this.currentNavigation=null,this.currentTransition=null,this.lastSuccessfulNavigation=null,this.events=new U,this.transitionAbortSubject=new U,this.configLoader=T(P_),this.environmentInjector=T(on),this.urlSerializer=T(rc),this.rootContexts=T(dc),this.inputBindingEnabled=null!==T(Fu,{optional:!0}),this.navigationId=0,this.afterPreactivation=()=>z(void 0),this.rootComponentType=null,this.configLoader.onLoadEndListener=r=>this.events.next(new vW(r)),this.configLoader.onLoadStartListener=r=>this.events.next(new bW(r))}complete(){this.transitions?.complete()}handleNavigationRequest(e){const i=++this.navigationId;this.transitions?.next({...this.transitions.value,...e,id:i})}setupNavigations(e,i,r){return this.transitions=new $t({id:0,currentUrlTree:i,currentRawUrl:i,currentBrowserUrl:i,extractedUrl:e.urlHandlingStrategy.extract(i),urlAfterRedirects:e.urlHandlingStrategy.extract(i),rawUrl:i,extras:{},resolve:null,reject:null,promise:Promise.resolve(!0),source:cc,restoredState:null,currentSnapshot:r.snapshot,targetSnapshot:null,currentRouterState:r,targetRouterState:null,guards:{canActivateChecks:[],canDeactivateChecks:[]},guardsResult:null}),this.transitions.pipe(Ze(o=>0!==o.id),pe(o=>({...o,extractedUrl:e.urlHandlingStrategy.extract(o.rawUrl)})),Xt(o=>{this.currentTransition=o;let s=!1,a=!1;return z(o).pipe(Ee(c=>{this.currentNavigation={id:c.id,initialUrl:c.rawUrl,extractedUrl:c.extractedUrl,trigger:c.source,extras:c.extras,previousNavigation:this.lastSuccessfulNavigation?{...this.lastSuccessfulNavigation,previousNavigation:null}:null}}),Xt(c=>{const l=c.currentBrowserUrl.toString(),d=!e.navigated||c.extractedUrl.toString()!==l||l!==c.currentUrlTree.toString();if(!d&&"reload"!==(c.extras.onSameUrlNavigation??e.onSameUrlNavigation)){const h="";return this.events.next(new _s(c.id,this.urlSerializer.serialize(c.rawUrl),h,0)),c.resolve(null),Qt}if(e.urlHandlingStrategy.shouldProcessUrl(c.rawUrl))return z(c).pipe(Xt(h=>{const p=this.transitions?.getValue();return this.events.next(new ku(h.id,this.urlSerializer.serialize(h.extractedUrl),h.source,h.restoredState)),p!==this.transitions?.getValue()?Qt:Promise.resolve(h)}),function yq(t,n,e,i,r,o){return yt(s=>function gq(t,n,e,i,r,o,s="emptyOnly"){return new _q(t,n,e,i,r,s,o).recognize()}(t,n,e,i,s.extractedUrl,r,o).pipe(pe(({state:a,tree:c})=>({...s,targetSnapshot:a,urlAfterRedirects:c}))))}(this.environmentInjector,this.configLoader,this.rootComponentType,e.config,this.urlSerializer,e.paramsInheritanceStrategy),Ee(h=>{o.targetSnapshot=h.targetSnapshot,o.urlAfterRedirects=h.urlAfterRedirects,this.currentNavigation={...this.currentNavigation,finalUrl:h.urlAfterRedirects};const p=new fT(h.id,this.urlSerializer.serialize(h.extractedUrl),this.urlSerializer.serialize(h.urlAfterRedirects),h.targetSnapshot);this.events.next(p)}));if(d&&e.urlHandlingStrategy.shouldProcessUrl(c.currentRawUrl)){const{id:h,extractedUrl:p,source:m,restoredState:g,extras:_}=c,C=new ku(h,this.urlSerializer.serialize(p),m,g);this.events.next(C);const v=_T(0,this.rootComponentType).snapshot;return this.currentTransition=o={...c,targetSnapshot:v,urlAfterRedirects:p,extras:{..._,skipLocationChange:!1,replaceUrl:!1}},z(o)}{const h="";return this.events.next(new _s(c.id,this.urlSerializer.serialize(c.extractedUrl),h,1)),c.resolve(null),Qt}}),Ee(c=>{const l=new pW(c.id,this.urlSerializer.serialize(c.extractedUrl),this.urlSerializer.serialize(c.urlAfterRedirects),c.targetSnapshot);this.events.next(l)}),pe(c=>(this.currentTransition=o={...c,guards:VW(c.targetSnapshot,c.currentSnapshot,this.rootContexts)},o)),function KW(t,n){return yt(e=>{const{targetSnapshot:i,currentSnapshot:r,guards:{canActivateChecks:o,canDeactivateChecks:s}}=e;return 0===s.length&&0===o.length?z({...e,guardsResult:!0}):function QW(t,n,e,i){return dt(t).pipe(yt(r=>function iq(t,n,e,i,r){const o=n&&n.routeConfig?n.routeConfig.canDeactivate:null;return o&&0!==o.length?z(o.map(a=>{const c=hc(n)??r,l=ys(a,c);return dr(function qW(t){return t&&mc(t.canDeactivate)}(l)?l.canDeactivate(t,n,e,i):c.runInContext(()=>l(t,n,e,i))).pipe(qr())})).pipe(ws()):z(!0)}(r.component,r.route,e,n,i)),qr(r=>!0!==r,!0))}(s,i,r,t).pipe(yt(a=>a&&function $W(t){return"boolean"==typeof t}(a)?function XW(t,n,e,i){return dt(n).pipe(rs(r=>yd(function eq(t,n){return null!==t&&n&&n(new yW(t)),z(!0)}(r.route.parent,i),function JW(t,n){return null!==t&&n&&n(new xW(t)),z(!0)}(r.route,i),function nq(t,n,e){const i=n[n.length-1],o=n.slice(0,n.length-1).reverse().map(s=>function jW(t){const n=t.routeConfig?t.routeConfig.canActivateChild:null;return n&&0!==n.length?{node:t,guards:n}:null}(s)).filter(s=>null!==s).map(s=>Vd(()=>z(s.guards.map(c=>{const l=hc(s.node)??e,d=ys(c,l);return dr(function WW(t){return t&&mc(t.canActivateChild)}(d)?d.canActivateChild(i,t):l.runInContext(()=>d(i,t))).pipe(qr())})).pipe(ws())));return z(o).pipe(ws())}(t,r.path,e),function tq(t,n,e){const i=n.routeConfig?n.routeConfig.canActivate:null;if(!i||0===i.length)return z(!0);const r=i.map(o=>Vd(()=>{const s=hc(n)??e,a=ys(o,s);return dr(function GW(t){return t&&mc(t.canActivate)}(a)?a.canActivate(n,t):s.runInContext(()=>a(n,t))).pipe(qr())}));return z(r).pipe(ws())}(t,r.route,e))),qr(r=>!0!==r,!0))}(i,o,t,n):z(a)),pe(a=>({...e,guardsResult:a})))})}(this.environmentInjector,c=>this.events.next(c)),Ee(c=>{if(o.guardsResult=c.guardsResult,Zr(c.guardsResult))throw CT(0,c.guardsResult);const l=new mW(c.id,this.urlSerializer.serialize(c.extractedUrl),this.urlSerializer.serialize(c.urlAfterRedirects),c.targetSnapshot,!!c.guardsResult);this.events.next(l)}),Ze(c=>!!c.guardsResult||(this.cancelNavigationTransition(c,"",3),!1)),N_(c=>{if(c.guards.canActivateChecks.length)return z(c).pipe(Ee(l=>{const d=new gW(l.id,this.urlSerializer.serialize(l.extractedUrl),this.urlSerializer.serialize(l.urlAfterRedirects),l.targetSnapshot);this.events.next(d)}),Xt(l=>{let d=!1;return z(l).pipe(function wq(t,n){return yt(e=>{const{targetSnapshot:i,guards:{canActivateChecks:r}}=e;if(!r.length)return z(e);let o=0;return dt(r).pipe(rs(s=>function xq(t,n,e,i){const r=t.routeConfig,o=t._resolve;return void 0!==r?.title&&!PT(r)&&(o[nc]=r.title),function Cq(t,n,e,i){const r=function Dq(t){return[...Object.keys(t),...Object.getOwnPropertySymbols(t)]}(t);if(0===r.length)return z({});const o={};return dt(r).pipe(yt(s=>function Eq(t,n,e,i){const r=hc(n)??i,o=ys(t,r);return dr(o.resolve?o.resolve(n,e):r.runInContext(()=>o(n,e)))}(t[s],n,e,i).pipe(qr(),Ee(a=>{o[s]=a}))),b_(1),function VG(t){return pe(()=>t)}(o),Ri(s=>IT(s)?Qt:hs(s)))}(o,t,n,i).pipe(pe(s=>(t._resolvedData=s,t.data=bT(t,e).resolve,r&&PT(r)&&(t.data[nc]=r.title),null)))}(s.route,i,t,n)),Ee(()=>o++),b_(1),yt(s=>o===r.length?z(e):Qt))})}(e.paramsInheritanceStrategy,this.environmentInjector),Ee({next:()=>d=!0,complete:()=>{d||this.cancelNavigationTransition(l,"",2)}}))}),Ee(l=>{const d=new _W(l.id,this.urlSerializer.serialize(l.extractedUrl),this.urlSerializer.serialize(l.urlAfterRedirects),l.targetSnapshot);this.events.next(d)}))}),N_(c=>{const l=d=>{const u=[];d.routeConfig?.loadComponent&&!d.routeConfig._loadedComponent&&u.push(this.configLoader.loadComponent(d.routeConfig).pipe(Ee(h=>{d.component=h}),pe(()=>{})));for(const h of d.children)u.push(...l(h));return u};return vd(l(c.targetSnapshot.root)).pipe(Du(),vt(1))}),N_(()=>this.afterPreactivation()),pe(c=>{const l=function IW(t,n,e){const i=uc(t,n._root,e?e._root:void 0);return new gT(i,n)}(e.routeReuseStrategy,c.targetSnapshot,c.currentRouterState);return this.currentTransition=o={...c,targetRouterState:l},o}),Ee(()=>{this.events.next(new D_)}),((t,n,e,i)=>pe(r=>(new BW(n,r.targetRouterState,r.currentRouterState,e,i).activate(t),r)))(this.rootContexts,e.routeReuseStrategy,c=>this.events.next(c),this.inputBindingEnabled),vt(1),Ee({next:c=>{s=!0,this.lastSuccessfulNavigation=this.currentNavigation,this.events.next(new ur(c.id,this.urlSerializer.serialize(c.extractedUrl),this.urlSerializer.serialize(c.urlAfterRedirects))),e.titleStrategy?.updateTitle(c.targetRouterState.snapshot),c.resolve(!0)},complete:()=>{s=!0}}),Re(this.transitionAbortSubject.pipe(Ee(c=>{throw c}))),rr(()=>{s||a||this.cancelNavigationTransition(o,"",1),this.currentNavigation?.id===o.id&&(this.currentNavigation=null)}),Ri(c=>{if(a=!0,ET(c))this.events.next(new lc(o.id,this.urlSerializer.serialize(o.extractedUrl),c.message,c.cancellationCode)),function kW(t){return ET(t)&&Zr(t.url)}(c)?this.events.next(new E_(c.url)):o.resolve(!1);else{this.events.next(new Ru(o.id,this.urlSerializer.serialize(o.extractedUrl),c,o.targetSnapshot??void 0));try{o.resolve(e.errorHandler(c))}catch(l){o.reject(l)}}return Qt}))}))}cancelNavigationTransition(e,i,r){const o=new lc(e.id,this.urlSerializer.serialize(e.extractedUrl),i,r);this.events.next(o),e.resolve(!1)}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();function BT(t){return t!==cc}let VT=(()=>{class t{buildTitle(e){let i,r=e.root;for(;void 0!==r;)i=this.getResolvedTitleForRoute(r)??i,r=r.children.find(o=>o.outlet===de);return i}getResolvedTitleForRoute(e){return e.data[nc]}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:function(){return T(Iq)},providedIn:"root"})}return t})(),Iq=(()=>{class t extends VT{constructor(e){super(),this.title=e}updateTitle(e){const i=this.buildTitle(e);void 0!==i&&this.title.setTitle(i)}static#e=this.\u0275fac=function(i){return new(i||t)(b(cE))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})(),Tq=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:function(){return T(kq)},providedIn:"root"})}return t})();class Aq{shouldDetach(n){return!1}store(n,e){}shouldAttach(n){return!1}retrieve(n){return null}shouldReuseRoute(n,e){return n.routeConfig===e.routeConfig}}let kq=(()=>{class t extends Aq{static#e=this.\u0275fac=function(){let e;return function(r){return(e||(e=fe(t)))(r||t)}}();static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();const ju=new E("",{providedIn:"root",factory:()=>({})});let Rq=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:function(){return T(Oq)},providedIn:"root"})}return t})(),Oq=(()=>{class t{shouldProcessUrl(e){return!0}extract(e){return e}merge(e,i){return e}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();var gc=function(t){return t[t.COMPLETE=0]="COMPLETE",t[t.FAILED=1]="FAILED",t[t.REDIRECTING=2]="REDIRECTING",t}(gc||{});function jT(t,n){t.events.pipe(Ze(e=>e instanceof ur||e instanceof lc||e instanceof Ru||e instanceof _s),pe(e=>e instanceof ur||e instanceof _s?gc.COMPLETE:e instanceof lc&&(0===e.code||1===e.code)?gc.REDIRECTING:gc.FAILED),Ze(e=>e!==gc.REDIRECTING),vt(1)).subscribe(()=>{n()})}function Fq(t){throw t}function Nq(t,n,e){return n.parse("/")}const Pq={paths:"exact",fragment:"ignored",matrixParams:"ignored",queryParams:"exact"},Lq={paths:"subset",fragment:"ignored",matrixParams:"ignored",queryParams:"subset"};let Mn=(()=>{class t{get navigationId(){return this.navigationTransitions.navigationId}get browserPageId(){return"computed"!==this.canceledNavigationResolution?this.currentPageId:this.location.getState()?.\u0275routerPageId??this.currentPageId}get events(){return this._events}constructor(){this.disposed=!1,this.currentPageId=0,this.console=T(jC),this.isNgZoneEnabled=!1,this._events=new U,this.options=T(ju,{optional:!0})||{},this.pendingTasks=T(Zl),this.errorHandler=this.options.errorHandler||Fq,this.malformedUriErrorHandler=this.options.malformedUriErrorHandler||Nq,this.navigated=!1,this.lastSuccessfulId=-1,this.urlHandlingStrategy=T(Rq),this.routeReuseStrategy=T(Tq),this.titleStrategy=T(VT),this.onSameUrlNavigation=this.options.onSameUrlNavigation||"ignore",this.paramsInheritanceStrategy=this.options.paramsInheritanceStrategy||"emptyOnly",this.urlUpdateStrategy=this.options.urlUpdateStrategy||"deferred",this.canceledNavigationResolution=this.options.canceledNavigationResolution||"replace",this.config=T(Cs,{optional:!0})?.flat()??[],this.navigationTransitions=T(Vu),this.urlSerializer=T(rc),this.location=T(Ma),this.componentInputBindingEnabled=!!T(Fu,{optional:!0}),this.eventsSubscription=new Le,this.isNgZoneEnabled=T(j)instanceof j&&j.isInAngularZone(),this.resetConfig(this.config),this.currentUrlTree=new gs,this.rawUrlTree=this.currentUrlTree,this.browserUrlTree=this.currentUrlTree,this.routerState=_T(0,null),this.navigationTransitions.setupNavigations(this,this.currentUrlTree,this.routerState).subscribe(e=>{this.lastSuccessfulId=e.id,this.currentPageId=this.browserPageId},e=>{this.console.warn(`Unhandled Navigation Error: ${e}`)}),this.subscribeToNavigationEvents()}subscribeToNavigationEvents(){const e=this.navigationTransitions.events.subscribe(i=>{try{const{currentTransition:r}=this.navigationTransitions;if(null===r)return void(HT(i)&&this._events.next(i));if(i instanceof ku)BT(r.source)&&(this.browserUrlTree=r.extractedUrl);else if(i instanceof _s)this.rawUrlTree=r.rawUrl;else if(i instanceof fT){if("eager"===this.urlUpdateStrategy){if(!r.extras.skipLocationChange){const o=this.urlHandlingStrategy.merge(r.urlAfterRedirects,r.rawUrl);this.setBrowserUrl(o,r)}this.browserUrlTree=r.urlAfterRedirects}}else if(i instanceof D_)this.currentUrlTree=r.urlAfterRedirects,this.rawUrlTree=this.urlHandlingStrategy.merge(r.urlAfterRedirects,r.rawUrl),this.routerState=r.targetRouterState,"deferred"===this.urlUpdateStrategy&&(r.extras.skipLocationChange||this.setBrowserUrl(this.rawUrlTree,r),this.browserUrlTree=r.urlAfterRedirects);else if(i instanceof lc)0!==i.code&&1!==i.code&&(this.navigated=!0),(3===i.code||2===i.code)&&this.restoreHistory(r);else if(i instanceof E_){const o=this.urlHandlingStrategy.merge(i.url,r.currentRawUrl),s={skipLocationChange:r.extras.skipLocationChange,replaceUrl:"eager"===this.urlUpdateStrategy||BT(r.source)};this.scheduleNavigation(o,cc,null,s,{resolve:r.resolve,reject:r.reject,promise:r.promise})}i instanceof Ru&&this.restoreHistory(r,!0),i instanceof ur&&(this.navigated=!0),HT(i)&&this._events.next(i)}catch(r){this.navigationTransitions.transitionAbortSubject.next(r)}});this.eventsSubscription.add(e)}resetRootComponentType(e){this.routerState.root.component=e,this.navigationTransitions.rootComponentType=e}initialNavigation(){if(this.setUpLocationChangeListener(),!this.navigationTransitions.hasRequestedNavigation){const e=this.location.getState();this.navigateToSyncWithBrowser(this.location.path(!0),cc,e)}}setUpLocationChangeListener(){this.locationSubscription||(this.locationSubscription=this.location.subscribe(e=>{const i="popstate"===e.type?"popstate":"hashchange";"popstate"===i&&setTimeout(()=>{this.navigateToSyncWithBrowser(e.url,i,e.state)},0)}))}navigateToSyncWithBrowser(e,i,r){const o={replaceUrl:!0},s=r?.navigationId?r:null;if(r){const c={...r};delete c.navigationId,delete c.\u0275routerPageId,0!==Object.keys(c).length&&(o.state=c)}const a=this.parseUrl(e);this.scheduleNavigation(a,i,s,o)}get url(){return this.serializeUrl(this.currentUrlTree)}getCurrentNavigation(){return this.navigationTransitions.currentNavigation}get lastSuccessfulNavigation(){return this.navigationTransitions.lastSuccessfulNavigation}resetConfig(e){this.config=e.map(R_),this.navigated=!1,this.lastSuccessfulId=-1}ngOnDestroy(){this.dispose()}dispose(){this.navigationTransitions.complete(),this.locationSubscription&&(this.locationSubscription.unsubscribe(),this.locationSubscription=void 0),this.disposed=!0,this.eventsSubscription.unsubscribe()}createUrlTree(e,i={}){const{relativeTo:r,queryParams:o,fragment:s,queryParamsHandling:a,preserveFragment:c}=i,l=c?this.currentUrlTree.fragment:s;let u,d=null;switch(a){case"merge":d={...this.currentUrlTree.queryParams,...o};break;case"preserve":d=this.currentUrlTree.queryParams;break;default:d=o||null}null!==d&&(d=this.removeEmptyProps(d));try{u=sT(r?r.snapshot:this.routerState.snapshot.root)}catch{("string"!=typeof e[0]||!e[0].startsWith("/"))&&(e=[]),u=this.currentUrlTree.root}return aT(u,e,d,l??null)}navigateByUrl(e,i={skipLocationChange:!1}){const r=Zr(e)?e:this.parseUrl(e),o=this.urlHandlingStrategy.merge(r,this.rawUrlTree);return this.scheduleNavigation(o,cc,null,i)}navigate(e,i={skipLocationChange:!1}){return function Bq(t){for(let n=0;n<t.length;n++)if(null==t[n])throw new y(4008,!1)}(e),this.navigateByUrl(this.createUrlTree(e,i),i)}serializeUrl(e){return this.urlSerializer.serialize(e)}parseUrl(e){let i;try{i=this.urlSerializer.parse(e)}catch(r){i=this.malformedUriErrorHandler(r,this.urlSerializer,e)}return i}isActive(e,i){let r;if(r=!0===i?{...Pq}:!1===i?{...Lq}:i,Zr(e))return QI(this.currentUrlTree,e,r);const o=this.parseUrl(e);return QI(this.currentUrlTree,o,r)}removeEmptyProps(e){return Object.keys(e).reduce((i,r)=>{const o=e[r];return null!=o&&(i[r]=o),i},{})}scheduleNavigation(e,i,r,o,s){if(this.disposed)return Promise.resolve(!1);let a,c,l;s?(a=s.resolve,c=s.reject,l=s.promise):l=new Promise((u,h)=>{a=u,c=h});const d=this.pendingTasks.add();return jT(this,()=>{queueMicrotask(()=>this.pendingTasks.remove(d))}),this.navigationTransitions.handleNavigationRequest({source:i,restoredState:r,currentUrlTree:this.currentUrlTree,currentRawUrl:this.currentUrlTree,currentBrowserUrl:this.browserUrlTree,rawUrl:e,extras:o,resolve:a,reject:c,promise:l,currentSnapshot:this.routerState.snapshot,currentRouterState:this.routerState}),l.catch(u=>Promise.reject(u))}setBrowserUrl(e,i){const r=this.urlSerializer.serialize(e);if(this.location.isCurrentPathEqualTo(r)||i.extras.replaceUrl){const s={...i.extras.state,...this.generateNgRouterState(i.id,this.browserPageId)};this.location.replaceState(r,"",s)}else{const o={...i.extras.state,...this.generateNgRouterState(i.id,this.browserPageId+1)};this.location.go(r,"",o)}}restoreHistory(e,i=!1){if("computed"===this.canceledNavigationResolution){const o=this.currentPageId-this.browserPageId;0!==o?this.location.historyGo(o):this.currentUrlTree===this.getCurrentNavigation()?.finalUrl&&0===o&&(this.resetState(e),this.browserUrlTree=e.currentUrlTree,this.resetUrlToCurrentUrlTree())}else"replace"===this.canceledNavigationResolution&&(i&&this.resetState(e),this.resetUrlToCurrentUrlTree())}resetState(e){this.routerState=e.currentRouterState,this.currentUrlTree=e.currentUrlTree,this.rawUrlTree=this.urlHandlingStrategy.merge(this.currentUrlTree,e.rawUrl)}resetUrlToCurrentUrlTree(){this.location.replaceState(this.urlSerializer.serialize(this.rawUrlTree),"",this.generateNgRouterState(this.lastSuccessfulId,this.currentPageId))}generateNgRouterState(e,i){return"computed"===this.canceledNavigationResolution?{navigationId:e,\u0275routerPageId:i}:{navigationId:e}}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();function HT(t){return!(t instanceof D_||t instanceof E_)}class zT{}let Hq=(()=>{class t{constructor(e,i,r,o,s){this.router=e,this.injector=r,this.preloadingStrategy=o,this.loader=s}setUpPreloading(){this.subscription=this.router.events.pipe(Ze(e=>e instanceof ur),rs(()=>this.preload())).subscribe(()=>{})}preload(){return this.processRoutes(this.injector,this.router.config)}ngOnDestroy(){this.subscription&&this.subscription.unsubscribe()}processRoutes(e,i){const r=[];for(const o of i){o.providers&&!o._injector&&(o._injector=Op(o.providers,e,`Route: ${o.path}`));const s=o._injector??e,a=o._loadedInjector??s;(o.loadChildren&&!o._loadedRoutes&&void 0===o.canLoad||o.loadComponent&&!o._loadedComponent)&&r.push(this.preloadConfig(s,o)),(o.children||o._loadedRoutes)&&r.push(this.processRoutes(a,o.children??o._loadedRoutes))}return dt(r).pipe(no())}preloadConfig(e,i){return this.preloadingStrategy.preload(i,()=>{let r;r=i.loadChildren&&void 0===i.canLoad?this.loader.loadChildren(e,i):z(null);const o=r.pipe(yt(s=>null===s?z(void 0):(i._loadedRoutes=s.routes,i._loadedInjector=s.injector,this.processRoutes(s.injector??e,s.routes))));return i.loadComponent&&!i._loadedComponent?dt([o,this.loader.loadComponent(i)]).pipe(no()):o})}static#e=this.\u0275fac=function(i){return new(i||t)(b(Mn),b(HC),b(on),b(zT),b(P_))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac,providedIn:"root"})}return t})();const B_=new E("");let $T=(()=>{class t{constructor(e,i,r,o,s={}){this.urlSerializer=e,this.transitions=i,this.viewportScroller=r,this.zone=o,this.options=s,this.lastId=0,this.lastSource="imperative",this.restoredId=0,this.store={},s.scrollPositionRestoration=s.scrollPositionRestoration||"disabled",s.anchorScrolling=s.anchorScrolling||"disabled"}init(){"disabled"!==this.options.scrollPositionRestoration&&this.viewportScroller.setHistoryScrollRestoration("manual"),this.routerEventsSubscription=this.createScrollEvents(),this.scrollEventsSubscription=this.consumeScrollEvents()}createScrollEvents(){return this.transitions.events.subscribe(e=>{e instanceof ku?(this.store[this.lastId]=this.viewportScroller.getScrollPosition(),this.lastSource=e.navigationTrigger,this.restoredId=e.restoredState?e.restoredState.navigationId:0):e instanceof ur?(this.lastId=e.id,this.scheduleScrollEvent(e,this.urlSerializer.parse(e.urlAfterRedirects).fragment)):e instanceof _s&&0===e.code&&(this.lastSource=void 0,this.restoredId=0,this.scheduleScrollEvent(e,this.urlSerializer.parse(e.url).fragment))})}consumeScrollEvents(){return this.transitions.events.subscribe(e=>{e instanceof pT&&(e.position?"top"===this.options.scrollPositionRestoration?this.viewportScroller.scrollToPosition([0,0]):"enabled"===this.options.scrollPositionRestoration&&this.viewportScroller.scrollToPosition(e.position):e.anchor&&"enabled"===this.options.anchorScrolling?this.viewportScroller.scrollToAnchor(e.anchor):"disabled"!==this.options.scrollPositionRestoration&&this.viewportScroller.scrollToPosition([0,0]))})}scheduleScrollEvent(e,i){this.zone.runOutsideAngular(()=>{setTimeout(()=>{this.zone.run(()=>{this.transitions.events.next(new pT(e,"popstate"===this.lastSource?this.store[this.restoredId]:null,i))})},0)})}ngOnDestroy(){this.routerEventsSubscription?.unsubscribe(),this.scrollEventsSubscription?.unsubscribe()}static#e=this.\u0275fac=function(i){Il()};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();function Li(t,n){return{\u0275kind:t,\u0275providers:n}}function GT(){const t=T(We);return n=>{const e=t.get(Xi);if(n!==e.components[0])return;const i=t.get(Mn),r=t.get(WT);1===t.get(V_)&&i.initialNavigation(),t.get(qT,null,ge.Optional)?.setUpPreloading(),t.get(B_,null,ge.Optional)?.init(),i.resetRootComponentType(e.componentTypes[0]),r.closed||(r.next(),r.complete(),r.unsubscribe())}}const WT=new E("",{factory:()=>new U}),V_=new E("",{providedIn:"root",factory:()=>1}),qT=new E("");function Gq(t){return Li(0,[{provide:qT,useExisting:Hq},{provide:zT,useExisting:t}])}const YT=new E("ROUTER_FORROOT_GUARD"),qq=[Ma,{provide:rc,useClass:v_},Mn,dc,{provide:vs,useFactory:function UT(t){return t.routerState.root},deps:[Mn]},P_,[]];function Yq(){return new YC("Router",Mn)}let j_=(()=>{class t{constructor(e){}static forRoot(e,i){return{ngModule:t,providers:[qq,[],{provide:Cs,multi:!0,useValue:e},{provide:YT,useFactory:Xq,deps:[[Mn,new xr,new qs]]},{provide:ju,useValue:i||{}},i?.useHash?{provide:Fr,useClass:AV}:{provide:Fr,useClass:DD},{provide:B_,useFactory:()=>{const t=T(Zj),n=T(j),e=T(ju),i=T(Vu),r=T(rc);return e.scrollOffset&&t.setOffset(e.scrollOffset),new $T(r,i,t,n,e)}},i?.preloadingStrategy?Gq(i.preloadingStrategy).\u0275providers:[],{provide:YC,multi:!0,useFactory:Yq},i?.initialNavigation?Jq(i):[],i?.bindToComponentInputs?Li(8,[xT,{provide:Fu,useExisting:xT}]).\u0275providers:[],[{provide:ZT,useFactory:GT},{provide:em,multi:!0,useExisting:ZT}]]}}static forChild(e){return{ngModule:t,providers:[{provide:Cs,multi:!0,useValue:e}]}}static#e=this.\u0275fac=function(i){return new(i||t)(b(YT,8))};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({})}return t})();function Xq(t){return"guarded"}function Jq(t){return["disabled"===t.initialNavigation?Li(3,[{provide:Wp,multi:!0,useFactory:()=>{const n=T(Mn);return()=>{n.setUpLocationChangeListener()}}},{provide:V_,useValue:2}]).\u0275providers:[],"enabledBlocking"===t.initialNavigation?Li(2,[{provide:V_,useValue:0},{provide:Wp,multi:!0,deps:[We],useFactory:n=>{const e=n.get(IV,Promise.resolve());return()=>e.then(()=>new Promise(i=>{const r=n.get(Mn),o=n.get(WT);jT(r,()=>{i(!0)}),n.get(Vu).afterPreactivation=()=>(i(!0),o.closed?z(void 0):o),r.initialNavigation()}))}}]).\u0275providers:[]]}const ZT=new E(""),t7=[{path:"",component:WI}];let n7=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[j_.forRoot(t7),j_]})}return t})();function KT(t){return new y(3e3,!1)}function hr(t){switch(t.length){case 0:return new Ba;case 1:return t[0];default:return new JE(t)}}function QT(t,n,e=new Map,i=new Map){const r=[],o=[];let s=-1,a=null;if(n.forEach(c=>{const l=c.get("offset"),d=l==s,u=d&&a||new Map;c.forEach((h,p)=>{let m=p,g=h;if("offset"!==p)switch(m=t.normalizePropertyName(m,r),g){case"!":g=e.get(p);break;case ki:g=i.get(p);break;default:g=t.normalizeStyleValue(p,m,g,r)}u.set(m,g)}),d||o.push(u),a=u,s=l}),r.length)throw function S7(t){return new y(3502,!1)}();return o}function H_(t,n,e,i){switch(n){case"start":t.onStart(()=>i(e&&z_(e,"start",t)));break;case"done":t.onDone(()=>i(e&&z_(e,"done",t)));break;case"destroy":t.onDestroy(()=>i(e&&z_(e,"destroy",t)))}}function z_(t,n,e){const o=$_(t.element,t.triggerName,t.fromState,t.toState,n||t.phaseName,e.totalTime??t.totalTime,!!e.disabled),s=t._data;return null!=s&&(o._data=s),o}function $_(t,n,e,i,r="",o=0,s){return{element:t,triggerName:n,fromState:e,toState:i,phaseName:r,totalTime:o,disabled:!!s}}function un(t,n,e){let i=t.get(n);return i||t.set(n,i=e),i}function XT(t){const n=t.indexOf(":");return[t.substring(1,n),t.slice(n+1)]}const B7=(()=>typeof document>"u"?null:document.documentElement)();function U_(t){const n=t.parentNode||t.host||null;return n===B7?null:n}let Kr=null,JT=!1;function eA(t,n){for(;n;){if(n===t)return!0;n=U_(n)}return!1}function tA(t,n,e){if(e)return Array.from(t.querySelectorAll(n));const i=t.querySelector(n);return i?[i]:[]}let nA=(()=>{class t{validateStyleProperty(e){return function j7(t){Kr||(Kr=function H7(){return typeof document<"u"?document.body:null}()||{},JT=!!Kr.style&&"WebkitAppearance"in Kr.style);let n=!0;return Kr.style&&!function V7(t){return"ebkit"==t.substring(1,6)}(t)&&(n=t in Kr.style,!n&&JT&&(n="Webkit"+t.charAt(0).toUpperCase()+t.slice(1)in Kr.style)),n}(e)}matchesElement(e,i){return!1}containsElement(e,i){return eA(e,i)}getParentElement(e){return U_(e)}query(e,i,r){return tA(e,i,r)}computeStyle(e,i,r){return r||""}animate(e,i,r,o,s,a=[],c){return new Ba(r,o)}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})(),G_=(()=>{class t{static#e=this.NOOP=new nA}return t})();const z7=1e3,W_="ng-enter",Hu="ng-leave",zu="ng-trigger",$u=".ng-trigger",rA="ng-animating",q_=".ng-animating";function Bi(t){if("number"==typeof t)return t;const n=t.match(/^(-?[\.\d]+)(m?s)/);return!n||n.length<2?0:Y_(parseFloat(n[1]),n[2])}function Y_(t,n){return"s"===n?t*z7:t}function Uu(t,n,e){return t.hasOwnProperty("duration")?t:function U7(t,n,e){let r,o=0,s="";if("string"==typeof t){const a=t.match(/^(-?[\.\d]+)(m?s)(?:\s+(-?[\.\d]+)(m?s))?(?:\s+([-a-z]+(?:\(.+?\))?))?$/i);if(null===a)return n.push(KT()),{duration:0,delay:0,easing:""};r=Y_(parseFloat(a[1]),a[2]);const c=a[3];null!=c&&(o=Y_(parseFloat(c),a[4]));const l=a[5];l&&(s=l)}else r=t;if(!e){let a=!1,c=n.length;r<0&&(n.push(function r7(){return new y(3100,!1)}()),a=!0),o<0&&(n.push(function o7(){return new y(3101,!1)}()),a=!0),a&&n.splice(c,0,KT())}return{duration:r,delay:o,easing:s}}(t,n,e)}function _c(t,n={}){return Object.keys(t).forEach(e=>{n[e]=t[e]}),n}function oA(t){const n=new Map;return Object.keys(t).forEach(e=>{n.set(e,t[e])}),n}function fr(t,n=new Map,e){if(e)for(let[i,r]of e)n.set(i,r);for(let[i,r]of t)n.set(i,r);return n}function di(t,n,e){n.forEach((i,r)=>{const o=K_(r);e&&!e.has(r)&&e.set(r,t.style[o]),t.style[o]=i})}function Qr(t,n){n.forEach((e,i)=>{const r=K_(i);t.style[r]=""})}function bc(t){return Array.isArray(t)?1==t.length?t[0]:XE(t):t}const Z_=new RegExp("{{\\s*(.+?)\\s*}}","g");function aA(t){let n=[];if("string"==typeof t){let e;for(;e=Z_.exec(t);)n.push(e[1]);Z_.lastIndex=0}return n}function vc(t,n,e){const i=t.toString(),r=i.replace(Z_,(o,s)=>{let a=n[s];return null==a&&(e.push(function a7(t){return new y(3003,!1)}()),a=""),a.toString()});return r==i?t:r}function Gu(t){const n=[];let e=t.next();for(;!e.done;)n.push(e.value),e=t.next();return n}const q7=/-+([a-z0-9])/g;function K_(t){return t.replace(q7,(...n)=>n[1].toUpperCase())}function hn(t,n,e){switch(n.type){case 7:return t.visitTrigger(n,e);case 0:return t.visitState(n,e);case 1:return t.visitTransition(n,e);case 2:return t.visitSequence(n,e);case 3:return t.visitGroup(n,e);case 4:return t.visitAnimate(n,e);case 5:return t.visitKeyframes(n,e);case 6:return t.visitStyle(n,e);case 8:return t.visitReference(n,e);case 9:return t.visitAnimateChild(n,e);case 10:return t.visitAnimateRef(n,e);case 11:return t.visitQuery(n,e);case 12:return t.visitStagger(n,e);default:throw function c7(t){return new y(3004,!1)}()}}function cA(t,n){return window.getComputedStyle(t)[n]}const Wu="*";function K7(t,n){const e=[];return"string"==typeof t?t.split(/\s*,\s*/).forEach(i=>function Q7(t,n,e){if(":"==t[0]){const c=function X7(t,n){switch(t){case":enter":return"void => *";case":leave":return"* => void";case":increment":return(e,i)=>parseFloat(i)>parseFloat(e);case":decrement":return(e,i)=>parseFloat(i)<parseFloat(e);default:return n.push(function x7(t){return new y(3016,!1)}()),"* => *"}}(t,e);if("function"==typeof c)return void n.push(c);t=c}const i=t.match(/^(\*|[-\w]+)\s*(<?[=-]>)\s*(\*|[-\w]+)$/);if(null==i||i.length<4)return e.push(function w7(t){return new y(3015,!1)}()),n;const r=i[1],o=i[2],s=i[3];n.push(lA(r,s));"<"==o[0]&&!(r==Wu&&s==Wu)&&n.push(lA(s,r))}(i,e,n)):e.push(t),e}const qu=new Set(["true","1"]),Yu=new Set(["false","0"]);function lA(t,n){const e=qu.has(t)||Yu.has(t),i=qu.has(n)||Yu.has(n);return(r,o)=>{let s=t==Wu||t==r,a=n==Wu||n==o;return!s&&e&&"boolean"==typeof r&&(s=r?qu.has(t):Yu.has(t)),!a&&i&&"boolean"==typeof o&&(a=o?qu.has(n):Yu.has(n)),s&&a}}const J7=new RegExp("s*:selfs*,?","g");function Q_(t,n,e,i){return new e9(t).build(n,e,i)}class e9{constructor(n){this._driver=n}build(n,e,i){const r=new i9(e);return this._resetContextStyleTimingState(r),hn(this,bc(n),r)}_resetContextStyleTimingState(n){n.currentQuerySelector="",n.collectedStyles=new Map,n.collectedStyles.set("",new Map),n.currentTime=0}visitTrigger(n,e){let i=e.queryCount=0,r=e.depCount=0;const o=[],s=[];return"@"==n.name.charAt(0)&&e.errors.push(function d7(){return new y(3006,!1)}()),n.definitions.forEach(a=>{if(this._resetContextStyleTimingState(e),0==a.type){const c=a,l=c.name;l.toString().split(/\s*,\s*/).forEach(d=>{c.name=d,o.push(this.visitState(c,e))}),c.name=l}else if(1==a.type){const c=this.visitTransition(a,e);i+=c.queryCount,r+=c.depCount,s.push(c)}else e.errors.push(function u7(){return new y(3007,!1)}())}),{type:7,name:n.name,states:o,transitions:s,queryCount:i,depCount:r,options:null}}visitState(n,e){const i=this.visitStyle(n.styles,e),r=n.options&&n.options.params||null;if(i.containsDynamicStyles){const o=new Set,s=r||{};i.styles.forEach(a=>{a instanceof Map&&a.forEach(c=>{aA(c).forEach(l=>{s.hasOwnProperty(l)||o.add(l)})})}),o.size&&(Gu(o.values()),e.errors.push(function h7(t,n){return new y(3008,!1)}()))}return{type:0,name:n.name,style:i,options:r?{params:r}:null}}visitTransition(n,e){e.queryCount=0,e.depCount=0;const i=hn(this,bc(n.animation),e);return{type:1,matchers:K7(n.expr,e.errors),animation:i,queryCount:e.queryCount,depCount:e.depCount,options:Xr(n.options)}}visitSequence(n,e){return{type:2,steps:n.steps.map(i=>hn(this,i,e)),options:Xr(n.options)}}visitGroup(n,e){const i=e.currentTime;let r=0;const o=n.steps.map(s=>{e.currentTime=i;const a=hn(this,s,e);return r=Math.max(r,e.currentTime),a});return e.currentTime=r,{type:3,steps:o,options:Xr(n.options)}}visitAnimate(n,e){const i=function o9(t,n){if(t.hasOwnProperty("duration"))return t;if("number"==typeof t)return X_(Uu(t,n).duration,0,"");const e=t;if(e.split(/\s+/).some(o=>"{"==o.charAt(0)&&"{"==o.charAt(1))){const o=X_(0,0,"");return o.dynamic=!0,o.strValue=e,o}const r=Uu(e,n);return X_(r.duration,r.delay,r.easing)}(n.timings,e.errors);e.currentAnimateTimings=i;let r,o=n.styles?n.styles:Ke({});if(5==o.type)r=this.visitKeyframes(o,e);else{let s=n.styles,a=!1;if(!s){a=!0;const l={};i.easing&&(l.easing=i.easing),s=Ke(l)}e.currentTime+=i.duration+i.delay;const c=this.visitStyle(s,e);c.isEmptyStep=a,r=c}return e.currentAnimateTimings=null,{type:4,timings:i,style:r,options:null}}visitStyle(n,e){const i=this._makeStyleAst(n,e);return this._validateStyleAst(i,e),i}_makeStyleAst(n,e){const i=[],r=Array.isArray(n.styles)?n.styles:[n.styles];for(let a of r)"string"==typeof a?a===ki?i.push(a):e.errors.push(new y(3002,!1)):i.push(oA(a));let o=!1,s=null;return i.forEach(a=>{if(a instanceof Map&&(a.has("easing")&&(s=a.get("easing"),a.delete("easing")),!o))for(let c of a.values())if(c.toString().indexOf("{{")>=0){o=!0;break}}),{type:6,styles:i,easing:s,offset:n.offset,containsDynamicStyles:o,options:null}}_validateStyleAst(n,e){const i=e.currentAnimateTimings;let r=e.currentTime,o=e.currentTime;i&&o>0&&(o-=i.duration+i.delay),n.styles.forEach(s=>{"string"!=typeof s&&s.forEach((a,c)=>{const l=e.collectedStyles.get(e.currentQuerySelector),d=l.get(c);let u=!0;d&&(o!=r&&o>=d.startTime&&r<=d.endTime&&(e.errors.push(function p7(t,n,e,i,r){return new y(3010,!1)}()),u=!1),o=d.startTime),u&&l.set(c,{startTime:o,endTime:r}),e.options&&function W7(t,n,e){const i=n.params||{},r=aA(t);r.length&&r.forEach(o=>{i.hasOwnProperty(o)||e.push(function s7(t){return new y(3001,!1)}())})}(a,e.options,e.errors)})})}visitKeyframes(n,e){const i={type:5,styles:[],options:null};if(!e.currentAnimateTimings)return e.errors.push(function m7(){return new y(3011,!1)}()),i;let o=0;const s=[];let a=!1,c=!1,l=0;const d=n.steps.map(C=>{const v=this._makeStyleAst(C,e);let R=null!=v.offset?v.offset:function r9(t){if("string"==typeof t)return null;let n=null;if(Array.isArray(t))t.forEach(e=>{if(e instanceof Map&&e.has("offset")){const i=e;n=parseFloat(i.get("offset")),i.delete("offset")}});else if(t instanceof Map&&t.has("offset")){const e=t;n=parseFloat(e.get("offset")),e.delete("offset")}return n}(v.styles),L=0;return null!=R&&(o++,L=v.offset=R),c=c||L<0||L>1,a=a||L<l,l=L,s.push(L),v});c&&e.errors.push(function g7(){return new y(3012,!1)}()),a&&e.errors.push(function _7(){return new y(3200,!1)}());const u=n.steps.length;let h=0;o>0&&o<u?e.errors.push(function b7(){return new y(3202,!1)}()):0==o&&(h=1/(u-1));const p=u-1,m=e.currentTime,g=e.currentAnimateTimings,_=g.duration;return d.forEach((C,v)=>{const R=h>0?v==p?1:h*v:s[v],L=R*_;e.currentTime=m+g.delay+L,g.duration=L,this._validateStyleAst(C,e),C.offset=R,i.styles.push(C)}),i}visitReference(n,e){return{type:8,animation:hn(this,bc(n.animation),e),options:Xr(n.options)}}visitAnimateChild(n,e){return e.depCount++,{type:9,options:Xr(n.options)}}visitAnimateRef(n,e){return{type:10,animation:this.visitReference(n.animation,e),options:Xr(n.options)}}visitQuery(n,e){const i=e.currentQuerySelector,r=n.options||{};e.queryCount++,e.currentQuery=n;const[o,s]=function t9(t){const n=!!t.split(/\s*,\s*/).find(e=>":self"==e);return n&&(t=t.replace(J7,"")),t=t.replace(/@\*/g,$u).replace(/@\w+/g,e=>$u+"-"+e.slice(1)).replace(/:animating/g,q_),[t,n]}(n.selector);e.currentQuerySelector=i.length?i+" "+o:o,un(e.collectedStyles,e.currentQuerySelector,new Map);const a=hn(this,bc(n.animation),e);return e.currentQuery=null,e.currentQuerySelector=i,{type:11,selector:o,limit:r.limit||0,optional:!!r.optional,includeSelf:s,animation:a,originalSelector:n.selector,options:Xr(n.options)}}visitStagger(n,e){e.currentQuery||e.errors.push(function v7(){return new y(3013,!1)}());const i="full"===n.timings?{duration:0,delay:0,easing:"full"}:Uu(n.timings,e.errors,!0);return{type:12,animation:hn(this,bc(n.animation),e),timings:i,options:null}}}class i9{constructor(n){this.errors=n,this.queryCount=0,this.depCount=0,this.currentTransition=null,this.currentQuery=null,this.currentQuerySelector=null,this.currentAnimateTimings=null,this.currentTime=0,this.collectedStyles=new Map,this.options=null,this.unsupportedCSSPropertiesFound=new Set}}function Xr(t){return t?(t=_c(t)).params&&(t.params=function n9(t){return t?_c(t):null}(t.params)):t={},t}function X_(t,n,e){return{duration:t,delay:n,easing:e}}function J_(t,n,e,i,r,o,s=null,a=!1){return{type:1,element:t,keyframes:n,preStyleProps:e,postStyleProps:i,duration:r,delay:o,totalTime:r+o,easing:s,subTimeline:a}}class Zu{constructor(){this._map=new Map}get(n){return this._map.get(n)||[]}append(n,e){let i=this._map.get(n);i||this._map.set(n,i=[]),i.push(...e)}has(n){return this._map.has(n)}clear(){this._map.clear()}}const c9=new RegExp(":enter","g"),d9=new RegExp(":leave","g");function eb(t,n,e,i,r,o=new Map,s=new Map,a,c,l=[]){return(new u9).buildKeyframes(t,n,e,i,r,o,s,a,c,l)}class u9{buildKeyframes(n,e,i,r,o,s,a,c,l,d=[]){l=l||new Zu;const u=new tb(n,e,l,r,o,d,[]);u.options=c;const h=c.delay?Bi(c.delay):0;u.currentTimeline.delayNextStep(h),u.currentTimeline.setStyles([s],null,u.errors,c),hn(this,i,u);const p=u.timelines.filter(m=>m.containsAnimation());if(p.length&&a.size){let m;for(let g=p.length-1;g>=0;g--){const _=p[g];if(_.element===e){m=_;break}}m&&!m.allowOnlyTimelineStyles()&&m.setStyles([a],null,u.errors,c)}return p.length?p.map(m=>m.buildKeyframes()):[J_(e,[],[],[],0,h,"",!1)]}visitTrigger(n,e){}visitState(n,e){}visitTransition(n,e){}visitAnimateChild(n,e){const i=e.subInstructions.get(e.element);if(i){const r=e.createSubContext(n.options),o=e.currentTimeline.currentTime,s=this._visitSubInstructions(i,r,r.options);o!=s&&e.transformIntoNewTimeline(s)}e.previousNode=n}visitAnimateRef(n,e){const i=e.createSubContext(n.options);i.transformIntoNewTimeline(),this._applyAnimationRefDelays([n.options,n.animation.options],e,i),this.visitReference(n.animation,i),e.transformIntoNewTimeline(i.currentTimeline.currentTime),e.previousNode=n}_applyAnimationRefDelays(n,e,i){for(const r of n){const o=r?.delay;if(o){const s="number"==typeof o?o:Bi(vc(o,r?.params??{},e.errors));i.delayNextStep(s)}}}_visitSubInstructions(n,e,i){let o=e.currentTimeline.currentTime;const s=null!=i.duration?Bi(i.duration):null,a=null!=i.delay?Bi(i.delay):null;return 0!==s&&n.forEach(c=>{const l=e.appendInstructionToTimeline(c,s,a);o=Math.max(o,l.duration+l.delay)}),o}visitReference(n,e){e.updateOptions(n.options,!0),hn(this,n.animation,e),e.previousNode=n}visitSequence(n,e){const i=e.subContextCount;let r=e;const o=n.options;if(o&&(o.params||o.delay)&&(r=e.createSubContext(o),r.transformIntoNewTimeline(),null!=o.delay)){6==r.previousNode.type&&(r.currentTimeline.snapshotCurrentStyles(),r.previousNode=Ku);const s=Bi(o.delay);r.delayNextStep(s)}n.steps.length&&(n.steps.forEach(s=>hn(this,s,r)),r.currentTimeline.applyStylesToKeyframe(),r.subContextCount>i&&r.transformIntoNewTimeline()),e.previousNode=n}visitGroup(n,e){const i=[];let r=e.currentTimeline.currentTime;const o=n.options&&n.options.delay?Bi(n.options.delay):0;n.steps.forEach(s=>{const a=e.createSubContext(n.options);o&&a.delayNextStep(o),hn(this,s,a),r=Math.max(r,a.currentTimeline.currentTime),i.push(a.currentTimeline)}),i.forEach(s=>e.currentTimeline.mergeTimelineCollectedStyles(s)),e.transformIntoNewTimeline(r),e.previousNode=n}_visitTiming(n,e){if(n.dynamic){const i=n.strValue;return Uu(e.params?vc(i,e.params,e.errors):i,e.errors)}return{duration:n.duration,delay:n.delay,easing:n.easing}}visitAnimate(n,e){const i=e.currentAnimateTimings=this._visitTiming(n.timings,e),r=e.currentTimeline;i.delay&&(e.incrementTime(i.delay),r.snapshotCurrentStyles());const o=n.style;5==o.type?this.visitKeyframes(o,e):(e.incrementTime(i.duration),this.visitStyle(o,e),r.applyStylesToKeyframe()),e.currentAnimateTimings=null,e.previousNode=n}visitStyle(n,e){const i=e.currentTimeline,r=e.currentAnimateTimings;!r&&i.hasCurrentStyleProperties()&&i.forwardFrame();const o=r&&r.easing||n.easing;n.isEmptyStep?i.applyEmptyStep(o):i.setStyles(n.styles,o,e.errors,e.options),e.previousNode=n}visitKeyframes(n,e){const i=e.currentAnimateTimings,r=e.currentTimeline.duration,o=i.duration,a=e.createSubContext().currentTimeline;a.easing=i.easing,n.styles.forEach(c=>{a.forwardTime((c.offset||0)*o),a.setStyles(c.styles,c.easing,e.errors,e.options),a.applyStylesToKeyframe()}),e.currentTimeline.mergeTimelineCollectedStyles(a),e.transformIntoNewTimeline(r+o),e.previousNode=n}visitQuery(n,e){const i=e.currentTimeline.currentTime,r=n.options||{},o=r.delay?Bi(r.delay):0;o&&(6===e.previousNode.type||0==i&&e.currentTimeline.hasCurrentStyleProperties())&&(e.currentTimeline.snapshotCurrentStyles(),e.previousNode=Ku);let s=i;const a=e.invokeQuery(n.selector,n.originalSelector,n.limit,n.includeSelf,!!r.optional,e.errors);e.currentQueryTotal=a.length;let c=null;a.forEach((l,d)=>{e.currentQueryIndex=d;const u=e.createSubContext(n.options,l);o&&u.delayNextStep(o),l===e.element&&(c=u.currentTimeline),hn(this,n.animation,u),u.currentTimeline.applyStylesToKeyframe(),s=Math.max(s,u.currentTimeline.currentTime)}),e.currentQueryIndex=0,e.currentQueryTotal=0,e.transformIntoNewTimeline(s),c&&(e.currentTimeline.mergeTimelineCollectedStyles(c),e.currentTimeline.snapshotCurrentStyles()),e.previousNode=n}visitStagger(n,e){const i=e.parentContext,r=e.currentTimeline,o=n.timings,s=Math.abs(o.duration),a=s*(e.currentQueryTotal-1);let c=s*e.currentQueryIndex;switch(o.duration<0?"reverse":o.easing){case"reverse":c=a-c;break;case"full":c=i.currentStaggerTime}const d=e.currentTimeline;c&&d.delayNextStep(c);const u=d.currentTime;hn(this,n.animation,e),e.previousNode=n,i.currentStaggerTime=r.currentTime-u+(r.startTime-i.currentTimeline.startTime)}}const Ku={};class tb{constructor(n,e,i,r,o,s,a,c){this._driver=n,this.element=e,this.subInstructions=i,this._enterClassName=r,this._leaveClassName=o,this.errors=s,this.timelines=a,this.parentContext=null,this.currentAnimateTimings=null,this.previousNode=Ku,this.subContextCount=0,this.options={},this.currentQueryIndex=0,this.currentQueryTotal=0,this.currentStaggerTime=0,this.currentTimeline=c||new Qu(this._driver,e,0),a.push(this.currentTimeline)}get params(){return this.options.params}updateOptions(n,e){if(!n)return;const i=n;let r=this.options;null!=i.duration&&(r.duration=Bi(i.duration)),null!=i.delay&&(r.delay=Bi(i.delay));const o=i.params;if(o){let s=r.params;s||(s=this.options.params={}),Object.keys(o).forEach(a=>{(!e||!s.hasOwnProperty(a))&&(s[a]=vc(o[a],s,this.errors))})}}_copyOptions(){const n={};if(this.options){const e=this.options.params;if(e){const i=n.params={};Object.keys(e).forEach(r=>{i[r]=e[r]})}}return n}createSubContext(n=null,e,i){const r=e||this.element,o=new tb(this._driver,r,this.subInstructions,this._enterClassName,this._leaveClassName,this.errors,this.timelines,this.currentTimeline.fork(r,i||0));return o.previousNode=this.previousNode,o.currentAnimateTimings=this.currentAnimateTimings,o.options=this._copyOptions(),o.updateOptions(n),o.currentQueryIndex=this.currentQueryIndex,o.currentQueryTotal=this.currentQueryTotal,o.parentContext=this,this.subContextCount++,o}transformIntoNewTimeline(n){return this.previousNode=Ku,this.currentTimeline=this.currentTimeline.fork(this.element,n),this.timelines.push(this.currentTimeline),this.currentTimeline}appendInstructionToTimeline(n,e,i){const r={duration:e??n.duration,delay:this.currentTimeline.currentTime+(i??0)+n.delay,easing:""},o=new h9(this._driver,n.element,n.keyframes,n.preStyleProps,n.postStyleProps,r,n.stretchStartingKeyframe);return this.timelines.push(o),r}incrementTime(n){this.currentTimeline.forwardTime(this.currentTimeline.duration+n)}delayNextStep(n){n>0&&this.currentTimeline.delayNextStep(n)}invokeQuery(n,e,i,r,o,s){let a=[];if(r&&a.push(this.element),n.length>0){n=(n=n.replace(c9,"."+this._enterClassName)).replace(d9,"."+this._leaveClassName);let l=this._driver.query(this.element,n,1!=i);0!==i&&(l=i<0?l.slice(l.length+i,l.length):l.slice(0,i)),a.push(...l)}return!o&&0==a.length&&s.push(function y7(t){return new y(3014,!1)}()),a}}class Qu{constructor(n,e,i,r){this._driver=n,this.element=e,this.startTime=i,this._elementTimelineStylesLookup=r,this.duration=0,this.easing=null,this._previousKeyframe=new Map,this._currentKeyframe=new Map,this._keyframes=new Map,this._styleSummary=new Map,this._localTimelineStyles=new Map,this._pendingStyles=new Map,this._backFill=new Map,this._currentEmptyStepKeyframe=null,this._elementTimelineStylesLookup||(this._elementTimelineStylesLookup=new Map),this._globalTimelineStyles=this._elementTimelineStylesLookup.get(e),this._globalTimelineStyles||(this._globalTimelineStyles=this._localTimelineStyles,this._elementTimelineStylesLookup.set(e,this._localTimelineStyles)),this._loadKeyframe()}containsAnimation(){switch(this._keyframes.size){case 0:return!1;case 1:return this.hasCurrentStyleProperties();default:return!0}}hasCurrentStyleProperties(){return this._currentKeyframe.size>0}get currentTime(){return this.startTime+this.duration}delayNextStep(n){const e=1===this._keyframes.size&&this._pendingStyles.size;this.duration||e?(this.forwardTime(this.currentTime+n),e&&this.snapshotCurrentStyles()):this.startTime+=n}fork(n,e){return this.applyStylesToKeyframe(),new Qu(this._driver,n,e||this.currentTime,this._elementTimelineStylesLookup)}_loadKeyframe(){this._currentKeyframe&&(this._previousKeyframe=this._currentKeyframe),this._currentKeyframe=this._keyframes.get(this.duration),this._currentKeyframe||(this._currentKeyframe=new Map,this._keyframes.set(this.duration,this._currentKeyframe))}forwardFrame(){this.duration+=1,this._loadKeyframe()}forwardTime(n){this.applyStylesToKeyframe(),this.duration=n,this._loadKeyframe()}_updateStyle(n,e){this._localTimelineStyles.set(n,e),this._globalTimelineStyles.set(n,e),this._styleSummary.set(n,{time:this.currentTime,value:e})}allowOnlyTimelineStyles(){return this._currentEmptyStepKeyframe!==this._currentKeyframe}applyEmptyStep(n){n&&this._previousKeyframe.set("easing",n);for(let[e,i]of this._globalTimelineStyles)this._backFill.set(e,i||ki),this._currentKeyframe.set(e,ki);this._currentEmptyStepKeyframe=this._currentKeyframe}setStyles(n,e,i,r){e&&this._previousKeyframe.set("easing",e);const o=r&&r.params||{},s=function f9(t,n){const e=new Map;let i;return t.forEach(r=>{if("*"===r){i=i||n.keys();for(let o of i)e.set(o,ki)}else fr(r,e)}),e}(n,this._globalTimelineStyles);for(let[a,c]of s){const l=vc(c,o,i);this._pendingStyles.set(a,l),this._localTimelineStyles.has(a)||this._backFill.set(a,this._globalTimelineStyles.get(a)??ki),this._updateStyle(a,l)}}applyStylesToKeyframe(){0!=this._pendingStyles.size&&(this._pendingStyles.forEach((n,e)=>{this._currentKeyframe.set(e,n)}),this._pendingStyles.clear(),this._localTimelineStyles.forEach((n,e)=>{this._currentKeyframe.has(e)||this._currentKeyframe.set(e,n)}))}snapshotCurrentStyles(){for(let[n,e]of this._localTimelineStyles)this._pendingStyles.set(n,e),this._updateStyle(n,e)}getFinalKeyframe(){return this._keyframes.get(this.duration)}get properties(){const n=[];for(let e in this._currentKeyframe)n.push(e);return n}mergeTimelineCollectedStyles(n){n._styleSummary.forEach((e,i)=>{const r=this._styleSummary.get(i);(!r||e.time>r.time)&&this._updateStyle(i,e.value)})}buildKeyframes(){this.applyStylesToKeyframe();const n=new Set,e=new Set,i=1===this._keyframes.size&&0===this.duration;let r=[];this._keyframes.forEach((a,c)=>{const l=fr(a,new Map,this._backFill);l.forEach((d,u)=>{"!"===d?n.add(u):d===ki&&e.add(u)}),i||l.set("offset",c/this.duration),r.push(l)});const o=n.size?Gu(n.values()):[],s=e.size?Gu(e.values()):[];if(i){const a=r[0],c=new Map(a);a.set("offset",0),c.set("offset",1),r=[a,c]}return J_(this.element,r,o,s,this.duration,this.startTime,this.easing,!1)}}class h9 extends Qu{constructor(n,e,i,r,o,s,a=!1){super(n,e,s.delay),this.keyframes=i,this.preStyleProps=r,this.postStyleProps=o,this._stretchStartingKeyframe=a,this.timings={duration:s.duration,delay:s.delay,easing:s.easing}}containsAnimation(){return this.keyframes.length>1}buildKeyframes(){let n=this.keyframes,{delay:e,duration:i,easing:r}=this.timings;if(this._stretchStartingKeyframe&&e){const o=[],s=i+e,a=e/s,c=fr(n[0]);c.set("offset",0),o.push(c);const l=fr(n[0]);l.set("offset",hA(a)),o.push(l);const d=n.length-1;for(let u=1;u<=d;u++){let h=fr(n[u]);const p=h.get("offset");h.set("offset",hA((e+p*i)/s)),o.push(h)}i=s,e=0,r="",n=o}return J_(this.element,n,this.preStyleProps,this.postStyleProps,i,e,r,!0)}}function hA(t,n=3){const e=Math.pow(10,n-1);return Math.round(t*e)/e}class nb{}const p9=new Set(["width","height","minWidth","minHeight","maxWidth","maxHeight","left","top","bottom","right","fontSize","outlineWidth","outlineOffset","paddingTop","paddingLeft","paddingBottom","paddingRight","marginTop","marginLeft","marginBottom","marginRight","borderRadius","borderWidth","borderTopWidth","borderLeftWidth","borderRightWidth","borderBottomWidth","textIndent","perspective"]);class m9 extends nb{normalizePropertyName(n,e){return K_(n)}normalizeStyleValue(n,e,i,r){let o="";const s=i.toString().trim();if(p9.has(e)&&0!==i&&"0"!==i)if("number"==typeof i)o="px";else{const a=i.match(/^[+-]?[\d\.]+([a-z]*)$/);a&&0==a[1].length&&r.push(function l7(t,n){return new y(3005,!1)}())}return s+o}}function fA(t,n,e,i,r,o,s,a,c,l,d,u,h){return{type:0,element:t,triggerName:n,isRemovalTransition:r,fromState:e,fromStyles:o,toState:i,toStyles:s,timelines:a,queriedElements:c,preStyleProps:l,postStyleProps:d,totalTime:u,errors:h}}const ib={};class pA{constructor(n,e,i){this._triggerName=n,this.ast=e,this._stateStyles=i}match(n,e,i,r){return function g9(t,n,e,i,r){return t.some(o=>o(n,e,i,r))}(this.ast.matchers,n,e,i,r)}buildStyles(n,e,i){let r=this._stateStyles.get("*");return void 0!==n&&(r=this._stateStyles.get(n?.toString())||r),r?r.buildStyles(e,i):new Map}build(n,e,i,r,o,s,a,c,l,d){const u=[],h=this.ast.options&&this.ast.options.params||ib,m=this.buildStyles(i,a&&a.params||ib,u),g=c&&c.params||ib,_=this.buildStyles(r,g,u),C=new Set,v=new Map,R=new Map,L="void"===r,ne={params:_9(g,h),delay:this.ast.options?.delay},Fe=d?[]:eb(n,e,this.ast.animation,o,s,m,_,ne,l,u);let $e=0;if(Fe.forEach(fn=>{$e=Math.max(fn.duration+fn.delay,$e)}),u.length)return fA(e,this._triggerName,i,r,L,m,_,[],[],v,R,$e,u);Fe.forEach(fn=>{const Wn=fn.element,th=un(v,Wn,new Set);fn.preStyleProps.forEach(Jr=>th.add(Jr));const wc=un(R,Wn,new Set);fn.postStyleProps.forEach(Jr=>wc.add(Jr)),Wn!==e&&C.add(Wn)});const Mt=Gu(C.values());return fA(e,this._triggerName,i,r,L,m,_,Fe,Mt,v,R,$e)}}function _9(t,n){const e=_c(n);for(const i in t)t.hasOwnProperty(i)&&null!=t[i]&&(e[i]=t[i]);return e}class b9{constructor(n,e,i){this.styles=n,this.defaultParams=e,this.normalizer=i}buildStyles(n,e){const i=new Map,r=_c(this.defaultParams);return Object.keys(n).forEach(o=>{const s=n[o];null!==s&&(r[o]=s)}),this.styles.styles.forEach(o=>{"string"!=typeof o&&o.forEach((s,a)=>{s&&(s=vc(s,r,e));const c=this.normalizer.normalizePropertyName(a,e);s=this.normalizer.normalizeStyleValue(a,c,s,e),i.set(a,s)})}),i}}class y9{constructor(n,e,i){this.name=n,this.ast=e,this._normalizer=i,this.transitionFactories=[],this.states=new Map,e.states.forEach(r=>{this.states.set(r.name,new b9(r.style,r.options&&r.options.params||{},i))}),mA(this.states,"true","1"),mA(this.states,"false","0"),e.transitions.forEach(r=>{this.transitionFactories.push(new pA(n,r,this.states))}),this.fallbackTransition=function w9(t,n,e){return new pA(t,{type:1,animation:{type:2,steps:[],options:null},matchers:[(s,a)=>!0],options:null,queryCount:0,depCount:0},n)}(n,this.states)}get containsQueries(){return this.ast.queryCount>0}matchTransition(n,e,i,r){return this.transitionFactories.find(s=>s.match(n,e,i,r))||null}matchStyles(n,e,i){return this.fallbackTransition.buildStyles(n,e,i)}}function mA(t,n,e){t.has(n)?t.has(e)||t.set(e,t.get(n)):t.has(e)&&t.set(n,t.get(e))}const x9=new Zu;class C9{constructor(n,e,i){this.bodyNode=n,this._driver=e,this._normalizer=i,this._animations=new Map,this._playersById=new Map,this.players=[]}register(n,e){const i=[],o=Q_(this._driver,e,i,[]);if(i.length)throw function M7(t){return new y(3503,!1)}();this._animations.set(n,o)}_buildPlayer(n,e,i){const r=n.element,o=QT(this._normalizer,n.keyframes,e,i);return this._driver.animate(r,o,n.duration,n.delay,n.easing,[],!0)}create(n,e,i={}){const r=[],o=this._animations.get(n);let s;const a=new Map;if(o?(s=eb(this._driver,e,o,W_,Hu,new Map,new Map,i,x9,r),s.forEach(d=>{const u=un(a,d.element,new Map);d.postStyleProps.forEach(h=>u.set(h,null))})):(r.push(function I7(){return new y(3300,!1)}()),s=[]),r.length)throw function T7(t){return new y(3504,!1)}();a.forEach((d,u)=>{d.forEach((h,p)=>{d.set(p,this._driver.computeStyle(u,p,ki))})});const l=hr(s.map(d=>{const u=a.get(d.element);return this._buildPlayer(d,new Map,u)}));return this._playersById.set(n,l),l.onDestroy(()=>this.destroy(n)),this.players.push(l),l}destroy(n){const e=this._getPlayer(n);e.destroy(),this._playersById.delete(n);const i=this.players.indexOf(e);i>=0&&this.players.splice(i,1)}_getPlayer(n){const e=this._playersById.get(n);if(!e)throw function A7(t){return new y(3301,!1)}();return e}listen(n,e,i,r){const o=$_(e,"","","");return H_(this._getPlayer(n),i,o,r),()=>{}}command(n,e,i,r){if("register"==i)return void this.register(n,r[0]);if("create"==i)return void this.create(n,e,r[0]||{});const o=this._getPlayer(n);switch(i){case"play":o.play();break;case"pause":o.pause();break;case"reset":o.reset();break;case"restart":o.restart();break;case"finish":o.finish();break;case"init":o.init();break;case"setPosition":o.setPosition(parseFloat(r[0]));break;case"destroy":this.destroy(n)}}}const gA="ng-animate-queued",rb="ng-animate-disabled",I9=[],_A={namespaceId:"",setForRemoval:!1,setForMove:!1,hasAnimation:!1,removedBeforeQueried:!1},T9={namespaceId:"",setForMove:!1,setForRemoval:!1,hasAnimation:!1,removedBeforeQueried:!0},Gn="__ng_removed";class ob{get params(){return this.options.params}constructor(n,e=""){this.namespaceId=e;const i=n&&n.hasOwnProperty("value");if(this.value=function O9(t){return t??null}(i?n.value:n),i){const o=_c(n);delete o.value,this.options=o}else this.options={};this.options.params||(this.options.params={})}absorbOptions(n){const e=n.params;if(e){const i=this.options.params;Object.keys(e).forEach(r=>{null==i[r]&&(i[r]=e[r])})}}}const yc="void",sb=new ob(yc);class A9{constructor(n,e,i){this.id=n,this.hostElement=e,this._engine=i,this.players=[],this._triggers=new Map,this._queue=[],this._elementListeners=new Map,this._hostClassName="ng-tns-"+n,In(e,this._hostClassName)}listen(n,e,i,r){if(!this._triggers.has(e))throw function k7(t,n){return new y(3302,!1)}();if(null==i||0==i.length)throw function R7(t){return new y(3303,!1)}();if(!function F9(t){return"start"==t||"done"==t}(i))throw function O7(t,n){return new y(3400,!1)}();const o=un(this._elementListeners,n,[]),s={name:e,phase:i,callback:r};o.push(s);const a=un(this._engine.statesByElement,n,new Map);return a.has(e)||(In(n,zu),In(n,zu+"-"+e),a.set(e,sb)),()=>{this._engine.afterFlush(()=>{const c=o.indexOf(s);c>=0&&o.splice(c,1),this._triggers.has(e)||a.delete(e)})}}register(n,e){return!this._triggers.has(n)&&(this._triggers.set(n,e),!0)}_getTrigger(n){const e=this._triggers.get(n);if(!e)throw function F7(t){return new y(3401,!1)}();return e}trigger(n,e,i,r=!0){const o=this._getTrigger(e),s=new ab(this.id,e,n);let a=this._engine.statesByElement.get(n);a||(In(n,zu),In(n,zu+"-"+e),this._engine.statesByElement.set(n,a=new Map));let c=a.get(e);const l=new ob(i,this.id);if(!(i&&i.hasOwnProperty("value"))&&c&&l.absorbOptions(c.options),a.set(e,l),c||(c=sb),l.value!==yc&&c.value===l.value){if(!function L9(t,n){const e=Object.keys(t),i=Object.keys(n);if(e.length!=i.length)return!1;for(let r=0;r<e.length;r++){const o=e[r];if(!n.hasOwnProperty(o)||t[o]!==n[o])return!1}return!0}(c.params,l.params)){const g=[],_=o.matchStyles(c.value,c.params,g),C=o.matchStyles(l.value,l.params,g);g.length?this._engine.reportError(g):this._engine.afterFlush(()=>{Qr(n,_),di(n,C)})}return}const h=un(this._engine.playersByElement,n,[]);h.forEach(g=>{g.namespaceId==this.id&&g.triggerName==e&&g.queued&&g.destroy()});let p=o.matchTransition(c.value,l.value,n,l.params),m=!1;if(!p){if(!r)return;p=o.fallbackTransition,m=!0}return this._engine.totalQueuedPlayers++,this._queue.push({element:n,triggerName:e,transition:p,fromState:c,toState:l,player:s,isFallbackTransition:m}),m||(In(n,gA),s.onStart(()=>{Ds(n,gA)})),s.onDone(()=>{let g=this.players.indexOf(s);g>=0&&this.players.splice(g,1);const _=this._engine.playersByElement.get(n);if(_){let C=_.indexOf(s);C>=0&&_.splice(C,1)}}),this.players.push(s),h.push(s),s}deregister(n){this._triggers.delete(n),this._engine.statesByElement.forEach(e=>e.delete(n)),this._elementListeners.forEach((e,i)=>{this._elementListeners.set(i,e.filter(r=>r.name!=n))})}clearElementCache(n){this._engine.statesByElement.delete(n),this._elementListeners.delete(n);const e=this._engine.playersByElement.get(n);e&&(e.forEach(i=>i.destroy()),this._engine.playersByElement.delete(n))}_signalRemovalForInnerTriggers(n,e){const i=this._engine.driver.query(n,$u,!0);i.forEach(r=>{if(r[Gn])return;const o=this._engine.fetchNamespacesByElement(r);o.size?o.forEach(s=>s.triggerLeaveAnimation(r,e,!1,!0)):this.clearElementCache(r)}),this._engine.afterFlushAnimationsDone(()=>i.forEach(r=>this.clearElementCache(r)))}triggerLeaveAnimation(n,e,i,r){const o=this._engine.statesByElement.get(n),s=new Map;if(o){const a=[];if(o.forEach((c,l)=>{if(s.set(l,c.value),this._triggers.has(l)){const d=this.trigger(n,l,yc,r);d&&a.push(d)}}),a.length)return this._engine.markElementAsRemoved(this.id,n,!0,e,s),i&&hr(a).onDone(()=>this._engine.processLeaveNode(n)),!0}return!1}prepareLeaveAnimationListeners(n){const e=this._elementListeners.get(n),i=this._engine.statesByElement.get(n);if(e&&i){const r=new Set;e.forEach(o=>{const s=o.name;if(r.has(s))return;r.add(s);const c=this._triggers.get(s).fallbackTransition,l=i.get(s)||sb,d=new ob(yc),u=new ab(this.id,s,n);this._engine.totalQueuedPlayers++,this._queue.push({element:n,triggerName:s,transition:c,fromState:l,toState:d,player:u,isFallbackTransition:!0})})}}removeNode(n,e){const i=this._engine;if(n.childElementCount&&this._signalRemovalForInnerTriggers(n,e),this.triggerLeaveAnimation(n,e,!0))return;let r=!1;if(i.totalAnimations){const o=i.players.length?i.playersByQueriedElement.get(n):[];if(o&&o.length)r=!0;else{let s=n;for(;s=s.parentNode;)if(i.statesByElement.get(s)){r=!0;break}}}if(this.prepareLeaveAnimationListeners(n),r)i.markElementAsRemoved(this.id,n,!1,e);else{const o=n[Gn];(!o||o===_A)&&(i.afterFlush(()=>this.clearElementCache(n)),i.destroyInnerAnimations(n),i._onRemovalComplete(n,e))}}insertNode(n,e){In(n,this._hostClassName)}drainQueuedTransitions(n){const e=[];return this._queue.forEach(i=>{const r=i.player;if(r.destroyed)return;const o=i.element,s=this._elementListeners.get(o);s&&s.forEach(a=>{if(a.name==i.triggerName){const c=$_(o,i.triggerName,i.fromState.value,i.toState.value);c._data=n,H_(i.player,a.phase,c,a.callback)}}),r.markedForDestroy?this._engine.afterFlush(()=>{r.destroy()}):e.push(i)}),this._queue=[],e.sort((i,r)=>{const o=i.transition.ast.depCount,s=r.transition.ast.depCount;return 0==o||0==s?o-s:this._engine.driver.containsElement(i.element,r.element)?1:-1})}destroy(n){this.players.forEach(e=>e.destroy()),this._signalRemovalForInnerTriggers(this.hostElement,n)}}class k9{_onRemovalComplete(n,e){this.onRemovalComplete(n,e)}constructor(n,e,i){this.bodyNode=n,this.driver=e,this._normalizer=i,this.players=[],this.newHostElements=new Map,this.playersByElement=new Map,this.playersByQueriedElement=new Map,this.statesByElement=new Map,this.disabledNodes=new Set,this.totalAnimations=0,this.totalQueuedPlayers=0,this._namespaceLookup={},this._namespaceList=[],this._flushFns=[],this._whenQuietFns=[],this.namespacesByHostElement=new Map,this.collectedEnterElements=[],this.collectedLeaveElements=[],this.onRemovalComplete=(r,o)=>{}}get queuedPlayers(){const n=[];return this._namespaceList.forEach(e=>{e.players.forEach(i=>{i.queued&&n.push(i)})}),n}createNamespace(n,e){const i=new A9(n,e,this);return this.bodyNode&&this.driver.containsElement(this.bodyNode,e)?this._balanceNamespaceList(i,e):(this.newHostElements.set(e,i),this.collectEnterElement(e)),this._namespaceLookup[n]=i}_balanceNamespaceList(n,e){const i=this._namespaceList,r=this.namespacesByHostElement;if(i.length-1>=0){let s=!1,a=this.driver.getParentElement(e);for(;a;){const c=r.get(a);if(c){const l=i.indexOf(c);i.splice(l+1,0,n),s=!0;break}a=this.driver.getParentElement(a)}s||i.unshift(n)}else i.push(n);return r.set(e,n),n}register(n,e){let i=this._namespaceLookup[n];return i||(i=this.createNamespace(n,e)),i}registerTrigger(n,e,i){let r=this._namespaceLookup[n];r&&r.register(e,i)&&this.totalAnimations++}destroy(n,e){n&&(this.afterFlush(()=>{}),this.afterFlushAnimationsDone(()=>{const i=this._fetchNamespace(n);this.namespacesByHostElement.delete(i.hostElement);const r=this._namespaceList.indexOf(i);r>=0&&this._namespaceList.splice(r,1),i.destroy(e),delete this._namespaceLookup[n]}))}_fetchNamespace(n){return this._namespaceLookup[n]}fetchNamespacesByElement(n){const e=new Set,i=this.statesByElement.get(n);if(i)for(let r of i.values())if(r.namespaceId){const o=this._fetchNamespace(r.namespaceId);o&&e.add(o)}return e}trigger(n,e,i,r){if(Xu(e)){const o=this._fetchNamespace(n);if(o)return o.trigger(e,i,r),!0}return!1}insertNode(n,e,i,r){if(!Xu(e))return;const o=e[Gn];if(o&&o.setForRemoval){o.setForRemoval=!1,o.setForMove=!0;const s=this.collectedLeaveElements.indexOf(e);s>=0&&this.collectedLeaveElements.splice(s,1)}if(n){const s=this._fetchNamespace(n);s&&s.insertNode(e,i)}r&&this.collectEnterElement(e)}collectEnterElement(n){this.collectedEnterElements.push(n)}markElementAsDisabled(n,e){e?this.disabledNodes.has(n)||(this.disabledNodes.add(n),In(n,rb)):this.disabledNodes.has(n)&&(this.disabledNodes.delete(n),Ds(n,rb))}removeNode(n,e,i){if(Xu(e)){const r=n?this._fetchNamespace(n):null;r?r.removeNode(e,i):this.markElementAsRemoved(n,e,!1,i);const o=this.namespacesByHostElement.get(e);o&&o.id!==n&&o.removeNode(e,i)}else this._onRemovalComplete(e,i)}markElementAsRemoved(n,e,i,r,o){this.collectedLeaveElements.push(e),e[Gn]={namespaceId:n,setForRemoval:r,hasAnimation:i,removedBeforeQueried:!1,previousTriggersValues:o}}listen(n,e,i,r,o){return Xu(e)?this._fetchNamespace(n).listen(e,i,r,o):()=>{}}_buildInstruction(n,e,i,r,o){return n.transition.build(this.driver,n.element,n.fromState.value,n.toState.value,i,r,n.fromState.options,n.toState.options,e,o)}destroyInnerAnimations(n){let e=this.driver.query(n,$u,!0);e.forEach(i=>this.destroyActiveAnimationsForElement(i)),0!=this.playersByQueriedElement.size&&(e=this.driver.query(n,q_,!0),e.forEach(i=>this.finishActiveQueriedAnimationOnElement(i)))}destroyActiveAnimationsForElement(n){const e=this.playersByElement.get(n);e&&e.forEach(i=>{i.queued?i.markedForDestroy=!0:i.destroy()})}finishActiveQueriedAnimationOnElement(n){const e=this.playersByQueriedElement.get(n);e&&e.forEach(i=>i.finish())}whenRenderingDone(){return new Promise(n=>{if(this.players.length)return hr(this.players).onDone(()=>n());n()})}processLeaveNode(n){const e=n[Gn];if(e&&e.setForRemoval){if(n[Gn]=_A,e.namespaceId){this.destroyInnerAnimations(n);const i=this._fetchNamespace(e.namespaceId);i&&i.clearElementCache(n)}this._onRemovalComplete(n,e.setForRemoval)}n.classList?.contains(rb)&&this.markElementAsDisabled(n,!1),this.driver.query(n,".ng-animate-disabled",!0).forEach(i=>{this.markElementAsDisabled(i,!1)})}flush(n=-1){let e=[];if(this.newHostElements.size&&(this.newHostElements.forEach((i,r)=>this._balanceNamespaceList(i,r)),this.newHostElements.clear()),this.totalAnimations&&this.collectedEnterElements.length)for(let i=0;i<this.collectedEnterElements.length;i++)In(this.collectedEnterElements[i],"ng-star-inserted");if(this._namespaceList.length&&(this.totalQueuedPlayers||this.collectedLeaveElements.length)){const i=[];try{e=this._flushAnimations(i,n)}finally{for(let r=0;r<i.length;r++)i[r]()}}else for(let i=0;i<this.collectedLeaveElements.length;i++)this.processLeaveNode(this.collectedLeaveElements[i]);if(this.totalQueuedPlayers=0,this.collectedEnterElements.length=0,this.collectedLeaveElements.length=0,this._flushFns.forEach(i=>i()),this._flushFns=[],this._whenQuietFns.length){const i=this._whenQuietFns;this._whenQuietFns=[],e.length?hr(e).onDone(()=>{i.forEach(r=>r())}):i.forEach(r=>r())}}reportError(n){throw function N7(t){return new y(3402,!1)}()}_flushAnimations(n,e){const i=new Zu,r=[],o=new Map,s=[],a=new Map,c=new Map,l=new Map,d=new Set;this.disabledNodes.forEach(H=>{d.add(H);const W=this.driver.query(H,".ng-animate-queued",!0);for(let X=0;X<W.length;X++)d.add(W[X])});const u=this.bodyNode,h=Array.from(this.statesByElement.keys()),p=yA(h,this.collectedEnterElements),m=new Map;let g=0;p.forEach((H,W)=>{const X=W_+g++;m.set(W,X),H.forEach(Ce=>In(Ce,X))});const _=[],C=new Set,v=new Set;for(let H=0;H<this.collectedLeaveElements.length;H++){const W=this.collectedLeaveElements[H],X=W[Gn];X&&X.setForRemoval&&(_.push(W),C.add(W),X.hasAnimation?this.driver.query(W,".ng-star-inserted",!0).forEach(Ce=>C.add(Ce)):v.add(W))}const R=new Map,L=yA(h,Array.from(C));L.forEach((H,W)=>{const X=Hu+g++;R.set(W,X),H.forEach(Ce=>In(Ce,X))}),n.push(()=>{p.forEach((H,W)=>{const X=m.get(W);H.forEach(Ce=>Ds(Ce,X))}),L.forEach((H,W)=>{const X=R.get(W);H.forEach(Ce=>Ds(Ce,X))}),_.forEach(H=>{this.processLeaveNode(H)})});const ne=[],Fe=[];for(let H=this._namespaceList.length-1;H>=0;H--)this._namespaceList[H].drainQueuedTransitions(e).forEach(X=>{const Ce=X.player,wt=X.element;if(ne.push(Ce),this.collectedEnterElements.length){const Rt=wt[Gn];if(Rt&&Rt.setForMove){if(Rt.previousTriggersValues&&Rt.previousTriggersValues.has(X.triggerName)){const eo=Rt.previousTriggersValues.get(X.triggerName),Tn=this.statesByElement.get(X.element);if(Tn&&Tn.has(X.triggerName)){const nh=Tn.get(X.triggerName);nh.value=eo,Tn.set(X.triggerName,nh)}}return void Ce.destroy()}}const ui=!u||!this.driver.containsElement(u,wt),pn=R.get(wt),pr=m.get(wt),Qe=this._buildInstruction(X,i,pr,pn,ui);if(Qe.errors&&Qe.errors.length)return void Fe.push(Qe);if(ui)return Ce.onStart(()=>Qr(wt,Qe.fromStyles)),Ce.onDestroy(()=>di(wt,Qe.toStyles)),void r.push(Ce);if(X.isFallbackTransition)return Ce.onStart(()=>Qr(wt,Qe.fromStyles)),Ce.onDestroy(()=>di(wt,Qe.toStyles)),void r.push(Ce);const kA=[];Qe.timelines.forEach(Rt=>{Rt.stretchStartingKeyframe=!0,this.disabledNodes.has(Rt.element)||kA.push(Rt)}),Qe.timelines=kA,i.append(wt,Qe.timelines),s.push({instruction:Qe,player:Ce,element:wt}),Qe.queriedElements.forEach(Rt=>un(a,Rt,[]).push(Ce)),Qe.preStyleProps.forEach((Rt,eo)=>{if(Rt.size){let Tn=c.get(eo);Tn||c.set(eo,Tn=new Set),Rt.forEach((nh,db)=>Tn.add(db))}}),Qe.postStyleProps.forEach((Rt,eo)=>{let Tn=l.get(eo);Tn||l.set(eo,Tn=new Set),Rt.forEach((nh,db)=>Tn.add(db))})});if(Fe.length){const H=[];Fe.forEach(W=>{H.push(function P7(t,n){return new y(3505,!1)}())}),ne.forEach(W=>W.destroy()),this.reportError(H)}const $e=new Map,Mt=new Map;s.forEach(H=>{const W=H.element;i.has(W)&&(Mt.set(W,W),this._beforeAnimationBuild(H.player.namespaceId,H.instruction,$e))}),r.forEach(H=>{const W=H.element;this._getPreviousPlayers(W,!1,H.namespaceId,H.triggerName,null).forEach(Ce=>{un($e,W,[]).push(Ce),Ce.destroy()})});const fn=_.filter(H=>xA(H,c,l)),Wn=new Map;vA(Wn,this.driver,v,l,ki).forEach(H=>{xA(H,c,l)&&fn.push(H)});const wc=new Map;p.forEach((H,W)=>{vA(wc,this.driver,new Set(H),c,"!")}),fn.forEach(H=>{const W=Wn.get(H),X=wc.get(H);Wn.set(H,new Map([...W?.entries()??[],...X?.entries()??[]]))});const Jr=[],TA=[],AA={};s.forEach(H=>{const{element:W,player:X,instruction:Ce}=H;if(i.has(W)){if(d.has(W))return X.onDestroy(()=>di(W,Ce.toStyles)),X.disabled=!0,X.overrideTotalTime(Ce.totalTime),void r.push(X);let wt=AA;if(Mt.size>1){let pn=W;const pr=[];for(;pn=pn.parentNode;){const Qe=Mt.get(pn);if(Qe){wt=Qe;break}pr.push(pn)}pr.forEach(Qe=>Mt.set(Qe,wt))}const ui=this._buildAnimation(X.namespaceId,Ce,$e,o,wc,Wn);if(X.setRealPlayer(ui),wt===AA)Jr.push(X);else{const pn=this.playersByElement.get(wt);pn&&pn.length&&(X.parentPlayer=hr(pn)),r.push(X)}}else Qr(W,Ce.fromStyles),X.onDestroy(()=>di(W,Ce.toStyles)),TA.push(X),d.has(W)&&r.push(X)}),TA.forEach(H=>{const W=o.get(H.element);if(W&&W.length){const X=hr(W);H.setRealPlayer(X)}}),r.forEach(H=>{H.parentPlayer?H.syncPlayerEvents(H.parentPlayer):H.destroy()});for(let H=0;H<_.length;H++){const W=_[H],X=W[Gn];if(Ds(W,Hu),X&&X.hasAnimation)continue;let Ce=[];if(a.size){let ui=a.get(W);ui&&ui.length&&Ce.push(...ui);let pn=this.driver.query(W,q_,!0);for(let pr=0;pr<pn.length;pr++){let Qe=a.get(pn[pr]);Qe&&Qe.length&&Ce.push(...Qe)}}const wt=Ce.filter(ui=>!ui.destroyed);wt.length?N9(this,W,wt):this.processLeaveNode(W)}return _.length=0,Jr.forEach(H=>{this.players.push(H),H.onDone(()=>{H.destroy();const W=this.players.indexOf(H);this.players.splice(W,1)}),H.play()}),Jr}afterFlush(n){this._flushFns.push(n)}afterFlushAnimationsDone(n){this._whenQuietFns.push(n)}_getPreviousPlayers(n,e,i,r,o){let s=[];if(e){const a=this.playersByQueriedElement.get(n);a&&(s=a)}else{const a=this.playersByElement.get(n);if(a){const c=!o||o==yc;a.forEach(l=>{l.queued||!c&&l.triggerName!=r||s.push(l)})}}return(i||r)&&(s=s.filter(a=>!(i&&i!=a.namespaceId||r&&r!=a.triggerName))),s}_beforeAnimationBuild(n,e,i){const o=e.element,s=e.isRemovalTransition?void 0:n,a=e.isRemovalTransition?void 0:e.triggerName;for(const c of e.timelines){const l=c.element,d=l!==o,u=un(i,l,[]);this._getPreviousPlayers(l,d,s,a,e.toState).forEach(p=>{const m=p.getRealPlayer();m.beforeDestroy&&m.beforeDestroy(),p.destroy(),u.push(p)})}Qr(o,e.fromStyles)}_buildAnimation(n,e,i,r,o,s){const a=e.triggerName,c=e.element,l=[],d=new Set,u=new Set,h=e.timelines.map(m=>{const g=m.element;d.add(g);const _=g[Gn];if(_&&_.removedBeforeQueried)return new Ba(m.duration,m.delay);const C=g!==c,v=function P9(t){const n=[];return wA(t,n),n}((i.get(g)||I9).map($e=>$e.getRealPlayer())).filter($e=>!!$e.element&&$e.element===g),R=o.get(g),L=s.get(g),ne=QT(this._normalizer,m.keyframes,R,L),Fe=this._buildPlayer(m,ne,v);if(m.subTimeline&&r&&u.add(g),C){const $e=new ab(n,a,g);$e.setRealPlayer(Fe),l.push($e)}return Fe});l.forEach(m=>{un(this.playersByQueriedElement,m.element,[]).push(m),m.onDone(()=>function R9(t,n,e){let i=t.get(n);if(i){if(i.length){const r=i.indexOf(e);i.splice(r,1)}0==i.length&&t.delete(n)}return i}(this.playersByQueriedElement,m.element,m))}),d.forEach(m=>In(m,rA));const p=hr(h);return p.onDestroy(()=>{d.forEach(m=>Ds(m,rA)),di(c,e.toStyles)}),u.forEach(m=>{un(r,m,[]).push(p)}),p}_buildPlayer(n,e,i){return e.length>0?this.driver.animate(n.element,e,n.duration,n.delay,n.easing,i):new Ba(n.duration,n.delay)}}class ab{constructor(n,e,i){this.namespaceId=n,this.triggerName=e,this.element=i,this._player=new Ba,this._containsRealPlayer=!1,this._queuedCallbacks=new Map,this.destroyed=!1,this.parentPlayer=null,this.markedForDestroy=!1,this.disabled=!1,this.queued=!0,this.totalTime=0}setRealPlayer(n){this._containsRealPlayer||(this._player=n,this._queuedCallbacks.forEach((e,i)=>{e.forEach(r=>H_(n,i,void 0,r))}),this._queuedCallbacks.clear(),this._containsRealPlayer=!0,this.overrideTotalTime(n.totalTime),this.queued=!1)}getRealPlayer(){return this._player}overrideTotalTime(n){this.totalTime=n}syncPlayerEvents(n){const e=this._player;e.triggerCallback&&n.onStart(()=>e.triggerCallback("start")),n.onDone(()=>this.finish()),n.onDestroy(()=>this.destroy())}_queueEvent(n,e){un(this._queuedCallbacks,n,[]).push(e)}onDone(n){this.queued&&this._queueEvent("done",n),this._player.onDone(n)}onStart(n){this.queued&&this._queueEvent("start",n),this._player.onStart(n)}onDestroy(n){this.queued&&this._queueEvent("destroy",n),this._player.onDestroy(n)}init(){this._player.init()}hasStarted(){return!this.queued&&this._player.hasStarted()}play(){!this.queued&&this._player.play()}pause(){!this.queued&&this._player.pause()}restart(){!this.queued&&this._player.restart()}finish(){this._player.finish()}destroy(){this.destroyed=!0,this._player.destroy()}reset(){!this.queued&&this._player.reset()}setPosition(n){this.queued||this._player.setPosition(n)}getPosition(){return this.queued?0:this._player.getPosition()}triggerCallback(n){const e=this._player;e.triggerCallback&&e.triggerCallback(n)}}function Xu(t){return t&&1===t.nodeType}function bA(t,n){const e=t.style.display;return t.style.display=n??"none",e}function vA(t,n,e,i,r){const o=[];e.forEach(c=>o.push(bA(c)));const s=[];i.forEach((c,l)=>{const d=new Map;c.forEach(u=>{const h=n.computeStyle(l,u,r);d.set(u,h),(!h||0==h.length)&&(l[Gn]=T9,s.push(l))}),t.set(l,d)});let a=0;return e.forEach(c=>bA(c,o[a++])),s}function yA(t,n){const e=new Map;if(t.forEach(a=>e.set(a,[])),0==n.length)return e;const r=new Set(n),o=new Map;function s(a){if(!a)return 1;let c=o.get(a);if(c)return c;const l=a.parentNode;return c=e.has(l)?l:r.has(l)?1:s(l),o.set(a,c),c}return n.forEach(a=>{const c=s(a);1!==c&&e.get(c).push(a)}),e}function In(t,n){t.classList?.add(n)}function Ds(t,n){t.classList?.remove(n)}function N9(t,n,e){hr(e).onDone(()=>t.processLeaveNode(n))}function wA(t,n){for(let e=0;e<t.length;e++){const i=t[e];i instanceof JE?wA(i.players,n):n.push(i)}}function xA(t,n,e){const i=e.get(t);if(!i)return!1;let r=n.get(t);return r?i.forEach(o=>r.add(o)):n.set(t,i),e.delete(t),!0}class Ju{constructor(n,e,i){this.bodyNode=n,this._driver=e,this._normalizer=i,this._triggerCache={},this.onRemovalComplete=(r,o)=>{},this._transitionEngine=new k9(n,e,i),this._timelineEngine=new C9(n,e,i),this._transitionEngine.onRemovalComplete=(r,o)=>this.onRemovalComplete(r,o)}registerTrigger(n,e,i,r,o){const s=n+"-"+r;let a=this._triggerCache[s];if(!a){const c=[],d=Q_(this._driver,o,c,[]);if(c.length)throw function E7(t,n){return new y(3404,!1)}();a=function v9(t,n,e){return new y9(t,n,e)}(r,d,this._normalizer),this._triggerCache[s]=a}this._transitionEngine.registerTrigger(e,r,a)}register(n,e){this._transitionEngine.register(n,e)}destroy(n,e){this._transitionEngine.destroy(n,e)}onInsert(n,e,i,r){this._transitionEngine.insertNode(n,e,i,r)}onRemove(n,e,i){this._transitionEngine.removeNode(n,e,i)}disableAnimations(n,e){this._transitionEngine.markElementAsDisabled(n,e)}process(n,e,i,r){if("@"==i.charAt(0)){const[o,s]=XT(i);this._timelineEngine.command(o,e,s,r)}else this._transitionEngine.trigger(n,e,i,r)}listen(n,e,i,r,o){if("@"==i.charAt(0)){const[s,a]=XT(i);return this._timelineEngine.listen(s,e,a,o)}return this._transitionEngine.listen(n,e,i,r,o)}flush(n=-1){this._transitionEngine.flush(n)}get players(){return[...this._transitionEngine.players,...this._timelineEngine.players]}whenRenderingDone(){return this._transitionEngine.whenRenderingDone()}afterFlushAnimationsDone(n){this._transitionEngine.afterFlushAnimationsDone(n)}}let V9=(()=>{class t{static#e=this.initialStylesByElement=new WeakMap;constructor(e,i,r){this._element=e,this._startStyles=i,this._endStyles=r,this._state=0;let o=t.initialStylesByElement.get(e);o||t.initialStylesByElement.set(e,o=new Map),this._initialStyles=o}start(){this._state<1&&(this._startStyles&&di(this._element,this._startStyles,this._initialStyles),this._state=1)}finish(){this.start(),this._state<2&&(di(this._element,this._initialStyles),this._endStyles&&(di(this._element,this._endStyles),this._endStyles=null),this._state=1)}destroy(){this.finish(),this._state<3&&(t.initialStylesByElement.delete(this._element),this._startStyles&&(Qr(this._element,this._startStyles),this._endStyles=null),this._endStyles&&(Qr(this._element,this._endStyles),this._endStyles=null),di(this._element,this._initialStyles),this._state=3)}}return t})();function cb(t){let n=null;return t.forEach((e,i)=>{(function j9(t){return"display"===t||"position"===t})(i)&&(n=n||new Map,n.set(i,e))}),n}class CA{constructor(n,e,i,r){this.element=n,this.keyframes=e,this.options=i,this._specialStyles=r,this._onDoneFns=[],this._onStartFns=[],this._onDestroyFns=[],this._initialized=!1,this._finished=!1,this._started=!1,this._destroyed=!1,this._originalOnDoneFns=[],this._originalOnStartFns=[],this.time=0,this.parentPlayer=null,this.currentSnapshot=new Map,this._duration=i.duration,this._delay=i.delay||0,this.time=this._duration+this._delay}_onFinish(){this._finished||(this._finished=!0,this._onDoneFns.forEach(n=>n()),this._onDoneFns=[])}init(){this._buildPlayer(),this._preparePlayerBeforeStart()}_buildPlayer(){if(this._initialized)return;this._initialized=!0;const n=this.keyframes;this.domPlayer=this._triggerWebAnimation(this.element,n,this.options),this._finalKeyframe=n.length?n[n.length-1]:new Map,this.domPlayer.addEventListener("finish",()=>this._onFinish())}_preparePlayerBeforeStart(){this._delay?this._resetDomPlayerState():this.domPlayer.pause()}_convertKeyframesToObject(n){const e=[];return n.forEach(i=>{e.push(Object.fromEntries(i))}),e}_triggerWebAnimation(n,e,i){return n.animate(this._convertKeyframesToObject(e),i)}onStart(n){this._originalOnStartFns.push(n),this._onStartFns.push(n)}onDone(n){this._originalOnDoneFns.push(n),this._onDoneFns.push(n)}onDestroy(n){this._onDestroyFns.push(n)}play(){this._buildPlayer(),this.hasStarted()||(this._onStartFns.forEach(n=>n()),this._onStartFns=[],this._started=!0,this._specialStyles&&this._specialStyles.start()),this.domPlayer.play()}pause(){this.init(),this.domPlayer.pause()}finish(){this.init(),this._specialStyles&&this._specialStyles.finish(),this._onFinish(),this.domPlayer.finish()}reset(){this._resetDomPlayerState(),this._destroyed=!1,this._finished=!1,this._started=!1,this._onStartFns=this._originalOnStartFns,this._onDoneFns=this._originalOnDoneFns}_resetDomPlayerState(){this.domPlayer&&this.domPlayer.cancel()}restart(){this.reset(),this.play()}hasStarted(){return this._started}destroy(){this._destroyed||(this._destroyed=!0,this._resetDomPlayerState(),this._onFinish(),this._specialStyles&&this._specialStyles.destroy(),this._onDestroyFns.forEach(n=>n()),this._onDestroyFns=[])}setPosition(n){void 0===this.domPlayer&&this.init(),this.domPlayer.currentTime=n*this.time}getPosition(){return+(this.domPlayer.currentTime??0)/this.time}get totalTime(){return this._delay+this._duration}beforeDestroy(){const n=new Map;this.hasStarted()&&this._finalKeyframe.forEach((i,r)=>{"offset"!==r&&n.set(r,this._finished?i:cA(this.element,r))}),this.currentSnapshot=n}triggerCallback(n){const e="start"===n?this._onStartFns:this._onDoneFns;e.forEach(i=>i()),e.length=0}}class H9{validateStyleProperty(n){return!0}validateAnimatableStyleProperty(n){return!0}matchesElement(n,e){return!1}containsElement(n,e){return eA(n,e)}getParentElement(n){return U_(n)}query(n,e,i){return tA(n,e,i)}computeStyle(n,e,i){return window.getComputedStyle(n)[e]}animate(n,e,i,r,o,s=[]){const c={duration:i,delay:r,fill:0==r?"both":"forwards"};o&&(c.easing=o);const l=new Map,d=s.filter(p=>p instanceof CA);(function Y7(t,n){return 0===t||0===n})(i,r)&&d.forEach(p=>{p.currentSnapshot.forEach((m,g)=>l.set(g,m))});let u=function G7(t){return t.length?t[0]instanceof Map?t:t.map(n=>oA(n)):[]}(e).map(p=>fr(p));u=function Z7(t,n,e){if(e.size&&n.length){let i=n[0],r=[];if(e.forEach((o,s)=>{i.has(s)||r.push(s),i.set(s,o)}),r.length)for(let o=1;o<n.length;o++){let s=n[o];r.forEach(a=>s.set(a,cA(t,a)))}}return n}(n,u,l);const h=function B9(t,n){let e=null,i=null;return Array.isArray(n)&&n.length?(e=cb(n[0]),n.length>1&&(i=cb(n[n.length-1]))):n instanceof Map&&(e=cb(n)),e||i?new V9(t,e,i):null}(n,u);return new CA(n,u,c,h)}}let z9=(()=>{class t extends KE{constructor(e,i){super(),this._nextAnimationId=0,this._renderer=e.createRenderer(i.body,{id:"0",encapsulation:gn.None,styles:[],data:{animation:[]}})}build(e){const i=this._nextAnimationId.toString();this._nextAnimationId++;const r=Array.isArray(e)?XE(e):e;return DA(this._renderer,null,i,"register",[r]),new $9(i,this._renderer)}static#e=this.\u0275fac=function(i){return new(i||t)(b(ra),b(Z))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();class $9 extends O3{constructor(n,e){super(),this._id=n,this._renderer=e}create(n,e){return new U9(this._id,n,e||{},this._renderer)}}class U9{constructor(n,e,i,r){this.id=n,this.element=e,this._renderer=r,this.parentPlayer=null,this._started=!1,this.totalTime=0,this._command("create",i)}_listen(n,e){return this._renderer.listen(this.element,`@@${this.id}:${n}`,e)}_command(n,...e){return DA(this._renderer,this.element,this.id,n,e)}onDone(n){this._listen("done",n)}onStart(n){this._listen("start",n)}onDestroy(n){this._listen("destroy",n)}init(){this._command("init")}hasStarted(){return this._started}play(){this._command("play"),this._started=!0}pause(){this._command("pause")}restart(){this._command("restart")}finish(){this._command("finish")}destroy(){this._command("destroy")}reset(){this._command("reset"),this._started=!1}setPosition(n){this._command("setPosition",n)}getPosition(){return this._renderer.engine.players[+this.id]?.getPosition()??0}}function DA(t,n,e,i,r){return t.setProperty(n,`@@${e}:${i}`,r)}const EA="@.disabled";let G9=(()=>{class t{constructor(e,i,r){this.delegate=e,this.engine=i,this._zone=r,this._currentId=0,this._microtaskId=1,this._animationCallbacksBuffer=[],this._rendererCache=new Map,this._cdRecurDepth=0,i.onRemovalComplete=(o,s)=>{const a=s?.parentNode(o);a&&s.removeChild(a,o)}}createRenderer(e,i){const o=this.delegate.createRenderer(e,i);if(!(e&&i&&i.data&&i.data.animation)){let d=this._rendererCache.get(o);return d||(d=new SA("",o,this.engine,()=>this._rendererCache.delete(o)),this._rendererCache.set(o,d)),d}const s=i.id,a=i.id+"-"+this._currentId;this._currentId++,this.engine.register(a,e);const c=d=>{Array.isArray(d)?d.forEach(c):this.engine.registerTrigger(s,a,e,d.name,d)};return i.data.animation.forEach(c),new W9(this,a,o,this.engine)}begin(){this._cdRecurDepth++,this.delegate.begin&&this.delegate.begin()}_scheduleCountTask(){queueMicrotask(()=>{this._microtaskId++})}scheduleListenerCallback(e,i,r){e>=0&&e<this._microtaskId?this._zone.run(()=>i(r)):(0==this._animationCallbacksBuffer.length&&queueMicrotask(()=>{this._zone.run(()=>{this._animationCallbacksBuffer.forEach(o=>{const[s,a]=o;s(a)}),this._animationCallbacksBuffer=[]})}),this._animationCallbacksBuffer.push([i,r]))}end(){this._cdRecurDepth--,0==this._cdRecurDepth&&this._zone.runOutsideAngular(()=>{this._scheduleCountTask(),this.engine.flush(this._microtaskId)}),this.delegate.end&&this.delegate.end()}whenRenderingDone(){return this.engine.whenRenderingDone()}static#e=this.\u0275fac=function(i){return new(i||t)(b(ra),b(Ju),b(j))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})();class SA{constructor(n,e,i,r){this.namespaceId=n,this.delegate=e,this.engine=i,this._onDestroy=r}get data(){return this.delegate.data}destroyNode(n){this.delegate.destroyNode?.(n)}destroy(){this.engine.destroy(this.namespaceId,this.delegate),this.engine.afterFlushAnimationsDone(()=>{queueMicrotask(()=>{this.delegate.destroy()})}),this._onDestroy?.()}createElement(n,e){return this.delegate.createElement(n,e)}createComment(n){return this.delegate.createComment(n)}createText(n){return this.delegate.createText(n)}appendChild(n,e){this.delegate.appendChild(n,e),this.engine.onInsert(this.namespaceId,e,n,!1)}insertBefore(n,e,i,r=!0){this.delegate.insertBefore(n,e,i),this.engine.onInsert(this.namespaceId,e,n,r)}removeChild(n,e,i){this.engine.onRemove(this.namespaceId,e,this.delegate)}selectRootElement(n,e){return this.delegate.selectRootElement(n,e)}parentNode(n){return this.delegate.parentNode(n)}nextSibling(n){return this.delegate.nextSibling(n)}setAttribute(n,e,i,r){this.delegate.setAttribute(n,e,i,r)}removeAttribute(n,e,i){this.delegate.removeAttribute(n,e,i)}addClass(n,e){this.delegate.addClass(n,e)}removeClass(n,e){this.delegate.removeClass(n,e)}setStyle(n,e,i,r){this.delegate.setStyle(n,e,i,r)}removeStyle(n,e,i){this.delegate.removeStyle(n,e,i)}setProperty(n,e,i){"@"==e.charAt(0)&&e==EA?this.disableAnimations(n,!!i):this.delegate.setProperty(n,e,i)}setValue(n,e){this.delegate.setValue(n,e)}listen(n,e,i){return this.delegate.listen(n,e,i)}disableAnimations(n,e){this.engine.disableAnimations(n,e)}}class W9 extends SA{constructor(n,e,i,r,o){super(e,i,r,o),this.factory=n,this.namespaceId=e}setProperty(n,e,i){"@"==e.charAt(0)?"."==e.charAt(1)&&e==EA?this.disableAnimations(n,i=void 0===i||!!i):this.engine.process(this.namespaceId,n,e.slice(1),i):this.delegate.setProperty(n,e,i)}listen(n,e,i){if("@"==e.charAt(0)){const r=function q9(t){switch(t){case"body":return document.body;case"document":return document;case"window":return window;default:return t}}(n);let o=e.slice(1),s="";return"@"!=o.charAt(0)&&([o,s]=function Y9(t){const n=t.indexOf(".");return[t.substring(0,n),t.slice(n+1)]}(o)),this.engine.listen(this.namespaceId,r,o,s,a=>{this.factory.scheduleListenerCallback(a._data||-1,i,a)})}return this.delegate.listen(n,e,i)}}const MA=[{provide:KE,useClass:z9},{provide:nb,useFactory:function K9(){return new m9}},{provide:Ju,useClass:(()=>{class t extends Ju{constructor(e,i,r,o){super(e.body,i,r)}ngOnDestroy(){this.flush()}static#e=this.\u0275fac=function(i){return new(i||t)(b(Z),b(G_),b(nb),b(Xi))};static#t=this.\u0275prov=I({token:t,factory:t.\u0275fac})}return t})()},{provide:ra,useFactory:function Q9(t,n,e){return new G9(t,n,e)},deps:[Om,Ju,j]}],lb=[{provide:G_,useFactory:()=>new H9},{provide:gt,useValue:"BrowserAnimations"},...MA],IA=[{provide:G_,useClass:nA},{provide:gt,useValue:"NoopAnimations"},...MA];let X9=(()=>{class t{static withConfig(e){return{ngModule:t,providers:e.disableAnimations?IA:lb}}static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({providers:lb,imports:[aE]})}return t})(),oY=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({imports:[Ae,oi,Ae]})}return t})();const dY={provide:new E("mat-select-scroll-strategy"),deps:[Oi],useFactory:function lY(t){return()=>t.scrollStrategies.reposition()}};let uY=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t});static#n=this.\u0275inj=ae({providers:[dY],imports:[oi,Bd,$E,Ae,ug,gu,$E,Ae]})}return t})(),hY=(()=>{class t{static#e=this.\u0275fac=function(i){return new(i||t)};static#t=this.\u0275mod=le({type:t,bootstrap:[WI]});static#n=this.\u0275inj=ae({imports:[x3,$4,N8,Y8,x6,aE,n7,j6,rU,X9,j_,oY,YS,gu,z5,$5,uY,KU,F$,hG,KS]})}return t})();jH().bootstrapModule(hY).catch(t=>console.error(t))}},ve=>{ve(ve.s=39)}]);
\ No newline at end of file
@@ -0,0 +1 @@
+curl  -vvv -u "admin:pass1234" --data-binary @listener.json -H "Content-Type: application/json" -X POST http://192.168.44.113:48888/apiserver/config/listeners/$1
@@ -0,0 +1 @@
+curl  -vvv -u "admin:pass1234" -H "Content-Type: application/json" -d "{\"name\": \"$1\", \"role\": \"${2:-viewer}\"}" -X POST http://192.168.44.113:48888/apiserver/tokens
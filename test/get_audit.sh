@@ -0,0 +1 @@
+curl  -vvv -u "admin:pass1234" -X GET "http://192.168.44.113:48888/apiserver/audit?offset=${1:-0}&limit=${2:-100}"
@@ -0,0 +1 @@
+curl  -vvv -u "admin:pass1234" -X GET http://192.168.44.113:48888/apiserver/openapi.json
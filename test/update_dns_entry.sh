@@ -0,0 +1 @@
+curl  -vvv -u "admin:pass1234" -d "\"$2\"" -H "Content-Type: application/json" -X PUT http://192.168.44.113:48888/apiserver/config/dns/$1